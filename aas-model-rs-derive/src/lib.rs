@@ -0,0 +1,442 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, GenericArgument, Ident, LitStr, Path,
+          PathArguments, Type};
+
+///Derives `TAasCollection` for a struct with named fields together with the conversions into a
+/// `SubmodelElementCollection` and a `Submodel` and the fallible conversions back.
+///
+/// Attributes of the struct:
+/// - `#[aas(id_short = "...")]`: idShort of the collection or submodel, defaults to the name of the
+///   struct
+/// - `#[aas(semantic_id = "...")]`: global reference used as semantic ID
+/// - `#[aas(id = "...")]`: ID of the submodel created by `Into<Submodel>`
+/// - `#[aas(crate = "...")]`: path of the `aas_model_rs` crate
+///
+/// Attributes of the fields:
+/// - `#[aas(id_short = "...")]`: idShort of the element, defaults to the field name in PascalCase
+/// - `#[aas(semantic_id = "...")]`: global reference used as semantic ID of the element
+/// - `#[aas(xsd = "...")]`: value type of a property, e.g. `double` or `xs:double`, defaults to the
+///   value type of the Rust type
+/// - `#[aas(multi_language)]`: the field is a `Vec<MultiLanguageTextType>` stored as a
+///   multi-language property
+/// - `#[aas(collection)]`: the field is a struct deriving `AasCollection` stored as a nested
+///   collection
+/// - `#[aas(element)]`: the field is a `SubmodelElement` stored as it is
+/// - `#[aas(id)]`: the field is a `String` holding the ID of the submodel
+/// - `#[aas(skip)]`: the field is not stored and set to its default value when reading
+///
+/// All other fields are stored as properties and need to implement `TPropertyValue`. Fields of
+/// type `Option` are stored as optional elements.
+#[proc_macro_derive(AasCollection, attributes(aas))]
+pub fn derive_aas_collection(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+struct StructOptions {
+    id_short: Option<String>,
+    semantic_id: Option<String>,
+    id: Option<String>,
+    crate_path: Option<Path>
+}
+
+#[derive(Default)]
+struct FieldOptions {
+    id_short: Option<String>,
+    semantic_id: Option<String>,
+    xsd: Option<Ident>,
+    multi_language: bool,
+    collection: bool,
+    element: bool,
+    id: bool,
+    skip: bool
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let name = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new_spanned(name, "AasCollection can only be derived for structs with named fields"))
+        },
+        _ => return Err(Error::new_spanned(name, "AasCollection can only be derived for structs"))
+    };
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(&input.generics, "AasCollection cannot be derived for generic structs"));
+    }
+
+    let options = parse_struct_options(&input.attrs)?;
+    let aas = match &options.crate_path {
+        Some(path) => quote!(#path),
+        None => quote!(::aas_model_rs)
+    };
+    let id_short = options.id_short.clone().unwrap_or_else(|| name.to_string());
+    let semantic_id = option_tokens(&options.semantic_id);
+    let conversion_error = quote!(#aas::enumerations::conversion_error::ConversionError);
+    let submodel_element = quote!(#aas::enumerations::interface_enumerations::submodel_element::SubmodelElement);
+    let submodel = quote!(#aas::structs::submodel::Submodel);
+    let collection = quote!(#aas::structs::submodel_element_collection::SubmodelElementCollection);
+    let reference = quote!(#aas::structs::reference::Reference);
+    let aas_collection = quote!(#aas::traits::aas_collection::TAasCollection);
+
+    let mut writes = Vec::new();
+    let mut reads = Vec::new();
+    let mut id_field = None;
+    for field in fields {
+        let ident = field.ident.as_ref().expect("named field");
+        let field_options = parse_field_options(&field.attrs)?;
+        if field_options.id {
+            id_field = Some(ident.clone());
+        }
+        if field_options.id || field_options.skip {
+            reads.push(quote!(#ident: ::core::default::Default::default()));
+            continue;
+        }
+
+        let element_id_short = field_options.id_short.clone().unwrap_or_else(|| to_pascal_case(&ident.to_string()));
+        let (optional, value_type) = match option_inner(&field.ty) {
+            Some(inner) => (true, inner),
+            None => (false, &field.ty)
+        };
+        let write = write_element(&aas, &field_options, value_type, &element_id_short);
+        writes.push(if optional {
+            quote!(if let ::core::option::Option::Some(value) = &self.#ident {
+                elements.push(#write);
+            })
+        } else {
+            quote!({
+                let value = &self.#ident;
+                elements.push(#write);
+            })
+        });
+
+        let read = read_element(&aas, &field_options, value_type);
+        let find = quote!(elements.iter().find(|element| element.get_id_short().is_some_and(|id_short| id_short == #element_id_short)));
+        let arms = if optional {
+            quote!(
+                ::core::option::Option::Some(element) => ::core::option::Option::Some(#read?),
+                ::core::option::Option::None => ::core::option::Option::None
+            )
+        } else {
+            quote!(
+                ::core::option::Option::Some(element) => #read?,
+                ::core::option::Option::None => return ::core::result::Result::Err(#conversion_error::MissingElement(path))
+            )
+        };
+        reads.push(quote!(#ident: {
+            let path = join_path(path, #element_id_short);
+            match #find {
+                #arms
+            }
+        }));
+    }
+
+    let submodel_id = match (&id_field, &options.id) {
+        (Some(field), _) => Some(quote!(value.#field.clone())),
+        (None, Some(id)) => Some(quote!(#id.to_string())),
+        (None, None) => None
+    };
+    let into_submodel = submodel_id.map(|submodel_id| quote!(
+        impl ::core::convert::From<#name> for #submodel {
+            fn from(value: #name) -> #submodel {
+                #aas_collection::to_submodel(&value, #submodel_id)
+            }
+        }
+    ));
+    let set_id = id_field.map(|field| quote!(value.#field = submodel.get_id().clone();));
+
+    Ok(quote!(
+        const _: () = {
+            #[allow(unused_imports)]
+            use #aas::traits::{
+                has_semantics::THasSemantics as _,
+                identifiable::TIdentifiable as _,
+                property_value::TPropertyValue as _,
+                referable::TReferable as _
+            };
+
+            #[allow(dead_code)]
+            fn global_reference(value: &str) -> #reference {
+                #reference::new(#aas::enumerations::reference_type::ReferenceType::ExternalReference,
+                                ::std::vec![#aas::structs::key::Key::new(#aas::enumerations::key_type::KeyType::GlobalReference,
+                                                                       ::std::string::ToString::to_string(value))])
+            }
+
+            #[allow(dead_code)]
+            fn join_path(path: &str, id_short: &str) -> ::std::string::String {
+                if path.is_empty() {
+                    ::std::string::ToString::to_string(id_short)
+                } else {
+                    ::std::format!("{}.{}", path, id_short)
+                }
+            }
+
+            impl #aas_collection for #name {
+                const ID_SHORT: &'static str = #id_short;
+                const SEMANTIC_ID: ::core::option::Option<&'static str> = #semantic_id;
+
+                #[allow(clippy::vec_init_then_push)]
+                fn to_elements(&self) -> ::std::vec::Vec<#submodel_element> {
+                    let mut elements = ::std::vec::Vec::new();
+                    #(#writes)*
+                    elements
+                }
+
+                #[allow(unused_variables)]
+                fn from_elements(elements: &[#submodel_element], path: &str) -> ::core::result::Result<#name, #conversion_error> {
+                    ::core::result::Result::Ok(#name {
+                        #(#reads),*
+                    })
+                }
+            }
+
+            impl ::core::convert::From<#name> for #collection {
+                fn from(value: #name) -> #collection {
+                    #aas_collection::to_collection(&value)
+                }
+            }
+
+            impl ::core::convert::TryFrom<&#collection> for #name {
+                type Error = #conversion_error;
+
+                fn try_from(collection: &#collection) -> ::core::result::Result<#name, #conversion_error> {
+                    let path = collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default();
+                    <#name as #aas_collection>::from_collection(collection, path)
+                }
+            }
+
+            impl ::core::convert::TryFrom<#collection> for #name {
+                type Error = #conversion_error;
+
+                fn try_from(collection: #collection) -> ::core::result::Result<#name, #conversion_error> {
+                    #name::try_from(&collection)
+                }
+            }
+
+            #into_submodel
+
+            impl ::core::convert::TryFrom<&#submodel> for #name {
+                type Error = #conversion_error;
+
+                #[allow(unused_mut)]
+                fn try_from(submodel: &#submodel) -> ::core::result::Result<#name, #conversion_error> {
+                    let mut value = <#name as #aas_collection>::from_submodel(submodel)?;
+                    #set_id
+                    ::core::result::Result::Ok(value)
+                }
+            }
+
+            impl ::core::convert::TryFrom<#submodel> for #name {
+                type Error = #conversion_error;
+
+                fn try_from(submodel: #submodel) -> ::core::result::Result<#name, #conversion_error> {
+                    #name::try_from(&submodel)
+                }
+            }
+        };
+    ))
+}
+
+///Returns an expression creating the submodel element from `value`, a reference to the field value.
+fn write_element(aas: &TokenStream2, options: &FieldOptions, value_type: &Type, id_short: &str) -> TokenStream2 {
+    let submodel_element = quote!(#aas::enumerations::interface_enumerations::submodel_element::SubmodelElement);
+    let semantic_id = options.semantic_id.as_ref()
+        .map(|semantic_id| quote!(element.set_semantic_id(global_reference(#semantic_id));));
+    if options.multi_language {
+        quote!({
+            let mut element = #aas::structs::multi_language_property::MultiLanguageProperty::new();
+            element.set_id_short(::std::string::ToString::to_string(#id_short));
+            #semantic_id
+            element.set_value(::core::clone::Clone::clone(value));
+            #submodel_element::MultiLanguageProperty(element)
+        })
+    } else if options.collection {
+        quote!({
+            let mut element = #aas::traits::aas_collection::TAasCollection::to_collection(value);
+            element.set_id_short(::std::string::ToString::to_string(#id_short));
+            #semantic_id
+            #submodel_element::SubmodelElementCollection(element)
+        })
+    } else if options.element {
+        quote!({
+            let mut element = #submodel_element::clone(value);
+            element.set_id_short(::std::string::ToString::to_string(#id_short));
+            element
+        })
+    } else {
+        let property_value = quote!(#aas::traits::property_value::TPropertyValue);
+        let xsd = match &options.xsd {
+            Some(variant) => quote!(#aas::enumerations::data_type_def_xsd::DataTypeDefXsd::#variant),
+            None => quote!(<#value_type as #property_value>::get_value_type())
+        };
+        quote!({
+            let mut element = #aas::structs::property::Property::new(#xsd);
+            element.set_id_short(::std::string::ToString::to_string(#id_short));
+            #semantic_id
+            element.set_value(#property_value::format_value(value));
+            #submodel_element::Property(element)
+        })
+    }
+}
+
+///Returns an expression converting `element`, found at `path`, into a result of the field value.
+fn read_element(aas: &TokenStream2, options: &FieldOptions, value_type: &Type) -> TokenStream2 {
+    let submodel_element = quote!(#aas::enumerations::interface_enumerations::submodel_element::SubmodelElement);
+    let conversion_error = quote!(#aas::enumerations::conversion_error::ConversionError);
+    let unexpected = quote!(_ => ::core::result::Result::Err(#conversion_error::UnexpectedElementType(path.clone())));
+    if options.multi_language {
+        quote!(match element {
+            #submodel_element::MultiLanguageProperty(property) =>
+                ::core::result::Result::Ok(::core::clone::Clone::clone(property.get_value())),
+            #unexpected
+        })
+    } else if options.collection {
+        quote!(match element {
+            #submodel_element::SubmodelElementCollection(collection) =>
+                <#value_type as #aas::traits::aas_collection::TAasCollection>::from_collection(collection, &path),
+            #unexpected
+        })
+    } else if options.element {
+        quote!(::core::result::Result::<#submodel_element, #conversion_error>::Ok(::core::clone::Clone::clone(element)))
+    } else {
+        quote!(match element {
+            #submodel_element::Property(property) => property.get_typed_value::<#value_type>()
+                .ok_or_else(|| #conversion_error::InvalidValue(path.clone())),
+            #unexpected
+        })
+    }
+}
+
+fn parse_struct_options(attrs: &[Attribute]) -> Result<StructOptions, Error> {
+    let mut options = StructOptions {
+        id_short: None,
+        semantic_id: None,
+        id: None,
+        crate_path: None
+    };
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("aas")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("id_short") {
+                options.id_short = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("semantic_id") {
+                options.semantic_id = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("id") {
+                options.id = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("crate") {
+                options.crate_path = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+            } else {
+                return Err(meta.error("unsupported aas attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(options)
+}
+
+fn parse_field_options(attrs: &[Attribute]) -> Result<FieldOptions, Error> {
+    let mut options = FieldOptions::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("aas")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("id_short") {
+                options.id_short = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("semantic_id") {
+                options.semantic_id = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("xsd") {
+                let xsd = meta.value()?.parse::<LitStr>()?;
+                let variant = get_value_type_variant(&xsd.value())
+                    .ok_or_else(|| Error::new_spanned(&xsd, "unknown XML schema data type"))?;
+                options.xsd = Some(format_ident!("{}", variant));
+            } else if meta.path.is_ident("multi_language") {
+                options.multi_language = true;
+            } else if meta.path.is_ident("collection") {
+                options.collection = true;
+            } else if meta.path.is_ident("element") {
+                options.element = true;
+            } else if meta.path.is_ident("id") {
+                options.id = true;
+            } else if meta.path.is_ident("skip") {
+                options.skip = true;
+            } else {
+                return Err(meta.error("unsupported aas attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(options)
+}
+
+///Returns the type wrapped in an `Option`, if the type is an `Option`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => match arguments.args.first() {
+            Some(GenericArgument::Type(inner)) if arguments.args.len() == 1 => Some(inner),
+            _ => None
+        },
+        _ => None
+    }
+}
+
+fn option_tokens(value: &Option<String>) -> TokenStream2 {
+    match value {
+        Some(value) => quote!(::core::option::Option::Some(#value)),
+        None => quote!(::core::option::Option::None)
+    }
+}
+
+fn to_pascal_case(name: &str) -> String {
+    name.trim_start_matches("r#")
+        .split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next().map(|first| first.to_uppercase().chain(chars).collect()).unwrap_or_default()
+        })
+        .collect::<Vec<String>>()
+        .concat()
+}
+
+///Returns the name of the `DataTypeDefXsd` variant of an XML schema data type like `xs:double`.
+fn get_value_type_variant(name: &str) -> Option<&'static str> {
+    let variant = match name.strip_prefix("xs:").unwrap_or(name) {
+        "anyURI" => "AnyUri",
+        "base64Binary" => "Base64Binary",
+        "boolean" => "Boolean",
+        "byte" => "Byte",
+        "date" => "Date",
+        "dateTime" => "DateTime",
+        "decimal" => "Decimal",
+        "double" => "Double",
+        "duration" => "Duration",
+        "float" => "Float",
+        "gDay" => "GDay",
+        "gMonth" => "GMonth",
+        "gMonthDay" => "GMonthDay",
+        "gYear" => "GYear",
+        "gYearMonth" => "GYearMonth",
+        "hexBinary" => "HexBinary",
+        "int" => "Int",
+        "integer" => "Integer",
+        "long" => "Long",
+        "negativeInteger" => "NegativeInteger",
+        "nonNegativeInteger" => "NonNegativeInteger",
+        "nonPositiveInteger" => "NonPositiveInteger",
+        "positiveInteger" => "PositiveInteger",
+        "short" => "Short",
+        "string" => "String",
+        "time" => "Time",
+        "unsignedByte" => "UnsignedByte",
+        "unsignedInt" => "UnsignedInt",
+        "unsignedLong" => "UnsignedLong",
+        "unsignedShort" => "UnsignedShort",
+        _ => return None
+    };
+    Some(variant)
+}
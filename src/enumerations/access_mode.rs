@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+///Enumeration of the access modes of submodel elements defined by the IDTA submodel template
+/// specification.
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
+pub enum AccessMode {
+    ///The value of the element can be read and written.
+    ReadWrite,
+    ///The value of the element can only be read.
+    ReadOnly
+}
+
+impl AccessMode {
+    ///Parses an access mode from its name. Returns None if the name is unknown.
+    ///
+    /// [name]: name of the access mode, e.g. `ReadOnly`
+    pub fn parse(name: &str) -> Option<AccessMode> {
+        match name.trim() {
+            "ReadWrite" => Some(AccessMode::ReadWrite),
+            "ReadOnly" => Some(AccessMode::ReadOnly),
+            _ => None
+        }
+    }
+
+    ///Returns the name of the access mode.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            AccessMode::ReadWrite => "ReadWrite",
+            AccessMode::ReadOnly => "ReadOnly"
+        }
+    }
+}
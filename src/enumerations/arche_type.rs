@@ -0,0 +1,34 @@
+///Enumeration of the archetypes of the Hierarchical Structures submodel, which state in which
+/// direction the hierarchy is modelled starting at the entry node.
+#[derive(PartialEq, Clone)]
+pub enum ArcheType {
+    ///The complete hierarchy of parts below the entry node is modelled.
+    Full,
+    ///Only the direct parts of the entry node are modelled.
+    OneDown,
+    ///Only the assemblies the entry node is a direct part of are modelled.
+    OneUp
+}
+
+impl ArcheType {
+    ///Parses an archetype from its name. Returns None if the name is unknown.
+    ///
+    /// [name]: name of the archetype, e.g. `OneDown`
+    pub fn parse(name: &str) -> Option<ArcheType> {
+        match name.trim() {
+            "Full" => Some(ArcheType::Full),
+            "OneDown" => Some(ArcheType::OneDown),
+            "OneUp" => Some(ArcheType::OneUp),
+            _ => None
+        }
+    }
+
+    ///Returns the name of the archetype.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            ArcheType::Full => "Full",
+            ArcheType::OneDown => "OneDown",
+            ArcheType::OneUp => "OneUp"
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+///Errors that can occur when the value of a data element is read from its source and written to
+/// the element.
+#[derive(Debug, PartialEq, Clone)]
+pub enum BindingError {
+    ///The contained address of the source is no valid URI or lacks information, e.g. a register.
+    InvalidAddress(String),
+    ///The contained protocol or operation of a form is not supported, e.g. `https`.
+    UnsupportedProtocol(String),
+    ///Connecting to or communicating with the source at the contained address failed with the
+    /// contained message.
+    ConnectionFailed(String, String),
+    ///The source answered with an error or a malformed response; the message is contained.
+    ProtocolError(String),
+    ///The source sent no value within the timeout.
+    Timeout,
+    ///No element exists at the contained idShortPath, or no affordance or interface at the contained
+    /// reference.
+    ElementNotFound(String),
+    ///The element at the contained idShortPath is neither a property nor a range.
+    UnsupportedElement(String),
+    ///The contained value read from the source cannot be converted to the data type of the element
+    /// at the contained idShortPath.
+    InvalidValue(String, String)
+}
+
+impl Display for BindingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BindingError::InvalidAddress(address) => write!(f, "invalid source address '{}'", address),
+            BindingError::UnsupportedProtocol(protocol) => write!(f, "unsupported protocol or operation '{}'", protocol),
+            BindingError::ConnectionFailed(address, message) => write!(f, "communication with '{}' failed: {}", address, message),
+            BindingError::ProtocolError(message) => write!(f, "invalid response of the source: {}", message),
+            BindingError::Timeout => write!(f, "the source sent no value within the timeout"),
+            BindingError::ElementNotFound(path) => write!(f, "element '{}' not found", path),
+            BindingError::UnsupportedElement(path) => write!(f, "element '{}' is neither a property nor a range", path),
+            BindingError::InvalidValue(path, value) => write!(f, "value '{}' is invalid for element '{}'", value, path)
+        }
+    }
+}
+
+impl Error for BindingError {}
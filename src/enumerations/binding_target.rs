@@ -0,0 +1,10 @@
+///Enumeration of the values of a data element a value binding writes to.
+#[derive(PartialEq, Clone)]
+pub enum BindingTarget {
+    ///The value of a property.
+    Value,
+    ///The lower bound of a range.
+    Min,
+    ///The upper bound of a range.
+    Max
+}
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+///Enumeration of the cardinalities of submodel elements defined by the IDTA submodel template
+/// specification.
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
+pub enum Cardinality {
+    ///The element occurs exactly once.
+    One,
+    ///The element occurs at most once.
+    ZeroToOne,
+    ///The element occurs arbitrarily often.
+    ZeroToMany,
+    ///The element occurs at least once.
+    OneToMany
+}
+
+impl Cardinality {
+    ///Parses a cardinality from its name. Returns None if the name is unknown.
+    ///
+    /// [name]: name of the cardinality, e.g. `ZeroToOne`
+    pub fn parse(name: &str) -> Option<Cardinality> {
+        match name.trim() {
+            "One" => Some(Cardinality::One),
+            "ZeroToOne" => Some(Cardinality::ZeroToOne),
+            "ZeroToMany" => Some(Cardinality::ZeroToMany),
+            "OneToMany" => Some(Cardinality::OneToMany),
+            _ => None
+        }
+    }
+
+    ///Returns the name of the cardinality.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Cardinality::One => "One",
+            Cardinality::ZeroToOne => "ZeroToOne",
+            Cardinality::ZeroToMany => "ZeroToMany",
+            Cardinality::OneToMany => "OneToMany"
+        }
+    }
+
+    ///Returns the minimum number of occurrences.
+    pub fn get_min(&self) -> usize {
+        match self {
+            Cardinality::One | Cardinality::OneToMany => 1,
+            Cardinality::ZeroToOne | Cardinality::ZeroToMany => 0
+        }
+    }
+
+    ///Returns the maximum number of occurrences, or None if the number is unlimited.
+    pub fn get_max(&self) -> Option<usize> {
+        match self {
+            Cardinality::One | Cardinality::ZeroToOne => Some(1),
+            Cardinality::ZeroToMany | Cardinality::OneToMany => None
+        }
+    }
+
+    ///Returns whether the element may be omitted.
+    pub fn is_optional(&self) -> bool {
+        self.get_min() == 0
+    }
+}
@@ -0,0 +1,48 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::structs::service_result::ServiceResult;
+
+///Errors that can occur when a server is called by the HTTP API, e.g. an AAS repository or
+/// registry.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ClientError {
+    ///The contained base URL is no valid absolute URL.
+    InvalidUrl(String),
+    ///The contained protocol is not supported, e.g. `ftp`.
+    UnsupportedProtocol(String),
+    ///The contained header name or value contains line breaks or other characters not allowed in
+    /// HTTP headers.
+    InvalidHeader(String),
+    ///Connecting to or communicating with the server at the contained address failed with the
+    /// contained message.
+    ConnectionFailed(String, String),
+    ///The server sent no response within the timeout.
+    Timeout,
+    ///The server answered with the contained HTTP status code and the contained messages.
+    Status(u16, ServiceResult),
+    ///The response is malformed or cannot be deserialized; the message is contained.
+    InvalidResponse(String)
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::InvalidUrl(url) => write!(f, "invalid URL '{}'", url),
+            ClientError::UnsupportedProtocol(protocol) => write!(f, "unsupported protocol '{}'", protocol),
+            ClientError::InvalidHeader(header) => write!(f, "invalid header '{}'", header),
+            ClientError::ConnectionFailed(address, message) => write!(f, "connection to '{}' failed: {}", address, message),
+            ClientError::Timeout => write!(f, "no response within the timeout"),
+            ClientError::Status(status, result) => {
+                write!(f, "HTTP status {}", status)?;
+                for message in result.get_messages() {
+                    write!(f, ": {}", message.get_text())?;
+                }
+                Ok(())
+            },
+            ClientError::InvalidResponse(message) => write!(f, "invalid response: {}", message)
+        }
+    }
+}
+
+impl Error for ClientError {}
\ No newline at end of file
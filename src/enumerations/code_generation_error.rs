@@ -0,0 +1,23 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+///Errors that can occur when Rust code is generated from a submodel template file.
+#[derive(Debug, PartialEq, Clone)]
+pub enum CodeGenerationError {
+    ///Reading the template or writing the generated code failed with the contained message.
+    Io(String),
+    ///The template file contains neither a submodel nor an environment with a submodel. Holds the
+    /// message of the parser.
+    InvalidTemplate(String)
+}
+
+impl Display for CodeGenerationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CodeGenerationError::Io(message) => write!(f, "I/O error: {}", message),
+            CodeGenerationError::InvalidTemplate(message) => write!(f, "invalid submodel template: {}", message)
+        }
+    }
+}
+
+impl Error for CodeGenerationError {}
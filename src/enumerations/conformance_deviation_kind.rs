@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+///Enumeration for denoting how a submodel instance deviates from its submodel template.
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
+pub enum ConformanceDeviationKind {
+    ///The semantic ID of the element differs from the one of the template.
+    SemanticIdMismatch,
+    ///A mandatory element of the template is missing in the instance.
+    MissingElement,
+    ///The element occurs more often than its cardinality allows.
+    TooManyElements,
+    ///The element is not defined by the template.
+    UnexpectedElement,
+    ///The element is of a different type than in the template.
+    ElementTypeMismatch,
+    ///The value type of the element differs from the one of the template.
+    ValueTypeMismatch,
+    ///The value of the element is not a valid value of its value type.
+    InvalidValue,
+    ///The value of the element is none of the allowed values of the template.
+    ValueNotAllowed,
+    ///The value of the element lies outside of the allowed range of the template.
+    ValueOutOfRange,
+    ///The type or value type of the elements of a submodel element list differs from the template
+    /// or from the types declared by the list.
+    ListElementTypeMismatch
+}
@@ -0,0 +1,25 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+///Errors that can occur when generic submodel elements are converted into typed Rust structs.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ConversionError {
+    ///A mandatory element at the contained idShortPath is missing.
+    MissingElement(String),
+    ///The element at the contained idShortPath is of an unexpected type.
+    UnexpectedElementType(String),
+    ///The element at the contained idShortPath has no value or a value that cannot be converted.
+    InvalidValue(String)
+}
+
+impl Display for ConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConversionError::MissingElement(path) => write!(f, "mandatory submodel element '{}' is missing", path),
+            ConversionError::UnexpectedElementType(path) => write!(f, "submodel element '{}' has an unexpected type", path),
+            ConversionError::InvalidValue(path) => write!(f, "submodel element '{}' has an invalid value", path)
+        }
+    }
+}
+
+impl Error for ConversionError {}
@@ -62,4 +62,37 @@ pub enum DataTypeDefXsd {
     UnsignedLong,
     #[serde(rename = "xs:unsignedShort")]
     UnsignedShort
-}
\ No newline at end of file
+}
+
+impl DataTypeDefXsd {
+    ///Returns whether the value is a valid lexical representation of the data type. Booleans and
+    /// numeric types are checked including the value space of the bounded integer types. Values of
+    /// all other data types are considered valid.
+    ///
+    /// [value]: value to check
+    pub fn is_valid_value(&self, value: &str) -> bool {
+        let integer = |min: i128, max: i128| value.strip_prefix('+').unwrap_or(value).parse::<i128>()
+            .is_ok_and(|value| value >= min && value <= max);
+        let floating = |special_values: bool| (special_values && matches!(value, "INF" | "-INF" | "+INF" | "NaN"))
+            || (!value.contains(['i', 'I', 'n', 'N']) && value.parse::<f64>().is_ok());
+        match self {
+            DataTypeDefXsd::Boolean => matches!(value, "true" | "false" | "1" | "0"),
+            DataTypeDefXsd::Decimal => !value.contains(['e', 'E']) && floating(false),
+            DataTypeDefXsd::Double | DataTypeDefXsd::Float => floating(true),
+            DataTypeDefXsd::Byte => integer(i8::MIN as i128, i8::MAX as i128),
+            DataTypeDefXsd::Short => integer(i16::MIN as i128, i16::MAX as i128),
+            DataTypeDefXsd::Int => integer(i32::MIN as i128, i32::MAX as i128),
+            DataTypeDefXsd::Long => integer(i64::MIN as i128, i64::MAX as i128),
+            DataTypeDefXsd::UnsignedByte => integer(0, u8::MAX as i128),
+            DataTypeDefXsd::UnsignedShort => integer(0, u16::MAX as i128),
+            DataTypeDefXsd::UnsignedInt => integer(0, u32::MAX as i128),
+            DataTypeDefXsd::UnsignedLong => integer(0, u64::MAX as i128),
+            DataTypeDefXsd::Integer => integer(i128::MIN, i128::MAX),
+            DataTypeDefXsd::NegativeInteger => integer(i128::MIN, -1),
+            DataTypeDefXsd::NonPositiveInteger => integer(i128::MIN, 0),
+            DataTypeDefXsd::NonNegativeInteger => integer(0, i128::MAX),
+            DataTypeDefXsd::PositiveInteger => integer(1, i128::MAX),
+            _ => true
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+///Enumeration of the data types of a concept described with the data specification IEC 61360.
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
+pub enum DataTypeIec61360 {
+    #[serde(rename = "DATE")]
+    Date,
+    #[serde(rename = "STRING")]
+    String,
+    #[serde(rename = "STRING_TRANSLATABLE")]
+    StringTranslatable,
+    #[serde(rename = "INTEGER_MEASURE")]
+    IntegerMeasure,
+    #[serde(rename = "INTEGER_COUNT")]
+    IntegerCount,
+    #[serde(rename = "INTEGER_CURRENCY")]
+    IntegerCurrency,
+    #[serde(rename = "REAL_MEASURE")]
+    RealMeasure,
+    #[serde(rename = "REAL_COUNT")]
+    RealCount,
+    #[serde(rename = "REAL_CURRENCY")]
+    RealCurrency,
+    #[serde(rename = "BOOLEAN")]
+    Boolean,
+    #[serde(rename = "IRI")]
+    Iri,
+    #[serde(rename = "IRDI")]
+    Irdi,
+    #[serde(rename = "RATIONAL")]
+    Rational,
+    #[serde(rename = "RATIONAL_MEASURE")]
+    RationalMeasure,
+    #[serde(rename = "TIME")]
+    Time,
+    #[serde(rename = "TIMESTAMP")]
+    Timestamp,
+    #[serde(rename = "FILE")]
+    File,
+    #[serde(rename = "HTML")]
+    Html,
+    #[serde(rename = "BLOB")]
+    Blob
+}
\ No newline at end of file
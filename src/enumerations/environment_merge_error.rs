@@ -0,0 +1,19 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+///Errors that can occur when environments are merged.
+#[derive(Debug, PartialEq, Clone)]
+pub enum EnvironmentMergeError {
+    ///Two different identifiables share the contained ID.
+    DuplicateId(String)
+}
+
+impl Display for EnvironmentMergeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EnvironmentMergeError::DuplicateId(id) => write!(f, "duplicate identifiable ID '{}'", id)
+        }
+    }
+}
+
+impl Error for EnvironmentMergeError {}
@@ -0,0 +1,27 @@
+///Enumeration of the extents of the HTTP API, which determine whether the values of blobs are
+/// contained in a response.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Extent {
+    ///The values of blobs are omitted.
+    WithoutBlobValue,
+    ///The values of blobs are contained as base64 text.
+    WithBlobValue
+}
+
+impl Extent {
+    ///Returns the extent with the name of the query parameter `extent`. Returns None for an unknown
+    /// name.
+    ///
+    /// [name]: name of the extent, e.g. `withBlobValue`
+    pub fn from_name(name: &str) -> Option<Extent> {
+        [Extent::WithoutBlobValue, Extent::WithBlobValue].into_iter().find(|extent| extent.get_name() == name)
+    }
+
+    ///Returns the name of the extent in the query parameter `extent`.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Extent::WithoutBlobValue => "withoutBlobValue",
+            Extent::WithBlobValue => "withBlobValue"
+        }
+    }
+}
\ No newline at end of file
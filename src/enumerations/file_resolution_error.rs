@@ -0,0 +1,31 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+///Errors that can occur when the content of a file element is resolved.
+#[derive(Debug, PartialEq, Clone)]
+pub enum FileResolutionError {
+    ///The file element has no path.
+    MissingPath,
+    ///The path is absolute outside of a package, leaves the file store or is no valid part name.
+    InvalidPath(String),
+    ///No file exists at the contained path.
+    FileNotFound(String),
+    ///Reading the file at the contained path failed with the contained message.
+    ReadFailed(String, String),
+    ///The package cannot be read as an AASX package; the message is contained.
+    InvalidPackage(String)
+}
+
+impl Display for FileResolutionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FileResolutionError::MissingPath => write!(f, "the file element has no path"),
+            FileResolutionError::InvalidPath(path) => write!(f, "invalid file path '{}'", path),
+            FileResolutionError::FileNotFound(path) => write!(f, "file '{}' not found", path),
+            FileResolutionError::ReadFailed(path, message) => write!(f, "file '{}' cannot be read: {}", path, message),
+            FileResolutionError::InvalidPackage(message) => write!(f, "invalid AASX package: {}", message)
+        }
+    }
+}
+
+impl Error for FileResolutionError {}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+///Enumeration for denoting how identifiables with the same ID are handled when environments are
+/// merged.
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
+pub enum IdConflictPolicy {
+    ///The merge fails on the first duplicate ID.
+    Fail,
+    ///The identifiable that was merged first is kept.
+    KeepFirst,
+    ///The identifiable with the highest version and revision of its administrative information is
+    /// kept. Identifiables without administrative information are considered oldest.
+    KeepNewest,
+    ///The later identifiable is kept under a new ID and all model references of its environment
+    /// pointing to the old ID are rewritten.
+    Rename
+}
@@ -0,0 +1,80 @@
+use std::fmt::{Display, Formatter};
+
+///Segment of an idShortPath addressing a submodel element within a submodel. Elements of submodel
+/// element collections and entities are addressed by their idShort, elements of submodel element
+/// lists by their index, e.g. `Collection.List[2].Property`.
+#[derive(PartialEq, Clone)]
+pub enum IdShortPathSegment {
+    ///Element addressed by its idShort.
+    IdShort(String),
+    ///Element of a submodel element list addressed by its index.
+    Index(usize)
+}
+
+impl IdShortPathSegment {
+    ///Parses an idShortPath into its segments. Returns None if the path is malformed. An empty
+    /// path results in no segments and denotes the submodel itself.
+    ///
+    /// [id_short_path]: idShortPath to parse
+    pub fn parse_path(id_short_path: &str) -> Option<Vec<IdShortPathSegment>> {
+        let mut segments = Vec::new();
+        if id_short_path.is_empty() {
+            return Some(segments);
+        }
+        for part in id_short_path.split('.') {
+            let (id_short, mut indexes) = match part.find('[') {
+                Some(position) => part.split_at(position),
+                None => (part, "")
+            };
+            if id_short.is_empty() && segments.is_empty() {
+                return None;
+            }
+            if !id_short.is_empty() {
+                segments.push(IdShortPathSegment::IdShort(id_short.to_string()));
+            } else if !segments.is_empty() {
+                return None;
+            }
+            while !indexes.is_empty() {
+                let end = indexes.find(']')?;
+                let index = indexes.get(1..end)?.parse().ok()?;
+                segments.push(IdShortPathSegment::Index(index));
+                indexes = &indexes[end + 1..];
+                if !indexes.is_empty() && !indexes.starts_with('[') {
+                    return None;
+                }
+            }
+        }
+        Some(segments)
+    }
+
+    ///Formats segments as an idShortPath.
+    ///
+    /// [segments]: segments of the path
+    pub fn format_path(segments: &[IdShortPathSegment]) -> String {
+        let mut path = String::new();
+        for segment in segments {
+            if let IdShortPathSegment::IdShort(_) = segment && !path.is_empty() {
+                path.push('.');
+            }
+            path.push_str(&segment.to_string());
+        }
+        path
+    }
+
+    ///Returns the value of the key addressing the element of this segment in a model reference.
+    pub fn get_key_value(&self) -> String {
+        match self {
+            IdShortPathSegment::IdShort(id_short) => id_short.clone(),
+            IdShortPathSegment::Index(index) => index.to_string()
+        }
+    }
+}
+
+impl Display for IdShortPathSegment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IdShortPathSegment::IdShort(id_short) => write!(f, "{}", id_short),
+            IdShortPathSegment::Index(index) => write!(f, "[{}]", index)
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+///Errors that can occur when a submodel template is instantiated.
+#[derive(Debug, PartialEq, Clone)]
+pub enum InstantiationError {
+    ///The contained idShortPath of a supplied value is malformed.
+    InvalidPath(String),
+    ///No submodel element of the instance exists at the contained idShortPath.
+    ElementNotFound(String),
+    ///The submodel element at the contained idShortPath cannot hold a supplied value.
+    UnsupportedValue(String)
+}
+
+impl Display for InstantiationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InstantiationError::InvalidPath(path) => write!(f, "invalid idShortPath '{}'", path),
+            InstantiationError::ElementNotFound(path) => write!(f, "submodel element '{}' not found", path),
+            InstantiationError::UnsupportedValue(path) => write!(f, "submodel element '{}' cannot hold a value", path)
+        }
+    }
+}
+
+impl Error for InstantiationError {}
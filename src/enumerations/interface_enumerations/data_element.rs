@@ -1,11 +1,18 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::interface_enumerations::submodel_element::{blob_value_only, file_value_only, get_common_references,
+    multi_language_value_only, property_value_only, range_value_only, set_blob_value_only, set_file_value_only,
+    set_multi_language_value_only, set_property_value_only, set_range_value_only, visit_common_references_mut};
 use crate::structs::blob::Blob;
 use crate::structs::file::File;
 use crate::structs::multi_language_property::MultiLanguageProperty;
 use crate::structs::property::Property;
 use crate::structs::range::Range;
+use crate::structs::reference::Reference;
 use crate::structs::reference_element::ReferenceElement;
+use crate::traits::referable::TReferable;
 
 #[derive(PartialEq, Clone, Serialize, Deserialize)]
 #[serde(tag = "modelType")]
@@ -19,5 +26,103 @@ pub enum DataElement {
 }
 
 impl DataElement {
+    ///Returns all references contained in the data element.
+    pub fn get_references(&self) -> Vec<&Reference> {
+        match self {
+            DataElement::Property(elem) => {
+                let mut references = get_common_references(elem);
+                references.extend(elem.get_value_id().into_iter().flat_map(|reference| reference.get_references()));
+                references
+            },
+            DataElement::MultiLanguageProperty(elem) => {
+                let mut references = get_common_references(elem);
+                references.extend(elem.get_value_id().into_iter().flat_map(|reference| reference.get_references()));
+                references
+            },
+            DataElement::Range(elem) => get_common_references(elem),
+            DataElement::Blob(elem) => get_common_references(elem),
+            DataElement::File(elem) => get_common_references(elem),
+            DataElement::ReferenceElement(elem) => {
+                let mut references = get_common_references(elem);
+                references.extend(elem.get_value().into_iter().flat_map(|reference| reference.get_references()));
+                references
+            }
+        }
+    }
 
+    ///Calls the visitor for every reference contained in the data element.
+    ///
+    /// [visitor]: function called for every reference
+    pub fn visit_references_mut(&mut self, visitor: &mut dyn FnMut(&mut Reference)) {
+        match self {
+            DataElement::Property(elem) => {
+                visit_common_references_mut(elem, visitor);
+                elem.get_mut_value_id().into_iter().for_each(|reference| reference.visit_references_mut(visitor));
+            },
+            DataElement::MultiLanguageProperty(elem) => {
+                visit_common_references_mut(elem, visitor);
+                elem.get_mut_value_id().into_iter().for_each(|reference| reference.visit_references_mut(visitor));
+            },
+            DataElement::Range(elem) => visit_common_references_mut(elem, visitor),
+            DataElement::Blob(elem) => visit_common_references_mut(elem, visitor),
+            DataElement::File(elem) => visit_common_references_mut(elem, visitor),
+            DataElement::ReferenceElement(elem) => {
+                visit_common_references_mut(elem, visitor);
+                elem.get_mut_value().into_iter().for_each(|reference| reference.visit_references_mut(visitor));
+            }
+        }
+    }
+
+    ///Returns the idShort of the data element.
+    pub fn get_id_short(&self) -> Option<&String> {
+        match self {
+            DataElement::Property(elem) => elem.get_id_short(),
+            DataElement::MultiLanguageProperty(elem) => elem.get_id_short(),
+            DataElement::Range(elem) => elem.get_id_short(),
+            DataElement::Blob(elem) => elem.get_id_short(),
+            DataElement::File(elem) => elem.get_id_short(),
+            DataElement::ReferenceElement(elem) => elem.get_id_short()
+        }
+    }
+
+    ///Returns the value of the data element in the value-only serialization of the HTTP API.
+    /// Returns None for properties without value.
+    ///
+    /// [with_blob_value]: whether the values of blobs are included as base64 text
+    pub fn get_value_only(&self, with_blob_value: bool) -> Option<Value> {
+        match self {
+            DataElement::Property(elem) => property_value_only(elem),
+            DataElement::MultiLanguageProperty(elem) => Some(multi_language_value_only(elem)),
+            DataElement::Range(elem) => Some(range_value_only(elem)),
+            DataElement::Blob(elem) => Some(blob_value_only(elem, with_blob_value)),
+            DataElement::File(elem) => Some(file_value_only(elem)),
+            DataElement::ReferenceElement(elem) => Some(elem.get_value().and_then(|reference| serde_json::to_value(reference).ok())
+                .unwrap_or(Value::Null))
+        }
+    }
+
+    ///Sets the value of the data element from its value-only serialization of the HTTP API.
+    ///
+    /// [value]: value-only serialization of the value
+    pub fn set_value_only(&mut self, value: &Value) -> Result<(), ConversionError> {
+        let path = self.get_id_short().cloned().unwrap_or_default();
+        set_data_element_value_only(self, value, &path)
+    }
+}
+
+///Sets the value of a data element from its value-only serialization.
+///
+/// [path]: idShortPath of the data element used in errors
+pub(crate) fn set_data_element_value_only(element: &mut DataElement, value: &Value, path: &str) -> Result<(), ConversionError> {
+    match element {
+        DataElement::Property(elem) => set_property_value_only(elem, value, path),
+        DataElement::MultiLanguageProperty(elem) => set_multi_language_value_only(elem, value, path),
+        DataElement::Range(elem) => set_range_value_only(elem, value, path),
+        DataElement::Blob(elem) => set_blob_value_only(elem, value, path),
+        DataElement::File(elem) => set_file_value_only(elem, value, path),
+        DataElement::ReferenceElement(elem) => {
+            elem.set_value(serde_json::from_value(value.clone()).map_err(|_| ConversionError::InvalidValue(path.to_string()))?);
+            Ok(())
+        }
+    }
 }
\ No newline at end of file
@@ -1,5 +1,6 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 
+use crate::enumerations::interface_enumerations::submodel_element::TaggedElement;
 use crate::structs::administrative_information::AdministrativeInformation;
 use crate::structs::annotated_relationship_element::AnnotatedRelationshipElement;
 use crate::structs::asset_administration_shell::AssetAdministrationShell;
@@ -7,13 +8,13 @@ use crate::structs::basic_event_element::BasicEventElement;
 use crate::structs::blob::Blob;
 use crate::structs::capability::Capability;
 use crate::structs::concept_description::ConceptDescription;
+use crate::structs::embedded_data_specification::EmbeddedDataSpecification;
 use crate::structs::entity::Entity;
 use crate::structs::file::File;
 use crate::structs::multi_language_property::MultiLanguageProperty;
 use crate::structs::operation::Operation;
 use crate::structs::property::Property;
 use crate::structs::range::Range;
-use crate::structs::reference::Reference;
 use crate::structs::reference_element::ReferenceElement;
 use crate::structs::relationship_element::RelationshipElement;
 use crate::structs::submodel::Submodel;
@@ -21,7 +22,7 @@ use crate::structs::submodel_element_collection::SubmodelElementCollection;
 use crate::structs::submodel_element_list::SubmodelElementList;
 use crate::traits::has_data_specification::THasDataSpecification;
 
-#[derive(PartialEq, Clone, Serialize, Deserialize)]
+#[derive(PartialEq, Clone, Deserialize)]
 #[serde(tag = "modelType")]
 pub enum HasDataSpecification {
     BasicEventElement(BasicEventElement),
@@ -45,7 +46,7 @@ pub enum HasDataSpecification {
 }
 
 impl HasDataSpecification {
-    pub fn get_data_specifications(&self) -> &Vec<Reference> {
+    pub fn get_data_specifications(&self) -> &Vec<EmbeddedDataSpecification> {
         match self { 
             HasDataSpecification::BasicEventElement(elem) => elem.get_data_specifications(),
             HasDataSpecification::Capability(elem) => elem.get_data_specifications(),
@@ -68,7 +69,7 @@ impl HasDataSpecification {
         }
     }
     
-    pub fn get_mut_data_specifications(&mut self) -> &mut Vec<Reference> {
+    pub fn get_mut_data_specifications(&mut self) -> &mut Vec<EmbeddedDataSpecification> {
         match self { 
             HasDataSpecification::BasicEventElement(elem) => elem.get_mut_data_specifications(),
             HasDataSpecification::Capability(elem) => elem.get_mut_data_specifications(),
@@ -91,7 +92,7 @@ impl HasDataSpecification {
         }
     }
     
-    pub fn set_data_specifications(&mut self, data_specifications: Vec<Reference>) {
+    pub fn set_data_specifications(&mut self, data_specifications: Vec<EmbeddedDataSpecification>) {
         match self {
             HasDataSpecification::BasicEventElement(elem) => elem.set_data_specifications(data_specifications),
             HasDataSpecification::Capability(elem) => elem.set_data_specifications(data_specifications),
@@ -114,7 +115,7 @@ impl HasDataSpecification {
         }
     }
     
-    pub fn add_data_specification(&mut self, data_specification: Reference) {
+    pub fn add_data_specification(&mut self, data_specification: EmbeddedDataSpecification) {
         match self {
             HasDataSpecification::BasicEventElement(elem) => elem.add_data_specification(data_specification),
             HasDataSpecification::Capability(elem) => elem.add_data_specification(data_specification),
@@ -137,7 +138,7 @@ impl HasDataSpecification {
         }
     }
     
-    pub fn remove_data_specification(&mut self, index: usize) -> Reference {
+    pub fn remove_data_specification(&mut self, index: usize) -> EmbeddedDataSpecification {
         match self {
             HasDataSpecification::BasicEventElement(elem) => elem.remove_data_specification(index),
             HasDataSpecification::Capability(elem) => elem.remove_data_specification(index),
@@ -159,4 +160,29 @@ impl HasDataSpecification {
             HasDataSpecification::ConceptDescription(elem) => elem.remove_data_specification(index)
         }
     }
+}
+
+impl Serialize for HasDataSpecification {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            HasDataSpecification::BasicEventElement(elem) => TaggedElement::new("BasicEventElement", elem).serialize(serializer),
+            HasDataSpecification::Capability(elem) => TaggedElement::new("Capability", elem).serialize(serializer),
+            HasDataSpecification::SubmodelElementCollection(elem) => TaggedElement::new("SubmodelElementCollection", elem).serialize(serializer),
+            HasDataSpecification::RelationshipElement(elem) => TaggedElement::new("RelationshipElement", elem).serialize(serializer),
+            HasDataSpecification::ReferenceElement(elem) => TaggedElement::new("ReferenceElement", elem).serialize(serializer),
+            HasDataSpecification::Property(elem) => TaggedElement::new("Property", elem).serialize(serializer),
+            HasDataSpecification::MultiLanguageProperty(elem) => TaggedElement::new("MultiLanguageProperty", elem).serialize(serializer),
+            HasDataSpecification::AnnotatedRelationshipElement(elem) => TaggedElement::new("AnnotatedRelationshipElement", elem).serialize(serializer),
+            HasDataSpecification::AssetAdministrationShell(elem) => elem.serialize(serializer),
+            HasDataSpecification::AdministrativeInformation(elem) => TaggedElement::new("AdministrativeInformation", elem).serialize(serializer),
+            HasDataSpecification::Entity(elem) => TaggedElement::new("Entity", elem).serialize(serializer),
+            HasDataSpecification::Operation(elem) => TaggedElement::new("Operation", elem).serialize(serializer),
+            HasDataSpecification::Range(elem) => TaggedElement::new("Range", elem).serialize(serializer),
+            HasDataSpecification::Blob(elem) => TaggedElement::new("Blob", elem).serialize(serializer),
+            HasDataSpecification::File(elem) => TaggedElement::new("File", elem).serialize(serializer),
+            HasDataSpecification::SubmodelElementList(elem) => TaggedElement::new("SubmodelElementList", elem).serialize(serializer),
+            HasDataSpecification::Submodel(elem) => elem.serialize(serializer),
+            HasDataSpecification::ConceptDescription(elem) => elem.serialize(serializer)
+        }
+    }
 }
\ No newline at end of file
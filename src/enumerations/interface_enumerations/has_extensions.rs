@@ -1,5 +1,6 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 
+use crate::enumerations::interface_enumerations::submodel_element::TaggedElement;
 use crate::structs::annotated_relationship_element::AnnotatedRelationshipElement;
 use crate::structs::asset_administration_shell::AssetAdministrationShell;
 use crate::structs::basic_event_element::BasicEventElement;
@@ -20,7 +21,7 @@ use crate::structs::submodel_element_collection::SubmodelElementCollection;
 use crate::structs::submodel_element_list::SubmodelElementList;
 use crate::traits::has_extensions::THasExtensions;
 
-#[derive(PartialEq, Clone, Serialize, Deserialize)]
+#[derive(PartialEq, Clone, Deserialize)]
 #[serde(tag = "modelType")]
 pub enum HasExtensions {
     BasicEventElement(BasicEventElement),
@@ -152,4 +153,28 @@ impl HasExtensions {
             HasExtensions::ConceptDescription(elem) => elem.remove_extension(index)
         }
     }
+}
+
+impl Serialize for HasExtensions {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            HasExtensions::BasicEventElement(elem) => TaggedElement::new("BasicEventElement", elem).serialize(serializer),
+            HasExtensions::Capability(elem) => TaggedElement::new("Capability", elem).serialize(serializer),
+            HasExtensions::SubmodelElementCollection(elem) => TaggedElement::new("SubmodelElementCollection", elem).serialize(serializer),
+            HasExtensions::RelationshipElement(elem) => TaggedElement::new("RelationshipElement", elem).serialize(serializer),
+            HasExtensions::ReferenceElement(elem) => TaggedElement::new("ReferenceElement", elem).serialize(serializer),
+            HasExtensions::Property(elem) => TaggedElement::new("Property", elem).serialize(serializer),
+            HasExtensions::MultiLanguageProperty(elem) => TaggedElement::new("MultiLanguageProperty", elem).serialize(serializer),
+            HasExtensions::AnnotatedRelationshipElement(elem) => TaggedElement::new("AnnotatedRelationshipElement", elem).serialize(serializer),
+            HasExtensions::AssetAdministrationShell(elem) => elem.serialize(serializer),
+            HasExtensions::Entity(elem) => TaggedElement::new("Entity", elem).serialize(serializer),
+            HasExtensions::Operation(elem) => TaggedElement::new("Operation", elem).serialize(serializer),
+            HasExtensions::Range(elem) => TaggedElement::new("Range", elem).serialize(serializer),
+            HasExtensions::Blob(elem) => TaggedElement::new("Blob", elem).serialize(serializer),
+            HasExtensions::File(elem) => TaggedElement::new("File", elem).serialize(serializer),
+            HasExtensions::SubmodelElementList(elem) => TaggedElement::new("SubmodelElementList", elem).serialize(serializer),
+            HasExtensions::Submodel(elem) => elem.serialize(serializer),
+            HasExtensions::ConceptDescription(elem) => elem.serialize(serializer)
+        }
+    }
 }
\ No newline at end of file
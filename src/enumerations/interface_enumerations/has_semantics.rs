@@ -1,5 +1,6 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 
+use crate::enumerations::interface_enumerations::submodel_element::TaggedElement;
 use crate::structs::annotated_relationship_element::AnnotatedRelationshipElement;
 use crate::structs::basic_event_element::BasicEventElement;
 use crate::structs::blob::Blob;
@@ -21,7 +22,7 @@ use crate::structs::submodel_element_collection::SubmodelElementCollection;
 use crate::structs::submodel_element_list::SubmodelElementList;
 use crate::traits::has_semantics::THasSemantics;
 
-#[derive(PartialEq, Clone, Serialize, Deserialize)]
+#[derive(PartialEq, Clone, Deserialize)]
 #[serde(tag = "modelType")]
 pub enum HasSemantics {
     Submodel(Submodel),
@@ -228,4 +229,29 @@ impl HasSemantics {
             HasSemantics::BasicEventElement(elem) => elem.remove_supplemental_semantic_id(index),
         }
     }
+}
+
+impl Serialize for HasSemantics {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            HasSemantics::Submodel(elem) => elem.serialize(serializer),
+            HasSemantics::Qualifier(elem) => TaggedElement::new("Qualifier", elem).serialize(serializer),
+            HasSemantics::Extension(elem) => TaggedElement::new("Extension", elem).serialize(serializer),
+            HasSemantics::SubmodelElementCollection(elem) => TaggedElement::new("SubmodelElementCollection", elem).serialize(serializer),
+            HasSemantics::RelationshipElement(elem) => TaggedElement::new("RelationshipElement", elem).serialize(serializer),
+            HasSemantics::ReferenceElement(elem) => TaggedElement::new("ReferenceElement", elem).serialize(serializer),
+            HasSemantics::Property(elem) => TaggedElement::new("Property", elem).serialize(serializer),
+            HasSemantics::MultiLanguageProperty(elem) => TaggedElement::new("MultiLanguageProperty", elem).serialize(serializer),
+            HasSemantics::AnnotatedRelationshipElement(elem) => TaggedElement::new("AnnotatedRelationshipElement", elem).serialize(serializer),
+            HasSemantics::Entity(elem) => TaggedElement::new("Entity", elem).serialize(serializer),
+            HasSemantics::Operation(elem) => TaggedElement::new("Operation", elem).serialize(serializer),
+            HasSemantics::Range(elem) => TaggedElement::new("Range", elem).serialize(serializer),
+            HasSemantics::Blob(elem) => TaggedElement::new("Blob", elem).serialize(serializer),
+            HasSemantics::File(elem) => TaggedElement::new("File", elem).serialize(serializer),
+            HasSemantics::SubmodelElementList(elem) => TaggedElement::new("SubmodelElementList", elem).serialize(serializer),
+            HasSemantics::SpecificAssetId(elem) => TaggedElement::new("SpecificAssetId", elem).serialize(serializer),
+            HasSemantics::Capability(elem) => TaggedElement::new("Capability", elem).serialize(serializer),
+            HasSemantics::BasicEventElement(elem) => TaggedElement::new("BasicEventElement", elem).serialize(serializer)
+        }
+    }
 }
\ No newline at end of file
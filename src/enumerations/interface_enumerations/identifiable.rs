@@ -1,4 +1,4 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 
 use crate::structs::administrative_information::AdministrativeInformation;
 use crate::structs::asset_administration_shell::AssetAdministrationShell;
@@ -6,7 +6,7 @@ use crate::structs::concept_description::ConceptDescription;
 use crate::structs::submodel::Submodel;
 use crate::traits::identifiable::TIdentifiable;
 
-#[derive(PartialEq, Clone, Serialize, Deserialize)]
+#[derive(PartialEq, Clone, Deserialize)]
 #[serde(tag = "modelType")]
 pub enum Identifiable {
     AssetAdministrationShell(AssetAdministrationShell),
@@ -62,4 +62,14 @@ impl Identifiable {
             Identifiable::ConceptDescription(elem) => elem.get_mut_id()
         }
     }
+}
+
+impl Serialize for Identifiable {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Identifiable::AssetAdministrationShell(elem) => elem.serialize(serializer),
+            Identifiable::Submodel(elem) => elem.serialize(serializer),
+            Identifiable::ConceptDescription(elem) => elem.serialize(serializer)
+        }
+    }
 }
\ No newline at end of file
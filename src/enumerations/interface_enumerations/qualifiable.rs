@@ -1,5 +1,6 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 
+use crate::enumerations::interface_enumerations::submodel_element::TaggedElement;
 use crate::structs::annotated_relationship_element::AnnotatedRelationshipElement;
 use crate::structs::basic_event_element::BasicEventElement;
 use crate::structs::blob::Blob;
@@ -18,7 +19,7 @@ use crate::structs::submodel_element_collection::SubmodelElementCollection;
 use crate::structs::submodel_element_list::SubmodelElementList;
 use crate::traits::qualifiable::TQualifiable;
 
-#[derive(PartialEq, Clone, Serialize, Deserialize)]
+#[derive(PartialEq, Clone, Deserialize)]
 #[serde(tag = "modelType")]
 pub enum Qualifiable {
     AnnotatedRelationshipElement(AnnotatedRelationshipElement),
@@ -138,4 +139,26 @@ impl Qualifiable {
             Qualifiable::SubmodelElementList(elem) => elem.remove_qualifier(index)
         }
     }
+}
+
+impl Serialize for Qualifiable {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Qualifiable::AnnotatedRelationshipElement(elem) => TaggedElement::new("AnnotatedRelationshipElement", elem).serialize(serializer),
+            Qualifiable::BasicEventElement(elem) => TaggedElement::new("BasicEventElement", elem).serialize(serializer),
+            Qualifiable::Blob(elem) => TaggedElement::new("Blob", elem).serialize(serializer),
+            Qualifiable::Capability(elem) => TaggedElement::new("Capability", elem).serialize(serializer),
+            Qualifiable::Entity(elem) => TaggedElement::new("Entity", elem).serialize(serializer),
+            Qualifiable::File(elem) => TaggedElement::new("File", elem).serialize(serializer),
+            Qualifiable::MultiLanguageProperty(elem) => TaggedElement::new("MultiLanguageProperty", elem).serialize(serializer),
+            Qualifiable::Operation(elem) => TaggedElement::new("Operation", elem).serialize(serializer),
+            Qualifiable::Property(elem) => TaggedElement::new("Property", elem).serialize(serializer),
+            Qualifiable::Range(elem) => TaggedElement::new("Range", elem).serialize(serializer),
+            Qualifiable::ReferenceElement(elem) => TaggedElement::new("ReferenceElement", elem).serialize(serializer),
+            Qualifiable::RelationshipElement(elem) => TaggedElement::new("RelationshipElement", elem).serialize(serializer),
+            Qualifiable::Submodel(elem) => elem.serialize(serializer),
+            Qualifiable::SubmodelElementCollection(elem) => TaggedElement::new("SubmodelElementCollection", elem).serialize(serializer),
+            Qualifiable::SubmodelElementList(elem) => TaggedElement::new("SubmodelElementList", elem).serialize(serializer)
+        }
+    }
 }
\ No newline at end of file
@@ -1,7 +1,7 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 
 use crate::enumerations::interface_enumerations::data_element::DataElement;
-use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::interface_enumerations::submodel_element::{SubmodelElement, TaggedElement};
 use crate::structs::annotated_relationship_element::AnnotatedRelationshipElement;
 use crate::structs::asset_administration_shell::AssetAdministrationShell;
 use crate::structs::basic_event_element::BasicEventElement;
@@ -23,7 +23,7 @@ use crate::structs::submodel_element_collection::SubmodelElementCollection;
 use crate::structs::submodel_element_list::SubmodelElementList;
 use crate::traits::referable::TReferable;
 
-#[derive(PartialEq, Clone, Serialize, Deserialize)]
+#[derive(PartialEq, Clone, Deserialize)]
 #[serde(tag = "modelType")]
 pub enum Referable {
     AnnotatedRelationshipElement(AnnotatedRelationshipElement),
@@ -864,4 +864,31 @@ impl Referable {
             Referable::SubmodelElementList(elem) => elem.get_mut_description()
         }
     }
+}
+
+impl Serialize for Referable {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Referable::AnnotatedRelationshipElement(elem) => TaggedElement::new("AnnotatedRelationshipElement", elem).serialize(serializer),
+            Referable::AssetAdministrationShell(elem) => elem.serialize(serializer),
+            Referable::BasicEventElement(elem) => TaggedElement::new("BasicEventElement", elem.as_ref()).serialize(serializer),
+            Referable::Blob(elem) => TaggedElement::new("Blob", elem).serialize(serializer),
+            Referable::Capability(elem) => TaggedElement::new("Capability", elem).serialize(serializer),
+            Referable::ConceptDescription(elem) => elem.serialize(serializer),
+            Referable::DataElement(elem) => elem.serialize(serializer),
+            Referable::Entity(elem) => TaggedElement::new("Entity", elem).serialize(serializer),
+            Referable::File(elem) => TaggedElement::new("File", elem).serialize(serializer),
+            Referable::MultiLanguageProperty(elem) => TaggedElement::new("MultiLanguageProperty", elem).serialize(serializer),
+            Referable::Operation(elem) => TaggedElement::new("Operation", elem).serialize(serializer),
+            Referable::Property(elem) => TaggedElement::new("Property", elem).serialize(serializer),
+            Referable::Range(elem) => TaggedElement::new("Range", elem).serialize(serializer),
+            Referable::Referable(elem) => elem.serialize(serializer),
+            Referable::ReferenceElement(elem) => TaggedElement::new("ReferenceElement", elem).serialize(serializer),
+            Referable::RelationshipElement(elem) => TaggedElement::new("RelationshipElement", elem).serialize(serializer),
+            Referable::Submodel(elem) => elem.serialize(serializer),
+            Referable::SubmodelElement(elem) => elem.serialize(serializer),
+            Referable::SubmodelElementCollection(elem) => TaggedElement::new("SubmodelElementCollection", elem).serialize(serializer),
+            Referable::SubmodelElementList(elem) => TaggedElement::new("SubmodelElementList", elem).serialize(serializer)
+        }
+    }
 }
\ No newline at end of file
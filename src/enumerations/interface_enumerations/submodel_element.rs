@@ -1,21 +1,36 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::{Map, Number, Value};
 
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::data_element::{set_data_element_value_only, DataElement};
+use crate::enumerations::key_type::KeyType;
 use crate::structs::annotated_relationship_element::AnnotatedRelationshipElement;
 use crate::structs::basic_event_element::BasicEventElement;
-use crate::structs::blob::Blob;
+use crate::structs::blob::{decode_base64, encode_base64, Blob};
 use crate::structs::capability::Capability;
+use crate::structs::embedded_data_specification::EmbeddedDataSpecification;
 use crate::structs::entity::Entity;
 use crate::structs::file::File;
 use crate::structs::multi_language_property::MultiLanguageProperty;
+use crate::structs::multi_language_text_type::MultiLanguageTextType;
 use crate::structs::operation::Operation;
 use crate::structs::property::Property;
+use crate::structs::qualifier::Qualifier;
 use crate::structs::range::Range;
+use crate::structs::reference::Reference;
 use crate::structs::reference_element::ReferenceElement;
 use crate::structs::relationship_element::RelationshipElement;
 use crate::structs::submodel_element_collection::SubmodelElementCollection;
 use crate::structs::submodel_element_list::SubmodelElementList;
+use crate::traits::has_data_specification::THasDataSpecification;
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::qualifiable::TQualifiable;
+use crate::traits::referable::TReferable;
+use crate::traits::relationship_element::TRelationshipElement;
+use crate::traits::submodel_element::TSubmodelElement;
 
-#[derive(PartialEq, Clone, Serialize, Deserialize)]
+#[derive(PartialEq, Clone, Deserialize)]
 #[serde(tag = "modelType")]
 pub enum SubmodelElement {
     RelationshipElement(RelationshipElement),
@@ -35,5 +50,863 @@ pub enum SubmodelElement {
 }
 
 impl SubmodelElement {
+    ///Sets the short ID of the element, which identifies the element within its parent.
+    ///
+    /// [id_short]: short ID of the element
+    pub fn set_id_short(&mut self, id_short: String) {
+        match self {
+            SubmodelElement::RelationshipElement(elem) => elem.set_id_short(id_short),
+            SubmodelElement::AnnotatedRelationshipElement(elem) => elem.set_id_short(id_short),
+            SubmodelElement::Property(elem) => elem.set_id_short(id_short),
+            SubmodelElement::MultiLanguageProperty(elem) => elem.set_id_short(id_short),
+            SubmodelElement::Range(elem) => elem.set_id_short(id_short),
+            SubmodelElement::Blob(elem) => elem.set_id_short(id_short),
+            SubmodelElement::File(elem) => elem.set_id_short(id_short),
+            SubmodelElement::ReferenceElement(elem) => elem.set_id_short(id_short),
+            SubmodelElement::Capability(elem) => elem.set_id_short(id_short),
+            SubmodelElement::SubmodelElementList(elem) => elem.set_id_short(id_short),
+            SubmodelElement::SubmodelElementCollection(elem) => elem.set_id_short(id_short),
+            SubmodelElement::Entity(elem) => elem.set_id_short(id_short),
+            SubmodelElement::BasicEventElement(elem) => elem.set_id_short(id_short),
+            SubmodelElement::Operation(elem) => elem.set_id_short(id_short)
+        }
+    }
 
+    ///Returns the short ID of the element, which identifies the element within its parent.
+    pub fn get_id_short(&self) -> Option<&String> {
+        match self {
+            SubmodelElement::RelationshipElement(elem) => elem.get_id_short(),
+            SubmodelElement::AnnotatedRelationshipElement(elem) => elem.get_id_short(),
+            SubmodelElement::Property(elem) => elem.get_id_short(),
+            SubmodelElement::MultiLanguageProperty(elem) => elem.get_id_short(),
+            SubmodelElement::Range(elem) => elem.get_id_short(),
+            SubmodelElement::Blob(elem) => elem.get_id_short(),
+            SubmodelElement::File(elem) => elem.get_id_short(),
+            SubmodelElement::ReferenceElement(elem) => elem.get_id_short(),
+            SubmodelElement::Capability(elem) => elem.get_id_short(),
+            SubmodelElement::SubmodelElementList(elem) => elem.get_id_short(),
+            SubmodelElement::SubmodelElementCollection(elem) => elem.get_id_short(),
+            SubmodelElement::Entity(elem) => elem.get_id_short(),
+            SubmodelElement::BasicEventElement(elem) => elem.get_id_short(),
+            SubmodelElement::Operation(elem) => elem.get_id_short()
+        }
+    }
+
+    ///Returns the mutable short ID of the element, which identifies the element within its parent.
+    pub fn get_mut_id_short(&mut self) -> Option<&mut String> {
+        match self {
+            SubmodelElement::RelationshipElement(elem) => elem.get_mut_id_short(),
+            SubmodelElement::AnnotatedRelationshipElement(elem) => elem.get_mut_id_short(),
+            SubmodelElement::Property(elem) => elem.get_mut_id_short(),
+            SubmodelElement::MultiLanguageProperty(elem) => elem.get_mut_id_short(),
+            SubmodelElement::Range(elem) => elem.get_mut_id_short(),
+            SubmodelElement::Blob(elem) => elem.get_mut_id_short(),
+            SubmodelElement::File(elem) => elem.get_mut_id_short(),
+            SubmodelElement::ReferenceElement(elem) => elem.get_mut_id_short(),
+            SubmodelElement::Capability(elem) => elem.get_mut_id_short(),
+            SubmodelElement::SubmodelElementList(elem) => elem.get_mut_id_short(),
+            SubmodelElement::SubmodelElementCollection(elem) => elem.get_mut_id_short(),
+            SubmodelElement::Entity(elem) => elem.get_mut_id_short(),
+            SubmodelElement::BasicEventElement(elem) => elem.get_mut_id_short(),
+            SubmodelElement::Operation(elem) => elem.get_mut_id_short()
+        }
+    }
+
+    ///Returns the description of the element in several languages.
+    pub fn get_description(&self) -> &Vec<MultiLanguageTextType> {
+        match self {
+            SubmodelElement::RelationshipElement(elem) => elem.get_description(),
+            SubmodelElement::AnnotatedRelationshipElement(elem) => elem.get_description(),
+            SubmodelElement::Property(elem) => elem.get_description(),
+            SubmodelElement::MultiLanguageProperty(elem) => elem.get_description(),
+            SubmodelElement::Range(elem) => elem.get_description(),
+            SubmodelElement::Blob(elem) => elem.get_description(),
+            SubmodelElement::File(elem) => elem.get_description(),
+            SubmodelElement::ReferenceElement(elem) => elem.get_description(),
+            SubmodelElement::Capability(elem) => elem.get_description(),
+            SubmodelElement::SubmodelElementList(elem) => elem.get_description(),
+            SubmodelElement::SubmodelElementCollection(elem) => elem.get_description(),
+            SubmodelElement::Entity(elem) => elem.get_description(),
+            SubmodelElement::BasicEventElement(elem) => elem.get_description(),
+            SubmodelElement::Operation(elem) => elem.get_description()
+        }
+    }
+
+    ///Sets the identifier of the semantic definition of the element called semantic ID.
+    ///
+    /// [semantic_id]: identifier of the semantic definition
+    pub fn set_semantic_id(&mut self, semantic_id: Reference) {
+        match self {
+            SubmodelElement::RelationshipElement(elem) => elem.set_semantic_id(semantic_id),
+            SubmodelElement::AnnotatedRelationshipElement(elem) => elem.set_semantic_id(semantic_id),
+            SubmodelElement::Property(elem) => elem.set_semantic_id(semantic_id),
+            SubmodelElement::MultiLanguageProperty(elem) => elem.set_semantic_id(semantic_id),
+            SubmodelElement::Range(elem) => elem.set_semantic_id(semantic_id),
+            SubmodelElement::Blob(elem) => elem.set_semantic_id(semantic_id),
+            SubmodelElement::File(elem) => elem.set_semantic_id(semantic_id),
+            SubmodelElement::ReferenceElement(elem) => elem.set_semantic_id(semantic_id),
+            SubmodelElement::Capability(elem) => elem.set_semantic_id(semantic_id),
+            SubmodelElement::SubmodelElementList(elem) => elem.set_semantic_id(semantic_id),
+            SubmodelElement::SubmodelElementCollection(elem) => elem.set_semantic_id(semantic_id),
+            SubmodelElement::Entity(elem) => elem.set_semantic_id(semantic_id),
+            SubmodelElement::BasicEventElement(elem) => elem.set_semantic_id(semantic_id),
+            SubmodelElement::Operation(elem) => elem.set_semantic_id(semantic_id)
+        }
+    }
+
+    ///Returns the identifier of the semantic definition of the element called semantic ID.
+    pub fn get_semantic_id(&self) -> Option<&Reference> {
+        match self {
+            SubmodelElement::RelationshipElement(elem) => elem.get_semantic_id(),
+            SubmodelElement::AnnotatedRelationshipElement(elem) => elem.get_semantic_id(),
+            SubmodelElement::Property(elem) => elem.get_semantic_id(),
+            SubmodelElement::MultiLanguageProperty(elem) => elem.get_semantic_id(),
+            SubmodelElement::Range(elem) => elem.get_semantic_id(),
+            SubmodelElement::Blob(elem) => elem.get_semantic_id(),
+            SubmodelElement::File(elem) => elem.get_semantic_id(),
+            SubmodelElement::ReferenceElement(elem) => elem.get_semantic_id(),
+            SubmodelElement::Capability(elem) => elem.get_semantic_id(),
+            SubmodelElement::SubmodelElementList(elem) => elem.get_semantic_id(),
+            SubmodelElement::SubmodelElementCollection(elem) => elem.get_semantic_id(),
+            SubmodelElement::Entity(elem) => elem.get_semantic_id(),
+            SubmodelElement::BasicEventElement(elem) => elem.get_semantic_id(),
+            SubmodelElement::Operation(elem) => elem.get_semantic_id()
+        }
+    }
+
+    ///Returns the identifiers of supplemental semantic definitions of the element.
+    pub fn get_supplemental_semantic_ids(&self) -> &Vec<Reference> {
+        match self {
+            SubmodelElement::RelationshipElement(elem) => elem.get_supplemental_semantic_ids(),
+            SubmodelElement::AnnotatedRelationshipElement(elem) => elem.get_supplemental_semantic_ids(),
+            SubmodelElement::Property(elem) => elem.get_supplemental_semantic_ids(),
+            SubmodelElement::MultiLanguageProperty(elem) => elem.get_supplemental_semantic_ids(),
+            SubmodelElement::Range(elem) => elem.get_supplemental_semantic_ids(),
+            SubmodelElement::Blob(elem) => elem.get_supplemental_semantic_ids(),
+            SubmodelElement::File(elem) => elem.get_supplemental_semantic_ids(),
+            SubmodelElement::ReferenceElement(elem) => elem.get_supplemental_semantic_ids(),
+            SubmodelElement::Capability(elem) => elem.get_supplemental_semantic_ids(),
+            SubmodelElement::SubmodelElementList(elem) => elem.get_supplemental_semantic_ids(),
+            SubmodelElement::SubmodelElementCollection(elem) => elem.get_supplemental_semantic_ids(),
+            SubmodelElement::Entity(elem) => elem.get_supplemental_semantic_ids(),
+            SubmodelElement::BasicEventElement(elem) => elem.get_supplemental_semantic_ids(),
+            SubmodelElement::Operation(elem) => elem.get_supplemental_semantic_ids()
+        }
+    }
+
+    ///Returns the mutable identifiers of supplemental semantic definitions of the element.
+    pub fn get_mut_supplemental_semantic_ids(&mut self) -> &mut Vec<Reference> {
+        match self {
+            SubmodelElement::RelationshipElement(elem) => elem.get_mut_supplemental_semantic_ids(),
+            SubmodelElement::AnnotatedRelationshipElement(elem) => elem.get_mut_supplemental_semantic_ids(),
+            SubmodelElement::Property(elem) => elem.get_mut_supplemental_semantic_ids(),
+            SubmodelElement::MultiLanguageProperty(elem) => elem.get_mut_supplemental_semantic_ids(),
+            SubmodelElement::Range(elem) => elem.get_mut_supplemental_semantic_ids(),
+            SubmodelElement::Blob(elem) => elem.get_mut_supplemental_semantic_ids(),
+            SubmodelElement::File(elem) => elem.get_mut_supplemental_semantic_ids(),
+            SubmodelElement::ReferenceElement(elem) => elem.get_mut_supplemental_semantic_ids(),
+            SubmodelElement::Capability(elem) => elem.get_mut_supplemental_semantic_ids(),
+            SubmodelElement::SubmodelElementList(elem) => elem.get_mut_supplemental_semantic_ids(),
+            SubmodelElement::SubmodelElementCollection(elem) => elem.get_mut_supplemental_semantic_ids(),
+            SubmodelElement::Entity(elem) => elem.get_mut_supplemental_semantic_ids(),
+            SubmodelElement::BasicEventElement(elem) => elem.get_mut_supplemental_semantic_ids(),
+            SubmodelElement::Operation(elem) => elem.get_mut_supplemental_semantic_ids()
+        }
+    }
+
+    ///Returns the external references to the data specification templates used by the element.
+    pub fn get_data_specifications(&self) -> &Vec<EmbeddedDataSpecification> {
+        match self {
+            SubmodelElement::RelationshipElement(elem) => elem.get_data_specifications(),
+            SubmodelElement::AnnotatedRelationshipElement(elem) => elem.get_data_specifications(),
+            SubmodelElement::Property(elem) => elem.get_data_specifications(),
+            SubmodelElement::MultiLanguageProperty(elem) => elem.get_data_specifications(),
+            SubmodelElement::Range(elem) => elem.get_data_specifications(),
+            SubmodelElement::Blob(elem) => elem.get_data_specifications(),
+            SubmodelElement::File(elem) => elem.get_data_specifications(),
+            SubmodelElement::ReferenceElement(elem) => elem.get_data_specifications(),
+            SubmodelElement::Capability(elem) => elem.get_data_specifications(),
+            SubmodelElement::SubmodelElementList(elem) => elem.get_data_specifications(),
+            SubmodelElement::SubmodelElementCollection(elem) => elem.get_data_specifications(),
+            SubmodelElement::Entity(elem) => elem.get_data_specifications(),
+            SubmodelElement::BasicEventElement(elem) => elem.get_data_specifications(),
+            SubmodelElement::Operation(elem) => elem.get_data_specifications()
+        }
+    }
+
+    ///Returns the mutable external references to the data specification templates used by the
+    /// element.
+    pub fn get_mut_data_specifications(&mut self) -> &mut Vec<EmbeddedDataSpecification> {
+        match self {
+            SubmodelElement::RelationshipElement(elem) => elem.get_mut_data_specifications(),
+            SubmodelElement::AnnotatedRelationshipElement(elem) => elem.get_mut_data_specifications(),
+            SubmodelElement::Property(elem) => elem.get_mut_data_specifications(),
+            SubmodelElement::MultiLanguageProperty(elem) => elem.get_mut_data_specifications(),
+            SubmodelElement::Range(elem) => elem.get_mut_data_specifications(),
+            SubmodelElement::Blob(elem) => elem.get_mut_data_specifications(),
+            SubmodelElement::File(elem) => elem.get_mut_data_specifications(),
+            SubmodelElement::ReferenceElement(elem) => elem.get_mut_data_specifications(),
+            SubmodelElement::Capability(elem) => elem.get_mut_data_specifications(),
+            SubmodelElement::SubmodelElementList(elem) => elem.get_mut_data_specifications(),
+            SubmodelElement::SubmodelElementCollection(elem) => elem.get_mut_data_specifications(),
+            SubmodelElement::Entity(elem) => elem.get_mut_data_specifications(),
+            SubmodelElement::BasicEventElement(elem) => elem.get_mut_data_specifications(),
+            SubmodelElement::Operation(elem) => elem.get_mut_data_specifications()
+        }
+    }
+
+    ///Returns the key type denoting the type of the element in a model reference.
+    pub fn get_key_type(&self) -> KeyType {
+        match self {
+            SubmodelElement::RelationshipElement(_) => KeyType::RelationshipElement,
+            SubmodelElement::AnnotatedRelationshipElement(_) => KeyType::AnnotatedRelationshipElement,
+            SubmodelElement::Property(_) => KeyType::Property,
+            SubmodelElement::MultiLanguageProperty(_) => KeyType::MultiLanguageProperty,
+            SubmodelElement::Range(_) => KeyType::Range,
+            SubmodelElement::Blob(_) => KeyType::Blob,
+            SubmodelElement::File(_) => KeyType::File,
+            SubmodelElement::ReferenceElement(_) => KeyType::ReferenceElement,
+            SubmodelElement::Capability(_) => KeyType::Capability,
+            SubmodelElement::SubmodelElementList(_) => KeyType::SubmodelElementList,
+            SubmodelElement::SubmodelElementCollection(_) => KeyType::SubmodelElementCollection,
+            SubmodelElement::Entity(_) => KeyType::Entity,
+            SubmodelElement::BasicEventElement(_) => KeyType::BasicEventElement,
+            SubmodelElement::Operation(_) => KeyType::Operation
+        }
+    }
+
+    ///Returns the submodel elements directly contained in the element. Only submodel element
+    /// collections, submodel element lists and entities contain further submodel elements.
+    pub fn get_children(&self) -> Option<&Vec<SubmodelElement>> {
+        match self {
+            SubmodelElement::SubmodelElementList(elem) => Some(elem.get_value()),
+            SubmodelElement::SubmodelElementCollection(elem) => Some(elem.get_value()),
+            SubmodelElement::Entity(elem) => Some(elem.get_statements()),
+            _ => None
+        }
+    }
+
+    ///Returns the mutable submodel elements directly contained in the element. Only submodel
+    /// element collections, submodel element lists and entities contain further submodel elements.
+    pub fn get_mut_children(&mut self) -> Option<&mut Vec<SubmodelElement>> {
+        match self {
+            SubmodelElement::SubmodelElementList(elem) => Some(elem.get_mut_value()),
+            SubmodelElement::SubmodelElementCollection(elem) => Some(elem.get_mut_value()),
+            SubmodelElement::Entity(elem) => Some(elem.get_mut_statements()),
+            _ => None
+        }
+    }
+
+    ///Returns all references contained in the element and in the elements nested in it.
+    pub fn get_references(&self) -> Vec<&Reference> {
+        match self {
+            SubmodelElement::RelationshipElement(elem) => {
+                let mut references = get_common_references(elem);
+                references.extend(elem.get_first().into_iter().chain(elem.get_second())
+                    .flat_map(|reference| reference.get_references()));
+                references
+            },
+            SubmodelElement::AnnotatedRelationshipElement(elem) => {
+                let mut references = get_common_references(elem);
+                references.extend(elem.get_first().into_iter().chain(elem.get_second())
+                    .flat_map(|reference| reference.get_references()));
+                references.extend(elem.get_data_elements().iter()
+                    .flat_map(|annotation| annotation.get_references()));
+                references
+            },
+            SubmodelElement::Property(elem) => {
+                let mut references = get_common_references(elem);
+                references.extend(elem.get_value_id().into_iter().flat_map(|reference| reference.get_references()));
+                references
+            },
+            SubmodelElement::MultiLanguageProperty(elem) => {
+                let mut references = get_common_references(elem);
+                references.extend(elem.get_value_id().into_iter().flat_map(|reference| reference.get_references()));
+                references
+            },
+            SubmodelElement::Range(elem) => get_common_references(elem),
+            SubmodelElement::Blob(elem) => get_common_references(elem),
+            SubmodelElement::File(elem) => get_common_references(elem),
+            SubmodelElement::ReferenceElement(elem) => {
+                let mut references = get_common_references(elem);
+                references.extend(elem.get_value().into_iter().flat_map(|reference| reference.get_references()));
+                references
+            },
+            SubmodelElement::Capability(elem) => get_common_references(elem),
+            SubmodelElement::SubmodelElementList(elem) => {
+                let mut references = get_common_references(elem);
+                references.extend(elem.get_semantic_id_list_element().into_iter()
+                    .flat_map(|reference| reference.get_references()));
+                references.extend(elem.get_value().iter().flat_map(|child| child.get_references()));
+                references
+            },
+            SubmodelElement::SubmodelElementCollection(elem) => {
+                let mut references = get_common_references(elem);
+                references.extend(elem.get_value().iter().flat_map(|child| child.get_references()));
+                references
+            },
+            SubmodelElement::Entity(elem) => {
+                let mut references = get_common_references(elem);
+                references.extend(elem.get_specific_asset_ids().iter()
+                    .flat_map(|specific_asset_id| specific_asset_id.get_references()));
+                references.extend(elem.get_statements().iter().flat_map(|statement| statement.get_references()));
+                references
+            },
+            SubmodelElement::BasicEventElement(elem) => {
+                let mut references = get_common_references(elem);
+                references.extend(elem.get_observed().get_references());
+                references
+            },
+            SubmodelElement::Operation(elem) => {
+                let mut references = get_common_references(elem);
+                references.extend(elem.get_input_variables().iter()
+                    .chain(elem.get_output_variables())
+                    .chain(elem.get_inoutput_variables())
+                    .flat_map(|variable| variable.get_value().get_references()));
+                references
+            }
+        }
+    }
+
+    ///Calls the visitor for every reference contained in the element and in the elements nested
+    /// in it.
+    ///
+    /// [visitor]: function called for every reference
+    pub fn visit_references_mut(&mut self, visitor: &mut dyn FnMut(&mut Reference)) {
+        match self {
+            SubmodelElement::RelationshipElement(elem) => {
+                visit_common_references_mut(elem, visitor);
+                elem.get_mut_first().into_iter().for_each(|reference| reference.visit_references_mut(visitor));
+                elem.get_mut_second().into_iter().for_each(|reference| reference.visit_references_mut(visitor));
+            },
+            SubmodelElement::AnnotatedRelationshipElement(elem) => {
+                visit_common_references_mut(elem, visitor);
+                elem.get_mut_first().into_iter().for_each(|reference| reference.visit_references_mut(visitor));
+                elem.get_mut_second().into_iter().for_each(|reference| reference.visit_references_mut(visitor));
+                elem.get_mut_data_elements().iter_mut()
+                    .for_each(|annotation| annotation.visit_references_mut(visitor));
+            },
+            SubmodelElement::Property(elem) => {
+                visit_common_references_mut(elem, visitor);
+                elem.get_mut_value_id().into_iter().for_each(|reference| reference.visit_references_mut(visitor));
+            },
+            SubmodelElement::MultiLanguageProperty(elem) => {
+                visit_common_references_mut(elem, visitor);
+                elem.get_mut_value_id().into_iter().for_each(|reference| reference.visit_references_mut(visitor));
+            },
+            SubmodelElement::Range(elem) => visit_common_references_mut(elem, visitor),
+            SubmodelElement::Blob(elem) => visit_common_references_mut(elem, visitor),
+            SubmodelElement::File(elem) => visit_common_references_mut(elem, visitor),
+            SubmodelElement::ReferenceElement(elem) => {
+                visit_common_references_mut(elem, visitor);
+                elem.get_mut_value().into_iter().for_each(|reference| reference.visit_references_mut(visitor));
+            },
+            SubmodelElement::Capability(elem) => visit_common_references_mut(elem, visitor),
+            SubmodelElement::SubmodelElementList(elem) => {
+                visit_common_references_mut(elem, visitor);
+                elem.get_mut_semantic_id_list_element().into_iter()
+                    .for_each(|reference| reference.visit_references_mut(visitor));
+                elem.get_mut_value().iter_mut().for_each(|child| child.visit_references_mut(visitor));
+            },
+            SubmodelElement::SubmodelElementCollection(elem) => {
+                visit_common_references_mut(elem, visitor);
+                elem.get_mut_value().iter_mut().for_each(|child| child.visit_references_mut(visitor));
+            },
+            SubmodelElement::Entity(elem) => {
+                visit_common_references_mut(elem, visitor);
+                elem.get_mut_specific_asset_ids().iter_mut()
+                    .for_each(|specific_asset_id| specific_asset_id.visit_references_mut(visitor));
+                elem.get_mut_statements().iter_mut().for_each(|statement| statement.visit_references_mut(visitor));
+            },
+            SubmodelElement::BasicEventElement(elem) => {
+                visit_common_references_mut(elem, visitor);
+                elem.get_mut_observed().visit_references_mut(visitor);
+            },
+            SubmodelElement::Operation(elem) => {
+                visit_common_references_mut(elem, visitor);
+                elem.get_mut_input_variables().iter_mut()
+                    .for_each(|variable| variable.get_mut_value().visit_references_mut(visitor));
+                elem.get_mut_output_variables().iter_mut()
+                    .for_each(|variable| variable.get_mut_value().visit_references_mut(visitor));
+                elem.get_mut_inoutput_variables().iter_mut()
+                    .for_each(|variable| variable.get_mut_value().visit_references_mut(visitor));
+            }
+        }
+    }
+
+    ///Returns the value of the element in the value-only serialization of the HTTP API, e.g. a
+    /// number for a property of type `xs:int` or `{"min": 1, "max": 5}` for a range. Returns None
+    /// for capabilities, operations and properties without value.
+    ///
+    /// [with_blob_value]: whether the values of blobs are included as base64 text
+    pub fn get_value_only(&self, with_blob_value: bool) -> Option<Value> {
+        match self {
+            SubmodelElement::RelationshipElement(elem) =>
+                Some(relationship_value_only(elem.get_first(), elem.get_second(), &[], with_blob_value)),
+            SubmodelElement::AnnotatedRelationshipElement(elem) =>
+                Some(relationship_value_only(elem.get_first(), elem.get_second(), elem.get_data_elements(), with_blob_value)),
+            SubmodelElement::Property(elem) => property_value_only(elem),
+            SubmodelElement::MultiLanguageProperty(elem) => Some(multi_language_value_only(elem)),
+            SubmodelElement::Range(elem) => Some(range_value_only(elem)),
+            SubmodelElement::Blob(elem) => Some(blob_value_only(elem, with_blob_value)),
+            SubmodelElement::File(elem) => Some(file_value_only(elem)),
+            SubmodelElement::ReferenceElement(elem) => Some(elem.get_value().and_then(|reference| serde_json::to_value(reference).ok())
+                .unwrap_or(Value::Null)),
+            SubmodelElement::Capability(_) | SubmodelElement::Operation(_) => None,
+            SubmodelElement::SubmodelElementList(elem) => Some(Value::Array(elem.get_value().iter()
+                .map(|child| child.get_value_only(with_blob_value).unwrap_or(Value::Null))
+                .collect())),
+            SubmodelElement::SubmodelElementCollection(elem) => Some(elements_value_only(elem.get_value(), with_blob_value)),
+            SubmodelElement::Entity(elem) => {
+                let mut value = Map::new();
+                value.insert("statements".to_string(), elements_value_only(elem.get_statements(), with_blob_value));
+                if let Some(entity_type) = elem.get_entity_type().and_then(|entity_type| serde_json::to_value(entity_type).ok()) {
+                    value.insert("entityType".to_string(), entity_type);
+                }
+                if let Some(global_asset_id) = elem.get_global_asset_id() {
+                    value.insert("globalAssetId".to_string(), Value::String(global_asset_id.clone()));
+                }
+                if !elem.get_specific_asset_ids().is_empty() {
+                    value.insert("specificAssetIds".to_string(), serde_json::to_value(elem.get_specific_asset_ids()).unwrap_or_default());
+                }
+                Some(Value::Object(value))
+            },
+            SubmodelElement::BasicEventElement(elem) => {
+                let mut value = Map::new();
+                value.insert("observed".to_string(), serde_json::to_value(elem.get_observed()).unwrap_or_default());
+                Some(Value::Object(value))
+            }
+        }
+    }
+
+    ///Sets the value of the element from its value-only serialization of the HTTP API. Elements
+    /// nested in collections, lists and entities are updated as far as they are contained in the
+    /// value; the structure of the element is not changed. Values of properties and ranges must be
+    /// valid for their data type.
+    ///
+    /// [value]: value-only serialization of the value
+    pub fn set_value_only(&mut self, value: &Value) -> Result<(), ConversionError> {
+        let path = self.get_id_short().cloned().unwrap_or_default();
+        set_element_value_only(self, value, &path)
+    }
+}
+
+///Returns the references every submodel element has: semantic IDs, qualifiers, embedded data
+/// specifications and extensions.
+pub(crate) fn get_common_references<T: TSubmodelElement>(elem: &T) -> Vec<&Reference> {
+    elem.get_semantic_id().into_iter()
+        .chain(elem.get_supplemental_semantic_ids())
+        .flat_map(|reference| reference.get_references())
+        .chain(elem.get_data_specifications().iter().flat_map(|data_specification| data_specification.get_references()))
+        .chain(elem.get_qualifiers().iter().flat_map(|qualifier| qualifier.get_references()))
+        .chain(elem.get_extensions().iter().flat_map(|extension| extension.get_references()))
+        .collect()
+}
+
+///Calls the visitor for the references every submodel element has: semantic IDs, qualifiers,
+/// embedded data specifications and extensions.
+pub(crate) fn visit_common_references_mut<T: TSubmodelElement>(elem: &mut T, visitor: &mut dyn FnMut(&mut Reference)) {
+    elem.get_mut_semantic_id().into_iter().for_each(|reference| reference.visit_references_mut(visitor));
+    elem.get_mut_supplemental_semantic_ids().iter_mut().for_each(|reference| reference.visit_references_mut(visitor));
+    elem.get_mut_data_specifications().iter_mut()
+        .for_each(|data_specification| data_specification.visit_references_mut(visitor));
+    elem.get_mut_qualifiers().iter_mut().for_each(|qualifier| qualifier.visit_references_mut(visitor));
+    elem.get_mut_extensions().iter_mut().for_each(|extension| extension.visit_references_mut(visitor));
+}
+
+impl TQualifiable for SubmodelElement {
+    fn set_qualifiers(&mut self, qualifiers: Vec<Qualifier>) {
+        match self {
+            SubmodelElement::RelationshipElement(elem) => elem.set_qualifiers(qualifiers),
+            SubmodelElement::AnnotatedRelationshipElement(elem) => elem.set_qualifiers(qualifiers),
+            SubmodelElement::Property(elem) => elem.set_qualifiers(qualifiers),
+            SubmodelElement::MultiLanguageProperty(elem) => elem.set_qualifiers(qualifiers),
+            SubmodelElement::Range(elem) => elem.set_qualifiers(qualifiers),
+            SubmodelElement::Blob(elem) => elem.set_qualifiers(qualifiers),
+            SubmodelElement::File(elem) => elem.set_qualifiers(qualifiers),
+            SubmodelElement::ReferenceElement(elem) => elem.set_qualifiers(qualifiers),
+            SubmodelElement::Capability(elem) => elem.set_qualifiers(qualifiers),
+            SubmodelElement::SubmodelElementList(elem) => elem.set_qualifiers(qualifiers),
+            SubmodelElement::SubmodelElementCollection(elem) => elem.set_qualifiers(qualifiers),
+            SubmodelElement::Entity(elem) => elem.set_qualifiers(qualifiers),
+            SubmodelElement::BasicEventElement(elem) => elem.set_qualifiers(qualifiers),
+            SubmodelElement::Operation(elem) => elem.set_qualifiers(qualifiers)
+        }
+    }
+
+    fn get_qualifiers(&self) -> &Vec<Qualifier> {
+        match self {
+            SubmodelElement::RelationshipElement(elem) => elem.get_qualifiers(),
+            SubmodelElement::AnnotatedRelationshipElement(elem) => elem.get_qualifiers(),
+            SubmodelElement::Property(elem) => elem.get_qualifiers(),
+            SubmodelElement::MultiLanguageProperty(elem) => elem.get_qualifiers(),
+            SubmodelElement::Range(elem) => elem.get_qualifiers(),
+            SubmodelElement::Blob(elem) => elem.get_qualifiers(),
+            SubmodelElement::File(elem) => elem.get_qualifiers(),
+            SubmodelElement::ReferenceElement(elem) => elem.get_qualifiers(),
+            SubmodelElement::Capability(elem) => elem.get_qualifiers(),
+            SubmodelElement::SubmodelElementList(elem) => elem.get_qualifiers(),
+            SubmodelElement::SubmodelElementCollection(elem) => elem.get_qualifiers(),
+            SubmodelElement::Entity(elem) => elem.get_qualifiers(),
+            SubmodelElement::BasicEventElement(elem) => elem.get_qualifiers(),
+            SubmodelElement::Operation(elem) => elem.get_qualifiers()
+        }
+    }
+
+    fn get_mut_qualifiers(&mut self) -> &mut Vec<Qualifier> {
+        match self {
+            SubmodelElement::RelationshipElement(elem) => elem.get_mut_qualifiers(),
+            SubmodelElement::AnnotatedRelationshipElement(elem) => elem.get_mut_qualifiers(),
+            SubmodelElement::Property(elem) => elem.get_mut_qualifiers(),
+            SubmodelElement::MultiLanguageProperty(elem) => elem.get_mut_qualifiers(),
+            SubmodelElement::Range(elem) => elem.get_mut_qualifiers(),
+            SubmodelElement::Blob(elem) => elem.get_mut_qualifiers(),
+            SubmodelElement::File(elem) => elem.get_mut_qualifiers(),
+            SubmodelElement::ReferenceElement(elem) => elem.get_mut_qualifiers(),
+            SubmodelElement::Capability(elem) => elem.get_mut_qualifiers(),
+            SubmodelElement::SubmodelElementList(elem) => elem.get_mut_qualifiers(),
+            SubmodelElement::SubmodelElementCollection(elem) => elem.get_mut_qualifiers(),
+            SubmodelElement::Entity(elem) => elem.get_mut_qualifiers(),
+            SubmodelElement::BasicEventElement(elem) => elem.get_mut_qualifiers(),
+            SubmodelElement::Operation(elem) => elem.get_mut_qualifiers()
+        }
+    }
+
+    fn add_qualifier(&mut self, qualifier: Qualifier) {
+        match self {
+            SubmodelElement::RelationshipElement(elem) => elem.add_qualifier(qualifier),
+            SubmodelElement::AnnotatedRelationshipElement(elem) => elem.add_qualifier(qualifier),
+            SubmodelElement::Property(elem) => elem.add_qualifier(qualifier),
+            SubmodelElement::MultiLanguageProperty(elem) => elem.add_qualifier(qualifier),
+            SubmodelElement::Range(elem) => elem.add_qualifier(qualifier),
+            SubmodelElement::Blob(elem) => elem.add_qualifier(qualifier),
+            SubmodelElement::File(elem) => elem.add_qualifier(qualifier),
+            SubmodelElement::ReferenceElement(elem) => elem.add_qualifier(qualifier),
+            SubmodelElement::Capability(elem) => elem.add_qualifier(qualifier),
+            SubmodelElement::SubmodelElementList(elem) => elem.add_qualifier(qualifier),
+            SubmodelElement::SubmodelElementCollection(elem) => elem.add_qualifier(qualifier),
+            SubmodelElement::Entity(elem) => elem.add_qualifier(qualifier),
+            SubmodelElement::BasicEventElement(elem) => elem.add_qualifier(qualifier),
+            SubmodelElement::Operation(elem) => elem.add_qualifier(qualifier)
+        }
+    }
+
+    fn remove_qualifier(&mut self, index: usize) -> Qualifier {
+        match self {
+            SubmodelElement::RelationshipElement(elem) => elem.remove_qualifier(index),
+            SubmodelElement::AnnotatedRelationshipElement(elem) => elem.remove_qualifier(index),
+            SubmodelElement::Property(elem) => elem.remove_qualifier(index),
+            SubmodelElement::MultiLanguageProperty(elem) => elem.remove_qualifier(index),
+            SubmodelElement::Range(elem) => elem.remove_qualifier(index),
+            SubmodelElement::Blob(elem) => elem.remove_qualifier(index),
+            SubmodelElement::File(elem) => elem.remove_qualifier(index),
+            SubmodelElement::ReferenceElement(elem) => elem.remove_qualifier(index),
+            SubmodelElement::Capability(elem) => elem.remove_qualifier(index),
+            SubmodelElement::SubmodelElementList(elem) => elem.remove_qualifier(index),
+            SubmodelElement::SubmodelElementCollection(elem) => elem.remove_qualifier(index),
+            SubmodelElement::Entity(elem) => elem.remove_qualifier(index),
+            SubmodelElement::BasicEventElement(elem) => elem.remove_qualifier(index),
+            SubmodelElement::Operation(elem) => elem.remove_qualifier(index)
+        }
+    }
+}
+
+
+///Returns the value-only serialization of elements as object of their values by idShort.
+///
+/// [elements]: elements of a submodel, collection or entity
+/// [with_blob_value]: whether the values of blobs are included
+pub(crate) fn elements_value_only(elements: &[SubmodelElement], with_blob_value: bool) -> Value {
+    Value::Object(elements.iter()
+        .filter_map(|element| Some((element.get_id_short()?.clone(), element.get_value_only(with_blob_value)?)))
+        .collect())
+}
+
+///Sets the values of elements from the value-only serialization as object of their values by
+/// idShort.
+///
+/// [elements]: elements of a submodel, collection or entity
+/// [value]: object of the values by idShort
+/// [path]: idShortPath of the parent of the elements, empty for a submodel
+pub(crate) fn set_elements_value_only(elements: &mut [SubmodelElement], value: &Value, path: &str) -> Result<(), ConversionError> {
+    let values = value.as_object().ok_or_else(|| ConversionError::InvalidValue(path.to_string()))?;
+    for (id_short, value) in values {
+        let child_path = match path.is_empty() {
+            true => id_short.clone(),
+            false => format!("{}.{}", path, id_short)
+        };
+        let element = elements.iter_mut()
+            .find(|element| element.get_id_short().is_some_and(|element_id_short| element_id_short == id_short))
+            .ok_or_else(|| ConversionError::MissingElement(child_path.clone()))?;
+        set_element_value_only(element, value, &child_path)?;
+    }
+    Ok(())
+}
+
+fn set_element_value_only(element: &mut SubmodelElement, value: &Value, path: &str) -> Result<(), ConversionError> {
+    let invalid = || ConversionError::InvalidValue(path.to_string());
+    match element {
+        SubmodelElement::RelationshipElement(elem) => {
+            let (first, second) = parse_relationship_value_only(value, path)?;
+            first.into_iter().for_each(|first| elem.set_first(first));
+            second.into_iter().for_each(|second| elem.set_second(second));
+            Ok(())
+        },
+        SubmodelElement::AnnotatedRelationshipElement(elem) => {
+            let (first, second) = parse_relationship_value_only(value, path)?;
+            first.into_iter().for_each(|first| elem.set_first(first));
+            second.into_iter().for_each(|second| elem.set_second(second));
+            let Some(annotation_values) = value.get("annotations") else {
+                return Ok(());
+            };
+            for annotation_value in annotation_values.as_array().ok_or_else(invalid)? {
+                for (id_short, annotation_value) in annotation_value.as_object().ok_or_else(invalid)? {
+                    let annotation_path = format!("{}.{}", path, id_short);
+                    let annotation = elem.get_mut_data_elements().iter_mut()
+                        .find(|annotation| annotation.get_id_short().is_some_and(|annotation_id_short| annotation_id_short == id_short))
+                        .ok_or_else(|| ConversionError::MissingElement(annotation_path.clone()))?;
+                    set_data_element_value_only(annotation, annotation_value, &annotation_path)?;
+                }
+            }
+            Ok(())
+        },
+        SubmodelElement::Property(elem) => set_property_value_only(elem, value, path),
+        SubmodelElement::MultiLanguageProperty(elem) => set_multi_language_value_only(elem, value, path),
+        SubmodelElement::Range(elem) => set_range_value_only(elem, value, path),
+        SubmodelElement::Blob(elem) => set_blob_value_only(elem, value, path),
+        SubmodelElement::File(elem) => set_file_value_only(elem, value, path),
+        SubmodelElement::ReferenceElement(elem) => {
+            elem.set_value(serde_json::from_value(value.clone()).map_err(|_| invalid())?);
+            Ok(())
+        },
+        SubmodelElement::Capability(_) | SubmodelElement::Operation(_) => Err(invalid()),
+        SubmodelElement::SubmodelElementList(elem) => {
+            let values = value.as_array().filter(|values| values.len() == elem.get_value().len()).ok_or_else(invalid)?;
+            for (index, (child, value)) in elem.get_mut_value().iter_mut().zip(values).enumerate() {
+                set_element_value_only(child, value, &format!("{}[{}]", path, index))?;
+            }
+            Ok(())
+        },
+        SubmodelElement::SubmodelElementCollection(elem) => set_elements_value_only(elem.get_mut_value(), value, path),
+        SubmodelElement::Entity(elem) => {
+            let values = value.as_object().ok_or_else(invalid)?;
+            if let Some(statements) = values.get("statements") {
+                set_elements_value_only(elem.get_mut_statements(), statements, path)?;
+            }
+            if let Some(entity_type) = values.get("entityType") {
+                elem.set_entity_type(serde_json::from_value(entity_type.clone()).map_err(|_| invalid())?);
+            }
+            if let Some(global_asset_id) = values.get("globalAssetId") {
+                elem.set_global_asset_id(global_asset_id.as_str().ok_or_else(invalid)?.to_string());
+            }
+            if let Some(specific_asset_ids) = values.get("specificAssetIds") {
+                elem.set_specific_asset_ids(serde_json::from_value(specific_asset_ids.clone()).map_err(|_| invalid())?);
+            }
+            Ok(())
+        },
+        SubmodelElement::BasicEventElement(elem) => {
+            let observed = value.get("observed").ok_or_else(invalid)?;
+            elem.set_observed(serde_json::from_value(observed.clone()).map_err(|_| invalid())?);
+            Ok(())
+        }
+    }
+}
+
+///Returns the value of a property as JSON boolean or number if its type is boolean or numeric, as
+/// JSON string otherwise.
+pub(crate) fn property_value_only(property: &Property) -> Option<Value> {
+    property.get_value().map(|value| typed_value(value, property.get_value_type()))
+}
+
+pub(crate) fn set_property_value_only(property: &mut Property, value: &Value, path: &str) -> Result<(), ConversionError> {
+    let value = lexical_value(value).filter(|value| property.get_value_type().is_valid_value(value))
+        .ok_or_else(|| ConversionError::InvalidValue(path.to_string()))?;
+    property.set_value(value);
+    Ok(())
+}
+
+pub(crate) fn multi_language_value_only(property: &MultiLanguageProperty) -> Value {
+    Value::Array(property.get_value().iter()
+        .map(|text| {
+            let mut value = Map::new();
+            value.insert(text.get_language().clone(), Value::String(text.get_text().clone()));
+            Value::Object(value)
+        })
+        .collect())
+}
+
+pub(crate) fn set_multi_language_value_only(property: &mut MultiLanguageProperty, value: &Value, path: &str) -> Result<(), ConversionError> {
+    let invalid = || ConversionError::InvalidValue(path.to_string());
+    let mut texts = Vec::new();
+    for text in value.as_array().ok_or_else(invalid)? {
+        for (language, text) in text.as_object().ok_or_else(invalid)? {
+            texts.push(MultiLanguageTextType::new(language.clone(), text.as_str().ok_or_else(invalid)?.to_string()));
+        }
+    }
+    property.set_value(texts);
+    Ok(())
+}
+
+pub(crate) fn range_value_only(range: &Range) -> Value {
+    let mut value = Map::new();
+    if let Some(min) = range.get_min() {
+        value.insert("min".to_string(), typed_value(min, range.get_value_type()));
+    }
+    if let Some(max) = range.get_max() {
+        value.insert("max".to_string(), typed_value(max, range.get_value_type()));
+    }
+    Value::Object(value)
+}
+
+pub(crate) fn set_range_value_only(range: &mut Range, value: &Value, path: &str) -> Result<(), ConversionError> {
+    let invalid = || ConversionError::InvalidValue(path.to_string());
+    let value_type = range.get_value_type().clone();
+    let bound = |bound: &Value| lexical_value(bound).filter(|bound| value_type.is_valid_value(bound)).ok_or_else(invalid);
+    let min = value.get("min").map(bound).transpose()?;
+    let max = value.get("max").map(bound).transpose()?;
+    min.into_iter().for_each(|min| range.set_min(min));
+    max.into_iter().for_each(|max| range.set_max(max));
+    Ok(())
+}
+
+pub(crate) fn blob_value_only(blob: &Blob, with_blob_value: bool) -> Value {
+    let mut value = Map::new();
+    if let Some(content_type) = blob.get_content_type() {
+        value.insert("contentType".to_string(), Value::String(content_type.clone()));
+    }
+    if with_blob_value && !blob.get_value().is_empty() {
+        value.insert("value".to_string(), Value::String(encode_base64(blob.get_value(), false)));
+    }
+    Value::Object(value)
+}
+
+pub(crate) fn set_blob_value_only(blob: &mut Blob, value: &Value, path: &str) -> Result<(), ConversionError> {
+    let invalid = || ConversionError::InvalidValue(path.to_string());
+    if let Some(content_type) = value.get("contentType") {
+        blob.set_content_type(content_type.as_str().ok_or_else(invalid)?.to_string());
+    }
+    if let Some(content) = value.get("value") {
+        blob.set_value(content.as_str().and_then(decode_base64).ok_or_else(invalid)?);
+    }
+    Ok(())
+}
+
+pub(crate) fn file_value_only(file: &File) -> Value {
+    let mut value = Map::new();
+    if let Some(content_type) = file.get_content_type() {
+        value.insert("contentType".to_string(), Value::String(content_type.clone()));
+    }
+    if let Some(path) = file.get_value() {
+        value.insert("value".to_string(), Value::String(path.clone()));
+    }
+    Value::Object(value)
+}
+
+pub(crate) fn set_file_value_only(file: &mut File, value: &Value, path: &str) -> Result<(), ConversionError> {
+    let invalid = || ConversionError::InvalidValue(path.to_string());
+    if let Some(content_type) = value.get("contentType") {
+        file.set_content_type(content_type.as_str().ok_or_else(invalid)?.to_string());
+    }
+    if let Some(file_path) = value.get("value") {
+        file.set_value(file_path.as_str().ok_or_else(invalid)?.to_string());
+    }
+    Ok(())
+}
+
+fn relationship_value_only(first: Option<&Reference>, second: Option<&Reference>, annotations: &[DataElement],
+                           with_blob_value: bool) -> Value {
+    let mut value = Map::new();
+    if let Some(first) = first {
+        value.insert("first".to_string(), serde_json::to_value(first).unwrap_or_default());
+    }
+    if let Some(second) = second {
+        value.insert("second".to_string(), serde_json::to_value(second).unwrap_or_default());
+    }
+    if !annotations.is_empty() {
+        value.insert("annotations".to_string(), Value::Array(annotations.iter()
+            .filter_map(|annotation| {
+                let mut annotation_value = Map::new();
+                annotation_value.insert(annotation.get_id_short()?.clone(), annotation.get_value_only(with_blob_value)?);
+                Some(Value::Object(annotation_value))
+            })
+            .collect()));
+    }
+    Value::Object(value)
+}
+
+///Returns the first and the second reference of the value-only serialization of a relationship.
+fn parse_relationship_value_only(value: &Value, path: &str) -> Result<(Option<Reference>, Option<Reference>), ConversionError> {
+    let parse = |name| value.get(name)
+        .map(|reference| serde_json::from_value(reference.clone()).map_err(|_| ConversionError::InvalidValue(path.to_string())))
+        .transpose();
+    Ok((parse("first")?, parse("second")?))
+}
+
+///Converts the lexical form of a value to a JSON boolean or number if the data type is boolean or
+/// numeric and the value can be represented, to a JSON string otherwise.
+fn typed_value(value: &str, value_type: &DataTypeDefXsd) -> Value {
+    let typed = match value_type {
+        DataTypeDefXsd::Boolean => value.parse::<bool>().ok().map(Value::Bool),
+        DataTypeDefXsd::Decimal | DataTypeDefXsd::Double | DataTypeDefXsd::Float => value.parse::<f64>().ok()
+            .and_then(Number::from_f64).map(Value::Number),
+        DataTypeDefXsd::Byte | DataTypeDefXsd::Int | DataTypeDefXsd::Integer | DataTypeDefXsd::Long
+        | DataTypeDefXsd::NegativeInteger | DataTypeDefXsd::NonPositiveInteger | DataTypeDefXsd::Short => value.parse::<i64>().ok()
+            .map(|value| Value::Number(value.into())),
+        DataTypeDefXsd::NonNegativeInteger | DataTypeDefXsd::PositiveInteger | DataTypeDefXsd::UnsignedByte
+        | DataTypeDefXsd::UnsignedInt | DataTypeDefXsd::UnsignedLong | DataTypeDefXsd::UnsignedShort => value.parse::<u64>().ok()
+            .map(|value| Value::Number(value.into())),
+        _ => None
+    };
+    typed.unwrap_or_else(|| Value::String(value.to_string()))
+}
+
+///Returns the lexical form of a JSON string, number or boolean.
+fn lexical_value(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(boolean) => Some(boolean.to_string()),
+        _ => None
+    }
+}
+
+impl Serialize for SubmodelElement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            SubmodelElement::RelationshipElement(elem) => TaggedElement::new("RelationshipElement", elem).serialize(serializer),
+            SubmodelElement::AnnotatedRelationshipElement(elem) => TaggedElement::new("AnnotatedRelationshipElement", elem).serialize(serializer),
+            SubmodelElement::Property(elem) => TaggedElement::new("Property", elem).serialize(serializer),
+            SubmodelElement::MultiLanguageProperty(elem) => TaggedElement::new("MultiLanguageProperty", elem).serialize(serializer),
+            SubmodelElement::Range(elem) => TaggedElement::new("Range", elem).serialize(serializer),
+            SubmodelElement::Blob(elem) => TaggedElement::new("Blob", elem).serialize(serializer),
+            SubmodelElement::File(elem) => TaggedElement::new("File", elem).serialize(serializer),
+            SubmodelElement::ReferenceElement(elem) => TaggedElement::new("ReferenceElement", elem).serialize(serializer),
+            SubmodelElement::Capability(elem) => TaggedElement::new("Capability", elem).serialize(serializer),
+            SubmodelElement::SubmodelElementList(elem) => TaggedElement::new("SubmodelElementList", elem).serialize(serializer),
+            SubmodelElement::SubmodelElementCollection(elem) => TaggedElement::new("SubmodelElementCollection", elem).serialize(serializer),
+            SubmodelElement::Entity(elem) => TaggedElement::new("Entity", elem).serialize(serializer),
+            SubmodelElement::BasicEventElement(elem) => TaggedElement::new("BasicEventElement", elem).serialize(serializer),
+            SubmodelElement::Operation(elem) => TaggedElement::new("Operation", elem).serialize(serializer)
+        }
+    }
+}
+
+///Element serialized together with its model type. The derived serialization of the
+/// internally tagged enum wraps the serializer once per level of nested elements, which exceeds the
+/// recursion limit of the compiler for collections, lists and entities.
+#[derive(Serialize)]
+pub(crate) struct TaggedElement<'a, T> {
+    #[serde(rename = "modelType")]
+    model_type: &'static str,
+    #[serde(flatten)]
+    element: &'a T
+}
+
+impl<'a, T> TaggedElement<'a, T> {
+    pub(crate) fn new(model_type: &'static str, element: &'a T) -> TaggedElement<'a, T> {
+        TaggedElement {
+            model_type,
+            element
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,72 @@
+///Enumeration of the protocols of the interfaces of the Asset Interfaces Description, each with
+/// the W3C WoT binding vocabulary of its protocol specific terms.
+#[derive(PartialEq, Clone)]
+pub enum InterfaceProtocol {
+    ///HTTP, with terms of the HTTP vocabulary, e.g. `htv:methodName`.
+    Http,
+    ///Modbus, with terms of the Modbus binding, e.g. `modv:function`.
+    Modbus,
+    ///MQTT, with terms of the MQTT binding, e.g. `mqv:controlPacket`.
+    Mqtt
+}
+
+impl InterfaceProtocol {
+    ///Returns the protocol with the supplemental semantic ID. Returns None if the semantic ID
+    /// belongs to no known protocol.
+    ///
+    /// [semantic_id]: supplemental semantic ID of an interface, e.g. `http://www.w3.org/2011/http`
+    pub fn from_semantic_id(semantic_id: &str) -> Option<InterfaceProtocol> {
+        [InterfaceProtocol::Http, InterfaceProtocol::Modbus, InterfaceProtocol::Mqtt].into_iter()
+            .find(|protocol| protocol.get_semantic_id() == semantic_id)
+    }
+
+    ///Returns the protocol of the URI scheme of a target, e.g. `modbus+tcp://192.168.0.1:502`.
+    /// Returns None if the scheme belongs to no known protocol.
+    ///
+    /// [href]: absolute URI of a target or base URI of an interface
+    pub fn from_href(href: &str) -> Option<InterfaceProtocol> {
+        let scheme = href.split_once(':')?.0.to_lowercase();
+        match scheme.as_str() {
+            "http" | "https" => Some(InterfaceProtocol::Http),
+            "modbus" | "modbus+tcp" | "modbus+rtu" => Some(InterfaceProtocol::Modbus),
+            "mqtt" | "mqtts" => Some(InterfaceProtocol::Mqtt),
+            _ => None
+        }
+    }
+
+    ///Returns the protocol with the prefix of its vocabulary. Returns None if the prefix belongs
+    /// to no known protocol.
+    ///
+    /// [prefix]: prefix of the vocabulary, e.g. `modv`
+    pub fn from_prefix(prefix: &str) -> Option<InterfaceProtocol> {
+        [InterfaceProtocol::Http, InterfaceProtocol::Modbus, InterfaceProtocol::Mqtt].into_iter()
+            .find(|protocol| protocol.get_prefix() == prefix)
+    }
+
+    ///Returns the supplemental semantic ID of interfaces of the protocol.
+    pub fn get_semantic_id(&self) -> &'static str {
+        match self {
+            InterfaceProtocol::Http => "http://www.w3.org/2011/http",
+            InterfaceProtocol::Modbus => "https://www.w3.org/2019/wot/modbus",
+            InterfaceProtocol::Mqtt => "https://www.w3.org/2019/wot/mqtt"
+        }
+    }
+
+    ///Returns the prefix of the vocabulary of the protocol specific terms, e.g. `htv`.
+    pub fn get_prefix(&self) -> &'static str {
+        match self {
+            InterfaceProtocol::Http => "htv",
+            InterfaceProtocol::Modbus => "modv",
+            InterfaceProtocol::Mqtt => "mqv"
+        }
+    }
+
+    ///Returns the namespace of the vocabulary of the protocol specific terms.
+    pub fn get_namespace(&self) -> &'static str {
+        match self {
+            InterfaceProtocol::Http => "http://www.w3.org/2011/http#",
+            InterfaceProtocol::Modbus => "https://www.w3.org/2019/wot/modbus#",
+            InterfaceProtocol::Mqtt => "https://www.w3.org/2019/wot/mqtt#"
+        }
+    }
+}
@@ -0,0 +1,27 @@
+///Enumeration of the levels of the HTTP API, which determine the depth of the elements contained
+/// in a response.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Level {
+    ///All elements are contained.
+    Deep,
+    ///Only the direct children are contained.
+    Core
+}
+
+impl Level {
+    ///Returns the level with the name of the query parameter `level`. Returns None for an unknown
+    /// name.
+    ///
+    /// [name]: name of the level, e.g. `core`
+    pub fn from_name(name: &str) -> Option<Level> {
+        [Level::Deep, Level::Core].into_iter().find(|level| level.get_name() == name)
+    }
+
+    ///Returns the name of the level in the query parameter `level`.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Level::Deep => "deep",
+            Level::Core => "core"
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+///Enumeration for denoting why an element could not be merged automatically during a three-way
+/// merge of submodels.
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
+pub enum MergeConflictKind {
+    ///The value of a property was changed to different values on both sides.
+    ValueChanged,
+    ///The element was changed differently on both sides.
+    ModifiedOnBothSides,
+    ///The element was deleted on our side and modified on their side.
+    DeletedByOursModifiedByTheirs,
+    ///The element was modified on our side and deleted on their side.
+    ModifiedByOursDeletedByTheirs,
+    ///Different elements with the same path were added on both sides.
+    AddedOnBothSides
+}
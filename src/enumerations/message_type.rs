@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+///Type of a message in the result of a call of the HTTP API.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub enum MessageType {
+    ///The type of the message is not specified.
+    Undefined,
+    ///The message informs about the processing of the call.
+    Info,
+    ///The message warns about a problem that did not prevent the processing of the call.
+    Warning,
+    ///The call failed, e.g. because of an invalid request.
+    Error,
+    ///The call failed because of an unexpected exception of the server.
+    Exception
+}
\ No newline at end of file
@@ -8,4 +8,34 @@ pub mod entity_type;
 pub mod aas_submodel_elements;
 pub mod reference_type;
 pub mod key_type;
-pub mod interface_enumerations;
\ No newline at end of file
+pub mod interface_enumerations;
+pub mod merge_conflict_kind;
+pub mod id_conflict_policy;
+pub mod environment_merge_error;
+pub mod id_short_path_segment;
+pub mod refactoring_error;
+pub mod instantiation_error;
+pub mod conformance_deviation_kind;
+pub mod cardinality;
+pub mod access_mode;
+pub mod smt_qualifier;
+pub mod conversion_error;
+pub mod code_generation_error;
+pub mod file_resolution_error;
+pub mod vdi_2770_class;
+pub mod arche_type;
+pub mod segment_kind;
+pub mod time_series_error;
+pub mod interface_protocol;
+pub mod thing_description_error;
+pub mod binding_error;
+pub mod binding_target;
+pub mod modbus_entity;
+pub mod passport_error;
+pub mod message_type;
+pub mod repository_error;
+pub mod level;
+pub mod extent;
+pub mod client_error;
+pub mod security_type;
+pub mod data_type_iec61360;
\ No newline at end of file
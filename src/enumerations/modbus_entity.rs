@@ -0,0 +1,54 @@
+///Enumeration of the Modbus data entities a value can be read from, each with its read function.
+#[derive(PartialEq, Clone)]
+pub enum ModbusEntity {
+    ///Single bit, read with function 1 (Read Coils).
+    Coil,
+    ///Single read-only bit, read with function 2 (Read Discrete Inputs).
+    DiscreteInput,
+    ///16-bit register, read with function 3 (Read Holding Registers).
+    HoldingRegister,
+    ///16-bit read-only register, read with function 4 (Read Input Registers).
+    InputRegister
+}
+
+impl ModbusEntity {
+    ///Parses an entity from its name in the Modbus binding of the Thing Description, i.e. the
+    /// value of `modv:entity` or of `modv:function`. Returns None if the name is unknown.
+    ///
+    /// [name]: name of the entity, e.g. `HoldingRegister`, or of the function, e.g.
+    /// `readHoldingRegisters`
+    pub fn parse(name: &str) -> Option<ModbusEntity> {
+        match name.trim() {
+            "Coil" | "readCoil" => Some(ModbusEntity::Coil),
+            "DiscreteInput" | "readDiscreteInput" => Some(ModbusEntity::DiscreteInput),
+            "HoldingRegister" | "readHoldingRegisters" => Some(ModbusEntity::HoldingRegister),
+            "InputRegister" | "readInputRegisters" => Some(ModbusEntity::InputRegister),
+            _ => None
+        }
+    }
+
+    ///Returns the name of the entity.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            ModbusEntity::Coil => "Coil",
+            ModbusEntity::DiscreteInput => "DiscreteInput",
+            ModbusEntity::HoldingRegister => "HoldingRegister",
+            ModbusEntity::InputRegister => "InputRegister"
+        }
+    }
+
+    ///Returns the code of the function reading the entity.
+    pub fn get_read_function(&self) -> u8 {
+        match self {
+            ModbusEntity::Coil => 1,
+            ModbusEntity::DiscreteInput => 2,
+            ModbusEntity::HoldingRegister => 3,
+            ModbusEntity::InputRegister => 4
+        }
+    }
+
+    ///Returns whether the entity consists of bits rather than 16-bit registers.
+    pub fn is_bit(&self) -> bool {
+        matches!(self, ModbusEntity::Coil | ModbusEntity::DiscreteInput)
+    }
+}
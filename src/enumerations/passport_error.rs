@@ -0,0 +1,32 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::enumerations::conversion_error::ConversionError;
+
+///Errors that can occur when a product passport is checked for completeness.
+#[derive(Debug, PartialEq, Clone)]
+pub enum PassportError {
+    ///The asset administration shell of the passport has no global asset ID.
+    MissingGlobalAssetId,
+    ///The mandatory submodel with the contained idShort, e.g. `Nameplate`, is missing.
+    MissingSubmodel(String),
+    ///The submodel with the contained idShort, or ID if it has none, is a template instead of an
+    /// instance.
+    TemplateSubmodel(String),
+    ///The submodel with the contained idShort lacks mandatory data or has invalid values, as
+    /// described by the contained error.
+    InvalidSubmodel(String, ConversionError)
+}
+
+impl Display for PassportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PassportError::MissingGlobalAssetId => write!(f, "the asset administration shell has no global asset ID"),
+            PassportError::MissingSubmodel(id_short) => write!(f, "mandatory submodel '{}' is missing", id_short),
+            PassportError::TemplateSubmodel(id_short) => write!(f, "submodel '{}' is a template instead of an instance", id_short),
+            PassportError::InvalidSubmodel(id_short, error) => write!(f, "submodel '{}' is invalid: {}", id_short, error)
+        }
+    }
+}
+
+impl Error for PassportError {}
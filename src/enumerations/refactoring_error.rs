@@ -0,0 +1,36 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+///Errors that can occur when submodel elements are renamed or moved or identifiables are cloned
+/// with new IDs.
+#[derive(Debug, PartialEq, Clone)]
+pub enum RefactoringError {
+    ///The contained idShortPath is malformed or does not address a submodel element by idShort.
+    InvalidPath(String),
+    ///No submodel with the contained ID exists.
+    SubmodelNotFound(String),
+    ///No submodel element exists at the contained idShortPath.
+    ElementNotFound(String),
+    ///An element with the same idShort already exists next to the element at the contained
+    /// idShortPath.
+    IdShortCollision(String),
+    ///The element cannot be moved to or from the contained idShortPath.
+    InvalidTarget(String),
+    ///The contained number of new IDs differs from the contained number of IDs to clone.
+    IdCountMismatch(usize, usize)
+}
+
+impl Display for RefactoringError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RefactoringError::InvalidPath(path) => write!(f, "invalid idShortPath '{}'", path),
+            RefactoringError::SubmodelNotFound(id) => write!(f, "submodel '{}' not found", id),
+            RefactoringError::ElementNotFound(path) => write!(f, "submodel element '{}' not found", path),
+            RefactoringError::IdShortCollision(path) => write!(f, "idShort of '{}' is already taken", path),
+            RefactoringError::InvalidTarget(path) => write!(f, "invalid move target '{}'", path),
+            RefactoringError::IdCountMismatch(ids, new_ids) => write!(f, "{} new IDs given for {} IDs to clone", new_ids, ids)
+        }
+    }
+}
+
+impl Error for RefactoringError {}
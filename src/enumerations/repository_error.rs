@@ -0,0 +1,31 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+///Errors that can occur when the shells, submodels and concept descriptions of a repository or the
+/// descriptors of a registry are read or modified, e.g. by the HTTP API of the repository.
+#[derive(Debug, PartialEq, Clone)]
+pub enum RepositoryError {
+    ///No identifiable, descriptor or submodel element with the contained identifier or idShortPath
+    /// exists.
+    NotFound(String),
+    ///An identifiable, descriptor or submodel element with the contained identifier or idShortPath
+    /// already exists.
+    Conflict(String),
+    ///The request is invalid; the message is contained.
+    InvalidRequest(String),
+    ///Reading from or writing to the storage failed with the contained message.
+    StorageFailed(String)
+}
+
+impl Display for RepositoryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RepositoryError::NotFound(id) => write!(f, "'{}' not found", id),
+            RepositoryError::Conflict(id) => write!(f, "'{}' already exists", id),
+            RepositoryError::InvalidRequest(message) => write!(f, "invalid request: {}", message),
+            RepositoryError::StorageFailed(message) => write!(f, "storage failed: {}", message)
+        }
+    }
+}
+
+impl Error for RepositoryError {}
\ No newline at end of file
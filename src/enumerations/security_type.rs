@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+///Enumeration of the types of the security attributes of the protocol information of an endpoint.
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
+pub enum SecurityType {
+    ///No security attribute.
+    #[serde(rename = "NONE")]
+    None,
+    ///TLSA record of the DNS-Based Authentication of Named Entities (RFC 6698).
+    #[serde(rename = "RFC_TLSA")]
+    RfcTlsa,
+    ///W3C decentralized identifier.
+    #[serde(rename = "W3C_DID")]
+    W3cDid
+}
\ No newline at end of file
@@ -0,0 +1,40 @@
+///Enumeration of the kinds of segments of the Time Series submodel, which differ in where the
+/// records are stored.
+#[derive(PartialEq, Clone)]
+pub enum SegmentKind {
+    ///The records are contained in the segment.
+    Internal,
+    ///The records are provided by an endpoint, e.g. a time series database, and queried on demand.
+    Linked,
+    ///The records are stored in a file or blob, e.g. as CSV or JSON.
+    External
+}
+
+impl SegmentKind {
+    ///Returns the kind of the segment with the semantic ID. Returns None if the semantic ID is no
+    /// segment semantic ID.
+    ///
+    /// [semantic_id]: semantic ID of the segment
+    pub fn from_semantic_id(semantic_id: &str) -> Option<SegmentKind> {
+        [SegmentKind::Internal, SegmentKind::Linked, SegmentKind::External].into_iter()
+            .find(|kind| kind.get_semantic_id() == semantic_id)
+    }
+
+    ///Returns the semantic ID of segments of the kind.
+    pub const fn get_semantic_id(&self) -> &'static str {
+        match self {
+            SegmentKind::Internal => "https://admin-shell.io/idta/TimeSeries/Segments/InternalSegment/1/1",
+            SegmentKind::Linked => "https://admin-shell.io/idta/TimeSeries/Segments/LinkedSegment/1/1",
+            SegmentKind::External => "https://admin-shell.io/idta/TimeSeries/Segments/ExternalSegment/1/1"
+        }
+    }
+
+    ///Returns the idShort of segments of the kind in the submodel template.
+    pub const fn get_id_short(&self) -> &'static str {
+        match self {
+            SegmentKind::Internal => "InternalSegment{00}",
+            SegmentKind::Linked => "LinkedSegment{00}",
+            SegmentKind::External => "ExternalSegment{00}"
+        }
+    }
+}
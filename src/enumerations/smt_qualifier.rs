@@ -0,0 +1,119 @@
+use crate::enumerations::access_mode::AccessMode;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::key_type::KeyType;
+use crate::enumerations::qualifier_kind::QualifierKind;
+use crate::enumerations::reference_type::ReferenceType;
+use crate::structs::allowed_range::AllowedRange;
+use crate::structs::key::Key;
+use crate::structs::qualifier::Qualifier;
+use crate::structs::reference::Reference;
+use crate::traits::has_semantics::THasSemantics;
+
+///Typed representation of the qualifiers defined by the IDTA submodel template specification.
+#[derive(PartialEq, Clone)]
+pub enum SmtQualifier {
+    ///Number of occurrences of the element in an instance.
+    Cardinality(Cardinality),
+    ///ID of a group of elements of which exactly one has to be present in an instance.
+    EitherOr(String),
+    ///Value the element has in an instance if no other value is given.
+    DefaultValue(String),
+    ///Value the element has when an instance is created.
+    InitialValue(String),
+    ///Example of a value of the element.
+    ExampleValue(String),
+    ///Range of numeric values the element is allowed to have in an instance.
+    AllowedRange(AllowedRange),
+    ///Value or regular expression for the values the element is allowed to have in an instance.
+    AllowedValue(String),
+    ///Language a multi-language property has to be available in.
+    RequiredLang(String),
+    ///Whether the value of the element can be written.
+    AccessMode(AccessMode),
+    ///Title of the element in forms.
+    FormTitle(String),
+    ///Additional information on the element shown in forms.
+    FormInfo(String)
+}
+
+impl SmtQualifier {
+    ///Parses a qualifier. The qualifier is recognized by its type, either with or without the
+    /// `SMT/` prefix, or by its semantic ID. Returns None if the qualifier is no SMT qualifier or
+    /// its value is malformed.
+    ///
+    /// [qualifier]: qualifier to parse
+    pub fn parse(qualifier: &Qualifier) -> Option<SmtQualifier> {
+        let qualifier_type = qualifier.get_qualifier_type();
+        let name = qualifier_type.strip_prefix("SMT/").unwrap_or(qualifier_type);
+        let name = match name {
+            "Multiplicity" => "Cardinality",
+            _ => name
+        };
+        let name = NAMES.iter()
+            .find(|known| **known == name || qualifier.get_semantic_id().is_some_and(|semantic_id| semantic_id.get_keys()
+                .first().is_some_and(|key| key.get_value() == &semantic_id_of(known))))?;
+        let value = qualifier.get_value()?.clone();
+        match *name {
+            "Cardinality" => Cardinality::parse(&value).map(SmtQualifier::Cardinality),
+            "EitherOr" => Some(SmtQualifier::EitherOr(value)),
+            "DefaultValue" => Some(SmtQualifier::DefaultValue(value)),
+            "InitialValue" => Some(SmtQualifier::InitialValue(value)),
+            "ExampleValue" => Some(SmtQualifier::ExampleValue(value)),
+            "AllowedRange" => AllowedRange::parse(&value).map(SmtQualifier::AllowedRange),
+            "AllowedValue" => Some(SmtQualifier::AllowedValue(value)),
+            "RequiredLang" => Some(SmtQualifier::RequiredLang(value)),
+            "AccessMode" => AccessMode::parse(&value).map(SmtQualifier::AccessMode),
+            "FormTitle" => Some(SmtQualifier::FormTitle(value)),
+            "FormInfo" => Some(SmtQualifier::FormInfo(value)),
+            _ => None
+        }
+    }
+
+    ///Returns the name of the qualifier without the `SMT/` prefix, e.g. `Cardinality`.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            SmtQualifier::Cardinality(_) => "Cardinality",
+            SmtQualifier::EitherOr(_) => "EitherOr",
+            SmtQualifier::DefaultValue(_) => "DefaultValue",
+            SmtQualifier::InitialValue(_) => "InitialValue",
+            SmtQualifier::ExampleValue(_) => "ExampleValue",
+            SmtQualifier::AllowedRange(_) => "AllowedRange",
+            SmtQualifier::AllowedValue(_) => "AllowedValue",
+            SmtQualifier::RequiredLang(_) => "RequiredLang",
+            SmtQualifier::AccessMode(_) => "AccessMode",
+            SmtQualifier::FormTitle(_) => "FormTitle",
+            SmtQualifier::FormInfo(_) => "FormInfo"
+        }
+    }
+
+    ///Returns the value of the qualifier as it is written to a qualifier.
+    pub fn get_value(&self) -> String {
+        match self {
+            SmtQualifier::Cardinality(cardinality) => cardinality.get_name().to_string(),
+            SmtQualifier::AllowedRange(range) => range.to_string(),
+            SmtQualifier::AccessMode(access_mode) => access_mode.get_name().to_string(),
+            SmtQualifier::EitherOr(value) | SmtQualifier::DefaultValue(value) | SmtQualifier::InitialValue(value)
+            | SmtQualifier::ExampleValue(value) | SmtQualifier::AllowedValue(value) | SmtQualifier::RequiredLang(value)
+            | SmtQualifier::FormTitle(value) | SmtQualifier::FormInfo(value) => value.clone()
+        }
+    }
+
+    ///Writes the qualifier as a template qualifier with the type `SMT/<name>` and the semantic ID
+    /// of the IDTA submodel template specification.
+    pub fn to_qualifier(&self) -> Qualifier {
+        let mut qualifier = Qualifier::new(format!("SMT/{}", self.get_name()), DataTypeDefXsd::String);
+        qualifier.set_kind(QualifierKind::TemplateQualifier);
+        qualifier.set_value(self.get_value());
+        qualifier.set_semantic_id(Reference::new(ReferenceType::ExternalReference,
+                                                 vec![Key::new(KeyType::GlobalReference, semantic_id_of(self.get_name()))]));
+        qualifier
+    }
+}
+
+const NAMES: [&str; 11] = ["Cardinality", "EitherOr", "DefaultValue", "InitialValue", "ExampleValue", "AllowedRange",
+    "AllowedValue", "RequiredLang", "AccessMode", "FormTitle", "FormInfo"];
+
+fn semantic_id_of(name: &str) -> String {
+    format!("https://admin-shell.io/SubmodelTemplates/{}/1/0", name)
+}
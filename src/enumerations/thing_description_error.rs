@@ -0,0 +1,26 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+///Errors that can occur when a W3C WoT Thing Description is converted into an interface of the
+/// Asset Interfaces Description.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ThingDescriptionError {
+    ///The document is no valid JSON; the message is contained.
+    InvalidJson(String),
+    ///The member at the contained JSON path is missing.
+    MissingMember(String),
+    ///The member at the contained JSON path has an unexpected JSON type.
+    UnexpectedType(String)
+}
+
+impl Display for ThingDescriptionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThingDescriptionError::InvalidJson(message) => write!(f, "invalid Thing Description: {}", message),
+            ThingDescriptionError::MissingMember(path) => write!(f, "member '{}' of the Thing Description is missing", path),
+            ThingDescriptionError::UnexpectedType(path) => write!(f, "member '{}' of the Thing Description has an unexpected type", path)
+        }
+    }
+}
+
+impl Error for ThingDescriptionError {}
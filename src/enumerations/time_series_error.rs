@@ -0,0 +1,44 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use crate::enumerations::file_resolution_error::FileResolutionError;
+
+///Errors that can occur when the records of a time series segment are read or written.
+#[derive(Debug, PartialEq, Clone)]
+pub enum TimeSeriesError {
+    ///The segment with the contained idShort does not exist.
+    SegmentNotFound(String),
+    ///The operation is not supported by the segment with the contained idShort, e.g. reading the
+    /// records of a linked segment.
+    UnsupportedSegment(String),
+    ///The external segment with the contained idShort has neither a file nor a blob.
+    MissingData(String),
+    ///The file of an external segment cannot be read.
+    FileResolution(FileResolutionError),
+    ///The data of an external segment has the contained content type, which is neither CSV nor
+    /// JSON.
+    UnsupportedFormat(String),
+    ///The data of an external segment cannot be parsed; the message is contained.
+    InvalidData(String),
+    ///The record contains a value of the contained variable, which is not part of the record
+    /// definition.
+    UnknownVariable(String),
+    ///The contained value of the contained variable is invalid for the data type of the variable.
+    InvalidValue(String, String)
+}
+
+impl Display for TimeSeriesError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeSeriesError::SegmentNotFound(id_short) => write!(f, "segment '{}' not found", id_short),
+            TimeSeriesError::UnsupportedSegment(id_short) => write!(f, "operation not supported by segment '{}'", id_short),
+            TimeSeriesError::MissingData(id_short) => write!(f, "external segment '{}' has neither a file nor a blob", id_short),
+            TimeSeriesError::FileResolution(error) => write!(f, "{}", error),
+            TimeSeriesError::UnsupportedFormat(content_type) => write!(f, "unsupported format '{}' of external segment", content_type),
+            TimeSeriesError::InvalidData(message) => write!(f, "invalid data of external segment: {}", message),
+            TimeSeriesError::UnknownVariable(variable) => write!(f, "variable '{}' is not part of the record definition", variable),
+            TimeSeriesError::InvalidValue(variable, value) => write!(f, "invalid value '{}' of variable '{}'", value, variable)
+        }
+    }
+}
+
+impl Error for TimeSeriesError {}
@@ -0,0 +1,91 @@
+///Enumeration of the document classes of VDI 2770, which the Handover Documentation uses to
+/// classify documents.
+#[derive(PartialEq, Clone)]
+pub enum Vdi2770Class {
+    ///01-01 Identification.
+    Identification,
+    ///02-01 Technical specification.
+    TechnicalSpecification,
+    ///02-02 Drawings, plans.
+    DrawingsPlans,
+    ///02-03 Assemblies.
+    Assemblies,
+    ///02-04 Certificates, declarations.
+    CertificatesDeclarations,
+    ///03-01 Commissioning, de-commissioning.
+    CommissioningDecommissioning,
+    ///03-02 Operation.
+    Operation,
+    ///03-03 General safety.
+    GeneralSafety,
+    ///03-04 Inspection, maintenance, testing.
+    InspectionMaintenanceTesting,
+    ///03-05 Repair.
+    Repair,
+    ///03-06 Spare parts.
+    SpareParts,
+    ///04-01 Contract documents.
+    ContractDocuments
+}
+
+impl Vdi2770Class {
+    ///Name of the classification system used for the VDI 2770 classes.
+    pub const CLASSIFICATION_SYSTEM: &str = "VDI2770:2018";
+
+    ///Parses a class from its class ID. Returns None if the class ID is unknown.
+    ///
+    /// [class_id]: class ID, e.g. `03-02`
+    pub fn parse(class_id: &str) -> Option<Vdi2770Class> {
+        match class_id.trim() {
+            "01-01" => Some(Vdi2770Class::Identification),
+            "02-01" => Some(Vdi2770Class::TechnicalSpecification),
+            "02-02" => Some(Vdi2770Class::DrawingsPlans),
+            "02-03" => Some(Vdi2770Class::Assemblies),
+            "02-04" => Some(Vdi2770Class::CertificatesDeclarations),
+            "03-01" => Some(Vdi2770Class::CommissioningDecommissioning),
+            "03-02" => Some(Vdi2770Class::Operation),
+            "03-03" => Some(Vdi2770Class::GeneralSafety),
+            "03-04" => Some(Vdi2770Class::InspectionMaintenanceTesting),
+            "03-05" => Some(Vdi2770Class::Repair),
+            "03-06" => Some(Vdi2770Class::SpareParts),
+            "04-01" => Some(Vdi2770Class::ContractDocuments),
+            _ => None
+        }
+    }
+
+    ///Returns the class ID, e.g. `03-02`.
+    pub fn get_class_id(&self) -> &'static str {
+        match self {
+            Vdi2770Class::Identification => "01-01",
+            Vdi2770Class::TechnicalSpecification => "02-01",
+            Vdi2770Class::DrawingsPlans => "02-02",
+            Vdi2770Class::Assemblies => "02-03",
+            Vdi2770Class::CertificatesDeclarations => "02-04",
+            Vdi2770Class::CommissioningDecommissioning => "03-01",
+            Vdi2770Class::Operation => "03-02",
+            Vdi2770Class::GeneralSafety => "03-03",
+            Vdi2770Class::InspectionMaintenanceTesting => "03-04",
+            Vdi2770Class::Repair => "03-05",
+            Vdi2770Class::SpareParts => "03-06",
+            Vdi2770Class::ContractDocuments => "04-01"
+        }
+    }
+
+    ///Returns the English name of the class, e.g. `Operation`.
+    pub fn get_class_name(&self) -> &'static str {
+        match self {
+            Vdi2770Class::Identification => "Identification",
+            Vdi2770Class::TechnicalSpecification => "Technical specification",
+            Vdi2770Class::DrawingsPlans => "Drawings, plans",
+            Vdi2770Class::Assemblies => "Assemblies",
+            Vdi2770Class::CertificatesDeclarations => "Certificates, declarations",
+            Vdi2770Class::CommissioningDecommissioning => "Commissioning, de-commissioning",
+            Vdi2770Class::Operation => "Operation",
+            Vdi2770Class::GeneralSafety => "General safety",
+            Vdi2770Class::InspectionMaintenanceTesting => "Inspection, maintenance, testing",
+            Vdi2770Class::Repair => "Repair",
+            Vdi2770Class::SpareParts => "Spare parts",
+            Vdi2770Class::ContractDocuments => "Contract documents"
+        }
+    }
+}
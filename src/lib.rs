@@ -1,3 +1,8 @@
 pub mod enumerations;
 pub mod traits;
-pub mod structs;
\ No newline at end of file
+pub mod structs;
+
+pub(crate) mod transport;
+
+#[cfg(feature = "derive")]
+pub use aas_model_rs_derive::AasCollection;
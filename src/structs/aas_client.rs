@@ -0,0 +1,817 @@
+use std::error::Error;
+use std::io::Read;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use ureq::Agent;
+
+use crate::enumerations::client_error::ClientError;
+use crate::enumerations::extent::Extent;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::level::Level;
+use crate::structs::asset_administration_shell::AssetAdministrationShell;
+use crate::structs::asset_information::AssetInformation;
+use crate::structs::blob::encode_base64;
+use crate::structs::concept_description::ConceptDescription;
+use crate::structs::paged_result::PagedResult;
+use crate::structs::reference::Reference;
+use crate::structs::service_result::ServiceResult;
+use crate::structs::specific_asset_id::SpecificAssetId;
+use crate::structs::submodel::Submodel;
+use crate::traits::identifiable::TIdentifiable;
+use crate::transport::split_uri;
+
+///Client of the HTTP API (IDTA 01002 Part 2 V3.1) of AAS servers, covering the AAS Repository,
+/// Submodel Repository, Concept Description Repository, registry and discovery interfaces.
+///
+/// Identifiers are base64url encoded and idShortPaths percent-encoded in the URLs. Lists are read
+/// page by page with a cursor, see `PagedResult::collect_all` to read all pages. Error responses
+/// are returned as `ClientError::Status` with the messages of the server. Servers are called by
+/// HTTP or HTTPS; the registry and discovery interfaces are often served at other base URLs, which
+/// need clients of their own.
+#[derive(PartialEq, Clone)]
+pub struct AasClient {
+    ///Base URL of the API, e.g. `http://localhost:8081/api/v3.0`.
+    base_url: String,
+    ///Additional request headers, e.g. for authorization.
+    headers: Vec<(String, String)>,
+    ///Timeout of a request from connecting until the response is read.
+    timeout: Duration
+}
+
+impl AasClient {
+    ///Creates a new client with a timeout of 30 seconds.
+    ///
+    /// [base_url]: base URL of the API, e.g. `http://localhost:8081/api/v3.0`
+    pub fn new(base_url: String) -> AasClient {
+        AasClient {
+            base_url,
+            headers: Vec::new(),
+            timeout: Duration::from_secs(30)
+        }
+    }
+
+    ///Returns the base URL of the API.
+    pub fn get_base_url(&self) -> &String {
+        &self.base_url
+    }
+
+    ///Sets the base URL of the API.
+    ///
+    /// [base_url]: base URL of the API
+    pub fn set_base_url(&mut self, base_url: String) {
+        self.base_url = base_url;
+    }
+
+    ///Returns the additional request headers.
+    pub fn get_headers(&self) -> &Vec<(String, String)> {
+        &self.headers
+    }
+
+    ///Adds a header sent with every request. Returns `ClientError::InvalidHeader` if the name is no
+    /// valid header name or the value contains line breaks.
+    ///
+    /// [name]: name of the header, e.g. `Authorization`
+    /// [value]: value of the header
+    pub fn add_header(&mut self, name: String, value: String) -> Result<(), ClientError> {
+        let is_token = !name.is_empty() && name.bytes().all(|byte| byte.is_ascii_graphic() && !b"\"(),/:;<=>?@[\\]{}".contains(&byte));
+        if !is_token || value.bytes().any(|byte| matches!(byte, b'\r' | b'\n' | 0)) {
+            return Err(ClientError::InvalidHeader(name));
+        }
+        self.headers.push((name, value));
+        Ok(())
+    }
+
+    ///Returns the timeout of a request from connecting until the response is read.
+    pub fn get_timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    ///Sets the timeout of a request from connecting until the response is read.
+    ///
+    /// [timeout]: timeout of a request
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    ///Returns a page of the shells of the repository.
+    ///
+    /// [id_short]: idShort the shells must have, None for all shells
+    /// [asset_ids]: asset IDs the shells must all have; the name `globalAssetId` denotes the global
+    /// asset ID
+    /// [limit]: maximum number of shells of the page, None for the default of the server
+    /// [cursor]: cursor of the page, None for the first page
+    pub fn get_shells(&self, id_short: Option<&str>, asset_ids: &[SpecificAssetId], limit: Option<usize>, cursor: Option<&str>)
+                      -> Result<PagedResult<AssetAdministrationShell>, ClientError> {
+        let mut query = Vec::new();
+        if let Some(id_short) = id_short {
+            query.push(("idShort", id_short.to_string()));
+        }
+        for asset_id in asset_ids {
+            query.push(("assetIds", encode_json(asset_id)?));
+        }
+        self.get_page("/shells", query, limit, cursor)
+    }
+
+    ///Returns the shell with the identifier.
+    ///
+    /// [id]: identifier of the shell
+    pub fn get_shell(&self, id: &str) -> Result<AssetAdministrationShell, ClientError> {
+        self.get_json(&format!("/shells/{}", encode_id(id)), Vec::new())
+    }
+
+    ///Creates a shell and returns the shell created by the server.
+    ///
+    /// [shell]: shell to create
+    pub fn post_shell(&self, shell: &AssetAdministrationShell) -> Result<AssetAdministrationShell, ClientError> {
+        self.send_json("POST", "/shells", shell).and_then(|body| parse(&body))
+    }
+
+    ///Replaces or creates the shell with the identifier of the shell.
+    ///
+    /// [shell]: new shell
+    pub fn put_shell(&self, shell: &AssetAdministrationShell) -> Result<(), ClientError> {
+        self.send_json("PUT", &format!("/shells/{}", encode_id(shell.get_id())), shell).map(|_| ())
+    }
+
+    ///Deletes the shell with the identifier.
+    ///
+    /// [id]: identifier of the shell
+    pub fn delete_shell(&self, id: &str) -> Result<(), ClientError> {
+        self.send("DELETE", &format!("/shells/{}", encode_id(id)), Vec::new(), None).map(|_| ())
+    }
+
+    ///Returns the asset information of the shell with the identifier.
+    ///
+    /// [id]: identifier of the shell
+    pub fn get_asset_information(&self, id: &str) -> Result<AssetInformation, ClientError> {
+        self.get_json(&format!("/shells/{}/asset-information", encode_id(id)), Vec::new())
+    }
+
+    ///Replaces the asset information of the shell with the identifier.
+    ///
+    /// [id]: identifier of the shell
+    /// [asset_information]: new asset information
+    pub fn put_asset_information(&self, id: &str, asset_information: &AssetInformation) -> Result<(), ClientError> {
+        self.send_json("PUT", &format!("/shells/{}/asset-information", encode_id(id)), asset_information).map(|_| ())
+    }
+
+    ///Returns a page of the references to the submodels of the shell with the identifier.
+    ///
+    /// [id]: identifier of the shell
+    /// [limit]: maximum number of references of the page, None for the default of the server
+    /// [cursor]: cursor of the page, None for the first page
+    pub fn get_submodel_references(&self, id: &str, limit: Option<usize>, cursor: Option<&str>)
+                                   -> Result<PagedResult<Reference>, ClientError> {
+        self.get_page(&format!("/shells/{}/submodel-refs", encode_id(id)), Vec::new(), limit, cursor)
+    }
+
+    ///Adds a reference to a submodel to the shell with the identifier.
+    ///
+    /// [id]: identifier of the shell
+    /// [reference]: model reference to the submodel
+    pub fn post_submodel_reference(&self, id: &str, reference: &Reference) -> Result<Reference, ClientError> {
+        self.send_json("POST", &format!("/shells/{}/submodel-refs", encode_id(id)), reference).and_then(|body| parse(&body))
+    }
+
+    ///Removes the reference to the submodel from the shell with the identifier.
+    ///
+    /// [id]: identifier of the shell
+    /// [submodel_id]: identifier of the referenced submodel
+    pub fn delete_submodel_reference(&self, id: &str, submodel_id: &str) -> Result<(), ClientError> {
+        self.send("DELETE", &format!("/shells/{}/submodel-refs/{}", encode_id(id), encode_id(submodel_id)), Vec::new(), None)
+            .map(|_| ())
+    }
+
+    ///Returns a page of the submodels of the repository.
+    ///
+    /// [semantic_id]: semantic ID the submodels must have, None for all submodels
+    /// [id_short]: idShort the submodels must have, None for all submodels
+    /// [level]: depth of the elements of the submodels
+    /// [extent]: whether the values of blobs are contained
+    /// [limit]: maximum number of submodels of the page, None for the default of the server
+    /// [cursor]: cursor of the page, None for the first page
+    pub fn get_submodels(&self, semantic_id: Option<&str>, id_short: Option<&str>, level: Level, extent: Extent, limit: Option<usize>,
+                         cursor: Option<&str>) -> Result<PagedResult<Submodel>, ClientError> {
+        let mut query = modifiers(level, extent);
+        if let Some(semantic_id) = semantic_id {
+            query.push(("semanticId", encode_id(semantic_id)));
+        }
+        if let Some(id_short) = id_short {
+            query.push(("idShort", id_short.to_string()));
+        }
+        self.get_page("/submodels", query, limit, cursor)
+    }
+
+    ///Returns the submodel with the identifier.
+    ///
+    /// [id]: identifier of the submodel
+    /// [level]: depth of the elements of the submodel
+    /// [extent]: whether the values of blobs are contained
+    pub fn get_submodel(&self, id: &str, level: Level, extent: Extent) -> Result<Submodel, ClientError> {
+        self.get_json(&format!("/submodels/{}", encode_id(id)), modifiers(level, extent))
+    }
+
+    ///Returns the submodel with the identifier without its elements.
+    ///
+    /// [id]: identifier of the submodel
+    pub fn get_submodel_metadata(&self, id: &str) -> Result<Submodel, ClientError> {
+        self.get_json(&format!("/submodels/{}/$metadata", encode_id(id)), Vec::new())
+    }
+
+    ///Returns the value-only serialization of the submodel with the identifier.
+    ///
+    /// [id]: identifier of the submodel
+    /// [level]: depth of the elements of the submodel
+    /// [extent]: whether the values of blobs are contained
+    pub fn get_submodel_value(&self, id: &str, level: Level, extent: Extent) -> Result<Value, ClientError> {
+        self.get_json(&format!("/submodels/{}/$value", encode_id(id)), modifiers(level, extent))
+    }
+
+    ///Returns the model reference to the submodel with the identifier.
+    ///
+    /// [id]: identifier of the submodel
+    pub fn get_submodel_reference(&self, id: &str) -> Result<Reference, ClientError> {
+        self.get_json(&format!("/submodels/{}/$reference", encode_id(id)), Vec::new())
+    }
+
+    ///Returns the idShortPaths of the elements of the submodel with the identifier.
+    ///
+    /// [id]: identifier of the submodel
+    /// [level]: `Core` for the paths of the top level elements and their children only
+    pub fn get_submodel_paths(&self, id: &str, level: Level) -> Result<Vec<String>, ClientError> {
+        self.get_json(&format!("/submodels/{}/$path", encode_id(id)), vec![("level", level.get_name().to_string())])
+    }
+
+    ///Creates a submodel and returns the submodel created by the server.
+    ///
+    /// [submodel]: submodel to create
+    pub fn post_submodel(&self, submodel: &Submodel) -> Result<Submodel, ClientError> {
+        self.send_json("POST", "/submodels", submodel).and_then(|body| parse(&body))
+    }
+
+    ///Replaces or creates the submodel with the identifier of the submodel.
+    ///
+    /// [submodel]: new submodel
+    pub fn put_submodel(&self, submodel: &Submodel) -> Result<(), ClientError> {
+        self.send_json("PUT", &format!("/submodels/{}", encode_id(submodel.get_id())), submodel).map(|_| ())
+    }
+
+    ///Updates the existing submodel with the identifier of the submodel.
+    ///
+    /// [submodel]: new submodel
+    pub fn patch_submodel(&self, submodel: &Submodel) -> Result<(), ClientError> {
+        self.send_json("PATCH", &format!("/submodels/{}", encode_id(submodel.get_id())), submodel).map(|_| ())
+    }
+
+    ///Updates the values of the elements of the submodel with the identifier.
+    ///
+    /// [id]: identifier of the submodel
+    /// [value]: value-only serialization of the elements to update, keyed by idShort
+    pub fn patch_submodel_value(&self, id: &str, value: &Value) -> Result<(), ClientError> {
+        self.send_json("PATCH", &format!("/submodels/{}/$value", encode_id(id)), value).map(|_| ())
+    }
+
+    ///Deletes the submodel with the identifier.
+    ///
+    /// [id]: identifier of the submodel
+    pub fn delete_submodel(&self, id: &str) -> Result<(), ClientError> {
+        self.send("DELETE", &format!("/submodels/{}", encode_id(id)), Vec::new(), None).map(|_| ())
+    }
+
+    ///Returns a page of the top level elements of the submodel with the identifier.
+    ///
+    /// [id]: identifier of the submodel
+    /// [level]: depth of the nested elements
+    /// [extent]: whether the values of blobs are contained
+    /// [limit]: maximum number of elements of the page, None for the default of the server
+    /// [cursor]: cursor of the page, None for the first page
+    pub fn get_submodel_elements(&self, id: &str, level: Level, extent: Extent, limit: Option<usize>, cursor: Option<&str>)
+                                 -> Result<PagedResult<SubmodelElement>, ClientError> {
+        self.get_page(&format!("/submodels/{}/submodel-elements", encode_id(id)), modifiers(level, extent), limit, cursor)
+    }
+
+    ///Returns the element at the idShortPath of the submodel with the identifier.
+    ///
+    /// [id]: identifier of the submodel
+    /// [id_short_path]: idShortPath of the element, e.g. `Collection.List[2].Property`
+    /// [level]: depth of the nested elements
+    /// [extent]: whether the values of blobs are contained
+    pub fn get_submodel_element(&self, id: &str, id_short_path: &str, level: Level, extent: Extent)
+                                -> Result<SubmodelElement, ClientError> {
+        self.get_json(&element_path(id, id_short_path, ""), modifiers(level, extent))
+    }
+
+    ///Returns the value-only serialization of the element at the idShortPath.
+    ///
+    /// [id]: identifier of the submodel
+    /// [id_short_path]: idShortPath of the element
+    /// [extent]: whether the values of blobs are contained
+    pub fn get_submodel_element_value(&self, id: &str, id_short_path: &str, extent: Extent) -> Result<Value, ClientError> {
+        self.get_json(&element_path(id, id_short_path, "/$value"), modifiers(Level::Deep, extent))
+    }
+
+    ///Returns the model reference to the element at the idShortPath.
+    ///
+    /// [id]: identifier of the submodel
+    /// [id_short_path]: idShortPath of the element
+    pub fn get_submodel_element_reference(&self, id: &str, id_short_path: &str) -> Result<Reference, ClientError> {
+        self.get_json(&element_path(id, id_short_path, "/$reference"), Vec::new())
+    }
+
+    ///Creates an element and returns the element created by the server.
+    ///
+    /// [id]: identifier of the submodel
+    /// [parent_path]: idShortPath of the collection, list or entity that contains the new
+    /// element, None for a top level element
+    /// [element]: element to create
+    pub fn post_submodel_element(&self, id: &str, parent_path: Option<&str>, element: &SubmodelElement)
+                                 -> Result<SubmodelElement, ClientError> {
+        let path = match parent_path {
+            Some(parent_path) => element_path(id, parent_path, ""),
+            None => format!("/submodels/{}/submodel-elements", encode_id(id))
+        };
+        self.send_json("POST", &path, element).and_then(|body| parse(&body))
+    }
+
+    ///Replaces the element at the idShortPath.
+    ///
+    /// [id]: identifier of the submodel
+    /// [id_short_path]: idShortPath of the element
+    /// [element]: new element
+    pub fn put_submodel_element(&self, id: &str, id_short_path: &str, element: &SubmodelElement) -> Result<(), ClientError> {
+        self.send_json("PUT", &element_path(id, id_short_path, ""), element).map(|_| ())
+    }
+
+    ///Updates the value of the element at the idShortPath.
+    ///
+    /// [id]: identifier of the submodel
+    /// [id_short_path]: idShortPath of the element
+    /// [value]: value-only serialization of the new value
+    pub fn patch_submodel_element_value(&self, id: &str, id_short_path: &str, value: &Value) -> Result<(), ClientError> {
+        self.send_json("PATCH", &element_path(id, id_short_path, "/$value"), value).map(|_| ())
+    }
+
+    ///Deletes the element at the idShortPath.
+    ///
+    /// [id]: identifier of the submodel
+    /// [id_short_path]: idShortPath of the element
+    pub fn delete_submodel_element(&self, id: &str, id_short_path: &str) -> Result<(), ClientError> {
+        self.send("DELETE", &element_path(id, id_short_path, ""), Vec::new(), None).map(|_| ())
+    }
+
+    ///Returns the content type and the content of the file or blob at the idShortPath.
+    ///
+    /// [id]: identifier of the submodel
+    /// [id_short_path]: idShortPath of the file or blob
+    pub fn get_attachment(&self, id: &str, id_short_path: &str) -> Result<(String, Vec<u8>), ClientError> {
+        let (content_type, content) = self.send("GET", &element_path(id, id_short_path, "/attachment"), Vec::new(), None)?;
+        Ok((content_type.unwrap_or_else(|| "application/octet-stream".to_string()), content))
+    }
+
+    ///Uploads the content of the file or blob at the idShortPath as `multipart/form-data`.
+    ///
+    /// [id]: identifier of the submodel
+    /// [id_short_path]: idShortPath of the file or blob
+    /// [file_name]: name of the file, e.g. `manual.pdf`
+    /// [content_type]: content type of the content, e.g. `application/pdf`
+    /// [content]: content of the file
+    pub fn put_attachment(&self, id: &str, id_short_path: &str, file_name: &str, content_type: &str, content: &[u8])
+                          -> Result<(), ClientError> {
+        let mut boundary = "aas-model-rs-attachment".to_string();
+        while content.windows(boundary.len()).any(|window| window == boundary.as_bytes()) {
+            boundary.push('-');
+        }
+        let file_name = file_name.replace(['"', '\r', '\n'], "_");
+        let mut body = format!("--{0}\r\nContent-Disposition: form-data; name=\"fileName\"\r\n\r\n{1}\r\n--{0}\r\n\
+                                Content-Disposition: form-data; name=\"file\"; filename=\"{1}\"\r\nContent-Type: {2}\r\n\r\n",
+                               boundary, file_name, content_type).into_bytes();
+        body.extend_from_slice(content);
+        body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+        let content_type = format!("multipart/form-data; boundary={}", boundary);
+        self.send("PUT", &element_path(id, id_short_path, "/attachment"), Vec::new(), Some((&content_type, body))).map(|_| ())
+    }
+
+    ///Deletes the content of the file or blob at the idShortPath.
+    ///
+    /// [id]: identifier of the submodel
+    /// [id_short_path]: idShortPath of the file or blob
+    pub fn delete_attachment(&self, id: &str, id_short_path: &str) -> Result<(), ClientError> {
+        self.send("DELETE", &element_path(id, id_short_path, "/attachment"), Vec::new(), None).map(|_| ())
+    }
+
+    ///Returns a page of the concept descriptions of the repository.
+    ///
+    /// [id_short]: idShort the concept descriptions must have, None for all concept descriptions
+    /// [limit]: maximum number of concept descriptions of the page, None for the default of the
+    /// server
+    /// [cursor]: cursor of the page, None for the first page
+    pub fn get_concept_descriptions(&self, id_short: Option<&str>, limit: Option<usize>, cursor: Option<&str>)
+                                    -> Result<PagedResult<ConceptDescription>, ClientError> {
+        let query = id_short.map(|id_short| vec![("idShort", id_short.to_string())]).unwrap_or_default();
+        self.get_page("/concept-descriptions", query, limit, cursor)
+    }
+
+    ///Returns the concept description with the identifier.
+    ///
+    /// [id]: identifier of the concept description
+    pub fn get_concept_description(&self, id: &str) -> Result<ConceptDescription, ClientError> {
+        self.get_json(&format!("/concept-descriptions/{}", encode_id(id)), Vec::new())
+    }
+
+    ///Creates a concept description and returns the concept description created by the server.
+    ///
+    /// [concept_description]: concept description to create
+    pub fn post_concept_description(&self, concept_description: &ConceptDescription) -> Result<ConceptDescription, ClientError> {
+        self.send_json("POST", "/concept-descriptions", concept_description).and_then(|body| parse(&body))
+    }
+
+    ///Replaces or creates the concept description with the identifier of the concept description.
+    ///
+    /// [concept_description]: new concept description
+    pub fn put_concept_description(&self, concept_description: &ConceptDescription) -> Result<(), ClientError> {
+        self.send_json("PUT", &format!("/concept-descriptions/{}", encode_id(concept_description.get_id())), concept_description)
+            .map(|_| ())
+    }
+
+    ///Deletes the concept description with the identifier.
+    ///
+    /// [id]: identifier of the concept description
+    pub fn delete_concept_description(&self, id: &str) -> Result<(), ClientError> {
+        self.send("DELETE", &format!("/concept-descriptions/{}", encode_id(id)), Vec::new(), None).map(|_| ())
+    }
+
+    ///Returns a page of the shell descriptors of the registry. The descriptors are read as `T`,
+    /// e.g. `serde_json::Value`.
+    ///
+    /// [limit]: maximum number of descriptors of the page, None for the default of the server
+    /// [cursor]: cursor of the page, None for the first page
+    pub fn get_shell_descriptors<T: DeserializeOwned>(&self, limit: Option<usize>, cursor: Option<&str>)
+                                                      -> Result<PagedResult<T>, ClientError> {
+        self.get_page("/shell-descriptors", Vec::new(), limit, cursor)
+    }
+
+    ///Returns the descriptor of the shell with the identifier.
+    ///
+    /// [id]: identifier of the shell
+    pub fn get_shell_descriptor<T: DeserializeOwned>(&self, id: &str) -> Result<T, ClientError> {
+        self.get_json(&format!("/shell-descriptors/{}", encode_id(id)), Vec::new())
+    }
+
+    ///Registers a shell descriptor and returns the descriptor created by the registry.
+    ///
+    /// [descriptor]: descriptor to register
+    pub fn post_shell_descriptor<T: Serialize + DeserializeOwned>(&self, descriptor: &T) -> Result<T, ClientError> {
+        self.send_json("POST", "/shell-descriptors", descriptor).and_then(|body| parse(&body))
+    }
+
+    ///Replaces or registers the descriptor of the shell with the identifier.
+    ///
+    /// [id]: identifier of the shell
+    /// [descriptor]: new descriptor
+    pub fn put_shell_descriptor<T: Serialize>(&self, id: &str, descriptor: &T) -> Result<(), ClientError> {
+        self.send_json("PUT", &format!("/shell-descriptors/{}", encode_id(id)), descriptor).map(|_| ())
+    }
+
+    ///Unregisters the descriptor of the shell with the identifier.
+    ///
+    /// [id]: identifier of the shell
+    pub fn delete_shell_descriptor(&self, id: &str) -> Result<(), ClientError> {
+        self.send("DELETE", &format!("/shell-descriptors/{}", encode_id(id)), Vec::new(), None).map(|_| ())
+    }
+
+    ///Returns a page of the submodel descriptors of the registry. The descriptors are read as `T`,
+    /// e.g. `serde_json::Value`.
+    ///
+    /// [limit]: maximum number of descriptors of the page, None for the default of the server
+    /// [cursor]: cursor of the page, None for the first page
+    pub fn get_submodel_descriptors<T: DeserializeOwned>(&self, limit: Option<usize>, cursor: Option<&str>)
+                                                         -> Result<PagedResult<T>, ClientError> {
+        self.get_page("/submodel-descriptors", Vec::new(), limit, cursor)
+    }
+
+    ///Returns the descriptor of the submodel with the identifier.
+    ///
+    /// [id]: identifier of the submodel
+    pub fn get_submodel_descriptor<T: DeserializeOwned>(&self, id: &str) -> Result<T, ClientError> {
+        self.get_json(&format!("/submodel-descriptors/{}", encode_id(id)), Vec::new())
+    }
+
+    ///Registers a submodel descriptor and returns the descriptor created by the registry.
+    ///
+    /// [descriptor]: descriptor to register
+    pub fn post_submodel_descriptor<T: Serialize + DeserializeOwned>(&self, descriptor: &T) -> Result<T, ClientError> {
+        self.send_json("POST", "/submodel-descriptors", descriptor).and_then(|body| parse(&body))
+    }
+
+    ///Replaces or registers the descriptor of the submodel with the identifier.
+    ///
+    /// [id]: identifier of the submodel
+    /// [descriptor]: new descriptor
+    pub fn put_submodel_descriptor<T: Serialize>(&self, id: &str, descriptor: &T) -> Result<(), ClientError> {
+        self.send_json("PUT", &format!("/submodel-descriptors/{}", encode_id(id)), descriptor).map(|_| ())
+    }
+
+    ///Unregisters the descriptor of the submodel with the identifier.
+    ///
+    /// [id]: identifier of the submodel
+    pub fn delete_submodel_descriptor(&self, id: &str) -> Result<(), ClientError> {
+        self.send("DELETE", &format!("/submodel-descriptors/{}", encode_id(id)), Vec::new(), None).map(|_| ())
+    }
+
+    ///Returns a page of the identifiers of the shells linked to all the asset IDs by the discovery
+    /// service.
+    ///
+    /// [asset_ids]: asset IDs the shells must all be linked to; the name `globalAssetId` denotes
+    /// the global asset ID
+    /// [limit]: maximum number of identifiers of the page, None for the default of the server
+    /// [cursor]: cursor of the page, None for the first page
+    pub fn lookup_shells(&self, asset_ids: &[SpecificAssetId], limit: Option<usize>, cursor: Option<&str>)
+                         -> Result<PagedResult<String>, ClientError> {
+        let query = asset_ids.iter().map(|asset_id| encode_json(asset_id).map(|asset_id| ("assetIds", asset_id)))
+            .collect::<Result<Vec<(&str, String)>, ClientError>>()?;
+        self.get_page("/lookup/shells", query, limit, cursor)
+    }
+
+    ///Returns the asset IDs linked to the shell with the identifier by the discovery service.
+    ///
+    /// [id]: identifier of the shell
+    pub fn get_asset_links(&self, id: &str) -> Result<Vec<SpecificAssetId>, ClientError> {
+        self.get_json(&format!("/lookup/shells/{}", encode_id(id)), Vec::new())
+    }
+
+    ///Links the asset IDs to the shell with the identifier and returns the links created by the
+    /// discovery service.
+    ///
+    /// [id]: identifier of the shell
+    /// [asset_ids]: asset IDs to link
+    pub fn post_asset_links(&self, id: &str, asset_ids: &[SpecificAssetId]) -> Result<Vec<SpecificAssetId>, ClientError> {
+        self.send_json("POST", &format!("/lookup/shells/{}", encode_id(id)), &asset_ids).and_then(|body| parse(&body))
+    }
+
+    ///Removes all asset IDs linked to the shell with the identifier from the discovery service.
+    ///
+    /// [id]: identifier of the shell
+    pub fn delete_asset_links(&self, id: &str) -> Result<(), ClientError> {
+        self.send("DELETE", &format!("/lookup/shells/{}", encode_id(id)), Vec::new(), None).map(|_| ())
+    }
+
+    ///Sends a GET request and deserializes the JSON body of the response.
+    fn get_json<T: DeserializeOwned>(&self, path: &str, query: Vec<(&str, String)>) -> Result<T, ClientError> {
+        let (_, body) = self.send("GET", path, query, None)?;
+        parse(&body)
+    }
+
+    ///Sends a GET request for a page of a list and deserializes the items of the page.
+    fn get_page<T: DeserializeOwned>(&self, path: &str, mut query: Vec<(&str, String)>, limit: Option<usize>, cursor: Option<&str>)
+                                     -> Result<PagedResult<T>, ClientError> {
+        if let Some(limit) = limit {
+            query.push(("limit", limit.to_string()));
+        }
+        if let Some(cursor) = cursor {
+            query.push(("cursor", cursor.to_string()));
+        }
+        let page: Value = self.get_json(path, query)?;
+        let cursor = page.pointer("/paging_metadata/cursor").and_then(|cursor| cursor.as_str()).map(|cursor| cursor.to_string());
+        let result = page.get("result").cloned().ok_or_else(|| ClientError::InvalidResponse("the page has no result".to_string()))?;
+        let result = serde_json::from_value(result).map_err(|error| ClientError::InvalidResponse(error.to_string()))?;
+        Ok(PagedResult::new(result, cursor))
+    }
+
+    ///Sends a request with a JSON body and returns the body of the response.
+    fn send_json<T: Serialize + ?Sized>(&self, method: &str, path: &str, value: &T) -> Result<Vec<u8>, ClientError> {
+        let body = serde_json::to_vec(value).map_err(|error| ClientError::InvalidResponse(error.to_string()))?;
+        self.send(method, path, Vec::new(), Some(("application/json", body))).map(|(_, body)| body)
+    }
+
+    ///Sends a request and returns the content type and the body of a successful response.
+    ///
+    /// [method]: HTTP method, e.g. `GET`
+    /// [path]: path relative to the base URL, whose components are already encoded
+    /// [query]: query parameters, which are percent-encoded
+    /// [content]: content type and body of the request
+    fn send(&self, method: &str, path: &str, query: Vec<(&str, String)>, content: Option<(&str, Vec<u8>)>)
+            -> Result<(Option<String>, Vec<u8>), ClientError> {
+        let (scheme, authority, _) = split_uri(&self.base_url).ok_or_else(|| ClientError::InvalidUrl(self.base_url.clone()))?;
+        if !scheme.eq_ignore_ascii_case("http") && !scheme.eq_ignore_ascii_case("https") {
+            return Err(ClientError::UnsupportedProtocol(scheme.to_string()));
+        }
+        let mut url = format!("{}{}", self.base_url.trim_end_matches('/'), path);
+        for (index, (name, value)) in query.iter().enumerate() {
+            url.push(if index == 0 { '?' } else { '&' });
+            url.push_str(&format!("{}={}", percent_encode(name), percent_encode(value)));
+        }
+        let mut request = AGENT.get_or_init(Agent::new).request(method, &url)
+            .timeout(self.timeout)
+            .set("Accept", "application/json");
+        for (name, value) in &self.headers {
+            request = request.set(name, value);
+        }
+        let result = match content {
+            Some((content_type, body)) => request.set("Content-Type", content_type).send_bytes(&body),
+            None => request.call()
+        };
+        let response = match result {
+            Ok(response) => response,
+            Err(ureq::Error::Status(status, response)) => {
+                let body = read_body(response)?;
+                let result = serde_json::from_slice(&body).unwrap_or_else(|_| {
+                    let text = String::from_utf8_lossy(&body).trim().to_string();
+                    match text.is_empty() {
+                        true => ServiceResult::new(),
+                        false => ServiceResult::error(status.to_string(), text)
+                    }
+                });
+                return Err(ClientError::Status(status, result));
+            },
+            Err(ureq::Error::Transport(transport)) => return Err(transport_error(authority, transport))
+        };
+        let content_type = response.header("Content-Type").map(|content_type| content_type.to_string());
+        Ok((content_type, read_body(response)?))
+    }
+}
+
+///Agent shared by all clients, which pools the connections to the servers.
+static AGENT: OnceLock<Agent> = OnceLock::new();
+
+///Reads the body of a response.
+fn read_body(response: ureq::Response) -> Result<Vec<u8>, ClientError> {
+    let mut body = Vec::new();
+    response.into_reader().read_to_end(&mut body).map_err(|error| ClientError::InvalidResponse(error.to_string()))?;
+    Ok(body)
+}
+
+///Converts an error of connecting to or communicating with the server, mapping timeouts to
+/// `ClientError::Timeout`.
+///
+/// [authority]: host and port of the server
+/// [transport]: error of the transport
+fn transport_error(authority: &str, transport: ureq::Transport) -> ClientError {
+    let is_timeout = transport.source().and_then(|source| source.downcast_ref::<std::io::Error>())
+        .is_some_and(|error| matches!(error.kind(), std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut));
+    match transport.kind() {
+        _ if is_timeout => ClientError::Timeout,
+        ureq::ErrorKind::InvalidUrl => ClientError::InvalidUrl(transport.url().map(|url| url.to_string()).unwrap_or_default()),
+        ureq::ErrorKind::UnknownScheme => ClientError::UnsupportedProtocol(transport.to_string()),
+        ureq::ErrorKind::BadStatus | ureq::ErrorKind::BadHeader => ClientError::InvalidResponse(transport.to_string()),
+        _ => ClientError::ConnectionFailed(authority.to_string(), transport.to_string())
+    }
+}
+
+///Returns the modifiers of a request as query parameters.
+fn modifiers(level: Level, extent: Extent) -> Vec<(&'static str, String)> {
+    vec![("level", level.get_name().to_string()), ("extent", extent.get_name().to_string())]
+}
+
+fn parse<T: DeserializeOwned>(body: &[u8]) -> Result<T, ClientError> {
+    serde_json::from_slice(body).map_err(|error| ClientError::InvalidResponse(error.to_string()))
+}
+
+///Returns the base64url encoded identifier for a path or query.
+fn encode_id(id: &str) -> String {
+    encode_base64(id.as_bytes(), true)
+}
+
+///Returns the base64url encoded JSON serialization of a value for a query, e.g. of an asset ID.
+fn encode_json<T: Serialize>(value: &T) -> Result<String, ClientError> {
+    serde_json::to_string(value).map(|json| encode_id(&json)).map_err(|error| ClientError::InvalidResponse(error.to_string()))
+}
+
+///Returns the path of an element of a submodel with the suffix, e.g. `/$value`.
+fn element_path(id: &str, id_short_path: &str, suffix: &str) -> String {
+    format!("/submodels/{}/submodel-elements/{}{}", encode_id(id), percent_encode(id_short_path), suffix)
+}
+
+///Percent-encodes all characters except the unreserved characters of RFC 3986.
+fn percent_encode(text: &str) -> String {
+    text.bytes().map(|byte| match byte {
+        b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (byte as char).to_string(),
+        _ => format!("%{:02X}", byte)
+    }).collect()
+}
+
+#[cfg(all(test, feature = "server"))]
+mod tests {
+    use std::net::TcpListener;
+
+    use serde_json::json;
+
+    use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+    use crate::enumerations::asset_kind::AssetKind;
+    use crate::enumerations::client_error::ClientError;
+    use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+    use crate::enumerations::extent::Extent;
+    use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+    use crate::enumerations::level::Level;
+    use crate::structs::aas_client::AasClient;
+    use crate::structs::asset_administration_shell::AssetAdministrationShell;
+    use crate::structs::asset_information::AssetInformation;
+    use crate::structs::file::File;
+    use crate::structs::memory_storage::MemoryStorage;
+    use crate::structs::paged_result::PagedResult;
+    use crate::structs::property::Property;
+    use crate::structs::repository_server::RepositoryServer;
+    use crate::structs::submodel::Submodel;
+    use crate::structs::submodel_element_list::SubmodelElementList;
+    use crate::traits::identifiable::TIdentifiable;
+    use crate::traits::referable::TReferable;
+
+    ///Serves an empty repository on a loopback port and returns a client of it.
+    fn serve() -> AasClient {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+            runtime.block_on(async {
+                let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+                sender.send(listener.local_addr().unwrap()).unwrap();
+                axum::serve(listener, RepositoryServer::new(MemoryStorage::new()).router()).await.unwrap();
+            });
+        });
+        AasClient::new(format!("http://{}/", receiver.recv().unwrap()))
+    }
+
+    fn shell(id: &str) -> AssetAdministrationShell {
+        AssetAdministrationShell::new(id.to_string(), AssetInformation::new(AssetKind::Instance))
+    }
+
+    fn submodel() -> Submodel {
+        let mut temperature = Property::new(DataTypeDefXsd::Int);
+        temperature.set_id_short("Temperature".to_string());
+        temperature.set_value("21".to_string());
+        let mut manual = File::new();
+        manual.set_id_short("Manual".to_string());
+        let mut list = SubmodelElementList::new(AasSubmodelElements::Property);
+        list.set_id_short("Speeds".to_string());
+        list.set_value(vec![SubmodelElement::Property(Property::new(DataTypeDefXsd::Int))]);
+        let mut submodel = Submodel::new("urn:example:submodel/1".to_string());
+        submodel.set_submodel_elements(vec![SubmodelElement::Property(temperature), SubmodelElement::File(manual),
+                                            SubmodelElement::SubmodelElementList(list)]);
+        submodel
+    }
+
+    #[test]
+    fn creates_reads_and_deletes_shells() {
+        let client = serve();
+        for id in ["urn:example:aas/1", "urn:example:aas/2", "urn:example:aas/3"] {
+            assert!(client.post_shell(&shell(id)).unwrap().get_id() == id);
+        }
+        assert!(client.get_shell("urn:example:aas/2").unwrap() == shell("urn:example:aas/2"));
+        let page = client.get_shells(None, &[], Some(2), None).unwrap();
+        assert_eq!(page.get_result().len(), 2);
+        assert!(page.get_cursor().is_some());
+        let shells = PagedResult::collect_all(|cursor| client.get_shells(None, &[], Some(2), cursor)).unwrap();
+        assert_eq!(shells.len(), 3);
+        client.delete_shell("urn:example:aas/1").unwrap();
+        assert!(matches!(client.get_shell("urn:example:aas/1"), Err(ClientError::Status(404, _))));
+    }
+
+    #[test]
+    fn reads_and_updates_element_values() {
+        let client = serve();
+        client.post_submodel(&submodel()).unwrap();
+        let id = "urn:example:submodel/1";
+        assert_eq!(client.get_submodel_element_value(id, "Temperature", Extent::WithBlobValue).unwrap(), json!(21));
+        client.patch_submodel_element_value(id, "Speeds[0]", &json!(1200)).unwrap();
+        assert_eq!(client.get_submodel_value(id, Level::Deep, Extent::WithBlobValue).unwrap()["Speeds"], json!([1200]));
+        match client.patch_submodel_element_value(id, "Temperature", &json!("warm")) {
+            Err(ClientError::Status(400, result)) => assert!(!result.get_messages().is_empty()),
+            _ => panic!("expected status 400")
+        }
+        assert_eq!(client.get_submodel_paths(id, Level::Deep).unwrap(), vec!["Temperature", "Manual", "Speeds", "Speeds[0]"]);
+    }
+
+    #[test]
+    fn uploads_and_downloads_attachments() {
+        let client = serve();
+        client.put_submodel(&submodel()).unwrap();
+        let id = "urn:example:submodel/1";
+        client.put_attachment(id, "Manual", "manual.txt", "text/plain", b"Read me\r\n").unwrap();
+        assert_eq!(client.get_attachment(id, "Manual").unwrap(), ("text/plain".to_string(), b"Read me\r\n".to_vec()));
+        client.delete_attachment(id, "Manual").unwrap();
+        assert!(matches!(client.get_attachment(id, "Manual"), Err(ClientError::Status(404, _))));
+    }
+
+    #[test]
+    fn rejects_header_with_line_break() {
+        let mut client = AasClient::new("http://localhost:8081".to_string());
+        assert_eq!(client.add_header("Authorization".to_string(), "Bearer token\r\nX-Injected: 1".to_string()),
+                   Err(ClientError::InvalidHeader("Authorization".to_string())));
+        assert_eq!(client.add_header("X-Name\n".to_string(), "value".to_string()), Err(ClientError::InvalidHeader("X-Name\n".to_string())));
+        assert_eq!(client.add_header("Authorization".to_string(), "Bearer token".to_string()), Ok(()));
+        assert_eq!(client.get_headers().len(), 1);
+    }
+
+    #[test]
+    fn reports_unsupported_protocol_and_unreachable_server() {
+        let client = AasClient::new("ftp://localhost/api".to_string());
+        assert_eq!(client.get_shell("urn:example:aas/1").err(), Some(ClientError::UnsupportedProtocol("ftp".to_string())));
+        let address = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+        for scheme in ["http", "https"] {
+            let client = AasClient::new(format!("{}://{}", scheme, address));
+            assert!(matches!(client.get_shell("urn:example:aas/1"), Err(ClientError::ConnectionFailed(..))));
+        }
+    }
+}
\ No newline at end of file
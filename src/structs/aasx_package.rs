@@ -0,0 +1,68 @@
+use std::io::{Cursor, Read};
+use std::path::Path;
+use std::sync::Arc;
+
+use zip::result::ZipError;
+use zip::ZipArchive;
+
+use crate::enumerations::file_resolution_error::FileResolutionError;
+use crate::traits::file_store::{relative_segments, TFileStore};
+
+///AASX package, i.e. the zip archive of the Open Packaging Conventions containing an environment
+/// and the supplementary files its file elements refer to. The paths of file elements are part
+/// names of the package, e.g. `/aasx/files/manual.pdf`.
+///
+/// Requires the `aasx` feature.
+#[derive(Clone)]
+pub struct AasxPackage {
+    ///The opened archive.
+    archive: ZipArchive<Cursor<Arc<[u8]>>>
+}
+
+impl AasxPackage {
+    ///Reads an AASX package from a file.
+    ///
+    /// [path]: location of the package in the file system
+    pub fn open(path: &Path) -> Result<AasxPackage, FileResolutionError> {
+        let data = std::fs::read(path)
+            .map_err(|error| FileResolutionError::ReadFailed(path.display().to_string(), error.to_string()))?;
+        AasxPackage::from_bytes(data)
+    }
+
+    ///Reads an AASX package from its content.
+    ///
+    /// [data]: content of the package
+    pub fn from_bytes(data: Vec<u8>) -> Result<AasxPackage, FileResolutionError> {
+        let archive = ZipArchive::new(Cursor::new(Arc::from(data)))
+            .map_err(|error| FileResolutionError::InvalidPackage(error.to_string()))?;
+        Ok(AasxPackage {
+            archive
+        })
+    }
+
+    ///Returns the names of the parts of the package, e.g. `/aasx/files/manual.pdf`.
+    pub fn get_part_names(&self) -> Vec<String> {
+        self.archive.file_names()
+            .filter(|name| !name.ends_with('/'))
+            .map(|name| format!("/{}", name))
+            .collect()
+    }
+}
+
+impl TFileStore for AasxPackage {
+    fn read_file(&self, path: &str) -> Result<Vec<u8>, FileResolutionError> {
+        let name = relative_segments(path).ok_or_else(|| FileResolutionError::InvalidPath(path.to_string()))?.join("/");
+        let mut archive = self.archive.clone();
+        let mut part = archive.by_name(&name).map_err(|error| match error {
+            ZipError::FileNotFound => FileResolutionError::FileNotFound(path.to_string()),
+            error => FileResolutionError::ReadFailed(path.to_string(), error.to_string())
+        })?;
+        let mut data = Vec::new();
+        part.read_to_end(&mut data).map_err(|error| FileResolutionError::ReadFailed(path.to_string(), error.to_string()))?;
+        Ok(data)
+    }
+
+    fn contains_file(&self, path: &str) -> bool {
+        relative_segments(path).is_some_and(|segments| self.archive.index_for_name(&segments.join("/")).is_some())
+    }
+}
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use crate::structs::embedded_data_specification::EmbeddedDataSpecification;
 use crate::structs::reference::Reference;
 use crate::traits::has_data_specification::THasDataSpecification;
 
@@ -7,16 +8,19 @@ use crate::traits::has_data_specification::THasDataSpecification;
 #[derive(PartialEq, Clone, Serialize, Deserialize)]
 pub struct AdministrativeInformation {
     ///The optional version of the element.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     version: Option<String>,
     ///The optional revision of the element.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     revision: Option<String>,
     ///The optional subject ID of the subject responsible for making the element.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     creator: Option<Reference>,
     ///The optional identifier of the template that guided the creation of the element.
-    #[serde(rename = "templateId")]
+    #[serde(rename = "templateId", default, skip_serializing_if = "Option::is_none")]
     template_id: Option<String>,
-    #[serde(rename = "embeddedDataSpecifications")]
-    data_specifications: Vec<Reference>,
+    #[serde(rename = "embeddedDataSpecifications", default, skip_serializing_if = "Vec::is_empty")]
+    data_specifications: Vec<EmbeddedDataSpecification>,
 }
 
 impl AdministrativeInformation {
@@ -95,26 +99,44 @@ impl AdministrativeInformation {
     pub fn get_mut_template_id(&mut self) -> Option<&mut String> {
         self.template_id.as_mut()
     }
+
+    ///Returns all references contained in the administrative information.
+    pub fn get_references(&self) -> Vec<&Reference> {
+        self.creator.iter()
+            .flat_map(|reference| reference.get_references())
+            .chain(self.data_specifications.iter().flat_map(|data_specification| data_specification.get_references()))
+            .collect()
+    }
+
+    ///Calls the visitor for every reference contained in the administrative information.
+    ///
+    /// [visitor]: function called for every reference
+    pub fn visit_references_mut(&mut self, visitor: &mut dyn FnMut(&mut Reference)) {
+        self.creator.iter_mut()
+            .for_each(|reference| reference.visit_references_mut(visitor));
+        self.data_specifications.iter_mut()
+            .for_each(|data_specification| data_specification.visit_references_mut(visitor));
+    }
 }
 
 impl THasDataSpecification for AdministrativeInformation {
-    fn get_data_specifications(&self) -> &Vec<Reference> {
+    fn get_data_specifications(&self) -> &Vec<EmbeddedDataSpecification> {
         &self.data_specifications
     }
 
-    fn get_mut_data_specifications(&mut self) -> &mut Vec<Reference> {
+    fn get_mut_data_specifications(&mut self) -> &mut Vec<EmbeddedDataSpecification> {
         &mut self.data_specifications
     }
 
-    fn set_data_specifications(&mut self, data_specifications: Vec<Reference>) {
+    fn set_data_specifications(&mut self, data_specifications: Vec<EmbeddedDataSpecification>) {
         self.data_specifications = data_specifications;
     }
 
-    fn add_data_specification(&mut self, data_specification: Reference) {
+    fn add_data_specification(&mut self, data_specification: EmbeddedDataSpecification) {
         self.data_specifications.push(data_specification);
     }
 
-    fn remove_data_specification(&mut self, index: usize) -> Reference {
+    fn remove_data_specification(&mut self, index: usize) -> EmbeddedDataSpecification {
         self.data_specifications.remove(index)
     }
 }
\ No newline at end of file
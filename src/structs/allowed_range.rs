@@ -0,0 +1,108 @@
+use std::fmt::{Display, Formatter};
+
+///Range of numeric values an instance of a submodel template element is allowed to have, e.g.
+/// `[0, 100]`, `(0, 100]` or `0..100`. Square brackets and the `..` notation denote inclusive
+/// bounds, round brackets exclusive ones. Missing bounds are unlimited.
+#[derive(PartialEq, Clone)]
+pub struct AllowedRange {
+    ///Lower bound of the range.
+    min: Option<String>,
+    ///Upper bound of the range.
+    max: Option<String>,
+    ///Whether the lower bound itself belongs to the range.
+    min_inclusive: bool,
+    ///Whether the upper bound itself belongs to the range.
+    max_inclusive: bool
+}
+
+impl AllowedRange {
+    ///Creates a new instance of the struct with inclusive bounds.
+    ///
+    /// [min]: lower bound of the range
+    /// [max]: upper bound of the range
+    pub fn new(min: Option<String>, max: Option<String>) -> AllowedRange {
+        AllowedRange {
+            min,
+            max,
+            min_inclusive: true,
+            max_inclusive: true
+        }
+    }
+
+    ///Parses a range expression. Returns None if the expression is malformed.
+    ///
+    /// [range]: range expression, e.g. `[0, 100]`
+    pub fn parse(range: &str) -> Option<AllowedRange> {
+        let range = range.trim();
+        let min_inclusive = !range.starts_with('(');
+        let max_inclusive = !range.ends_with(')');
+        let bounds = range.strip_prefix(['[', '(']).unwrap_or(range);
+        let bounds = bounds.strip_suffix([']', ')']).unwrap_or(bounds);
+        let (min, max) = bounds.split_once("..").or_else(|| bounds.split_once(','))?;
+        let bound = |bound: &str| Some(bound.trim()).filter(|bound| !bound.is_empty()).map(|bound| bound.to_string());
+        Some(AllowedRange {
+            min: bound(min),
+            max: bound(max),
+            min_inclusive,
+            max_inclusive
+        })
+    }
+
+    ///Sets the lower bound of the range.
+    ///
+    /// [min]: lower bound
+    /// [inclusive]: whether the bound itself belongs to the range
+    pub fn set_min(&mut self, min: String, inclusive: bool) {
+        self.min = Some(min);
+        self.min_inclusive = inclusive;
+    }
+
+    ///Returns the lower bound of the range.
+    pub fn get_min(&self) -> Option<&String> {
+        self.min.as_ref()
+    }
+
+    ///Returns whether the lower bound itself belongs to the range.
+    pub fn is_min_inclusive(&self) -> bool {
+        self.min_inclusive
+    }
+
+    ///Sets the upper bound of the range.
+    ///
+    /// [max]: upper bound
+    /// [inclusive]: whether the bound itself belongs to the range
+    pub fn set_max(&mut self, max: String, inclusive: bool) {
+        self.max = Some(max);
+        self.max_inclusive = inclusive;
+    }
+
+    ///Returns the upper bound of the range.
+    pub fn get_max(&self) -> Option<&String> {
+        self.max.as_ref()
+    }
+
+    ///Returns whether the upper bound itself belongs to the range.
+    pub fn is_max_inclusive(&self) -> bool {
+        self.max_inclusive
+    }
+
+    ///Returns whether the value lies within the range. Values and bounds that are not numeric are
+    /// not checked.
+    ///
+    /// [value]: value to check
+    pub fn contains(&self, value: &str) -> bool {
+        let Ok(value) = value.trim().parse::<f64>() else {
+            return true;
+        };
+        let bound = |bound: &Option<String>| bound.as_ref().and_then(|bound| bound.parse::<f64>().ok());
+        bound(&self.min).is_none_or(|min| if self.min_inclusive { value >= min } else { value > min })
+            && bound(&self.max).is_none_or(|max| if self.max_inclusive { value <= max } else { value < max })
+    }
+}
+
+impl Display for AllowedRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}, {}{}", if self.min_inclusive { '[' } else { '(' }, self.min.as_deref().unwrap_or(""),
+               self.max.as_deref().unwrap_or(""), if self.max_inclusive { ']' } else { ')' })
+    }
+}
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::enumerations::interface_enumerations::data_element::DataElement;
+use crate::structs::embedded_data_specification::EmbeddedDataSpecification;
 use crate::structs::extension::Extension;
 use crate::structs::multi_language_name_type::MultiLanguageNameType;
 use crate::structs::multi_language_text_type::MultiLanguageTextType;
@@ -20,22 +21,29 @@ use crate::traits::submodel_element::TSubmodelElement;
 pub struct AnnotatedRelationshipElement {
     ///Data elements that represent annotations that holds for the relationship between the two
     /// elements.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     annotations: Vec<DataElement>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     category: Option<String>,
-    #[serde(rename = "idShort")]
+    #[serde(rename = "idShort", default, skip_serializing_if = "Option::is_none")]
     id_short: Option<String>,
-    #[serde(rename = "displayName")]
+    #[serde(rename = "displayName", default, skip_serializing_if = "Vec::is_empty")]
     display_name: Vec<MultiLanguageNameType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     description: Vec<MultiLanguageTextType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     extensions: Vec<Extension>,
-    #[serde(rename = "semanticId")]
+    #[serde(rename = "semanticId", default, skip_serializing_if = "Option::is_none")]
     semantic_id: Option<Reference>,
-    #[serde(rename = "supplementalSemanticIds")]
+    #[serde(rename = "supplementalSemanticIds", default, skip_serializing_if = "Vec::is_empty")]
     supplemental_semantic_ids: Vec<Reference>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     qualifiers: Vec<Qualifier>,
-    #[serde(rename = "embeddedDataSpecifications")]
-    data_specifications: Vec<Reference>,
+    #[serde(rename = "embeddedDataSpecifications", default, skip_serializing_if = "Vec::is_empty")]
+    data_specifications: Vec<EmbeddedDataSpecification>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     first: Option<Reference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     second: Option<Reference>,
 }
 
@@ -235,23 +243,23 @@ impl TQualifiable for AnnotatedRelationshipElement {
 }
 
 impl THasDataSpecification for AnnotatedRelationshipElement {
-    fn get_data_specifications(&self) -> &Vec<Reference> {
+    fn get_data_specifications(&self) -> &Vec<EmbeddedDataSpecification> {
         &self.data_specifications
     }
 
-    fn get_mut_data_specifications(&mut self) -> &mut Vec<Reference> {
+    fn get_mut_data_specifications(&mut self) -> &mut Vec<EmbeddedDataSpecification> {
         &mut self.data_specifications
     }
 
-    fn set_data_specifications(&mut self, data_specifications: Vec<Reference>) {
+    fn set_data_specifications(&mut self, data_specifications: Vec<EmbeddedDataSpecification>) {
         self.data_specifications = data_specifications;
     }
 
-    fn add_data_specification(&mut self, data_specification: Reference) {
+    fn add_data_specification(&mut self, data_specification: EmbeddedDataSpecification) {
         self.data_specifications.push(data_specification);
     }
 
-    fn remove_data_specification(&mut self, index: usize) -> Reference {
+    fn remove_data_specification(&mut self, index: usize) -> EmbeddedDataSpecification {
         self.data_specifications.remove(index)
     }
 }
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::structs::administrative_information::AdministrativeInformation;
 use crate::structs::asset_information::AssetInformation;
+use crate::structs::embedded_data_specification::EmbeddedDataSpecification;
 use crate::structs::extension::Extension;
 use crate::structs::multi_language_name_type::MultiLanguageNameType;
 use crate::structs::multi_language_text_type::MultiLanguageTextType;
@@ -13,27 +14,33 @@ use crate::traits::referable::TReferable;
 
 ///A struct representing an asset administration shell.
 #[derive(PartialEq, Clone, Serialize, Deserialize)]
+#[serde(tag = "modelType", rename = "AssetAdministrationShell")]
 pub struct AssetAdministrationShell {
     ///Optional reference to the asset administration shell which the asset administration shell
     /// was derived from.
+    #[serde(rename = "derivedFrom", alias = "derived_from", default, skip_serializing_if = "Option::is_none")]
     derived_from: Option<Reference>,
     ///Meta information about the asset, the asset administration shell is representing.
     #[serde(rename = "assetInformation")]
     asset_information: AssetInformation,
     ///List of references to submodels of the asset administration shell.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     submodels: Vec<Reference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     category: Option<String>,
-    #[serde(rename = "idShort")]
+    #[serde(rename = "idShort", default, skip_serializing_if = "Option::is_none")]
     id_short: Option<String>,
-    #[serde(rename = "displayName")]
+    #[serde(rename = "displayName", default, skip_serializing_if = "Vec::is_empty")]
     display_name: Vec<MultiLanguageNameType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     description: Vec<MultiLanguageTextType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     extensions: Vec<Extension>,
-    #[serde(rename = "administration")]
+    #[serde(rename = "administration", default, skip_serializing_if = "Option::is_none")]
     administrative_information: Option<AdministrativeInformation>,
     id: String,
-    #[serde(rename = "embeddedDataSpecifications")]
-    data_specifications: Vec<Reference>
+    #[serde(rename = "embeddedDataSpecifications", default, skip_serializing_if = "Vec::is_empty")]
+    data_specifications: Vec<EmbeddedDataSpecification>
 }
 
 impl AssetAdministrationShell {
@@ -117,6 +124,33 @@ impl AssetAdministrationShell {
     pub fn remove_submodel(&mut self, index: usize) -> Reference {
         self.submodels.remove(index)
     }
+
+    ///Returns all references contained in the asset administration shell.
+    pub fn get_references(&self) -> Vec<&Reference> {
+        self.derived_from.iter()
+            .chain(self.submodels.iter())
+            .flat_map(|reference| reference.get_references())
+            .chain(self.data_specifications.iter().flat_map(|data_specification| data_specification.get_references()))
+            .chain(self.extensions.iter().flat_map(|extension| extension.get_references()))
+            .chain(self.administrative_information.iter().flat_map(|administration| administration.get_references()))
+            .chain(self.asset_information.get_references())
+            .collect()
+    }
+
+    ///Calls the visitor for every reference contained in the asset administration shell.
+    ///
+    /// [visitor]: function called for every reference
+    pub fn visit_references_mut(&mut self, visitor: &mut dyn FnMut(&mut Reference)) {
+        self.derived_from.iter_mut()
+            .chain(self.submodels.iter_mut())
+            .for_each(|reference| reference.visit_references_mut(visitor));
+        self.data_specifications.iter_mut()
+            .for_each(|data_specification| data_specification.visit_references_mut(visitor));
+        self.extensions.iter_mut().for_each(|extension| extension.visit_references_mut(visitor));
+        self.administrative_information.iter_mut()
+            .for_each(|administration| administration.visit_references_mut(visitor));
+        self.asset_information.visit_references_mut(visitor);
+    }
 }
 
 impl TReferable for AssetAdministrationShell {
@@ -234,23 +268,23 @@ impl TIdentifiable for AssetAdministrationShell {
 }
 
 impl THasDataSpecification for AssetAdministrationShell {
-    fn get_data_specifications(&self) -> &Vec<Reference> {
+    fn get_data_specifications(&self) -> &Vec<EmbeddedDataSpecification> {
         &self.data_specifications
     }
     
-    fn get_mut_data_specifications(&mut self) -> &mut Vec<Reference> {
+    fn get_mut_data_specifications(&mut self) -> &mut Vec<EmbeddedDataSpecification> {
         &mut self.data_specifications
     }
 
-    fn set_data_specifications(&mut self, data_specifications: Vec<Reference>) {
+    fn set_data_specifications(&mut self, data_specifications: Vec<EmbeddedDataSpecification>) {
         self.data_specifications = data_specifications;
     }
 
-    fn add_data_specification(&mut self, data_specification: Reference) {
+    fn add_data_specification(&mut self, data_specification: EmbeddedDataSpecification) {
         self.data_specifications.push(data_specification);
     }
 
-    fn remove_data_specification(&mut self, index: usize) -> Reference {
+    fn remove_data_specification(&mut self, index: usize) -> EmbeddedDataSpecification {
         self.data_specifications.remove(index)
     }
 }
\ No newline at end of file
@@ -0,0 +1,342 @@
+use serde::{Deserialize, Serialize};
+
+use crate::enumerations::asset_kind::AssetKind;
+use crate::structs::administrative_information::AdministrativeInformation;
+use crate::structs::asset_administration_shell::AssetAdministrationShell;
+use crate::structs::blob::encode_base64;
+use crate::structs::endpoint::Endpoint;
+use crate::structs::extension::Extension;
+use crate::structs::multi_language_name_type::MultiLanguageNameType;
+use crate::structs::multi_language_text_type::MultiLanguageTextType;
+use crate::structs::protocol_information::ProtocolInformation;
+use crate::structs::specific_asset_id::SpecificAssetId;
+use crate::structs::submodel_descriptor::SubmodelDescriptor;
+use crate::traits::has_extensions::THasExtensions;
+use crate::traits::identifiable::TIdentifiable;
+use crate::traits::referable::TReferable;
+
+///Descriptor of an asset administration shell in a registry, which describes the shell, its asset,
+/// the endpoints it is reachable at and the descriptors of its submodels.
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
+pub struct AssetAdministrationShellDescriptor {
+    ///Description of the shell in different languages.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    description: Vec<MultiLanguageTextType>,
+    ///Display name of the shell in different languages.
+    #[serde(rename = "displayName", default, skip_serializing_if = "Vec::is_empty")]
+    display_name: Vec<MultiLanguageNameType>,
+    ///Extensions of the descriptor.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    extensions: Vec<Extension>,
+    ///Administrative information of the shell.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    administration: Option<AdministrativeInformation>,
+    ///Kind of the asset represented by the shell.
+    #[serde(rename = "assetKind", default, skip_serializing_if = "Option::is_none")]
+    asset_kind: Option<AssetKind>,
+    ///Type of the asset represented by the shell.
+    #[serde(rename = "assetType", default, skip_serializing_if = "Option::is_none")]
+    asset_type: Option<String>,
+    ///Endpoints the shell is reachable at.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    endpoints: Vec<Endpoint>,
+    ///Global identifier of the asset represented by the shell.
+    #[serde(rename = "globalAssetId", default, skip_serializing_if = "Option::is_none")]
+    global_asset_id: Option<String>,
+    ///Short name of the shell.
+    #[serde(rename = "idShort", default, skip_serializing_if = "Option::is_none")]
+    id_short: Option<String>,
+    ///Global identifier of the shell.
+    id: String,
+    ///Specific asset IDs of the asset represented by the shell.
+    #[serde(rename = "specificAssetIds", default, skip_serializing_if = "Vec::is_empty")]
+    specific_asset_ids: Vec<SpecificAssetId>,
+    ///Descriptors of the submodels of the shell.
+    #[serde(rename = "submodelDescriptors", default, skip_serializing_if = "Vec::is_empty")]
+    submodel_descriptors: Vec<SubmodelDescriptor>
+}
+
+impl AssetAdministrationShellDescriptor {
+    ///Creates a new descriptor without endpoints.
+    ///
+    /// [id]: global identifier of the shell
+    pub fn new(id: String) -> AssetAdministrationShellDescriptor {
+        AssetAdministrationShellDescriptor {
+            description: Vec::new(),
+            display_name: Vec::new(),
+            extensions: Vec::new(),
+            administration: None,
+            asset_kind: None,
+            asset_type: None,
+            endpoints: Vec::new(),
+            global_asset_id: None,
+            id_short: None,
+            id,
+            specific_asset_ids: Vec::new(),
+            submodel_descriptors: Vec::new()
+        }
+    }
+
+    ///Creates the descriptor of a shell served by an AAS repository. The endpoint is the URL of the
+    /// shell below the base URL, e.g. `http://localhost:8081/shells/dXJuOmFhczox`. The descriptors
+    /// of the submodels are not created, see `SubmodelDescriptor::from_submodel`.
+    ///
+    /// [shell]: described shell
+    /// [base_url]: base URL of the repository serving the shell, e.g. `http://localhost:8081`
+    pub fn from_shell(shell: &AssetAdministrationShell, base_url: &str) -> AssetAdministrationShellDescriptor {
+        let href = format!("{}/shells/{}", base_url.trim_end_matches('/'), encode_base64(shell.get_id().as_bytes(), true));
+        let asset_information = shell.get_asset_information();
+        AssetAdministrationShellDescriptor {
+            description: shell.get_description().clone(),
+            display_name: shell.get_display_name().clone(),
+            extensions: shell.get_extensions().clone(),
+            administration: shell.get_administration().cloned(),
+            asset_kind: Some(asset_information.get_asset_kind().clone()),
+            asset_type: asset_information.get_asset_type().cloned(),
+            endpoints: vec![Endpoint::new(Endpoint::AAS_INTERFACE.to_string(), ProtocolInformation::from_href(href))],
+            global_asset_id: asset_information.get_global_asset_id().cloned(),
+            id_short: shell.get_id_short().cloned(),
+            id: shell.get_id().clone(),
+            specific_asset_ids: asset_information.get_specific_asset_ids().clone(),
+            submodel_descriptors: Vec::new()
+        }
+    }
+
+    ///Returns the description of the shell.
+    pub fn get_description(&self) -> &Vec<MultiLanguageTextType> {
+        &self.description
+    }
+
+    ///Sets the description of the shell.
+    ///
+    /// [description]: description in different languages
+    pub fn set_description(&mut self, description: Vec<MultiLanguageTextType>) {
+        self.description = description;
+    }
+
+    ///Returns the display name of the shell.
+    pub fn get_display_name(&self) -> &Vec<MultiLanguageNameType> {
+        &self.display_name
+    }
+
+    ///Sets the display name of the shell.
+    ///
+    /// [display_name]: display name in different languages
+    pub fn set_display_name(&mut self, display_name: Vec<MultiLanguageNameType>) {
+        self.display_name = display_name;
+    }
+
+    ///Returns the extensions of the descriptor.
+    pub fn get_extensions(&self) -> &Vec<Extension> {
+        &self.extensions
+    }
+
+    ///Sets the extensions of the descriptor.
+    ///
+    /// [extensions]: extensions of the descriptor
+    pub fn set_extensions(&mut self, extensions: Vec<Extension>) {
+        self.extensions = extensions;
+    }
+
+    ///Returns the administrative information of the shell.
+    pub fn get_administration(&self) -> Option<&AdministrativeInformation> {
+        self.administration.as_ref()
+    }
+
+    ///Sets the administrative information of the shell. Passing None removes it.
+    ///
+    /// [administration]: administrative information of the shell
+    pub fn set_administration(&mut self, administration: Option<AdministrativeInformation>) {
+        self.administration = administration;
+    }
+
+    ///Returns the kind of the asset represented by the shell.
+    pub fn get_asset_kind(&self) -> Option<&AssetKind> {
+        self.asset_kind.as_ref()
+    }
+
+    ///Sets the kind of the asset represented by the shell. Passing None removes it.
+    ///
+    /// [asset_kind]: kind of the asset
+    pub fn set_asset_kind(&mut self, asset_kind: Option<AssetKind>) {
+        self.asset_kind = asset_kind;
+    }
+
+    ///Returns the type of the asset represented by the shell.
+    pub fn get_asset_type(&self) -> Option<&String> {
+        self.asset_type.as_ref()
+    }
+
+    ///Sets the type of the asset represented by the shell. Passing None removes it.
+    ///
+    /// [asset_type]: type of the asset
+    pub fn set_asset_type(&mut self, asset_type: Option<String>) {
+        self.asset_type = asset_type;
+    }
+
+    ///Returns the endpoints the shell is reachable at.
+    pub fn get_endpoints(&self) -> &Vec<Endpoint> {
+        &self.endpoints
+    }
+
+    ///Returns the mutable endpoints the shell is reachable at.
+    pub fn get_mut_endpoints(&mut self) -> &mut Vec<Endpoint> {
+        &mut self.endpoints
+    }
+
+    ///Adds an endpoint the shell is reachable at.
+    ///
+    /// [endpoint]: endpoint to add
+    pub fn add_endpoint(&mut self, endpoint: Endpoint) {
+        self.endpoints.push(endpoint);
+    }
+
+    ///Returns the global identifier of the asset represented by the shell.
+    pub fn get_global_asset_id(&self) -> Option<&String> {
+        self.global_asset_id.as_ref()
+    }
+
+    ///Sets the global identifier of the asset represented by the shell. Passing None removes it.
+    ///
+    /// [global_asset_id]: global identifier of the asset
+    pub fn set_global_asset_id(&mut self, global_asset_id: Option<String>) {
+        self.global_asset_id = global_asset_id;
+    }
+
+    ///Returns the short name of the shell.
+    pub fn get_id_short(&self) -> Option<&String> {
+        self.id_short.as_ref()
+    }
+
+    ///Sets the short name of the shell. Passing None removes it.
+    ///
+    /// [id_short]: short name of the shell
+    pub fn set_id_short(&mut self, id_short: Option<String>) {
+        self.id_short = id_short;
+    }
+
+    ///Returns the global identifier of the shell.
+    pub fn get_id(&self) -> &String {
+        &self.id
+    }
+
+    ///Sets the global identifier of the shell.
+    ///
+    /// [id]: global identifier of the shell
+    pub fn set_id(&mut self, id: String) {
+        self.id = id;
+    }
+
+    ///Returns the specific asset IDs of the asset represented by the shell.
+    pub fn get_specific_asset_ids(&self) -> &Vec<SpecificAssetId> {
+        &self.specific_asset_ids
+    }
+
+    ///Sets the specific asset IDs of the asset represented by the shell.
+    ///
+    /// [specific_asset_ids]: specific asset IDs of the asset
+    pub fn set_specific_asset_ids(&mut self, specific_asset_ids: Vec<SpecificAssetId>) {
+        self.specific_asset_ids = specific_asset_ids;
+    }
+
+    ///Returns the descriptors of the submodels of the shell.
+    pub fn get_submodel_descriptors(&self) -> &Vec<SubmodelDescriptor> {
+        &self.submodel_descriptors
+    }
+
+    ///Returns the mutable descriptors of the submodels of the shell.
+    pub fn get_mut_submodel_descriptors(&mut self) -> &mut Vec<SubmodelDescriptor> {
+        &mut self.submodel_descriptors
+    }
+
+    ///Adds the descriptor of a submodel of the shell.
+    ///
+    /// [submodel_descriptor]: descriptor to add
+    pub fn add_submodel_descriptor(&mut self, submodel_descriptor: SubmodelDescriptor) {
+        self.submodel_descriptors.push(submodel_descriptor);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::enumerations::asset_kind::AssetKind;
+    use crate::enumerations::security_type::SecurityType;
+    use crate::structs::asset_administration_shell::AssetAdministrationShell;
+    use crate::structs::asset_administration_shell_descriptor::AssetAdministrationShellDescriptor;
+    use crate::structs::asset_information::AssetInformation;
+    use crate::structs::endpoint::Endpoint;
+    use crate::traits::referable::TReferable;
+
+    #[test]
+    fn deserializes_and_serializes_descriptor_of_specification() {
+        let json = serde_json::json!({
+            "description": [{"language": "en", "text": "Shell of the pump"}],
+            "assetKind": "Instance",
+            "assetType": "Pump",
+            "endpoints": [{
+                "interface": "AAS-3.0",
+                "protocolInformation": {
+                    "href": "https://example.com/shells/dXJuOmFhczox",
+                    "endpointProtocol": "HTTPS",
+                    "endpointProtocolVersion": ["1.1"],
+                    "subprotocol": "OPC UA Basic SOAP",
+                    "subprotocolBody": "ns=2;s=MyAAS",
+                    "subprotocolBodyEncoding": "plain",
+                    "securityAttributes": [{"type": "RFC_TLSA", "key": "TLSA", "value": "3 1 1 abcdef"}]
+                }
+            }],
+            "globalAssetId": "urn:asset:pump:1",
+            "idShort": "Pump",
+            "id": "urn:aas:1",
+            "specificAssetIds": [{"name": "serialNumber", "value": "1234"}],
+            "submodelDescriptors": [{
+                "endpoints": [{
+                    "interface": "SUBMODEL-3.0",
+                    "protocolInformation": {"href": "https://example.com/submodels/dXJuOnNtOjE"}
+                }],
+                "idShort": "Nameplate",
+                "id": "urn:sm:1",
+                "semanticId": {"type": "ExternalReference", "keys": [{"type": "GlobalReference", "value": "https://admin-shell.io/idta/nameplate/3/0/Nameplate"}]}
+            }]
+        });
+
+        let descriptor: AssetAdministrationShellDescriptor = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(descriptor.get_id(), "urn:aas:1");
+        assert_eq!(descriptor.get_global_asset_id(), Some(&"urn:asset:pump:1".to_string()));
+        assert!(descriptor.get_asset_kind() == Some(&AssetKind::Instance));
+        let protocol_information = descriptor.get_endpoints()[0].get_protocol_information();
+        assert_eq!(protocol_information.get_subprotocol_body(), Some(&"ns=2;s=MyAAS".to_string()));
+        let security_attribute = &protocol_information.get_security_attributes()[0];
+        assert!(*security_attribute.get_security_type() == SecurityType::RfcTlsa);
+        assert_eq!(security_attribute.get_value(), "3 1 1 abcdef");
+        let submodel_descriptor = &descriptor.get_submodel_descriptors()[0];
+        assert_eq!(submodel_descriptor.get_id(), "urn:sm:1");
+        assert_eq!(submodel_descriptor.get_endpoints()[0].get_protocol_information().get_href(), "https://example.com/submodels/dXJuOnNtOjE");
+        assert!(submodel_descriptor.get_semantic_id().is_some());
+        assert_eq!(serde_json::to_value(&descriptor).unwrap(), json);
+    }
+
+    #[test]
+    fn creates_descriptor_of_shell_below_base_url() {
+        let mut asset_information = AssetInformation::new(AssetKind::Instance);
+        asset_information.set_global_asset_id("urn:asset:pump:1".to_string());
+        let mut shell = AssetAdministrationShell::new("urn:aas:1".to_string(), asset_information);
+        shell.set_id_short("Pump".to_string());
+
+        let descriptor = AssetAdministrationShellDescriptor::from_shell(&shell, "http://localhost:8081/");
+
+        assert_eq!(descriptor.get_id(), "urn:aas:1");
+        assert_eq!(descriptor.get_id_short(), Some(&"Pump".to_string()));
+        assert_eq!(descriptor.get_global_asset_id(), Some(&"urn:asset:pump:1".to_string()));
+        assert!(descriptor.get_asset_kind() == Some(&AssetKind::Instance));
+        assert!(descriptor.get_submodel_descriptors().is_empty());
+        assert_eq!(descriptor.get_endpoints().len(), 1);
+        let endpoint = &descriptor.get_endpoints()[0];
+        assert_eq!(endpoint.get_interface(), Endpoint::AAS_INTERFACE);
+        let protocol_information = endpoint.get_protocol_information();
+        assert_eq!(protocol_information.get_href(), "http://localhost:8081/shells/dXJuOmFhczox");
+        assert_eq!(protocol_information.get_endpoint_protocol(), Some(&"HTTP".to_string()));
+        assert_eq!(protocol_information.get_endpoint_protocol_versions(), &vec!["1.1".to_string()]);
+    }
+}
\ No newline at end of file
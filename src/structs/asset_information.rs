@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::enumerations::asset_kind::AssetKind;
+use crate::structs::reference::Reference;
 use crate::structs::resource::Resource;
 use crate::structs::specific_asset_id::SpecificAssetId;
 
@@ -11,17 +12,17 @@ pub struct AssetInformation {
     #[serde(rename = "assetKind")]
     asset_kind: AssetKind,
     ///Optional identifier of the asset, the asset administration shell is representing.
-    #[serde(rename = "globalAssetId")]
+    #[serde(rename = "globalAssetId", default, skip_serializing_if = "Option::is_none")]
     global_asset_id: Option<String>,
     ///Additional domain specific, typically proprietary identifiers for the asset like serial number,
     /// manufacturer part ID, customer part ID...
-    #[serde(rename = "specificAssetIds")]
+    #[serde(rename = "specificAssetIds", default, skip_serializing_if = "Vec::is_empty")]
     specific_asset_ids: Vec<SpecificAssetId>,
     ///The type of asset.
-    #[serde(rename = "assetType")]
+    #[serde(rename = "assetType", default, skip_serializing_if = "Option::is_none")]
     asset_type: Option<String>,
     ///Optional thumbnail of the asset represented by the asset administration shell.
-    #[serde(rename = "defaultThumbnail")]
+    #[serde(rename = "defaultThumbnail", default, skip_serializing_if = "Option::is_none")]
     default_thumbnail: Option<Resource>
 }
 
@@ -130,4 +131,20 @@ impl AssetInformation {
     pub fn get_mut_default_thumbnail(&mut self) -> Option<&mut Resource> {
         self.default_thumbnail.as_mut()
     }
+
+    ///Returns all references contained in the specific asset IDs of the asset information.
+    pub fn get_references(&self) -> Vec<&Reference> {
+        self.specific_asset_ids.iter()
+            .flat_map(|specific_asset_id| specific_asset_id.get_references())
+            .collect()
+    }
+
+    ///Calls the visitor for every reference contained in the specific asset IDs of the asset
+    /// information.
+    ///
+    /// [visitor]: function called for every reference
+    pub fn visit_references_mut(&mut self, visitor: &mut dyn FnMut(&mut Reference)) {
+        self.specific_asset_ids.iter_mut()
+            .for_each(|specific_asset_id| specific_asset_id.visit_references_mut(visitor));
+    }
 }
\ No newline at end of file
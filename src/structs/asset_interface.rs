@@ -0,0 +1,769 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::binding_error::BindingError;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::interface_protocol::InterfaceProtocol;
+use crate::enumerations::key_type::KeyType;
+use crate::enumerations::modbus_entity::ModbusEntity;
+use crate::enumerations::reference_type::ReferenceType;
+use crate::enumerations::thing_description_error::ThingDescriptionError;
+use crate::structs::endpoint_metadata::EndpointMetadata;
+use crate::structs::form::Form;
+use crate::structs::http_value_provider::HttpValueProvider;
+use crate::structs::key::Key;
+use crate::structs::modbus_value_provider::ModbusValueProvider;
+use crate::structs::mqtt_value_provider::MqttValueProvider;
+use crate::structs::property::Property;
+use crate::structs::property_definition::PropertyDefinition;
+use crate::structs::reference::Reference;
+use crate::structs::security_scheme::SecurityScheme;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::submodel_element_list::SubmodelElementList;
+use crate::structs::template_element::{global_reference, join_path, TemplateElement, ARBITRARY};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+use crate::traits::value_provider::TValueProvider;
+use crate::transport::{split_uri, with_default_port};
+use serde_json::{Map, Number, Value};
+
+///Typed view on an interface of the Asset Interfaces Description, which describes how the data
+/// points of an asset are accessed by one protocol in terms of the W3C Web of Things (WoT) Thing
+/// Description.
+///
+/// An interface can be converted to and from a Thing Description. The conversion covers the
+/// metadata, the endpoint metadata with the security definitions and the property affordances;
+/// actions and events are kept in the interface but not converted.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct AssetInterface {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl AssetInterface {
+    ///Semantic ID of an interface.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/idta/AssetInterfacesDescription/1/0/Interface";
+    ///Context of the Thing Descriptions the interfaces are converted to.
+    pub const THING_DESCRIPTION_CONTEXT: &str = "https://www.w3.org/2019/wot/td/v1";
+
+    ///Creates a new interface without property affordances.
+    ///
+    /// [id_short]: idShort of the interface, e.g. `InterfaceHTTP`
+    /// [title]: human-readable title of the interface
+    /// [protocol]: protocol of the interface
+    /// [endpoint_metadata]: endpoint metadata with base URI and security schemes
+    pub fn new(id_short: String, title: String, protocol: InterfaceProtocol, endpoint_metadata: EndpointMetadata) -> AssetInterface {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_id_short(id_short);
+        collection.set_semantic_id(global_reference(AssetInterface::SEMANTIC_ID));
+        let mut asset_interface = AssetInterface {
+            collection
+        };
+        asset_interface.set_title(title);
+        asset_interface.set_protocol(protocol);
+        asset_interface.set_endpoint_metadata(endpoint_metadata);
+        asset_interface.collection.set_element(INTERACTION_METADATA.id_short, INTERACTION_METADATA.semantic_id,
+                                               Some(SubmodelElement::SubmodelElementCollection(SubmodelElementCollection::new())));
+        asset_interface
+    }
+
+    ///Wraps a submodel element collection after checking the elements of an interface.
+    ///
+    /// [collection]: collection containing an interface
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<AssetInterface, ConversionError> {
+        AssetInterface::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(AssetInterface {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the interface and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the human-readable title of the interface.
+    pub fn get_title(&self) -> Option<String> {
+        self.collection.get_property_value(TITLE.id_short, TITLE.semantic_id)
+    }
+
+    ///Sets the human-readable title of the interface.
+    ///
+    /// [title]: human-readable title of the interface
+    pub fn set_title(&mut self, title: String) {
+        TITLE.set_property_value(&mut self.collection, Some(&title));
+    }
+
+    ///Returns the point in time the interface description was created.
+    pub fn get_created(&self) -> Option<String> {
+        self.collection.get_property_value(CREATED.id_short, CREATED.semantic_id)
+    }
+
+    ///Sets the point in time the interface description was created. Passing None removes it.
+    ///
+    /// [created]: point in time as xs:dateTime, e.g. `2024-01-31T12:00:00Z`
+    pub fn set_created(&mut self, created: Option<String>) {
+        CREATED.set_property_value(&mut self.collection, created.as_ref());
+    }
+
+    ///Returns the point in time the interface description was modified last.
+    pub fn get_modified(&self) -> Option<String> {
+        self.collection.get_property_value(MODIFIED.id_short, MODIFIED.semantic_id)
+    }
+
+    ///Sets the point in time the interface description was modified last. Passing None removes it.
+    ///
+    /// [modified]: point in time as xs:dateTime, e.g. `2024-01-31T12:00:00Z`
+    pub fn set_modified(&mut self, modified: Option<String>) {
+        MODIFIED.set_property_value(&mut self.collection, modified.as_ref());
+    }
+
+    ///Returns the URI of the support contact, e.g. a mail address.
+    pub fn get_support(&self) -> Option<String> {
+        self.collection.get_property_value(SUPPORT.id_short, SUPPORT.semantic_id)
+    }
+
+    ///Sets the URI of the support contact. Passing None removes it.
+    ///
+    /// [support]: URI of the support contact, e.g. `mailto:support@example.com`
+    pub fn set_support(&mut self, support: Option<String>) {
+        SUPPORT.set_property_value(&mut self.collection, support.as_ref());
+    }
+
+    ///Returns the protocol of the interface, which is stated by a supplemental semantic ID.
+    pub fn get_protocol(&self) -> Option<InterfaceProtocol> {
+        self.collection.get_supplemental_semantic_ids().iter()
+            .filter_map(|reference| reference.get_keys().first())
+            .find_map(|key| InterfaceProtocol::from_semantic_id(key.get_value()))
+    }
+
+    ///Sets the protocol of the interface, replacing the supplemental semantic ID of the previous
+    /// protocol.
+    ///
+    /// [protocol]: protocol of the interface
+    pub fn set_protocol(&mut self, protocol: InterfaceProtocol) {
+        self.collection.get_mut_supplemental_semantic_ids().retain(|reference| reference.get_keys().first()
+            .is_none_or(|key| InterfaceProtocol::from_semantic_id(key.get_value()).is_none()));
+        self.collection.add_supplemental_semantic_id(global_reference(protocol.get_semantic_id()));
+    }
+
+    ///Returns the endpoint metadata with base URI and security schemes.
+    pub fn get_endpoint_metadata(&self) -> Option<EndpointMetadata> {
+        self.collection.get_collection(ENDPOINT_METADATA.id_short, ENDPOINT_METADATA.semantic_id)
+            .map(|collection| EndpointMetadata {
+                collection: collection.clone()
+            })
+    }
+
+    ///Sets the endpoint metadata with base URI and security schemes.
+    ///
+    /// [endpoint_metadata]: endpoint metadata
+    pub fn set_endpoint_metadata(&mut self, endpoint_metadata: EndpointMetadata) {
+        self.collection.set_element(ENDPOINT_METADATA.id_short, ENDPOINT_METADATA.semantic_id,
+                                    Some(SubmodelElement::SubmodelElementCollection(endpoint_metadata.into_collection())));
+    }
+
+    ///Sets the security configuration of the endpoint metadata to the security schemes with the
+    /// names. The schemes are referred to by model references into the submodel.
+    ///
+    /// [submodel_id]: identifier of the Asset Interfaces Description submodel of the interface
+    /// [names]: names of security schemes of the security definitions, e.g. `basic_sc`
+    pub fn set_security(&mut self, submodel_id: &str, names: &[&str]) {
+        let Some(mut endpoint_metadata) = self.get_endpoint_metadata() else {
+            return;
+        };
+        let id_short = self.collection.get_id_short().cloned().unwrap_or_default();
+        endpoint_metadata.set_security(names.iter()
+            .map(|name| Reference::new(ReferenceType::ModelReference, vec![
+                Key::new(KeyType::Submodel, submodel_id.to_string()),
+                Key::new(KeyType::SubmodelElementCollection, id_short.clone()),
+                Key::new(KeyType::SubmodelElementCollection, EndpointMetadata::ID_SHORT.to_string()),
+                Key::new(KeyType::SubmodelElementCollection, SECURITY_DEFINITIONS_ID_SHORT.to_string()),
+                Key::new(KeyType::SubmodelElementCollection, name.to_string())
+            ]))
+            .collect());
+        self.set_endpoint_metadata(endpoint_metadata);
+    }
+
+    ///Returns the definitions of the property affordances.
+    pub fn get_properties(&self) -> Vec<PropertyDefinition> {
+        self.get_interaction_metadata()
+            .and_then(|metadata| metadata.get_collection(PROPERTIES.id_short, PROPERTIES.semantic_id))
+            .map(|properties| properties.get_collections(PROPERTY_DEFINITION.id_short, PROPERTY_DEFINITION.semantic_id).into_iter()
+                .map(|collection| PropertyDefinition {
+                    collection: collection.clone()
+                })
+                .collect())
+            .unwrap_or_default()
+    }
+
+    ///Returns the definition of the property affordance with the name.
+    ///
+    /// [name]: name of the property affordance, e.g. `temperature`
+    pub fn get_property(&self, name: &str) -> Option<PropertyDefinition> {
+        self.get_properties().into_iter().find(|property_definition| property_definition.get_name() == name)
+    }
+
+    ///Sets the definitions of the property affordances. The definitions keep their idShorts, i.e.
+    /// the names of the property affordances.
+    ///
+    /// [properties]: definitions of the property affordances
+    pub fn set_properties(&mut self, properties: Vec<PropertyDefinition>) {
+        let mut metadata = self.get_interaction_metadata().cloned().unwrap_or_else(SubmodelElementCollection::new);
+        let mut collection = metadata.get_collection(PROPERTIES.id_short, PROPERTIES.semantic_id).cloned()
+            .unwrap_or_else(SubmodelElementCollection::new);
+        collection.set_named_elements(PROPERTY_DEFINITION.id_short, PROPERTY_DEFINITION.semantic_id, properties.into_iter()
+            .map(|property_definition| SubmodelElement::SubmodelElementCollection(property_definition.into_collection()))
+            .collect());
+        metadata.set_element(PROPERTIES.id_short, PROPERTIES.semantic_id, Some(SubmodelElement::SubmodelElementCollection(collection)));
+        self.collection.set_element(INTERACTION_METADATA.id_short, INTERACTION_METADATA.semantic_id,
+                                    Some(SubmodelElement::SubmodelElementCollection(metadata)));
+    }
+
+    ///Adds the definition of a property affordance, replacing a definition with the same name.
+    ///
+    /// [property_definition]: definition of the property affordance
+    pub fn add_property(&mut self, property_definition: PropertyDefinition) {
+        let mut properties = self.get_properties();
+        properties.retain(|existing| existing.get_name() != property_definition.get_name());
+        properties.push(property_definition);
+        self.set_properties(properties);
+    }
+
+    ///Converts the interface to a W3C WoT Thing Description. Protocol specific terms are prefixed
+    /// as declared in the context, e.g. `htv:methodName`.
+    pub fn to_thing_description(&self) -> Value {
+        let mut context = vec![Value::String(AssetInterface::THING_DESCRIPTION_CONTEXT.to_string())];
+        if let Some(protocol) = self.get_protocol() {
+            let mut prefix = Map::new();
+            prefix.insert(protocol.get_prefix().to_string(), Value::String(protocol.get_namespace().to_string()));
+            context.push(Value::Object(prefix));
+        }
+        let mut thing_description = Map::new();
+        thing_description.insert("@context".to_string(), Value::Array(context));
+        for (element, term) in [(&TITLE, "title"), (&CREATED, "created"), (&MODIFIED, "modified"), (&SUPPORT, "support")] {
+            if let Some(value) = self.collection.get_property_value::<String>(element.id_short, element.semantic_id) {
+                thing_description.insert(term.to_string(), Value::String(value));
+            }
+        }
+        if let Some(endpoint_metadata) = self.get_endpoint_metadata() {
+            if let Some(base) = endpoint_metadata.get_base() {
+                thing_description.insert("base".to_string(), Value::String(base));
+            }
+            let security_definitions = endpoint_metadata.get_security_definitions().into_iter()
+                .filter_map(|security_scheme| Some((security_scheme.get_name()?.clone(), Value::Object(security_scheme.to_json()))))
+                .collect();
+            thing_description.insert("securityDefinitions".to_string(), Value::Object(security_definitions));
+            thing_description.insert("security".to_string(), Value::Array(endpoint_metadata.get_security().into_iter().map(Value::String).collect()));
+        }
+        let properties: Map<String, Value> = self.get_properties().into_iter()
+            .map(|property_definition| (property_definition.get_name(), Value::Object(property_definition.to_json())))
+            .collect();
+        if !properties.is_empty() {
+            thing_description.insert("properties".to_string(), Value::Object(properties));
+        }
+        Value::Object(thing_description)
+    }
+
+    ///Creates an interface from a W3C WoT Thing Description. The protocol is derived from the URI
+    /// scheme of the base URI or, if it is unknown, of the first target of a form. Without base URI,
+    /// the scheme and authority of the first target of a form are taken as base URI.
+    ///
+    /// [submodel_id]: identifier of the Asset Interfaces Description submodel the interface is
+    /// added to, used by the references to the security schemes
+    /// [id_short]: idShort of the interface, e.g. `InterfaceHTTP`
+    /// [thing_description]: Thing Description as JSON
+    pub fn from_thing_description(submodel_id: &str, id_short: &str, thing_description: &Value)
+        -> Result<AssetInterface, ThingDescriptionError> {
+        let thing_description = as_object(thing_description, "")?;
+        let title = as_str(member(thing_description, "", "title")?, "title")?;
+        let mut properties = Vec::new();
+        if let Some(affordances) = thing_description.get("properties") {
+            for (name, affordance) in as_object(affordances, "properties")? {
+                let path = join_path("properties", name);
+                let affordance = as_object(affordance, &path)?;
+                let forms_path = join_path(&path, "forms");
+                let forms = match member(affordance, &path, "forms")? {
+                    Value::Array(forms) => forms.first().ok_or_else(|| ThingDescriptionError::MissingMember(format!("{}[0]", forms_path)))?,
+                    _ => return Err(ThingDescriptionError::UnexpectedType(forms_path))
+                };
+                let forms_path = format!("{}[0]", forms_path);
+                let forms = as_object(forms, &forms_path)?;
+                let href = as_str(member(forms, &forms_path, "href")?, &join_path(&forms_path, "href"))?;
+                properties.push(PropertyDefinition::from_json(name, Form::from_json(href.to_string(), forms), affordance));
+            }
+        }
+        let base = match thing_description.get("base") {
+            Some(base) => as_str(base, "base")?.to_string(),
+            None => properties.first()
+                .and_then(|property_definition| property_definition.get_forms()?.get_href())
+                .and_then(|href| split_uri(&href).map(|(scheme, authority, _)| format!("{}://{}", scheme, authority)))
+                .ok_or_else(|| ThingDescriptionError::MissingMember("base".to_string()))?
+        };
+        let mut endpoint_metadata = EndpointMetadata::new(base.clone());
+        for (name, security_scheme) in as_object(member(thing_description, "", "securityDefinitions")?, "securityDefinitions")? {
+            let path = join_path("securityDefinitions", name);
+            let security_scheme = as_object(security_scheme, &path)?;
+            let scheme = as_str(member(security_scheme, &path, "scheme")?, &join_path(&path, "scheme"))?;
+            endpoint_metadata.add_security_definition(SecurityScheme::from_json(name, scheme, security_scheme));
+        }
+        let security = match member(thing_description, "", "security")? {
+            Value::String(name) => vec![name.as_str()],
+            Value::Array(names) => names.iter().enumerate()
+                .map(|(index, name)| as_str(name, &format!("security[{}]", index)))
+                .collect::<Result<Vec<&str>, ThingDescriptionError>>()?,
+            _ => return Err(ThingDescriptionError::UnexpectedType("security".to_string()))
+        };
+        let protocol = InterfaceProtocol::from_href(&base)
+            .or_else(|| properties.iter()
+                .filter_map(|property_definition| property_definition.get_forms()?.get_href())
+                .find_map(|href| InterfaceProtocol::from_href(&href)))
+            .unwrap_or(InterfaceProtocol::Http);
+        let mut asset_interface = AssetInterface::new(id_short.to_string(), title.to_string(), protocol, endpoint_metadata);
+        for (element, term) in [(&CREATED, "created"), (&MODIFIED, "modified"), (&SUPPORT, "support")] {
+            if let Some(value) = thing_description.get(term) {
+                element.set_property_value(&mut asset_interface.collection, Some(&as_str(value, term)?.to_string()));
+            }
+        }
+        asset_interface.set_security(submodel_id, &security);
+        asset_interface.set_properties(properties);
+        Ok(asset_interface)
+    }
+
+    ///Creates the provider reading the live value of a property affordance of the interface. The
+    /// target of the form is resolved against the base URI of the endpoint metadata; protocol
+    /// specific terms of the form, e.g. `modv:unitID` or `mqv:topic`, override the target.
+    ///
+    /// [property_definition]: definition of the property affordance
+    pub fn create_value_provider(&self, property_definition: &PropertyDefinition) -> Result<Box<dyn TValueProvider>, BindingError> {
+        let name = property_definition.get_name();
+        let forms = property_definition.get_forms().ok_or_else(|| BindingError::InvalidAddress(name.clone()))?;
+        let href = forms.get_href().ok_or_else(|| BindingError::InvalidAddress(name.clone()))?;
+        let base = self.get_endpoint_metadata().and_then(|endpoint_metadata| endpoint_metadata.get_base());
+        let uri = resolve_href(base.as_deref(), &href);
+        let protocol = self.get_protocol().or_else(|| InterfaceProtocol::from_href(&uri))
+            .ok_or_else(|| BindingError::UnsupportedProtocol(uri.clone()))?;
+        let term = |term: &str| forms.get_term(term);
+        let number = |term: &str| -> Result<Option<u64>, BindingError> {
+            match forms.get_term(term) {
+                None => Ok(None),
+                Some(Value::Number(number)) => number.as_u64().map(Some).ok_or_else(|| BindingError::InvalidAddress(uri.clone())),
+                Some(Value::String(text)) => text.trim().parse().map(Some).map_err(|_| BindingError::InvalidAddress(uri.clone())),
+                Some(_) => Err(BindingError::InvalidAddress(uri.clone()))
+            }
+        };
+        let provider: Box<dyn TValueProvider> = match protocol {
+            InterfaceProtocol::Http => {
+                if let Some(Value::String(method)) = term("htv:methodName")
+                    && !method.eq_ignore_ascii_case("GET") {
+                    return Err(BindingError::UnsupportedProtocol(method));
+                }
+                Box::new(HttpValueProvider::new(uri.clone()))
+            },
+            InterfaceProtocol::Modbus => {
+                let mut provider = match ModbusValueProvider::from_uri(&uri) {
+                    Ok(provider) => provider,
+                    Err(BindingError::InvalidAddress(_)) if term("modv:address").is_some() => {
+                        let (_, authority, _) = split_uri(&uri).ok_or_else(|| BindingError::InvalidAddress(uri.clone()))?;
+                        ModbusValueProvider::new(with_default_port(authority, 502), 1, ModbusEntity::HoldingRegister, 0)
+                    },
+                    Err(error) => return Err(error)
+                };
+                let invalid = || BindingError::InvalidAddress(uri.clone());
+                if let Some(unit_id) = number("modv:unitID")? {
+                    provider.set_unit_id(unit_id.try_into().map_err(|_| invalid())?);
+                }
+                if let Some(register) = number("modv:address")? {
+                    provider.set_register(register.try_into().map_err(|_| invalid())?);
+                }
+                if let Some(quantity) = number("modv:quantity")? {
+                    provider.set_quantity(Some(quantity.try_into().map_err(|_| invalid())?));
+                }
+                for term_name in ["modv:function", "modv:entity"] {
+                    if let Some(Value::String(entity)) = term(term_name) {
+                        provider.set_entity(ModbusEntity::parse(&entity).ok_or(BindingError::UnsupportedProtocol(entity))?);
+                    }
+                }
+                Box::new(provider)
+            },
+            InterfaceProtocol::Mqtt => match term("mqv:topic") {
+                Some(Value::String(topic)) => {
+                    let (_, authority, _) = split_uri(&uri).ok_or_else(|| BindingError::InvalidAddress(uri.clone()))?;
+                    Box::new(MqttValueProvider::new(with_default_port(authority, 1883), topic))
+                },
+                _ => Box::new(MqttValueProvider::from_uri(&uri)?)
+            }
+        };
+        Ok(provider)
+    }
+
+    ///Returns the elements of an interface in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        vec![
+            TITLE.to_element(),
+            CREATED.to_element(),
+            MODIFIED.to_element(),
+            SUPPORT.to_element(),
+            ENDPOINT_METADATA.to_collection(EndpointMetadata::template_elements()),
+            INTERACTION_METADATA.to_collection(vec![
+                PROPERTIES.to_collection(vec![PROPERTY_DEFINITION.to_collection(PropertyDefinition::template_elements())]),
+                ACTIONS.to_collection(vec![ARBITRARY.to_element()]),
+                EVENTS.to_collection(vec![ARBITRARY.to_element()])
+            ]),
+            EXTERNAL_DESCRIPTOR.to_collection(vec![ARBITRARY.to_element()])
+        ]
+    }
+
+    ///Checks the elements of an interface.
+    ///
+    /// [collection]: collection containing an interface
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        for element in ENDPOINT_METADATA.check(collection, path)? {
+            if let SubmodelElement::SubmodelElementCollection(endpoint_metadata) = element {
+                EndpointMetadata::check(endpoint_metadata, &join_path(path, ENDPOINT_METADATA.id_short))?;
+            }
+        }
+        let metadata_path = join_path(path, INTERACTION_METADATA.id_short);
+        for element in INTERACTION_METADATA.check(collection, path)? {
+            if let SubmodelElement::SubmodelElementCollection(metadata) = element {
+                for element in &INTERACTION_ELEMENTS {
+                    element.check(metadata, &metadata_path)?;
+                }
+                if let Some(properties) = metadata.get_collection(PROPERTIES.id_short, PROPERTIES.semantic_id) {
+                    let properties_path = join_path(&metadata_path, PROPERTIES.id_short);
+                    for property_definition in properties.get_collections(PROPERTY_DEFINITION.id_short, PROPERTY_DEFINITION.semantic_id) {
+                        PropertyDefinition::check(property_definition,
+                                                  &join_path(&properties_path, property_definition.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default()))?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn get_interaction_metadata(&self) -> Option<&SubmodelElementCollection> {
+        self.collection.get_collection(INTERACTION_METADATA.id_short, INTERACTION_METADATA.semantic_id)
+    }
+}
+
+///Returns the value of a term in the container as JSON.
+///
+/// [container]: container of the term, e.g. a form
+/// [term]: term of the Thing Description, protocol specific terms with prefix
+pub(crate) fn get_term<C: TSubmodelElementContainer + ?Sized>(container: &C, term: &str) -> Option<Value> {
+    let id_short = term_id_short(term);
+    container.get_elements().iter()
+        .find(|element| element.get_id_short().is_some_and(|element_id_short| *element_id_short == id_short))
+        .and_then(element_to_json)
+}
+
+///Sets the value of a term in the container. The element of the term has the idShort of the term
+/// with `_` instead of `:` and, if the term is known, its semantic ID. Passing None removes it.
+///
+/// [container]: container of the term, e.g. a form
+/// [term]: term of the Thing Description, protocol specific terms with prefix
+/// [value]: JSON value of the term
+pub(crate) fn set_term<C: TSubmodelElementContainer + ?Sized>(container: &mut C, term: &str, value: Option<&Value>) {
+    let id_short = term_id_short(term);
+    let elements = container.get_mut_elements();
+    let position = elements.iter().position(|element| element.get_id_short().is_some_and(|element_id_short| *element_id_short == id_short));
+    match (position, value.and_then(|value| json_to_element(Some(term), value))) {
+        (Some(position), Some(element)) => elements[position] = element,
+        (Some(position), None) => {
+            elements.remove(position);
+        },
+        (None, Some(element)) => elements.push(element),
+        (None, None) => {}
+    }
+}
+
+///Returns the terms of the elements in the container as JSON object.
+///
+/// [container]: container of the terms
+/// [skipped]: idShorts of elements that are no terms or are converted separately
+pub(crate) fn terms_to_json<C: TSubmodelElementContainer + ?Sized>(container: &C, skipped: &[&str]) -> Map<String, Value> {
+    container.get_elements().iter()
+        .filter_map(|element| {
+            let id_short = element.get_id_short()?;
+            if skipped.contains(&id_short.as_str()) {
+                return None;
+            }
+            Some((id_short_term(id_short), element_to_json(element)?))
+        })
+        .collect()
+}
+
+///Adds the members of the JSON object as terms to the container. JSON-LD keywords like `@type`
+/// are skipped.
+///
+/// [container]: container of the terms
+/// [json]: JSON object of the Thing Description
+/// [skipped]: members that are no terms or are converted separately
+pub(crate) fn add_terms<C: TSubmodelElementContainer + ?Sized>(container: &mut C, json: &Map<String, Value>, skipped: &[&str]) {
+    for (term, value) in json {
+        if !term.starts_with('@') && !skipped.contains(&term.as_str()) {
+            set_term(container, term, Some(value));
+        }
+    }
+}
+
+///Resolves the target of a form against the base URI. Absolute targets are returned as is.
+fn resolve_href(base: Option<&str>, href: &str) -> String {
+    match base {
+        Some(base) if !href.contains("://") => format!("{}/{}", base.trim_end_matches('/'), href.trim_start_matches('/')),
+        _ => href.to_string()
+    }
+}
+
+///Returns the idShort of the element of a term, i.e. the term with `_` instead of `:` and of other
+/// characters that are not allowed in an idShort.
+///
+/// [term]: term of the Thing Description, e.g. `htv:methodName`
+pub(crate) fn term_id_short(term: &str) -> String {
+    let id_short: String = term.chars()
+        .map(|character| if character.is_ascii_alphanumeric() || character == '-' { character } else { '_' })
+        .collect();
+    match id_short.starts_with(|character: char| character.is_ascii_alphabetic()) {
+        true => id_short,
+        false => format!("_{}", id_short)
+    }
+}
+
+///Returns the term of the element with the idShort, i.e. the idShort with `:` after the prefix of
+/// a protocol vocabulary.
+fn id_short_term(id_short: &str) -> String {
+    match id_short.split_once('_') {
+        Some((prefix, local_name)) if InterfaceProtocol::from_prefix(prefix).is_some() => format!("{}:{}", prefix, local_name),
+        _ => id_short.to_string()
+    }
+}
+
+///Returns the semantic ID and the data type of a term. The semantic IDs of protocol specific terms
+/// consist of the namespace of the vocabulary and the term without prefix; their data type is the
+/// one of their value.
+fn term_definition(term: &str) -> Option<(String, Option<DataTypeDefXsd>)> {
+    if let Some((_, semantic_id, value_type)) = TERMS.iter().find(|(known_term, _, _)| *known_term == term) {
+        return Some((semantic_id.to_string(), Some(value_type.clone())));
+    }
+    let (prefix, local_name) = term.split_once(':')?;
+    InterfaceProtocol::from_prefix(prefix)
+        .map(|protocol| (format!("{}{}", protocol.get_namespace(), local_name), None))
+}
+
+///Converts the JSON value of a term to an element. Arrays become lists and objects become
+/// collections of terms.
+///
+/// [term]: term of the value; None for the elements of a list, which have no idShort
+/// [value]: JSON value
+fn json_to_element(term: Option<&str>, value: &Value) -> Option<SubmodelElement> {
+    let definition = term.and_then(term_definition);
+    let element = match value {
+        Value::Null => return None,
+        Value::Bool(_) | Value::Number(_) | Value::String(_) => {
+            let (text, value_type) = match value {
+                Value::Bool(boolean) => (boolean.to_string(), DataTypeDefXsd::Boolean),
+                Value::Number(number) if number.is_i64() || number.is_u64() => (number.to_string(), DataTypeDefXsd::Integer),
+                Value::Number(number) => (number.to_string(), DataTypeDefXsd::Double),
+                Value::String(text) => (text.clone(), DataTypeDefXsd::String),
+                _ => unreachable!("value is a JSON scalar")
+            };
+            let value_type = definition.as_ref()
+                .and_then(|(_, value_type)| value_type.clone())
+                .filter(|value_type| value_type.is_valid_value(&text))
+                .unwrap_or(value_type);
+            let mut property = Property::new(value_type);
+            property.set_value(text);
+            SubmodelElement::Property(property)
+        },
+        Value::Array(values) => {
+            let items: Vec<SubmodelElement> = values.iter().filter_map(|value| json_to_element(None, value)).collect();
+            let mut list = match items.first() {
+                Some(SubmodelElement::Property(property)) => {
+                    let mut list = SubmodelElementList::new(AasSubmodelElements::Property);
+                    list.set_value_type_list_element(property.get_value_type().clone());
+                    list
+                },
+                Some(_) => SubmodelElementList::new(AasSubmodelElements::SubmodelElementCollection),
+                None => SubmodelElementList::new(AasSubmodelElements::Property)
+            };
+            list.set_value(items);
+            SubmodelElement::SubmodelElementList(list)
+        },
+        Value::Object(members) => {
+            let mut collection = SubmodelElementCollection::new();
+            add_terms(&mut collection, members, &[]);
+            SubmodelElement::SubmodelElementCollection(collection)
+        }
+    };
+    Some(identify(element, term, definition))
+}
+
+///Sets the idShort and the semantic ID of the element of a term.
+fn identify(mut element: SubmodelElement, term: Option<&str>, definition: Option<(String, Option<DataTypeDefXsd>)>) -> SubmodelElement {
+    let Some(term) = term else {
+        return element;
+    };
+    element.set_id_short(term_id_short(term));
+    let Some((semantic_id, _)) = definition else {
+        return element;
+    };
+    match &mut element {
+        SubmodelElement::Property(property) => property.set_semantic_id(global_reference(&semantic_id)),
+        SubmodelElement::SubmodelElementList(list) => list.set_semantic_id(global_reference(&semantic_id)),
+        SubmodelElement::SubmodelElementCollection(collection) => collection.set_semantic_id(global_reference(&semantic_id)),
+        _ => {}
+    }
+    element
+}
+
+///Converts an element of a term to its JSON value. Properties become JSON values of their data
+/// type, lists become arrays and collections become objects.
+fn element_to_json(element: &SubmodelElement) -> Option<Value> {
+    match element {
+        SubmodelElement::Property(property) => {
+            let value = property.get_value()?;
+            Some(match property.get_value_type() {
+                DataTypeDefXsd::Boolean => Value::Bool(property.get_typed_value::<bool>()?),
+                DataTypeDefXsd::Byte | DataTypeDefXsd::Decimal | DataTypeDefXsd::Double | DataTypeDefXsd::Float | DataTypeDefXsd::Int
+                | DataTypeDefXsd::Integer | DataTypeDefXsd::Long | DataTypeDefXsd::NegativeInteger | DataTypeDefXsd::NonNegativeInteger
+                | DataTypeDefXsd::NonPositiveInteger | DataTypeDefXsd::PositiveInteger | DataTypeDefXsd::Short | DataTypeDefXsd::UnsignedByte
+                | DataTypeDefXsd::UnsignedInt | DataTypeDefXsd::UnsignedLong | DataTypeDefXsd::UnsignedShort => match value.parse::<i64>() {
+                    Ok(integer) => Value::Number(Number::from(integer)),
+                    Err(_) => Value::Number(value.parse::<f64>().ok().and_then(Number::from_f64)?)
+                },
+                _ => Value::String(value.clone())
+            })
+        },
+        SubmodelElement::SubmodelElementList(list) => Some(Value::Array(list.get_value().iter().filter_map(element_to_json).collect())),
+        SubmodelElement::SubmodelElementCollection(collection) => Some(Value::Object(terms_to_json(collection, &[]))),
+        _ => None
+    }
+}
+
+fn member<'a>(object: &'a Map<String, Value>, path: &str, name: &str) -> Result<&'a Value, ThingDescriptionError> {
+    object.get(name).ok_or_else(|| ThingDescriptionError::MissingMember(join_path(path, name)))
+}
+
+fn as_object<'a>(value: &'a Value, path: &str) -> Result<&'a Map<String, Value>, ThingDescriptionError> {
+    value.as_object().ok_or_else(|| ThingDescriptionError::UnexpectedType(path.to_string()))
+}
+
+fn as_str<'a>(value: &'a Value, path: &str) -> Result<&'a str, ThingDescriptionError> {
+    value.as_str().ok_or_else(|| ThingDescriptionError::UnexpectedType(path.to_string()))
+}
+
+const SECURITY_DEFINITIONS_ID_SHORT: &str = "securityDefinitions";
+
+const TITLE: TemplateElement =
+    TemplateElement::property("title", "https://www.w3.org/2019/wot/td#title", DataTypeDefXsd::String, Cardinality::One);
+const CREATED: TemplateElement =
+    TemplateElement::property("created", "http://purl.org/dc/terms/created", DataTypeDefXsd::DateTime, Cardinality::ZeroToOne);
+const MODIFIED: TemplateElement =
+    TemplateElement::property("modified", "http://purl.org/dc/terms/modified", DataTypeDefXsd::DateTime, Cardinality::ZeroToOne);
+const SUPPORT: TemplateElement =
+    TemplateElement::property("support", "https://www.w3.org/2019/wot/td#supportContact", DataTypeDefXsd::AnyUri, Cardinality::ZeroToOne);
+const ENDPOINT_METADATA: TemplateElement =
+    TemplateElement::new(EndpointMetadata::ID_SHORT, EndpointMetadata::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::One);
+const INTERACTION_METADATA: TemplateElement =
+    TemplateElement::new("InteractionMetadata", "https://admin-shell.io/idta/AssetInterfacesDescription/1/0/InteractionMetadata", AasSubmodelElements::SubmodelElementCollection, Cardinality::One);
+const PROPERTIES: TemplateElement =
+    TemplateElement::new("properties", "https://www.w3.org/2019/wot/td#PropertyAffordance", AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToOne);
+const PROPERTY_DEFINITION: TemplateElement =
+    TemplateElement::new("PropertyDefinition{00}", PropertyDefinition::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToMany);
+const ACTIONS: TemplateElement =
+    TemplateElement::new("actions", "https://www.w3.org/2019/wot/td#ActionAffordance", AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToOne);
+const EVENTS: TemplateElement =
+    TemplateElement::new("events", "https://www.w3.org/2019/wot/td#EventAffordance", AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToOne);
+const EXTERNAL_DESCRIPTOR: TemplateElement =
+    TemplateElement::new("ExternalDescriptor", "https://admin-shell.io/idta/AssetInterfacesDescription/1/0/ExternalDescriptor", AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToOne);
+
+const ELEMENTS: [&TemplateElement; 5] = [&TITLE, &CREATED, &MODIFIED, &SUPPORT, &EXTERNAL_DESCRIPTOR];
+const INTERACTION_ELEMENTS: [&TemplateElement; 3] = [&PROPERTIES, &ACTIONS, &EVENTS];
+
+///Terms of the Thing Description with the semantic IDs and data types of their elements in the
+/// Asset Interfaces Description. Other terms get no semantic ID and the data type of their value.
+const TERMS: [(&str, &str, DataTypeDefXsd); 17] = [
+    ("title", "https://www.w3.org/2019/wot/td#title", DataTypeDefXsd::String),
+    ("description", "https://www.w3.org/2019/wot/td#description", DataTypeDefXsd::String),
+    ("href", "https://www.w3.org/2019/wot/hypermedia#hasTarget", DataTypeDefXsd::AnyUri),
+    ("contentType", "https://www.w3.org/2019/wot/hypermedia#forContentType", DataTypeDefXsd::String),
+    ("subprotocol", "https://www.w3.org/2019/wot/hypermedia#forSubProtocol", DataTypeDefXsd::String),
+    ("op", "https://www.w3.org/2019/wot/td#hasOperationType", DataTypeDefXsd::String),
+    ("type", "http://www.w3.org/1999/02/22-rdf-syntax-ns#type", DataTypeDefXsd::String),
+    ("observable", "https://www.w3.org/2019/wot/td#isObservable", DataTypeDefXsd::Boolean),
+    ("unit", "https://schema.org/unitCode", DataTypeDefXsd::String),
+    ("readOnly", "https://www.w3.org/2019/wot/json-schema#readOnly", DataTypeDefXsd::Boolean),
+    ("writeOnly", "https://www.w3.org/2019/wot/json-schema#writeOnly", DataTypeDefXsd::Boolean),
+    ("minimum", "https://www.w3.org/2019/wot/json-schema#minimum", DataTypeDefXsd::Double),
+    ("maximum", "https://www.w3.org/2019/wot/json-schema#maximum", DataTypeDefXsd::Double),
+    ("scheme", "https://www.w3.org/2019/wot/security#SecurityScheme", DataTypeDefXsd::String),
+    ("in", "https://www.w3.org/2019/wot/security#in", DataTypeDefXsd::String),
+    ("name", "https://www.w3.org/2019/wot/security#name", DataTypeDefXsd::String),
+    ("key", "https://admin-shell.io/idta/AssetInterfacesDescription/1/0/key", DataTypeDefXsd::String)
+];
+
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use crate::enumerations::interface_protocol::InterfaceProtocol;
+    use crate::enumerations::thing_description_error::ThingDescriptionError;
+    use crate::structs::asset_interface::AssetInterface;
+
+    fn thing_description(base: Option<&str>, href: &str) -> Value {
+        let mut thing_description = json!({
+            "title": "Sensor",
+            "securityDefinitions": {"nosec_sc": {"scheme": "nosec"}},
+            "security": "nosec_sc",
+            "properties": {
+                "temperature": {"type": "number", "forms": [{"href": href}]}
+            }
+        });
+        if let Some(base) = base {
+            thing_description["base"] = Value::String(base.to_string());
+        }
+        thing_description
+    }
+
+    fn base(asset_interface: &AssetInterface) -> Option<String> {
+        asset_interface.get_endpoint_metadata().and_then(|endpoint_metadata| endpoint_metadata.get_base())
+    }
+
+    #[test]
+    fn takes_base_of_thing_description() {
+        let asset_interface = AssetInterface::from_thing_description("urn:example:aid", "InterfaceMQTT",
+            &thing_description(Some("mqtt://broker:1883"), "/temperature")).unwrap();
+        assert_eq!(base(&asset_interface).as_deref(), Some("mqtt://broker:1883"));
+        assert!(asset_interface.get_protocol() == Some(InterfaceProtocol::Mqtt));
+    }
+
+    #[test]
+    fn derives_base_from_first_form() {
+        let asset_interface = AssetInterface::from_thing_description("urn:example:aid", "InterfaceHTTP",
+            &thing_description(None, "http://192.168.0.1:8080/sensor/temperature")).unwrap();
+        assert_eq!(base(&asset_interface).as_deref(), Some("http://192.168.0.1:8080"));
+        assert!(asset_interface.get_protocol() == Some(InterfaceProtocol::Http));
+        assert_eq!(asset_interface.get_properties().len(), 1);
+    }
+
+    #[test]
+    fn rejects_relative_form_without_base() {
+        let result = AssetInterface::from_thing_description("urn:example:aid", "InterfaceHTTP",
+            &thing_description(None, "/temperature"));
+        assert!(result.err() == Some(ThingDescriptionError::MissingMember("base".to_string())));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,156 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::modelling_kind::ModellingKind;
+use crate::enumerations::thing_description_error::ThingDescriptionError;
+use crate::structs::asset_interface::AssetInterface;
+use crate::structs::conformance_report::ConformanceReport;
+use crate::structs::submodel::Submodel;
+use crate::structs::template_element::{global_reference, TemplateElement};
+use crate::traits::has_kind::THasKind;
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::identifiable::TIdentifiable;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+use serde_json::Value;
+
+///Typed model of the submodel template Asset Interfaces Description (IDTA 02017-1-0), which
+/// describes the interfaces of an asset, e.g. an HTTP, Modbus or MQTT interface of a controller,
+/// in terms of the W3C WoT Thing Description.
+///
+/// The model wraps the submodel, so the conversion from and to a submodel is lossless and elements
+/// the model does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct AssetInterfacesDescription {
+    ///The wrapped submodel.
+    submodel: Submodel
+}
+
+impl AssetInterfacesDescription {
+    ///Semantic ID of the Asset Interfaces Description submodel.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/idta/AssetInterfacesDescription/1/0/Submodel";
+    ///idShort of the Asset Interfaces Description submodel.
+    pub const ID_SHORT: &str = "AssetInterfacesDescription";
+
+    ///Creates a new Asset Interfaces Description submodel without interfaces.
+    ///
+    /// [id]: global identifier of the submodel
+    pub fn new(id: String) -> AssetInterfacesDescription {
+        let mut submodel = Submodel::new(id);
+        submodel.set_id_short(AssetInterfacesDescription::ID_SHORT.to_string());
+        submodel.set_semantic_id(global_reference(AssetInterfacesDescription::SEMANTIC_ID));
+        submodel.set_kind(ModellingKind::Instance);
+        AssetInterfacesDescription {
+            submodel
+        }
+    }
+
+    ///Wraps a submodel after checking that its interfaces contain the mandatory elements with the
+    /// expected types and valid values.
+    ///
+    /// [submodel]: Asset Interfaces Description submodel
+    pub fn from_submodel(submodel: Submodel) -> Result<AssetInterfacesDescription, ConversionError> {
+        AssetInterfacesDescription::check(&submodel)?;
+        Ok(AssetInterfacesDescription {
+            submodel
+        })
+    }
+
+    ///Returns the wrapped submodel.
+    pub fn get_submodel(&self) -> &Submodel {
+        &self.submodel
+    }
+
+    ///Consumes the Asset Interfaces Description and returns the wrapped submodel.
+    pub fn into_submodel(self) -> Submodel {
+        self.submodel
+    }
+
+    ///Returns the submodel template of the Asset Interfaces Description.
+    pub fn template() -> Submodel {
+        let mut template = Submodel::new("https://admin-shell.io/idta/SubmodelTemplate/AssetInterfacesDescription/1/0".to_string());
+        template.set_id_short(AssetInterfacesDescription::ID_SHORT.to_string());
+        template.set_semantic_id(global_reference(AssetInterfacesDescription::SEMANTIC_ID));
+        template.set_kind(ModellingKind::Template);
+        template.set_submodel_elements(vec![
+            INTERFACE.to_collection(AssetInterface::template_elements())
+        ]);
+        template
+    }
+
+    ///Checks the wrapped submodel against the submodel template of the Asset Interfaces
+    /// Description.
+    pub fn check_conformance(&self) -> ConformanceReport {
+        self.submodel.check_conformance(&AssetInterfacesDescription::template())
+    }
+
+    ///Returns the interfaces.
+    pub fn get_interfaces(&self) -> Vec<AssetInterface> {
+        self.submodel.get_collections(INTERFACE.id_short, INTERFACE.semantic_id).into_iter()
+            .map(|collection| AssetInterface {
+                collection: collection.clone()
+            })
+            .collect()
+    }
+
+    ///Returns the interface with the idShort.
+    ///
+    /// [id_short]: idShort of the interface, e.g. `InterfaceHTTP`
+    pub fn get_interface(&self, id_short: &str) -> Option<AssetInterface> {
+        self.get_interfaces().into_iter()
+            .find(|asset_interface| asset_interface.get_collection().get_id_short().is_some_and(|interface_id_short| interface_id_short == id_short))
+    }
+
+    ///Sets the interfaces. The interfaces keep their idShorts.
+    ///
+    /// [interfaces]: interfaces with unique idShorts
+    pub fn set_interfaces(&mut self, interfaces: Vec<AssetInterface>) {
+        let elements = interfaces.into_iter()
+            .map(|asset_interface| SubmodelElement::SubmodelElementCollection(asset_interface.into_collection()))
+            .collect();
+        self.submodel.set_named_elements(INTERFACE.id_short, INTERFACE.semantic_id, elements);
+    }
+
+    ///Adds an interface after the existing ones, replacing an interface with the same idShort.
+    ///
+    /// [asset_interface]: interface to add
+    pub fn add_interface(&mut self, asset_interface: AssetInterface) {
+        let mut interfaces = self.get_interfaces();
+        interfaces.retain(|existing| existing.get_collection().get_id_short() != asset_interface.get_collection().get_id_short());
+        interfaces.push(asset_interface);
+        self.set_interfaces(interfaces);
+    }
+
+    ///Adds an interface converted from a W3C WoT Thing Description, see
+    /// `AssetInterface::from_thing_description`.
+    ///
+    /// [id_short]: idShort of the interface, e.g. `InterfaceHTTP`
+    /// [document]: Thing Description as JSON document
+    pub fn add_thing_description(&mut self, id_short: &str, document: &str) -> Result<(), ThingDescriptionError> {
+        let thing_description: Value = serde_json::from_str(document)
+            .map_err(|error| ThingDescriptionError::InvalidJson(error.to_string()))?;
+        let asset_interface = AssetInterface::from_thing_description(self.submodel.get_id(), id_short, &thing_description)?;
+        self.add_interface(asset_interface);
+        Ok(())
+    }
+
+    ///Returns the interface with the idShort converted to a W3C WoT Thing Description.
+    ///
+    /// [id_short]: idShort of the interface, e.g. `InterfaceHTTP`
+    pub fn get_thing_description(&self, id_short: &str) -> Option<Value> {
+        self.get_interface(id_short).map(|asset_interface| asset_interface.to_thing_description())
+    }
+
+    fn check(submodel: &Submodel) -> Result<(), ConversionError> {
+        for element in INTERFACE.check(submodel, "")? {
+            if let SubmodelElement::SubmodelElementCollection(collection) = element {
+                AssetInterface::check(collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+const INTERFACE: TemplateElement =
+    TemplateElement::new("Interface{00}", AssetInterface::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToMany);
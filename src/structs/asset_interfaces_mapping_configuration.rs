@@ -0,0 +1,144 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::modelling_kind::ModellingKind;
+use crate::structs::conformance_report::ConformanceReport;
+use crate::structs::mapping_configuration::MappingConfiguration;
+use crate::structs::submodel::Submodel;
+use crate::structs::submodel_element_list::SubmodelElementList;
+use crate::structs::template_element::{global_reference, TemplateElement};
+use crate::traits::has_kind::THasKind;
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed model of the submodel template Asset Interfaces Mapping Configuration (IDTA 02027-1-0),
+/// which maps the property affordances of the interfaces of an Asset Interfaces Description to the
+/// elements of other submodels, e.g. to bind them to live values with `ValueBindings`.
+///
+/// The model wraps the submodel, so the conversion from and to a submodel is lossless and elements
+/// the model does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct AssetInterfacesMappingConfiguration {
+    ///The wrapped submodel.
+    submodel: Submodel
+}
+
+impl AssetInterfacesMappingConfiguration {
+    ///Semantic ID of the Asset Interfaces Mapping Configuration submodel.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/idta/AssetInterfacesMappingConfiguration/1/0/Submodel";
+    ///idShort of the Asset Interfaces Mapping Configuration submodel.
+    pub const ID_SHORT: &str = "AssetInterfacesMappingConfiguration";
+
+    ///Creates a new Asset Interfaces Mapping Configuration submodel without mapping
+    /// configurations.
+    ///
+    /// [id]: global identifier of the submodel
+    pub fn new(id: String) -> AssetInterfacesMappingConfiguration {
+        let mut submodel = Submodel::new(id);
+        submodel.set_id_short(AssetInterfacesMappingConfiguration::ID_SHORT.to_string());
+        submodel.set_semantic_id(global_reference(AssetInterfacesMappingConfiguration::SEMANTIC_ID));
+        submodel.set_kind(ModellingKind::Instance);
+        let mut mapping_configuration = AssetInterfacesMappingConfiguration {
+            submodel
+        };
+        mapping_configuration.set_mapping_configurations(Vec::new());
+        mapping_configuration
+    }
+
+    ///Wraps a submodel after checking that it contains the mapping configurations with the
+    /// expected element types.
+    ///
+    /// [submodel]: Asset Interfaces Mapping Configuration submodel
+    pub fn from_submodel(submodel: Submodel) -> Result<AssetInterfacesMappingConfiguration, ConversionError> {
+        AssetInterfacesMappingConfiguration::check(&submodel)?;
+        Ok(AssetInterfacesMappingConfiguration {
+            submodel
+        })
+    }
+
+    ///Returns the wrapped submodel.
+    pub fn get_submodel(&self) -> &Submodel {
+        &self.submodel
+    }
+
+    ///Consumes the Asset Interfaces Mapping Configuration and returns the wrapped submodel.
+    pub fn into_submodel(self) -> Submodel {
+        self.submodel
+    }
+
+    ///Returns the submodel template of the Asset Interfaces Mapping Configuration.
+    pub fn template() -> Submodel {
+        let mut template = Submodel::new("https://admin-shell.io/idta/SubmodelTemplate/AssetInterfacesMappingConfiguration/1/0".to_string());
+        template.set_id_short(AssetInterfacesMappingConfiguration::ID_SHORT.to_string());
+        template.set_semantic_id(global_reference(AssetInterfacesMappingConfiguration::SEMANTIC_ID));
+        template.set_kind(ModellingKind::Template);
+        template.set_submodel_elements(vec![
+            MAPPING_CONFIGURATIONS.to_list(&MAPPING_CONFIGURATION, MappingConfiguration::template_elements())
+        ]);
+        template
+    }
+
+    ///Checks the wrapped submodel against the submodel template of the Asset Interfaces Mapping
+    /// Configuration.
+    pub fn check_conformance(&self) -> ConformanceReport {
+        self.submodel.check_conformance(&AssetInterfacesMappingConfiguration::template())
+    }
+
+    ///Returns the mapping configurations.
+    pub fn get_mapping_configurations(&self) -> Vec<MappingConfiguration> {
+        self.submodel.get_list(MAPPING_CONFIGURATIONS.id_short, MAPPING_CONFIGURATIONS.semantic_id)
+            .map(|list| list.get_value().iter()
+                .filter_map(|element| match element {
+                    SubmodelElement::SubmodelElementCollection(collection) => Some(MappingConfiguration {
+                        collection: collection.clone()
+                    }),
+                    _ => None
+                })
+                .collect())
+            .unwrap_or_default()
+    }
+
+    ///Sets the mapping configurations.
+    ///
+    /// [mapping_configurations]: mapping configurations, one per interface
+    pub fn set_mapping_configurations(&mut self, mapping_configurations: Vec<MappingConfiguration>) {
+        let mut list = SubmodelElementList::new(AasSubmodelElements::SubmodelElementCollection);
+        list.set_semantic_id_list_element(global_reference(MappingConfiguration::SEMANTIC_ID));
+        list.set_value(mapping_configurations.into_iter()
+            .map(|mapping_configuration| SubmodelElement::SubmodelElementCollection(mapping_configuration.into_collection()))
+            .collect());
+        self.submodel.set_element(MAPPING_CONFIGURATIONS.id_short, MAPPING_CONFIGURATIONS.semantic_id,
+                                  Some(SubmodelElement::SubmodelElementList(list)));
+    }
+
+    ///Adds a mapping configuration after the existing ones.
+    ///
+    /// [mapping_configuration]: mapping configuration to add
+    pub fn add_mapping_configuration(&mut self, mapping_configuration: MappingConfiguration) {
+        let mut mapping_configurations = self.get_mapping_configurations();
+        mapping_configurations.push(mapping_configuration);
+        self.set_mapping_configurations(mapping_configurations);
+    }
+
+    fn check(submodel: &Submodel) -> Result<(), ConversionError> {
+        for element in MAPPING_CONFIGURATIONS.check(submodel, "")? {
+            if let SubmodelElement::SubmodelElementList(list) = element {
+                for (index, mapping_configuration) in list.get_value().iter().enumerate() {
+                    let path = format!("{}[{}]", MAPPING_CONFIGURATIONS.id_short, index);
+                    match mapping_configuration {
+                        SubmodelElement::SubmodelElementCollection(collection) => MappingConfiguration::check(collection, &path)?,
+                        _ => return Err(ConversionError::UnexpectedElementType(path))
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+const MAPPING_CONFIGURATIONS: TemplateElement =
+    TemplateElement::new("MappingConfigurations", "https://admin-shell.io/idta/AssetInterfacesMappingConfiguration/1/0/MappingConfigurations", AasSubmodelElements::SubmodelElementList, Cardinality::One);
+const MAPPING_CONFIGURATION: TemplateElement =
+    TemplateElement::new("MappingConfiguration", MappingConfiguration::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToMany);
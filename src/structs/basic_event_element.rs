@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use crate::enumerations::direction::Direction;
 use crate::enumerations::interface_enumerations::referable::Referable;
 use crate::enumerations::state_of_event::StateOfEvent;
+use crate::structs::embedded_data_specification::EmbeddedDataSpecification;
 use crate::structs::extension::Extension;
 use crate::structs::multi_language_name_type::MultiLanguageNameType;
 use crate::structs::multi_language_text_type::MultiLanguageTextType;
@@ -27,35 +28,40 @@ pub struct BasicEventElement {
     state: StateOfEvent,
     ///Information for the outer message infrastructure to schedule the event for the respective
     /// communication channel.
-    #[serde(rename = "messageTopic")]
+    #[serde(rename = "messageTopic", default, skip_serializing_if = "Option::is_none")]
     message_topic: Option<String>,
     ///Information about which outer message infrastructure shall handle messages for the event
     /// element.
-    #[serde(rename = "messageBroker")]
+    #[serde(rename = "messageBroker", default, skip_serializing_if = "Option::is_none")]
     message_broker: Option<Referable>,
     ///Optional timestamp in UTC when the last event was received or sent.
-    #[serde(rename = "lastUpdate")]
+    #[serde(rename = "lastUpdate", default, skip_serializing_if = "Option::is_none")]
     last_update: Option<String>,
     ///Optional maximum frequency the software entity behind the referable/the outer infrastructure
     /// can handle events.
-    #[serde(rename = "minInterval")]
+    #[serde(rename = "minInterval", default, skip_serializing_if = "Option::is_none")]
     min_interval: Option<String>,
     ///Optional maximum output interval.
-    #[serde(rename = "maxInterval")]
+    #[serde(rename = "maxInterval", default, skip_serializing_if = "Option::is_none")]
     max_interval: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     category: Option<String>,
-    #[serde(rename = "idShort")]
+    #[serde(rename = "idShort", default, skip_serializing_if = "Option::is_none")]
     id_short: Option<String>,
-    #[serde(rename = "displayName")]
+    #[serde(rename = "displayName", default, skip_serializing_if = "Vec::is_empty")]
     display_name: Vec<MultiLanguageNameType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     description: Vec<MultiLanguageTextType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     extensions: Vec<Extension>,
-    #[serde(rename = "semanticId")]
+    #[serde(rename = "semanticId", default, skip_serializing_if = "Option::is_none")]
     semantic_id: Option<Reference>,
-    #[serde(rename = "supplementalSemanticIds")]
+    #[serde(rename = "supplementalSemanticIds", default, skip_serializing_if = "Vec::is_empty")]
     supplemental_semantic_ids: Vec<Reference>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     qualifiers: Vec<Qualifier>,
-    specifications: Vec<Reference>
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    specifications: Vec<EmbeddedDataSpecification>
 }
 
 impl BasicEventElement {
@@ -369,23 +375,23 @@ impl TQualifiable for BasicEventElement {
 }
 
 impl THasDataSpecification for BasicEventElement {
-    fn get_data_specifications(&self) -> &Vec<Reference> {
+    fn get_data_specifications(&self) -> &Vec<EmbeddedDataSpecification> {
         &self.specifications
     }
 
-    fn get_mut_data_specifications(&mut self) -> &mut Vec<Reference> {
+    fn get_mut_data_specifications(&mut self) -> &mut Vec<EmbeddedDataSpecification> {
         &mut self.specifications
     }
 
-    fn set_data_specifications(&mut self, data_specifications: Vec<Reference>) {
+    fn set_data_specifications(&mut self, data_specifications: Vec<EmbeddedDataSpecification>) {
         self.specifications = data_specifications;
     }
 
-    fn add_data_specification(&mut self, data_specification: Reference) {
+    fn add_data_specification(&mut self, data_specification: EmbeddedDataSpecification) {
         self.specifications.push(data_specification);
     }
 
-    fn remove_data_specification(&mut self, index: usize) -> Reference {
+    fn remove_data_specification(&mut self, index: usize) -> EmbeddedDataSpecification {
         self.specifications.remove(index)
     }
 }
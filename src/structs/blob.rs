@@ -1,5 +1,7 @@
-use serde::{Deserialize, Serialize};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+use crate::structs::embedded_data_specification::EmbeddedDataSpecification;
 use crate::structs::extension::Extension;
 use crate::structs::multi_language_name_type::MultiLanguageNameType;
 use crate::structs::multi_language_text_type::MultiLanguageTextType;
@@ -18,24 +20,29 @@ use crate::traits::submodel_element::TSubmodelElement;
 #[derive(PartialEq, Clone, Serialize, Deserialize)]
 pub struct Blob {
     ///The optional value of the blob instance of a blob data element.
+    #[serde(default, skip_serializing_if = "Vec::is_empty", serialize_with = "serialize_value", deserialize_with = "deserialize_value")]
     value: Vec<u8>,
     ///The optional content type of the content of the blob.
-    #[serde(rename = "contentType")]
+    #[serde(rename = "contentType", default, skip_serializing_if = "Option::is_none")]
     content_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     category: Option<String>,
-    #[serde(rename = "idShort")]
+    #[serde(rename = "idShort", default, skip_serializing_if = "Option::is_none")]
     id_short: Option<String>,
-    #[serde(rename = "displayName")]
+    #[serde(rename = "displayName", default, skip_serializing_if = "Vec::is_empty")]
     display_name: Vec<MultiLanguageNameType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     description: Vec<MultiLanguageTextType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     extensions: Vec<Extension>,
-    #[serde(rename = "semanticId")]
+    #[serde(rename = "semanticId", default, skip_serializing_if = "Option::is_none")]
     semantic_id: Option<Reference>,
-    #[serde(rename = "supplementalSemanticIds")]
+    #[serde(rename = "supplementalSemanticIds", default, skip_serializing_if = "Vec::is_empty")]
     supplemental_semantic_ids: Vec<Reference>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     qualifiers: Vec<Qualifier>,
-    #[serde(rename = "embeddedDataSpecifications")]
-    data_specifications: Vec<Reference>
+    #[serde(rename = "embeddedDataSpecifications", default, skip_serializing_if = "Vec::is_empty")]
+    data_specifications: Vec<EmbeddedDataSpecification>
 }
 
 impl Blob {
@@ -238,27 +245,105 @@ impl TQualifiable for Blob {
 }
 
 impl THasDataSpecification for Blob {
-    fn get_data_specifications(&self) -> &Vec<Reference> {
+    fn get_data_specifications(&self) -> &Vec<EmbeddedDataSpecification> {
         &self.data_specifications
     }
 
-    fn get_mut_data_specifications(&mut self) -> &mut Vec<Reference> {
+    fn get_mut_data_specifications(&mut self) -> &mut Vec<EmbeddedDataSpecification> {
         &mut self.data_specifications
     }
 
-    fn set_data_specifications(&mut self, data_specifications: Vec<Reference>) {
+    fn set_data_specifications(&mut self, data_specifications: Vec<EmbeddedDataSpecification>) {
         self.data_specifications = data_specifications;
     }
 
-    fn add_data_specification(&mut self, data_specification: Reference) {
+    fn add_data_specification(&mut self, data_specification: EmbeddedDataSpecification) {
         self.data_specifications.push(data_specification);
     }
 
-    fn remove_data_specification(&mut self, index: usize) -> Reference {
+    fn remove_data_specification(&mut self, index: usize) -> EmbeddedDataSpecification {
         self.data_specifications.remove(index)
     }
 }
 
 impl TDataElement for Blob {
 
+}
+
+///Serializes the value of a blob as base64 text.
+fn serialize_value<S: Serializer>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&encode_base64(value, false))
+}
+
+///Serialized value of a blob. Values are written as base64 text; JSON written by earlier versions
+/// of this crate contains the value as array of bytes, which is still read.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SerializedValue {
+    Base64(String),
+    Bytes(Vec<u8>)
+}
+
+///Deserializes the value of a blob from base64 text or from an array of bytes.
+fn deserialize_value<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    match SerializedValue::deserialize(deserializer)? {
+        SerializedValue::Base64(text) => decode_base64(&text)
+            .ok_or_else(|| D::Error::custom("the value of the blob is no valid base64 text")),
+        SerializedValue::Bytes(bytes) => Ok(bytes)
+    }
+}
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64_URL_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+///Encodes bytes as base64 text. The URL safe alphabet is used without padding, e.g. for the
+/// identifiers in the paths of the HTTP API, the standard alphabet with padding.
+///
+/// [bytes]: bytes to encode
+/// [url_safe]: whether to use the URL safe alphabet
+pub(crate) fn encode_base64(bytes: &[u8], url_safe: bool) -> String {
+    let alphabet = if url_safe { BASE64_URL_ALPHABET } else { BASE64_ALPHABET };
+    let mut text = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let bits = chunk.iter().enumerate().fold(0u32, |bits, (index, byte)| bits | (*byte as u32) << (16 - 8 * index));
+        for index in 0..=chunk.len() {
+            text.push(alphabet[(bits >> (18 - 6 * index) & 0x3f) as usize] as char);
+        }
+        if !url_safe {
+            text.push_str(&"=="[chunk.len() - 1..]);
+        }
+    }
+    text
+}
+
+///Decodes base64 text of the standard or the URL safe alphabet with or without padding. Returns
+/// None if the text is no valid base64.
+///
+/// [text]: text to decode
+pub(crate) fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    let text = text.trim_end_matches('=');
+    let mut bytes = Vec::with_capacity(text.len() * 3 / 4);
+    let mut bits = 0u32;
+    let mut count = 0;
+    for character in text.bytes() {
+        let value = match character {
+            b'A'..=b'Z' => character - b'A',
+            b'a'..=b'z' => character - b'a' + 26,
+            b'0'..=b'9' => character - b'0' + 52,
+            b'+' | b'-' => 62,
+            b'/' | b'_' => 63,
+            _ => return None
+        };
+        bits = bits << 6 | value as u32;
+        count += 6;
+        if count >= 8 {
+            count -= 8;
+            bytes.push((bits >> count) as u8);
+            bits &= (1 << count) - 1;
+        }
+    }
+    match count < 6 {
+        true => Some(bytes),
+        false => None
+    }
 }
\ No newline at end of file
@@ -0,0 +1,402 @@
+use std::collections::{HashMap, VecDeque};
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::bom_node::BomNode;
+use crate::structs::entity::Entity;
+use crate::structs::environment::Environment;
+use crate::structs::hierarchical_structures::HierarchicalStructures;
+use crate::structs::reference::Reference;
+use crate::structs::submodel::Submodel;
+use crate::structs::template_element::join_path;
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::identifiable::TIdentifiable;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Part structure built from the `HasPart`, `IsPartOf` and `SameAs` relationships of Hierarchical
+/// Structures submodels. Entities with the same global asset ID are merged into one node, so the
+/// structure continues from a self-managed entity into the Hierarchical Structures of the shell of
+/// that entity.
+#[derive(PartialEq, Clone, Default)]
+pub struct BomGraph {
+    ///Nodes in the order they were found.
+    nodes: Vec<BomNode>,
+    ///Positions of the nodes by their IDs.
+    index: HashMap<String, usize>,
+    ///Positions of the direct parts of each node.
+    parts: Vec<Vec<usize>>,
+    ///Positions of the assemblies each node is a direct part of.
+    parents: Vec<Vec<usize>>,
+    ///Positions of the nodes standing for the same asset as each node.
+    same_as: Vec<Vec<usize>>
+}
+
+impl BomGraph {
+    ///Creates a new graph without nodes.
+    pub fn new() -> BomGraph {
+        BomGraph::default()
+    }
+
+    ///Builds the graph from all Hierarchical Structures submodels of the environment. References
+    /// to entities of other submodels are resolved within the environment and nodes whose global
+    /// asset ID is the global asset ID of a shell get the ID of that shell.
+    ///
+    /// [environment]: environment with the shells and submodels of the assets
+    pub fn from_environment(environment: &Environment) -> BomGraph {
+        let mut graph = BomGraph::new();
+        let submodels: Vec<&Submodel> = environment.get_submodels().iter()
+            .filter(|submodel| is_hierarchical_structures(submodel))
+            .collect();
+        for submodel in &submodels {
+            graph.add_entities(submodel);
+        }
+        for submodel in &submodels {
+            graph.add_relationships(submodel, environment.get_submodels());
+        }
+        for shell in environment.get_asset_administration_shells() {
+            if let Some(global_asset_id) = shell.get_asset_information().get_global_asset_id()
+                && let Some(position) = graph.index.get(global_asset_id) {
+                graph.nodes[*position].set_shell_id(shell.get_id().clone());
+            }
+        }
+        graph
+    }
+
+    ///Adds the entities and relationships of a Hierarchical Structures submodel. References to
+    /// entities of other submodels cannot be resolved, so they only match nodes without global
+    /// asset ID.
+    ///
+    /// [submodel]: Hierarchical Structures submodel
+    pub fn add_submodel(&mut self, submodel: &Submodel) {
+        self.add_entities(submodel);
+        self.add_relationships(submodel, std::slice::from_ref(submodel));
+    }
+
+    ///Returns the node with the ID.
+    ///
+    /// [id]: global asset ID of the asset or submodel ID and idShortPath of the entity
+    pub fn get_node(&self, id: &str) -> Option<&BomNode> {
+        self.index.get(id).map(|position| &self.nodes[*position])
+    }
+
+    ///Returns all nodes in the order they were found.
+    pub fn get_nodes(&self) -> &Vec<BomNode> {
+        &self.nodes
+    }
+
+    ///Returns the nodes that are no part of another node.
+    pub fn get_roots(&self) -> Vec<&BomNode> {
+        (0..self.nodes.len())
+            .filter(|position| self.parents[*position].is_empty())
+            .map(|position| &self.nodes[position])
+            .collect()
+    }
+
+    ///Returns the direct parts of the node.
+    ///
+    /// [id]: ID of the node
+    pub fn get_parts(&self, id: &str) -> Vec<&BomNode> {
+        self.neighbours(id, &self.parts)
+    }
+
+    ///Returns the assemblies the node is a direct part of.
+    ///
+    /// [id]: ID of the node
+    pub fn get_where_used(&self, id: &str) -> Vec<&BomNode> {
+        self.neighbours(id, &self.parents)
+    }
+
+    ///Returns the nodes standing for the same asset as the node.
+    ///
+    /// [id]: ID of the node
+    pub fn get_same_as(&self, id: &str) -> Vec<&BomNode> {
+        self.neighbours(id, &self.same_as)
+    }
+
+    ///Returns all assemblies the node is part of, directly or through other assemblies, ordered by
+    /// their distance to the node.
+    ///
+    /// [id]: ID of the node
+    pub fn get_ancestors(&self, id: &str) -> Vec<&BomNode> {
+        self.traverse(id, &self.parents, true).into_iter().skip(1).collect()
+    }
+
+    ///Returns the node and all its parts in breadth-first order. Every node is returned once, even
+    /// if it is part of several assemblies. Returns an empty vector if the node is unknown.
+    ///
+    /// [id]: ID of the start node
+    pub fn breadth_first(&self, id: &str) -> Vec<&BomNode> {
+        self.traverse(id, &self.parts, true)
+    }
+
+    ///Returns the node and all its parts in depth-first pre-order. Every node is returned once,
+    /// even if it is part of several assemblies. Returns an empty vector if the node is unknown.
+    ///
+    /// [id]: ID of the start node
+    pub fn depth_first(&self, id: &str) -> Vec<&BomNode> {
+        self.traverse(id, &self.parts, false)
+    }
+
+    fn neighbours(&self, id: &str, edges: &[Vec<usize>]) -> Vec<&BomNode> {
+        self.index.get(id)
+            .map(|position| edges[*position].iter().map(|neighbour| &self.nodes[*neighbour]).collect())
+            .unwrap_or_default()
+    }
+
+    fn traverse(&self, id: &str, edges: &[Vec<usize>], breadth_first: bool) -> Vec<&BomNode> {
+        let mut visited = vec![false; self.nodes.len()];
+        let mut pending: VecDeque<usize> = self.index.get(id).copied().into_iter().collect();
+        let mut result = Vec::new();
+        while let Some(position) = if breadth_first { pending.pop_front() } else { pending.pop_back() } {
+            if visited[position] {
+                continue;
+            }
+            visited[position] = true;
+            result.push(&self.nodes[position]);
+            if breadth_first {
+                pending.extend(edges[position].iter());
+            } else {
+                pending.extend(edges[position].iter().rev());
+            }
+        }
+        result
+    }
+
+    fn add_entities(&mut self, submodel: &Submodel) {
+        for element in submodel.get_submodel_elements() {
+            if let SubmodelElement::Entity(entity) = element {
+                let path = entity.get_id_short().cloned().unwrap_or_default();
+                self.add_entity(submodel.get_id(), &path, entity);
+            }
+        }
+    }
+
+    fn add_entity(&mut self, submodel_id: &str, path: &str, entity: &Entity) {
+        let position = self.get_or_add_node(node_id(submodel_id, path, entity));
+        let node = &mut self.nodes[position];
+        if node.get_submodel_id().is_none() {
+            node.set_submodel_id(submodel_id.to_string());
+            node.set_id_short_path(path.to_string());
+        }
+        if let Some(entity_type) = entity.get_entity_type()
+            && node.get_entity_type().is_none() {
+            node.set_entity_type(entity_type.clone());
+        }
+        if let Some(global_asset_id) = entity.get_global_asset_id() {
+            node.set_global_asset_id(global_asset_id.clone());
+        }
+        for statement in entity.get_statements() {
+            if let SubmodelElement::Entity(part) = statement {
+                let part_path = join_path(path, part.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default());
+                self.add_entity(submodel_id, &part_path, part);
+            }
+        }
+    }
+
+    fn add_relationships(&mut self, submodel: &Submodel, submodels: &[Submodel]) {
+        for element in submodel.get_submodel_elements() {
+            if let SubmodelElement::Entity(entity) = element {
+                self.add_entity_relationships(entity, submodels);
+            }
+        }
+    }
+
+    fn add_entity_relationships(&mut self, entity: &Entity, submodels: &[Submodel]) {
+        for (id_short, semantic_id) in [("HasPart{00}", HierarchicalStructures::HAS_PART_SEMANTIC_ID),
+                                        ("IsPartOf{00}", HierarchicalStructures::IS_PART_OF_SEMANTIC_ID),
+                                        ("SameAs{00}", HierarchicalStructures::SAME_AS_SEMANTIC_ID)] {
+            for element in entity.find_elements(id_short, semantic_id) {
+                let SubmodelElement::RelationshipElement(relationship) = element else {
+                    continue;
+                };
+                let (Some(first), Some(second)) = (relationship.get_first(), relationship.get_second()) else {
+                    continue;
+                };
+                let (Some(first), Some(second)) = (self.resolve(first, submodels), self.resolve(second, submodels)) else {
+                    continue;
+                };
+                match semantic_id {
+                    HierarchicalStructures::HAS_PART_SEMANTIC_ID => self.add_part(first, second),
+                    HierarchicalStructures::IS_PART_OF_SEMANTIC_ID => self.add_part(second, first),
+                    _ => {
+                        add_edge(&mut self.same_as[first], second);
+                        add_edge(&mut self.same_as[second], first);
+                    }
+                }
+            }
+        }
+        for statement in entity.get_statements() {
+            if let SubmodelElement::Entity(part) = statement {
+                self.add_entity_relationships(part, submodels);
+            }
+        }
+    }
+
+    fn add_part(&mut self, assembly: usize, part: usize) {
+        add_edge(&mut self.parts[assembly], part);
+        add_edge(&mut self.parents[part], assembly);
+    }
+
+    ///Returns the position of the node a relationship refers to. Model references are resolved to
+    /// the entity they address, external references stand for the asset with the global asset ID
+    /// of their first key. Returns None for references without keys.
+    fn resolve(&mut self, reference: &Reference, submodels: &[Submodel]) -> Option<usize> {
+        if let Some((submodel_id, path)) = reference.get_submodel_element_path() {
+            let entity = submodels.iter()
+                .find(|submodel| submodel.get_id() == submodel_id)
+                .and_then(|submodel| submodel.get_submodel_element(&path));
+            return match entity {
+                Some(SubmodelElement::Entity(entity)) => {
+                    let position = self.get_or_add_node(node_id(submodel_id, &path, entity));
+                    if self.nodes[position].get_submodel_id().is_none() {
+                        self.add_entity(submodel_id, &path, entity);
+                    }
+                    Some(position)
+                },
+                _ => Some(self.get_or_add_node(format!("{}#{}", submodel_id, path)))
+            };
+        }
+        let first = reference.get_keys().first()?;
+        let position = self.get_or_add_node(first.get_value().clone());
+        self.nodes[position].set_global_asset_id(first.get_value().clone());
+        Some(position)
+    }
+
+    fn get_or_add_node(&mut self, id: String) -> usize {
+        if let Some(position) = self.index.get(&id) {
+            return *position;
+        }
+        let position = self.nodes.len();
+        self.index.insert(id.clone(), position);
+        self.nodes.push(BomNode::new(id));
+        self.parts.push(Vec::new());
+        self.parents.push(Vec::new());
+        self.same_as.push(Vec::new());
+        position
+    }
+}
+
+fn is_hierarchical_structures(submodel: &Submodel) -> bool {
+    submodel.get_semantic_id()
+        .and_then(|semantic_id| semantic_id.get_keys().first())
+        .is_some_and(|key| key.get_value().starts_with("https://admin-shell.io/idta/HierarchicalStructures/"))
+}
+
+///Returns the ID of the node of an entity, i.e. its global asset ID or the ID of the submodel and
+/// its idShortPath.
+fn node_id(submodel_id: &str, path: &str, entity: &Entity) -> String {
+    entity.get_global_asset_id().cloned().unwrap_or_else(|| format!("{}#{}", submodel_id, path))
+}
+
+fn add_edge(edges: &mut Vec<usize>, position: usize) {
+    if !edges.contains(&position) {
+        edges.push(position);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::enumerations::arche_type::ArcheType;
+    use crate::enumerations::entity_type::EntityType;
+    use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+    use crate::enumerations::key_type::KeyType;
+    use crate::enumerations::reference_type::ReferenceType;
+    use crate::structs::bom_graph::BomGraph;
+    use crate::structs::bom_node::BomNode;
+    use crate::structs::entity::Entity;
+    use crate::structs::hierarchical_structures::HierarchicalStructures;
+    use crate::structs::key::Key;
+    use crate::structs::reference::Reference;
+    use crate::structs::relationship_element::RelationshipElement;
+    use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+    const SUBMODEL_ID: &str = "urn:example:pump:hierarchy";
+
+    fn entity_reference(id_shorts: &[&str]) -> Reference {
+        let keys = std::iter::once(Key::new(KeyType::Submodel, SUBMODEL_ID.to_string()))
+            .chain(id_shorts.iter().map(|id_short| Key::new(KeyType::Entity, id_short.to_string())))
+            .collect();
+        Reference::new(ReferenceType::ModelReference, keys)
+    }
+
+    fn has_part(first: Reference, second: Reference) -> SubmodelElement {
+        let mut relationship = RelationshipElement::new();
+        relationship.set_first(first);
+        relationship.set_second(second);
+        SubmodelElement::RelationshipElement(relationship)
+    }
+
+    ///Creates the hierarchy of a pump with a motor and a housing, which is extended by the
+    /// function before the graph is built.
+    fn graph(extend: impl FnOnce(&mut Entity)) -> BomGraph {
+        let mut entry_node = Entity::new();
+        entry_node.set_entity_type(EntityType::SelfManagedEntity);
+        entry_node.set_global_asset_id("urn:example:pump".to_string());
+        let mut hierarchy = HierarchicalStructures::new(SUBMODEL_ID.to_string(), ArcheType::Full, entry_node);
+        hierarchy.add_part("Motor", EntityType::SelfManagedEntity, Some("urn:example:motor".to_string()), None).unwrap();
+        hierarchy.add_part("Housing", EntityType::CoManagedEntity, None, None).unwrap();
+        extend(hierarchy.get_mut_entry_node().unwrap());
+
+        let mut graph = BomGraph::new();
+        graph.add_submodel(hierarchy.get_submodel());
+        graph
+    }
+
+    fn ids(nodes: Vec<&BomNode>) -> Vec<&str> {
+        nodes.into_iter().map(|node| node.get_id().as_str()).collect()
+    }
+
+    #[test]
+    fn builds_parts_of_entities() {
+        let graph = graph(|_| {});
+        let housing = format!("{}#EntryNode.Housing", SUBMODEL_ID);
+
+        assert!(ids(graph.get_roots()) == vec!["urn:example:pump"]);
+        assert!(ids(graph.get_parts("urn:example:pump")) == vec!["urn:example:motor", housing.as_str()]);
+        assert!(ids(graph.get_where_used(&housing)) == vec!["urn:example:pump"]);
+        let housing = graph.get_node(&housing).unwrap();
+        assert!(housing.get_entity_type() == Some(&EntityType::CoManagedEntity));
+        assert!(housing.get_id_short_path() == Some(&"EntryNode.Housing".to_string()));
+    }
+
+    #[test]
+    fn keeps_unresolved_references_as_nodes_without_entity() {
+        let graph = graph(|pump| {
+            pump.add_element("HasPart{00}", HierarchicalStructures::HAS_PART_SEMANTIC_ID,
+                             has_part(entity_reference(&["EntryNode"]), entity_reference(&["EntryNode", "Missing"])));
+            pump.add_element("HasPart{00}", HierarchicalStructures::HAS_PART_SEMANTIC_ID,
+                             has_part(entity_reference(&["EntryNode"]), Reference::new(ReferenceType::ExternalReference,
+                                 vec![Key::new(KeyType::GlobalReference, "urn:example:bearing".to_string())])));
+            pump.add_element("HasPart{00}", HierarchicalStructures::HAS_PART_SEMANTIC_ID,
+                             has_part(entity_reference(&["EntryNode"]), Reference::new(ReferenceType::ExternalReference, Vec::new())));
+        });
+        let missing = format!("{}#EntryNode.Missing", SUBMODEL_ID);
+
+        assert!(ids(graph.get_parts("urn:example:pump")).len() == 4);
+        let missing = graph.get_node(&missing).unwrap();
+        assert!(missing.get_submodel_id().is_none() && missing.get_entity_type().is_none());
+        let bearing = graph.get_node("urn:example:bearing").unwrap();
+        assert!(bearing.get_global_asset_id() == Some(&"urn:example:bearing".to_string()));
+        assert!(bearing.get_submodel_id().is_none());
+    }
+
+    #[test]
+    fn traverses_cycles_once() {
+        let graph = graph(|pump| {
+            let Some(SubmodelElement::Entity(motor)) = pump.get_mut_statements().iter_mut()
+                .find(|statement| statement.get_id_short().is_some_and(|id_short| id_short == "Motor")) else {
+                panic!("the motor was added as part");
+            };
+            motor.add_element("HasPart{00}", HierarchicalStructures::HAS_PART_SEMANTIC_ID,
+                              has_part(entity_reference(&["EntryNode", "Motor"]), entity_reference(&["EntryNode"])));
+        });
+        let housing = format!("{}#EntryNode.Housing", SUBMODEL_ID);
+
+        assert!(graph.get_roots().is_empty());
+        assert!(ids(graph.breadth_first("urn:example:pump")) == vec!["urn:example:pump", "urn:example:motor", housing.as_str()]);
+        assert!(ids(graph.depth_first("urn:example:motor")) == vec!["urn:example:motor", "urn:example:pump", housing.as_str()]);
+        assert!(ids(graph.get_ancestors("urn:example:motor")) == vec!["urn:example:pump"]);
+        assert!(ids(graph.get_ancestors(&housing)) == vec!["urn:example:pump", "urn:example:motor"]);
+        assert!(graph.breadth_first("urn:example:unknown").is_empty());
+    }
+}
\ No newline at end of file
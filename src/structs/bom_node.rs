@@ -0,0 +1,105 @@
+use crate::enumerations::entity_type::EntityType;
+
+///Node of a `BomGraph`, i.e. an asset of the part structure. Entities with the same global asset
+/// ID are merged into one node, so a self-managed entity and the entry node of the Hierarchical
+/// Structures of its own shell are the same node.
+#[derive(PartialEq, Clone)]
+pub struct BomNode {
+    ///Global asset ID of the asset, or for entities without one the ID of the submodel and the
+    /// idShortPath of the entity separated by `#`.
+    id: String,
+    ///Global asset ID of the asset.
+    global_asset_id: Option<String>,
+    ///Whether the asset has an own Asset Administration Shell.
+    entity_type: Option<EntityType>,
+    ///ID of the submodel containing the first entity of the asset.
+    submodel_id: Option<String>,
+    ///IdShortPath of the first entity of the asset within its submodel.
+    id_short_path: Option<String>,
+    ///ID of the Asset Administration Shell of the asset.
+    shell_id: Option<String>
+}
+
+impl BomNode {
+    ///Creates a new instance of the struct.
+    ///
+    /// [id]: global asset ID of the asset or submodel ID and idShortPath of the entity
+    pub fn new(id: String) -> BomNode {
+        BomNode {
+            id,
+            global_asset_id: None,
+            entity_type: None,
+            submodel_id: None,
+            id_short_path: None,
+            shell_id: None
+        }
+    }
+
+    ///Returns the ID of the node, i.e. the global asset ID of the asset or, for entities without
+    /// one, the ID of the submodel and the idShortPath of the entity separated by `#`.
+    pub fn get_id(&self) -> &String {
+        &self.id
+    }
+
+    ///Sets the global asset ID of the asset.
+    ///
+    /// [global_asset_id]: global asset ID of the asset
+    pub fn set_global_asset_id(&mut self, global_asset_id: String) {
+        self.global_asset_id = Some(global_asset_id);
+    }
+
+    ///Returns the global asset ID of the asset.
+    pub fn get_global_asset_id(&self) -> Option<&String> {
+        self.global_asset_id.as_ref()
+    }
+
+    ///Sets whether the asset has an own Asset Administration Shell.
+    ///
+    /// [entity_type]: entity type of the asset
+    pub fn set_entity_type(&mut self, entity_type: EntityType) {
+        self.entity_type = Some(entity_type);
+    }
+
+    ///Returns whether the asset has an own Asset Administration Shell.
+    pub fn get_entity_type(&self) -> Option<&EntityType> {
+        self.entity_type.as_ref()
+    }
+
+    ///Sets the ID of the submodel containing the first entity of the asset.
+    ///
+    /// [submodel_id]: ID of the submodel
+    pub fn set_submodel_id(&mut self, submodel_id: String) {
+        self.submodel_id = Some(submodel_id);
+    }
+
+    ///Returns the ID of the submodel containing the first entity of the asset. Returns None if the
+    /// node is only known from a reference.
+    pub fn get_submodel_id(&self) -> Option<&String> {
+        self.submodel_id.as_ref()
+    }
+
+    ///Sets the idShortPath of the first entity of the asset within its submodel.
+    ///
+    /// [id_short_path]: idShortPath of the entity
+    pub fn set_id_short_path(&mut self, id_short_path: String) {
+        self.id_short_path = Some(id_short_path);
+    }
+
+    ///Returns the idShortPath of the first entity of the asset within its submodel.
+    pub fn get_id_short_path(&self) -> Option<&String> {
+        self.id_short_path.as_ref()
+    }
+
+    ///Sets the ID of the Asset Administration Shell of the asset.
+    ///
+    /// [shell_id]: ID of the Asset Administration Shell
+    pub fn set_shell_id(&mut self, shell_id: String) {
+        self.shell_id = Some(shell_id);
+    }
+
+    ///Returns the ID of the Asset Administration Shell whose global asset ID is the global asset ID
+    /// of the node.
+    pub fn get_shell_id(&self) -> Option<&String> {
+        self.shell_id.as_ref()
+    }
+}
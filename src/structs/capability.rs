@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use crate::structs::embedded_data_specification::EmbeddedDataSpecification;
 use crate::structs::extension::Extension;
 use crate::structs::multi_language_name_type::MultiLanguageNameType;
 use crate::structs::multi_language_text_type::MultiLanguageTextType;
@@ -16,20 +17,24 @@ use crate::traits::submodel_element::TSubmodelElement;
 /// achieve a certain effect in the physical or virtual world.
 #[derive(PartialEq, Clone, Serialize, Deserialize)]
 pub struct Capability {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     category: Option<String>,
-    #[serde(rename = "idShort")]
+    #[serde(rename = "idShort", default, skip_serializing_if = "Option::is_none")]
     id_short: Option<String>,
-    #[serde(rename = "displayName")]
+    #[serde(rename = "displayName", default, skip_serializing_if = "Vec::is_empty")]
     display_name: Vec<MultiLanguageNameType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     description: Vec<MultiLanguageTextType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     extensions: Vec<Extension>,
-    #[serde(rename = "semanticId")]
+    #[serde(rename = "semanticId", default, skip_serializing_if = "Option::is_none")]
     semantic_id: Option<Reference>,
-    #[serde(rename = "supplementalSemanticIds")]
+    #[serde(rename = "supplementalSemanticIds", default, skip_serializing_if = "Vec::is_empty")]
     supplemental_semantic_ids: Vec<Reference>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     qualifiers: Vec<Qualifier>,
-    #[serde(rename = "embeddedDataSpecifications")]
-    data_specifications: Vec<Reference>
+    #[serde(rename = "embeddedDataSpecifications", default, skip_serializing_if = "Vec::is_empty")]
+    data_specifications: Vec<EmbeddedDataSpecification>
 }
 
 impl Capability {
@@ -194,23 +199,23 @@ impl TQualifiable for Capability {
 }
 
 impl THasDataSpecification for Capability {
-    fn get_data_specifications(&self) -> &Vec<Reference> {
+    fn get_data_specifications(&self) -> &Vec<EmbeddedDataSpecification> {
         &self.data_specifications
     }
 
-    fn get_mut_data_specifications(&mut self) -> &mut Vec<Reference> {
+    fn get_mut_data_specifications(&mut self) -> &mut Vec<EmbeddedDataSpecification> {
         &mut self.data_specifications
     }
 
-    fn set_data_specifications(&mut self, data_specifications: Vec<Reference>) {
+    fn set_data_specifications(&mut self, data_specifications: Vec<EmbeddedDataSpecification>) {
         self.data_specifications = data_specifications;
     }
 
-    fn add_data_specification(&mut self, data_specification: Reference) {
+    fn add_data_specification(&mut self, data_specification: EmbeddedDataSpecification) {
         self.data_specifications.push(data_specification);
     }
 
-    fn remove_data_specification(&mut self, index: usize) -> Reference {
+    fn remove_data_specification(&mut self, index: usize) -> EmbeddedDataSpecification {
         self.data_specifications.remove(index)
     }
 }
@@ -0,0 +1,157 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::modelling_kind::ModellingKind;
+use crate::structs::conformance_report::ConformanceReport;
+use crate::structs::product_carbon_footprint::ProductCarbonFootprint;
+use crate::structs::submodel::Submodel;
+use crate::structs::template_element::{global_reference, TemplateElement};
+use crate::structs::transport_carbon_footprint::TransportCarbonFootprint;
+use crate::traits::has_kind::THasKind;
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed model of the submodel template Carbon Footprint (IDTA 02023-0-9), which contains the
+/// product and transport carbon footprints of an asset.
+///
+/// The model wraps the submodel, so the conversion from and to a submodel is lossless and elements
+/// the model does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct CarbonFootprint {
+    ///The wrapped submodel.
+    submodel: Submodel
+}
+
+impl CarbonFootprint {
+    ///Semantic ID of the Carbon Footprint submodel.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/idta/CarbonFootprint/CarbonFootprint/0/9";
+    ///idShort of the Carbon Footprint submodel.
+    pub const ID_SHORT: &str = "CarbonFootprint";
+
+    ///Creates a new Carbon Footprint submodel without footprints.
+    ///
+    /// [id]: global identifier of the submodel
+    pub fn new(id: String) -> CarbonFootprint {
+        let mut submodel = Submodel::new(id);
+        submodel.set_id_short(CarbonFootprint::ID_SHORT.to_string());
+        submodel.set_semantic_id(global_reference(CarbonFootprint::SEMANTIC_ID));
+        submodel.set_kind(ModellingKind::Instance);
+        CarbonFootprint {
+            submodel
+        }
+    }
+
+    ///Wraps a submodel after checking that it contains the mandatory elements of the Carbon Footprint
+    /// with the expected types and valid values.
+    ///
+    /// [submodel]: Carbon Footprint submodel
+    pub fn from_submodel(submodel: Submodel) -> Result<CarbonFootprint, ConversionError> {
+        CarbonFootprint::check(&submodel)?;
+        Ok(CarbonFootprint {
+            submodel
+        })
+    }
+
+    ///Returns the wrapped submodel.
+    pub fn get_submodel(&self) -> &Submodel {
+        &self.submodel
+    }
+
+    ///Consumes the Carbon Footprint and returns the wrapped submodel.
+    pub fn into_submodel(self) -> Submodel {
+        self.submodel
+    }
+
+    ///Returns the submodel template of the Carbon Footprint.
+    pub fn template() -> Submodel {
+        let mut template = Submodel::new("https://admin-shell.io/idta/SubmodelTemplate/CarbonFootprint/0/9".to_string());
+        template.set_id_short(CarbonFootprint::ID_SHORT.to_string());
+        template.set_semantic_id(global_reference(CarbonFootprint::SEMANTIC_ID));
+        template.set_kind(ModellingKind::Template);
+        template.set_submodel_elements(vec![
+            PRODUCT_CARBON_FOOTPRINT.to_collection(ProductCarbonFootprint::template_elements()),
+            TRANSPORT_CARBON_FOOTPRINT.to_collection(TransportCarbonFootprint::template_elements())
+        ]);
+        template
+    }
+
+    ///Checks the wrapped submodel against the submodel template of the Carbon Footprint.
+    pub fn check_conformance(&self) -> ConformanceReport {
+        self.submodel.check_conformance(&CarbonFootprint::template())
+    }
+
+    ///Returns the product carbon footprints.
+    pub fn get_product_carbon_footprints(&self) -> Vec<ProductCarbonFootprint> {
+        self.submodel.get_collections(PRODUCT_CARBON_FOOTPRINT.id_short, PRODUCT_CARBON_FOOTPRINT.semantic_id).into_iter()
+            .map(|collection| ProductCarbonFootprint {
+                collection: collection.clone()
+            })
+            .collect()
+    }
+
+    ///Sets the product carbon footprints.
+    ///
+    /// [product_carbon_footprints]: product carbon footprints
+    pub fn set_product_carbon_footprints(&mut self, product_carbon_footprints: Vec<ProductCarbonFootprint>) {
+        let elements = product_carbon_footprints.into_iter()
+            .map(|item| SubmodelElement::SubmodelElementCollection(item.into_collection()))
+            .collect();
+        self.submodel.set_elements(PRODUCT_CARBON_FOOTPRINT.id_short, PRODUCT_CARBON_FOOTPRINT.semantic_id, elements);
+    }
+
+    ///Returns the transport carbon footprints.
+    pub fn get_transport_carbon_footprints(&self) -> Vec<TransportCarbonFootprint> {
+        self.submodel.get_collections(TRANSPORT_CARBON_FOOTPRINT.id_short, TRANSPORT_CARBON_FOOTPRINT.semantic_id).into_iter()
+            .map(|collection| TransportCarbonFootprint {
+                collection: collection.clone()
+            })
+            .collect()
+    }
+
+    ///Sets the transport carbon footprints.
+    ///
+    /// [transport_carbon_footprints]: transport carbon footprints
+    pub fn set_transport_carbon_footprints(&mut self, transport_carbon_footprints: Vec<TransportCarbonFootprint>) {
+        let elements = transport_carbon_footprints.into_iter()
+            .map(|item| SubmodelElement::SubmodelElementCollection(item.into_collection()))
+            .collect();
+        self.submodel.set_elements(TRANSPORT_CARBON_FOOTPRINT.id_short, TRANSPORT_CARBON_FOOTPRINT.semantic_id, elements);
+    }
+
+    ///Returns the sum of the emissions of all product carbon footprints in kg CO2 equivalents.
+    /// Footprints usually cover distinct life cycle phases, so their emissions add up.
+    pub fn get_total_pcf_co2eq(&self) -> f64 {
+        self.get_product_carbon_footprints().iter().filter_map(|footprint| footprint.get_pcf_co2eq()).sum()
+    }
+
+    ///Returns the sum of the emissions of all transport carbon footprints in kg CO2 equivalents.
+    pub fn get_total_tcf_co2eq(&self) -> f64 {
+        self.get_transport_carbon_footprints().iter().filter_map(|footprint| footprint.get_tcf_co2eq()).sum()
+    }
+
+    fn check(submodel: &Submodel) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(submodel, "")?;
+        }
+        for element in PRODUCT_CARBON_FOOTPRINT.check(submodel, "")? {
+            if let SubmodelElement::SubmodelElementCollection(collection) = element {
+                ProductCarbonFootprint::check(collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+            }
+        }
+        for element in TRANSPORT_CARBON_FOOTPRINT.check(submodel, "")? {
+            if let SubmodelElement::SubmodelElementCollection(collection) = element {
+                TransportCarbonFootprint::check(collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+const PRODUCT_CARBON_FOOTPRINT: TemplateElement =
+    TemplateElement::new("ProductCarbonFootprint{00}", ProductCarbonFootprint::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToMany);
+const TRANSPORT_CARBON_FOOTPRINT: TemplateElement =
+    TemplateElement::new("TransportCarbonFootprint{00}", TransportCarbonFootprint::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToMany);
+
+const ELEMENTS: [&TemplateElement; 2] = [&PRODUCT_CARBON_FOOTPRINT, &TRANSPORT_CARBON_FOOTPRINT];
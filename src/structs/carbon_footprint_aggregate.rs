@@ -0,0 +1,84 @@
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::carbon_footprint::CarbonFootprint;
+use crate::structs::entity::Entity;
+use crate::structs::template_element::join_path;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Emissions of the carbon footprints summed over the entity hierarchy of a bill of materials, e.g.
+/// the entry node of a Hierarchical Structures submodel and the entities of its parts.
+#[derive(PartialEq, Clone)]
+pub struct CarbonFootprintAggregate {
+    ///Sum of the product carbon footprints in kg CO2 equivalents.
+    pcf_co2eq: f64,
+    ///Sum of the transport carbon footprints in kg CO2 equivalents.
+    tcf_co2eq: f64,
+    ///IdShortPaths of the entities without carbon footprint, starting at the root entity.
+    entities_without_footprint: Vec<String>
+}
+
+impl CarbonFootprintAggregate {
+    ///Sums the emissions of the root entity and all entities below it. The parts of an entity are
+    /// the entities among its statements. Each entity counts with the number of times it occurs in
+    /// the root, i.e. the product of its `BulkCount` and the bulk counts of its parents, which
+    /// default to 1.
+    ///
+    /// The emissions of every entity with a footprint are added, so if the footprint of an
+    /// assembly already covers its parts, the closure should only return footprints of the parts.
+    ///
+    /// [root]: root entity of the bill of materials
+    /// [footprint_of]: returns the carbon footprint of an entity, e.g. by its global asset ID
+    pub fn aggregate<'a, F: Fn(&Entity) -> Option<&'a CarbonFootprint>>(root: &Entity, footprint_of: F)
+        -> CarbonFootprintAggregate {
+        let mut aggregate = CarbonFootprintAggregate {
+            pcf_co2eq: 0.0,
+            tcf_co2eq: 0.0,
+            entities_without_footprint: Vec::new()
+        };
+        let path = root.get_id_short().cloned().unwrap_or_default();
+        aggregate.add(root, &path, 1.0, &footprint_of);
+        aggregate
+    }
+
+    ///Returns the sum of the product carbon footprints in kg CO2 equivalents.
+    pub fn get_pcf_co2eq(&self) -> f64 {
+        self.pcf_co2eq
+    }
+
+    ///Returns the sum of the transport carbon footprints in kg CO2 equivalents.
+    pub fn get_tcf_co2eq(&self) -> f64 {
+        self.tcf_co2eq
+    }
+
+    ///Returns the sum of the product and transport carbon footprints in kg CO2 equivalents.
+    pub fn get_total_co2eq(&self) -> f64 {
+        self.pcf_co2eq + self.tcf_co2eq
+    }
+
+    ///Returns the idShortPaths of the entities without carbon footprint, starting at the root
+    /// entity. If the list is not empty, the sums are incomplete.
+    pub fn get_entities_without_footprint(&self) -> &Vec<String> {
+        &self.entities_without_footprint
+    }
+
+    fn add<'a, F: Fn(&Entity) -> Option<&'a CarbonFootprint>>(&mut self, entity: &Entity, path: &str, count: f64,
+                                                                  footprint_of: &F) {
+        let count = count * entity.get_property_value::<f64>(BULK_COUNT_ID_SHORT, BULK_COUNT_SEMANTIC_ID).unwrap_or(1.0);
+        match footprint_of(entity) {
+            Some(footprint) => {
+                self.pcf_co2eq += count * footprint.get_total_pcf_co2eq();
+                self.tcf_co2eq += count * footprint.get_total_tcf_co2eq();
+            },
+            None => self.entities_without_footprint.push(path.to_string())
+        }
+        for statement in entity.get_statements() {
+            if let SubmodelElement::Entity(part) = statement {
+                let part_path = join_path(path, part.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default());
+                self.add(part, &part_path, count, footprint_of);
+            }
+        }
+    }
+}
+
+const BULK_COUNT_ID_SHORT: &str = "BulkCount";
+const BULK_COUNT_SEMANTIC_ID: &str = "https://admin-shell.io/idta/HierarchicalStructures/BulkCount/1/0";
@@ -0,0 +1,859 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::code_generation_error::CodeGenerationError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::modelling_kind::ModellingKind;
+use crate::structs::environment::Environment;
+use crate::structs::multi_language_text_type::MultiLanguageTextType;
+use crate::structs::reference::Reference;
+use crate::structs::submodel::Submodel;
+use crate::traits::has_kind::THasKind;
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::identifiable::TIdentifiable;
+use crate::traits::qualifiable::TQualifiable;
+use crate::traits::referable::TReferable;
+
+///Generator of Rust structs from a submodel template, e.g. for use in a build script.
+///
+/// Every submodel element collection of the template becomes a struct, the submodel itself the
+/// top level struct. Properties and ranges become fields of the Rust type matching their value
+/// type, multi-language properties, files, blobs and reference elements fields of the matching
+/// value type and submodel element lists vectors of their elements. All other elements are kept as
+/// generic submodel elements. Fields of optional elements are wrapped in `Option`, fields of
+/// elements that may occur several times in `Vec`, according to the SMT cardinality.
+///
+/// Each struct gets a `from_elements` and a `to_elements` function converting from and to generic
+/// submodel elements, the top level struct additionally `from_submodel` and `to_submodel`.
+/// Elements are recognized by their semantic ID or, if the template element has none, by their
+/// idShort. Elements that may occur several times get numbered idShorts, e.g. `Marking01` and
+/// `Marking02`, replacing the placeholder `{00}` of the template idShort or appended to it.
+#[derive(PartialEq, Clone)]
+pub struct CodeGenerator {
+    ///Path of this crate in the generated code.
+    crate_path: String,
+    ///Name of the top level struct.
+    struct_name: Option<String>
+}
+
+impl CodeGenerator {
+    ///Creates a new instance of the struct. The generated code refers to this crate as
+    /// `aas_model_rs` and the top level struct is named after the idShort of the template.
+    pub fn new() -> CodeGenerator {
+        CodeGenerator {
+            crate_path: "aas_model_rs".to_string(),
+            struct_name: None
+        }
+    }
+
+    ///Sets the path of this crate in the generated code.
+    ///
+    /// [crate_path]: path of this crate, e.g. `crate` when generating code within this crate
+    pub fn set_crate_path(&mut self, crate_path: String) {
+        self.crate_path = crate_path;
+    }
+
+    ///Returns the path of this crate in the generated code.
+    pub fn get_crate_path(&self) -> &String {
+        &self.crate_path
+    }
+
+    ///Sets the name of the top level struct.
+    ///
+    /// [struct_name]: name of the top level struct
+    pub fn set_struct_name(&mut self, struct_name: String) {
+        self.struct_name = Some(struct_name);
+    }
+
+    ///Returns the name of the top level struct, if it is not derived from the template.
+    pub fn get_struct_name(&self) -> Option<&String> {
+        self.struct_name.as_ref()
+    }
+
+    ///Generates the Rust source code for the submodel template.
+    ///
+    /// [template]: submodel template
+    pub fn generate(&self, template: &Submodel) -> String {
+        let mut generation = Generation {
+            crate_path: &self.crate_path,
+            types: Vec::new(),
+            imports: BTreeSet::new(),
+            helpers: BTreeSet::new()
+        };
+        let name = match &self.struct_name {
+            Some(name) => name.clone(),
+            None => to_pascal_case(template.get_id_short().map(|id_short| id_short.as_str()).unwrap_or("Submodel"))
+        };
+        generation.define_type(name, get_doc(template.get_description()), template.get_submodel_elements());
+        generation.emit(template)
+    }
+
+    ///Reads a submodel template from a JSON file and writes the generated Rust source code to a
+    /// file. The JSON file contains either the submodel itself or an environment, in which case the
+    /// first submodel template of the environment is used. The generated file is meant to be
+    /// included with `include!`, typically from the `OUT_DIR` of a build script.
+    ///
+    /// [template_path]: path of the JSON file containing the submodel template
+    /// [output_path]: path of the Rust file to write
+    pub fn generate_file(&self, template_path: &Path, output_path: &Path) -> Result<(), CodeGenerationError> {
+        let json = fs::read_to_string(template_path).map_err(|error| CodeGenerationError::Io(error.to_string()))?;
+        let template = match serde_json::from_str::<Submodel>(&json) {
+            Ok(template) => template,
+            Err(error) => serde_json::from_str::<Environment>(&json).ok()
+                .and_then(|environment| {
+                    let submodels = environment.get_submodels();
+                    submodels.iter().find(|submodel| submodel.get_kind() == Some(&ModellingKind::Template))
+                        .or(submodels.first())
+                        .cloned()
+                })
+                .ok_or_else(|| CodeGenerationError::InvalidTemplate(error.to_string()))?
+        };
+        fs::write(output_path, self.generate(&template)).map_err(|error| CodeGenerationError::Io(error.to_string()))
+    }
+}
+
+impl Default for CodeGenerator {
+    fn default() -> CodeGenerator {
+        CodeGenerator::new()
+    }
+}
+
+struct TypeDefinition {
+    name: String,
+    doc: Option<String>,
+    fields: Vec<FieldDefinition>
+}
+
+struct FieldDefinition {
+    name: String,
+    doc: Option<String>,
+    id_short: String,
+    semantic_id: Option<String>,
+    cardinality: Cardinality,
+    kind: FieldKind
+}
+
+impl FieldDefinition {
+    ///Returns the idShort of the elements of a field that may occur several times, which contains
+    /// the placeholder `{00}` for the number of the element, e.g. `Marking{00}` for `Marking01`.
+    fn get_numbered_id_short(&self) -> String {
+        if self.id_short.contains("{00}") {
+            self.id_short.clone()
+        } else {
+            format!("{}{{00}}", self.id_short)
+        }
+    }
+}
+
+enum FieldKind {
+    Property(DataTypeDefXsd),
+    MultiLanguageProperty,
+    Range(DataTypeDefXsd),
+    File(Option<String>),
+    Blob(Option<String>),
+    ReferenceElement,
+    Collection(String),
+    List(Box<ListDefinition>),
+    Raw
+}
+
+struct ListDefinition {
+    item: FieldKind,
+    item_semantic_id: Option<String>,
+    type_value_list_element: AasSubmodelElements,
+    value_type_list_element: Option<DataTypeDefXsd>
+}
+
+///State of a single code generation.
+struct Generation<'a> {
+    crate_path: &'a str,
+    types: Vec<TypeDefinition>,
+    imports: BTreeSet<&'static str>,
+    helpers: BTreeSet<&'static str>
+}
+
+impl Generation<'_> {
+    ///Defines a struct for the elements and returns its unique name.
+    fn define_type(&mut self, name: String, doc: Option<String>, elements: &[SubmodelElement]) -> String {
+        let name = unique_name(name, |candidate| self.types.iter().any(|definition| definition.name == candidate));
+        let index = self.types.len();
+        self.types.push(TypeDefinition {
+            name: name.clone(),
+            doc,
+            fields: Vec::new()
+        });
+
+        let mut fields: Vec<FieldDefinition> = Vec::new();
+        for element in elements {
+            let id_short = element.get_id_short().cloned().unwrap_or_default();
+            let field_name = unique_name(to_snake_case(&id_short), |candidate| {
+                fields.iter().any(|field| field.name == candidate)
+            });
+            let kind = self.define_kind(element, &name, &id_short);
+            fields.push(FieldDefinition {
+                name: field_name,
+                doc: get_doc(element.get_description()),
+                id_short,
+                semantic_id: get_first_key(element.get_semantic_id()),
+                cardinality: element.get_cardinality().unwrap_or(Cardinality::One),
+                kind
+            });
+        }
+        self.types[index].fields = fields;
+        name
+    }
+
+    fn define_kind(&mut self, element: &SubmodelElement, owner: &str, id_short: &str) -> FieldKind {
+        match element {
+            SubmodelElement::Property(property) => FieldKind::Property(property.get_value_type().clone()),
+            SubmodelElement::MultiLanguageProperty(_) => FieldKind::MultiLanguageProperty,
+            SubmodelElement::Range(range) => FieldKind::Range(range.get_value_type().clone()),
+            SubmodelElement::File(file) => FieldKind::File(file.get_content_type().cloned()),
+            SubmodelElement::Blob(blob) => FieldKind::Blob(blob.get_content_type().cloned()),
+            SubmodelElement::ReferenceElement(_) => FieldKind::ReferenceElement,
+            SubmodelElement::SubmodelElementCollection(collection) => {
+                let mut name = to_pascal_case(id_short);
+                if self.types.iter().any(|definition| definition.name == name) {
+                    name = format!("{}{}", owner, name);
+                }
+                FieldKind::Collection(self.define_type(name, get_doc(collection.get_description()), collection.get_value()))
+            },
+            SubmodelElement::SubmodelElementList(list) => match list.get_value().first() {
+                Some(item) => {
+                    let item_id_short = item.get_id_short().cloned().unwrap_or_else(|| format!("{}Item", id_short));
+                    FieldKind::List(Box::new(ListDefinition {
+                        item: self.define_kind(item, owner, &item_id_short),
+                        item_semantic_id: get_first_key(list.get_semantic_id_list_element().or(item.get_semantic_id())),
+                        type_value_list_element: list.get_type_value_list_element().clone(),
+                        value_type_list_element: list.get_value_type_list_element().cloned()
+                    }))
+                },
+                None => FieldKind::Raw
+            },
+            _ => FieldKind::Raw
+        }
+    }
+
+    fn import(&mut self, path: &'static str) {
+        self.imports.insert(path);
+    }
+
+    fn helper(&mut self, name: &'static str) {
+        if self.helpers.insert(name) {
+            let (_, imports, dependencies) = helper_source(name);
+            imports.iter().for_each(|path| self.import(path));
+            dependencies.iter().for_each(|dependency| self.helper(dependency));
+        }
+    }
+
+    fn emit(&mut self, template: &Submodel) -> String {
+        let mut body = String::new();
+        let types = std::mem::take(&mut self.types);
+        for (index, definition) in types.iter().enumerate() {
+            body.push_str(&self.emit_type(definition, if index == 0 { Some(template) } else { None }));
+        }
+        for name in self.helpers.clone() {
+            body.push('\n');
+            body.push_str(helper_source(name).0);
+        }
+
+        let mut code = format!("// Generated by aas-model-rs from the submodel template '{}'. Do not edit.\n\n", template.get_id());
+        for path in &self.imports {
+            code.push_str(&format!("use {}::{};\n", self.crate_path, path));
+        }
+        code.push_str(&body);
+        code
+    }
+
+    fn emit_type(&mut self, definition: &TypeDefinition, template: Option<&Submodel>) -> String {
+        self.import("enumerations::conversion_error::ConversionError");
+        self.import("enumerations::interface_enumerations::submodel_element::SubmodelElement");
+
+        let mut code = String::from("\n");
+        code.push_str(&format_doc(definition.doc.as_deref().unwrap_or(&definition.name), ""));
+        code.push_str(&format!("#[derive(PartialEq, Clone)]\npub struct {} {{\n", definition.name));
+        for field in &definition.fields {
+            let field_type = self.rust_type(&field.kind);
+            let field_type = match field.cardinality {
+                Cardinality::One => field_type,
+                Cardinality::ZeroToOne => format!("Option<{}>", field_type),
+                Cardinality::ZeroToMany | Cardinality::OneToMany => format!("Vec<{}>", field_type)
+            };
+            code.push_str(&format_doc(field.doc.as_deref().unwrap_or(&field.id_short), "    "));
+            code.push_str(&format!("    pub {}: {},\n", field.name, field_type));
+        }
+        code.push_str(&format!("}}\n\nimpl {} {{\n", definition.name));
+
+        if let Some(semantic_id) = template.and_then(|template| get_first_key(template.get_semantic_id())) {
+            code.push_str(&format!("    ///Semantic ID of the submodel template.\n    pub const SEMANTIC_ID: &str = {:?};\n\n",
+                                   semantic_id));
+        }
+
+        code.push_str("    ///Reads the struct from submodel elements.\n    ///\n");
+        code.push_str("    /// [elements]: submodel elements to read\n");
+        code.push_str("    /// [path]: idShortPath of the parent of the elements used in error messages\n");
+        code.push_str(&format!("    pub fn from_elements(elements: &[SubmodelElement], path: &str) -> Result<{}, ConversionError> {{\n",
+                               definition.name));
+        code.push_str(&format!("        Ok({} {{\n", definition.name));
+        for field in &definition.fields {
+            code.push_str(&format!("            {}: {},\n", field.name, self.emit_read_field(field)));
+        }
+        code.push_str("        })\n    }\n\n");
+
+        code.push_str("    ///Writes the struct as submodel elements.\n");
+        code.push_str("    pub fn to_elements(&self) -> Vec<SubmodelElement> {\n");
+        let leading = definition.fields.iter().take_while(|field| field.cardinality == Cardinality::One).count();
+        let mut items = String::new();
+        for field in &definition.fields[..leading] {
+            let id_short = format!("Some({:?}.to_string())", field.id_short);
+            let item = self.emit_write(&field.kind, &format!("self.{}", field.name), &id_short, &format_option(&field.semantic_id));
+            items.push_str(&format!("            {},\n", item));
+        }
+        if leading == definition.fields.len() {
+            code.push_str(&format!("        vec![\n{}        ]\n    }}\n", items));
+        } else {
+            code.push_str(&format!("        let mut elements = vec![\n{}        ];\n", items));
+            for field in &definition.fields[leading..] {
+                code.push_str(&self.emit_write_field(field));
+            }
+            code.push_str("        elements\n    }\n");
+        }
+
+        if let Some(template) = template {
+            self.import("structs::submodel::Submodel");
+            self.import("enumerations::modelling_kind::ModellingKind");
+            self.import("traits::has_kind::THasKind");
+            code.push_str("\n    ///Reads the struct from a submodel instance of the template.\n    ///\n");
+            code.push_str("    /// [submodel]: submodel instance\n");
+            code.push_str(&format!("    pub fn from_submodel(submodel: &Submodel) -> Result<{}, ConversionError> {{\n",
+                                   definition.name));
+            code.push_str(&format!("        {}::from_elements(submodel.get_submodel_elements(), \"\")\n    }}\n\n",
+                                   definition.name));
+            code.push_str("    ///Writes the struct as a submodel instance of the template.\n    ///\n");
+            code.push_str("    /// [id]: global identifier of the submodel instance\n");
+            code.push_str("    pub fn to_submodel(&self, id: String) -> Submodel {\n");
+            code.push_str("        let mut submodel = Submodel::new(id);\n");
+            if let Some(id_short) = template.get_id_short() {
+                self.import("traits::referable::TReferable");
+                code.push_str(&format!("        submodel.set_id_short({:?}.to_string());\n", id_short));
+            }
+            if get_first_key(template.get_semantic_id()).is_some() {
+                self.import("traits::has_semantics::THasSemantics");
+                self.helper("global_reference");
+                code.push_str(&format!("        submodel.set_semantic_id(global_reference({}::SEMANTIC_ID));\n",
+                                       definition.name));
+            }
+            code.push_str("        submodel.set_kind(ModellingKind::Instance);\n");
+            code.push_str("        submodel.set_submodel_elements(self.to_elements());\n");
+            code.push_str("        submodel\n    }\n");
+        }
+        code.push_str("}\n");
+        code
+    }
+
+    fn rust_type(&mut self, kind: &FieldKind) -> String {
+        match kind {
+            FieldKind::Property(value_type) => get_rust_type(value_type).to_string(),
+            FieldKind::MultiLanguageProperty => {
+                self.import("structs::multi_language_text_type::MultiLanguageTextType");
+                "Vec<MultiLanguageTextType>".to_string()
+            },
+            FieldKind::Range(value_type) => {
+                let value_type = get_rust_type(value_type);
+                format!("(Option<{}>, Option<{}>)", value_type, value_type)
+            },
+            FieldKind::File(_) => "String".to_string(),
+            FieldKind::Blob(_) => "Vec<u8>".to_string(),
+            FieldKind::ReferenceElement => {
+                self.import("structs::reference::Reference");
+                "Reference".to_string()
+            },
+            FieldKind::Collection(name) => name.clone(),
+            FieldKind::List(list) => format!("Vec<{}>", self.rust_type(&list.item)),
+            FieldKind::Raw => "SubmodelElement".to_string()
+        }
+    }
+
+    fn emit_read_field(&mut self, field: &FieldDefinition) -> String {
+        self.helper("find_elements");
+        let id_short = match field.cardinality {
+            Cardinality::One | Cardinality::ZeroToOne => field.id_short.clone(),
+            Cardinality::ZeroToMany | Cardinality::OneToMany => field.get_numbered_id_short()
+        };
+        let find = format!("find_elements(elements, {:?}, {})", id_short, format_option(&field.semantic_id));
+        if let FieldKind::Raw = field.kind {
+            return match field.cardinality {
+                Cardinality::One => {
+                    self.helper("required");
+                    self.helper("join_path");
+                    format!("required({}, &join_path(path, {:?}))?.clone()", find, field.id_short)
+                },
+                Cardinality::ZeroToOne => format!("{}.into_iter().next().cloned()", find),
+                Cardinality::ZeroToMany | Cardinality::OneToMany => format!("{}.into_iter().cloned().collect()", find)
+            };
+        }
+        self.helper("join_path");
+        match field.cardinality {
+            Cardinality::One => {
+                self.helper("required");
+                format!("{{\n                let path = join_path(path, {:?});\n                \
+                         let element = required({}, &path)?;\n                {}\n            }}",
+                        field.id_short, find, self.emit_read(&field.kind, "element", "&path", 16))
+            },
+            Cardinality::ZeroToOne => {
+                format!("{{\n                let path = join_path(path, {:?});\n                \
+                         match {}.first() {{\n                    Some(element) => Some({}),\n                    \
+                         None => None\n                }}\n            }}",
+                        field.id_short, find, self.emit_read(&field.kind, "element", "&path", 20))
+            },
+            Cardinality::ZeroToMany | Cardinality::OneToMany => {
+                format!("{{\n                let mut values = Vec::new();\n                \
+                         for element in {} {{\n                    \
+                         let path = join_path(path, element.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default());\n                    \
+                         values.push({});\n                }}\n                values\n            }}",
+                        find, self.emit_read(&field.kind, "element", "&path", 20))
+            }
+        }
+    }
+
+    ///Returns an expression reading the value of the element at the path.
+    fn emit_read(&mut self, kind: &FieldKind, element: &str, path: &str, indent: usize) -> String {
+        let helper = |generation: &mut Generation, name: &'static str| {
+            generation.helper(name);
+            name
+        };
+        match kind {
+            FieldKind::Property(value_type) => format!("{}::<{}>({}, {})?", helper(self, "property_value"),
+                                                       get_rust_type(value_type), element, path),
+            FieldKind::MultiLanguageProperty => format!("{}({}, {})?", helper(self, "multi_language_value"), element, path),
+            FieldKind::Range(value_type) => format!("{}::<{}>({}, {})?", helper(self, "range_value"),
+                                                    get_rust_type(value_type), element, path),
+            FieldKind::File(_) => format!("{}({}, {})?", helper(self, "file_value"), element, path),
+            FieldKind::Blob(_) => format!("{}({}, {})?", helper(self, "blob_value"), element, path),
+            FieldKind::ReferenceElement => format!("{}({}, {})?", helper(self, "reference_value"), element, path),
+            FieldKind::Collection(name) => format!("{}::from_elements({}({}, {})?, {})?", name,
+                                                   helper(self, "children"), element, path, path),
+            FieldKind::List(list) if matches!(list.item, FieldKind::Raw) => {
+                format!("{}({}, {})?.clone()", helper(self, "children"), element, path)
+            },
+            FieldKind::List(list) => {
+                let children = helper(self, "children");
+                let pad = " ".repeat(indent);
+                format!("{{\n{pad}    let mut items = Vec::new();\n{pad}    \
+                         for (index, element) in {children}({element}, {path})?.iter().enumerate() {{\n{pad}        \
+                         let path = format!(\"{{}}[{{}}]\", {path}, index);\n{pad}        items.push({});\n{pad}    }}\n{pad}    \
+                         items\n{pad}}}",
+                        self.emit_read(&list.item, "element", "&path", indent + 8))
+            },
+            FieldKind::Raw => format!("SubmodelElement::clone({})", element)
+        }
+    }
+
+    fn emit_write_field(&mut self, field: &FieldDefinition) -> String {
+        let semantic_id = format_option(&field.semantic_id);
+        match field.cardinality {
+            Cardinality::One => {
+                let id_short = format!("Some({:?}.to_string())", field.id_short);
+                format!("        elements.push({});\n",
+                        self.emit_write(&field.kind, &format!("self.{}", field.name), &id_short, &semantic_id))
+            },
+            Cardinality::ZeroToOne => {
+                let id_short = format!("Some({:?}.to_string())", field.id_short);
+                format!("        if let Some(value) = &self.{} {{\n            elements.push({});\n        }}\n",
+                        field.name, self.emit_write(&field.kind, "value", &id_short, &semantic_id))
+            },
+            Cardinality::ZeroToMany | Cardinality::OneToMany => {
+                let id_short = format!("Some(format!(\"{}\", index + 1))", field.get_numbered_id_short().replace("{00}", "{:02}"));
+                format!("        for (index, value) in self.{}.iter().enumerate() {{\n            elements.push({});\n        }}\n",
+                        field.name, self.emit_write(&field.kind, "value", &id_short, &semantic_id))
+            }
+        }
+    }
+
+    ///Returns an expression creating a submodel element from the value.
+    fn emit_write(&mut self, kind: &FieldKind, value: &str, id_short: &str, semantic_id: &str) -> String {
+        match kind {
+            FieldKind::Property(value_type) => {
+                self.helper("property");
+                self.import("traits::property_value::TPropertyValue");
+                format!("property({}, {}, DataTypeDefXsd::{}, {}.format_value())", id_short, semantic_id,
+                        get_value_type_name(value_type), value)
+            },
+            FieldKind::MultiLanguageProperty => {
+                self.helper("multi_language_property");
+                format!("multi_language_property({}, {}, {}.clone())", id_short, semantic_id, value)
+            },
+            FieldKind::Range(value_type) => {
+                self.helper("range");
+                self.import("traits::property_value::TPropertyValue");
+                format!("range({}, {}, DataTypeDefXsd::{}, {}.0.as_ref().map(TPropertyValue::format_value), \
+                         {}.1.as_ref().map(TPropertyValue::format_value))", id_short, semantic_id,
+                        get_value_type_name(value_type), value, value)
+            },
+            FieldKind::File(content_type) => {
+                self.helper("file");
+                format!("file({}, {}, {}, {}.clone())", id_short, semantic_id, format_option(content_type), value)
+            },
+            FieldKind::Blob(content_type) => {
+                self.helper("blob");
+                format!("blob({}, {}, {}, {}.clone())", id_short, semantic_id, format_option(content_type), value)
+            },
+            FieldKind::ReferenceElement => {
+                self.helper("reference_element");
+                format!("reference_element({}, {}, {}.clone())", id_short, semantic_id, value)
+            },
+            FieldKind::Collection(_) => {
+                self.helper("collection");
+                format!("collection({}, {}, {}.to_elements())", id_short, semantic_id, value)
+            },
+            FieldKind::List(list) => {
+                self.helper("list");
+                let value_type_list_element = match &list.value_type_list_element {
+                    Some(value_type) => format!("Some(DataTypeDefXsd::{})", get_value_type_name(value_type)),
+                    None => "None".to_string()
+                };
+                let item = self.emit_write(&list.item, "item", "None", &format_option(&list.item_semantic_id));
+                format!("list({}, {}, AasSubmodelElements::{}, {}, {}.iter().map(|item| {}).collect())", id_short,
+                        semantic_id, get_element_type_name(&list.type_value_list_element), value_type_list_element, value, item)
+            },
+            FieldKind::Raw => {
+                self.helper("raw");
+                format!("raw({}, {}, {}.clone())", id_short, semantic_id, value)
+            }
+        }
+    }
+}
+
+///Returns the source code, the imports and the helpers used by a helper function of the generated
+/// code.
+fn helper_source(name: &str) -> (&'static str, &'static [&'static str], &'static [&'static str]) {
+    match name {
+        "join_path" => ("fn join_path(path: &str, id_short: &str) -> String {
+    if path.is_empty() {
+        id_short.to_string()
+    } else {
+        format!(\"{}.{}\", path, id_short)
+    }
+}
+", &[], &[]),
+        "find_elements" => ("fn find_elements<'a>(elements: &'a [SubmodelElement], id_short: &str, semantic_id: Option<&str>)
+    -> Vec<&'a SubmodelElement> {
+    elements.iter().filter(|element| match semantic_id {
+        Some(semantic_id) => element.get_semantic_id().and_then(|reference| reference.get_keys().first())
+            .is_some_and(|key| key.get_value() == semantic_id),
+        None => element.get_id_short().is_some_and(|element_id_short| match id_short.split_once(\"{00}\") {
+            Some((prefix, suffix)) => element_id_short.strip_prefix(prefix)
+                .and_then(|number| number.strip_suffix(suffix))
+                .is_some_and(|number| !number.is_empty() && number.chars().all(|char| char.is_ascii_digit())),
+            None => element_id_short == id_short
+        })
+    }).collect()
+}
+", &[], &[]),
+        "required" => ("fn required<'a>(elements: Vec<&'a SubmodelElement>, path: &str) -> Result<&'a SubmodelElement, ConversionError> {
+    elements.into_iter().next().ok_or_else(|| ConversionError::MissingElement(path.to_string()))
+}
+", &[], &[]),
+        "children" => ("fn children<'a>(element: &'a SubmodelElement, path: &str) -> Result<&'a Vec<SubmodelElement>, ConversionError> {
+    element.get_children().ok_or_else(|| ConversionError::UnexpectedElementType(path.to_string()))
+}
+", &[], &[]),
+        "property_value" => ("fn property_value<T: TPropertyValue>(element: &SubmodelElement, path: &str) -> Result<T, ConversionError> {
+    match element {
+        SubmodelElement::Property(property) => property.get_typed_value()
+            .ok_or_else(|| ConversionError::InvalidValue(path.to_string())),
+        _ => Err(ConversionError::UnexpectedElementType(path.to_string()))
+    }
+}
+", &["traits::property_value::TPropertyValue"], &[]),
+        "range_value" => ("fn range_value<T: TPropertyValue>(element: &SubmodelElement, path: &str)
+    -> Result<(Option<T>, Option<T>), ConversionError> {
+    match element {
+        SubmodelElement::Range(range) => {
+            let bound = |bound: Option<&String>| bound
+                .map(|bound| T::parse_value(bound).ok_or_else(|| ConversionError::InvalidValue(path.to_string())))
+                .transpose();
+            Ok((bound(range.get_min())?, bound(range.get_max())?))
+        },
+        _ => Err(ConversionError::UnexpectedElementType(path.to_string()))
+    }
+}
+", &["traits::property_value::TPropertyValue"], &[]),
+        "multi_language_value" => ("fn multi_language_value(element: &SubmodelElement, path: &str)
+    -> Result<Vec<MultiLanguageTextType>, ConversionError> {
+    match element {
+        SubmodelElement::MultiLanguageProperty(property) => Ok(property.get_value().clone()),
+        _ => Err(ConversionError::UnexpectedElementType(path.to_string()))
+    }
+}
+", &["structs::multi_language_text_type::MultiLanguageTextType"], &[]),
+        "file_value" => ("fn file_value(element: &SubmodelElement, path: &str) -> Result<String, ConversionError> {
+    match element {
+        SubmodelElement::File(file) => file.get_value().cloned().ok_or_else(|| ConversionError::InvalidValue(path.to_string())),
+        _ => Err(ConversionError::UnexpectedElementType(path.to_string()))
+    }
+}
+", &[], &[]),
+        "blob_value" => ("fn blob_value(element: &SubmodelElement, path: &str) -> Result<Vec<u8>, ConversionError> {
+    match element {
+        SubmodelElement::Blob(blob) => Ok(blob.get_value().clone()),
+        _ => Err(ConversionError::UnexpectedElementType(path.to_string()))
+    }
+}
+", &[], &[]),
+        "reference_value" => ("fn reference_value(element: &SubmodelElement, path: &str) -> Result<Reference, ConversionError> {
+    match element {
+        SubmodelElement::ReferenceElement(reference_element) => reference_element.get_value().cloned()
+            .ok_or_else(|| ConversionError::InvalidValue(path.to_string())),
+        _ => Err(ConversionError::UnexpectedElementType(path.to_string()))
+    }
+}
+", &["structs::reference::Reference"], &[]),
+        "global_reference" => ("fn global_reference(value: &str) -> Reference {
+    Reference::new(ReferenceType::ExternalReference, vec![Key::new(KeyType::GlobalReference, value.to_string())])
+}
+", &["structs::reference::Reference", "enumerations::reference_type::ReferenceType", "structs::key::Key",
+     "enumerations::key_type::KeyType"], &[]),
+        "identify" => ("fn identify<T: TReferable + THasSemantics>(mut element: T, id_short: Option<String>, semantic_id: Option<&str>) -> T {
+    if let Some(id_short) = id_short {
+        element.set_id_short(id_short);
+    }
+    if let Some(semantic_id) = semantic_id {
+        element.set_semantic_id(global_reference(semantic_id));
+    }
+    element
+}
+", &["traits::referable::TReferable", "traits::has_semantics::THasSemantics"], &["global_reference"]),
+        "raw" => ("fn raw(id_short: Option<String>, semantic_id: Option<&str>, mut element: SubmodelElement) -> SubmodelElement {
+    if let Some(id_short) = id_short {
+        element.set_id_short(id_short);
+    }
+    if let Some(semantic_id) = semantic_id {
+        element.set_semantic_id(global_reference(semantic_id));
+    }
+    element
+}
+", &[], &["global_reference"]),
+        "property" => ("fn property(id_short: Option<String>, semantic_id: Option<&str>, value_type: DataTypeDefXsd, value: String)
+    -> SubmodelElement {
+    let mut property = Property::new(value_type);
+    property.set_value(value);
+    SubmodelElement::Property(identify(property, id_short, semantic_id))
+}
+", &["structs::property::Property", "enumerations::data_type_def_xsd::DataTypeDefXsd"], &["identify"]),
+        "multi_language_property" => ("fn multi_language_property(id_short: Option<String>, semantic_id: Option<&str>,
+                           value: Vec<MultiLanguageTextType>) -> SubmodelElement {
+    let mut property = MultiLanguageProperty::new();
+    property.set_value(value);
+    SubmodelElement::MultiLanguageProperty(identify(property, id_short, semantic_id))
+}
+", &["structs::multi_language_property::MultiLanguageProperty", "structs::multi_language_text_type::MultiLanguageTextType"],
+         &["identify"]),
+        "range" => ("fn range(id_short: Option<String>, semantic_id: Option<&str>, value_type: DataTypeDefXsd, min: Option<String>,
+         max: Option<String>) -> SubmodelElement {
+    let mut range = Range::new(value_type);
+    if let Some(min) = min {
+        range.set_min(min);
+    }
+    if let Some(max) = max {
+        range.set_max(max);
+    }
+    SubmodelElement::Range(identify(range, id_short, semantic_id))
+}
+", &["structs::range::Range", "enumerations::data_type_def_xsd::DataTypeDefXsd"], &["identify"]),
+        "file" => ("fn file(id_short: Option<String>, semantic_id: Option<&str>, content_type: Option<&str>, value: String)
+    -> SubmodelElement {
+    let mut file = File::new();
+    if let Some(content_type) = content_type {
+        file.set_content_type(content_type.to_string());
+    }
+    file.set_value(value);
+    SubmodelElement::File(identify(file, id_short, semantic_id))
+}
+", &["structs::file::File"], &["identify"]),
+        "blob" => ("fn blob(id_short: Option<String>, semantic_id: Option<&str>, content_type: Option<&str>, value: Vec<u8>)
+    -> SubmodelElement {
+    let mut blob = Blob::new();
+    if let Some(content_type) = content_type {
+        blob.set_content_type(content_type.to_string());
+    }
+    blob.set_value(value);
+    SubmodelElement::Blob(identify(blob, id_short, semantic_id))
+}
+", &["structs::blob::Blob"], &["identify"]),
+        "reference_element" => ("fn reference_element(id_short: Option<String>, semantic_id: Option<&str>, value: Reference) -> SubmodelElement {
+    let mut reference_element = ReferenceElement::new();
+    reference_element.set_value(value);
+    SubmodelElement::ReferenceElement(identify(reference_element, id_short, semantic_id))
+}
+", &["structs::reference_element::ReferenceElement", "structs::reference::Reference"], &["identify"]),
+        "collection" => ("fn collection(id_short: Option<String>, semantic_id: Option<&str>, value: Vec<SubmodelElement>) -> SubmodelElement {
+    let mut collection = SubmodelElementCollection::new();
+    collection.set_value(value);
+    SubmodelElement::SubmodelElementCollection(identify(collection, id_short, semantic_id))
+}
+", &["structs::submodel_element_collection::SubmodelElementCollection"], &["identify"]),
+        _ => ("fn list(id_short: Option<String>, semantic_id: Option<&str>, type_value_list_element: AasSubmodelElements,
+        value_type_list_element: Option<DataTypeDefXsd>, value: Vec<SubmodelElement>) -> SubmodelElement {
+    let mut list = SubmodelElementList::new(type_value_list_element);
+    if let Some(value_type_list_element) = value_type_list_element {
+        list.set_value_type_list_element(value_type_list_element);
+    }
+    list.set_value(value);
+    SubmodelElement::SubmodelElementList(identify(list, id_short, semantic_id))
+}
+", &["structs::submodel_element_list::SubmodelElementList", "enumerations::aas_submodel_elements::AasSubmodelElements",
+     "enumerations::data_type_def_xsd::DataTypeDefXsd"], &["identify"])
+    }
+}
+
+///Returns a name that is not taken yet by appending a number if necessary.
+fn unique_name(name: String, is_taken: impl Fn(&str) -> bool) -> String {
+    if !is_taken(&name) {
+        return name;
+    }
+    (2..).map(|counter| format!("{}{}", name, counter))
+        .find(|candidate| !is_taken(candidate))
+        .unwrap_or(name)
+}
+
+fn to_pascal_case(id_short: &str) -> String {
+    let name: String = id_short.replace("{00}", "")
+        .split(['_', '-'])
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next().map(|first| first.to_uppercase().chain(chars).collect()).unwrap_or_default()
+        })
+        .collect::<Vec<String>>()
+        .concat();
+    match name.chars().next() {
+        Some(first) if first.is_alphabetic() => name,
+        _ => format!("Element{}", name)
+    }
+}
+
+fn to_snake_case(id_short: &str) -> String {
+    let chars: Vec<char> = id_short.replace("{00}", "").replace('-', "_").chars().collect();
+    let mut name = String::new();
+    for (index, char) in chars.iter().enumerate() {
+        if char.is_uppercase() && index > 0 {
+            let previous = chars[index - 1];
+            let next_is_lowercase = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+            if previous.is_lowercase() || previous.is_ascii_digit() || (previous.is_uppercase() && next_is_lowercase) {
+                name.push('_');
+            }
+        }
+        name.extend(char.to_lowercase());
+    }
+    let keywords = ["as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false",
+        "fn", "for", "gen", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self",
+        "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where", "while", "yield"];
+    match name.chars().next() {
+        None => "element".to_string(),
+        Some(first) if !first.is_alphabetic() => format!("element_{}", name),
+        _ if keywords.contains(&name.as_str()) => format!("{}_", name),
+        _ => name
+    }
+}
+
+///Returns the English or otherwise first description as a single line.
+fn get_doc(description: &[MultiLanguageTextType]) -> Option<String> {
+    description.iter().find(|text| text.get_language().starts_with("en"))
+        .or(description.first())
+        .map(|text| text.get_text().split_whitespace().collect::<Vec<&str>>().join(" "))
+        .filter(|text| !text.is_empty())
+}
+
+fn format_doc(doc: &str, indent: &str) -> String {
+    format!("{}///{}\n", indent, doc)
+}
+
+fn format_option(value: &Option<String>) -> String {
+    match value {
+        Some(value) => format!("Some({:?})", value),
+        None => "None".to_string()
+    }
+}
+
+fn get_first_key(reference: Option<&Reference>) -> Option<String> {
+    reference.and_then(|reference| reference.get_keys().first()).map(|key| key.get_value().clone())
+}
+
+fn get_rust_type(value_type: &DataTypeDefXsd) -> &'static str {
+    match value_type {
+        DataTypeDefXsd::Boolean => "bool",
+        DataTypeDefXsd::Byte => "i8",
+        DataTypeDefXsd::Short => "i16",
+        DataTypeDefXsd::Int => "i32",
+        DataTypeDefXsd::Long | DataTypeDefXsd::Integer | DataTypeDefXsd::NegativeInteger
+        | DataTypeDefXsd::NonPositiveInteger => "i64",
+        DataTypeDefXsd::UnsignedByte => "u8",
+        DataTypeDefXsd::UnsignedShort => "u16",
+        DataTypeDefXsd::UnsignedInt => "u32",
+        DataTypeDefXsd::UnsignedLong | DataTypeDefXsd::NonNegativeInteger | DataTypeDefXsd::PositiveInteger => "u64",
+        DataTypeDefXsd::Float => "f32",
+        DataTypeDefXsd::Double | DataTypeDefXsd::Decimal => "f64",
+        _ => "String"
+    }
+}
+
+fn get_value_type_name(value_type: &DataTypeDefXsd) -> &'static str {
+    match value_type {
+        DataTypeDefXsd::AnyUri => "AnyUri",
+        DataTypeDefXsd::Base64Binary => "Base64Binary",
+        DataTypeDefXsd::Boolean => "Boolean",
+        DataTypeDefXsd::Byte => "Byte",
+        DataTypeDefXsd::Date => "Date",
+        DataTypeDefXsd::DateTime => "DateTime",
+        DataTypeDefXsd::Decimal => "Decimal",
+        DataTypeDefXsd::Double => "Double",
+        DataTypeDefXsd::Duration => "Duration",
+        DataTypeDefXsd::Float => "Float",
+        DataTypeDefXsd::GDay => "GDay",
+        DataTypeDefXsd::GMonth => "GMonth",
+        DataTypeDefXsd::GMonthDay => "GMonthDay",
+        DataTypeDefXsd::GYear => "GYear",
+        DataTypeDefXsd::GYearMonth => "GYearMonth",
+        DataTypeDefXsd::HexBinary => "HexBinary",
+        DataTypeDefXsd::Int => "Int",
+        DataTypeDefXsd::Integer => "Integer",
+        DataTypeDefXsd::Long => "Long",
+        DataTypeDefXsd::NegativeInteger => "NegativeInteger",
+        DataTypeDefXsd::NonNegativeInteger => "NonNegativeInteger",
+        DataTypeDefXsd::NonPositiveInteger => "NonPositiveInteger",
+        DataTypeDefXsd::PositiveInteger => "PositiveInteger",
+        DataTypeDefXsd::Short => "Short",
+        DataTypeDefXsd::String => "String",
+        DataTypeDefXsd::Time => "Time",
+        DataTypeDefXsd::UnsignedByte => "UnsignedByte",
+        DataTypeDefXsd::UnsignedInt => "UnsignedInt",
+        DataTypeDefXsd::UnsignedLong => "UnsignedLong",
+        DataTypeDefXsd::UnsignedShort => "UnsignedShort"
+    }
+}
+
+fn get_element_type_name(element_type: &AasSubmodelElements) -> &'static str {
+    match element_type {
+        AasSubmodelElements::SubmodelElement => "SubmodelElement",
+        AasSubmodelElements::AnnotatedRelationshipElement => "AnnotatedRelationshipElement",
+        AasSubmodelElements::Entity => "Entity",
+        AasSubmodelElements::SubmodelElementCollection => "SubmodelElementCollection",
+        AasSubmodelElements::SubmodelElementList => "SubmodelElementList",
+        AasSubmodelElements::BasicEventElement => "BasicEventElement",
+        AasSubmodelElements::Blob => "Blob",
+        AasSubmodelElements::Capability => "Capability",
+        AasSubmodelElements::DataElement => "DataElement",
+        AasSubmodelElements::EventElement => "EventElement",
+        AasSubmodelElements::File => "File",
+        AasSubmodelElements::MultiLanguageProperty => "MultiLanguageProperty",
+        AasSubmodelElements::Operation => "Operation",
+        AasSubmodelElements::Property => "Property",
+        AasSubmodelElements::Range => "Range",
+        AasSubmodelElements::ReferenceElement => "ReferenceElement",
+        AasSubmodelElements::RelationshipElement => "RelationshipElement"
+    }
+}
\ No newline at end of file
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::structs::administrative_information::AdministrativeInformation;
+use crate::structs::data_specification_iec61360::DataSpecificationIec61360;
+use crate::structs::embedded_data_specification::EmbeddedDataSpecification;
 use crate::structs::extension::Extension;
 use crate::structs::multi_language_name_type::MultiLanguageNameType;
 use crate::structs::multi_language_text_type::MultiLanguageTextType;
@@ -13,20 +15,24 @@ use crate::traits::referable::TReferable;
 ///The semantics of a property or other elements that may have a semantic description is defined by
 /// a concept description.
 #[derive(PartialEq, Clone, Serialize, Deserialize)]
+#[serde(tag = "modelType", rename = "ConceptDescription")]
 pub struct ConceptDescription {
     ///List of references to external definitions the concept is compatible to or was derived from.
-    #[serde(rename = "isCaseOf")]
+    #[serde(rename = "isCaseOf", default, skip_serializing_if = "Vec::is_empty")]
     is_case_of: Vec<Reference>,
-    #[serde(rename = "embeddedDataSpecifications")]
-    data_specifications: Vec<Reference>,
+    #[serde(rename = "embeddedDataSpecifications", default, skip_serializing_if = "Vec::is_empty")]
+    data_specifications: Vec<EmbeddedDataSpecification>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     category: Option<String>,
-    #[serde(rename = "idShort")]
+    #[serde(rename = "idShort", default, skip_serializing_if = "Option::is_none")]
     id_short: Option<String>,
-    #[serde(rename = "displayName")]
+    #[serde(rename = "displayName", default, skip_serializing_if = "Vec::is_empty")]
     display_name: Vec<MultiLanguageNameType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     description: Vec<MultiLanguageTextType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     extensions: Vec<Extension>,
-    #[serde(rename = "administration")]
+    #[serde(rename = "administration", default, skip_serializing_if = "Option::is_none")]
     administrative_information: Option<AdministrativeInformation>,
     id: String
 }
@@ -80,26 +86,55 @@ impl ConceptDescription {
     pub fn remove_is_case_of(&mut self, index: usize) -> Reference {
         self.is_case_of.remove(index)
     }
+
+    ///Returns the content of the first embedded data specification IEC 61360, which describes the
+    /// concept including its unit.
+    pub fn get_data_specification_iec61360(&self) -> Option<&DataSpecificationIec61360> {
+        self.data_specifications.first().map(|data_specification| data_specification.get_data_specification_content())
+    }
+
+    ///Returns all references contained in the concept description.
+    pub fn get_references(&self) -> Vec<&Reference> {
+        self.is_case_of.iter()
+            .flat_map(|reference| reference.get_references())
+            .chain(self.data_specifications.iter().flat_map(|data_specification| data_specification.get_references()))
+            .chain(self.extensions.iter().flat_map(|extension| extension.get_references()))
+            .chain(self.administrative_information.iter().flat_map(|administration| administration.get_references()))
+            .collect()
+    }
+
+    ///Calls the visitor for every reference contained in the concept description.
+    ///
+    /// [visitor]: function called for every reference
+    pub fn visit_references_mut(&mut self, visitor: &mut dyn FnMut(&mut Reference)) {
+        self.is_case_of.iter_mut()
+            .for_each(|reference| reference.visit_references_mut(visitor));
+        self.data_specifications.iter_mut()
+            .for_each(|data_specification| data_specification.visit_references_mut(visitor));
+        self.extensions.iter_mut().for_each(|extension| extension.visit_references_mut(visitor));
+        self.administrative_information.iter_mut()
+            .for_each(|administration| administration.visit_references_mut(visitor));
+    }
 }
 
 impl THasDataSpecification for ConceptDescription {
-    fn get_data_specifications(&self) -> &Vec<Reference> {
+    fn get_data_specifications(&self) -> &Vec<EmbeddedDataSpecification> {
         &self.data_specifications
     }
 
-    fn get_mut_data_specifications(&mut self) -> &mut Vec<Reference> {
+    fn get_mut_data_specifications(&mut self) -> &mut Vec<EmbeddedDataSpecification> {
         &mut self.data_specifications
     }
 
-    fn set_data_specifications(&mut self, data_specifications: Vec<Reference>) {
+    fn set_data_specifications(&mut self, data_specifications: Vec<EmbeddedDataSpecification>) {
         self.data_specifications = data_specifications;
     }
 
-    fn add_data_specification(&mut self, data_specification: Reference) {
+    fn add_data_specification(&mut self, data_specification: EmbeddedDataSpecification) {
         self.data_specifications.push(data_specification);
     }
 
-    fn remove_data_specification(&mut self, index: usize) -> Reference {
+    fn remove_data_specification(&mut self, index: usize) -> EmbeddedDataSpecification {
         self.data_specifications.remove(index)
     }
 }
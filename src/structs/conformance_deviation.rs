@@ -0,0 +1,53 @@
+use crate::enumerations::conformance_deviation_kind::ConformanceDeviationKind;
+
+///A deviation of a submodel instance from its submodel template.
+#[derive(PartialEq, Clone)]
+pub struct ConformanceDeviation {
+    ///IdShortPath of the deviating element in the instance, or of the missing element in the
+    /// template. An empty path denotes the submodel itself.
+    path: String,
+    ///Kind of the deviation.
+    kind: ConformanceDeviationKind,
+    ///What the template expects, if applicable.
+    expected: Option<String>,
+    ///What the instance contains, if applicable.
+    actual: Option<String>
+}
+
+impl ConformanceDeviation {
+    ///Creates a new instance of the struct.
+    ///
+    /// [path]: idShortPath of the deviating element
+    /// [kind]: kind of the deviation
+    /// [expected]: what the template expects
+    /// [actual]: what the instance contains
+    pub fn new(path: String, kind: ConformanceDeviationKind, expected: Option<String>, actual: Option<String>)
+        -> ConformanceDeviation {
+        ConformanceDeviation {
+            path,
+            kind,
+            expected,
+            actual
+        }
+    }
+
+    ///Returns the idShortPath of the deviating element. An empty path denotes the submodel itself.
+    pub fn get_path(&self) -> &String {
+        &self.path
+    }
+
+    ///Returns the kind of the deviation.
+    pub fn get_kind(&self) -> &ConformanceDeviationKind {
+        &self.kind
+    }
+
+    ///Returns what the template expects, e.g. the allowed values or the expected value type.
+    pub fn get_expected(&self) -> Option<&String> {
+        self.expected.as_ref()
+    }
+
+    ///Returns what the instance contains, e.g. the actual value or value type.
+    pub fn get_actual(&self) -> Option<&String> {
+        self.actual.as_ref()
+    }
+}
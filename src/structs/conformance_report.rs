@@ -0,0 +1,337 @@
+use std::mem::discriminant;
+
+use serde::Serialize;
+
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conformance_deviation_kind::ConformanceDeviationKind;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::id_short_path_segment::IdShortPathSegment;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::conformance_deviation::ConformanceDeviation;
+use crate::structs::reference::Reference;
+use crate::structs::submodel::Submodel;
+use crate::structs::submodel_element_list::SubmodelElementList;
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::qualifiable::TQualifiable;
+
+///Result of checking a submodel instance against its submodel template.
+///
+/// Elements of the instance are matched with the elements of the template by their semantic ID and
+/// their idShort. The number of matching elements is checked against the SMT cardinality of the
+/// template element, which defaults to `One`. Values of properties and ranges are checked against
+/// their value type and the `SMT/AllowedValue` and `SMT/AllowedRange` qualifiers of the template.
+/// Elements of submodel element lists are checked against the first element of the list in the
+/// template. Elements the template does not define are accepted where the template contains an
+/// element with the semantic ID [ARBITRARY_SEMANTIC_ID].
+#[derive(PartialEq, Clone)]
+pub struct ConformanceReport {
+    ///Deviations of the instance from the template.
+    deviations: Vec<ConformanceDeviation>
+}
+
+///Semantic ID of the template elements standing for arbitrary elements of an instance.
+pub const ARBITRARY_SEMANTIC_ID: &str = "https://admin-shell.io/SMT/General/Arbitrary";
+
+impl ConformanceReport {
+    ///Checks the submodel instance against the submodel template.
+    ///
+    /// [instance]: submodel instance to check
+    /// [template]: submodel template the instance claims to conform to
+    pub fn check(instance: &Submodel, template: &Submodel) -> ConformanceReport {
+        let mut report = ConformanceReport {
+            deviations: Vec::new()
+        };
+        report.check_semantic_id(&[], instance.get_semantic_id(), template.get_semantic_id());
+        report.check_children(instance.get_submodel_elements(), template.get_submodel_elements(), &[]);
+        report
+    }
+
+    ///Returns the deviations of the instance from the template.
+    pub fn get_deviations(&self) -> &Vec<ConformanceDeviation> {
+        &self.deviations
+    }
+
+    ///Returns whether the instance conforms to the template.
+    pub fn is_conformant(&self) -> bool {
+        self.deviations.is_empty()
+    }
+
+    fn add(&mut self, path: &[IdShortPathSegment], kind: ConformanceDeviationKind, expected: Option<String>,
+           actual: Option<String>) {
+        self.deviations.push(ConformanceDeviation::new(IdShortPathSegment::format_path(path), kind, expected, actual));
+    }
+
+    fn check_semantic_id(&mut self, path: &[IdShortPathSegment], instance: Option<&Reference>, template: Option<&Reference>) {
+        if template.is_some() && !is_same_semantic_id(instance, template) {
+            self.add(path, ConformanceDeviationKind::SemanticIdMismatch, template.map(format_reference),
+                     instance.map(format_reference));
+        }
+    }
+
+    fn check_children(&mut self, instance: &[SubmodelElement], template: &[SubmodelElement], prefix: &[IdShortPathSegment]) {
+        let mut matches: Vec<Vec<&SubmodelElement>> = vec![Vec::new(); template.len()];
+        for element in instance {
+            match find_template_element(element, template) {
+                Some(index) => matches[index].push(element),
+                None if template.iter().any(is_arbitrary) => {},
+                None => self.add(&id_short_path(prefix, element), ConformanceDeviationKind::UnexpectedElement, None,
+                                 element.get_id_short().cloned())
+            }
+        }
+
+        for (template_element, matched) in template.iter().zip(matches) {
+            let cardinality = template_element.get_cardinality().unwrap_or(Cardinality::One);
+            if matched.len() < cardinality.get_min() {
+                self.add(&id_short_path(prefix, template_element), ConformanceDeviationKind::MissingElement,
+                         Some(cardinality.get_name().to_string()), Some(matched.len().to_string()));
+            }
+            if cardinality.get_max().is_some_and(|max| matched.len() > max) {
+                self.add(&id_short_path(prefix, template_element), ConformanceDeviationKind::TooManyElements,
+                         Some(cardinality.get_name().to_string()), Some(matched.len().to_string()));
+            }
+            for element in matched {
+                self.check_element(element, template_element, &id_short_path(prefix, element));
+            }
+        }
+    }
+
+    fn check_element(&mut self, element: &SubmodelElement, template: &SubmodelElement, path: &[IdShortPathSegment]) {
+        if discriminant(element) != discriminant(template) {
+            self.add(path, ConformanceDeviationKind::ElementTypeMismatch, Some(name(&template.get_key_type())),
+                     Some(name(&element.get_key_type())));
+            return;
+        }
+        self.check_semantic_id(path, element.get_semantic_id(), template.get_semantic_id());
+
+        match (element, template) {
+            (SubmodelElement::Property(property), SubmodelElement::Property(template_property)) => {
+                self.check_value_type(path, property.get_value_type(), template_property.get_value_type());
+                if let Some(value) = property.get_value() {
+                    self.check_value(path, value, property.get_value_type(), template);
+                }
+            },
+            (SubmodelElement::Range(range), SubmodelElement::Range(template_range)) => {
+                self.check_value_type(path, range.get_value_type(), template_range.get_value_type());
+                for value in [range.get_min(), range.get_max()].into_iter().flatten() {
+                    self.check_value(path, value, range.get_value_type(), template);
+                }
+            },
+            (SubmodelElement::SubmodelElementList(list), SubmodelElement::SubmodelElementList(template_list)) => {
+                self.check_list(list, template_list, path);
+            },
+            _ => {
+                if let (Some(children), Some(template_children)) = (element.get_children(), template.get_children()) {
+                    self.check_children(children, template_children, path);
+                }
+            }
+        }
+    }
+
+    fn check_value_type(&mut self, path: &[IdShortPathSegment], value_type: &DataTypeDefXsd, template: &DataTypeDefXsd) {
+        if value_type != template {
+            self.add(path, ConformanceDeviationKind::ValueTypeMismatch, Some(name(template)), Some(name(value_type)));
+        }
+    }
+
+    fn check_value(&mut self, path: &[IdShortPathSegment], value: &String, value_type: &DataTypeDefXsd,
+                   template: &SubmodelElement) {
+        if !value_type.is_valid_value(value) {
+            self.add(path, ConformanceDeviationKind::InvalidValue, Some(name(value_type)), Some(value.clone()));
+            return;
+        }
+        let allowed_values = template.get_allowed_values();
+        if !allowed_values.is_empty() && !allowed_values.contains(value) {
+            self.add(path, ConformanceDeviationKind::ValueNotAllowed, Some(allowed_values.join(", ")), Some(value.clone()));
+        }
+        if let Some(allowed_range) = template.get_allowed_range()
+            && !allowed_range.contains(value) {
+            self.add(path, ConformanceDeviationKind::ValueOutOfRange, Some(allowed_range.to_string()), Some(value.clone()));
+        }
+    }
+
+    fn check_list(&mut self, list: &SubmodelElementList, template: &SubmodelElementList, path: &[IdShortPathSegment]) {
+        if list.get_type_value_list_element() != template.get_type_value_list_element() {
+            self.add(path, ConformanceDeviationKind::ListElementTypeMismatch, Some(name(template.get_type_value_list_element())),
+                     Some(name(list.get_type_value_list_element())));
+        }
+        if template.get_value_type_list_element().is_some()
+            && list.get_value_type_list_element() != template.get_value_type_list_element() {
+            self.add(path, ConformanceDeviationKind::ListElementTypeMismatch, template.get_value_type_list_element().map(name),
+                     list.get_value_type_list_element().map(name));
+        }
+
+        for (index, element) in list.get_value().iter().enumerate() {
+            let mut element_path = path.to_vec();
+            element_path.push(IdShortPathSegment::Index(index));
+            if !is_of_type(element, list.get_type_value_list_element()) {
+                self.add(&element_path, ConformanceDeviationKind::ListElementTypeMismatch,
+                         Some(name(list.get_type_value_list_element())), Some(name(&element.get_key_type())));
+                continue;
+            }
+            if let (Some(value_type), SubmodelElement::Property(property)) = (list.get_value_type_list_element(), element)
+                && property.get_value_type() != value_type {
+                self.add(&element_path, ConformanceDeviationKind::ListElementTypeMismatch, Some(name(value_type)),
+                         Some(name(property.get_value_type())));
+                continue;
+            }
+            if let Some(template_element) = template.get_value().first() {
+                self.check_element(element, template_element, &element_path);
+            }
+        }
+    }
+}
+
+fn id_short_path(prefix: &[IdShortPathSegment], element: &SubmodelElement) -> Vec<IdShortPathSegment> {
+    let mut path = prefix.to_vec();
+    path.push(IdShortPathSegment::IdShort(element.get_id_short().cloned().unwrap_or_default()));
+    path
+}
+
+///Returns the serialized name of an enumeration value.
+fn name<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value).ok()
+        .and_then(|value| value.as_str().map(|name| name.to_string()))
+        .unwrap_or_default()
+}
+
+fn format_reference(reference: &Reference) -> String {
+    reference.get_keys().iter()
+        .map(|key| key.get_value().as_str())
+        .collect::<Vec<&str>>()
+        .join("/")
+}
+
+///Returns whether both semantic IDs are present and consist of the same key values.
+fn is_same_semantic_id(first: Option<&Reference>, second: Option<&Reference>) -> bool {
+    match (first, second) {
+        (Some(first), Some(second)) => first.get_keys().len() == second.get_keys().len()
+            && first.get_keys().iter().zip(second.get_keys()).all(|(a, b)| a.get_value() == b.get_value()),
+        _ => false
+    }
+}
+
+///Returns the index of the template element the element is an occurrence of. Elements with the
+/// same semantic ID and idShort are preferred over elements with the same semantic ID only, which
+/// are preferred over elements with the same idShort only.
+fn find_template_element(element: &SubmodelElement, template: &[SubmodelElement]) -> Option<usize> {
+    let same_semantic_id = |candidate: &SubmodelElement| is_same_semantic_id(candidate.get_semantic_id(), element.get_semantic_id());
+    let same_id_short = |candidate: &SubmodelElement| candidate.get_id_short().is_some()
+        && candidate.get_id_short() == element.get_id_short();
+    template.iter().position(|candidate| same_semantic_id(candidate) && same_id_short(candidate))
+        .or_else(|| template.iter().position(same_semantic_id))
+        .or_else(|| template.iter().position(same_id_short))
+}
+
+///Returns whether the template element stands for arbitrary elements of an instance.
+fn is_arbitrary(template: &SubmodelElement) -> bool {
+    template.get_semantic_id()
+        .and_then(|semantic_id| semantic_id.get_keys().first())
+        .is_some_and(|key| key.get_value() == ARBITRARY_SEMANTIC_ID)
+}
+
+///Returns whether the element is of the given, possibly abstract, submodel element type.
+pub(crate) fn is_of_type(element: &SubmodelElement, element_type: &AasSubmodelElements) -> bool {
+    match element_type {
+        AasSubmodelElements::SubmodelElement => true,
+        AasSubmodelElements::DataElement => matches!(element, SubmodelElement::Property(_)
+            | SubmodelElement::MultiLanguageProperty(_) | SubmodelElement::Range(_) | SubmodelElement::Blob(_)
+            | SubmodelElement::File(_) | SubmodelElement::ReferenceElement(_)),
+        AasSubmodelElements::EventElement => matches!(element, SubmodelElement::BasicEventElement(_)),
+        AasSubmodelElements::RelationshipElement => matches!(element, SubmodelElement::RelationshipElement(_)
+            | SubmodelElement::AnnotatedRelationshipElement(_)),
+        AasSubmodelElements::AnnotatedRelationshipElement => matches!(element, SubmodelElement::AnnotatedRelationshipElement(_)),
+        AasSubmodelElements::Entity => matches!(element, SubmodelElement::Entity(_)),
+        AasSubmodelElements::SubmodelElementCollection => matches!(element, SubmodelElement::SubmodelElementCollection(_)),
+        AasSubmodelElements::SubmodelElementList => matches!(element, SubmodelElement::SubmodelElementList(_)),
+        AasSubmodelElements::BasicEventElement => matches!(element, SubmodelElement::BasicEventElement(_)),
+        AasSubmodelElements::Blob => matches!(element, SubmodelElement::Blob(_)),
+        AasSubmodelElements::Capability => matches!(element, SubmodelElement::Capability(_)),
+        AasSubmodelElements::File => matches!(element, SubmodelElement::File(_)),
+        AasSubmodelElements::MultiLanguageProperty => matches!(element, SubmodelElement::MultiLanguageProperty(_)),
+        AasSubmodelElements::Operation => matches!(element, SubmodelElement::Operation(_)),
+        AasSubmodelElements::Property => matches!(element, SubmodelElement::Property(_)),
+        AasSubmodelElements::Range => matches!(element, SubmodelElement::Range(_)),
+        AasSubmodelElements::ReferenceElement => matches!(element, SubmodelElement::ReferenceElement(_))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::enumerations::cardinality::Cardinality;
+    use crate::enumerations::conformance_deviation_kind::ConformanceDeviationKind;
+    use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+    use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+    use crate::enumerations::smt_qualifier::SmtQualifier;
+    use crate::structs::conformance_report::ConformanceReport;
+    use crate::structs::property::Property;
+    use crate::structs::submodel::Submodel;
+    use crate::traits::qualifiable::TQualifiable;
+    use crate::traits::referable::TReferable;
+
+    fn property(cardinality: Option<Cardinality>) -> SubmodelElement {
+        let mut property = Property::new(DataTypeDefXsd::String);
+        property.set_id_short("Name".to_string());
+        if let Some(cardinality) = cardinality {
+            property.add_smt_qualifier(SmtQualifier::Cardinality(cardinality));
+        }
+        SubmodelElement::Property(property)
+    }
+
+    fn submodel(elements: Vec<SubmodelElement>) -> Submodel {
+        let mut submodel = Submodel::new("urn:example:submodel".to_string());
+        submodel.set_submodel_elements(elements);
+        submodel
+    }
+
+    ///Checks instances with zero, one and two occurrences of an element against a template with the
+    /// cardinality and returns the deviation kinds per number of occurrences.
+    fn deviations(cardinality: Option<Cardinality>) -> Vec<Option<ConformanceDeviationKind>> {
+        let template = submodel(vec![property(cardinality)]);
+        (0..3)
+            .map(|count| {
+                let instance = submodel((0..count).map(|_| property(None)).collect());
+                let report = ConformanceReport::check(&instance, &template);
+                assert!(report.get_deviations().len() <= 1);
+                report.get_deviations().first().map(|deviation| deviation.get_kind().clone())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn checks_cardinality_one() {
+        assert!(deviations(Some(Cardinality::One)) == vec![Some(ConformanceDeviationKind::MissingElement), None,
+                                                            Some(ConformanceDeviationKind::TooManyElements)]);
+    }
+
+    #[test]
+    fn checks_missing_cardinality_as_one() {
+        assert!(deviations(None) == deviations(Some(Cardinality::One)));
+    }
+
+    #[test]
+    fn checks_cardinality_zero_to_one() {
+        assert!(deviations(Some(Cardinality::ZeroToOne)) == vec![None, None, Some(ConformanceDeviationKind::TooManyElements)]);
+    }
+
+    #[test]
+    fn checks_cardinality_zero_to_many() {
+        assert!(deviations(Some(Cardinality::ZeroToMany)) == vec![None, None, None]);
+    }
+
+    #[test]
+    fn checks_cardinality_one_to_many() {
+        assert!(deviations(Some(Cardinality::OneToMany)) == vec![Some(ConformanceDeviationKind::MissingElement), None, None]);
+    }
+
+    #[test]
+    fn reports_cardinality_and_count() {
+        let template = submodel(vec![property(Some(Cardinality::OneToMany))]);
+        let report = ConformanceReport::check(&submodel(Vec::new()), &template);
+        let deviation = &report.get_deviations()[0];
+        assert_eq!(deviation.get_path(), "Name");
+        assert_eq!(deviation.get_expected().map(String::as_str), Some("OneToMany"));
+        assert_eq!(deviation.get_actual().map(String::as_str), Some("0"));
+    }
+}
\ No newline at end of file
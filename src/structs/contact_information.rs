@@ -0,0 +1,490 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::email::Email;
+use crate::structs::fax::Fax;
+use crate::structs::ip_communication::IpCommunication;
+use crate::structs::multi_language_text_type::MultiLanguageTextType;
+use crate::structs::phone::Phone;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, join_path, TemplateElement};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on a contact information of the IDTA submodel template Contact Information, which is
+/// also used for the address of the Digital Nameplate.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct ContactInformation {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl ContactInformation {
+    ///Semantic ID of a contact information.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/zvei/nameplate/1/0/ContactInformations/ContactInformation";
+
+    ///Creates a new, empty contact information.
+    pub fn new() -> ContactInformation {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_semantic_id(global_reference(ContactInformation::SEMANTIC_ID));
+        ContactInformation {
+            collection
+        }
+    }
+
+    ///Wraps a submodel element collection after checking the elements of a contact information.
+    ///
+    /// [collection]: collection containing a contact information
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<ContactInformation, ConversionError> {
+        ContactInformation::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(ContactInformation {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the contact information and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the name of the company.
+    pub fn get_company(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(COMPANY.id_short, COMPANY.semantic_id)
+    }
+
+    ///Sets the name of the company. Passing None removes it.
+    ///
+    /// [company]: name of the company
+    pub fn set_company(&mut self, company: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(COMPANY.id_short, COMPANY.semantic_id, company);
+    }
+
+    ///Returns the department of the company.
+    pub fn get_department(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(DEPARTMENT.id_short, DEPARTMENT.semantic_id)
+    }
+
+    ///Sets the department of the company. Passing None removes it.
+    ///
+    /// [department]: department of the company
+    pub fn set_department(&mut self, department: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(DEPARTMENT.id_short, DEPARTMENT.semantic_id, department);
+    }
+
+    ///Returns the street name and house number.
+    pub fn get_street(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(STREET.id_short, STREET.semantic_id)
+    }
+
+    ///Sets the street name and house number. Passing None removes it.
+    ///
+    /// [street]: street name and house number
+    pub fn set_street(&mut self, street: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(STREET.id_short, STREET.semantic_id, street);
+    }
+
+    ///Returns the ZIP code of the address.
+    pub fn get_zipcode(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(ZIPCODE.id_short, ZIPCODE.semantic_id)
+    }
+
+    ///Sets the ZIP code of the address. Passing None removes it.
+    ///
+    /// [zipcode]: ZIP code of the address
+    pub fn set_zipcode(&mut self, zipcode: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(ZIPCODE.id_short, ZIPCODE.semantic_id, zipcode);
+    }
+
+    ///Returns the P.O. box number.
+    pub fn get_po_box(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(PO_BOX.id_short, PO_BOX.semantic_id)
+    }
+
+    ///Sets the P.O. box number. Passing None removes it.
+    ///
+    /// [po_box]: P.O. box number
+    pub fn set_po_box(&mut self, po_box: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(PO_BOX.id_short, PO_BOX.semantic_id, po_box);
+    }
+
+    ///Returns the ZIP code of the P.O. box.
+    pub fn get_zip_code_of_po_box(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(ZIP_CODE_OF_PO_BOX.id_short, ZIP_CODE_OF_PO_BOX.semantic_id)
+    }
+
+    ///Sets the ZIP code of the P.O. box. Passing None removes it.
+    ///
+    /// [zip_code_of_po_box]: ZIP code of the P.O. box
+    pub fn set_zip_code_of_po_box(&mut self, zip_code_of_po_box: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(ZIP_CODE_OF_PO_BOX.id_short, ZIP_CODE_OF_PO_BOX.semantic_id, zip_code_of_po_box);
+    }
+
+    ///Returns the town or city.
+    pub fn get_city_town(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(CITY_TOWN.id_short, CITY_TOWN.semantic_id)
+    }
+
+    ///Sets the town or city. Passing None removes it.
+    ///
+    /// [city_town]: town or city
+    pub fn set_city_town(&mut self, city_town: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(CITY_TOWN.id_short, CITY_TOWN.semantic_id, city_town);
+    }
+
+    ///Returns the federal state, part of a state or county.
+    pub fn get_state_county(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(STATE_COUNTY.id_short, STATE_COUNTY.semantic_id)
+    }
+
+    ///Sets the federal state, part of a state or county. Passing None removes it.
+    ///
+    /// [state_county]: federal state, part of a state or county
+    pub fn set_state_county(&mut self, state_county: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(STATE_COUNTY.id_short, STATE_COUNTY.semantic_id, state_county);
+    }
+
+    ///Returns the country code according to ISO 3166-1.
+    pub fn get_national_code(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(NATIONAL_CODE.id_short, NATIONAL_CODE.semantic_id)
+    }
+
+    ///Sets the country code according to ISO 3166-1. Passing None removes it.
+    ///
+    /// [national_code]: country code according to ISO 3166-1
+    pub fn set_national_code(&mut self, national_code: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(NATIONAL_CODE.id_short, NATIONAL_CODE.semantic_id, national_code);
+    }
+
+    ///Returns the VAT identification number of the business partner.
+    pub fn get_vat_number(&self) -> Option<String> {
+        self.collection.get_property_value(VAT_NUMBER.id_short, VAT_NUMBER.semantic_id)
+    }
+
+    ///Sets the VAT identification number of the business partner. Passing None removes it.
+    ///
+    /// [vat_number]: VAT identification number of the business partner
+    pub fn set_vat_number(&mut self, vat_number: Option<String>) {
+        VAT_NUMBER.set_property_value(&mut self.collection, vat_number.as_ref());
+    }
+
+    ///Returns the web address of further information.
+    pub fn get_address_of_additional_link(&self) -> Option<String> {
+        self.collection.get_property_value(ADDRESS_OF_ADDITIONAL_LINK.id_short, ADDRESS_OF_ADDITIONAL_LINK.semantic_id)
+    }
+
+    ///Sets the web address of further information. Passing None removes it.
+    ///
+    /// [address_of_additional_link]: web address of further information
+    pub fn set_address_of_additional_link(&mut self, address_of_additional_link: Option<String>) {
+        ADDRESS_OF_ADDITIONAL_LINK.set_property_value(&mut self.collection, address_of_additional_link.as_ref());
+    }
+
+    ///Returns the role of the contact person, e.g. commercial contact.
+    pub fn get_role_of_contact_person(&self) -> Option<String> {
+        self.collection.get_property_value(ROLE_OF_CONTACT_PERSON.id_short, ROLE_OF_CONTACT_PERSON.semantic_id)
+    }
+
+    ///Sets the role of the contact person, e.g. commercial contact. Passing None removes it.
+    ///
+    /// [role_of_contact_person]: role of the contact person, e.g. commercial contact
+    pub fn set_role_of_contact_person(&mut self, role_of_contact_person: Option<String>) {
+        ROLE_OF_CONTACT_PERSON.set_property_value(&mut self.collection, role_of_contact_person.as_ref());
+    }
+
+    ///Returns the languages of the contact according to ISO 639-1, e.g. en.
+    pub fn get_languages(&self) -> Vec<String> {
+        self.collection.find_elements(LANGUAGE.id_short, LANGUAGE.semantic_id).into_iter()
+            .filter_map(|element| match element {
+                SubmodelElement::Property(property) => property.get_typed_value(),
+                _ => None
+            })
+            .collect()
+    }
+
+    ///Sets the languages of the contact according to ISO 639-1, e.g. en.
+    ///
+    /// [languages]: languages of the contact according to ISO 639-1, e.g. en
+    pub fn set_languages(&mut self, languages: Vec<String>) {
+        let elements = languages.iter().map(|value| LANGUAGE.new_property(value)).collect();
+        self.collection.set_elements(LANGUAGE.id_short, LANGUAGE.semantic_id, elements);
+    }
+
+    ///Returns the time zone of the contact as offset to UTC, e.g. +01:00.
+    pub fn get_time_zone(&self) -> Option<String> {
+        self.collection.get_property_value(TIME_ZONE.id_short, TIME_ZONE.semantic_id)
+    }
+
+    ///Sets the time zone of the contact as offset to UTC, e.g. +01:00. Passing None removes it.
+    ///
+    /// [time_zone]: time zone of the contact as offset to UTC, e.g. +01:00
+    pub fn set_time_zone(&mut self, time_zone: Option<String>) {
+        TIME_ZONE.set_property_value(&mut self.collection, time_zone.as_ref());
+    }
+
+    ///Returns the surname of the contact person.
+    pub fn get_name_of_contact(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(NAME_OF_CONTACT.id_short, NAME_OF_CONTACT.semantic_id)
+    }
+
+    ///Sets the surname of the contact person. Passing None removes it.
+    ///
+    /// [name_of_contact]: surname of the contact person
+    pub fn set_name_of_contact(&mut self, name_of_contact: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(NAME_OF_CONTACT.id_short, NAME_OF_CONTACT.semantic_id, name_of_contact);
+    }
+
+    ///Returns the first name of the contact person.
+    pub fn get_first_name(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(FIRST_NAME.id_short, FIRST_NAME.semantic_id)
+    }
+
+    ///Sets the first name of the contact person. Passing None removes it.
+    ///
+    /// [first_name]: first name of the contact person
+    pub fn set_first_name(&mut self, first_name: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(FIRST_NAME.id_short, FIRST_NAME.semantic_id, first_name);
+    }
+
+    ///Returns the middle names of the contact person.
+    pub fn get_middle_names(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(MIDDLE_NAMES.id_short, MIDDLE_NAMES.semantic_id)
+    }
+
+    ///Sets the middle names of the contact person. Passing None removes it.
+    ///
+    /// [middle_names]: middle names of the contact person
+    pub fn set_middle_names(&mut self, middle_names: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(MIDDLE_NAMES.id_short, MIDDLE_NAMES.semantic_id, middle_names);
+    }
+
+    ///Returns the formal title of the contact person.
+    pub fn get_title(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(TITLE.id_short, TITLE.semantic_id)
+    }
+
+    ///Sets the formal title of the contact person. Passing None removes it.
+    ///
+    /// [title]: formal title of the contact person
+    pub fn set_title(&mut self, title: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(TITLE.id_short, TITLE.semantic_id, title);
+    }
+
+    ///Returns the academic title of the contact person.
+    pub fn get_academic_title(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(ACADEMIC_TITLE.id_short, ACADEMIC_TITLE.semantic_id)
+    }
+
+    ///Sets the academic title of the contact person. Passing None removes it.
+    ///
+    /// [academic_title]: academic title of the contact person
+    pub fn set_academic_title(&mut self, academic_title: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(ACADEMIC_TITLE.id_short, ACADEMIC_TITLE.semantic_id, academic_title);
+    }
+
+    ///Returns the further details of the contact, e.g. opening hours.
+    pub fn get_further_details_of_contact(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(FURTHER_DETAILS_OF_CONTACT.id_short, FURTHER_DETAILS_OF_CONTACT.semantic_id)
+    }
+
+    ///Sets the further details of the contact, e.g. opening hours. Passing None removes it.
+    ///
+    /// [further_details_of_contact]: further details of the contact, e.g. opening hours
+    pub fn set_further_details_of_contact(&mut self, further_details_of_contact: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(FURTHER_DETAILS_OF_CONTACT.id_short, FURTHER_DETAILS_OF_CONTACT.semantic_id, further_details_of_contact);
+    }
+
+    ///Returns the telephone of the contact.
+    pub fn get_phone(&self) -> Option<Phone> {
+        self.collection.get_collection(PHONE.id_short, PHONE.semantic_id)
+            .map(|collection| Phone {
+                collection: collection.clone()
+            })
+    }
+
+    ///Sets the telephone of the contact. Passing None removes it.
+    ///
+    /// [phone]: telephone of the contact
+    pub fn set_phone(&mut self, phone: Option<Phone>) {
+        self.collection.set_element(PHONE.id_short, PHONE.semantic_id, phone.map(|phone| SubmodelElement::SubmodelElementCollection(phone.into_collection())));
+    }
+
+    ///Returns the fax of the contact.
+    pub fn get_fax(&self) -> Option<Fax> {
+        self.collection.get_collection(FAX.id_short, FAX.semantic_id)
+            .map(|collection| Fax {
+                collection: collection.clone()
+            })
+    }
+
+    ///Sets the fax of the contact. Passing None removes it.
+    ///
+    /// [fax]: fax of the contact
+    pub fn set_fax(&mut self, fax: Option<Fax>) {
+        self.collection.set_element(FAX.id_short, FAX.semantic_id, fax.map(|fax| SubmodelElement::SubmodelElementCollection(fax.into_collection())));
+    }
+
+    ///Returns the email of the contact.
+    pub fn get_email(&self) -> Option<Email> {
+        self.collection.get_collection(EMAIL.id_short, EMAIL.semantic_id)
+            .map(|collection| Email {
+                collection: collection.clone()
+            })
+    }
+
+    ///Sets the email of the contact. Passing None removes it.
+    ///
+    /// [email]: email of the contact
+    pub fn set_email(&mut self, email: Option<Email>) {
+        self.collection.set_element(EMAIL.id_short, EMAIL.semantic_id, email.map(|email| SubmodelElement::SubmodelElementCollection(email.into_collection())));
+    }
+
+    ///Returns the IP based communication channels of the contact, e.g. chats.
+    pub fn get_ip_communications(&self) -> Vec<IpCommunication> {
+        self.collection.get_collections(IP_COMMUNICATION.id_short, IP_COMMUNICATION.semantic_id).into_iter()
+            .map(|collection| IpCommunication {
+                collection: collection.clone()
+            })
+            .collect()
+    }
+
+    ///Sets the IP based communication channels of the contact, e.g. chats.
+    ///
+    /// [ip_communications]: IP based communication channels of the contact, e.g. chats
+    pub fn set_ip_communications(&mut self, ip_communications: Vec<IpCommunication>) {
+        let elements = ip_communications.into_iter()
+            .map(|item| SubmodelElement::SubmodelElementCollection(item.into_collection()))
+            .collect();
+        self.collection.set_elements(IP_COMMUNICATION.id_short, IP_COMMUNICATION.semantic_id, elements);
+    }
+
+    ///Returns the elements of a contact information in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        vec![
+            COMPANY.to_element(),
+            DEPARTMENT.to_element(),
+            STREET.to_element(),
+            ZIPCODE.to_element(),
+            PO_BOX.to_element(),
+            ZIP_CODE_OF_PO_BOX.to_element(),
+            CITY_TOWN.to_element(),
+            STATE_COUNTY.to_element(),
+            NATIONAL_CODE.to_element(),
+            VAT_NUMBER.to_element(),
+            ADDRESS_OF_ADDITIONAL_LINK.to_element(),
+            ROLE_OF_CONTACT_PERSON.to_element(),
+            LANGUAGE.to_element(),
+            TIME_ZONE.to_element(),
+            NAME_OF_CONTACT.to_element(),
+            FIRST_NAME.to_element(),
+            MIDDLE_NAMES.to_element(),
+            TITLE.to_element(),
+            ACADEMIC_TITLE.to_element(),
+            FURTHER_DETAILS_OF_CONTACT.to_element(),
+            PHONE.to_collection(Phone::template_elements()),
+            FAX.to_collection(Fax::template_elements()),
+            EMAIL.to_collection(Email::template_elements()),
+            IP_COMMUNICATION.to_collection(IpCommunication::template_elements())
+        ]
+    }
+
+    ///Checks the elements of a contact information.
+    ///
+    /// [collection]: collection containing a contact information
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        for element in PHONE.check(collection, path)? {
+            if let SubmodelElement::SubmodelElementCollection(nested) = element {
+                Phone::check(nested, &join_path(path, nested.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default()))?;
+            }
+        }
+        for element in FAX.check(collection, path)? {
+            if let SubmodelElement::SubmodelElementCollection(nested) = element {
+                Fax::check(nested, &join_path(path, nested.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default()))?;
+            }
+        }
+        for element in EMAIL.check(collection, path)? {
+            if let SubmodelElement::SubmodelElementCollection(nested) = element {
+                Email::check(nested, &join_path(path, nested.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default()))?;
+            }
+        }
+        for element in IP_COMMUNICATION.check(collection, path)? {
+            if let SubmodelElement::SubmodelElementCollection(nested) = element {
+                IpCommunication::check(nested, &join_path(path, nested.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default()))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for ContactInformation {
+    fn default() -> ContactInformation {
+        ContactInformation::new()
+    }
+}
+
+const COMPANY: TemplateElement =
+    TemplateElement::new("Company", "0173-1#02-AAW001#001", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const DEPARTMENT: TemplateElement =
+    TemplateElement::new("Department", "0173-1#02-AAO127#003", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const STREET: TemplateElement =
+    TemplateElement::new("Street", "0173-1#02-AAO128#002", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const ZIPCODE: TemplateElement =
+    TemplateElement::new("Zipcode", "0173-1#02-AAO129#002", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const PO_BOX: TemplateElement =
+    TemplateElement::new("POBox", "0173-1#02-AAO130#002", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const ZIP_CODE_OF_PO_BOX: TemplateElement =
+    TemplateElement::new("ZipCodeOfPOBox", "0173-1#02-AAO131#002", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const CITY_TOWN: TemplateElement =
+    TemplateElement::new("CityTown", "0173-1#02-AAO132#002", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const STATE_COUNTY: TemplateElement =
+    TemplateElement::new("StateCounty", "0173-1#02-AAO133#002", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const NATIONAL_CODE: TemplateElement =
+    TemplateElement::new("NationalCode", "0173-1#02-AAO134#002", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const VAT_NUMBER: TemplateElement =
+    TemplateElement::property("VATNumber", "0173-1#02-AAO135#002", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const ADDRESS_OF_ADDITIONAL_LINK: TemplateElement =
+    TemplateElement::property("AddressOfAdditionalLink", "0173-1#02-AAQ326#002", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const ROLE_OF_CONTACT_PERSON: TemplateElement =
+    TemplateElement::property("RoleOfContactPerson", "0173-1#02-AAO204#003", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const LANGUAGE: TemplateElement =
+    TemplateElement::property("Language{00}", "0173-1#02-AAO895#003", DataTypeDefXsd::String, Cardinality::ZeroToMany);
+const TIME_ZONE: TemplateElement =
+    TemplateElement::property("TimeZone", "https://admin-shell.io/zvei/nameplate/1/0/ContactInformations/ContactInformation/TimeZone", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const NAME_OF_CONTACT: TemplateElement =
+    TemplateElement::new("NameOfContact", "0173-1#02-AAO205#002", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const FIRST_NAME: TemplateElement =
+    TemplateElement::new("FirstName", "0173-1#02-AAO206#002", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const MIDDLE_NAMES: TemplateElement =
+    TemplateElement::new("MiddleNames", "0173-1#02-AAO207#002", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const TITLE: TemplateElement =
+    TemplateElement::new("Title", "0173-1#02-AAO208#003", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const ACADEMIC_TITLE: TemplateElement =
+    TemplateElement::new("AcademicTitle", "0173-1#02-AAO209#003", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const FURTHER_DETAILS_OF_CONTACT: TemplateElement =
+    TemplateElement::new("FurtherDetailsOfContact", "0173-1#02-AAO210#002", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const PHONE: TemplateElement =
+    TemplateElement::new("Phone", Phone::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToOne);
+const FAX: TemplateElement =
+    TemplateElement::new("Fax", Fax::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToOne);
+const EMAIL: TemplateElement =
+    TemplateElement::new("Email", Email::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToOne);
+const IP_COMMUNICATION: TemplateElement =
+    TemplateElement::new("IPCommunication{00}", IpCommunication::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToMany);
+
+const ELEMENTS: [&TemplateElement; 24] = [&COMPANY, &DEPARTMENT, &STREET, &ZIPCODE, &PO_BOX, &ZIP_CODE_OF_PO_BOX,
+    &CITY_TOWN, &STATE_COUNTY, &NATIONAL_CODE, &VAT_NUMBER, &ADDRESS_OF_ADDITIONAL_LINK, &ROLE_OF_CONTACT_PERSON,
+    &LANGUAGE, &TIME_ZONE, &NAME_OF_CONTACT, &FIRST_NAME, &MIDDLE_NAMES, &TITLE, &ACADEMIC_TITLE,
+    &FURTHER_DETAILS_OF_CONTACT, &PHONE, &FAX, &EMAIL, &IP_COMMUNICATION];
@@ -0,0 +1,131 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::modelling_kind::ModellingKind;
+use crate::structs::conformance_report::ConformanceReport;
+use crate::structs::contact_information::ContactInformation;
+use crate::structs::submodel::Submodel;
+use crate::structs::template_element::{global_reference, TemplateElement};
+use crate::traits::has_kind::THasKind;
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed model of the submodel template Contact Information (IDTA 02002-1-0), which contains the
+/// contacts of an asset, e.g. the manufacturer's service hotline.
+///
+/// The model wraps the submodel, so the conversion from and to a submodel is lossless and elements
+/// the model does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct ContactInformations {
+    ///The wrapped submodel.
+    submodel: Submodel
+}
+
+impl ContactInformations {
+    ///Semantic ID of the Contact Information submodel.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/zvei/nameplate/1/0/ContactInformations";
+    ///idShort of the Contact Information submodel.
+    pub const ID_SHORT: &str = "ContactInformations";
+
+    ///Creates a new Contact Information submodel without contacts.
+    ///
+    /// [id]: global identifier of the submodel
+    pub fn new(id: String) -> ContactInformations {
+        let mut submodel = Submodel::new(id);
+        submodel.set_id_short(ContactInformations::ID_SHORT.to_string());
+        submodel.set_semantic_id(global_reference(ContactInformations::SEMANTIC_ID));
+        submodel.set_kind(ModellingKind::Instance);
+        ContactInformations {
+            submodel
+        }
+    }
+
+    ///Wraps a submodel after checking that its contact informations contain the expected element
+    /// types and valid values.
+    ///
+    /// [submodel]: Contact Information submodel
+    pub fn from_submodel(submodel: Submodel) -> Result<ContactInformations, ConversionError> {
+        ContactInformations::check(&submodel)?;
+        Ok(ContactInformations {
+            submodel
+        })
+    }
+
+    ///Returns the wrapped submodel.
+    pub fn get_submodel(&self) -> &Submodel {
+        &self.submodel
+    }
+
+    ///Consumes the Contact Information and returns the wrapped submodel.
+    pub fn into_submodel(self) -> Submodel {
+        self.submodel
+    }
+
+    ///Returns the submodel template of the Contact Information.
+    pub fn template() -> Submodel {
+        let mut template = Submodel::new("https://admin-shell.io/idta/SubmodelTemplate/ContactInformation/1/0".to_string());
+        template.set_id_short(ContactInformations::ID_SHORT.to_string());
+        template.set_semantic_id(global_reference(ContactInformations::SEMANTIC_ID));
+        template.set_kind(ModellingKind::Template);
+        template.set_submodel_elements(vec![
+            CONTACT_INFORMATION.to_collection(ContactInformation::template_elements())
+        ]);
+        template
+    }
+
+    ///Checks the wrapped submodel against the submodel template of the Contact Information.
+    pub fn check_conformance(&self) -> ConformanceReport {
+        self.submodel.check_conformance(&ContactInformations::template())
+    }
+
+    ///Returns the contact informations.
+    pub fn get_contact_informations(&self) -> Vec<ContactInformation> {
+        self.submodel.get_collections(CONTACT_INFORMATION.id_short, CONTACT_INFORMATION.semantic_id).into_iter()
+            .map(|collection| ContactInformation {
+                collection: collection.clone()
+            })
+            .collect()
+    }
+
+    ///Sets the contact informations.
+    ///
+    /// [contact_informations]: contact informations
+    pub fn set_contact_informations(&mut self, contact_informations: Vec<ContactInformation>) {
+        let elements = contact_informations.into_iter()
+            .map(|item| SubmodelElement::SubmodelElementCollection(item.into_collection()))
+            .collect();
+        self.submodel.set_elements(CONTACT_INFORMATION.id_short, CONTACT_INFORMATION.semantic_id, elements);
+    }
+
+    ///Adds a contact information after the existing ones. It gets the next free idShort, e.g.
+    /// `ContactInformation02`.
+    ///
+    /// [contact_information]: contact information to add
+    pub fn add_contact_information(&mut self, contact_information: ContactInformation) {
+        self.submodel.add_element(CONTACT_INFORMATION.id_short, CONTACT_INFORMATION.semantic_id,
+                                  SubmodelElement::SubmodelElementCollection(contact_information.into_collection()));
+    }
+
+    ///Returns the contact informations whose contact person has the role.
+    ///
+    /// [role]: role of the contact person, e.g. the value ID of commercial contact
+    pub fn get_contact_informations_of_role(&self, role: &str) -> Vec<ContactInformation> {
+        self.get_contact_informations().into_iter()
+            .filter(|contact_information| contact_information.get_role_of_contact_person().is_some_and(|value| value.trim() == role))
+            .collect()
+    }
+
+    fn check(submodel: &Submodel) -> Result<(), ConversionError> {
+        for element in CONTACT_INFORMATION.check(submodel, "")? {
+            if let SubmodelElement::SubmodelElementCollection(collection) = element {
+                ContactInformation::check(collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+const CONTACT_INFORMATION: TemplateElement =
+    TemplateElement::new("ContactInformation{00}", ContactInformation::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToMany);
@@ -0,0 +1,320 @@
+use serde::{Deserialize, Serialize};
+
+use crate::enumerations::data_type_iec61360::DataTypeIec61360;
+use crate::structs::level_type::LevelType;
+use crate::structs::multi_language_text_type::MultiLanguageTextType;
+use crate::structs::reference::Reference;
+use crate::structs::value_list::ValueList;
+
+///Content of the data specification IEC 61360, which describes a concept like a property
+/// including its preferred name, its unit and its data type. It is typically embedded in a concept
+/// description.
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
+#[serde(tag = "modelType", rename = "DataSpecificationIec61360")]
+pub struct DataSpecificationIec61360 {
+    ///Preferred names of the concept in several languages.
+    #[serde(rename = "preferredName")]
+    preferred_name: Vec<MultiLanguageTextType>,
+    ///Short names of the concept in several languages.
+    #[serde(rename = "shortName", default, skip_serializing_if = "Vec::is_empty")]
+    short_name: Vec<MultiLanguageTextType>,
+    ///Unit of the values of the concept, e.g. `mm`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    unit: Option<String>,
+    ///Global reference to the unit, e.g. an ECLASS unit IRDI.
+    #[serde(rename = "unitId", default, skip_serializing_if = "Option::is_none")]
+    unit_id: Option<Reference>,
+    ///Source of the definition of the concept.
+    #[serde(rename = "sourceOfDefinition", default, skip_serializing_if = "Option::is_none")]
+    source_of_definition: Option<String>,
+    ///Symbol of the concept.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    symbol: Option<String>,
+    ///Data type of the values of the concept.
+    #[serde(rename = "dataType", default, skip_serializing_if = "Option::is_none")]
+    data_type: Option<DataTypeIec61360>,
+    ///Definitions of the concept in several languages.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    definition: Vec<MultiLanguageTextType>,
+    ///Format of the values of the concept.
+    #[serde(rename = "valueFormat", default, skip_serializing_if = "Option::is_none")]
+    value_format: Option<String>,
+    ///List of the allowed values of the concept.
+    #[serde(rename = "valueList", default, skip_serializing_if = "Option::is_none")]
+    value_list: Option<ValueList>,
+    ///Value of the concept, if the concept is a value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+    ///Levels of the values of the concept.
+    #[serde(rename = "levelType", default, skip_serializing_if = "Option::is_none")]
+    level_type: Option<LevelType>
+}
+
+impl DataSpecificationIec61360 {
+    ///Creates a new instance of the struct.
+    ///
+    /// [preferred_name]: preferred names of the concept in several languages
+    pub fn new(preferred_name: Vec<MultiLanguageTextType>) -> DataSpecificationIec61360 {
+        DataSpecificationIec61360 {
+            preferred_name,
+            short_name: Vec::new(),
+            unit: None,
+            unit_id: None,
+            source_of_definition: None,
+            symbol: None,
+            data_type: None,
+            definition: Vec::new(),
+            value_format: None,
+            value_list: None,
+            value: None,
+            level_type: None
+        }
+    }
+
+    ///Sets the preferred names of the concept in several languages.
+    ///
+    /// [preferred_name]: preferred names of the concept in several languages
+    pub fn set_preferred_name(&mut self, preferred_name: Vec<MultiLanguageTextType>) {
+        self.preferred_name = preferred_name;
+    }
+
+    ///Returns the preferred names of the concept in several languages.
+    pub fn get_preferred_name(&self) -> &Vec<MultiLanguageTextType> {
+        &self.preferred_name
+    }
+
+    ///Returns the mutable preferred names of the concept in several languages.
+    pub fn get_mut_preferred_name(&mut self) -> &mut Vec<MultiLanguageTextType> {
+        &mut self.preferred_name
+    }
+
+    ///Adds a preferred name of the concept in a language.
+    ///
+    /// [preferred_name]: preferred name of the concept in a language
+    pub fn add_preferred_name(&mut self, preferred_name: MultiLanguageTextType) {
+        self.preferred_name.push(preferred_name);
+    }
+
+    ///Sets the short names of the concept in several languages.
+    ///
+    /// [short_name]: short names of the concept in several languages
+    pub fn set_short_name(&mut self, short_name: Vec<MultiLanguageTextType>) {
+        self.short_name = short_name;
+    }
+
+    ///Returns the short names of the concept in several languages.
+    pub fn get_short_name(&self) -> &Vec<MultiLanguageTextType> {
+        &self.short_name
+    }
+
+    ///Returns the mutable short names of the concept in several languages.
+    pub fn get_mut_short_name(&mut self) -> &mut Vec<MultiLanguageTextType> {
+        &mut self.short_name
+    }
+
+    ///Adds a short name of the concept in a language.
+    ///
+    /// [short_name]: short name of the concept in a language
+    pub fn add_short_name(&mut self, short_name: MultiLanguageTextType) {
+        self.short_name.push(short_name);
+    }
+
+    ///Sets the unit of the values of the concept, e.g. `mm`.
+    ///
+    /// [unit]: unit of the values of the concept, e.g. `mm`
+    pub fn set_unit(&mut self, unit: String) {
+        self.unit = Some(unit);
+    }
+
+    ///Returns the optional unit of the values of the concept, e.g. `mm`.
+    pub fn get_unit(&self) -> Option<&String> {
+        self.unit.as_ref()
+    }
+
+    ///Returns the optional mutable unit of the values of the concept, e.g. `mm`.
+    pub fn get_mut_unit(&mut self) -> Option<&mut String> {
+        self.unit.as_mut()
+    }
+
+    ///Sets the global reference to the unit, e.g. an ECLASS unit IRDI.
+    ///
+    /// [unit_id]: global reference to the unit, e.g. an ECLASS unit IRDI
+    pub fn set_unit_id(&mut self, unit_id: Reference) {
+        self.unit_id = Some(unit_id);
+    }
+
+    ///Returns the optional global reference to the unit, e.g. an ECLASS unit IRDI.
+    pub fn get_unit_id(&self) -> Option<&Reference> {
+        self.unit_id.as_ref()
+    }
+
+    ///Returns the optional mutable global reference to the unit, e.g. an ECLASS unit IRDI.
+    pub fn get_mut_unit_id(&mut self) -> Option<&mut Reference> {
+        self.unit_id.as_mut()
+    }
+
+    ///Sets the source of the definition of the concept.
+    ///
+    /// [source_of_definition]: source of the definition of the concept
+    pub fn set_source_of_definition(&mut self, source_of_definition: String) {
+        self.source_of_definition = Some(source_of_definition);
+    }
+
+    ///Returns the optional source of the definition of the concept.
+    pub fn get_source_of_definition(&self) -> Option<&String> {
+        self.source_of_definition.as_ref()
+    }
+
+    ///Returns the optional mutable source of the definition of the concept.
+    pub fn get_mut_source_of_definition(&mut self) -> Option<&mut String> {
+        self.source_of_definition.as_mut()
+    }
+
+    ///Sets the symbol of the concept.
+    ///
+    /// [symbol]: symbol of the concept
+    pub fn set_symbol(&mut self, symbol: String) {
+        self.symbol = Some(symbol);
+    }
+
+    ///Returns the optional symbol of the concept.
+    pub fn get_symbol(&self) -> Option<&String> {
+        self.symbol.as_ref()
+    }
+
+    ///Returns the optional mutable symbol of the concept.
+    pub fn get_mut_symbol(&mut self) -> Option<&mut String> {
+        self.symbol.as_mut()
+    }
+
+    ///Sets the data type of the values of the concept.
+    ///
+    /// [data_type]: data type of the values of the concept
+    pub fn set_data_type(&mut self, data_type: DataTypeIec61360) {
+        self.data_type = Some(data_type);
+    }
+
+    ///Returns the optional data type of the values of the concept.
+    pub fn get_data_type(&self) -> Option<&DataTypeIec61360> {
+        self.data_type.as_ref()
+    }
+
+    ///Returns the optional mutable data type of the values of the concept.
+    pub fn get_mut_data_type(&mut self) -> Option<&mut DataTypeIec61360> {
+        self.data_type.as_mut()
+    }
+
+    ///Sets the definitions of the concept in several languages.
+    ///
+    /// [definition]: definitions of the concept in several languages
+    pub fn set_definition(&mut self, definition: Vec<MultiLanguageTextType>) {
+        self.definition = definition;
+    }
+
+    ///Returns the definitions of the concept in several languages.
+    pub fn get_definition(&self) -> &Vec<MultiLanguageTextType> {
+        &self.definition
+    }
+
+    ///Returns the mutable definitions of the concept in several languages.
+    pub fn get_mut_definition(&mut self) -> &mut Vec<MultiLanguageTextType> {
+        &mut self.definition
+    }
+
+    ///Adds a definition of the concept in a language.
+    ///
+    /// [definition]: definition of the concept in a language
+    pub fn add_definition(&mut self, definition: MultiLanguageTextType) {
+        self.definition.push(definition);
+    }
+
+    ///Sets the format of the values of the concept.
+    ///
+    /// [value_format]: format of the values of the concept
+    pub fn set_value_format(&mut self, value_format: String) {
+        self.value_format = Some(value_format);
+    }
+
+    ///Returns the optional format of the values of the concept.
+    pub fn get_value_format(&self) -> Option<&String> {
+        self.value_format.as_ref()
+    }
+
+    ///Returns the optional mutable format of the values of the concept.
+    pub fn get_mut_value_format(&mut self) -> Option<&mut String> {
+        self.value_format.as_mut()
+    }
+
+    ///Sets the list of the allowed values of the concept.
+    ///
+    /// [value_list]: list of the allowed values of the concept
+    pub fn set_value_list(&mut self, value_list: ValueList) {
+        self.value_list = Some(value_list);
+    }
+
+    ///Returns the optional list of the allowed values of the concept.
+    pub fn get_value_list(&self) -> Option<&ValueList> {
+        self.value_list.as_ref()
+    }
+
+    ///Returns the optional mutable list of the allowed values of the concept.
+    pub fn get_mut_value_list(&mut self) -> Option<&mut ValueList> {
+        self.value_list.as_mut()
+    }
+
+    ///Sets the value of the concept, if the concept is a value.
+    ///
+    /// [value]: value of the concept, if the concept is a value
+    pub fn set_value(&mut self, value: String) {
+        self.value = Some(value);
+    }
+
+    ///Returns the optional value of the concept, if the concept is a value.
+    pub fn get_value(&self) -> Option<&String> {
+        self.value.as_ref()
+    }
+
+    ///Returns the optional mutable value of the concept, if the concept is a value.
+    pub fn get_mut_value(&mut self) -> Option<&mut String> {
+        self.value.as_mut()
+    }
+
+    ///Sets the levels of the values of the concept.
+    ///
+    /// [level_type]: levels of the values of the concept
+    pub fn set_level_type(&mut self, level_type: LevelType) {
+        self.level_type = Some(level_type);
+    }
+
+    ///Returns the optional levels of the values of the concept.
+    pub fn get_level_type(&self) -> Option<&LevelType> {
+        self.level_type.as_ref()
+    }
+
+    ///Returns the optional mutable levels of the values of the concept.
+    pub fn get_mut_level_type(&mut self) -> Option<&mut LevelType> {
+        self.level_type.as_mut()
+    }
+
+
+    ///Returns all references contained in the data specification content.
+    pub fn get_references(&self) -> Vec<&Reference> {
+        self.unit_id.iter()
+            .chain(self.value_list.iter()
+                .flat_map(|value_list| value_list.get_value_reference_pairs())
+                .filter_map(|value_reference_pair| value_reference_pair.get_value_id()))
+            .flat_map(|reference| reference.get_references())
+            .collect()
+    }
+
+    ///Calls the visitor for every reference contained in the data specification content.
+    ///
+    /// [visitor]: function called for every reference
+    pub fn visit_references_mut(&mut self, visitor: &mut dyn FnMut(&mut Reference)) {
+        self.unit_id.iter_mut()
+            .chain(self.value_list.iter_mut()
+                .flat_map(|value_list| value_list.get_mut_value_reference_pairs())
+                .filter_map(|value_reference_pair| value_reference_pair.get_mut_value_id()))
+            .for_each(|reference| reference.visit_references_mut(visitor));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,498 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::modelling_kind::ModellingKind;
+use crate::structs::conformance_report::ConformanceReport;
+use crate::structs::contact_information::ContactInformation;
+use crate::structs::file::File;
+use crate::structs::guideline_specific_properties::GuidelineSpecificProperties;
+use crate::structs::marking::Marking;
+use crate::structs::multi_language_text_type::MultiLanguageTextType;
+use crate::structs::submodel::Submodel;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::submodel_element_list::SubmodelElementList;
+use crate::structs::template_element::{global_reference, join_path, TemplateElement};
+use crate::traits::has_kind::THasKind;
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed model of the submodel template Digital Nameplate for Industrial Equipment (IDTA 02006-3-0).
+///
+/// The model wraps the submodel, so the conversion from and to a submodel is lossless and elements
+/// the model does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct DigitalNameplate {
+    ///The wrapped submodel.
+    submodel: Submodel
+}
+
+impl DigitalNameplate {
+    ///Semantic ID of the Digital Nameplate submodel.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/idta/nameplate/3/0/Nameplate";
+    ///idShort of the Digital Nameplate submodel.
+    pub const ID_SHORT: &str = "Nameplate";
+
+    ///Creates a new Digital Nameplate submodel with the mandatory elements.
+    ///
+    /// [id]: global identifier of the submodel
+    /// [uri_of_the_product]: unique global identification of the product
+    /// [manufacturer_name]: legally valid designation of the manufacturer
+    /// [manufacturer_product_designation]: short description of the product
+    /// [address_information]: address of the manufacturer
+    /// [year_of_construction]: year the product instance was completed
+    pub fn new(id: String, uri_of_the_product: String, manufacturer_name: Vec<MultiLanguageTextType>,
+               manufacturer_product_designation: Vec<MultiLanguageTextType>, address_information: ContactInformation,
+               year_of_construction: String) -> DigitalNameplate {
+        let mut submodel = Submodel::new(id);
+        submodel.set_id_short(DigitalNameplate::ID_SHORT.to_string());
+        submodel.set_semantic_id(global_reference(DigitalNameplate::SEMANTIC_ID));
+        submodel.set_kind(ModellingKind::Instance);
+        let mut nameplate = DigitalNameplate {
+            submodel
+        };
+        nameplate.set_uri_of_the_product(uri_of_the_product);
+        nameplate.set_manufacturer_name(manufacturer_name);
+        nameplate.set_manufacturer_product_designation(manufacturer_product_designation);
+        nameplate.set_address_information(address_information);
+        nameplate.set_year_of_construction(year_of_construction);
+        nameplate
+    }
+
+    ///Wraps a submodel after checking that it contains the mandatory elements of the Digital
+    /// Nameplate with the expected types and valid values.
+    ///
+    /// [submodel]: Digital Nameplate submodel
+    pub fn from_submodel(submodel: Submodel) -> Result<DigitalNameplate, ConversionError> {
+        DigitalNameplate::check(&submodel)?;
+        Ok(DigitalNameplate {
+            submodel
+        })
+    }
+
+    ///Returns the wrapped submodel.
+    pub fn get_submodel(&self) -> &Submodel {
+        &self.submodel
+    }
+
+    ///Consumes the Digital Nameplate and returns the wrapped submodel.
+    pub fn into_submodel(self) -> Submodel {
+        self.submodel
+    }
+
+    ///Returns the submodel template of the Digital Nameplate.
+    pub fn template() -> Submodel {
+        let mut template = Submodel::new("https://admin-shell.io/idta/SubmodelTemplate/DigitalNameplate/3/0".to_string());
+        template.set_id_short(DigitalNameplate::ID_SHORT.to_string());
+        template.set_semantic_id(global_reference(DigitalNameplate::SEMANTIC_ID));
+        template.set_kind(ModellingKind::Template);
+        template.set_submodel_elements(vec![
+            URI_OF_THE_PRODUCT.to_element(),
+            MANUFACTURER_NAME.to_element(),
+            MANUFACTURER_PRODUCT_DESIGNATION.to_element(),
+            ADDRESS_INFORMATION.to_collection(ContactInformation::template_elements()),
+            MANUFACTURER_PRODUCT_ROOT.to_element(),
+            MANUFACTURER_PRODUCT_FAMILY.to_element(),
+            MANUFACTURER_PRODUCT_TYPE.to_element(),
+            ORDER_CODE_OF_MANUFACTURER.to_element(),
+            PRODUCT_ARTICLE_NUMBER_OF_MANUFACTURER.to_element(),
+            SERIAL_NUMBER.to_element(),
+            YEAR_OF_CONSTRUCTION.to_element(),
+            DATE_OF_MANUFACTURE.to_element(),
+            HARDWARE_VERSION.to_element(),
+            FIRMWARE_VERSION.to_element(),
+            SOFTWARE_VERSION.to_element(),
+            COUNTRY_OF_ORIGIN.to_element(),
+            UNIQUE_FACILITY_IDENTIFIER.to_element(),
+            COMPANY_LOGO.to_element(),
+            MARKINGS.to_list(&MARKING, Marking::template_elements()),
+            ASSET_SPECIFIC_PROPERTIES.to_collection(vec![
+                GUIDELINE_SPECIFIC_PROPERTIES.to_collection(GuidelineSpecificProperties::template_elements())
+            ])
+        ]);
+        template
+    }
+
+    ///Checks the wrapped submodel against the submodel template of the Digital Nameplate.
+    pub fn check_conformance(&self) -> ConformanceReport {
+        self.submodel.check_conformance(&DigitalNameplate::template())
+    }
+
+    ///Returns the unique global identification of the product using a universal resource identifier.
+    pub fn get_uri_of_the_product(&self) -> Option<String> {
+        self.submodel.get_property_value(URI_OF_THE_PRODUCT.id_short, URI_OF_THE_PRODUCT.semantic_id)
+    }
+
+    ///Sets the unique global identification of the product using a universal resource identifier.
+    ///
+    /// [uri_of_the_product]: unique global identification of the product using a universal resource identifier
+    pub fn set_uri_of_the_product(&mut self, uri_of_the_product: String) {
+        URI_OF_THE_PRODUCT.set_property_value(&mut self.submodel, Some(&uri_of_the_product));
+    }
+
+    ///Returns the legally valid designation of the manufacturer.
+    pub fn get_manufacturer_name(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.submodel.get_multi_language_value(MANUFACTURER_NAME.id_short, MANUFACTURER_NAME.semantic_id)
+    }
+
+    ///Sets the legally valid designation of the manufacturer.
+    ///
+    /// [manufacturer_name]: legally valid designation of the manufacturer
+    pub fn set_manufacturer_name(&mut self, manufacturer_name: Vec<MultiLanguageTextType>) {
+        self.submodel.set_multi_language_value(MANUFACTURER_NAME.id_short, MANUFACTURER_NAME.semantic_id, Some(manufacturer_name));
+    }
+
+    ///Returns the short description of the product.
+    pub fn get_manufacturer_product_designation(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.submodel.get_multi_language_value(MANUFACTURER_PRODUCT_DESIGNATION.id_short, MANUFACTURER_PRODUCT_DESIGNATION.semantic_id)
+    }
+
+    ///Sets the short description of the product.
+    ///
+    /// [manufacturer_product_designation]: short description of the product
+    pub fn set_manufacturer_product_designation(&mut self, manufacturer_product_designation: Vec<MultiLanguageTextType>) {
+        self.submodel.set_multi_language_value(MANUFACTURER_PRODUCT_DESIGNATION.id_short, MANUFACTURER_PRODUCT_DESIGNATION.semantic_id, Some(manufacturer_product_designation));
+    }
+
+    ///Returns the address of the manufacturer.
+    pub fn get_address_information(&self) -> Option<ContactInformation> {
+        self.submodel.get_collection(ADDRESS_INFORMATION.id_short, ADDRESS_INFORMATION.semantic_id)
+            .map(|collection| ContactInformation {
+                collection: collection.clone()
+            })
+    }
+
+    ///Sets the address of the manufacturer.
+    ///
+    /// [address_information]: address of the manufacturer
+    pub fn set_address_information(&mut self, address_information: ContactInformation) {
+        self.submodel.set_element(ADDRESS_INFORMATION.id_short, ADDRESS_INFORMATION.semantic_id,
+                                  Some(SubmodelElement::SubmodelElementCollection(address_information.into_collection())));
+    }
+
+    ///Returns the top level of the product hierarchy of the manufacturer.
+    pub fn get_manufacturer_product_root(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.submodel.get_multi_language_value(MANUFACTURER_PRODUCT_ROOT.id_short, MANUFACTURER_PRODUCT_ROOT.semantic_id)
+    }
+
+    ///Sets the top level of the product hierarchy of the manufacturer. Passing None removes it.
+    ///
+    /// [manufacturer_product_root]: top level of the product hierarchy of the manufacturer
+    pub fn set_manufacturer_product_root(&mut self, manufacturer_product_root: Option<Vec<MultiLanguageTextType>>) {
+        self.submodel.set_multi_language_value(MANUFACTURER_PRODUCT_ROOT.id_short, MANUFACTURER_PRODUCT_ROOT.semantic_id, manufacturer_product_root);
+    }
+
+    ///Returns the second level of the product hierarchy of the manufacturer.
+    pub fn get_manufacturer_product_family(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.submodel.get_multi_language_value(MANUFACTURER_PRODUCT_FAMILY.id_short, MANUFACTURER_PRODUCT_FAMILY.semantic_id)
+    }
+
+    ///Sets the second level of the product hierarchy of the manufacturer. Passing None removes it.
+    ///
+    /// [manufacturer_product_family]: second level of the product hierarchy of the manufacturer
+    pub fn set_manufacturer_product_family(&mut self, manufacturer_product_family: Option<Vec<MultiLanguageTextType>>) {
+        self.submodel.set_multi_language_value(MANUFACTURER_PRODUCT_FAMILY.id_short, MANUFACTURER_PRODUCT_FAMILY.semantic_id, manufacturer_product_family);
+    }
+
+    ///Returns the characteristic designation of the product type.
+    pub fn get_manufacturer_product_type(&self) -> Option<String> {
+        self.submodel.get_property_value(MANUFACTURER_PRODUCT_TYPE.id_short, MANUFACTURER_PRODUCT_TYPE.semantic_id)
+    }
+
+    ///Sets the characteristic designation of the product type. Passing None removes it.
+    ///
+    /// [manufacturer_product_type]: characteristic designation of the product type
+    pub fn set_manufacturer_product_type(&mut self, manufacturer_product_type: Option<String>) {
+        MANUFACTURER_PRODUCT_TYPE.set_property_value(&mut self.submodel, manufacturer_product_type.as_ref());
+    }
+
+    ///Returns the order code of the product assigned by the manufacturer.
+    pub fn get_order_code_of_manufacturer(&self) -> Option<String> {
+        self.submodel.get_property_value(ORDER_CODE_OF_MANUFACTURER.id_short, ORDER_CODE_OF_MANUFACTURER.semantic_id)
+    }
+
+    ///Sets the order code of the product assigned by the manufacturer. Passing None removes it.
+    ///
+    /// [order_code_of_manufacturer]: order code of the product assigned by the manufacturer
+    pub fn set_order_code_of_manufacturer(&mut self, order_code_of_manufacturer: Option<String>) {
+        ORDER_CODE_OF_MANUFACTURER.set_property_value(&mut self.submodel, order_code_of_manufacturer.as_ref());
+    }
+
+    ///Returns the article number of the product assigned by the manufacturer.
+    pub fn get_product_article_number_of_manufacturer(&self) -> Option<String> {
+        self.submodel.get_property_value(PRODUCT_ARTICLE_NUMBER_OF_MANUFACTURER.id_short, PRODUCT_ARTICLE_NUMBER_OF_MANUFACTURER.semantic_id)
+    }
+
+    ///Sets the article number of the product assigned by the manufacturer. Passing None removes it.
+    ///
+    /// [product_article_number_of_manufacturer]: article number of the product assigned by the manufacturer
+    pub fn set_product_article_number_of_manufacturer(&mut self, product_article_number_of_manufacturer: Option<String>) {
+        PRODUCT_ARTICLE_NUMBER_OF_MANUFACTURER.set_property_value(&mut self.submodel, product_article_number_of_manufacturer.as_ref());
+    }
+
+    ///Returns the serial number of the product instance.
+    pub fn get_serial_number(&self) -> Option<String> {
+        self.submodel.get_property_value(SERIAL_NUMBER.id_short, SERIAL_NUMBER.semantic_id)
+    }
+
+    ///Sets the serial number of the product instance. Passing None removes it.
+    ///
+    /// [serial_number]: serial number of the product instance
+    pub fn set_serial_number(&mut self, serial_number: Option<String>) {
+        SERIAL_NUMBER.set_property_value(&mut self.submodel, serial_number.as_ref());
+    }
+
+    ///Returns the year the product instance was completed.
+    pub fn get_year_of_construction(&self) -> Option<String> {
+        self.submodel.get_property_value(YEAR_OF_CONSTRUCTION.id_short, YEAR_OF_CONSTRUCTION.semantic_id)
+    }
+
+    ///Sets the year the product instance was completed.
+    ///
+    /// [year_of_construction]: year the product instance was completed
+    pub fn set_year_of_construction(&mut self, year_of_construction: String) {
+        YEAR_OF_CONSTRUCTION.set_property_value(&mut self.submodel, Some(&year_of_construction));
+    }
+
+    ///Returns the date the product instance was manufactured, as xs:date.
+    pub fn get_date_of_manufacture(&self) -> Option<String> {
+        self.submodel.get_property_value(DATE_OF_MANUFACTURE.id_short, DATE_OF_MANUFACTURE.semantic_id)
+    }
+
+    ///Sets the date the product instance was manufactured, as xs:date. Passing None removes it.
+    ///
+    /// [date_of_manufacture]: date the product instance was manufactured, as xs:date
+    pub fn set_date_of_manufacture(&mut self, date_of_manufacture: Option<String>) {
+        DATE_OF_MANUFACTURE.set_property_value(&mut self.submodel, date_of_manufacture.as_ref());
+    }
+
+    ///Returns the version of the hardware of the product instance.
+    pub fn get_hardware_version(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.submodel.get_multi_language_value(HARDWARE_VERSION.id_short, HARDWARE_VERSION.semantic_id)
+    }
+
+    ///Sets the version of the hardware of the product instance. Passing None removes it.
+    ///
+    /// [hardware_version]: version of the hardware of the product instance
+    pub fn set_hardware_version(&mut self, hardware_version: Option<Vec<MultiLanguageTextType>>) {
+        self.submodel.set_multi_language_value(HARDWARE_VERSION.id_short, HARDWARE_VERSION.semantic_id, hardware_version);
+    }
+
+    ///Returns the version of the firmware of the product instance.
+    pub fn get_firmware_version(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.submodel.get_multi_language_value(FIRMWARE_VERSION.id_short, FIRMWARE_VERSION.semantic_id)
+    }
+
+    ///Sets the version of the firmware of the product instance. Passing None removes it.
+    ///
+    /// [firmware_version]: version of the firmware of the product instance
+    pub fn set_firmware_version(&mut self, firmware_version: Option<Vec<MultiLanguageTextType>>) {
+        self.submodel.set_multi_language_value(FIRMWARE_VERSION.id_short, FIRMWARE_VERSION.semantic_id, firmware_version);
+    }
+
+    ///Returns the version of the software of the product instance.
+    pub fn get_software_version(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.submodel.get_multi_language_value(SOFTWARE_VERSION.id_short, SOFTWARE_VERSION.semantic_id)
+    }
+
+    ///Sets the version of the software of the product instance. Passing None removes it.
+    ///
+    /// [software_version]: version of the software of the product instance
+    pub fn set_software_version(&mut self, software_version: Option<Vec<MultiLanguageTextType>>) {
+        self.submodel.set_multi_language_value(SOFTWARE_VERSION.id_short, SOFTWARE_VERSION.semantic_id, software_version);
+    }
+
+    ///Returns the country the product was manufactured in, according to ISO 3166-1.
+    pub fn get_country_of_origin(&self) -> Option<String> {
+        self.submodel.get_property_value(COUNTRY_OF_ORIGIN.id_short, COUNTRY_OF_ORIGIN.semantic_id)
+    }
+
+    ///Sets the country the product was manufactured in, according to ISO 3166-1. Passing None removes it.
+    ///
+    /// [country_of_origin]: country the product was manufactured in, according to ISO 3166-1
+    pub fn set_country_of_origin(&mut self, country_of_origin: Option<String>) {
+        COUNTRY_OF_ORIGIN.set_property_value(&mut self.submodel, country_of_origin.as_ref());
+    }
+
+    ///Returns the identifier of the facility the product was manufactured in.
+    pub fn get_unique_facility_identifier(&self) -> Option<String> {
+        self.submodel.get_property_value(UNIQUE_FACILITY_IDENTIFIER.id_short, UNIQUE_FACILITY_IDENTIFIER.semantic_id)
+    }
+
+    ///Sets the identifier of the facility the product was manufactured in. Passing None removes it.
+    ///
+    /// [unique_facility_identifier]: identifier of the facility the product was manufactured in
+    pub fn set_unique_facility_identifier(&mut self, unique_facility_identifier: Option<String>) {
+        UNIQUE_FACILITY_IDENTIFIER.set_property_value(&mut self.submodel, unique_facility_identifier.as_ref());
+    }
+
+    ///Returns the image file of the company logo.
+    pub fn get_company_logo(&self) -> Option<&File> {
+        self.submodel.get_file(COMPANY_LOGO.id_short, COMPANY_LOGO.semantic_id)
+    }
+
+    ///Sets the image file of the company logo. Passing None removes it.
+    ///
+    /// [company_logo]: image file of the company logo
+    pub fn set_company_logo(&mut self, company_logo: Option<File>) {
+        self.submodel.set_element(COMPANY_LOGO.id_short, COMPANY_LOGO.semantic_id, company_logo.map(SubmodelElement::File));
+    }
+
+    ///Returns the markings of the product, e.g. the CE marking.
+    pub fn get_markings(&self) -> Vec<Marking> {
+        self.submodel.get_list(MARKINGS.id_short, MARKINGS.semantic_id)
+            .map(|list| list.get_value().iter()
+                .filter_map(|element| match element {
+                    SubmodelElement::SubmodelElementCollection(collection) => Some(Marking {
+                        collection: collection.clone()
+                    }),
+                    _ => None
+                })
+                .collect())
+            .unwrap_or_default()
+    }
+
+    ///Sets the markings of the product. An existing list keeps its attributes, an empty vector
+    /// removes the list.
+    ///
+    /// [markings]: markings of the product
+    pub fn set_markings(&mut self, markings: Vec<Marking>) {
+        if markings.is_empty() {
+            self.submodel.set_element(MARKINGS.id_short, MARKINGS.semantic_id, None);
+            return;
+        }
+        let markings = markings.into_iter()
+            .map(|marking| SubmodelElement::SubmodelElementCollection(marking.into_collection()))
+            .collect();
+        if let Some(SubmodelElement::SubmodelElementList(list)) = self.submodel.find_mut_element(MARKINGS.id_short, MARKINGS.semantic_id) {
+            list.set_value(markings);
+            return;
+        }
+        let mut list = SubmodelElementList::new(AasSubmodelElements::SubmodelElementCollection);
+        list.set_semantic_id_list_element(global_reference(Marking::SEMANTIC_ID));
+        list.set_value(markings);
+        self.submodel.set_element(MARKINGS.id_short, MARKINGS.semantic_id, Some(SubmodelElement::SubmodelElementList(list)));
+    }
+
+    ///Returns the properties of the asset that guidelines for the declaration of conformity
+    /// require.
+    pub fn get_guideline_specific_properties(&self) -> Vec<GuidelineSpecificProperties> {
+        self.submodel.get_collection(ASSET_SPECIFIC_PROPERTIES.id_short, ASSET_SPECIFIC_PROPERTIES.semantic_id)
+            .map(|collection| collection.get_collections(GUIDELINE_SPECIFIC_PROPERTIES.id_short, GUIDELINE_SPECIFIC_PROPERTIES.semantic_id)
+                .into_iter()
+                .map(|collection| GuidelineSpecificProperties {
+                    collection: collection.clone()
+                })
+                .collect())
+            .unwrap_or_default()
+    }
+
+    ///Sets the properties of the asset that guidelines for the declaration of conformity require.
+    /// An empty vector removes the asset specific properties.
+    ///
+    /// [guideline_specific_properties]: properties required by guidelines
+    pub fn set_guideline_specific_properties(&mut self, guideline_specific_properties: Vec<GuidelineSpecificProperties>) {
+        if guideline_specific_properties.is_empty() {
+            self.submodel.set_element(ASSET_SPECIFIC_PROPERTIES.id_short, ASSET_SPECIFIC_PROPERTIES.semantic_id, None);
+            return;
+        }
+        let elements = guideline_specific_properties.into_iter()
+            .map(|properties| SubmodelElement::SubmodelElementCollection(properties.into_collection()))
+            .collect();
+        if self.submodel.get_collection(ASSET_SPECIFIC_PROPERTIES.id_short, ASSET_SPECIFIC_PROPERTIES.semantic_id).is_none() {
+            self.submodel.set_element(ASSET_SPECIFIC_PROPERTIES.id_short, ASSET_SPECIFIC_PROPERTIES.semantic_id,
+                                      Some(SubmodelElement::SubmodelElementCollection(SubmodelElementCollection::new())));
+        }
+        if let Some(SubmodelElement::SubmodelElementCollection(collection)) =
+            self.submodel.find_mut_element(ASSET_SPECIFIC_PROPERTIES.id_short, ASSET_SPECIFIC_PROPERTIES.semantic_id) {
+            collection.set_elements(GUIDELINE_SPECIFIC_PROPERTIES.id_short, GUIDELINE_SPECIFIC_PROPERTIES.semantic_id, elements);
+        }
+    }
+
+    fn check(submodel: &Submodel) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(submodel, "")?;
+        }
+        for element in ADDRESS_INFORMATION.check(submodel, "")? {
+            if let SubmodelElement::SubmodelElementCollection(collection) = element {
+                ContactInformation::check(collection, ADDRESS_INFORMATION.id_short)?;
+            }
+        }
+        for element in MARKINGS.check(submodel, "")? {
+            if let SubmodelElement::SubmodelElementList(list) = element {
+                for (index, marking) in list.get_value().iter().enumerate() {
+                    let path = format!("{}[{}]", MARKINGS.id_short, index);
+                    match marking {
+                        SubmodelElement::SubmodelElementCollection(collection) => Marking::check(collection, &path)?,
+                        _ => return Err(ConversionError::UnexpectedElementType(path))
+                    }
+                }
+            }
+        }
+        for element in ASSET_SPECIFIC_PROPERTIES.check(submodel, "")? {
+            if let SubmodelElement::SubmodelElementCollection(collection) = element {
+                for properties in GUIDELINE_SPECIFIC_PROPERTIES.check(collection, ASSET_SPECIFIC_PROPERTIES.id_short)? {
+                    if let SubmodelElement::SubmodelElementCollection(properties) = properties {
+                        let path = join_path(ASSET_SPECIFIC_PROPERTIES.id_short,
+                                             properties.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default());
+                        GuidelineSpecificProperties::check(properties, &path)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+const URI_OF_THE_PRODUCT: TemplateElement =
+    TemplateElement::property("URIOfTheProduct", "0173-1#02-AAY811#001", DataTypeDefXsd::String, Cardinality::One);
+const MANUFACTURER_NAME: TemplateElement =
+    TemplateElement::new("ManufacturerName", "0173-1#02-AAO677#002", AasSubmodelElements::MultiLanguageProperty, Cardinality::One);
+const MANUFACTURER_PRODUCT_DESIGNATION: TemplateElement =
+    TemplateElement::new("ManufacturerProductDesignation", "0173-1#02-AAW338#001", AasSubmodelElements::MultiLanguageProperty, Cardinality::One);
+const ADDRESS_INFORMATION: TemplateElement =
+    TemplateElement::new("AddressInformation", ContactInformation::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::One);
+const MANUFACTURER_PRODUCT_ROOT: TemplateElement =
+    TemplateElement::new("ManufacturerProductRoot", "0173-1#02-AAU732#001", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const MANUFACTURER_PRODUCT_FAMILY: TemplateElement =
+    TemplateElement::new("ManufacturerProductFamily", "0173-1#02-AAU731#001", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const MANUFACTURER_PRODUCT_TYPE: TemplateElement =
+    TemplateElement::property("ManufacturerProductType", "0173-1#02-AAO057#002", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const ORDER_CODE_OF_MANUFACTURER: TemplateElement =
+    TemplateElement::property("OrderCodeOfManufacturer", "0173-1#02-AAO227#002", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const PRODUCT_ARTICLE_NUMBER_OF_MANUFACTURER: TemplateElement =
+    TemplateElement::property("ProductArticleNumberOfManufacturer", "0173-1#02-AAO676#003", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const SERIAL_NUMBER: TemplateElement =
+    TemplateElement::property("SerialNumber", "0173-1#02-AAM556#002", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const YEAR_OF_CONSTRUCTION: TemplateElement =
+    TemplateElement::property("YearOfConstruction", "0173-1#02-AAP906#001", DataTypeDefXsd::String, Cardinality::One);
+const DATE_OF_MANUFACTURE: TemplateElement =
+    TemplateElement::property("DateOfManufacture", "0173-1#02-AAR972#002", DataTypeDefXsd::Date, Cardinality::ZeroToOne);
+const HARDWARE_VERSION: TemplateElement =
+    TemplateElement::new("HardwareVersion", "0173-1#02-AAN270#002", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const FIRMWARE_VERSION: TemplateElement =
+    TemplateElement::new("FirmwareVersion", "0173-1#02-AAM985#002", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const SOFTWARE_VERSION: TemplateElement =
+    TemplateElement::new("SoftwareVersion", "0173-1#02-AAM737#002", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const COUNTRY_OF_ORIGIN: TemplateElement =
+    TemplateElement::property("CountryOfOrigin", "0173-1#02-AAO259#004", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const UNIQUE_FACILITY_IDENTIFIER: TemplateElement =
+    TemplateElement::property("UniqueFacilityIdentifier", "https://admin-shell.io/idta/nameplate/3/0/UniqueFacilityIdentifier", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const COMPANY_LOGO: TemplateElement =
+    TemplateElement::new("CompanyLogo", "https://admin-shell.io/idta/nameplate/3/0/CompanyLogo", AasSubmodelElements::File, Cardinality::ZeroToOne);
+const MARKINGS: TemplateElement =
+    TemplateElement::new("Markings", "https://admin-shell.io/idta/nameplate/3/0/Markings", AasSubmodelElements::SubmodelElementList, Cardinality::ZeroToOne);
+const ASSET_SPECIFIC_PROPERTIES: TemplateElement =
+    TemplateElement::new("AssetSpecificProperties", "https://admin-shell.io/idta/nameplate/3/0/AssetSpecificProperties", AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToOne);
+const MARKING: TemplateElement =
+    TemplateElement::new("Marking", Marking::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToMany);
+const GUIDELINE_SPECIFIC_PROPERTIES: TemplateElement =
+    TemplateElement::new("GuidelineSpecificProperties{00}", GuidelineSpecificProperties::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::OneToMany);
+
+const ELEMENTS: [&TemplateElement; 20] = [&URI_OF_THE_PRODUCT, &MANUFACTURER_NAME, &MANUFACTURER_PRODUCT_DESIGNATION,
+    &ADDRESS_INFORMATION, &MANUFACTURER_PRODUCT_ROOT, &MANUFACTURER_PRODUCT_FAMILY, &MANUFACTURER_PRODUCT_TYPE,
+    &ORDER_CODE_OF_MANUFACTURER, &PRODUCT_ARTICLE_NUMBER_OF_MANUFACTURER, &SERIAL_NUMBER, &YEAR_OF_CONSTRUCTION,
+    &DATE_OF_MANUFACTURE, &HARDWARE_VERSION, &FIRMWARE_VERSION, &SOFTWARE_VERSION, &COUNTRY_OF_ORIGIN,
+    &UNIQUE_FACILITY_IDENTIFIER, &COMPANY_LOGO, &MARKINGS, &ASSET_SPECIFIC_PROPERTIES];
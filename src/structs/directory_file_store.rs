@@ -0,0 +1,51 @@
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use crate::enumerations::file_resolution_error::FileResolutionError;
+use crate::traits::file_store::{relative_segments, TFileStore};
+
+///Store of supplementary files in a directory of the file system. The paths of file elements are
+/// resolved relative to the directory, so `/aasx/files/manual.pdf` refers to
+/// `<root>/aasx/files/manual.pdf`. Paths leaving the directory are rejected.
+#[derive(PartialEq, Clone)]
+pub struct DirectoryFileStore {
+    ///Directory containing the files.
+    root: PathBuf
+}
+
+impl DirectoryFileStore {
+    ///Creates a new instance of the struct.
+    ///
+    /// [root]: directory containing the files
+    pub fn new(root: PathBuf) -> DirectoryFileStore {
+        DirectoryFileStore {
+            root
+        }
+    }
+
+    ///Returns the directory containing the files.
+    pub fn get_root(&self) -> &Path {
+        &self.root
+    }
+
+    ///Returns the location of the file in the file system.
+    ///
+    /// [path]: path of the file as given in a file element
+    pub fn get_file_path(&self, path: &str) -> Result<PathBuf, FileResolutionError> {
+        let segments = relative_segments(path).ok_or_else(|| FileResolutionError::InvalidPath(path.to_string()))?;
+        Ok(segments.into_iter().fold(self.root.clone(), |file_path, segment| file_path.join(segment)))
+    }
+}
+
+impl TFileStore for DirectoryFileStore {
+    fn read_file(&self, path: &str) -> Result<Vec<u8>, FileResolutionError> {
+        std::fs::read(self.get_file_path(path)?).map_err(|error| match error.kind() {
+            ErrorKind::NotFound => FileResolutionError::FileNotFound(path.to_string()),
+            _ => FileResolutionError::ReadFailed(path.to_string(), error.to_string())
+        })
+    }
+
+    fn contains_file(&self, path: &str) -> bool {
+        self.get_file_path(path).is_ok_and(|file_path| file_path.is_file())
+    }
+}
@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+
+use crate::enumerations::repository_error::RepositoryError;
+use crate::structs::asset_administration_shell::AssetAdministrationShell;
+use crate::structs::environment::Environment;
+use crate::structs::reference::Reference;
+use crate::structs::specific_asset_id::SpecificAssetId;
+use crate::traits::identifiable::TIdentifiable;
+use crate::traits::repository_storage::TRepositoryStorage;
+
+///Index of the asset links of shells, as the Discovery interface of the HTTP API, which finds the
+/// shells of an asset by its asset IDs, e.g. the serial number and the part number known to an
+/// ERP system.
+///
+/// An asset link is a specific asset ID of the asset information of a shell; the global asset ID
+/// is linked with the name `globalAssetId`. A link with an external subject ID is only visible to
+/// that subject, links without one are visible to everybody.
+#[derive(PartialEq, Clone)]
+pub struct DiscoveryIndex {
+    ///Asset links of the shells in the order the shells were indexed.
+    links: Vec<(String, Vec<SpecificAssetId>)>,
+    ///Identifiers of the shells having a link with the name and value.
+    shell_ids: HashMap<(String, String), Vec<String>>
+}
+
+impl DiscoveryIndex {
+    ///Name of the asset links of the global asset IDs.
+    pub const GLOBAL_ASSET_ID: &str = "globalAssetId";
+
+    ///Creates a new index without asset links.
+    pub fn new() -> DiscoveryIndex {
+        DiscoveryIndex {
+            links: Vec::new(),
+            shell_ids: HashMap::new()
+        }
+    }
+
+    ///Creates the index of the asset links of the shells of an environment.
+    ///
+    /// [environment]: environment containing the shells
+    pub fn from_environment(environment: &Environment) -> DiscoveryIndex {
+        let mut index = DiscoveryIndex::new();
+        environment.get_asset_administration_shells().iter().for_each(|shell| index.add_shell(shell));
+        index
+    }
+
+    ///Creates the index of the asset links of the shells of a repository.
+    ///
+    /// [storage]: storage of the repository
+    pub fn from_storage<S: TRepositoryStorage>(storage: &S) -> Result<DiscoveryIndex, RepositoryError> {
+        let mut index = DiscoveryIndex::new();
+        storage.get_shells()?.iter().for_each(|shell| index.add_shell(shell));
+        Ok(index)
+    }
+
+    ///Indexes the global asset ID and the specific asset IDs of a shell as its asset links, which
+    /// replace the links indexed for the shell before.
+    ///
+    /// [shell]: shell to index
+    pub fn add_shell(&mut self, shell: &AssetAdministrationShell) {
+        let asset_information = shell.get_asset_information();
+        let mut links = Vec::new();
+        if let Some(global_asset_id) = asset_information.get_global_asset_id() {
+            links.push(SpecificAssetId::new(DiscoveryIndex::GLOBAL_ASSET_ID.to_string(), global_asset_id.clone()));
+        }
+        links.extend(asset_information.get_specific_asset_ids().iter().cloned());
+        self.remove_shell(shell.get_id());
+        self.add_asset_links(shell.get_id(), links);
+    }
+
+    ///Adds asset links of a shell. Links equal to a link of the shell are skipped.
+    ///
+    /// [shell_id]: identifier of the shell
+    /// [asset_links]: asset links to add
+    pub fn add_asset_links(&mut self, shell_id: &str, asset_links: Vec<SpecificAssetId>) {
+        let position = match self.links.iter().position(|(id, _)| id == shell_id) {
+            Some(position) => position,
+            None => {
+                self.links.push((shell_id.to_string(), Vec::new()));
+                self.links.len() - 1
+            }
+        };
+        for asset_link in asset_links {
+            let links = &mut self.links[position].1;
+            if links.contains(&asset_link) {
+                continue;
+            }
+            let shell_ids = self.shell_ids.entry((asset_link.get_name().clone(), asset_link.get_value().clone())).or_default();
+            if !shell_ids.iter().any(|id| id == shell_id) {
+                shell_ids.push(shell_id.to_string());
+            }
+            links.push(asset_link);
+        }
+    }
+
+    ///Removes all asset links of a shell. Returns whether the shell had links.
+    ///
+    /// [shell_id]: identifier of the shell
+    pub fn remove_shell(&mut self, shell_id: &str) -> bool {
+        let Some(position) = self.links.iter().position(|(id, _)| id == shell_id) else {
+            return false;
+        };
+        let (_, links) = self.links.remove(position);
+        for link in links {
+            let key = (link.get_name().clone(), link.get_value().clone());
+            if let Some(shell_ids) = self.shell_ids.get_mut(&key) {
+                shell_ids.retain(|id| id != shell_id);
+                if shell_ids.is_empty() {
+                    self.shell_ids.remove(&key);
+                }
+            }
+        }
+        true
+    }
+
+    ///Returns the asset links of a shell that are visible to the subject.
+    ///
+    /// [shell_id]: identifier of the shell
+    /// [subject]: external subject ID of the requester, None to see the public links only
+    pub fn get_asset_links(&self, shell_id: &str, subject: Option<&Reference>) -> Vec<&SpecificAssetId> {
+        self.links.iter().filter(|(id, _)| id == shell_id)
+            .flat_map(|(_, links)| links.iter())
+            .filter(|link| is_visible(link, subject))
+            .collect()
+    }
+
+    ///Returns the identifiers of the shells having all of the asset links visible to the subject.
+    /// Returns no shells if no asset links are given.
+    ///
+    /// [asset_links]: names and values of the asset links, e.g. `("globalAssetId", "urn:asset")`
+    /// [subject]: external subject ID of the requester, None to match the public links only
+    pub fn lookup_shells(&self, asset_links: &[(&str, &str)], subject: Option<&Reference>) -> Vec<&String> {
+        let Some((name, value)) = asset_links.first() else {
+            return Vec::new();
+        };
+        let Some(candidates) = self.shell_ids.get(&(name.to_string(), value.to_string())) else {
+            return Vec::new();
+        };
+        self.links.iter()
+            .filter(|(id, _)| candidates.contains(id))
+            .filter(|(_, links)| asset_links.iter().all(|(name, value)| links.iter()
+                .any(|link| link.get_name() == name && link.get_value() == value && is_visible(link, subject))))
+            .map(|(id, _)| id)
+            .collect()
+    }
+}
+
+impl Default for DiscoveryIndex {
+    fn default() -> Self {
+        DiscoveryIndex::new()
+    }
+}
+
+///Returns whether an asset link is visible to the subject, which is the case for links without an
+/// external subject ID and for links of the subject.
+fn is_visible(link: &SpecificAssetId, subject: Option<&Reference>) -> bool {
+    match link.get_external_subject_id() {
+        Some(external_subject_id) => subject.is_some_and(|subject| subject == external_subject_id),
+        None => true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::enumerations::asset_kind::AssetKind;
+    use crate::structs::asset_administration_shell::AssetAdministrationShell;
+    use crate::structs::asset_information::AssetInformation;
+    use crate::structs::discovery_index::DiscoveryIndex;
+    use crate::structs::specific_asset_id::SpecificAssetId;
+    use crate::structs::template_element::global_reference;
+
+    fn asset_link(name: &str, value: &str, subject: Option<&str>) -> SpecificAssetId {
+        let mut link = SpecificAssetId::new(name.to_string(), value.to_string());
+        if let Some(subject) = subject {
+            link.set_external_subject_id(global_reference(subject));
+        }
+        link
+    }
+
+    fn shell(id: &str, global_asset_id: &str) -> AssetAdministrationShell {
+        let mut asset_information = AssetInformation::new(AssetKind::Instance);
+        asset_information.set_global_asset_id(global_asset_id.to_string());
+        AssetAdministrationShell::new(id.to_string(), asset_information)
+    }
+
+    #[test]
+    fn shows_links_with_external_subject_id_to_that_subject_only() {
+        let mut index = DiscoveryIndex::new();
+        index.add_asset_links("urn:aas:1", vec![asset_link("serialNumber", "1234", Some("urn:erp"))]);
+        let erp = global_reference("urn:erp");
+        let mes = global_reference("urn:mes");
+
+        assert!(index.lookup_shells(&[("serialNumber", "1234")], None).is_empty());
+        assert!(index.lookup_shells(&[("serialNumber", "1234")], Some(&mes)).is_empty());
+        assert_eq!(index.lookup_shells(&[("serialNumber", "1234")], Some(&erp)), vec!["urn:aas:1"]);
+        assert!(index.get_asset_links("urn:aas:1", None).is_empty());
+        assert_eq!(index.get_asset_links("urn:aas:1", Some(&erp)).len(), 1);
+    }
+
+    #[test]
+    fn looks_up_shells_having_all_visible_links() {
+        let mut index = DiscoveryIndex::new();
+        index.add_asset_links("urn:aas:1", vec![
+            asset_link("serialNumber", "1234", None),
+            asset_link("partNumber", "P-1", Some("urn:erp"))
+        ]);
+        index.add_asset_links("urn:aas:2", vec![
+            asset_link("serialNumber", "1234", None),
+            asset_link("partNumber", "P-2", None)
+        ]);
+        let erp = global_reference("urn:erp");
+        let links = [("serialNumber", "1234"), ("partNumber", "P-1")];
+
+        assert_eq!(index.lookup_shells(&[("serialNumber", "1234")], None), vec!["urn:aas:1", "urn:aas:2"]);
+        assert!(index.lookup_shells(&links, None).is_empty());
+        assert_eq!(index.lookup_shells(&links, Some(&erp)), vec!["urn:aas:1"]);
+        assert!(index.lookup_shells(&[("serialNumber", "1234"), ("partNumber", "P-3")], Some(&erp)).is_empty());
+        assert!(index.lookup_shells(&[], None).is_empty());
+    }
+
+    #[test]
+    fn looks_up_shells_by_global_asset_id() {
+        let mut index = DiscoveryIndex::new();
+        index.add_shell(&shell("urn:aas:1", "urn:asset:1"));
+        index.add_shell(&shell("urn:aas:2", "urn:asset:2"));
+
+        assert_eq!(index.lookup_shells(&[(DiscoveryIndex::GLOBAL_ASSET_ID, "urn:asset:2")], None), vec!["urn:aas:2"]);
+
+        index.add_shell(&shell("urn:aas:2", "urn:asset:3"));
+        assert!(index.lookup_shells(&[(DiscoveryIndex::GLOBAL_ASSET_ID, "urn:asset:2")], None).is_empty());
+        assert_eq!(index.lookup_shells(&[(DiscoveryIndex::GLOBAL_ASSET_ID, "urn:asset:3")], None), vec!["urn:aas:2"]);
+    }
+
+    #[test]
+    fn removes_shells_with_links_of_the_same_name_and_value_for_different_subjects() {
+        let mut index = DiscoveryIndex::new();
+        index.add_asset_links("urn:aas:1", vec![
+            asset_link("serialNumber", "1234", Some("urn:erp")),
+            asset_link("serialNumber", "1234", Some("urn:mes"))
+        ]);
+        index.add_asset_links("urn:aas:2", vec![asset_link("serialNumber", "1234", Some("urn:mes"))]);
+        let mes = global_reference("urn:mes");
+
+        assert_eq!(index.get_asset_links("urn:aas:1", Some(&mes)).len(), 1);
+        assert_eq!(index.lookup_shells(&[("serialNumber", "1234")], Some(&mes)), vec!["urn:aas:1", "urn:aas:2"]);
+
+        assert!(index.remove_shell("urn:aas:1"));
+        assert!(!index.remove_shell("urn:aas:1"));
+        assert!(index.get_asset_links("urn:aas:1", Some(&mes)).is_empty());
+        assert_eq!(index.lookup_shells(&[("serialNumber", "1234")], Some(&mes)), vec!["urn:aas:2"]);
+
+        assert!(index.remove_shell("urn:aas:2"));
+        assert!(index.lookup_shells(&[("serialNumber", "1234")], Some(&mes)).is_empty());
+        assert!(index == DiscoveryIndex::new());
+    }
+}
\ No newline at end of file
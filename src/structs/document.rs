@@ -0,0 +1,174 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::vdi_2770_class::Vdi2770Class;
+use crate::structs::document_classification::DocumentClassification;
+use crate::structs::document_id::DocumentId;
+use crate::structs::document_version::DocumentVersion;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, join_path, TemplateElement};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on a document of the Handover Documentation, which has IDs, classifications and
+/// versions.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct Document {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl Document {
+    ///Semantic ID of a document.
+    pub const SEMANTIC_ID: &str = "0173-1#02-ABI500#001/0173-1#01-AHF579#001";
+
+    ///Creates a new document.
+    ///
+    /// [document_id]: ID of the document
+    /// [document_classification]: classification of the document
+    /// [document_version]: version of the document
+    pub fn new(document_id: DocumentId, document_classification: DocumentClassification, document_version: DocumentVersion)
+        -> Document {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_semantic_id(global_reference(Document::SEMANTIC_ID));
+        let mut document = Document {
+            collection
+        };
+        document.set_document_ids(vec![document_id]);
+        document.set_document_classifications(vec![document_classification]);
+        document.set_document_versions(vec![document_version]);
+        document
+    }
+
+    ///Wraps a submodel element collection after checking the elements of a document.
+    ///
+    /// [collection]: collection containing a document
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<Document, ConversionError> {
+        Document::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(Document {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the document and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the IDs of the document.
+    pub fn get_document_ids(&self) -> Vec<DocumentId> {
+        self.collection.get_collections(DOCUMENT_ID.id_short, DOCUMENT_ID.semantic_id).into_iter()
+            .map(|collection| DocumentId {
+                collection: collection.clone()
+            })
+            .collect()
+    }
+
+    ///Sets the IDs of the document.
+    ///
+    /// [document_ids]: IDs of the document
+    pub fn set_document_ids(&mut self, document_ids: Vec<DocumentId>) {
+        let elements = document_ids.into_iter()
+            .map(|item| SubmodelElement::SubmodelElementCollection(item.into_collection()))
+            .collect();
+        self.collection.set_elements(DOCUMENT_ID.id_short, DOCUMENT_ID.semantic_id, elements);
+    }
+
+    ///Returns the classifications of the document.
+    pub fn get_document_classifications(&self) -> Vec<DocumentClassification> {
+        self.collection.get_collections(DOCUMENT_CLASSIFICATION.id_short, DOCUMENT_CLASSIFICATION.semantic_id).into_iter()
+            .map(|collection| DocumentClassification {
+                collection: collection.clone()
+            })
+            .collect()
+    }
+
+    ///Sets the classifications of the document.
+    ///
+    /// [document_classifications]: classifications of the document
+    pub fn set_document_classifications(&mut self, document_classifications: Vec<DocumentClassification>) {
+        let elements = document_classifications.into_iter()
+            .map(|item| SubmodelElement::SubmodelElementCollection(item.into_collection()))
+            .collect();
+        self.collection.set_elements(DOCUMENT_CLASSIFICATION.id_short, DOCUMENT_CLASSIFICATION.semantic_id, elements);
+    }
+
+    ///Returns the versions of the document.
+    pub fn get_document_versions(&self) -> Vec<DocumentVersion> {
+        self.collection.get_collections(DOCUMENT_VERSION.id_short, DOCUMENT_VERSION.semantic_id).into_iter()
+            .map(|collection| DocumentVersion {
+                collection: collection.clone()
+            })
+            .collect()
+    }
+
+    ///Sets the versions of the document.
+    ///
+    /// [document_versions]: versions of the document
+    pub fn set_document_versions(&mut self, document_versions: Vec<DocumentVersion>) {
+        let elements = document_versions.into_iter()
+            .map(|item| SubmodelElement::SubmodelElementCollection(item.into_collection()))
+            .collect();
+        self.collection.set_elements(DOCUMENT_VERSION.id_short, DOCUMENT_VERSION.semantic_id, elements);
+    }
+
+    ///Returns the primary ID of the document, i.e. the ID flagged as primary or the first ID.
+    pub fn get_primary_document_id(&self) -> Option<DocumentId> {
+        let document_ids = self.get_document_ids();
+        document_ids.iter().find(|document_id| document_id.get_is_primary() == Some(true)).cloned()
+            .or_else(|| document_ids.into_iter().next())
+    }
+
+    ///Returns the VDI 2770 classes of the document.
+    pub fn get_vdi_2770_classes(&self) -> Vec<Vdi2770Class> {
+        self.get_document_classifications().iter().filter_map(|classification| classification.get_vdi_2770_class()).collect()
+    }
+
+    ///Returns the elements of a document in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        vec![
+            DOCUMENT_ID.to_collection(DocumentId::template_elements()),
+            DOCUMENT_CLASSIFICATION.to_collection(DocumentClassification::template_elements()),
+            DOCUMENT_VERSION.to_collection(DocumentVersion::template_elements())
+        ]
+    }
+
+    ///Checks the elements of a document.
+    ///
+    /// [collection]: collection containing a document
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        for (element, check) in [(&DOCUMENT_ID, DocumentId::check as Check), (&DOCUMENT_CLASSIFICATION, DocumentClassification::check),
+                                 (&DOCUMENT_VERSION, DocumentVersion::check)] {
+            for child in element.check(collection, path)? {
+                if let SubmodelElement::SubmodelElementCollection(child) = child {
+                    check(child, &join_path(path, child.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default()))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+const DOCUMENT_ID: TemplateElement =
+    TemplateElement::new("DocumentId{00}", DocumentId::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::OneToMany);
+const DOCUMENT_CLASSIFICATION: TemplateElement =
+    TemplateElement::new("DocumentClassification{00}", DocumentClassification::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::OneToMany);
+const DOCUMENT_VERSION: TemplateElement =
+    TemplateElement::new("DocumentVersion{00}", DocumentVersion::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::OneToMany);
+
+type Check = fn(&SubmodelElementCollection, &str) -> Result<(), ConversionError>;
+
+const ELEMENTS: [&TemplateElement; 3] = [&DOCUMENT_ID, &DOCUMENT_CLASSIFICATION, &DOCUMENT_VERSION];
@@ -0,0 +1,144 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::vdi_2770_class::Vdi2770Class;
+use crate::structs::multi_language_text_type::MultiLanguageTextType;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, TemplateElement};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on a classification of a document of the Handover Documentation, usually by a class of
+/// VDI 2770.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct DocumentClassification {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl DocumentClassification {
+    ///Semantic ID of a document classification.
+    pub const SEMANTIC_ID: &str = "0173-1#02-ABI502#001/0173-1#01-AHF581#001";
+
+    ///Creates a new document classification.
+    ///
+    /// [class_id]: ID of the class in the classification system, e.g. 03-02
+    /// [class_name]: name of the class in the classification system
+    /// [classification_system]: classification system, e.g. VDI2770:2018
+    pub fn new(class_id: String, class_name: Vec<MultiLanguageTextType>, classification_system: String) -> DocumentClassification {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_semantic_id(global_reference(DocumentClassification::SEMANTIC_ID));
+        let mut document_classification = DocumentClassification {
+            collection
+        };
+        document_classification.set_class_id(class_id);
+        document_classification.set_class_name(class_name);
+        document_classification.set_classification_system(classification_system);
+        document_classification
+    }
+
+    ///Creates a new classification of a document with a VDI 2770 class. The class name is given in
+    /// English.
+    ///
+    /// [class]: VDI 2770 class of the document
+    pub fn from_vdi_2770_class(class: Vdi2770Class) -> DocumentClassification {
+        DocumentClassification::new(class.get_class_id().to_string(),
+                                    vec![MultiLanguageTextType::new("en".to_string(), class.get_class_name().to_string())],
+                                    Vdi2770Class::CLASSIFICATION_SYSTEM.to_string())
+    }
+
+    ///Wraps a submodel element collection after checking the elements of a document classification.
+    ///
+    /// [collection]: collection containing a document classification
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<DocumentClassification, ConversionError> {
+        DocumentClassification::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(DocumentClassification {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the document classification and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the ID of the class in the classification system, e.g. 03-02.
+    pub fn get_class_id(&self) -> Option<String> {
+        self.collection.get_property_value(CLASS_ID.id_short, CLASS_ID.semantic_id)
+    }
+
+    ///Sets the ID of the class in the classification system, e.g. 03-02.
+    ///
+    /// [class_id]: ID of the class in the classification system, e.g. 03-02
+    pub fn set_class_id(&mut self, class_id: String) {
+        CLASS_ID.set_property_value(&mut self.collection, Some(&class_id));
+    }
+
+    ///Returns the name of the class in the classification system.
+    pub fn get_class_name(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(CLASS_NAME.id_short, CLASS_NAME.semantic_id)
+    }
+
+    ///Sets the name of the class in the classification system.
+    ///
+    /// [class_name]: name of the class in the classification system
+    pub fn set_class_name(&mut self, class_name: Vec<MultiLanguageTextType>) {
+        self.collection.set_multi_language_value(CLASS_NAME.id_short, CLASS_NAME.semantic_id, Some(class_name));
+    }
+
+    ///Returns the classification system, e.g. VDI2770:2018.
+    pub fn get_classification_system(&self) -> Option<String> {
+        self.collection.get_property_value(CLASSIFICATION_SYSTEM.id_short, CLASSIFICATION_SYSTEM.semantic_id)
+    }
+
+    ///Sets the classification system, e.g. VDI2770:2018.
+    ///
+    /// [classification_system]: classification system, e.g. VDI2770:2018
+    pub fn set_classification_system(&mut self, classification_system: String) {
+        CLASSIFICATION_SYSTEM.set_property_value(&mut self.collection, Some(&classification_system));
+    }
+
+    ///Returns the VDI 2770 class of the document. Returns None if the classification uses another
+    /// classification system or an unknown class ID.
+    pub fn get_vdi_2770_class(&self) -> Option<Vdi2770Class> {
+        if self.get_classification_system()? != Vdi2770Class::CLASSIFICATION_SYSTEM {
+            return None;
+        }
+        Vdi2770Class::parse(&self.get_class_id()?)
+    }
+
+    ///Returns the elements of a document classification in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        ELEMENTS.iter().map(|element| element.to_element()).collect()
+    }
+
+    ///Checks the elements of a document classification.
+    ///
+    /// [collection]: collection containing a document classification
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        Ok(())
+    }
+}
+
+const CLASS_ID: TemplateElement =
+    TemplateElement::property("ClassId", "0173-1#02-ABH996#001", DataTypeDefXsd::String, Cardinality::One);
+const CLASS_NAME: TemplateElement =
+    TemplateElement::new("ClassName", "0173-1#02-AAO102#003", AasSubmodelElements::MultiLanguageProperty, Cardinality::One);
+const CLASSIFICATION_SYSTEM: TemplateElement =
+    TemplateElement::property("ClassificationSystem", "0173-1#02-ABH997#001", DataTypeDefXsd::String, Cardinality::One);
+
+const ELEMENTS: [&TemplateElement; 3] = [&CLASS_ID, &CLASS_NAME, &CLASSIFICATION_SYSTEM];
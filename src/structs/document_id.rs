@@ -0,0 +1,119 @@
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, TemplateElement};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on an ID of a document of the Handover Documentation.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct DocumentId {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl DocumentId {
+    ///Semantic ID of a document ID.
+    pub const SEMANTIC_ID: &str = "0173-1#02-ABI501#001/0173-1#01-AHF580#001";
+
+    ///Creates a new document ID.
+    ///
+    /// [document_domain_id]: ID of the domain the document ID is unique in, e.g. the manufacturer
+    /// [value_id]: ID of the document within the domain
+    pub fn new(document_domain_id: String, value_id: String) -> DocumentId {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_semantic_id(global_reference(DocumentId::SEMANTIC_ID));
+        let mut document_id = DocumentId {
+            collection
+        };
+        document_id.set_document_domain_id(document_domain_id);
+        document_id.set_value_id(value_id);
+        document_id
+    }
+
+    ///Wraps a submodel element collection after checking the elements of a document ID.
+    ///
+    /// [collection]: collection containing a document ID
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<DocumentId, ConversionError> {
+        DocumentId::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(DocumentId {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the document ID and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the ID of the domain the document ID is unique in, e.g. the manufacturer.
+    pub fn get_document_domain_id(&self) -> Option<String> {
+        self.collection.get_property_value(DOCUMENT_DOMAIN_ID.id_short, DOCUMENT_DOMAIN_ID.semantic_id)
+    }
+
+    ///Sets the ID of the domain the document ID is unique in, e.g. the manufacturer.
+    ///
+    /// [document_domain_id]: ID of the domain the document ID is unique in, e.g. the manufacturer
+    pub fn set_document_domain_id(&mut self, document_domain_id: String) {
+        DOCUMENT_DOMAIN_ID.set_property_value(&mut self.collection, Some(&document_domain_id));
+    }
+
+    ///Returns the ID of the document within the domain.
+    pub fn get_value_id(&self) -> Option<String> {
+        self.collection.get_property_value(VALUE_ID.id_short, VALUE_ID.semantic_id)
+    }
+
+    ///Sets the ID of the document within the domain.
+    ///
+    /// [value_id]: ID of the document within the domain
+    pub fn set_value_id(&mut self, value_id: String) {
+        VALUE_ID.set_property_value(&mut self.collection, Some(&value_id));
+    }
+
+    ///Returns the flag whether the ID is the primary ID of the document.
+    pub fn get_is_primary(&self) -> Option<bool> {
+        self.collection.get_property_value(IS_PRIMARY.id_short, IS_PRIMARY.semantic_id)
+    }
+
+    ///Sets the flag whether the ID is the primary ID of the document. Passing None removes it.
+    ///
+    /// [is_primary]: flag whether the ID is the primary ID of the document
+    pub fn set_is_primary(&mut self, is_primary: Option<bool>) {
+        IS_PRIMARY.set_property_value(&mut self.collection, is_primary.as_ref());
+    }
+
+    ///Returns the elements of a document ID in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        ELEMENTS.iter().map(|element| element.to_element()).collect()
+    }
+
+    ///Checks the elements of a document ID.
+    ///
+    /// [collection]: collection containing a document ID
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        Ok(())
+    }
+}
+
+const DOCUMENT_DOMAIN_ID: TemplateElement =
+    TemplateElement::property("DocumentDomainId", "0173-1#02-ABH994#001", DataTypeDefXsd::String, Cardinality::One);
+const VALUE_ID: TemplateElement =
+    TemplateElement::property("ValueId", "0173-1#02-AAO099#004", DataTypeDefXsd::String, Cardinality::One);
+const IS_PRIMARY: TemplateElement =
+    TemplateElement::property("IsPrimary", "0173-1#02-ABH995#001", DataTypeDefXsd::Boolean, Cardinality::ZeroToOne);
+
+const ELEMENTS: [&TemplateElement; 3] = [&DOCUMENT_DOMAIN_ID, &VALUE_ID, &IS_PRIMARY];
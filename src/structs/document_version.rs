@@ -0,0 +1,297 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::file_resolution_error::FileResolutionError;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::file::File;
+use crate::structs::multi_language_text_type::MultiLanguageTextType;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, TemplateElement};
+use crate::traits::file_store::TFileStore;
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on a version of a document of the Handover Documentation with the files of the
+/// version.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct DocumentVersion {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl DocumentVersion {
+    ///Semantic ID of a document version.
+    pub const SEMANTIC_ID: &str = "0173-1#02-ABI503#001/0173-1#01-AHF582#001";
+
+    ///Creates a new document version.
+    ///
+    /// [languages]: languages of the document version according to ISO 639-1, e.g. en
+    /// [document_version_id]: ID of the document version
+    /// [title]: title of the document
+    /// [status_set_date]: date the status of the document version was set, as xs:date
+    /// [status_value]: status of the document version, e.g. Released or InReview
+    /// [organization_name]: name of the organization that authored the document version, also used
+    /// as its official name
+    /// [digital_file]: file of the document version, e.g. the PDF file
+    pub fn new(languages: Vec<String>, document_version_id: String, title: Vec<MultiLanguageTextType>,
+               status_set_date: String, status_value: String, organization_name: String, digital_file: File) -> DocumentVersion {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_semantic_id(global_reference(DocumentVersion::SEMANTIC_ID));
+        let mut document_version = DocumentVersion {
+            collection
+        };
+        document_version.set_languages(languages);
+        document_version.set_document_version_id(document_version_id);
+        document_version.set_title(title);
+        document_version.set_status_set_date(status_set_date);
+        document_version.set_status_value(status_value);
+        document_version.set_organization_official_name(organization_name.clone());
+        document_version.set_organization_name(organization_name);
+        document_version.set_digital_files(vec![digital_file]);
+        document_version
+    }
+
+    ///Wraps a submodel element collection after checking the elements of a document version.
+    ///
+    /// [collection]: collection containing a document version
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<DocumentVersion, ConversionError> {
+        DocumentVersion::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(DocumentVersion {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the document version and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the languages of the document version according to ISO 639-1, e.g. en.
+    pub fn get_languages(&self) -> Vec<String> {
+        self.collection.find_elements(LANGUAGE.id_short, LANGUAGE.semantic_id).into_iter()
+            .filter_map(|element| match element {
+                SubmodelElement::Property(property) => property.get_typed_value(),
+                _ => None
+            })
+            .collect()
+    }
+
+    ///Sets the languages of the document version according to ISO 639-1, e.g. en.
+    ///
+    /// [languages]: languages of the document version according to ISO 639-1, e.g. en
+    pub fn set_languages(&mut self, languages: Vec<String>) {
+        let elements = languages.iter().map(|value| LANGUAGE.new_property(value)).collect();
+        self.collection.set_elements(LANGUAGE.id_short, LANGUAGE.semantic_id, elements);
+    }
+
+    ///Returns the ID of the document version.
+    pub fn get_document_version_id(&self) -> Option<String> {
+        self.collection.get_property_value(DOCUMENT_VERSION_ID.id_short, DOCUMENT_VERSION_ID.semantic_id)
+    }
+
+    ///Sets the ID of the document version.
+    ///
+    /// [document_version_id]: ID of the document version
+    pub fn set_document_version_id(&mut self, document_version_id: String) {
+        DOCUMENT_VERSION_ID.set_property_value(&mut self.collection, Some(&document_version_id));
+    }
+
+    ///Returns the title of the document.
+    pub fn get_title(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(TITLE.id_short, TITLE.semantic_id)
+    }
+
+    ///Sets the title of the document.
+    ///
+    /// [title]: title of the document
+    pub fn set_title(&mut self, title: Vec<MultiLanguageTextType>) {
+        self.collection.set_multi_language_value(TITLE.id_short, TITLE.semantic_id, Some(title));
+    }
+
+    ///Returns the subtitle of the document.
+    pub fn get_sub_title(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(SUB_TITLE.id_short, SUB_TITLE.semantic_id)
+    }
+
+    ///Sets the subtitle of the document. Passing None removes it.
+    ///
+    /// [sub_title]: subtitle of the document
+    pub fn set_sub_title(&mut self, sub_title: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(SUB_TITLE.id_short, SUB_TITLE.semantic_id, sub_title);
+    }
+
+    ///Returns the summary of the content of the document.
+    pub fn get_summary(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(SUMMARY.id_short, SUMMARY.semantic_id)
+    }
+
+    ///Sets the summary of the content of the document. Passing None removes it.
+    ///
+    /// [summary]: summary of the content of the document
+    pub fn set_summary(&mut self, summary: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(SUMMARY.id_short, SUMMARY.semantic_id, summary);
+    }
+
+    ///Returns the keywords describing the document.
+    pub fn get_key_words(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(KEY_WORDS.id_short, KEY_WORDS.semantic_id)
+    }
+
+    ///Sets the keywords describing the document. Passing None removes it.
+    ///
+    /// [key_words]: keywords describing the document
+    pub fn set_key_words(&mut self, key_words: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(KEY_WORDS.id_short, KEY_WORDS.semantic_id, key_words);
+    }
+
+    ///Returns the date the status of the document version was set, as xs:date.
+    pub fn get_status_set_date(&self) -> Option<String> {
+        self.collection.get_property_value(STATUS_SET_DATE.id_short, STATUS_SET_DATE.semantic_id)
+    }
+
+    ///Sets the date the status of the document version was set, as xs:date.
+    ///
+    /// [status_set_date]: date the status of the document version was set, as xs:date
+    pub fn set_status_set_date(&mut self, status_set_date: String) {
+        STATUS_SET_DATE.set_property_value(&mut self.collection, Some(&status_set_date));
+    }
+
+    ///Returns the status of the document version, e.g. Released or InReview.
+    pub fn get_status_value(&self) -> Option<String> {
+        self.collection.get_property_value(STATUS_VALUE.id_short, STATUS_VALUE.semantic_id)
+    }
+
+    ///Sets the status of the document version, e.g. Released or InReview.
+    ///
+    /// [status_value]: status of the document version, e.g. Released or InReview
+    pub fn set_status_value(&mut self, status_value: String) {
+        STATUS_VALUE.set_property_value(&mut self.collection, Some(&status_value));
+    }
+
+    ///Returns the common name of the organization that authored the document version.
+    pub fn get_organization_name(&self) -> Option<String> {
+        self.collection.get_property_value(ORGANIZATION_NAME.id_short, ORGANIZATION_NAME.semantic_id)
+    }
+
+    ///Sets the common name of the organization that authored the document version.
+    ///
+    /// [organization_name]: common name of the organization that authored the document version
+    pub fn set_organization_name(&mut self, organization_name: String) {
+        ORGANIZATION_NAME.set_property_value(&mut self.collection, Some(&organization_name));
+    }
+
+    ///Returns the official name of the organization that authored the document version.
+    pub fn get_organization_official_name(&self) -> Option<String> {
+        self.collection.get_property_value(ORGANIZATION_OFFICIAL_NAME.id_short, ORGANIZATION_OFFICIAL_NAME.semantic_id)
+    }
+
+    ///Sets the official name of the organization that authored the document version.
+    ///
+    /// [organization_official_name]: official name of the organization that authored the document version
+    pub fn set_organization_official_name(&mut self, organization_official_name: String) {
+        ORGANIZATION_OFFICIAL_NAME.set_property_value(&mut self.collection, Some(&organization_official_name));
+    }
+
+    ///Returns the files of the document version, e.g. the PDF file.
+    pub fn get_digital_files(&self) -> Vec<&File> {
+        self.collection.find_elements(DIGITAL_FILE.id_short, DIGITAL_FILE.semantic_id).into_iter()
+            .filter_map(|element| match element {
+                SubmodelElement::File(file) => Some(file),
+                _ => None
+            })
+            .collect()
+    }
+
+    ///Sets the files of the document version, e.g. the PDF file.
+    ///
+    /// [digital_files]: files of the document version, e.g. the PDF file
+    pub fn set_digital_files(&mut self, digital_files: Vec<File>) {
+        self.collection.set_elements(DIGITAL_FILE.id_short, DIGITAL_FILE.semantic_id, digital_files.into_iter().map(SubmodelElement::File).collect());
+    }
+
+    ///Returns the preview image of the document version.
+    pub fn get_preview_file(&self) -> Option<&File> {
+        self.collection.get_file(PREVIEW_FILE.id_short, PREVIEW_FILE.semantic_id)
+    }
+
+    ///Sets the preview image of the document version. Passing None removes it.
+    ///
+    /// [preview_file]: preview image of the document version
+    pub fn set_preview_file(&mut self, preview_file: Option<File>) {
+        self.collection.set_element(PREVIEW_FILE.id_short, PREVIEW_FILE.semantic_id, preview_file.map(SubmodelElement::File));
+    }
+
+    ///Reads the files of the document version from the file store.
+    ///
+    /// [store]: file store containing the files, e.g. an AASX package
+    pub fn read_digital_files(&self, store: &dyn TFileStore) -> Result<Vec<Vec<u8>>, FileResolutionError> {
+        self.get_digital_files().into_iter().map(|file| store.resolve(file)).collect()
+    }
+
+    ///Reads the preview image of the document version from the file store, if there is one.
+    ///
+    /// [store]: file store containing the files, e.g. an AASX package
+    pub fn read_preview_file(&self, store: &dyn TFileStore) -> Result<Option<Vec<u8>>, FileResolutionError> {
+        self.get_preview_file().map(|file| store.resolve(file)).transpose()
+    }
+
+    ///Returns the elements of a document version in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        ELEMENTS.iter().map(|element| element.to_element()).collect()
+    }
+
+    ///Checks the elements of a document version.
+    ///
+    /// [collection]: collection containing a document version
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        Ok(())
+    }
+}
+
+const LANGUAGE: TemplateElement =
+    TemplateElement::property("Language{00}", "0173-1#02-AAN468#006", DataTypeDefXsd::String, Cardinality::OneToMany);
+const DOCUMENT_VERSION_ID: TemplateElement =
+    TemplateElement::property("DocumentVersionId", "0173-1#02-AAO100#002", DataTypeDefXsd::String, Cardinality::One);
+const TITLE: TemplateElement =
+    TemplateElement::new("Title", "0173-1#02-AAO105#002", AasSubmodelElements::MultiLanguageProperty, Cardinality::One);
+const SUB_TITLE: TemplateElement =
+    TemplateElement::new("SubTitle", "0173-1#02-ABH998#001", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const SUMMARY: TemplateElement =
+    TemplateElement::new("Summary", "0173-1#02-AAO106#002", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const KEY_WORDS: TemplateElement =
+    TemplateElement::new("KeyWords", "0173-1#02-ABH999#001", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const STATUS_SET_DATE: TemplateElement =
+    TemplateElement::property("StatusSetDate", "0173-1#02-ABI000#001", DataTypeDefXsd::Date, Cardinality::One);
+const STATUS_VALUE: TemplateElement =
+    TemplateElement::property("StatusValue", "0173-1#02-ABI001#001", DataTypeDefXsd::String, Cardinality::One);
+const ORGANIZATION_NAME: TemplateElement =
+    TemplateElement::property("OrganizationName", "0173-1#02-ABI002#001", DataTypeDefXsd::String, Cardinality::One);
+const ORGANIZATION_OFFICIAL_NAME: TemplateElement =
+    TemplateElement::property("OrganizationOfficialName", "0173-1#02-ABI004#001", DataTypeDefXsd::String, Cardinality::One);
+const DIGITAL_FILE: TemplateElement =
+    TemplateElement::new("DigitalFile{00}", "0173-1#02-ABI504#001/0173-1#01-AHF583#001", AasSubmodelElements::File, Cardinality::OneToMany);
+const PREVIEW_FILE: TemplateElement =
+    TemplateElement::new("PreviewFile", "0173-1#02-ABI505#001/0173-1#01-AHF584#001", AasSubmodelElements::File, Cardinality::ZeroToOne);
+const REFERS_TO: TemplateElement =
+    TemplateElement::new("RefersTo{00}", "0173-1#02-ABI006#001", AasSubmodelElements::ReferenceElement, Cardinality::ZeroToMany);
+const BASED_ON: TemplateElement =
+    TemplateElement::new("BasedOn{00}", "0173-1#02-ABI007#001", AasSubmodelElements::ReferenceElement, Cardinality::ZeroToMany);
+const TRANSLATION_OF: TemplateElement =
+    TemplateElement::new("TranslationOf{00}", "0173-1#02-ABI008#001", AasSubmodelElements::ReferenceElement, Cardinality::ZeroToMany);
+
+const ELEMENTS: [&TemplateElement; 15] = [&LANGUAGE, &DOCUMENT_VERSION_ID, &TITLE, &SUB_TITLE, &SUMMARY, &KEY_WORDS,
+    &STATUS_SET_DATE, &STATUS_VALUE, &ORGANIZATION_NAME, &ORGANIZATION_OFFICIAL_NAME, &DIGITAL_FILE, &PREVIEW_FILE,
+    &REFERS_TO, &BASED_ON, &TRANSLATION_OF];
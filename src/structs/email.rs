@@ -0,0 +1,133 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::multi_language_text_type::MultiLanguageTextType;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, TemplateElement};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on the email of a contact information.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct Email {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl Email {
+    ///Semantic ID of an email.
+    pub const SEMANTIC_ID: &str = "0173-1#02-AAQ836#005";
+
+    ///Creates a new email.
+    ///
+    /// [email_address]: email address
+    pub fn new(email_address: String) -> Email {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_semantic_id(global_reference(Email::SEMANTIC_ID));
+        let mut email = Email {
+            collection
+        };
+        email.set_email_address(email_address);
+        email
+    }
+
+    ///Wraps a submodel element collection after checking the elements of an email.
+    ///
+    /// [collection]: collection containing an email
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<Email, ConversionError> {
+        Email::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(Email {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the email and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the email address.
+    pub fn get_email_address(&self) -> Option<String> {
+        self.collection.get_property_value(EMAIL_ADDRESS.id_short, EMAIL_ADDRESS.semantic_id)
+    }
+
+    ///Sets the email address.
+    ///
+    /// [email_address]: email address
+    pub fn set_email_address(&mut self, email_address: String) {
+        EMAIL_ADDRESS.set_property_value(&mut self.collection, Some(&email_address));
+    }
+
+    ///Returns the public key for encrypting emails to the address.
+    pub fn get_public_key(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(PUBLIC_KEY.id_short, PUBLIC_KEY.semantic_id)
+    }
+
+    ///Sets the public key for encrypting emails to the address. Passing None removes it.
+    ///
+    /// [public_key]: public key for encrypting emails to the address
+    pub fn set_public_key(&mut self, public_key: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(PUBLIC_KEY.id_short, PUBLIC_KEY.semantic_id, public_key);
+    }
+
+    ///Returns the type of the email address, e.g. office.
+    pub fn get_type_of_email_address(&self) -> Option<String> {
+        self.collection.get_property_value(TYPE_OF_EMAIL_ADDRESS.id_short, TYPE_OF_EMAIL_ADDRESS.semantic_id)
+    }
+
+    ///Sets the type of the email address, e.g. office. Passing None removes it.
+    ///
+    /// [type_of_email_address]: type of the email address, e.g. office
+    pub fn set_type_of_email_address(&mut self, type_of_email_address: Option<String>) {
+        TYPE_OF_EMAIL_ADDRESS.set_property_value(&mut self.collection, type_of_email_address.as_ref());
+    }
+
+    ///Returns the type of the public key, e.g. PGP.
+    pub fn get_type_of_public_key(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(TYPE_OF_PUBLIC_KEY.id_short, TYPE_OF_PUBLIC_KEY.semantic_id)
+    }
+
+    ///Sets the type of the public key, e.g. PGP. Passing None removes it.
+    ///
+    /// [type_of_public_key]: type of the public key, e.g. PGP
+    pub fn set_type_of_public_key(&mut self, type_of_public_key: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(TYPE_OF_PUBLIC_KEY.id_short, TYPE_OF_PUBLIC_KEY.semantic_id, type_of_public_key);
+    }
+
+    ///Returns the elements of an email in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        ELEMENTS.iter().map(|element| element.to_element()).collect()
+    }
+
+    ///Checks the elements of an email.
+    ///
+    /// [collection]: collection containing an email
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        Ok(())
+    }
+}
+
+const EMAIL_ADDRESS: TemplateElement =
+    TemplateElement::property("EmailAddress", "0173-1#02-AAO198#002", DataTypeDefXsd::String, Cardinality::One);
+const PUBLIC_KEY: TemplateElement =
+    TemplateElement::new("PublicKey", "0173-1#02-AAO200#002", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const TYPE_OF_EMAIL_ADDRESS: TemplateElement =
+    TemplateElement::property("TypeOfEmailAddress", "0173-1#02-AAO199#003", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const TYPE_OF_PUBLIC_KEY: TemplateElement =
+    TemplateElement::new("TypeOfPublicKey", "0173-1#02-AAO201#002", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+
+const ELEMENTS: [&TemplateElement; 4] = [&EMAIL_ADDRESS, &PUBLIC_KEY, &TYPE_OF_EMAIL_ADDRESS, &TYPE_OF_PUBLIC_KEY];
@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::data_specification_iec61360::DataSpecificationIec61360;
+use crate::structs::reference::Reference;
+
+///Data specification template embedded in an element together with the content the element
+/// provides for it, e.g. the IEC 61360 description of the concept of a concept description.
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
+pub struct EmbeddedDataSpecification {
+    ///Global reference to the data specification template.
+    #[serde(rename = "dataSpecification")]
+    data_specification: Reference,
+    ///Content of the data specification template provided by the element.
+    #[serde(rename = "dataSpecificationContent")]
+    data_specification_content: DataSpecificationIec61360
+}
+
+impl EmbeddedDataSpecification {
+    ///Creates a new instance of the struct.
+    ///
+    /// [data_specification]: global reference to the data specification template
+    /// [data_specification_content]: content of the data specification template
+    pub fn new(data_specification: Reference, data_specification_content: DataSpecificationIec61360) -> EmbeddedDataSpecification {
+        EmbeddedDataSpecification {
+            data_specification,
+            data_specification_content
+        }
+    }
+
+    ///Sets the global reference to the data specification template.
+    ///
+    /// [data_specification]: global reference to the data specification template
+    pub fn set_data_specification(&mut self, data_specification: Reference) {
+        self.data_specification = data_specification;
+    }
+
+    ///Returns the global reference to the data specification template.
+    pub fn get_data_specification(&self) -> &Reference {
+        &self.data_specification
+    }
+
+    ///Returns the mutable global reference to the data specification template.
+    pub fn get_mut_data_specification(&mut self) -> &mut Reference {
+        &mut self.data_specification
+    }
+
+    ///Sets the content of the data specification template.
+    ///
+    /// [data_specification_content]: content of the data specification template
+    pub fn set_data_specification_content(&mut self, data_specification_content: DataSpecificationIec61360) {
+        self.data_specification_content = data_specification_content;
+    }
+
+    ///Returns the content of the data specification template.
+    pub fn get_data_specification_content(&self) -> &DataSpecificationIec61360 {
+        &self.data_specification_content
+    }
+
+    ///Returns the mutable content of the data specification template.
+    pub fn get_mut_data_specification_content(&mut self) -> &mut DataSpecificationIec61360 {
+        &mut self.data_specification_content
+    }
+
+    ///Returns all references contained in the embedded data specification.
+    pub fn get_references(&self) -> Vec<&Reference> {
+        self.data_specification.get_references().into_iter()
+            .chain(self.data_specification_content.get_references())
+            .collect()
+    }
+
+    ///Calls the visitor for every reference contained in the embedded data specification.
+    ///
+    /// [visitor]: function called for every reference
+    pub fn visit_references_mut(&mut self, visitor: &mut dyn FnMut(&mut Reference)) {
+        self.data_specification.visit_references_mut(visitor);
+        self.data_specification_content.visit_references_mut(visitor);
+    }
+}
\ No newline at end of file
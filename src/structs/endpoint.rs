@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::protocol_information::ProtocolInformation;
+
+///Endpoint of a shell or submodel in a descriptor, which names the interface offered at the
+/// endpoint and how to reach it.
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
+pub struct Endpoint {
+    ///Name and version of the interface offered at the endpoint, e.g. `AAS-3.0`.
+    interface: String,
+    ///Information on how to reach the endpoint.
+    #[serde(rename = "protocolInformation")]
+    protocol_information: ProtocolInformation
+}
+
+impl Endpoint {
+    ///Interface of the endpoints of shells.
+    pub const AAS_INTERFACE: &str = "AAS-3.0";
+    ///Interface of the endpoints of submodels.
+    pub const SUBMODEL_INTERFACE: &str = "SUBMODEL-3.0";
+
+    ///Creates a new endpoint.
+    ///
+    /// [interface]: name and version of the interface, e.g. `Endpoint::AAS_INTERFACE`
+    /// [protocol_information]: information on how to reach the endpoint
+    pub fn new(interface: String, protocol_information: ProtocolInformation) -> Endpoint {
+        Endpoint {
+            interface,
+            protocol_information
+        }
+    }
+
+    ///Returns the name and version of the interface offered at the endpoint.
+    pub fn get_interface(&self) -> &String {
+        &self.interface
+    }
+
+    ///Sets the name and version of the interface offered at the endpoint.
+    ///
+    /// [interface]: name and version of the interface
+    pub fn set_interface(&mut self, interface: String) {
+        self.interface = interface;
+    }
+
+    ///Returns the information on how to reach the endpoint.
+    pub fn get_protocol_information(&self) -> &ProtocolInformation {
+        &self.protocol_information
+    }
+
+    ///Returns the mutable information on how to reach the endpoint.
+    pub fn get_mut_protocol_information(&mut self) -> &mut ProtocolInformation {
+        &mut self.protocol_information
+    }
+
+    ///Sets the information on how to reach the endpoint.
+    ///
+    /// [protocol_information]: information on how to reach the endpoint
+    pub fn set_protocol_information(&mut self, protocol_information: ProtocolInformation) {
+        self.protocol_information = protocol_information;
+    }
+}
\ No newline at end of file
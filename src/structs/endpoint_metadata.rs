@@ -0,0 +1,213 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::reference::Reference;
+use crate::structs::reference_element::ReferenceElement;
+use crate::structs::security_scheme::SecurityScheme;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::submodel_element_list::SubmodelElementList;
+use crate::structs::template_element::{global_reference, join_path, TemplateElement, ARBITRARY};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on the endpoint metadata of an asset interface, i.e. the base URI, the default
+/// content type and the security schemes of the interface.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct EndpointMetadata {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl EndpointMetadata {
+    ///Semantic ID of the endpoint metadata.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/idta/AssetInterfacesDescription/1/0/EndpointMetadata";
+    ///idShort of the endpoint metadata.
+    pub const ID_SHORT: &str = "EndpointMetadata";
+
+    ///Creates new endpoint metadata without security schemes.
+    ///
+    /// [base]: base URI the targets of the forms are relative to, e.g. `http://192.168.0.1:8080`
+    pub fn new(base: String) -> EndpointMetadata {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_id_short(EndpointMetadata::ID_SHORT.to_string());
+        collection.set_semantic_id(global_reference(EndpointMetadata::SEMANTIC_ID));
+        let mut endpoint_metadata = EndpointMetadata {
+            collection
+        };
+        endpoint_metadata.set_base(base);
+        endpoint_metadata.set_security(Vec::new());
+        endpoint_metadata.set_security_definitions(Vec::new());
+        endpoint_metadata
+    }
+
+    ///Wraps a submodel element collection after checking the elements of the endpoint metadata.
+    ///
+    /// [collection]: collection containing the endpoint metadata
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<EndpointMetadata, ConversionError> {
+        EndpointMetadata::check(&collection, EndpointMetadata::ID_SHORT)?;
+        Ok(EndpointMetadata {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the endpoint metadata and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the base URI the targets of the forms are relative to.
+    pub fn get_base(&self) -> Option<String> {
+        self.collection.get_property_value(BASE.id_short, BASE.semantic_id)
+    }
+
+    ///Sets the base URI the targets of the forms are relative to.
+    ///
+    /// [base]: base URI, e.g. `http://192.168.0.1:8080`
+    pub fn set_base(&mut self, base: String) {
+        BASE.set_property_value(&mut self.collection, Some(&base));
+    }
+
+    ///Returns the default content type of the payloads, e.g. `application/json`.
+    pub fn get_content_type(&self) -> Option<String> {
+        self.collection.get_property_value(CONTENT_TYPE.id_short, CONTENT_TYPE.semantic_id)
+    }
+
+    ///Sets the default content type of the payloads. Passing None removes it.
+    ///
+    /// [content_type]: default content type of the payloads, e.g. `application/json`
+    pub fn set_content_type(&mut self, content_type: Option<String>) {
+        CONTENT_TYPE.set_property_value(&mut self.collection, content_type.as_ref());
+    }
+
+    ///Returns the names of the security schemes the interface is secured with, i.e. the last key
+    /// values of the references of the security configuration.
+    pub fn get_security(&self) -> Vec<String> {
+        self.collection.get_list(SECURITY.id_short, SECURITY.semantic_id)
+            .map(|list| list.get_value().iter()
+                .filter_map(|element| match element {
+                    SubmodelElement::ReferenceElement(reference_element) => reference_element.get_value()
+                        .and_then(|reference| reference.get_keys().last())
+                        .map(|key| key.get_value().clone()),
+                    _ => None
+                })
+                .collect())
+            .unwrap_or_default()
+    }
+
+    ///Sets the security configuration, i.e. the references to the security schemes the interface
+    /// is secured with. See `AssetInterface::set_security` to refer to schemes by name.
+    ///
+    /// [security]: model references to security schemes of the security definitions
+    pub fn set_security(&mut self, security: Vec<Reference>) {
+        let mut list = SubmodelElementList::new(AasSubmodelElements::ReferenceElement);
+        list.set_semantic_id_list_element(global_reference(SECURITY_ITEM.semantic_id));
+        list.set_value(security.into_iter()
+            .map(|reference| {
+                let mut reference_element = ReferenceElement::new();
+                reference_element.set_semantic_id(global_reference(SECURITY_ITEM.semantic_id));
+                reference_element.set_value(reference);
+                SubmodelElement::ReferenceElement(reference_element)
+            })
+            .collect());
+        self.collection.set_element(SECURITY.id_short, SECURITY.semantic_id, Some(SubmodelElement::SubmodelElementList(list)));
+    }
+
+    ///Returns the security schemes of the security definitions.
+    pub fn get_security_definitions(&self) -> Vec<SecurityScheme> {
+        self.collection.get_collection(SECURITY_DEFINITIONS.id_short, SECURITY_DEFINITIONS.semantic_id)
+            .map(|definitions| definitions.get_value().iter()
+                .filter_map(|element| match element {
+                    SubmodelElement::SubmodelElementCollection(collection) => Some(SecurityScheme {
+                        collection: collection.clone()
+                    }),
+                    _ => None
+                })
+                .collect())
+            .unwrap_or_default()
+    }
+
+    ///Returns the security scheme of the security definitions with the name.
+    ///
+    /// [name]: name of the security scheme, e.g. `basic_sc`
+    pub fn get_security_definition(&self, name: &str) -> Option<SecurityScheme> {
+        self.get_security_definitions().into_iter()
+            .find(|security_scheme| security_scheme.get_name().is_some_and(|scheme_name| scheme_name == name))
+    }
+
+    ///Sets the security schemes of the security definitions.
+    ///
+    /// [security_definitions]: security schemes with unique names
+    pub fn set_security_definitions(&mut self, security_definitions: Vec<SecurityScheme>) {
+        let mut definitions = self.collection.get_collection(SECURITY_DEFINITIONS.id_short, SECURITY_DEFINITIONS.semantic_id).cloned()
+            .unwrap_or_else(SubmodelElementCollection::new);
+        definitions.set_value(security_definitions.into_iter()
+            .map(|security_scheme| SubmodelElement::SubmodelElementCollection(security_scheme.into_collection()))
+            .collect());
+        self.collection.set_element(SECURITY_DEFINITIONS.id_short, SECURITY_DEFINITIONS.semantic_id,
+                                    Some(SubmodelElement::SubmodelElementCollection(definitions)));
+    }
+
+    ///Adds a security scheme to the security definitions, replacing a scheme with the same name.
+    ///
+    /// [security_scheme]: security scheme to add
+    pub fn add_security_definition(&mut self, security_scheme: SecurityScheme) {
+        let mut security_definitions = self.get_security_definitions();
+        security_definitions.retain(|existing| existing.get_name() != security_scheme.get_name());
+        security_definitions.push(security_scheme);
+        self.set_security_definitions(security_definitions);
+    }
+
+    ///Returns the elements of the endpoint metadata in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        vec![
+            BASE.to_element(),
+            CONTENT_TYPE.to_element(),
+            SECURITY.to_list(&SECURITY_ITEM, Vec::new()),
+            SECURITY_DEFINITIONS.to_collection(vec![ARBITRARY.to_element()]),
+            ARBITRARY.to_element()
+        ]
+    }
+
+    ///Checks the elements of the endpoint metadata.
+    ///
+    /// [collection]: collection containing the endpoint metadata
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        if let Some(definitions) = collection.get_collection(SECURITY_DEFINITIONS.id_short, SECURITY_DEFINITIONS.semantic_id) {
+            let definitions_path = join_path(path, SECURITY_DEFINITIONS.id_short);
+            for element in definitions.get_value() {
+                if let SubmodelElement::SubmodelElementCollection(security_scheme) = element {
+                    SecurityScheme::check(security_scheme,
+                                          &join_path(&definitions_path, security_scheme.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default()))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+const BASE: TemplateElement =
+    TemplateElement::property("base", "https://www.w3.org/2019/wot/td#baseURI", DataTypeDefXsd::AnyUri, Cardinality::One);
+const CONTENT_TYPE: TemplateElement =
+    TemplateElement::property("contentType", "https://www.w3.org/2019/wot/hypermedia#forContentType", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const SECURITY: TemplateElement =
+    TemplateElement::new("security", "https://www.w3.org/2019/wot/td#hasSecurityConfiguration", AasSubmodelElements::SubmodelElementList, Cardinality::One);
+const SECURITY_ITEM: TemplateElement =
+    TemplateElement::new("SecurityReference", "https://www.w3.org/2019/wot/td#hasSecurityConfiguration", AasSubmodelElements::ReferenceElement, Cardinality::ZeroToMany);
+const SECURITY_DEFINITIONS: TemplateElement =
+    TemplateElement::new("securityDefinitions", "https://www.w3.org/2019/wot/td#definesSecurityScheme", AasSubmodelElements::SubmodelElementCollection, Cardinality::One);
+
+const ELEMENTS: [&TemplateElement; 4] = [&BASE, &CONTENT_TYPE, &SECURITY, &SECURITY_DEFINITIONS];
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::enumerations::entity_type::EntityType;
 use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::embedded_data_specification::EmbeddedDataSpecification;
 use crate::structs::extension::Extension;
 use crate::structs::multi_language_name_type::MultiLanguageNameType;
 use crate::structs::multi_language_text_type::MultiLanguageTextType;
@@ -20,31 +21,36 @@ use crate::traits::submodel_element::TSubmodelElement;
 pub struct Entity {
     ///Optional statements applicable to the entity, each statement described by submodel element -
     /// typically with a qualified value
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     statements: Vec<SubmodelElement>,
     ///Describes whether the entity is a co-managed entity or a self-managed entity. Optional.
-    #[serde(rename = "entityType")]
+    #[serde(rename = "entityType", default, skip_serializing_if = "Option::is_none")]
     entity_type: Option<EntityType>,
     ///Optional global identifier of the asset the entity is representing.
-    #[serde(rename = "globalAssetId")]
+    #[serde(rename = "globalAssetId", default, skip_serializing_if = "Option::is_none")]
     global_asset_id: Option<String>,
     ///Optional references to a specific asset ID representing a supplementary identifier of the asset
     /// represented by the Asset Administration Shell.
-    #[serde(rename = "specificAssetIds")]
+    #[serde(rename = "specificAssetIds", default, skip_serializing_if = "Vec::is_empty")]
     specific_asset_ids: Vec<SpecificAssetId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     category: Option<String>,
-    #[serde(rename = "idShort")]
+    #[serde(rename = "idShort", default, skip_serializing_if = "Option::is_none")]
     id_short: Option<String>,
-    #[serde(rename = "displayName")]
+    #[serde(rename = "displayName", default, skip_serializing_if = "Vec::is_empty")]
     display_name: Vec<MultiLanguageNameType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     description: Vec<MultiLanguageTextType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     extensions: Vec<Extension>,
-    #[serde(rename = "semanticId")]
+    #[serde(rename = "semanticId", default, skip_serializing_if = "Option::is_none")]
     semantic_id: Option<Reference>,
-    #[serde(rename = "supplementalSemanticIds")]
+    #[serde(rename = "supplementalSemanticIds", default, skip_serializing_if = "Vec::is_empty")]
     supplemental_semantic_ids: Vec<Reference>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     qualifiers: Vec<Qualifier>,
-    #[serde(rename = "embeddedDataSpecifications")]
-    data_specifications: Vec<Reference>
+    #[serde(rename = "embeddedDataSpecifications", default, skip_serializing_if = "Vec::is_empty")]
+    data_specifications: Vec<EmbeddedDataSpecification>
 }
 
 impl Entity {
@@ -305,23 +311,23 @@ impl TQualifiable for Entity {
 }
 
 impl THasDataSpecification for Entity {
-    fn get_data_specifications(&self) -> &Vec<Reference> {
+    fn get_data_specifications(&self) -> &Vec<EmbeddedDataSpecification> {
         &self.data_specifications
     }
 
-    fn get_mut_data_specifications(&mut self) -> &mut Vec<Reference> {
+    fn get_mut_data_specifications(&mut self) -> &mut Vec<EmbeddedDataSpecification> {
         &mut self.data_specifications
     }
 
-    fn set_data_specifications(&mut self, data_specifications: Vec<Reference>) {
+    fn set_data_specifications(&mut self, data_specifications: Vec<EmbeddedDataSpecification>) {
         self.data_specifications = data_specifications;
     }
 
-    fn add_data_specification(&mut self, data_specification: Reference) {
+    fn add_data_specification(&mut self, data_specification: EmbeddedDataSpecification) {
         self.data_specifications.push(data_specification);
     }
 
-    fn remove_data_specification(&mut self, index: usize) -> Reference {
+    fn remove_data_specification(&mut self, index: usize) -> EmbeddedDataSpecification {
         self.data_specifications.remove(index)
     }
 }
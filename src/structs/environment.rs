@@ -1,19 +1,38 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use serde::{Deserialize, Serialize};
 
+use crate::enumerations::environment_merge_error::EnvironmentMergeError;
+use crate::enumerations::id_conflict_policy::IdConflictPolicy;
+use crate::enumerations::id_short_path_segment::IdShortPathSegment;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::key_type::KeyType;
+use crate::enumerations::refactoring_error::RefactoringError;
+use crate::enumerations::reference_type::ReferenceType;
 use crate::structs::asset_administration_shell::AssetAdministrationShell;
 use crate::structs::concept_description::ConceptDescription;
+use crate::structs::environment_merge::EnvironmentMerge;
+use crate::structs::key::Key;
+use crate::structs::normalization_options::NormalizationOptions;
+use crate::structs::reference::Reference;
 use crate::structs::submodel::Submodel;
+use crate::traits::has_data_specification::THasDataSpecification;
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::identifiable::TIdentifiable;
 
 ///Container for the sets of different identifiables.
 #[derive(PartialEq, Clone, Serialize, Deserialize)]
 pub struct Environment {
     ///Asset administration shells.
-    #[serde(rename = "assetAdministrationShells")]
+    #[serde(rename = "assetAdministrationShells", default, skip_serializing_if = "Vec::is_empty")]
     asset_administration_shells: Vec<AssetAdministrationShell>,
     ///Submodels.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     submodels: Vec<Submodel>,
     ///Concept descriptions.
-    #[serde(rename = "conceptDescriptions")]
+    #[serde(rename = "conceptDescriptions", default, skip_serializing_if = "Vec::is_empty")]
     concept_descriptions: Vec<ConceptDescription>
 }
 
@@ -112,4 +131,731 @@ impl Environment {
     pub fn remove_concept_description(&mut self, index: usize) -> ConceptDescription {
         self.concept_descriptions.remove(index)
     }
+
+    ///Returns all references contained in the asset administration shells, submodels and concept
+    /// descriptions of the environment.
+    pub fn get_references(&self) -> Vec<&Reference> {
+        self.asset_administration_shells.iter().flat_map(|aas| aas.get_references())
+            .chain(self.submodels.iter().flat_map(|submodel| submodel.get_references()))
+            .chain(self.concept_descriptions.iter().flat_map(|concept_description| concept_description.get_references()))
+            .collect()
+    }
+
+    ///Calls the visitor for every reference contained in the asset administration shells,
+    /// submodels and concept descriptions of the environment.
+    ///
+    /// [visitor]: function called for every reference
+    pub fn visit_references_mut(&mut self, visitor: &mut dyn FnMut(&mut Reference)) {
+        self.asset_administration_shells.iter_mut().for_each(|aas| aas.visit_references_mut(visitor));
+        self.submodels.iter_mut().for_each(|submodel| submodel.visit_references_mut(visitor));
+        self.concept_descriptions.iter_mut()
+            .for_each(|concept_description| concept_description.visit_references_mut(visitor));
+    }
+
+    ///Merges several environments into one. Identifiables are merged in the order of the
+    /// environments. Identifiables equal to an already merged identifiable are deduplicated, while
+    /// different identifiables with the same ID are handled according to the policy.
+    ///
+    /// [environments]: environments to merge
+    /// [policy]: handling of different identifiables with the same ID
+    pub fn merge(environments: Vec<Environment>, policy: IdConflictPolicy) -> Result<EnvironmentMerge, EnvironmentMergeError> {
+        EnvironmentMerge::merge(environments, policy)
+    }
+
+    ///Extracts a self-contained environment for an asset administration shell. The extracted
+    /// environment contains the shell, the submodels the shell references and the transitive closure
+    /// of the concept descriptions referenced by them, e.g. through semantic IDs, value IDs or
+    /// isCaseOf references. Returns None if the environment contains no shell with the ID.
+    ///
+    /// [id]: ID of the asset administration shell
+    pub fn extract_for_shell(&self, id: &str) -> Option<Environment> {
+        let aas = self.asset_administration_shells.iter().find(|aas| aas.get_id() == id)?;
+        let submodels = self.submodels.iter()
+            .filter(|submodel| aas.get_submodels().iter().any(|reference| refers_to(reference, &KeyType::Submodel, submodel.get_id())))
+            .cloned()
+            .collect();
+        Some(self.extract(vec![aas.clone()], submodels))
+    }
+
+    ///Extracts a self-contained environment for a set of submodels. The extracted environment
+    /// contains the submodels and the transitive closure of the concept descriptions referenced by
+    /// them. IDs of submodels not contained in the environment are ignored.
+    ///
+    /// [ids]: IDs of the submodels
+    pub fn extract_for_submodels(&self, ids: &[String]) -> Environment {
+        let submodels = self.submodels.iter()
+            .filter(|submodel| ids.contains(submodel.get_id()))
+            .cloned()
+            .collect();
+        self.extract(Vec::new(), submodels)
+    }
+
+    ///Returns the model references of the environment whose target identifiable is not contained
+    /// in the environment, e.g. references that leave an extracted environment.
+    pub fn get_unresolved_references(&self) -> Vec<&Reference> {
+        self.get_references().into_iter()
+            .filter(|reference| reference.get_reference_type() == &ReferenceType::ModelReference)
+            .filter(|reference| match reference.get_keys().first() {
+                Some(key) => match key.get_key_type() {
+                    KeyType::AssetAdministrationShell => !self.asset_administration_shells.iter()
+                        .any(|aas| aas.get_id() == key.get_value()),
+                    KeyType::Submodel => !self.submodels.iter()
+                        .any(|submodel| submodel.get_id() == key.get_value()),
+                    KeyType::ConceptDescription => !self.concept_descriptions.iter()
+                        .any(|concept_description| concept_description.get_id() == key.get_value()),
+                    KeyType::Identifiable => !self.contains_identifiable(key.get_value()),
+                    _ => false
+                },
+                None => false
+            })
+            .collect()
+    }
+
+    fn contains_identifiable(&self, id: &str) -> bool {
+        self.asset_administration_shells.iter().any(|aas| aas.get_id() == id)
+            || self.submodels.iter().any(|submodel| submodel.get_id() == id)
+            || self.concept_descriptions.iter().any(|concept_description| concept_description.get_id() == id)
+    }
+
+    ///Creates an environment of the shells and submodels together with the transitive closure of
+    /// the concept descriptions of this environment they reference.
+    fn extract(&self, asset_administration_shells: Vec<AssetAdministrationShell>, submodels: Vec<Submodel>) -> Environment {
+        let mut extracted = Environment {
+            asset_administration_shells,
+            submodels,
+            concept_descriptions: Vec::new()
+        };
+        let referenced = self.get_referenced_concept_description_ids(extracted.get_references());
+        extracted.concept_descriptions = self.concept_descriptions.iter()
+            .filter(|concept_description| referenced.contains(concept_description.get_id()))
+            .cloned()
+            .collect();
+        extracted
+    }
+
+    ///Returns the IDs of the concept descriptions of this environment that are referenced by the
+    /// given references, either directly or transitively through other concept descriptions.
+    fn get_referenced_concept_description_ids(&self, references: Vec<&Reference>) -> Vec<String> {
+        let mut referenced: Vec<String> = Vec::new();
+        let mut pending: Vec<&Reference> = references;
+        while let Some(reference) = pending.pop() {
+            let Some(key) = reference.get_keys().first() else {
+                continue;
+            };
+            if referenced.contains(key.get_value()) {
+                continue;
+            }
+            if let Some(concept_description) = self.concept_descriptions.iter().find(|cd| cd.get_id() == key.get_value()) {
+                referenced.push(concept_description.get_id().clone());
+                pending.extend(concept_description.get_references());
+            }
+        }
+        referenced
+    }
+
+    ///Normalizes the environment so that its serialization is stable, e.g. for version control.
+    /// Depending on the options, concept descriptions not referenced by any shell or submodel are
+    /// removed, identical references in supplemental semantic IDs and embedded data specifications
+    /// are deduplicated, identifiables are sorted by their ID and the elements of submodel element
+    /// lists whose order is not relevant are sorted. Returns the removed concept descriptions.
+    ///
+    /// [options]: steps to apply
+    pub fn normalize(&mut self, options: &NormalizationOptions) -> Vec<ConceptDescription> {
+        let mut removed = Vec::new();
+        if options.get_remove_unreferenced_concept_descriptions() {
+            let seeds = self.asset_administration_shells.iter().flat_map(|aas| aas.get_references())
+                .chain(self.submodels.iter().flat_map(|submodel| submodel.get_references()))
+                .collect();
+            let referenced = self.get_referenced_concept_description_ids(seeds);
+            let (kept, unreferenced) = std::mem::take(&mut self.concept_descriptions).into_iter()
+                .partition(|concept_description| referenced.contains(concept_description.get_id()));
+            self.concept_descriptions = kept;
+            removed = unreferenced;
+        }
+
+        if options.get_deduplicate_references() {
+            for aas in self.asset_administration_shells.iter_mut() {
+                deduplicate(aas.get_mut_data_specifications());
+                aas.get_mut_administration().into_iter()
+                    .for_each(|administration| deduplicate(administration.get_mut_data_specifications()));
+            }
+            for submodel in self.submodels.iter_mut() {
+                deduplicate(submodel.get_mut_supplemental_semantic_ids());
+                deduplicate(submodel.get_mut_data_specifications());
+                submodel.get_mut_administration().into_iter()
+                    .for_each(|administration| deduplicate(administration.get_mut_data_specifications()));
+                submodel.get_mut_submodel_elements().iter_mut().for_each(deduplicate_element_references);
+            }
+            for concept_description in self.concept_descriptions.iter_mut() {
+                deduplicate(concept_description.get_mut_data_specifications());
+                concept_description.get_mut_administration().into_iter()
+                    .for_each(|administration| deduplicate(administration.get_mut_data_specifications()));
+            }
+        }
+
+        if options.get_sort_unordered_lists() {
+            for submodel in self.submodels.iter_mut() {
+                submodel.get_mut_submodel_elements().iter_mut().for_each(sort_unordered_lists);
+            }
+        }
+
+        if options.get_sort_identifiables() {
+            self.asset_administration_shells.sort_by(|a, b| a.get_id().cmp(b.get_id()));
+            self.submodels.sort_by(|a, b| a.get_id().cmp(b.get_id()));
+            self.concept_descriptions.sort_by(|a, b| a.get_id().cmp(b.get_id()));
+        }
+        removed
+    }
+
+    ///Clones the identifiables with the given IDs into a new environment and assigns new IDs to
+    /// them. All model references of the clones pointing to one of the cloned identifiables are
+    /// rewritten to the new IDs, e.g. the submodel references of a shell, the ends of relationship
+    /// elements, the values of reference elements and the observed references of events. External
+    /// references and model references to identifiables that are not cloned stay untouched.
+    ///
+    /// [ids]: IDs of the shells, submodels and concept descriptions to clone
+    /// [id_mapping]: function returning the new ID for an old ID
+    pub fn clone_with_ids(&self, ids: &[String], id_mapping: &mut dyn FnMut(&str) -> String) -> Environment {
+        let mut cloned = Environment {
+            asset_administration_shells: self.asset_administration_shells.iter()
+                .filter(|aas| ids.contains(aas.get_id()))
+                .cloned()
+                .collect(),
+            submodels: self.submodels.iter()
+                .filter(|submodel| ids.contains(submodel.get_id()))
+                .cloned()
+                .collect(),
+            concept_descriptions: self.concept_descriptions.iter()
+                .filter(|concept_description| ids.contains(concept_description.get_id()))
+                .cloned()
+                .collect()
+        };
+
+        let mut renames: Vec<(KeyType, String, String)> = Vec::new();
+        for aas in cloned.asset_administration_shells.iter_mut() {
+            let new_id = id_mapping(aas.get_id());
+            renames.push((KeyType::AssetAdministrationShell, aas.get_id().clone(), new_id.clone()));
+            aas.set_id(new_id);
+        }
+        for submodel in cloned.submodels.iter_mut() {
+            let new_id = id_mapping(submodel.get_id());
+            renames.push((KeyType::Submodel, submodel.get_id().clone(), new_id.clone()));
+            submodel.set_id(new_id);
+        }
+        for concept_description in cloned.concept_descriptions.iter_mut() {
+            let new_id = id_mapping(concept_description.get_id());
+            renames.push((KeyType::ConceptDescription, concept_description.get_id().clone(), new_id.clone()));
+            concept_description.set_id(new_id);
+        }
+
+        cloned.rewrite_model_references(&renames);
+        cloned
+    }
+
+    ///Rewrites the first key of every model reference of the environment that points to a renamed
+    /// identifiable.
+    ///
+    /// [renames]: key type, old ID and new ID of every renamed identifiable
+    pub(crate) fn rewrite_model_references(&mut self, renames: &[(KeyType, String, String)]) {
+        if renames.is_empty() {
+            return;
+        }
+        self.visit_references_mut(&mut |reference| {
+            if reference.get_reference_type() != &ReferenceType::ModelReference {
+                return;
+            }
+            if let Some(key) = reference.get_mut_keys().first_mut() {
+                let rename = renames.iter().find(|(key_type, old_id, _)| {
+                    (key_type == key.get_key_type() || key.get_key_type() == &KeyType::Identifiable)
+                        && old_id == key.get_value()
+                });
+                if let Some((_, _, new_id)) = rename {
+                    key.set_value(new_id.clone());
+                }
+            }
+        });
+    }
+
+    ///Clones the identifiables with the given IDs into a new environment like
+    /// [Environment::clone_with_ids] and assigns the new ID at the same position to each of them.
+    /// Returns `RefactoringError::IdCountMismatch` if the number of new IDs differs.
+    ///
+    /// [ids]: IDs of the shells, submodels and concept descriptions to clone
+    /// [new_ids]: new IDs in the order of the IDs to clone
+    pub fn clone_with_new_ids(&self, ids: &[String], new_ids: &[String]) -> Result<Environment, RefactoringError> {
+        if ids.len() != new_ids.len() {
+            return Err(RefactoringError::IdCountMismatch(ids.len(), new_ids.len()));
+        }
+        Ok(self.clone_with_ids(ids, &mut |id| ids.iter().position(|old_id| old_id == id)
+            .map(|index| new_ids[index].clone())
+            .unwrap_or_else(|| id.to_string())))
+    }
+
+    ///Clones the identifiables with the given IDs into a new environment like
+    /// [Environment::clone_with_ids] and assigns newly generated UUID URNs as IDs to them.
+    ///
+    /// [ids]: IDs of the shells, submodels and concept descriptions to clone
+    pub fn clone_with_generated_ids(&self, ids: &[String]) -> Environment {
+        self.clone_with_ids(ids, &mut |_| generate_id())
+    }
+
+    ///Changes the idShort of a submodel element and updates all model references of the environment
+    /// pointing to the element or to one of its children.
+    ///
+    /// [submodel_id]: ID of the submodel containing the element
+    /// [id_short_path]: idShortPath of the element to rename
+    /// [new_id_short]: new idShort of the element
+    pub fn rename_element(&mut self, submodel_id: &str, id_short_path: &str, new_id_short: &str)
+        -> Result<(), RefactoringError> {
+        let segments = parse_element_path(id_short_path)?;
+        let submodel = self.find_submodel(submodel_id)?;
+        let old_keys = element_keys(submodel, &segments)
+            .ok_or_else(|| RefactoringError::ElementNotFound(id_short_path.to_string()))?;
+        if old_keys.last().is_some_and(|key| key.get_value() == new_id_short) {
+            return Ok(());
+        }
+        let siblings = children_at(submodel, &segments[..segments.len() - 1])
+            .ok_or_else(|| RefactoringError::ElementNotFound(id_short_path.to_string()))?;
+        if siblings.iter().any(|sibling| sibling.get_id_short().is_some_and(|id_short| id_short == new_id_short)) {
+            return Err(RefactoringError::IdShortCollision(id_short_path.to_string()));
+        }
+
+        let mut new_keys = old_keys.clone();
+        if let Some(key) = new_keys.last_mut() {
+            key.set_value(new_id_short.to_string());
+        }
+        if let Some(element) = self.find_submodel_mut(submodel_id)?.get_mut_submodel_element(id_short_path) {
+            element.set_id_short(new_id_short.to_string());
+        }
+        self.replace_key_prefix(&old_keys, &new_keys);
+        Ok(())
+    }
+
+    ///Moves a submodel element into another submodel or submodel element and updates all model
+    /// references of the environment pointing to the element or to one of its children. Elements
+    /// cannot be moved into or out of submodel element lists, as this would change the indexes of
+    /// their elements.
+    ///
+    /// [submodel_id]: ID of the submodel containing the element
+    /// [id_short_path]: idShortPath of the element to move
+    /// [target_submodel_id]: ID of the submodel to move the element to
+    /// [target_parent_path]: idShortPath of the submodel element collection or entity to move the
+    /// element to, or an empty path to move it to the top level of the target submodel
+    pub fn move_element(&mut self, submodel_id: &str, id_short_path: &str, target_submodel_id: &str,
+                        target_parent_path: &str) -> Result<(), RefactoringError> {
+        let segments = parse_element_path(id_short_path)?;
+        let parent_segments = &segments[..segments.len() - 1];
+        let target_segments = IdShortPathSegment::parse_path(target_parent_path)
+            .ok_or_else(|| RefactoringError::InvalidPath(target_parent_path.to_string()))?;
+        if submodel_id == target_submodel_id {
+            if target_segments == parent_segments {
+                return Ok(());
+            }
+            if target_segments.starts_with(&segments) {
+                return Err(RefactoringError::InvalidTarget(target_parent_path.to_string()));
+            }
+        }
+
+        let submodel = self.find_submodel(submodel_id)?;
+        let old_keys = element_keys(submodel, &segments)
+            .ok_or_else(|| RefactoringError::ElementNotFound(id_short_path.to_string()))?;
+        let parent_path = IdShortPathSegment::format_path(parent_segments);
+        if let Some(SubmodelElement::SubmodelElementList(_)) = submodel.get_submodel_element(&parent_path) {
+            return Err(RefactoringError::InvalidTarget(id_short_path.to_string()));
+        }
+
+        let target = self.find_submodel(target_submodel_id)?;
+        if !target_segments.is_empty() {
+            match target.get_submodel_element(target_parent_path) {
+                None => return Err(RefactoringError::ElementNotFound(target_parent_path.to_string())),
+                Some(SubmodelElement::SubmodelElementCollection(_)) | Some(SubmodelElement::Entity(_)) => {},
+                Some(_) => return Err(RefactoringError::InvalidTarget(target_parent_path.to_string()))
+            }
+        }
+        let id_short = segments[segments.len() - 1].get_key_value();
+        let mut new_keys = element_keys(target, &target_segments)
+            .ok_or_else(|| RefactoringError::ElementNotFound(target_parent_path.to_string()))?;
+        if children_at(target, &target_segments).is_some_and(|children| children.iter()
+            .any(|child| child.get_id_short() == Some(&id_short))) {
+            let mut target_path = target_segments.clone();
+            target_path.push(IdShortPathSegment::IdShort(id_short));
+            return Err(RefactoringError::IdShortCollision(IdShortPathSegment::format_path(&target_path)));
+        }
+        if let Some(key) = old_keys.last() {
+            new_keys.push(Key::new(key.get_key_type().clone(), id_short.clone()));
+        }
+
+        let source = self.find_submodel_mut(submodel_id)?;
+        let siblings = children_at_mut(source, parent_segments)
+            .ok_or_else(|| RefactoringError::ElementNotFound(id_short_path.to_string()))?;
+        let index = siblings.iter().position(|sibling| sibling.get_id_short() == Some(&id_short))
+            .ok_or_else(|| RefactoringError::ElementNotFound(id_short_path.to_string()))?;
+        let element = siblings.remove(index);
+        let target = self.find_submodel_mut(target_submodel_id)?;
+        if let Some(children) = children_at_mut(target, &target_segments) {
+            children.push(element);
+        }
+        self.replace_key_prefix(&old_keys, &new_keys);
+        Ok(())
+    }
+
+    fn find_submodel(&self, id: &str) -> Result<&Submodel, RefactoringError> {
+        self.submodels.iter().find(|submodel| submodel.get_id() == id)
+            .ok_or_else(|| RefactoringError::SubmodelNotFound(id.to_string()))
+    }
+
+    fn find_submodel_mut(&mut self, id: &str) -> Result<&mut Submodel, RefactoringError> {
+        self.submodels.iter_mut().find(|submodel| submodel.get_id() == id)
+            .ok_or_else(|| RefactoringError::SubmodelNotFound(id.to_string()))
+    }
+
+    ///Replaces the leading keys of every model reference of the environment starting with the old
+    /// keys by the new keys.
+    fn replace_key_prefix(&mut self, old_keys: &[Key], new_keys: &[Key]) {
+        self.visit_references_mut(&mut |reference| {
+            if reference.get_reference_type() != &ReferenceType::ModelReference {
+                return;
+            }
+            let keys = reference.get_mut_keys();
+            let starts_with_old_keys = keys.len() >= old_keys.len()
+                && keys.first().is_some_and(|key| key.get_key_type() == &KeyType::Submodel
+                    || key.get_key_type() == &KeyType::Identifiable)
+                && keys.iter().zip(old_keys).all(|(key, old_key)| key.get_value() == old_key.get_value());
+            if starts_with_old_keys {
+                keys.splice(..old_keys.len(), new_keys.iter().cloned());
+            }
+        });
+    }
+}
+
+///Returns whether the first key of the reference denotes the identifiable of the given type and ID.
+fn refers_to(reference: &Reference, key_type: &KeyType, id: &str) -> bool {
+    reference.get_keys().first()
+        .is_some_and(|key| key.get_key_type() == key_type && key.get_value() == id)
+}
+
+///Removes all but the first occurrence of identical references or embedded data specifications.
+fn deduplicate<T: PartialEq>(items: &mut Vec<T>) {
+    let mut unique: Vec<T> = Vec::with_capacity(items.len());
+    for item in items.drain(..) {
+        if !unique.contains(&item) {
+            unique.push(item);
+        }
+    }
+    *items = unique;
+}
+
+fn deduplicate_element_references(element: &mut SubmodelElement) {
+    deduplicate(element.get_mut_supplemental_semantic_ids());
+    deduplicate(element.get_mut_data_specifications());
+    if let Some(children) = element.get_mut_children() {
+        children.iter_mut().for_each(deduplicate_element_references);
+    }
+}
+
+///Sorts the elements of all nested submodel element lists whose order is explicitly marked as not
+/// relevant. Elements are sorted by their semantic ID, idShort and value. Elements that cannot be
+/// distinguished this way keep their relative order.
+fn sort_unordered_lists(element: &mut SubmodelElement) {
+    if let Some(children) = element.get_mut_children() {
+        children.iter_mut().for_each(sort_unordered_lists);
+    }
+    if let SubmodelElement::SubmodelElementList(list) = element
+        && list.get_order_relevant() == Some(&false) {
+        list.get_mut_value().sort_by_cached_key(sort_key);
+    }
+}
+
+fn sort_key(element: &SubmodelElement) -> Vec<String> {
+    let keys = |reference: Option<&Reference>| reference.map(|reference| reference.get_keys().iter()
+        .map(|key| key.get_value().as_str())
+        .collect::<Vec<&str>>()
+        .join("/"))
+        .unwrap_or_default();
+    let mut key = vec![keys(element.get_semantic_id()), element.get_id_short().cloned().unwrap_or_default()];
+    match element {
+        SubmodelElement::Property(property) => key.push(property.get_value().cloned().unwrap_or_default()),
+        SubmodelElement::MultiLanguageProperty(property) => key.extend(property.get_value().iter()
+            .map(|text| format!("{}@{}", text.get_text(), text.get_language()))),
+        SubmodelElement::Range(range) => {
+            key.push(range.get_min().cloned().unwrap_or_default());
+            key.push(range.get_max().cloned().unwrap_or_default());
+        },
+        SubmodelElement::File(file) => key.push(file.get_value().cloned().unwrap_or_default()),
+        SubmodelElement::ReferenceElement(reference_element) => key.push(keys(reference_element.get_value())),
+        _ => {}
+    }
+    if let Some(children) = element.get_children() {
+        key.extend(children.iter().flat_map(sort_key));
+    }
+    key
+}
+
+///Generates a random version 4 UUID URN.
+fn generate_id() -> String {
+    let state = RandomState::new();
+    let mut first = state.build_hasher();
+    first.write_u128(SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_nanos()).unwrap_or_default());
+    let mut second = state.build_hasher();
+    second.write_u64(first.finish());
+    let random = ((first.finish() as u128) << 64) | second.finish() as u128;
+    let bits = (random & !((0xf000 << 64) | (0xc000 << 48))) | (0x4000 << 64) | (0x8000 << 48);
+    let hex = format!("{:032x}", bits);
+    format!("urn:uuid:{}-{}-{}-{}-{}", &hex[0..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..32])
+}
+
+///Parses the idShortPath of an element that is addressed by its idShort.
+fn parse_element_path(id_short_path: &str) -> Result<Vec<IdShortPathSegment>, RefactoringError> {
+    IdShortPathSegment::parse_path(id_short_path)
+        .filter(|segments| matches!(segments.last(), Some(IdShortPathSegment::IdShort(_))))
+        .ok_or_else(|| RefactoringError::InvalidPath(id_short_path.to_string()))
+}
+
+///Returns the keys of a model reference to the submodel element addressed by the segments.
+fn element_keys(submodel: &Submodel, segments: &[IdShortPathSegment]) -> Option<Vec<Key>> {
+    submodel.get_submodel_element_reference(&IdShortPathSegment::format_path(segments))
+        .map(|reference| reference.get_keys().clone())
+}
+
+///Returns the submodel elements contained in the submodel element addressed by the segments, or
+/// the top level elements of the submodel if there are no segments.
+fn children_at<'a>(submodel: &'a Submodel, segments: &[IdShortPathSegment]) -> Option<&'a Vec<SubmodelElement>> {
+    if segments.is_empty() {
+        return Some(submodel.get_submodel_elements());
+    }
+    submodel.get_submodel_element(&IdShortPathSegment::format_path(segments))?.get_children()
+}
+
+fn children_at_mut<'a>(submodel: &'a mut Submodel, segments: &[IdShortPathSegment]) -> Option<&'a mut Vec<SubmodelElement>> {
+    if segments.is_empty() {
+        return Some(submodel.get_mut_submodel_elements());
+    }
+    submodel.get_mut_submodel_element(&IdShortPathSegment::format_path(segments))?.get_mut_children()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+    use crate::enumerations::asset_kind::AssetKind;
+    use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+    use crate::enumerations::direction::Direction;
+    use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+    use crate::enumerations::key_type::KeyType;
+    use crate::enumerations::refactoring_error::RefactoringError;
+    use crate::enumerations::reference_type::ReferenceType;
+    use crate::enumerations::state_of_event::StateOfEvent;
+    use crate::structs::asset_administration_shell::AssetAdministrationShell;
+    use crate::structs::asset_information::AssetInformation;
+    use crate::structs::basic_event_element::BasicEventElement;
+    use crate::structs::environment::{generate_id, Environment};
+    use crate::structs::key::Key;
+    use crate::structs::property::Property;
+    use crate::structs::reference::Reference;
+    use crate::structs::reference_element::ReferenceElement;
+    use crate::structs::relationship_element::RelationshipElement;
+    use crate::structs::submodel::Submodel;
+    use crate::structs::submodel_element_collection::SubmodelElementCollection;
+    use crate::structs::submodel_element_list::SubmodelElementList;
+    use crate::traits::identifiable::TIdentifiable;
+    use crate::traits::referable::TReferable;
+
+    const SUBMODEL_ID: &str = "urn:example:submodel";
+
+    fn property(id_short: &str) -> SubmodelElement {
+        let mut property = Property::new(DataTypeDefXsd::String);
+        property.set_id_short(id_short.to_string());
+        SubmodelElement::Property(property)
+    }
+
+    fn model_reference(keys: &[(KeyType, &str)]) -> Reference {
+        Reference::new(ReferenceType::ModelReference, keys.iter()
+            .map(|(key_type, value)| Key::new(key_type.clone(), value.to_string()))
+            .collect())
+    }
+
+    fn link(id_short: &str, keys: &[(KeyType, &str)]) -> SubmodelElement {
+        let mut link = ReferenceElement::new();
+        link.set_id_short(id_short.to_string());
+        link.set_value(model_reference(keys));
+        SubmodelElement::ReferenceElement(link)
+    }
+
+    ///Creates an environment with a submodel containing the list `List` of one collection with the
+    /// property `Inner`, the property `Free` and references to both properties.
+    fn environment() -> Environment {
+        let mut item = SubmodelElementCollection::new();
+        item.add_value(property("Inner"));
+        let mut list = SubmodelElementList::new(AasSubmodelElements::SubmodelElementCollection);
+        list.set_id_short("List".to_string());
+        list.add_value(SubmodelElement::SubmodelElementCollection(item));
+        let mut submodel = Submodel::new(SUBMODEL_ID.to_string());
+        submodel.add_submodel_element(SubmodelElement::SubmodelElementList(list));
+        submodel.add_submodel_element(property("Free"));
+        submodel.add_submodel_element(link("InnerLink", &[(KeyType::Submodel, SUBMODEL_ID),
+            (KeyType::SubmodelElementList, "List"), (KeyType::SubmodelElementCollection, "0"), (KeyType::Property, "Inner")]));
+        submodel.add_submodel_element(link("FreeLink", &[(KeyType::Submodel, SUBMODEL_ID), (KeyType::Property, "Free")]));
+        let mut environment = Environment::new();
+        environment.add_submodel(submodel);
+        environment
+    }
+
+    fn link_keys(environment: &Environment, id_short: &str) -> Vec<String> {
+        match environment.get_submodels()[0].get_submodel_element(id_short) {
+            Some(SubmodelElement::ReferenceElement(link)) => link.get_value().unwrap().get_keys().iter()
+                .map(|key| key.get_value().clone())
+                .collect(),
+            _ => panic!("no reference element '{}'", id_short)
+        }
+    }
+
+    #[test]
+    fn rename_element_updates_references_to_children() {
+        let mut environment = environment();
+        environment.rename_element(SUBMODEL_ID, "List", "Items").unwrap();
+        assert!(environment.get_submodels()[0].get_submodel_element("Items[0].Inner").is_some());
+        assert_eq!(link_keys(&environment, "InnerLink"), vec![SUBMODEL_ID, "Items", "0", "Inner"]);
+    }
+
+    #[test]
+    fn rename_element_within_list() {
+        let mut environment = environment();
+        environment.rename_element(SUBMODEL_ID, "List[0].Inner", "Renamed").unwrap();
+        assert_eq!(link_keys(&environment, "InnerLink"), vec![SUBMODEL_ID, "List", "0", "Renamed"]);
+        assert_eq!(link_keys(&environment, "FreeLink"), vec![SUBMODEL_ID, "Free"]);
+    }
+
+    #[test]
+    fn rename_element_rejects_collision() {
+        let mut environment = environment();
+        assert_eq!(environment.rename_element(SUBMODEL_ID, "Free", "List"),
+                   Err(RefactoringError::IdShortCollision("Free".to_string())));
+        assert_eq!(environment.rename_element(SUBMODEL_ID, "Missing", "Other"),
+                   Err(RefactoringError::ElementNotFound("Missing".to_string())));
+    }
+
+    #[test]
+    fn move_element_out_of_list_item() {
+        let mut environment = environment();
+        environment.move_element(SUBMODEL_ID, "List[0].Inner", SUBMODEL_ID, "").unwrap();
+        let submodel = &environment.get_submodels()[0];
+        assert!(submodel.get_submodel_element("Inner").is_some());
+        assert!(submodel.get_submodel_element("List[0].Inner").is_none());
+        assert_eq!(link_keys(&environment, "InnerLink"), vec![SUBMODEL_ID, "Inner"]);
+    }
+
+    #[test]
+    fn move_element_into_list_item() {
+        let mut environment = environment();
+        environment.move_element(SUBMODEL_ID, "Free", SUBMODEL_ID, "List[0]").unwrap();
+        assert!(environment.get_submodels()[0].get_submodel_element("List[0].Free").is_some());
+        assert_eq!(link_keys(&environment, "FreeLink"), vec![SUBMODEL_ID, "List", "0", "Free"]);
+        let keys = match environment.get_submodels()[0].get_submodel_element("FreeLink") {
+            Some(SubmodelElement::ReferenceElement(link)) => link.get_value().unwrap().get_keys().clone(),
+            _ => Vec::new()
+        };
+        assert!(*keys[2].get_key_type() == KeyType::SubmodelElementCollection);
+    }
+
+    #[test]
+    fn move_element_rejects_list_as_source_and_target() {
+        let mut environment = environment();
+        assert_eq!(environment.move_element(SUBMODEL_ID, "List[0]", SUBMODEL_ID, ""),
+                   Err(RefactoringError::InvalidPath("List[0]".to_string())));
+        assert_eq!(environment.move_element(SUBMODEL_ID, "Free", SUBMODEL_ID, "List"),
+                   Err(RefactoringError::InvalidTarget("List".to_string())));
+        assert_eq!(environment.move_element(SUBMODEL_ID, "List", SUBMODEL_ID, "List[0]"),
+                   Err(RefactoringError::InvalidTarget("List[0]".to_string())));
+    }
+    ///Creates an environment with a shell referencing the submodel of [environment] and another
+    /// submodel, and a relationship and an event in the submodel of [environment].
+    fn environment_with_shell() -> Environment {
+        let mut environment = environment();
+        let mut relationship = RelationshipElement::new();
+        relationship.set_id_short("Relationship".to_string());
+        relationship.set_first(model_reference(&[(KeyType::Submodel, SUBMODEL_ID), (KeyType::Property, "Free")]));
+        relationship.set_second(model_reference(&[(KeyType::Submodel, "urn:example:other"), (KeyType::Property, "Free")]));
+        let mut event = BasicEventElement::new(model_reference(&[(KeyType::Submodel, SUBMODEL_ID)]), Direction::Output,
+                                               StateOfEvent::On);
+        event.set_id_short("Event".to_string());
+        let external = Reference::new(ReferenceType::ExternalReference, vec![Key::new(KeyType::GlobalReference, SUBMODEL_ID.to_string())]);
+        let mut semantic_link = ReferenceElement::new();
+        semantic_link.set_id_short("ExternalLink".to_string());
+        semantic_link.set_value(external);
+        let submodel = &mut environment.get_mut_submodels()[0];
+        submodel.add_submodel_element(SubmodelElement::RelationshipElement(relationship));
+        submodel.add_submodel_element(SubmodelElement::BasicEventElement(event));
+        submodel.add_submodel_element(SubmodelElement::ReferenceElement(semantic_link));
+        let mut shell = AssetAdministrationShell::new("urn:example:aas".to_string(), AssetInformation::new(AssetKind::Type));
+        shell.add_submodel(model_reference(&[(KeyType::Submodel, SUBMODEL_ID)]));
+        shell.add_submodel(model_reference(&[(KeyType::Submodel, "urn:example:other")]));
+        environment.add_asset_administration_shell(shell);
+        environment.add_submodel(Submodel::new("urn:example:other".to_string()));
+        environment
+    }
+
+    fn first_key(reference: Option<&Reference>) -> String {
+        reference.and_then(|reference| reference.get_keys().first()).map(|key| key.get_value().clone()).unwrap_or_default()
+    }
+
+    #[test]
+    fn clone_with_new_ids_rewrites_model_references() {
+        let ids = vec!["urn:example:aas".to_string(), SUBMODEL_ID.to_string()];
+        let new_ids = vec!["urn:example:aas/2".to_string(), "urn:example:submodel/2".to_string()];
+        let cloned = environment_with_shell().clone_with_new_ids(&ids, &new_ids).unwrap();
+        assert_eq!(cloned.get_asset_administration_shells().len(), 1);
+        assert_eq!(cloned.get_submodels().len(), 1);
+        let shell = &cloned.get_asset_administration_shells()[0];
+        assert_eq!(shell.get_id(), "urn:example:aas/2");
+        let submodels: Vec<String> = shell.get_submodels().iter().map(|reference| first_key(Some(reference))).collect();
+        assert_eq!(submodels, vec!["urn:example:submodel/2", "urn:example:other"]);
+        let submodel = &cloned.get_submodels()[0];
+        assert_eq!(submodel.get_id(), "urn:example:submodel/2");
+        assert_eq!(link_keys(&cloned, "FreeLink"), vec!["urn:example:submodel/2", "Free"]);
+        assert_eq!(link_keys(&cloned, "InnerLink")[0], "urn:example:submodel/2");
+        match submodel.get_submodel_element("Relationship") {
+            Some(SubmodelElement::RelationshipElement(relationship)) => {
+                assert_eq!(first_key(relationship.get_first()), "urn:example:submodel/2");
+                assert_eq!(first_key(relationship.get_second()), "urn:example:other");
+            },
+            _ => panic!("no relationship")
+        }
+        match submodel.get_submodel_element("Event") {
+            Some(SubmodelElement::BasicEventElement(event)) => assert_eq!(first_key(Some(event.get_observed())), "urn:example:submodel/2"),
+            _ => panic!("no event")
+        }
+        assert_eq!(link_keys(&cloned, "ExternalLink"), vec![SUBMODEL_ID]);
+    }
+
+    #[test]
+    fn clone_with_new_ids_rejects_count_mismatch() {
+        let ids = vec!["urn:example:aas".to_string(), SUBMODEL_ID.to_string()];
+        let result = environment_with_shell().clone_with_new_ids(&ids, &["urn:example:aas/2".to_string()]);
+        assert!(result.err() == Some(RefactoringError::IdCountMismatch(2, 1)));
+    }
+
+    #[test]
+    fn clone_with_generated_ids_assigns_distinct_uuids() {
+        let ids = vec!["urn:example:aas".to_string(), SUBMODEL_ID.to_string(), "urn:example:other".to_string()];
+        let cloned = environment_with_shell().clone_with_generated_ids(&ids);
+        let shell = &cloned.get_asset_administration_shells()[0];
+        assert_eq!(first_key(shell.get_submodels().first()), *cloned.get_submodels()[0].get_id());
+        assert_eq!(first_key(shell.get_submodels().get(1)), *cloned.get_submodels()[1].get_id());
+        let mut generated: HashSet<String> = (0..100).map(|_| generate_id()).collect();
+        generated.insert(shell.get_id().clone());
+        cloned.get_submodels().iter().for_each(|submodel| {
+            generated.insert(submodel.get_id().clone());
+        });
+        assert_eq!(generated.len(), 103);
+        for id in &generated {
+            let uuid = id.strip_prefix("urn:uuid:").unwrap();
+            let groups: Vec<&str> = uuid.split('-').collect();
+            assert_eq!(groups.iter().map(|group| group.len()).collect::<Vec<_>>(), vec![8, 4, 4, 4, 12], "{}", id);
+            assert!(uuid.chars().all(|character| character == '-' || matches!(character, '0'..='9' | 'a'..='f')), "{}", id);
+            assert!(groups[2].starts_with('4'), "{}", id);
+            assert!(groups[3].starts_with(['8', '9', 'a', 'b']), "{}", id);
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,306 @@
+use std::cmp::Ordering;
+
+use crate::enumerations::environment_merge_error::EnvironmentMergeError;
+use crate::enumerations::id_conflict_policy::IdConflictPolicy;
+use crate::enumerations::key_type::KeyType;
+use crate::structs::administrative_information::AdministrativeInformation;
+use crate::structs::environment::Environment;
+use crate::traits::identifiable::TIdentifiable;
+
+///Result of merging several environments into one, together with a report of what happened to
+/// every identifiable.
+///
+/// Identifiables that are equal to an already merged identifiable with the same ID are never
+/// treated as conflicts but are deduplicated. This typically applies to concept descriptions that
+/// are shipped by several suppliers.
+#[derive(PartialEq, Clone)]
+pub struct EnvironmentMerge {
+    ///The merged environment.
+    merged: Environment,
+    ///IDs of the identifiables that were added without conflict.
+    added: Vec<String>,
+    ///IDs of the identifiables that were dropped because they were equal to an already merged one.
+    deduplicated: Vec<String>,
+    ///IDs of the identifiables whose later version was dropped in favour of the merged one.
+    skipped: Vec<String>,
+    ///IDs of the identifiables whose merged version was replaced by a newer one.
+    replaced: Vec<String>,
+    ///Pairs of old and new IDs of the identifiables that were renamed.
+    renamed: Vec<(String, String)>
+}
+
+impl EnvironmentMerge {
+    ///Merges the environments in the given order.
+    ///
+    /// [environments]: environments to merge
+    /// [policy]: handling of different identifiables with the same ID
+    pub fn merge(environments: Vec<Environment>, policy: IdConflictPolicy) -> Result<EnvironmentMerge, EnvironmentMergeError> {
+        let mut result = EnvironmentMerge {
+            merged: Environment::new(),
+            added: Vec::new(),
+            deduplicated: Vec::new(),
+            skipped: Vec::new(),
+            replaced: Vec::new(),
+            renamed: Vec::new()
+        };
+
+        for mut environment in environments {
+            if policy == IdConflictPolicy::Rename {
+                result.rename_conflicts(&mut environment);
+            }
+            for aas in environment.get_asset_administration_shells() {
+                let merged = result.merged.get_mut_asset_administration_shells();
+                let outcome = merge_identifiable(merged, aas.clone(), &policy)?;
+                result.record(outcome, aas.get_id());
+            }
+            for submodel in environment.get_submodels() {
+                let merged = result.merged.get_mut_submodels();
+                let outcome = merge_identifiable(merged, submodel.clone(), &policy)?;
+                result.record(outcome, submodel.get_id());
+            }
+            for concept_description in environment.get_concept_descriptions() {
+                let merged = result.merged.get_mut_concept_descriptions();
+                let outcome = merge_identifiable(merged, concept_description.clone(), &policy)?;
+                result.record(outcome, concept_description.get_id());
+            }
+        }
+        Ok(result)
+    }
+
+    ///Returns the merged environment.
+    pub fn get_merged(&self) -> &Environment {
+        &self.merged
+    }
+
+    ///Consumes the result and returns the merged environment.
+    pub fn into_merged(self) -> Environment {
+        self.merged
+    }
+
+    ///Returns the IDs of the identifiables that were added without conflict.
+    pub fn get_added(&self) -> &Vec<String> {
+        &self.added
+    }
+
+    ///Returns the IDs of the identifiables that were dropped because they were equal to an already
+    /// merged one.
+    pub fn get_deduplicated(&self) -> &Vec<String> {
+        &self.deduplicated
+    }
+
+    ///Returns the IDs of the identifiables whose later version was dropped in favour of the merged
+    /// one.
+    pub fn get_skipped(&self) -> &Vec<String> {
+        &self.skipped
+    }
+
+    ///Returns the IDs of the identifiables whose merged version was replaced by a newer one.
+    pub fn get_replaced(&self) -> &Vec<String> {
+        &self.replaced
+    }
+
+    ///Returns pairs of old and new IDs of the identifiables that were renamed.
+    pub fn get_renamed(&self) -> &Vec<(String, String)> {
+        &self.renamed
+    }
+
+    fn record(&mut self, outcome: Outcome, id: &str) {
+        let ids = match outcome {
+            Outcome::Added => &mut self.added,
+            Outcome::Deduplicated => &mut self.deduplicated,
+            Outcome::Skipped => &mut self.skipped,
+            Outcome::Replaced => &mut self.replaced
+        };
+        ids.push(id.to_string());
+    }
+
+    ///Assigns new IDs to all identifiables of the environment that conflict with a different,
+    /// already merged identifiable and rewrites the model references of the environment
+    /// accordingly. Rewriting the references can make an identifiable that equals the merged one
+    /// differ from it, e.g. a shell referencing a renamed submodel, so renaming is repeated until
+    /// no conflicts remain.
+    fn rename_conflicts(&mut self, environment: &mut Environment) {
+        let mut taken: Vec<String> = self.merged.get_asset_administration_shells().iter().map(|aas| aas.get_id())
+            .chain(self.merged.get_submodels().iter().map(|submodel| submodel.get_id()))
+            .chain(self.merged.get_concept_descriptions().iter().map(|cd| cd.get_id()))
+            .chain(environment.get_asset_administration_shells().iter().map(|aas| aas.get_id()))
+            .chain(environment.get_submodels().iter().map(|submodel| submodel.get_id()))
+            .chain(environment.get_concept_descriptions().iter().map(|cd| cd.get_id()))
+            .cloned()
+            .collect();
+        loop {
+            let mut renames: Vec<(KeyType, String, String)> = Vec::new();
+            let merged = &self.merged;
+            for aas in environment.get_mut_asset_administration_shells() {
+                if merged.get_asset_administration_shells().iter().any(|other| other.get_id() == aas.get_id() && other != aas) {
+                    let new_id = unique_id(aas.get_id(), &mut taken);
+                    renames.push((KeyType::AssetAdministrationShell, aas.get_id().clone(), new_id.clone()));
+                    aas.set_id(new_id);
+                }
+            }
+            for submodel in environment.get_mut_submodels() {
+                if merged.get_submodels().iter().any(|other| other.get_id() == submodel.get_id() && other != submodel) {
+                    let new_id = unique_id(submodel.get_id(), &mut taken);
+                    renames.push((KeyType::Submodel, submodel.get_id().clone(), new_id.clone()));
+                    submodel.set_id(new_id);
+                }
+            }
+            for concept_description in environment.get_mut_concept_descriptions() {
+                if merged.get_concept_descriptions().iter()
+                    .any(|other| other.get_id() == concept_description.get_id() && other != concept_description) {
+                    let new_id = unique_id(concept_description.get_id(), &mut taken);
+                    renames.push((KeyType::ConceptDescription, concept_description.get_id().clone(), new_id.clone()));
+                    concept_description.set_id(new_id);
+                }
+            }
+            if renames.is_empty() {
+                return;
+            }
+            environment.rewrite_model_references(&renames);
+            self.renamed.extend(renames.into_iter().map(|(_, old_id, new_id)| (old_id, new_id)));
+        }
+    }
+}
+
+///What happened to a single identifiable during the merge.
+enum Outcome {
+    Added,
+    Deduplicated,
+    Skipped,
+    Replaced
+}
+
+fn merge_identifiable<T: TIdentifiable + PartialEq>(merged: &mut Vec<T>, incoming: T, policy: &IdConflictPolicy)
+    -> Result<Outcome, EnvironmentMergeError> {
+    let Some(existing) = merged.iter_mut().find(|existing| existing.get_id() == incoming.get_id()) else {
+        merged.push(incoming);
+        return Ok(Outcome::Added);
+    };
+    if *existing == incoming {
+        return Ok(Outcome::Deduplicated);
+    }
+    match policy {
+        IdConflictPolicy::Fail => Err(EnvironmentMergeError::DuplicateId(incoming.get_id().clone())),
+        IdConflictPolicy::KeepNewest
+            if compare_administration(incoming.get_administration(), existing.get_administration()) == Ordering::Greater => {
+            *existing = incoming;
+            Ok(Outcome::Replaced)
+        },
+        _ => Ok(Outcome::Skipped)
+    }
+}
+
+///Compares two administrative information by version first and revision second. Missing
+/// information is considered older than any present information.
+fn compare_administration(first: Option<&AdministrativeInformation>, second: Option<&AdministrativeInformation>) -> Ordering {
+    let version = |administration: Option<&AdministrativeInformation>| administration.and_then(|a| a.get_version()).cloned();
+    let revision = |administration: Option<&AdministrativeInformation>| administration.and_then(|a| a.get_revision()).cloned();
+    compare_version(version(first), version(second))
+        .then_with(|| compare_version(revision(first), revision(second)))
+}
+
+///Compares two dotted version strings segment by segment, numerically where possible.
+fn compare_version(first: Option<String>, second: Option<String>) -> Ordering {
+    match (first, second) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (Some(first), Some(second)) => {
+            let mut first_segments = first.split('.');
+            let mut second_segments = second.split('.');
+            loop {
+                let ordering = match (first_segments.next(), second_segments.next()) {
+                    (None, None) => return Ordering::Equal,
+                    (None, Some(_)) => return Ordering::Less,
+                    (Some(_), None) => return Ordering::Greater,
+                    (Some(a), Some(b)) => match (a.parse::<u64>(), b.parse::<u64>()) {
+                        (Ok(a), Ok(b)) => a.cmp(&b),
+                        _ => a.cmp(b)
+                    }
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+        }
+    }
+}
+
+fn unique_id(id: &str, taken: &mut Vec<String>) -> String {
+    let mut counter = 1;
+    loop {
+        let candidate = format!("{}_{}", id, counter);
+        if !taken.contains(&candidate) {
+            taken.push(candidate.clone());
+            return candidate;
+        }
+        counter += 1;
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::enumerations::asset_kind::AssetKind;
+    use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+    use crate::enumerations::id_conflict_policy::IdConflictPolicy;
+    use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+    use crate::enumerations::key_type::KeyType;
+    use crate::enumerations::reference_type::ReferenceType;
+    use crate::structs::asset_administration_shell::AssetAdministrationShell;
+    use crate::structs::asset_information::AssetInformation;
+    use crate::structs::environment::Environment;
+    use crate::structs::environment_merge::EnvironmentMerge;
+    use crate::structs::key::Key;
+    use crate::structs::property::Property;
+    use crate::structs::reference::Reference;
+    use crate::structs::submodel::Submodel;
+    use crate::traits::referable::TReferable;
+
+    fn environment(value: &str) -> Environment {
+        let mut property = Property::new(DataTypeDefXsd::String);
+        property.set_id_short("Value".to_string());
+        property.set_value(value.to_string());
+        let mut submodel = Submodel::new("urn:example:submodel".to_string());
+        submodel.add_submodel_element(SubmodelElement::Property(property));
+        let mut aas = AssetAdministrationShell::new("urn:example:aas".to_string(), AssetInformation::new(AssetKind::Instance));
+        aas.add_submodel(Reference::new(ReferenceType::ModelReference,
+                                        vec![Key::new(KeyType::Submodel, "urn:example:submodel".to_string())]));
+        let mut environment = Environment::new();
+        environment.add_asset_administration_shell(aas);
+        environment.add_submodel(submodel);
+        environment
+    }
+
+    fn renamed(merge: &EnvironmentMerge) -> Vec<(&str, &str)> {
+        merge.get_renamed().iter().map(|(old_id, new_id)| (old_id.as_str(), new_id.as_str())).collect()
+    }
+
+    #[test]
+    fn deduplicates_equal_environments() {
+        let merge = EnvironmentMerge::merge(vec![environment("1"), environment("1")], IdConflictPolicy::Rename).unwrap();
+        assert_eq!(merge.get_deduplicated().len(), 2);
+        assert!(merge.get_renamed().is_empty());
+    }
+
+    #[test]
+    fn renames_shell_referencing_renamed_submodel() {
+        let merge = EnvironmentMerge::merge(vec![environment("1"), environment("2")], IdConflictPolicy::Rename).unwrap();
+        assert_eq!(renamed(&merge), vec![("urn:example:submodel", "urn:example:submodel_1"),
+                                         ("urn:example:aas", "urn:example:aas_1")]);
+        assert!(merge.get_skipped().is_empty());
+        let merged = merge.get_merged();
+        assert_eq!(merged.get_asset_administration_shells().len(), 2);
+        assert_eq!(merged.get_submodels().len(), 2);
+        let renamed_aas = &merged.get_asset_administration_shells()[1];
+        assert_eq!(renamed_aas.get_submodels()[0].get_keys()[0].get_value(), "urn:example:submodel_1");
+    }
+
+    #[test]
+    fn skips_conflicting_identifiables_with_keep_first() {
+        let merge = EnvironmentMerge::merge(vec![environment("1"), environment("2")], IdConflictPolicy::KeepFirst).unwrap();
+        assert_eq!(merge.get_deduplicated(), &vec!["urn:example:aas".to_string()]);
+        assert_eq!(merge.get_skipped(), &vec!["urn:example:submodel".to_string()]);
+    }
+}
\ No newline at end of file
@@ -8,24 +8,26 @@ pub struct EventPayload {
     ///Reference to the source event element.
     source: Reference,
     ///Optional semantic ID of the source event element if available.
-    #[serde(rename = "sourceSemanticId")]
+    #[serde(rename = "sourceSemanticId", default, skip_serializing_if = "Option::is_none")]
     source_semantic_id: Option<Reference>,
     ///Reference to the referable which defines the scope of the event.
     #[serde(rename = "observableReference")]
     observable_reference: Reference,
     ///Optional semantic ID of the referable which defines the scope of the event if available.
-    #[serde(rename = "observableSemanticId")]
+    #[serde(rename = "observableSemanticId", default, skip_serializing_if = "Option::is_none")]
     observable_semantic_id: Option<Reference>,
     ///Optional information for the outer message infrastructure to schedule the event for the respective
     ///communication channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     topic: Option<String>,
     ///Optional subject who/which initiated the creation.
-    #[serde(rename = "subjectId")]
+    #[serde(rename = "subjectId", default, skip_serializing_if = "Option::is_none")]
     subject_id: Option<Reference>,
     ///Timestamp in UTC when this event was triggered.
     #[serde(rename = "timeStamp")]
     time_stamp: String,
     ///Optional event-specific payload.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     payload: Vec<u8>
 }
 
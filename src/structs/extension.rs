@@ -10,16 +10,17 @@ pub struct Extension {
     ///The name of the extension.
     name: String,
     ///The optional data type of the value attribute of the extension.
-    #[serde(rename = "valueType")]
+    #[serde(rename = "valueType", default, skip_serializing_if = "Option::is_none")]
     value_type: Option<DataTypeDefXsd>,
     ///The optional value of the extension.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     value: Option<String>,
     ///List of references to an element the extension refers to.
-    #[serde(rename = "refersTo")]
+    #[serde(rename = "refersTo", default, skip_serializing_if = "Vec::is_empty")]
     refers_to: Vec<Reference>,
-    #[serde(rename = "semanticId")]
+    #[serde(rename = "semanticId", default, skip_serializing_if = "Option::is_none")]
     semantic_id: Option<Reference>,
-    #[serde(rename = "supplementalSemanticIds")]
+    #[serde(rename = "supplementalSemanticIds", default, skip_serializing_if = "Vec::is_empty")]
     supplemental_semantic_ids: Vec<Reference>
 }
 
@@ -112,6 +113,25 @@ impl Extension {
     pub fn remove_refers_to(&mut self, index: usize) -> Reference {
         self.refers_to.remove(index)
     }
+
+    ///Returns all references contained in the extension.
+    pub fn get_references(&self) -> Vec<&Reference> {
+        self.refers_to.iter()
+            .chain(self.semantic_id.iter())
+            .chain(self.supplemental_semantic_ids.iter())
+            .flat_map(|reference| reference.get_references())
+            .collect()
+    }
+
+    ///Calls the visitor for every reference contained in the extension.
+    ///
+    /// [visitor]: function called for every reference
+    pub fn visit_references_mut(&mut self, visitor: &mut dyn FnMut(&mut Reference)) {
+        self.refers_to.iter_mut()
+            .chain(self.semantic_id.iter_mut())
+            .chain(self.supplemental_semantic_ids.iter_mut())
+            .for_each(|reference| reference.visit_references_mut(visitor));
+    }
 }
 
 impl THasSemantics for Extension {
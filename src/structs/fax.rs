@@ -0,0 +1,105 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::multi_language_text_type::MultiLanguageTextType;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, TemplateElement};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on the fax of a contact information.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct Fax {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl Fax {
+    ///Semantic ID of a fax.
+    pub const SEMANTIC_ID: &str = "0173-1#02-AAQ834#005";
+
+    ///Creates a new fax.
+    ///
+    /// [fax_number]: complete fax number including the country and area code
+    pub fn new(fax_number: Vec<MultiLanguageTextType>) -> Fax {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_semantic_id(global_reference(Fax::SEMANTIC_ID));
+        let mut fax = Fax {
+            collection
+        };
+        fax.set_fax_number(fax_number);
+        fax
+    }
+
+    ///Wraps a submodel element collection after checking the elements of a fax.
+    ///
+    /// [collection]: collection containing a fax
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<Fax, ConversionError> {
+        Fax::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(Fax {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the fax and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the complete fax number including the country and area code.
+    pub fn get_fax_number(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(FAX_NUMBER.id_short, FAX_NUMBER.semantic_id)
+    }
+
+    ///Sets the complete fax number including the country and area code.
+    ///
+    /// [fax_number]: complete fax number including the country and area code
+    pub fn set_fax_number(&mut self, fax_number: Vec<MultiLanguageTextType>) {
+        self.collection.set_multi_language_value(FAX_NUMBER.id_short, FAX_NUMBER.semantic_id, Some(fax_number));
+    }
+
+    ///Returns the type of the fax number, e.g. office.
+    pub fn get_type_of_fax_number(&self) -> Option<String> {
+        self.collection.get_property_value(TYPE_OF_FAX_NUMBER.id_short, TYPE_OF_FAX_NUMBER.semantic_id)
+    }
+
+    ///Sets the type of the fax number, e.g. office. Passing None removes it.
+    ///
+    /// [type_of_fax_number]: type of the fax number, e.g. office
+    pub fn set_type_of_fax_number(&mut self, type_of_fax_number: Option<String>) {
+        TYPE_OF_FAX_NUMBER.set_property_value(&mut self.collection, type_of_fax_number.as_ref());
+    }
+
+    ///Returns the elements of a fax in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        ELEMENTS.iter().map(|element| element.to_element()).collect()
+    }
+
+    ///Checks the elements of a fax.
+    ///
+    /// [collection]: collection containing a fax
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        Ok(())
+    }
+}
+
+const FAX_NUMBER: TemplateElement =
+    TemplateElement::new("FaxNumber", "0173-1#02-AAO195#002", AasSubmodelElements::MultiLanguageProperty, Cardinality::One);
+const TYPE_OF_FAX_NUMBER: TemplateElement =
+    TemplateElement::property("TypeOfFaxNumber", "0173-1#02-AAO196#003", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+
+const ELEMENTS: [&TemplateElement; 2] = [&FAX_NUMBER, &TYPE_OF_FAX_NUMBER];
@@ -0,0 +1,142 @@
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::asset_interface::{add_terms, get_term, set_term, terms_to_json};
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, TemplateElement, ARBITRARY};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+use serde_json::{Map, Value};
+
+///Typed view on the form of an interaction affordance of an asset interface, which states how the
+/// affordance is accessed, i.e. the target, the content type and protocol specific terms like
+/// `htv:methodName` or `modv:function`.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct Form {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl Form {
+    ///Semantic ID of a form.
+    pub const SEMANTIC_ID: &str = "https://www.w3.org/2019/wot/td#hasForm";
+    ///idShort of a form.
+    pub const ID_SHORT: &str = "forms";
+
+    ///Creates a new form.
+    ///
+    /// [href]: target of the form, relative to the base URI of the interface or absolute
+    pub fn new(href: String) -> Form {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_id_short(Form::ID_SHORT.to_string());
+        collection.set_semantic_id(global_reference(Form::SEMANTIC_ID));
+        let mut form = Form {
+            collection
+        };
+        form.set_href(href);
+        form
+    }
+
+    ///Wraps a submodel element collection after checking the elements of a form.
+    ///
+    /// [collection]: collection containing a form
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<Form, ConversionError> {
+        Form::check(&collection, Form::ID_SHORT)?;
+        Ok(Form {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the form and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the target of the form.
+    pub fn get_href(&self) -> Option<String> {
+        self.collection.get_property_value(HREF.id_short, HREF.semantic_id)
+    }
+
+    ///Sets the target of the form.
+    ///
+    /// [href]: target of the form, relative to the base URI of the interface or absolute
+    pub fn set_href(&mut self, href: String) {
+        HREF.set_property_value(&mut self.collection, Some(&href));
+    }
+
+    ///Returns the content type of the payload, e.g. `application/json`.
+    pub fn get_content_type(&self) -> Option<String> {
+        self.collection.get_property_value(CONTENT_TYPE.id_short, CONTENT_TYPE.semantic_id)
+    }
+
+    ///Sets the content type of the payload. Passing None removes it, so the content type of the
+    /// endpoint metadata applies.
+    ///
+    /// [content_type]: content type of the payload, e.g. `application/json`
+    pub fn set_content_type(&mut self, content_type: Option<String>) {
+        CONTENT_TYPE.set_property_value(&mut self.collection, content_type.as_ref());
+    }
+
+    ///Returns the value of a term of the form as JSON, e.g. `"GET"` for `htv:methodName` or
+    /// `["readproperty"]` for `op`.
+    ///
+    /// [term]: term of the Thing Description, protocol specific terms with prefix
+    pub fn get_term(&self, term: &str) -> Option<Value> {
+        get_term(&self.collection, term)
+    }
+
+    ///Sets the value of a term of the form. Passing None removes it.
+    ///
+    /// [term]: term of the Thing Description, protocol specific terms with prefix
+    /// [value]: JSON value of the term
+    pub fn set_term(&mut self, term: &str, value: Option<&Value>) {
+        set_term(&mut self.collection, term, value);
+    }
+
+    ///Returns the form as JSON object of a Thing Description.
+    pub(crate) fn to_json(&self) -> Map<String, Value> {
+        terms_to_json(&self.collection, &[])
+    }
+
+    ///Creates a form from the JSON object of a Thing Description.
+    ///
+    /// [href]: target of the form
+    /// [json]: JSON object of the form
+    pub(crate) fn from_json(href: String, json: &Map<String, Value>) -> Form {
+        let mut form = Form::new(href);
+        add_terms(&mut form.collection, json, &["href"]);
+        form
+    }
+
+    ///Returns the elements of a form in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        vec![HREF.to_element(), CONTENT_TYPE.to_element(), ARBITRARY.to_element()]
+    }
+
+    ///Checks the elements of a form.
+    ///
+    /// [collection]: collection containing a form
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        Ok(())
+    }
+}
+
+const HREF: TemplateElement =
+    TemplateElement::property("href", "https://www.w3.org/2019/wot/hypermedia#hasTarget", DataTypeDefXsd::AnyUri, Cardinality::One);
+const CONTENT_TYPE: TemplateElement =
+    TemplateElement::property("contentType", "https://www.w3.org/2019/wot/hypermedia#forContentType", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+
+const ELEMENTS: [&TemplateElement; 2] = [&HREF, &CONTENT_TYPE];
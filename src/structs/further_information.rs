@@ -0,0 +1,116 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::multi_language_property::MultiLanguageProperty;
+use crate::structs::multi_language_text_type::MultiLanguageTextType;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, TemplateElement};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on the further information of the Technical Data.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct FurtherInformation {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl FurtherInformation {
+    ///Semantic ID of the further information.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/ZVEI/TechnicalData/FurtherInformation/1/1";
+
+    ///Creates new further information.
+    ///
+    /// [valid_date]: date the technical data is valid from, as xs:date
+    pub fn new(valid_date: String) -> FurtherInformation {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_semantic_id(global_reference(FurtherInformation::SEMANTIC_ID));
+        let mut further_information = FurtherInformation {
+            collection
+        };
+        further_information.set_valid_date(valid_date);
+        further_information
+    }
+
+    ///Wraps a submodel element collection after checking the elements of the further information.
+    ///
+    /// [collection]: collection containing the further information
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<FurtherInformation, ConversionError> {
+        FurtherInformation::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(FurtherInformation {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the further information and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the statements on the technical data, e.g. on its validity.
+    pub fn get_text_statements(&self) -> Vec<&Vec<MultiLanguageTextType>> {
+        self.collection.find_elements(TEXT_STATEMENT.id_short, TEXT_STATEMENT.semantic_id).into_iter()
+            .filter_map(|element| match element {
+                SubmodelElement::MultiLanguageProperty(property) => Some(property.get_value()),
+                _ => None
+            })
+            .collect()
+    }
+
+    ///Sets the statements on the technical data, e.g. on its validity.
+    ///
+    /// [text_statements]: statements on the technical data, e.g. on its validity
+    pub fn set_text_statements(&mut self, text_statements: Vec<Vec<MultiLanguageTextType>>) {
+        let elements = text_statements.into_iter().map(|value| {
+            let mut property = MultiLanguageProperty::new();
+            property.set_value(value);
+            SubmodelElement::MultiLanguageProperty(property)
+        }).collect();
+        self.collection.set_elements(TEXT_STATEMENT.id_short, TEXT_STATEMENT.semantic_id, elements);
+    }
+
+    ///Returns the date the technical data is valid from, as xs:date.
+    pub fn get_valid_date(&self) -> Option<String> {
+        self.collection.get_property_value(VALID_DATE.id_short, VALID_DATE.semantic_id)
+    }
+
+    ///Sets the date the technical data is valid from, as xs:date.
+    ///
+    /// [valid_date]: date the technical data is valid from, as xs:date
+    pub fn set_valid_date(&mut self, valid_date: String) {
+        VALID_DATE.set_property_value(&mut self.collection, Some(&valid_date));
+    }
+
+    ///Returns the elements of the further information in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        ELEMENTS.iter().map(|element| element.to_element()).collect()
+    }
+
+    ///Checks the elements of the further information.
+    ///
+    /// [collection]: collection containing the further information
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        Ok(())
+    }
+}
+
+const TEXT_STATEMENT: TemplateElement =
+    TemplateElement::new("TextStatement{00}", "https://admin-shell.io/ZVEI/TechnicalData/TextStatement/1/1", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToMany);
+const VALID_DATE: TemplateElement =
+    TemplateElement::property("ValidDate", "https://admin-shell.io/ZVEI/TechnicalData/ValidDate/1/1", DataTypeDefXsd::Date, Cardinality::One);
+
+const ELEMENTS: [&TemplateElement; 2] = [&TEXT_STATEMENT, &VALID_DATE];
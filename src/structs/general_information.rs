@@ -0,0 +1,176 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::file::File;
+use crate::structs::multi_language_text_type::MultiLanguageTextType;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, TemplateElement};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on the general information of the Technical Data, which identifies the manufacturer
+/// and the product.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct GeneralInformation {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl GeneralInformation {
+    ///Semantic ID of the general information.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/ZVEI/TechnicalData/GeneralInformation/1/1";
+
+    ///Creates new general information.
+    ///
+    /// [manufacturer_name]: legally valid designation of the manufacturer
+    /// [manufacturer_product_designation]: short description of the product
+    /// [manufacturer_article_number]: article number of the product assigned by the manufacturer
+    /// [manufacturer_order_code]: order code of the product assigned by the manufacturer
+    pub fn new(manufacturer_name: String, manufacturer_product_designation: Vec<MultiLanguageTextType>,
+               manufacturer_article_number: String, manufacturer_order_code: String) -> GeneralInformation {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_semantic_id(global_reference(GeneralInformation::SEMANTIC_ID));
+        let mut general_information = GeneralInformation {
+            collection
+        };
+        general_information.set_manufacturer_name(manufacturer_name);
+        general_information.set_manufacturer_product_designation(manufacturer_product_designation);
+        general_information.set_manufacturer_article_number(manufacturer_article_number);
+        general_information.set_manufacturer_order_code(manufacturer_order_code);
+        general_information
+    }
+
+    ///Wraps a submodel element collection after checking the elements of the general information.
+    ///
+    /// [collection]: collection containing the general information
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<GeneralInformation, ConversionError> {
+        GeneralInformation::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(GeneralInformation {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the general information and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the legally valid designation of the manufacturer.
+    pub fn get_manufacturer_name(&self) -> Option<String> {
+        self.collection.get_property_value(MANUFACTURER_NAME.id_short, MANUFACTURER_NAME.semantic_id)
+    }
+
+    ///Sets the legally valid designation of the manufacturer.
+    ///
+    /// [manufacturer_name]: legally valid designation of the manufacturer
+    pub fn set_manufacturer_name(&mut self, manufacturer_name: String) {
+        MANUFACTURER_NAME.set_property_value(&mut self.collection, Some(&manufacturer_name));
+    }
+
+    ///Returns the image file of the logo of the manufacturer.
+    pub fn get_manufacturer_logo(&self) -> Option<&File> {
+        self.collection.get_file(MANUFACTURER_LOGO.id_short, MANUFACTURER_LOGO.semantic_id)
+    }
+
+    ///Sets the image file of the logo of the manufacturer. Passing None removes it.
+    ///
+    /// [manufacturer_logo]: image file of the logo of the manufacturer
+    pub fn set_manufacturer_logo(&mut self, manufacturer_logo: Option<File>) {
+        self.collection.set_element(MANUFACTURER_LOGO.id_short, MANUFACTURER_LOGO.semantic_id, manufacturer_logo.map(SubmodelElement::File));
+    }
+
+    ///Returns the short description of the product.
+    pub fn get_manufacturer_product_designation(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(MANUFACTURER_PRODUCT_DESIGNATION.id_short, MANUFACTURER_PRODUCT_DESIGNATION.semantic_id)
+    }
+
+    ///Sets the short description of the product.
+    ///
+    /// [manufacturer_product_designation]: short description of the product
+    pub fn set_manufacturer_product_designation(&mut self, manufacturer_product_designation: Vec<MultiLanguageTextType>) {
+        self.collection.set_multi_language_value(MANUFACTURER_PRODUCT_DESIGNATION.id_short, MANUFACTURER_PRODUCT_DESIGNATION.semantic_id, Some(manufacturer_product_designation));
+    }
+
+    ///Returns the article number of the product assigned by the manufacturer.
+    pub fn get_manufacturer_article_number(&self) -> Option<String> {
+        self.collection.get_property_value(MANUFACTURER_ARTICLE_NUMBER.id_short, MANUFACTURER_ARTICLE_NUMBER.semantic_id)
+    }
+
+    ///Sets the article number of the product assigned by the manufacturer.
+    ///
+    /// [manufacturer_article_number]: article number of the product assigned by the manufacturer
+    pub fn set_manufacturer_article_number(&mut self, manufacturer_article_number: String) {
+        MANUFACTURER_ARTICLE_NUMBER.set_property_value(&mut self.collection, Some(&manufacturer_article_number));
+    }
+
+    ///Returns the order code of the product assigned by the manufacturer.
+    pub fn get_manufacturer_order_code(&self) -> Option<String> {
+        self.collection.get_property_value(MANUFACTURER_ORDER_CODE.id_short, MANUFACTURER_ORDER_CODE.semantic_id)
+    }
+
+    ///Sets the order code of the product assigned by the manufacturer.
+    ///
+    /// [manufacturer_order_code]: order code of the product assigned by the manufacturer
+    pub fn set_manufacturer_order_code(&mut self, manufacturer_order_code: String) {
+        MANUFACTURER_ORDER_CODE.set_property_value(&mut self.collection, Some(&manufacturer_order_code));
+    }
+
+    ///Returns the image files of the product.
+    pub fn get_product_images(&self) -> Vec<&File> {
+        self.collection.find_elements(PRODUCT_IMAGE.id_short, PRODUCT_IMAGE.semantic_id).into_iter()
+            .filter_map(|element| match element {
+                SubmodelElement::File(file) => Some(file),
+                _ => None
+            })
+            .collect()
+    }
+
+    ///Sets the image files of the product.
+    ///
+    /// [product_images]: image files of the product
+    pub fn set_product_images(&mut self, product_images: Vec<File>) {
+        self.collection.set_elements(PRODUCT_IMAGE.id_short, PRODUCT_IMAGE.semantic_id, product_images.into_iter().map(SubmodelElement::File).collect());
+    }
+
+    ///Returns the elements of the general information in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        ELEMENTS.iter().map(|element| element.to_element()).collect()
+    }
+
+    ///Checks the elements of the general information.
+    ///
+    /// [collection]: collection containing the general information
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        Ok(())
+    }
+}
+
+const MANUFACTURER_NAME: TemplateElement =
+    TemplateElement::property("ManufacturerName", "0173-1#02-AAO677#002", DataTypeDefXsd::String, Cardinality::One);
+const MANUFACTURER_LOGO: TemplateElement =
+    TemplateElement::new("ManufacturerLogo", "https://admin-shell.io/ZVEI/TechnicalData/ManufacturerLogo/1/1", AasSubmodelElements::File, Cardinality::ZeroToOne);
+const MANUFACTURER_PRODUCT_DESIGNATION: TemplateElement =
+    TemplateElement::new("ManufacturerProductDesignation", "0173-1#02-AAW338#001", AasSubmodelElements::MultiLanguageProperty, Cardinality::One);
+const MANUFACTURER_ARTICLE_NUMBER: TemplateElement =
+    TemplateElement::property("ManufacturerArticleNumber", "0173-1#02-AAO676#003", DataTypeDefXsd::String, Cardinality::One);
+const MANUFACTURER_ORDER_CODE: TemplateElement =
+    TemplateElement::property("ManufacturerOrderCode", "0173-1#02-AAO227#002", DataTypeDefXsd::String, Cardinality::One);
+const PRODUCT_IMAGE: TemplateElement =
+    TemplateElement::new("ProductImage{00}", "https://admin-shell.io/ZVEI/TechnicalData/ProductImage/1/1", AasSubmodelElements::File, Cardinality::ZeroToMany);
+
+const ELEMENTS: [&TemplateElement; 6] = [&MANUFACTURER_NAME, &MANUFACTURER_LOGO, &MANUFACTURER_PRODUCT_DESIGNATION,
+    &MANUFACTURER_ARTICLE_NUMBER, &MANUFACTURER_ORDER_CODE, &PRODUCT_IMAGE];
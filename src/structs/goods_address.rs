@@ -0,0 +1,171 @@
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::TemplateElement;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on an address of the Carbon Footprint at which goods are handed over or taken over.
+/// The address gets the semantic ID of the element it is set as.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct GoodsAddress {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl GoodsAddress {
+    ///Creates a new, empty goods address.
+    pub fn new() -> GoodsAddress {
+        GoodsAddress {
+            collection: SubmodelElementCollection::new()
+        }
+    }
+
+    ///Wraps a submodel element collection after checking the elements of a goods address.
+    ///
+    /// [collection]: collection containing a goods address
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<GoodsAddress, ConversionError> {
+        GoodsAddress::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(GoodsAddress {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the goods address and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the street name.
+    pub fn get_street(&self) -> Option<String> {
+        self.collection.get_property_value(STREET.id_short, STREET.semantic_id)
+    }
+
+    ///Sets the street name. Passing None removes it.
+    ///
+    /// [street]: street name
+    pub fn set_street(&mut self, street: Option<String>) {
+        STREET.set_property_value(&mut self.collection, street.as_ref());
+    }
+
+    ///Returns the house number.
+    pub fn get_house_number(&self) -> Option<String> {
+        self.collection.get_property_value(HOUSE_NUMBER.id_short, HOUSE_NUMBER.semantic_id)
+    }
+
+    ///Sets the house number. Passing None removes it.
+    ///
+    /// [house_number]: house number
+    pub fn set_house_number(&mut self, house_number: Option<String>) {
+        HOUSE_NUMBER.set_property_value(&mut self.collection, house_number.as_ref());
+    }
+
+    ///Returns the ZIP code.
+    pub fn get_zip_code(&self) -> Option<String> {
+        self.collection.get_property_value(ZIP_CODE.id_short, ZIP_CODE.semantic_id)
+    }
+
+    ///Sets the ZIP code. Passing None removes it.
+    ///
+    /// [zip_code]: ZIP code
+    pub fn set_zip_code(&mut self, zip_code: Option<String>) {
+        ZIP_CODE.set_property_value(&mut self.collection, zip_code.as_ref());
+    }
+
+    ///Returns the town or city.
+    pub fn get_city_town(&self) -> Option<String> {
+        self.collection.get_property_value(CITY_TOWN.id_short, CITY_TOWN.semantic_id)
+    }
+
+    ///Sets the town or city. Passing None removes it.
+    ///
+    /// [city_town]: town or city
+    pub fn set_city_town(&mut self, city_town: Option<String>) {
+        CITY_TOWN.set_property_value(&mut self.collection, city_town.as_ref());
+    }
+
+    ///Returns the country.
+    pub fn get_country(&self) -> Option<String> {
+        self.collection.get_property_value(COUNTRY.id_short, COUNTRY.semantic_id)
+    }
+
+    ///Sets the country. Passing None removes it.
+    ///
+    /// [country]: country
+    pub fn set_country(&mut self, country: Option<String>) {
+        COUNTRY.set_property_value(&mut self.collection, country.as_ref());
+    }
+
+    ///Returns the latitude in degrees.
+    pub fn get_latitude(&self) -> Option<f64> {
+        self.collection.get_property_value(LATITUDE.id_short, LATITUDE.semantic_id)
+    }
+
+    ///Sets the latitude in degrees. Passing None removes it.
+    ///
+    /// [latitude]: latitude in degrees
+    pub fn set_latitude(&mut self, latitude: Option<f64>) {
+        LATITUDE.set_property_value(&mut self.collection, latitude.as_ref());
+    }
+
+    ///Returns the longitude in degrees.
+    pub fn get_longitude(&self) -> Option<f64> {
+        self.collection.get_property_value(LONGITUDE.id_short, LONGITUDE.semantic_id)
+    }
+
+    ///Sets the longitude in degrees. Passing None removes it.
+    ///
+    /// [longitude]: longitude in degrees
+    pub fn set_longitude(&mut self, longitude: Option<f64>) {
+        LONGITUDE.set_property_value(&mut self.collection, longitude.as_ref());
+    }
+
+    ///Returns the elements of a goods address in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        ELEMENTS.iter().map(|element| element.to_element()).collect()
+    }
+
+    ///Checks the elements of a goods address.
+    ///
+    /// [collection]: collection containing a goods address
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for GoodsAddress {
+    fn default() -> GoodsAddress {
+        GoodsAddress::new()
+    }
+}
+
+const STREET: TemplateElement =
+    TemplateElement::property("Street", "0173-1#02-ABH956#001", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const HOUSE_NUMBER: TemplateElement =
+    TemplateElement::property("HouseNumber", "0173-1#02-ABH957#001", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const ZIP_CODE: TemplateElement =
+    TemplateElement::property("ZipCode", "0173-1#02-ABH958#001", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const CITY_TOWN: TemplateElement =
+    TemplateElement::property("CityTown", "0173-1#02-ABH959#001", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const COUNTRY: TemplateElement =
+    TemplateElement::property("Country", "0173-1#02-ABH960#001", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const LATITUDE: TemplateElement =
+    TemplateElement::property("Latitude", "0173-1#02-ABH961#001", DataTypeDefXsd::Double, Cardinality::ZeroToOne);
+const LONGITUDE: TemplateElement =
+    TemplateElement::property("Longitude", "0173-1#02-ABH962#001", DataTypeDefXsd::Double, Cardinality::ZeroToOne);
+
+const ELEMENTS: [&TemplateElement; 7] = [&STREET, &HOUSE_NUMBER, &ZIP_CODE, &CITY_TOWN, &COUNTRY, &LATITUDE,
+    &LONGITUDE];
@@ -0,0 +1,91 @@
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, ARBITRARY, TemplateElement};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on the asset specific properties of the Digital Nameplate that a guideline for the
+/// declaration of conformity requires. Apart from the guideline, the properties are arbitrary
+/// elements of the wrapped collection.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct GuidelineSpecificProperties {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl GuidelineSpecificProperties {
+    ///Semantic ID of guideline specific properties.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/idta/nameplate/3/0/GuidelineSpecificProperties";
+
+    ///Creates new guideline specific properties.
+    ///
+    /// [guideline_for_conformity_declaration]: guideline, e.g. a directive, the declaration of conformity refers to
+    pub fn new(guideline_for_conformity_declaration: String) -> GuidelineSpecificProperties {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_semantic_id(global_reference(GuidelineSpecificProperties::SEMANTIC_ID));
+        let mut properties = GuidelineSpecificProperties {
+            collection
+        };
+        properties.set_guideline_for_conformity_declaration(guideline_for_conformity_declaration);
+        properties
+    }
+
+    ///Wraps a submodel element collection after checking the elements of guideline specific properties.
+    ///
+    /// [collection]: collection containing guideline specific properties
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<GuidelineSpecificProperties, ConversionError> {
+        GuidelineSpecificProperties::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(GuidelineSpecificProperties {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the guideline specific properties and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the guideline, e.g. a directive, the declaration of conformity refers to.
+    pub fn get_guideline_for_conformity_declaration(&self) -> Option<String> {
+        self.collection.get_property_value(GUIDELINE_FOR_CONFORMITY_DECLARATION.id_short, GUIDELINE_FOR_CONFORMITY_DECLARATION.semantic_id)
+    }
+
+    ///Sets the guideline, e.g. a directive, the declaration of conformity refers to.
+    ///
+    /// [guideline_for_conformity_declaration]: guideline, e.g. a directive, the declaration of conformity refers to
+    pub fn set_guideline_for_conformity_declaration(&mut self, guideline_for_conformity_declaration: String) {
+        GUIDELINE_FOR_CONFORMITY_DECLARATION.set_property_value(&mut self.collection, Some(&guideline_for_conformity_declaration));
+    }
+
+    ///Returns the elements of guideline specific properties in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        ELEMENTS.iter().copied().chain([&ARBITRARY]).map(|element| element.to_element()).collect()
+    }
+
+    ///Checks the elements of guideline specific properties.
+    ///
+    /// [collection]: collection containing guideline specific properties
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        Ok(())
+    }
+}
+
+const GUIDELINE_FOR_CONFORMITY_DECLARATION: TemplateElement =
+    TemplateElement::property("GuidelineForConformityDeclaration", "0173-1#02-AAO856#002", DataTypeDefXsd::String, Cardinality::One);
+
+const ELEMENTS: [&TemplateElement; 1] = [&GUIDELINE_FOR_CONFORMITY_DECLARATION];
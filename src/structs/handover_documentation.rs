@@ -0,0 +1,157 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::file_resolution_error::FileResolutionError;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::modelling_kind::ModellingKind;
+use crate::enumerations::vdi_2770_class::Vdi2770Class;
+use crate::structs::conformance_report::ConformanceReport;
+use crate::structs::document::Document;
+use crate::structs::submodel::Submodel;
+use crate::structs::template_element::{global_reference, TemplateElement};
+use crate::traits::file_store::TFileStore;
+use crate::traits::has_kind::THasKind;
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed model of the submodel template Handover Documentation (IDTA 02004-1-2), which contains
+/// the documents of an asset according to VDI 2770. The files of the documents can be read from an
+/// AASX package or another file store.
+///
+/// The model wraps the submodel, so the conversion from and to a submodel is lossless and elements
+/// the model does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct HandoverDocumentation {
+    ///The wrapped submodel.
+    submodel: Submodel
+}
+
+impl HandoverDocumentation {
+    ///Semantic ID of the Handover Documentation submodel.
+    pub const SEMANTIC_ID: &str = "0173-1#01-AHF578#001";
+    ///idShort of the Handover Documentation submodel.
+    pub const ID_SHORT: &str = "HandoverDocumentation";
+
+    ///Creates a new Handover Documentation submodel without documents.
+    ///
+    /// [id]: global identifier of the submodel
+    pub fn new(id: String) -> HandoverDocumentation {
+        let mut submodel = Submodel::new(id);
+        submodel.set_id_short(HandoverDocumentation::ID_SHORT.to_string());
+        submodel.set_semantic_id(global_reference(HandoverDocumentation::SEMANTIC_ID));
+        submodel.set_kind(ModellingKind::Instance);
+        HandoverDocumentation {
+            submodel
+        }
+    }
+
+    ///Wraps a submodel after checking that it contains the mandatory elements of the Handover Documentation
+    /// with the expected types and valid values.
+    ///
+    /// [submodel]: Handover Documentation submodel
+    pub fn from_submodel(submodel: Submodel) -> Result<HandoverDocumentation, ConversionError> {
+        HandoverDocumentation::check(&submodel)?;
+        Ok(HandoverDocumentation {
+            submodel
+        })
+    }
+
+    ///Returns the wrapped submodel.
+    pub fn get_submodel(&self) -> &Submodel {
+        &self.submodel
+    }
+
+    ///Consumes the Handover Documentation and returns the wrapped submodel.
+    pub fn into_submodel(self) -> Submodel {
+        self.submodel
+    }
+
+    ///Returns the submodel template of the Handover Documentation.
+    pub fn template() -> Submodel {
+        let mut template = Submodel::new("https://admin-shell.io/idta/SubmodelTemplate/HandoverDocumentation/1/2".to_string());
+        template.set_id_short(HandoverDocumentation::ID_SHORT.to_string());
+        template.set_semantic_id(global_reference(HandoverDocumentation::SEMANTIC_ID));
+        template.set_kind(ModellingKind::Template);
+        template.set_submodel_elements(vec![
+            DOCUMENT.to_collection(Document::template_elements())
+        ]);
+        template
+    }
+
+    ///Checks the wrapped submodel against the submodel template of the Handover Documentation.
+    pub fn check_conformance(&self) -> ConformanceReport {
+        self.submodel.check_conformance(&HandoverDocumentation::template())
+    }
+
+    ///Returns the documents handed over with the asset.
+    pub fn get_documents(&self) -> Vec<Document> {
+        self.submodel.get_collections(DOCUMENT.id_short, DOCUMENT.semantic_id).into_iter()
+            .map(|collection| Document {
+                collection: collection.clone()
+            })
+            .collect()
+    }
+
+    ///Sets the documents handed over with the asset.
+    ///
+    /// [documents]: documents handed over with the asset
+    pub fn set_documents(&mut self, documents: Vec<Document>) {
+        let elements = documents.into_iter()
+            .map(|item| SubmodelElement::SubmodelElementCollection(item.into_collection()))
+            .collect();
+        self.submodel.set_elements(DOCUMENT.id_short, DOCUMENT.semantic_id, elements);
+    }
+
+    ///Adds a document, which gets the next free idShort.
+    ///
+    /// [document]: document to add
+    pub fn add_document(&mut self, document: Document) {
+        let mut documents = self.get_documents();
+        documents.push(document);
+        self.set_documents(documents);
+    }
+
+    ///Returns the documents of a VDI 2770 class.
+    ///
+    /// [class]: VDI 2770 class of the documents
+    pub fn get_documents_of_class(&self, class: &Vdi2770Class) -> Vec<Document> {
+        self.get_documents().into_iter().filter(|document| document.get_vdi_2770_classes().contains(class)).collect()
+    }
+
+    ///Checks that the files of all document versions can be read from the file store. Returns the
+    /// error of the first file that cannot be read.
+    ///
+    /// [store]: file store containing the files, e.g. an AASX package
+    pub fn check_files(&self, store: &dyn TFileStore) -> Result<(), FileResolutionError> {
+        for document in self.get_documents() {
+            for version in document.get_document_versions() {
+                for file in version.get_digital_files().into_iter().chain(version.get_preview_file()) {
+                    match file.get_value() {
+                        Some(path) if !store.contains_file(path) => return Err(FileResolutionError::FileNotFound(path.clone())),
+                        Some(_) => {},
+                        None => return Err(FileResolutionError::MissingPath)
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check(submodel: &Submodel) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(submodel, "")?;
+        }
+        for element in DOCUMENT.check(submodel, "")? {
+            if let SubmodelElement::SubmodelElementCollection(collection) = element {
+                Document::check(collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+const DOCUMENT: TemplateElement =
+    TemplateElement::new("Document{00}", Document::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToMany);
+
+const ELEMENTS: [&TemplateElement; 1] = [&DOCUMENT];
@@ -0,0 +1,251 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::arche_type::ArcheType;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::entity_type::EntityType;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::key_type::KeyType;
+use crate::enumerations::modelling_kind::ModellingKind;
+use crate::enumerations::reference_type::ReferenceType;
+use crate::structs::conformance_report::ConformanceReport;
+use crate::structs::entity::Entity;
+use crate::structs::key::Key;
+use crate::structs::reference::Reference;
+use crate::structs::relationship_element::RelationshipElement;
+use crate::structs::submodel::Submodel;
+use crate::structs::template_element::{global_reference, TemplateElement, ARBITRARY};
+use crate::traits::has_kind::THasKind;
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::identifiable::TIdentifiable;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed model of the submodel template Hierarchical Structures enabling Bills of Material (IDTA
+/// 02011-1-1), which models the part structure of an asset as entities starting at an entry node.
+/// The structure is expressed by `HasPart`, `IsPartOf` and `SameAs` relationships among the
+/// statements of the entities. Use `BomGraph` to traverse the structure across submodels.
+///
+/// The model wraps the submodel, so the conversion from and to a submodel is lossless and elements
+/// the model does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct HierarchicalStructures {
+    ///The wrapped submodel.
+    submodel: Submodel
+}
+
+impl HierarchicalStructures {
+    ///Semantic ID of the Hierarchical Structures submodel.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/idta/HierarchicalStructures/1/1/Submodel";
+    ///idShort of the Hierarchical Structures submodel.
+    pub const ID_SHORT: &str = "HierarchicalStructures";
+    ///Semantic ID of the entities below the entry node.
+    pub const NODE_SEMANTIC_ID: &str = "https://admin-shell.io/idta/HierarchicalStructures/Node/1/0";
+    ///Semantic ID of the relationships whose first entity has the second entity as part.
+    pub const HAS_PART_SEMANTIC_ID: &str = "https://admin-shell.io/idta/HierarchicalStructures/HasPart/1/0";
+    ///Semantic ID of the relationships whose first entity is part of the second entity.
+    pub const IS_PART_OF_SEMANTIC_ID: &str = "https://admin-shell.io/idta/HierarchicalStructures/IsPartOf/1/0";
+    ///Semantic ID of the relationships between two entities standing for the same asset.
+    pub const SAME_AS_SEMANTIC_ID: &str = "https://admin-shell.io/idta/HierarchicalStructures/SameAs/1/0";
+    ///Semantic ID of the property stating how often an entity occurs in its parent.
+    pub const BULK_COUNT_SEMANTIC_ID: &str = "https://admin-shell.io/idta/HierarchicalStructures/BulkCount/1/0";
+
+    ///Creates a new Hierarchical Structures submodel. The entry node gets the idShort and semantic
+    /// ID of the template.
+    ///
+    /// [id]: global identifier of the submodel
+    /// [arche_type]: direction in which the hierarchy is modelled
+    /// [entry_node]: entity of the asset the hierarchy starts at
+    pub fn new(id: String, arche_type: ArcheType, entry_node: Entity) -> HierarchicalStructures {
+        let mut submodel = Submodel::new(id);
+        submodel.set_id_short(HierarchicalStructures::ID_SHORT.to_string());
+        submodel.set_semantic_id(global_reference(HierarchicalStructures::SEMANTIC_ID));
+        submodel.set_kind(ModellingKind::Instance);
+        let mut hierarchical_structures = HierarchicalStructures {
+            submodel
+        };
+        hierarchical_structures.set_entry_node(entry_node);
+        hierarchical_structures.set_arche_type(arche_type);
+        hierarchical_structures
+    }
+
+    ///Wraps a submodel after checking that it contains the mandatory elements of the Hierarchical
+    /// Structures with the expected types and valid values.
+    ///
+    /// [submodel]: Hierarchical Structures submodel
+    pub fn from_submodel(submodel: Submodel) -> Result<HierarchicalStructures, ConversionError> {
+        HierarchicalStructures::check(&submodel)?;
+        Ok(HierarchicalStructures {
+            submodel
+        })
+    }
+
+    ///Returns the wrapped submodel.
+    pub fn get_submodel(&self) -> &Submodel {
+        &self.submodel
+    }
+
+    ///Consumes the Hierarchical Structures and returns the wrapped submodel.
+    pub fn into_submodel(self) -> Submodel {
+        self.submodel
+    }
+
+    ///Returns the submodel template of the Hierarchical Structures. Nodes may contain further
+    /// nodes, so the statements of the nodes are not restricted.
+    pub fn template() -> Submodel {
+        let mut template = Submodel::new("https://admin-shell.io/idta/SubmodelTemplate/HierarchicalStructures/1/1".to_string());
+        template.set_id_short(HierarchicalStructures::ID_SHORT.to_string());
+        template.set_semantic_id(global_reference(HierarchicalStructures::SEMANTIC_ID));
+        template.set_kind(ModellingKind::Template);
+        template.set_submodel_elements(vec![
+            ENTRY_NODE.to_entity(vec![
+                NODE.to_entity(vec![
+                    SAME_AS.to_element(),
+                    HAS_PART.to_element(),
+                    IS_PART_OF.to_element(),
+                    BULK_COUNT.to_element(),
+                    ARBITRARY.to_element()
+                ]),
+                SAME_AS.to_element(),
+                HAS_PART.to_element(),
+                IS_PART_OF.to_element(),
+                BULK_COUNT.to_element()
+            ]),
+            ARCHE_TYPE.to_element()
+        ]);
+        template
+    }
+
+    ///Checks the wrapped submodel against the submodel template of the Hierarchical Structures.
+    pub fn check_conformance(&self) -> ConformanceReport {
+        self.submodel.check_conformance(&HierarchicalStructures::template())
+    }
+
+    ///Returns the archetype. Returns None if the value is missing or unknown.
+    pub fn get_arche_type(&self) -> Option<ArcheType> {
+        self.submodel.get_property_value::<String>(ARCHE_TYPE.id_short, ARCHE_TYPE.semantic_id)
+            .and_then(|name| ArcheType::parse(&name))
+    }
+
+    ///Sets the archetype.
+    ///
+    /// [arche_type]: direction in which the hierarchy is modelled
+    pub fn set_arche_type(&mut self, arche_type: ArcheType) {
+        ARCHE_TYPE.set_property_value(&mut self.submodel, Some(&arche_type.get_name().to_string()));
+    }
+
+    ///Returns the entity of the asset the hierarchy starts at.
+    pub fn get_entry_node(&self) -> Option<&Entity> {
+        match self.submodel.find_element(ENTRY_NODE.id_short, ENTRY_NODE.semantic_id)? {
+            SubmodelElement::Entity(entity) => Some(entity),
+            _ => None
+        }
+    }
+
+    ///Returns the mutable entity of the asset the hierarchy starts at.
+    pub fn get_mut_entry_node(&mut self) -> Option<&mut Entity> {
+        match self.submodel.find_mut_element(ENTRY_NODE.id_short, ENTRY_NODE.semantic_id)? {
+            SubmodelElement::Entity(entity) => Some(entity),
+            _ => None
+        }
+    }
+
+    ///Sets the entity of the asset the hierarchy starts at. The entity gets the idShort and, if it
+    /// has none, the semantic ID of the template.
+    ///
+    /// [entry_node]: entity of the asset the hierarchy starts at
+    pub fn set_entry_node(&mut self, entry_node: Entity) {
+        self.submodel.set_element(ENTRY_NODE.id_short, ENTRY_NODE.semantic_id, Some(SubmodelElement::Entity(entry_node)));
+    }
+
+    ///Adds a node to the statements of the entry node together with a `HasPart` relationship from
+    /// the entry node to the node, as modelled by the archetypes `Full` and `OneDown`. Returns the
+    /// added node, so parts of the part can be added to its statements.
+    ///
+    /// [id_short]: idShort of the node
+    /// [entity_type]: whether the part has an own Asset Administration Shell
+    /// [global_asset_id]: global asset ID of the part
+    /// [bulk_count]: number of times the part occurs in the entry node
+    pub fn add_part(&mut self, id_short: &str, entity_type: EntityType, global_asset_id: Option<String>,
+                    bulk_count: Option<u64>) -> Result<&mut Entity, ConversionError> {
+        self.add_node(&HAS_PART, id_short, entity_type, global_asset_id, bulk_count)
+    }
+
+    ///Adds a node to the statements of the entry node together with an `IsPartOf` relationship
+    /// from the entry node to the node, as modelled by the archetype `OneUp`. Returns the added
+    /// node.
+    ///
+    /// [id_short]: idShort of the node
+    /// [entity_type]: whether the assembly has an own Asset Administration Shell
+    /// [global_asset_id]: global asset ID of the assembly
+    /// [bulk_count]: number of times the entry node occurs in the assembly
+    pub fn add_assembly(&mut self, id_short: &str, entity_type: EntityType, global_asset_id: Option<String>,
+                        bulk_count: Option<u64>) -> Result<&mut Entity, ConversionError> {
+        self.add_node(&IS_PART_OF, id_short, entity_type, global_asset_id, bulk_count)
+    }
+
+    fn add_node(&mut self, relationship_element: &TemplateElement, id_short: &str, entity_type: EntityType,
+                global_asset_id: Option<String>, bulk_count: Option<u64>) -> Result<&mut Entity, ConversionError> {
+        let submodel_id = self.submodel.get_id().clone();
+        let entry_node = self.get_mut_entry_node()
+            .ok_or_else(|| ConversionError::MissingElement(ENTRY_NODE.id_short.to_string()))?;
+        let entry_node_id_short = entry_node.get_id_short().cloned().unwrap_or_default();
+
+        let mut node = Entity::new();
+        node.set_id_short(id_short.to_string());
+        node.set_semantic_id(global_reference(NODE.semantic_id));
+        node.set_entity_type(entity_type);
+        if let Some(global_asset_id) = global_asset_id {
+            node.set_global_asset_id(global_asset_id);
+        }
+        BULK_COUNT.set_property_value(&mut node, bulk_count.as_ref());
+
+        let mut relationship = RelationshipElement::new();
+        relationship.set_first(entity_reference(&submodel_id, &[&entry_node_id_short]));
+        relationship.set_second(entity_reference(&submodel_id, &[&entry_node_id_short, id_short]));
+        entry_node.add_element(relationship_element.id_short, relationship_element.semantic_id,
+                               SubmodelElement::RelationshipElement(relationship));
+        entry_node.add_statement(SubmodelElement::Entity(node));
+        match entry_node.get_mut_statements().last_mut() {
+            Some(SubmodelElement::Entity(node)) => Ok(node),
+            _ => unreachable!("the node was added above")
+        }
+    }
+
+    fn check(submodel: &Submodel) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(submodel, "")?;
+        }
+        let arche_type = submodel.get_property_value::<String>(ARCHE_TYPE.id_short, ARCHE_TYPE.semantic_id)
+            .and_then(|name| ArcheType::parse(&name));
+        if arche_type.is_none() {
+            return Err(ConversionError::InvalidValue(ARCHE_TYPE.id_short.to_string()));
+        }
+        Ok(())
+    }
+}
+
+///Returns a model reference to the entity addressed by the idShorts within the submodel.
+fn entity_reference(submodel_id: &str, id_shorts: &[&str]) -> Reference {
+    let keys = std::iter::once(Key::new(KeyType::Submodel, submodel_id.to_string()))
+        .chain(id_shorts.iter().map(|id_short| Key::new(KeyType::Entity, id_short.to_string())))
+        .collect();
+    Reference::new(ReferenceType::ModelReference, keys)
+}
+
+const ENTRY_NODE: TemplateElement =
+    TemplateElement::new("EntryNode", "https://admin-shell.io/idta/HierarchicalStructures/EntryNode/1/0", AasSubmodelElements::Entity, Cardinality::One);
+const ARCHE_TYPE: TemplateElement =
+    TemplateElement::property("ArcheType", "https://admin-shell.io/idta/HierarchicalStructures/ArcheType/1/0", DataTypeDefXsd::String, Cardinality::One);
+const NODE: TemplateElement =
+    TemplateElement::new("Node", HierarchicalStructures::NODE_SEMANTIC_ID, AasSubmodelElements::Entity, Cardinality::ZeroToMany);
+const HAS_PART: TemplateElement =
+    TemplateElement::new("HasPart{00}", HierarchicalStructures::HAS_PART_SEMANTIC_ID, AasSubmodelElements::RelationshipElement, Cardinality::ZeroToMany);
+const IS_PART_OF: TemplateElement =
+    TemplateElement::new("IsPartOf{00}", HierarchicalStructures::IS_PART_OF_SEMANTIC_ID, AasSubmodelElements::RelationshipElement, Cardinality::ZeroToMany);
+const SAME_AS: TemplateElement =
+    TemplateElement::new("SameAs{00}", HierarchicalStructures::SAME_AS_SEMANTIC_ID, AasSubmodelElements::RelationshipElement, Cardinality::ZeroToMany);
+const BULK_COUNT: TemplateElement =
+    TemplateElement::property("BulkCount", HierarchicalStructures::BULK_COUNT_SEMANTIC_ID, DataTypeDefXsd::UnsignedLong, Cardinality::ZeroToOne);
+
+const ELEMENTS: [&TemplateElement; 2] = [&ENTRY_NODE, &ARCHE_TYPE];
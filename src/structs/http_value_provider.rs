@@ -0,0 +1,191 @@
+use std::io::{Read, Write};
+use std::time::Duration;
+
+use crate::enumerations::binding_error::BindingError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::structs::value_binding::{communication_error, extract_value};
+use crate::traits::value_provider::TValueProvider;
+use crate::transport::{connect, parse_http_response, split_uri, with_default_port};
+
+///Provider reading values by HTTP GET requests, e.g. from the REST interface of a controller.
+///
+/// The response body is the value. If the body is JSON, a scalar is taken as is and the value
+/// within a structured body is selected by a JSON pointer. Only plain HTTP is supported.
+#[derive(PartialEq, Clone)]
+pub struct HttpValueProvider {
+    ///Absolute URL of the value.
+    url: String,
+    ///Additional request headers, e.g. for authorization.
+    headers: Vec<(String, String)>,
+    ///JSON pointer selecting the value within a structured JSON body, e.g. `/value`.
+    json_pointer: Option<String>,
+    ///Timeout of connecting and reading.
+    timeout: Duration
+}
+
+impl HttpValueProvider {
+    ///Creates a new provider with a timeout of 5 seconds.
+    ///
+    /// [url]: absolute URL of the value, e.g. `http://192.168.0.1:8080/temperature`
+    pub fn new(url: String) -> HttpValueProvider {
+        HttpValueProvider {
+            url,
+            headers: Vec::new(),
+            json_pointer: None,
+            timeout: Duration::from_secs(5)
+        }
+    }
+
+    ///Returns the URL of the value.
+    pub fn get_url(&self) -> &String {
+        &self.url
+    }
+
+    ///Sets the URL of the value.
+    ///
+    /// [url]: absolute URL of the value
+    pub fn set_url(&mut self, url: String) {
+        self.url = url;
+    }
+
+    ///Returns the additional request headers.
+    pub fn get_headers(&self) -> &Vec<(String, String)> {
+        &self.headers
+    }
+
+    ///Adds a request header.
+    ///
+    /// [name]: name of the header, e.g. `Authorization`
+    /// [value]: value of the header
+    pub fn add_header(&mut self, name: String, value: String) {
+        self.headers.push((name, value));
+    }
+
+    ///Returns the JSON pointer selecting the value within a structured JSON body.
+    pub fn get_json_pointer(&self) -> Option<&String> {
+        self.json_pointer.as_ref()
+    }
+
+    ///Sets the JSON pointer selecting the value within a structured JSON body. Passing None takes
+    /// the whole body.
+    ///
+    /// [json_pointer]: JSON pointer, e.g. `/value`
+    pub fn set_json_pointer(&mut self, json_pointer: Option<String>) {
+        self.json_pointer = json_pointer;
+    }
+
+    ///Returns the timeout of connecting and reading.
+    pub fn get_timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    ///Sets the timeout of connecting and reading.
+    ///
+    /// [timeout]: timeout of connecting and reading
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    ///Sends the GET request and returns the body of a successful response.
+    fn get(&self) -> Result<Vec<u8>, BindingError> {
+        let (scheme, authority, path) = split_uri(&self.url).ok_or_else(|| BindingError::InvalidAddress(self.url.clone()))?;
+        if !scheme.eq_ignore_ascii_case("http") {
+            return Err(BindingError::UnsupportedProtocol(scheme.to_string()));
+        }
+        let address = with_default_port(authority, 80);
+        let connection_error = |error| communication_error(&address, error);
+        let mut stream = connect(&address, self.timeout).map_err(connection_error)?;
+        let mut request = format!("GET {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n",
+                                  if path.is_empty() { "/" } else { path }, authority);
+        for (name, value) in &self.headers {
+            request.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        request.push_str("\r\n");
+        stream.write_all(request.as_bytes()).map_err(connection_error)?;
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response).map_err(connection_error)?;
+        let (status, _, body) = parse_http_response(&response)
+            .ok_or_else(|| BindingError::ProtocolError("malformed HTTP response".to_string()))?;
+        match (200..300).contains(&status) {
+            true => Ok(body),
+            false => Err(BindingError::ProtocolError(format!("HTTP status {}", status)))
+        }
+    }
+}
+
+impl TValueProvider for HttpValueProvider {
+    fn read_value(&mut self, _value_type: &DataTypeDefXsd) -> Result<String, BindingError> {
+        let body = self.get()?;
+        let text = String::from_utf8(body).map_err(|_| BindingError::ProtocolError("the body is no UTF-8 text".to_string()))?;
+        extract_value(&text, self.json_pointer.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    use crate::enumerations::binding_error::BindingError;
+    use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+    use crate::structs::http_value_provider::HttpValueProvider;
+    use crate::traits::value_provider::TValueProvider;
+
+    ///Serves the response to a single request on a loopback port and returns the URL of the value
+    /// and the handle of the server thread, which yields the received request.
+    fn serve(response: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/sensor/temperature", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let read = stream.read(&mut buffer).unwrap();
+                if read == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buffer[..read]);
+            }
+            stream.write_all(response.as_bytes()).unwrap();
+            String::from_utf8(request).unwrap()
+        });
+        (url, server)
+    }
+
+    #[test]
+    fn reads_plain_body() {
+        let (url, server) = serve("HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\n21.5\n");
+        let mut provider = HttpValueProvider::new(url);
+        provider.add_header("Authorization".to_string(), "Bearer token".to_string());
+        assert_eq!(provider.read_value(&DataTypeDefXsd::Double), Ok("21.5".to_string()));
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /sensor/temperature HTTP/1.1\r\n"));
+        assert!(request.contains("\r\nAuthorization: Bearer token\r\n"));
+    }
+
+    #[test]
+    fn selects_value_of_chunked_json_body() {
+        let (url, server) = serve("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nTransfer-Encoding: chunked\r\n\r\n\
+                                   b\r\n{\"value\": {\r\nf\r\n\"celsius\": 21}}\r\n0\r\n\r\n");
+        let mut provider = HttpValueProvider::new(url);
+        provider.set_json_pointer(Some("/value/celsius".to_string()));
+        assert_eq!(provider.read_value(&DataTypeDefXsd::Int), Ok("21".to_string()));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn reports_error_status() {
+        let (url, server) = serve("HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n");
+        let mut provider = HttpValueProvider::new(url);
+        assert_eq!(provider.read_value(&DataTypeDefXsd::Int), Err(BindingError::ProtocolError("HTTP status 404".to_string())));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn rejects_other_protocols() {
+        let mut provider = HttpValueProvider::new("ftp://127.0.0.1/temperature".to_string());
+        assert_eq!(provider.read_value(&DataTypeDefXsd::Int), Err(BindingError::UnsupportedProtocol("ftp".to_string())));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,51 @@
+///Options controlling how a submodel template is instantiated.
+#[derive(PartialEq, Clone)]
+pub struct InstantiationOptions {
+    ///Values of submodel elements of the instance by their idShortPath.
+    values: Vec<(String, String)>,
+    ///Whether optional elements are instantiated even if no value is supplied for them.
+    include_optional_elements: bool
+}
+
+impl InstantiationOptions {
+    ///Creates a new instance of the struct without any values. Optional elements are only
+    /// instantiated if a value is supplied for them or one of their children.
+    pub fn new() -> InstantiationOptions {
+        InstantiationOptions {
+            values: Vec::new(),
+            include_optional_elements: false
+        }
+    }
+
+    ///Adds the value of a property or file of the instance. Supplied values take precedence over
+    /// the default values of the template.
+    ///
+    /// [id_short_path]: idShortPath of the submodel element, e.g. `Collection.List[0].Property`
+    /// [value]: value of the submodel element
+    pub fn add_value(&mut self, id_short_path: String, value: String) {
+        self.values.push((id_short_path, value));
+    }
+
+    ///Returns the supplied values as pairs of idShortPath and value.
+    pub fn get_values(&self) -> &Vec<(String, String)> {
+        &self.values
+    }
+
+    ///Sets whether optional elements are instantiated even if no value is supplied for them.
+    ///
+    /// [include_optional_elements]: whether all optional elements are instantiated
+    pub fn set_include_optional_elements(&mut self, include_optional_elements: bool) {
+        self.include_optional_elements = include_optional_elements;
+    }
+
+    ///Returns whether optional elements are instantiated even if no value is supplied for them.
+    pub fn get_include_optional_elements(&self) -> bool {
+        self.include_optional_elements
+    }
+}
+
+impl Default for InstantiationOptions {
+    fn default() -> InstantiationOptions {
+        InstantiationOptions::new()
+    }
+}
@@ -0,0 +1,120 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::multi_language_text_type::MultiLanguageTextType;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, TemplateElement};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on an IP based communication channel of a contact information, e.g. a chat or video
+/// call.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct IpCommunication {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl IpCommunication {
+    ///Semantic ID of an IP communication.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/zvei/nameplate/1/0/ContactInformations/ContactInformation/IPCommunication";
+
+    ///Creates a new IP communication.
+    ///
+    /// [address_of_additional_link]: address of the channel, e.g. a URL or a chat handle
+    pub fn new(address_of_additional_link: String) -> IpCommunication {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_semantic_id(global_reference(IpCommunication::SEMANTIC_ID));
+        let mut ip_communication = IpCommunication {
+            collection
+        };
+        ip_communication.set_address_of_additional_link(address_of_additional_link);
+        ip_communication
+    }
+
+    ///Wraps a submodel element collection after checking the elements of an IP communication.
+    ///
+    /// [collection]: collection containing an IP communication
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<IpCommunication, ConversionError> {
+        IpCommunication::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(IpCommunication {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the ip_communication and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the address of the channel, e.g. a URL or a chat handle.
+    pub fn get_address_of_additional_link(&self) -> Option<String> {
+        self.collection.get_property_value(ADDRESS_OF_ADDITIONAL_LINK.id_short, ADDRESS_OF_ADDITIONAL_LINK.semantic_id)
+    }
+
+    ///Sets the address of the channel, e.g. a URL or a chat handle.
+    ///
+    /// [address_of_additional_link]: address of the channel, e.g. a URL or a chat handle
+    pub fn set_address_of_additional_link(&mut self, address_of_additional_link: String) {
+        ADDRESS_OF_ADDITIONAL_LINK.set_property_value(&mut self.collection, Some(&address_of_additional_link));
+    }
+
+    ///Returns the type of the communication channel, e.g. chat or video call.
+    pub fn get_type_of_communication(&self) -> Option<String> {
+        self.collection.get_property_value(TYPE_OF_COMMUNICATION.id_short, TYPE_OF_COMMUNICATION.semantic_id)
+    }
+
+    ///Sets the type of the communication channel, e.g. chat or video call. Passing None removes it.
+    ///
+    /// [type_of_communication]: type of the communication channel, e.g. chat or video call
+    pub fn set_type_of_communication(&mut self, type_of_communication: Option<String>) {
+        TYPE_OF_COMMUNICATION.set_property_value(&mut self.collection, type_of_communication.as_ref());
+    }
+
+    ///Returns the times the contact is available by the channel.
+    pub fn get_available_time(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(AVAILABLE_TIME.id_short, AVAILABLE_TIME.semantic_id)
+    }
+
+    ///Sets the times the contact is available by the channel. Passing None removes it.
+    ///
+    /// [available_time]: times the contact is available by the channel
+    pub fn set_available_time(&mut self, available_time: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(AVAILABLE_TIME.id_short, AVAILABLE_TIME.semantic_id, available_time);
+    }
+
+    ///Returns the elements of an IP communication in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        ELEMENTS.iter().map(|element| element.to_element()).collect()
+    }
+
+    ///Checks the elements of an IP communication.
+    ///
+    /// [collection]: collection containing an IP communication
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        Ok(())
+    }
+}
+
+const ADDRESS_OF_ADDITIONAL_LINK: TemplateElement =
+    TemplateElement::property("AddressOfAdditionalLink", "0173-1#02-AAQ326#002", DataTypeDefXsd::String, Cardinality::One);
+const TYPE_OF_COMMUNICATION: TemplateElement =
+    TemplateElement::property("TypeOfCommunication", "https://admin-shell.io/zvei/nameplate/1/0/ContactInformations/ContactInformation/IPCommunication/TypeOfCommunication", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const AVAILABLE_TIME: TemplateElement =
+    TemplateElement::new("AvailableTime", "https://admin-shell.io/zvei/nameplate/1/0/ContactInformations/ContactInformation/AvailableTime/", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+
+const ELEMENTS: [&TemplateElement; 3] = [&ADDRESS_OF_ADDITIONAL_LINK, &TYPE_OF_COMMUNICATION, &AVAILABLE_TIME];
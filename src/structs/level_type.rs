@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+///Levels of a value of a concept described with the data specification IEC 61360, e.g. whether
+/// the value is a minimum, nominal, typical or maximum value.
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
+pub struct LevelType {
+    ///Whether the value is a minimum value.
+    min: bool,
+    ///Whether the value is a nominal value.
+    nom: bool,
+    ///Whether the value is a typical value.
+    typ: bool,
+    ///Whether the value is a maximum value.
+    max: bool
+}
+
+impl LevelType {
+    ///Creates a new instance of the struct.
+    ///
+    /// [min]: whether the value is a minimum value
+    /// [nom]: whether the value is a nominal value
+    /// [typ]: whether the value is a typical value
+    /// [max]: whether the value is a maximum value
+    pub fn new(min: bool, nom: bool, typ: bool, max: bool) -> LevelType {
+        LevelType {
+            min,
+            nom,
+            typ,
+            max
+        }
+    }
+
+    ///Sets whether the value is a minimum value.
+    pub fn set_min(&mut self, min: bool) {
+        self.min = min;
+    }
+
+    ///Returns whether the value is a minimum value.
+    pub fn get_min(&self) -> bool {
+        self.min
+    }
+
+    ///Sets whether the value is a nominal value.
+    pub fn set_nom(&mut self, nom: bool) {
+        self.nom = nom;
+    }
+
+    ///Returns whether the value is a nominal value.
+    pub fn get_nom(&self) -> bool {
+        self.nom
+    }
+
+    ///Sets whether the value is a typical value.
+    pub fn set_typ(&mut self, typ: bool) {
+        self.typ = typ;
+    }
+
+    ///Returns whether the value is a typical value.
+    pub fn get_typ(&self) -> bool {
+        self.typ
+    }
+
+    ///Sets whether the value is a maximum value.
+    pub fn set_max(&mut self, max: bool) {
+        self.max = max;
+    }
+
+    ///Returns whether the value is a maximum value.
+    pub fn get_max(&self) -> bool {
+        self.max
+    }
+}
\ No newline at end of file
@@ -0,0 +1,154 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::reference::Reference;
+use crate::structs::reference_element::ReferenceElement;
+use crate::structs::relationship_element::RelationshipElement;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::submodel_element_list::SubmodelElementList;
+use crate::structs::template_element::{global_reference, join_path, TemplateElement};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on a mapping configuration of the Asset Interfaces Mapping Configuration, which maps
+/// the property affordances of an interface of the Asset Interfaces Description to the elements
+/// of other submodels that receive their values.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct MappingConfiguration {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl MappingConfiguration {
+    ///Semantic ID of a mapping configuration.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/idta/AssetInterfacesMappingConfiguration/1/0/MappingConfiguration";
+
+    ///Creates a new mapping configuration without relations.
+    ///
+    /// [interface_reference]: model reference to the interface of the Asset Interfaces
+    /// Description
+    pub fn new(interface_reference: Reference) -> MappingConfiguration {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_semantic_id(global_reference(MappingConfiguration::SEMANTIC_ID));
+        let mut mapping_configuration = MappingConfiguration {
+            collection
+        };
+        mapping_configuration.set_interface_reference(interface_reference);
+        mapping_configuration.set_relations(Vec::new());
+        mapping_configuration
+    }
+
+    ///Wraps a submodel element collection after checking the elements of a mapping configuration.
+    ///
+    /// [collection]: collection containing a mapping configuration
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<MappingConfiguration, ConversionError> {
+        MappingConfiguration::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(MappingConfiguration {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the mapping configuration and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the model reference to the interface of the Asset Interfaces Description.
+    pub fn get_interface_reference(&self) -> Option<&Reference> {
+        match self.collection.find_element(INTERFACE_REFERENCE.id_short, INTERFACE_REFERENCE.semantic_id) {
+            Some(SubmodelElement::ReferenceElement(reference_element)) => reference_element.get_value(),
+            _ => None
+        }
+    }
+
+    ///Sets the model reference to the interface of the Asset Interfaces Description.
+    ///
+    /// [interface_reference]: model reference to the interface
+    pub fn set_interface_reference(&mut self, interface_reference: Reference) {
+        let mut reference_element = ReferenceElement::new();
+        reference_element.set_value(interface_reference);
+        self.collection.set_element(INTERFACE_REFERENCE.id_short, INTERFACE_REFERENCE.semantic_id,
+                                    Some(SubmodelElement::ReferenceElement(reference_element)));
+    }
+
+    ///Returns the relations, each consisting of the model reference to a property affordance of the
+    /// interface as source and the model reference to the element receiving its value as sink.
+    pub fn get_relations(&self) -> Vec<(Reference, Reference)> {
+        self.collection.get_list(RELATIONS.id_short, RELATIONS.semantic_id)
+            .map(|list| list.get_value().iter()
+                .filter_map(|element| match element {
+                    SubmodelElement::RelationshipElement(relationship) => Some((relationship.get_first()?.clone(), relationship.get_second()?.clone())),
+                    _ => None
+                })
+                .collect())
+            .unwrap_or_default()
+    }
+
+    ///Sets the relations between property affordances and the elements receiving their values.
+    ///
+    /// [relations]: pairs of the model references to a property affordance and to the element
+    /// receiving its value
+    pub fn set_relations(&mut self, relations: Vec<(Reference, Reference)>) {
+        let mut list = SubmodelElementList::new(AasSubmodelElements::RelationshipElement);
+        list.set_semantic_id_list_element(global_reference(RELATION.semantic_id));
+        list.set_value(relations.into_iter()
+            .map(|(source, sink)| {
+                let mut relationship = RelationshipElement::new();
+                relationship.set_semantic_id(global_reference(RELATION.semantic_id));
+                relationship.set_first(source);
+                relationship.set_second(sink);
+                SubmodelElement::RelationshipElement(relationship)
+            })
+            .collect());
+        self.collection.set_element(RELATIONS.id_short, RELATIONS.semantic_id, Some(SubmodelElement::SubmodelElementList(list)));
+    }
+
+    ///Adds a relation between a property affordance and the element receiving its value.
+    ///
+    /// [source]: model reference to the property affordance
+    /// [sink]: model reference to the element receiving the value
+    pub fn add_relation(&mut self, source: Reference, sink: Reference) {
+        let mut relations = self.get_relations();
+        relations.push((source, sink));
+        self.set_relations(relations);
+    }
+
+    ///Returns the elements of a mapping configuration in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        vec![INTERFACE_REFERENCE.to_element(), RELATIONS.to_list(&RELATION, Vec::new())]
+    }
+
+    ///Checks the elements of a mapping configuration.
+    ///
+    /// [collection]: collection containing a mapping configuration
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        INTERFACE_REFERENCE.check(collection, path)?;
+        for element in RELATIONS.check(collection, path)? {
+            if let SubmodelElement::SubmodelElementList(list) = element {
+                for (index, relation) in list.get_value().iter().enumerate() {
+                    if !matches!(relation, SubmodelElement::RelationshipElement(_)) {
+                        return Err(ConversionError::UnexpectedElementType(format!("{}[{}]", join_path(path, RELATIONS.id_short), index)));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+const INTERFACE_REFERENCE: TemplateElement =
+    TemplateElement::new("InterfaceReference", "https://admin-shell.io/idta/AssetInterfacesMappingConfiguration/1/0/InterfaceReference", AasSubmodelElements::ReferenceElement, Cardinality::One);
+const RELATIONS: TemplateElement =
+    TemplateElement::new("MappingSourceSinkRelations", "https://admin-shell.io/idta/AssetInterfacesMappingConfiguration/1/0/MappingSourceSinkRelations", AasSubmodelElements::SubmodelElementList, Cardinality::One);
+const RELATION: TemplateElement =
+    TemplateElement::new("MappingSourceSinkRelation", "https://admin-shell.io/idta/AssetInterfacesMappingConfiguration/1/0/MappingSourceSinkRelation", AasSubmodelElements::RelationshipElement, Cardinality::ZeroToMany);
@@ -0,0 +1,171 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::file::File;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, TemplateElement};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on a marking of the Digital Nameplate, e.g. the CE marking, with the file showing
+/// the marking.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct Marking {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl Marking {
+    ///Semantic ID of a marking.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/idta/nameplate/3/0/Marking";
+
+    ///Creates a new marking.
+    ///
+    /// [marking_name]: name of the marking, e.g. CE
+    /// [marking_file]: file with the picture of the marking
+    pub fn new(marking_name: String, marking_file: File) -> Marking {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_semantic_id(global_reference(Marking::SEMANTIC_ID));
+        let mut marking = Marking {
+            collection
+        };
+        marking.set_marking_name(marking_name);
+        marking.set_marking_file(marking_file);
+        marking
+    }
+
+    ///Wraps a submodel element collection after checking the elements of a marking.
+    ///
+    /// [collection]: collection containing a marking
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<Marking, ConversionError> {
+        Marking::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(Marking {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the marking and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the name of the marking, e.g. CE.
+    pub fn get_marking_name(&self) -> Option<String> {
+        self.collection.get_property_value(MARKING_NAME.id_short, MARKING_NAME.semantic_id)
+    }
+
+    ///Sets the name of the marking, e.g. CE.
+    ///
+    /// [marking_name]: name of the marking, e.g. CE
+    pub fn set_marking_name(&mut self, marking_name: String) {
+        MARKING_NAME.set_property_value(&mut self.collection, Some(&marking_name));
+    }
+
+    ///Returns the designation of the certificate or approval the marking refers to.
+    pub fn get_designation_of_certificate_or_approval(&self) -> Option<String> {
+        self.collection.get_property_value(DESIGNATION_OF_CERTIFICATE_OR_APPROVAL.id_short, DESIGNATION_OF_CERTIFICATE_OR_APPROVAL.semantic_id)
+    }
+
+    ///Sets the designation of the certificate or approval the marking refers to. Passing None removes it.
+    ///
+    /// [designation_of_certificate_or_approval]: designation of the certificate or approval the marking refers to
+    pub fn set_designation_of_certificate_or_approval(&mut self, designation_of_certificate_or_approval: Option<String>) {
+        DESIGNATION_OF_CERTIFICATE_OR_APPROVAL.set_property_value(&mut self.collection, designation_of_certificate_or_approval.as_ref());
+    }
+
+    ///Returns the date the marking was issued, as xs:date.
+    pub fn get_issue_date(&self) -> Option<String> {
+        self.collection.get_property_value(ISSUE_DATE.id_short, ISSUE_DATE.semantic_id)
+    }
+
+    ///Sets the date the marking was issued, as xs:date. Passing None removes it.
+    ///
+    /// [issue_date]: date the marking was issued, as xs:date
+    pub fn set_issue_date(&mut self, issue_date: Option<String>) {
+        ISSUE_DATE.set_property_value(&mut self.collection, issue_date.as_ref());
+    }
+
+    ///Returns the date the marking expires, as xs:date.
+    pub fn get_expiry_date(&self) -> Option<String> {
+        self.collection.get_property_value(EXPIRY_DATE.id_short, EXPIRY_DATE.semantic_id)
+    }
+
+    ///Sets the date the marking expires, as xs:date. Passing None removes it.
+    ///
+    /// [expiry_date]: date the marking expires, as xs:date
+    pub fn set_expiry_date(&mut self, expiry_date: Option<String>) {
+        EXPIRY_DATE.set_property_value(&mut self.collection, expiry_date.as_ref());
+    }
+
+    ///Returns the file with the picture of the marking.
+    pub fn get_marking_file(&self) -> Option<&File> {
+        self.collection.get_file(MARKING_FILE.id_short, MARKING_FILE.semantic_id)
+    }
+
+    ///Sets the file with the picture of the marking.
+    ///
+    /// [marking_file]: file with the picture of the marking
+    pub fn set_marking_file(&mut self, marking_file: File) {
+        self.collection.set_element(MARKING_FILE.id_short, MARKING_FILE.semantic_id, Some(SubmodelElement::File(marking_file)));
+    }
+
+    ///Returns the additional texts of the marking.
+    pub fn get_marking_additional_texts(&self) -> Vec<String> {
+        self.collection.find_elements(MARKING_ADDITIONAL_TEXT.id_short, MARKING_ADDITIONAL_TEXT.semantic_id).into_iter()
+            .filter_map(|element| match element {
+                SubmodelElement::Property(property) => property.get_typed_value(),
+                _ => None
+            })
+            .collect()
+    }
+
+    ///Sets the additional texts of the marking.
+    ///
+    /// [marking_additional_texts]: additional texts of the marking
+    pub fn set_marking_additional_texts(&mut self, marking_additional_texts: Vec<String>) {
+        let elements = marking_additional_texts.iter().map(|value| MARKING_ADDITIONAL_TEXT.new_property(value)).collect();
+        self.collection.set_elements(MARKING_ADDITIONAL_TEXT.id_short, MARKING_ADDITIONAL_TEXT.semantic_id, elements);
+    }
+
+    ///Returns the elements of a marking in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        ELEMENTS.iter().map(|element| element.to_element()).collect()
+    }
+
+    ///Checks the elements of a marking.
+    ///
+    /// [collection]: collection containing a marking
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        Ok(())
+    }
+}
+
+const MARKING_NAME: TemplateElement =
+    TemplateElement::property("MarkingName", "https://admin-shell.io/idta/nameplate/3/0/MarkingName", DataTypeDefXsd::String, Cardinality::One);
+const DESIGNATION_OF_CERTIFICATE_OR_APPROVAL: TemplateElement =
+    TemplateElement::property("DesignationOfCertificateOrApproval", "0112/2///61987#ABH783#001", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const ISSUE_DATE: TemplateElement =
+    TemplateElement::property("IssueDate", "https://admin-shell.io/idta/nameplate/3/0/IssueDate", DataTypeDefXsd::Date, Cardinality::ZeroToOne);
+const EXPIRY_DATE: TemplateElement =
+    TemplateElement::property("ExpiryDate", "https://admin-shell.io/idta/nameplate/3/0/ExpiryDate", DataTypeDefXsd::Date, Cardinality::ZeroToOne);
+const MARKING_FILE: TemplateElement =
+    TemplateElement::new("MarkingFile", "https://admin-shell.io/idta/nameplate/3/0/MarkingFile", AasSubmodelElements::File, Cardinality::One);
+const MARKING_ADDITIONAL_TEXT: TemplateElement =
+    TemplateElement::property("MarkingAdditionalText{00}", "https://admin-shell.io/idta/nameplate/3/0/MarkingAdditionalText", DataTypeDefXsd::String, Cardinality::ZeroToMany);
+
+const ELEMENTS: [&TemplateElement; 6] = [&MARKING_NAME, &DESIGNATION_OF_CERTIFICATE_OR_APPROVAL, &ISSUE_DATE,
+    &EXPIRY_DATE, &MARKING_FILE, &MARKING_ADDITIONAL_TEXT];
@@ -0,0 +1,196 @@
+use crate::enumerations::repository_error::RepositoryError;
+use crate::structs::asset_administration_shell_descriptor::AssetAdministrationShellDescriptor;
+use crate::structs::submodel_descriptor::SubmodelDescriptor;
+
+///Registry keeping the descriptors of shells and submodels in memory, as the AAS Registry and
+/// Submodel Registry interfaces of the HTTP API.
+#[derive(PartialEq, Clone)]
+pub struct MemoryRegistry {
+    ///Registered descriptors of shells.
+    shell_descriptors: Vec<AssetAdministrationShellDescriptor>,
+    ///Registered descriptors of submodels, independent of the descriptors of the shells.
+    submodel_descriptors: Vec<SubmodelDescriptor>
+}
+
+impl MemoryRegistry {
+    ///Creates a new registry without descriptors.
+    pub fn new() -> MemoryRegistry {
+        MemoryRegistry {
+            shell_descriptors: Vec::new(),
+            submodel_descriptors: Vec::new()
+        }
+    }
+
+    ///Registers the descriptor of a shell. Returns `RepositoryError::Conflict` if a descriptor
+    /// with the identifier of the shell is already registered.
+    ///
+    /// [descriptor]: descriptor to register
+    pub fn register_shell_descriptor(&mut self, descriptor: AssetAdministrationShellDescriptor) -> Result<(), RepositoryError> {
+        if self.get_shell_descriptor(descriptor.get_id()).is_some() {
+            return Err(RepositoryError::Conflict(descriptor.get_id().clone()));
+        }
+        self.shell_descriptors.push(descriptor);
+        Ok(())
+    }
+
+    ///Returns all registered descriptors of shells.
+    pub fn get_shell_descriptors(&self) -> &Vec<AssetAdministrationShellDescriptor> {
+        &self.shell_descriptors
+    }
+
+    ///Returns the descriptor of the shell with the identifier.
+    ///
+    /// [id]: identifier of the shell
+    pub fn get_shell_descriptor(&self, id: &str) -> Option<&AssetAdministrationShellDescriptor> {
+        self.shell_descriptors.iter().find(|descriptor| descriptor.get_id() == id)
+    }
+
+    ///Returns the descriptors of the shells representing the asset with the global identifier.
+    ///
+    /// [global_asset_id]: global identifier of the asset, e.g. of `AssetInformation`
+    pub fn get_shell_descriptors_by_global_asset_id(&self, global_asset_id: &str) -> Vec<&AssetAdministrationShellDescriptor> {
+        self.shell_descriptors.iter()
+            .filter(|descriptor| descriptor.get_global_asset_id().is_some_and(|id| id == global_asset_id))
+            .collect()
+    }
+
+    ///Replaces the registered descriptor with the identifier of the descriptor. Returns
+    /// `RepositoryError::NotFound` if no such descriptor is registered.
+    ///
+    /// [descriptor]: new descriptor
+    pub fn update_shell_descriptor(&mut self, descriptor: AssetAdministrationShellDescriptor) -> Result<(), RepositoryError> {
+        let existing = self.shell_descriptors.iter_mut().find(|existing| existing.get_id() == descriptor.get_id())
+            .ok_or_else(|| RepositoryError::NotFound(descriptor.get_id().clone()))?;
+        *existing = descriptor;
+        Ok(())
+    }
+
+    ///Unregisters and returns the descriptor of the shell with the identifier. Returns
+    /// `RepositoryError::NotFound` if no such descriptor is registered.
+    ///
+    /// [id]: identifier of the shell
+    pub fn delete_shell_descriptor(&mut self, id: &str) -> Result<AssetAdministrationShellDescriptor, RepositoryError> {
+        let index = self.shell_descriptors.iter().position(|descriptor| descriptor.get_id() == id)
+            .ok_or_else(|| RepositoryError::NotFound(id.to_string()))?;
+        Ok(self.shell_descriptors.remove(index))
+    }
+
+    ///Registers the descriptor of a submodel. Returns `RepositoryError::Conflict` if a descriptor
+    /// with the identifier of the submodel is already registered.
+    ///
+    /// [descriptor]: descriptor to register
+    pub fn register_submodel_descriptor(&mut self, descriptor: SubmodelDescriptor) -> Result<(), RepositoryError> {
+        if self.get_submodel_descriptor(descriptor.get_id()).is_some() {
+            return Err(RepositoryError::Conflict(descriptor.get_id().clone()));
+        }
+        self.submodel_descriptors.push(descriptor);
+        Ok(())
+    }
+
+    ///Returns all registered descriptors of submodels.
+    pub fn get_submodel_descriptors(&self) -> &Vec<SubmodelDescriptor> {
+        &self.submodel_descriptors
+    }
+
+    ///Returns the descriptor of the submodel with the identifier.
+    ///
+    /// [id]: identifier of the submodel
+    pub fn get_submodel_descriptor(&self, id: &str) -> Option<&SubmodelDescriptor> {
+        self.submodel_descriptors.iter().find(|descriptor| descriptor.get_id() == id)
+    }
+
+    ///Replaces the registered descriptor with the identifier of the descriptor. Returns
+    /// `RepositoryError::NotFound` if no such descriptor is registered.
+    ///
+    /// [descriptor]: new descriptor
+    pub fn update_submodel_descriptor(&mut self, descriptor: SubmodelDescriptor) -> Result<(), RepositoryError> {
+        let existing = self.submodel_descriptors.iter_mut().find(|existing| existing.get_id() == descriptor.get_id())
+            .ok_or_else(|| RepositoryError::NotFound(descriptor.get_id().clone()))?;
+        *existing = descriptor;
+        Ok(())
+    }
+
+    ///Unregisters and returns the descriptor of the submodel with the identifier. Returns
+    /// `RepositoryError::NotFound` if no such descriptor is registered.
+    ///
+    /// [id]: identifier of the submodel
+    pub fn delete_submodel_descriptor(&mut self, id: &str) -> Result<SubmodelDescriptor, RepositoryError> {
+        let index = self.submodel_descriptors.iter().position(|descriptor| descriptor.get_id() == id)
+            .ok_or_else(|| RepositoryError::NotFound(id.to_string()))?;
+        Ok(self.submodel_descriptors.remove(index))
+    }
+}
+
+impl Default for MemoryRegistry {
+    fn default() -> Self {
+        MemoryRegistry::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::enumerations::repository_error::RepositoryError;
+    use crate::structs::asset_administration_shell_descriptor::AssetAdministrationShellDescriptor;
+    use crate::structs::memory_registry::MemoryRegistry;
+    use crate::structs::submodel_descriptor::SubmodelDescriptor;
+
+    fn shell_descriptor(id: &str, global_asset_id: Option<&str>) -> AssetAdministrationShellDescriptor {
+        let mut descriptor = AssetAdministrationShellDescriptor::new(id.to_string());
+        descriptor.set_global_asset_id(global_asset_id.map(str::to_string));
+        descriptor
+    }
+
+    #[test]
+    fn registers_updates_and_deletes_shell_descriptors() {
+        let mut registry = MemoryRegistry::new();
+        registry.register_shell_descriptor(shell_descriptor("urn:aas:1", Some("urn:asset:1"))).unwrap();
+        registry.register_shell_descriptor(shell_descriptor("urn:aas:2", Some("urn:asset:2"))).unwrap();
+
+        assert_eq!(registry.register_shell_descriptor(shell_descriptor("urn:aas:1", None)),
+                   Err(RepositoryError::Conflict("urn:aas:1".to_string())));
+        assert_eq!(registry.get_shell_descriptors().len(), 2);
+        assert!(registry.get_shell_descriptor("urn:aas:1").unwrap().get_global_asset_id() == Some(&"urn:asset:1".to_string()));
+
+        registry.update_shell_descriptor(shell_descriptor("urn:aas:1", Some("urn:asset:3"))).unwrap();
+        assert!(registry.get_shell_descriptor("urn:aas:1").unwrap().get_global_asset_id() == Some(&"urn:asset:3".to_string()));
+        assert_eq!(registry.update_shell_descriptor(shell_descriptor("urn:aas:4", None)),
+                   Err(RepositoryError::NotFound("urn:aas:4".to_string())));
+
+        assert_eq!(registry.delete_shell_descriptor("urn:aas:2").unwrap().get_id(), "urn:aas:2");
+        assert!(registry.get_shell_descriptor("urn:aas:2").is_none());
+        assert!(registry.delete_shell_descriptor("urn:aas:2").err() == Some(RepositoryError::NotFound("urn:aas:2".to_string())));
+    }
+
+    #[test]
+    fn finds_shell_descriptors_by_global_asset_id() {
+        let mut registry = MemoryRegistry::new();
+        registry.register_shell_descriptor(shell_descriptor("urn:aas:1", Some("urn:asset:1"))).unwrap();
+        registry.register_shell_descriptor(shell_descriptor("urn:aas:2", None)).unwrap();
+        registry.register_shell_descriptor(shell_descriptor("urn:aas:3", Some("urn:asset:1"))).unwrap();
+
+        let ids: Vec<&String> = registry.get_shell_descriptors_by_global_asset_id("urn:asset:1").into_iter()
+            .map(|descriptor| descriptor.get_id())
+            .collect();
+
+        assert_eq!(ids, vec!["urn:aas:1", "urn:aas:3"]);
+        assert!(registry.get_shell_descriptors_by_global_asset_id("urn:asset:2").is_empty());
+    }
+
+    #[test]
+    fn registers_updates_and_deletes_submodel_descriptors() {
+        let mut registry = MemoryRegistry::new();
+        registry.register_submodel_descriptor(SubmodelDescriptor::new("urn:sm:1".to_string())).unwrap();
+
+        assert_eq!(registry.register_submodel_descriptor(SubmodelDescriptor::new("urn:sm:1".to_string())),
+                   Err(RepositoryError::Conflict("urn:sm:1".to_string())));
+        let mut descriptor = SubmodelDescriptor::new("urn:sm:1".to_string());
+        descriptor.set_id_short(Some("Nameplate".to_string()));
+        registry.update_submodel_descriptor(descriptor).unwrap();
+        assert_eq!(registry.get_submodel_descriptor("urn:sm:1").unwrap().get_id_short(), Some(&"Nameplate".to_string()));
+        assert_eq!(registry.update_submodel_descriptor(SubmodelDescriptor::new("urn:sm:2".to_string())),
+                   Err(RepositoryError::NotFound("urn:sm:2".to_string())));
+        assert!(registry.delete_submodel_descriptor("urn:sm:2").err() == Some(RepositoryError::NotFound("urn:sm:2".to_string())));
+        assert_eq!(registry.delete_submodel_descriptor("urn:sm:1").unwrap().get_id(), "urn:sm:1");
+        assert!(registry.get_submodel_descriptors().is_empty());
+    }
+}
\ No newline at end of file
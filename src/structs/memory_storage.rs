@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use crate::enumerations::repository_error::RepositoryError;
+use crate::structs::asset_administration_shell::AssetAdministrationShell;
+use crate::structs::concept_description::ConceptDescription;
+use crate::structs::environment::Environment;
+use crate::structs::submodel::Submodel;
+use crate::traits::identifiable::TIdentifiable;
+use crate::traits::repository_storage::TRepositoryStorage;
+
+///Storage of a repository in memory, which keeps the shells, submodels and concept descriptions
+/// in an environment and the attachments of file elements in a map. The storage never fails.
+#[derive(PartialEq, Clone)]
+pub struct MemoryStorage {
+    ///The environment containing the identifiables.
+    environment: Environment,
+    ///The content types and contents of the attachments by submodel identifier and idShortPath.
+    attachments: HashMap<(String, String), (String, Vec<u8>)>
+}
+
+impl MemoryStorage {
+    ///Creates a new empty storage.
+    pub fn new() -> MemoryStorage {
+        MemoryStorage::from_environment(Environment::new())
+    }
+
+    ///Creates a new storage containing the identifiables of an environment.
+    ///
+    /// [environment]: environment containing the initial identifiables
+    pub fn from_environment(environment: Environment) -> MemoryStorage {
+        MemoryStorage {
+            environment,
+            attachments: HashMap::new()
+        }
+    }
+
+    ///Returns the environment containing the identifiables.
+    pub fn get_environment(&self) -> &Environment {
+        &self.environment
+    }
+
+    ///Consumes the storage and returns the environment containing the identifiables.
+    pub fn into_environment(self) -> Environment {
+        self.environment
+    }
+}
+
+impl Default for MemoryStorage {
+    fn default() -> Self {
+        MemoryStorage::new()
+    }
+}
+
+impl TRepositoryStorage for MemoryStorage {
+    fn get_shells(&self) -> Result<Vec<AssetAdministrationShell>, RepositoryError> {
+        Ok(self.environment.get_asset_administration_shells().clone())
+    }
+
+    fn get_shell(&self, id: &str) -> Result<Option<AssetAdministrationShell>, RepositoryError> {
+        Ok(self.environment.get_asset_administration_shells().iter().find(|shell| shell.get_id() == id).cloned())
+    }
+
+    fn put_shell(&mut self, shell: AssetAdministrationShell) -> Result<(), RepositoryError> {
+        put(self.environment.get_mut_asset_administration_shells(), shell);
+        Ok(())
+    }
+
+    fn delete_shell(&mut self, id: &str) -> Result<bool, RepositoryError> {
+        Ok(delete(self.environment.get_mut_asset_administration_shells(), id))
+    }
+
+    fn get_submodels(&self) -> Result<Vec<Submodel>, RepositoryError> {
+        Ok(self.environment.get_submodels().clone())
+    }
+
+    fn get_submodel(&self, id: &str) -> Result<Option<Submodel>, RepositoryError> {
+        Ok(self.environment.get_submodels().iter().find(|submodel| submodel.get_id() == id).cloned())
+    }
+
+    fn put_submodel(&mut self, submodel: Submodel) -> Result<(), RepositoryError> {
+        put(self.environment.get_mut_submodels(), submodel);
+        Ok(())
+    }
+
+    fn delete_submodel(&mut self, id: &str) -> Result<bool, RepositoryError> {
+        self.attachments.retain(|(submodel_id, _), _| submodel_id != id);
+        Ok(delete(self.environment.get_mut_submodels(), id))
+    }
+
+    fn get_concept_descriptions(&self) -> Result<Vec<ConceptDescription>, RepositoryError> {
+        Ok(self.environment.get_concept_descriptions().clone())
+    }
+
+    fn get_concept_description(&self, id: &str) -> Result<Option<ConceptDescription>, RepositoryError> {
+        Ok(self.environment.get_concept_descriptions().iter()
+            .find(|concept_description| concept_description.get_id() == id).cloned())
+    }
+
+    fn put_concept_description(&mut self, concept_description: ConceptDescription) -> Result<(), RepositoryError> {
+        put(self.environment.get_mut_concept_descriptions(), concept_description);
+        Ok(())
+    }
+
+    fn delete_concept_description(&mut self, id: &str) -> Result<bool, RepositoryError> {
+        Ok(delete(self.environment.get_mut_concept_descriptions(), id))
+    }
+
+    fn get_attachment(&self, submodel_id: &str, id_short_path: &str) -> Result<Option<(String, Vec<u8>)>, RepositoryError> {
+        Ok(self.attachments.get(&(submodel_id.to_string(), id_short_path.to_string())).cloned())
+    }
+
+    fn put_attachment(&mut self, submodel_id: &str, id_short_path: &str, content_type: String, content: Vec<u8>)
+        -> Result<(), RepositoryError> {
+        self.attachments.insert((submodel_id.to_string(), id_short_path.to_string()), (content_type, content));
+        Ok(())
+    }
+
+    fn delete_attachment(&mut self, submodel_id: &str, id_short_path: &str) -> Result<bool, RepositoryError> {
+        Ok(self.attachments.remove(&(submodel_id.to_string(), id_short_path.to_string())).is_some())
+    }
+}
+
+///Replaces the identifiable with the same identifier or appends it.
+fn put<T: TIdentifiable>(identifiables: &mut Vec<T>, identifiable: T) {
+    match identifiables.iter_mut().find(|existing| existing.get_id() == identifiable.get_id()) {
+        Some(existing) => *existing = identifiable,
+        None => identifiables.push(identifiable)
+    }
+}
+
+///Removes the identifiable with the identifier and returns whether it existed.
+fn delete<T: TIdentifiable>(identifiables: &mut Vec<T>, id: &str) -> bool {
+    let count = identifiables.len();
+    identifiables.retain(|identifiable| identifiable.get_id() != id);
+    identifiables.len() != count
+}
\ No newline at end of file
@@ -0,0 +1,110 @@
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::merge_conflict_kind::MergeConflictKind;
+use crate::structs::submodel::Submodel;
+
+///A conflict detected during a three-way merge of submodels. Holds the state of the conflicting
+/// element in the base, our and their version of the submodel, or the versions of the submodel
+/// without their elements if the attributes of the submodel itself conflict.
+#[derive(PartialEq, Clone)]
+pub struct MergeConflict {
+    ///IdShortPath of the conflicting element. An empty path denotes the submodel itself.
+    path: String,
+    ///Kind of the conflict.
+    kind: MergeConflictKind,
+    ///The element in the base version, if present.
+    base: Option<SubmodelElement>,
+    ///The element in our version, if present.
+    ours: Option<SubmodelElement>,
+    ///The element in their version, if present.
+    theirs: Option<SubmodelElement>,
+    ///The base version of the submodel without its elements, for a conflict of the submodel.
+    base_submodel: Option<Submodel>,
+    ///Our version of the submodel without its elements, for a conflict of the submodel.
+    ours_submodel: Option<Submodel>,
+    ///Their version of the submodel without its elements, for a conflict of the submodel.
+    theirs_submodel: Option<Submodel>
+}
+
+impl MergeConflict {
+    ///Creates a new instance of the struct.
+    ///
+    /// [path]: idShortPath of the conflicting element
+    /// [kind]: kind of the conflict
+    /// [base]: element in the base version
+    /// [ours]: element in our version
+    /// [theirs]: element in their version
+    pub fn new(path: String, kind: MergeConflictKind, base: Option<SubmodelElement>,
+               ours: Option<SubmodelElement>, theirs: Option<SubmodelElement>) -> MergeConflict {
+        MergeConflict {
+            path,
+            kind,
+            base,
+            ours,
+            theirs,
+            base_submodel: None,
+            ours_submodel: None,
+            theirs_submodel: None
+        }
+    }
+
+    ///Creates a conflict of the attributes of the submodel itself, which were changed differently
+    /// on both sides. The path of the conflict is empty.
+    ///
+    /// [base]: base version of the submodel without its elements
+    /// [ours]: our version of the submodel without its elements
+    /// [theirs]: their version of the submodel without its elements
+    pub fn for_submodel(base: Submodel, ours: Submodel, theirs: Submodel) -> MergeConflict {
+        MergeConflict {
+            path: String::new(),
+            kind: MergeConflictKind::ModifiedOnBothSides,
+            base: None,
+            ours: None,
+            theirs: None,
+            base_submodel: Some(base),
+            ours_submodel: Some(ours),
+            theirs_submodel: Some(theirs)
+        }
+    }
+
+    ///Returns the idShortPath of the conflicting element. An empty path denotes the submodel
+    /// itself.
+    pub fn get_path(&self) -> &String {
+        &self.path
+    }
+
+    ///Returns the kind of the conflict.
+    pub fn get_kind(&self) -> &MergeConflictKind {
+        &self.kind
+    }
+
+    ///Returns the element in the base version.
+    pub fn get_base(&self) -> Option<&SubmodelElement> {
+        self.base.as_ref()
+    }
+
+    ///Returns the element in our version.
+    pub fn get_ours(&self) -> Option<&SubmodelElement> {
+        self.ours.as_ref()
+    }
+
+    ///Returns the element in their version.
+    pub fn get_theirs(&self) -> Option<&SubmodelElement> {
+        self.theirs.as_ref()
+    }
+    ///Returns the base version of the submodel without its elements if the submodel itself
+    /// conflicts.
+    pub fn get_base_submodel(&self) -> Option<&Submodel> {
+        self.base_submodel.as_ref()
+    }
+
+    ///Returns our version of the submodel without its elements if the submodel itself conflicts.
+    pub fn get_ours_submodel(&self) -> Option<&Submodel> {
+        self.ours_submodel.as_ref()
+    }
+
+    ///Returns their version of the submodel without its elements if the submodel itself
+    /// conflicts.
+    pub fn get_theirs_submodel(&self) -> Option<&Submodel> {
+        self.theirs_submodel.as_ref()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,102 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::enumerations::message_type::MessageType;
+
+///Message in the result of a call of the HTTP API, e.g. the reason why a request failed.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Message {
+    ///Optional code of the message, e.g. the HTTP status code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    ///Optional identifier relating the message to a request.
+    #[serde(rename = "correlationId", default, skip_serializing_if = "Option::is_none")]
+    correlation_id: Option<String>,
+    ///Type of the message.
+    #[serde(rename = "messageType", default = "undefined")]
+    message_type: MessageType,
+    ///Text of the message.
+    #[serde(default)]
+    text: String,
+    ///Optional point in time the message was created as `xs:dateTime` in UTC.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    timestamp: Option<String>
+}
+
+impl Message {
+    ///Creates a new message with the current point in time as timestamp.
+    ///
+    /// [message_type]: type of the message
+    /// [text]: text of the message
+    pub fn new(message_type: MessageType, text: String) -> Message {
+        Message {
+            code: None,
+            correlation_id: None,
+            message_type,
+            text,
+            timestamp: Some(format_timestamp(SystemTime::now()))
+        }
+    }
+
+    ///Sets the code of the message.
+    ///
+    /// [code]: code of the message, e.g. the HTTP status code `404`
+    pub fn set_code(&mut self, code: String) {
+        self.code = Some(code);
+    }
+
+    ///Returns the code of the message.
+    pub fn get_code(&self) -> Option<&String> {
+        self.code.as_ref()
+    }
+
+    ///Sets the identifier relating the message to a request.
+    ///
+    /// [correlation_id]: identifier of the request
+    pub fn set_correlation_id(&mut self, correlation_id: String) {
+        self.correlation_id = Some(correlation_id);
+    }
+
+    ///Returns the identifier relating the message to a request.
+    pub fn get_correlation_id(&self) -> Option<&String> {
+        self.correlation_id.as_ref()
+    }
+
+    ///Returns the type of the message.
+    pub fn get_message_type(&self) -> &MessageType {
+        &self.message_type
+    }
+
+    ///Returns the text of the message.
+    pub fn get_text(&self) -> &String {
+        &self.text
+    }
+
+    ///Returns the point in time the message was created as `xs:dateTime`.
+    pub fn get_timestamp(&self) -> Option<&String> {
+        self.timestamp.as_ref()
+    }
+}
+
+fn undefined() -> MessageType {
+    MessageType::Undefined
+}
+
+///Formats a point in time as `xs:dateTime` in UTC, e.g. `2024-05-01T12:30:00Z`.
+fn format_timestamp(time: SystemTime) -> String {
+    let seconds = time.duration_since(UNIX_EPOCH).map(|duration| duration.as_secs()).unwrap_or_default();
+    let (days, seconds_of_day) = (seconds / 86400, seconds % 86400);
+    // civil date from the days since 1970-01-01 in the proleptic Gregorian calendar
+    let shifted = days as i64 + 719468;
+    let era = shifted.div_euclid(146097);
+    let day_of_era = shifted.rem_euclid(146097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", year, month, day, seconds_of_day / 3600, seconds_of_day / 60 % 60,
+            seconds_of_day % 60)
+}
\ No newline at end of file
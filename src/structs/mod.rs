@@ -28,3 +28,86 @@ pub mod submodel_element_list;
 pub mod concept_description;
 pub mod environment;
 pub mod key;
+
+pub mod merge_conflict;
+pub mod submodel_merge;
+pub mod environment_merge;
+pub mod normalization_options;
+pub mod instantiation_options;
+pub mod conformance_deviation;
+pub mod conformance_report;
+pub mod allowed_range;
+pub mod code_generator;
+pub mod template_element;
+pub mod contact_information;
+pub mod marking;
+pub mod guideline_specific_properties;
+pub mod digital_nameplate;
+pub mod general_information;
+pub mod product_classification;
+pub mod further_information;
+pub mod technical_property;
+pub mod technical_data;
+
+pub mod directory_file_store;
+#[cfg(feature = "aasx")]
+pub mod aasx_package;
+pub mod document_id;
+pub mod document_classification;
+pub mod document_version;
+pub mod document;
+pub mod handover_documentation;
+pub mod goods_address;
+pub mod product_carbon_footprint;
+pub mod transport_carbon_footprint;
+pub mod carbon_footprint;
+pub mod carbon_footprint_aggregate;
+pub mod hierarchical_structures;
+pub mod bom_node;
+pub mod bom_graph;
+pub mod time_series_record;
+pub mod time_series_metadata;
+pub mod time_series_segment;
+pub mod time_series;
+pub mod phone;
+pub mod fax;
+pub mod email;
+pub mod ip_communication;
+pub mod contact_informations;
+pub mod form;
+pub mod property_definition;
+pub mod security_scheme;
+pub mod endpoint_metadata;
+pub mod asset_interface;
+pub mod asset_interfaces_description;
+pub mod http_value_provider;
+pub mod modbus_value_provider;
+pub mod mqtt_value_provider;
+pub mod value_binding;
+pub mod value_bindings;
+pub mod mapping_configuration;
+pub mod asset_interfaces_mapping_configuration;
+pub mod software_nameplate_type;
+pub mod software_nameplate_instance;
+pub mod software_nameplate;
+pub mod product_passport;
+pub mod message;
+pub mod service_result;
+pub mod memory_storage;
+#[cfg(feature = "server")]
+pub mod repository_server;
+pub mod paged_result;
+#[cfg(feature = "client")]
+pub mod aas_client;
+pub mod security_attribute_object;
+pub mod protocol_information;
+pub mod endpoint;
+pub mod submodel_descriptor;
+pub mod asset_administration_shell_descriptor;
+pub mod memory_registry;
+pub mod discovery_index;
+pub mod data_specification_iec61360;
+pub mod embedded_data_specification;
+pub mod level_type;
+pub mod value_list;
+pub mod value_reference_pair;
\ No newline at end of file
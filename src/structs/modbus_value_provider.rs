@@ -0,0 +1,306 @@
+use std::io::{Read, Write};
+use std::time::Duration;
+
+use crate::enumerations::binding_error::BindingError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::modbus_entity::ModbusEntity;
+use crate::structs::value_binding::communication_error;
+use crate::traits::value_provider::TValueProvider;
+use crate::transport::{connect, split_uri, with_default_port};
+
+///Provider reading values from coils, discrete inputs or registers of a Modbus TCP server, e.g. a
+/// PLC.
+///
+/// Registers are decoded by the data type of the bound element: 16-bit types from one register,
+/// 32-bit integers and xs:float from two and 64-bit integers and xs:double from four registers,
+/// each in big-endian order. Strings are read as ASCII from the given quantity of registers.
+#[derive(PartialEq, Clone)]
+pub struct ModbusValueProvider {
+    ///Host and port of the server, e.g. `192.168.0.1:502`.
+    address: String,
+    ///Unit identifier of the addressed device.
+    unit_id: u8,
+    ///Entity the value is read from.
+    entity: ModbusEntity,
+    ///Zero-based address of the first coil, input or register.
+    register: u16,
+    ///Number of coils, inputs or registers to read; None derives it from the data type.
+    quantity: Option<u16>,
+    ///Timeout of connecting and reading.
+    timeout: Duration,
+    ///Identifier of the last transaction.
+    transaction_id: u16
+}
+
+impl ModbusValueProvider {
+    ///Creates a new provider with a timeout of 5 seconds.
+    ///
+    /// [address]: host and port of the server, e.g. `192.168.0.1:502`
+    /// [unit_id]: unit identifier of the addressed device
+    /// [entity]: entity the value is read from
+    /// [register]: zero-based address of the first coil, input or register
+    pub fn new(address: String, unit_id: u8, entity: ModbusEntity, register: u16) -> ModbusValueProvider {
+        ModbusValueProvider {
+            address,
+            unit_id,
+            entity,
+            register,
+            quantity: None,
+            timeout: Duration::from_secs(5),
+            transaction_id: 0
+        }
+    }
+
+    ///Creates a provider of holding registers from a URI of the Modbus binding of the Thing
+    /// Description, e.g. `modbus+tcp://192.168.0.1:502/1/40?quantity=2` for two registers at
+    /// address 40 of unit 1. The unit identifier defaults to 1.
+    ///
+    /// [uri]: URI of the value
+    pub fn from_uri(uri: &str) -> Result<ModbusValueProvider, BindingError> {
+        let invalid = || BindingError::InvalidAddress(uri.to_string());
+        let (scheme, authority, path) = split_uri(uri).ok_or_else(invalid)?;
+        if !matches!(scheme.to_lowercase().as_str(), "modbus" | "modbus+tcp") {
+            return Err(BindingError::UnsupportedProtocol(scheme.to_string()));
+        }
+        let (path, query) = path.split_once('?').unwrap_or((path, ""));
+        let segments: Vec<&str> = path.split('/').filter(|segment| !segment.is_empty()).collect();
+        let (unit_id, register) = match segments.as_slice() {
+            [register] => (1, register.parse().map_err(|_| invalid())?),
+            [unit_id, register] => (unit_id.parse().map_err(|_| invalid())?, register.parse().map_err(|_| invalid())?),
+            _ => return Err(invalid())
+        };
+        let mut provider = ModbusValueProvider::new(with_default_port(authority, 502), unit_id, ModbusEntity::HoldingRegister, register);
+        for (name, value) in query.split('&').filter_map(|parameter| parameter.split_once('=')) {
+            if name == "quantity" {
+                provider.quantity = Some(value.parse().map_err(|_| invalid())?);
+            }
+        }
+        Ok(provider)
+    }
+
+    ///Returns the host and port of the server.
+    pub fn get_address(&self) -> &String {
+        &self.address
+    }
+
+    ///Returns the unit identifier of the addressed device.
+    pub fn get_unit_id(&self) -> u8 {
+        self.unit_id
+    }
+
+    ///Sets the unit identifier of the addressed device.
+    ///
+    /// [unit_id]: unit identifier of the addressed device
+    pub fn set_unit_id(&mut self, unit_id: u8) {
+        self.unit_id = unit_id;
+    }
+
+    ///Returns the entity the value is read from.
+    pub fn get_entity(&self) -> &ModbusEntity {
+        &self.entity
+    }
+
+    ///Sets the entity the value is read from.
+    ///
+    /// [entity]: entity the value is read from
+    pub fn set_entity(&mut self, entity: ModbusEntity) {
+        self.entity = entity;
+    }
+
+    ///Returns the zero-based address of the first coil, input or register.
+    pub fn get_register(&self) -> u16 {
+        self.register
+    }
+
+    ///Sets the zero-based address of the first coil, input or register.
+    ///
+    /// [register]: zero-based address of the first coil, input or register
+    pub fn set_register(&mut self, register: u16) {
+        self.register = register;
+    }
+
+    ///Returns the number of coils, inputs or registers to read.
+    pub fn get_quantity(&self) -> Option<u16> {
+        self.quantity
+    }
+
+    ///Sets the number of coils, inputs or registers to read. Passing None derives it from the data
+    /// type of the bound element.
+    ///
+    /// [quantity]: number of coils, inputs or registers to read
+    pub fn set_quantity(&mut self, quantity: Option<u16>) {
+        self.quantity = quantity;
+    }
+
+    ///Returns the timeout of connecting and reading.
+    pub fn get_timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    ///Sets the timeout of connecting and reading.
+    ///
+    /// [timeout]: timeout of connecting and reading
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    ///Sends a read request and returns the data bytes of the response.
+    fn read(&mut self, quantity: u16) -> Result<Vec<u8>, BindingError> {
+        self.transaction_id = self.transaction_id.wrapping_add(1);
+        let function = self.entity.get_read_function();
+        let mut request = Vec::with_capacity(12);
+        request.extend_from_slice(&self.transaction_id.to_be_bytes());
+        request.extend_from_slice(&[0, 0, 0, 6, self.unit_id, function]);
+        request.extend_from_slice(&self.register.to_be_bytes());
+        request.extend_from_slice(&quantity.to_be_bytes());
+        let mut stream = connect(&self.address, self.timeout).map_err(|error| communication_error(&self.address, error))?;
+        stream.write_all(&request).map_err(|error| communication_error(&self.address, error))?;
+        let mut header = [0; 7];
+        stream.read_exact(&mut header).map_err(|error| communication_error(&self.address, error))?;
+        let length = u16::from_be_bytes([header[4], header[5]]) as usize;
+        if header[0..2] != self.transaction_id.to_be_bytes() || length < 2 {
+            return Err(BindingError::ProtocolError("unexpected Modbus response header".to_string()));
+        }
+        let mut pdu = vec![0; length - 1];
+        stream.read_exact(&mut pdu).map_err(|error| communication_error(&self.address, error))?;
+        if pdu[0] == function | 0x80 {
+            return Err(BindingError::ProtocolError(format!("Modbus exception {}", pdu.get(1).copied().unwrap_or_default())));
+        }
+        if pdu[0] != function || pdu.len() < 2 || pdu.len() != 2 + pdu[1] as usize {
+            return Err(BindingError::ProtocolError("unexpected Modbus response".to_string()));
+        }
+        Ok(pdu.split_off(2))
+    }
+}
+
+impl TValueProvider for ModbusValueProvider {
+    fn read_value(&mut self, value_type: &DataTypeDefXsd) -> Result<String, BindingError> {
+        let quantity = self.quantity.unwrap_or(match self.entity.is_bit() {
+            true => 1,
+            false => register_count(value_type)
+        });
+        let data = self.read(quantity)?;
+        if self.entity.is_bit() {
+            let bit = data.first().is_some_and(|byte| byte & 1 == 1);
+            return Ok(format_bit(bit, value_type));
+        }
+        decode_registers(&data, value_type)
+    }
+}
+
+///Returns the number of registers a value of the data type occupies.
+fn register_count(value_type: &DataTypeDefXsd) -> u16 {
+    match value_type {
+        DataTypeDefXsd::Long | DataTypeDefXsd::UnsignedLong | DataTypeDefXsd::Double => 4,
+        DataTypeDefXsd::Int | DataTypeDefXsd::UnsignedInt | DataTypeDefXsd::Float => 2,
+        _ => 1
+    }
+}
+
+fn format_bit(bit: bool, value_type: &DataTypeDefXsd) -> String {
+    match value_type {
+        DataTypeDefXsd::Boolean | DataTypeDefXsd::String => bit.to_string(),
+        _ => (bit as u8).to_string()
+    }
+}
+
+///Decodes the big-endian register data as value of the data type.
+fn decode_registers(data: &[u8], value_type: &DataTypeDefXsd) -> Result<String, BindingError> {
+    let invalid = || BindingError::ProtocolError(format!("{} bytes cannot be decoded as value of the data type", data.len()));
+    let value = match value_type {
+        DataTypeDefXsd::Boolean => format_bit(data.iter().any(|byte| *byte != 0), value_type),
+        DataTypeDefXsd::String => String::from_utf8_lossy(data).trim_end_matches('\0').to_string(),
+        DataTypeDefXsd::Float => f32::from_be_bytes(data.try_into().map_err(|_| invalid())?).to_string(),
+        DataTypeDefXsd::Double => f64::from_be_bytes(data.try_into().map_err(|_| invalid())?).to_string(),
+        DataTypeDefXsd::Byte | DataTypeDefXsd::Short | DataTypeDefXsd::Int | DataTypeDefXsd::Long | DataTypeDefXsd::Integer
+        | DataTypeDefXsd::Decimal | DataTypeDefXsd::NegativeInteger | DataTypeDefXsd::NonPositiveInteger => match data.len() {
+            2 => i16::from_be_bytes(data.try_into().map_err(|_| invalid())?).to_string(),
+            4 => i32::from_be_bytes(data.try_into().map_err(|_| invalid())?).to_string(),
+            8 => i64::from_be_bytes(data.try_into().map_err(|_| invalid())?).to_string(),
+            _ => return Err(invalid())
+        },
+        _ => match data.len() {
+            2 | 4 | 8 => data.iter().fold(0u64, |value, byte| value << 8 | *byte as u64).to_string(),
+            _ => return Err(invalid())
+        }
+    };
+    Ok(value)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    use crate::enumerations::binding_error::BindingError;
+    use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+    use crate::enumerations::modbus_entity::ModbusEntity;
+    use crate::structs::modbus_value_provider::ModbusValueProvider;
+    use crate::traits::value_provider::TValueProvider;
+
+    ///Answers a single request on a loopback port with the PDU and returns the address of the
+    /// server and the handle of the server thread, which yields the received request.
+    fn serve(pdu: Vec<u8>) -> (String, JoinHandle<Vec<u8>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![0; 12];
+            stream.read_exact(&mut request).unwrap();
+            let mut response = request[0..4].to_vec();
+            response.extend_from_slice(&(pdu.len() as u16 + 1).to_be_bytes());
+            response.push(request[6]);
+            response.extend_from_slice(&pdu);
+            stream.write_all(&response).unwrap();
+            request
+        });
+        (address, server)
+    }
+
+    #[test]
+    fn reads_integer_from_two_registers() {
+        let (address, server) = serve(vec![3, 4, 0xFF, 0xFF, 0xFF, 0xFE]);
+        let mut provider = ModbusValueProvider::new(address, 7, ModbusEntity::HoldingRegister, 40);
+        assert_eq!(provider.read_value(&DataTypeDefXsd::Int), Ok("-2".to_string()));
+        assert_eq!(server.join().unwrap(), vec![0, 1, 0, 0, 0, 6, 7, 3, 0, 40, 0, 2]);
+    }
+
+    #[test]
+    fn reads_float_from_input_registers() {
+        let mut pdu = vec![4, 4];
+        pdu.extend_from_slice(&21.5f32.to_be_bytes());
+        let (address, server) = serve(pdu);
+        let mut provider = ModbusValueProvider::new(address, 1, ModbusEntity::InputRegister, 0);
+        assert_eq!(provider.read_value(&DataTypeDefXsd::Float), Ok("21.5".to_string()));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn reads_coil_as_boolean() {
+        let (address, server) = serve(vec![1, 1, 1]);
+        let mut provider = ModbusValueProvider::new(address, 1, ModbusEntity::Coil, 3);
+        assert_eq!(provider.read_value(&DataTypeDefXsd::Boolean), Ok("true".to_string()));
+        assert_eq!(server.join().unwrap()[7..], [1, 0, 3, 0, 1]);
+    }
+
+    #[test]
+    fn reports_exception_response() {
+        let (address, server) = serve(vec![0x83, 2]);
+        let mut provider = ModbusValueProvider::new(address, 1, ModbusEntity::HoldingRegister, 9999);
+        assert_eq!(provider.read_value(&DataTypeDefXsd::Short), Err(BindingError::ProtocolError("Modbus exception 2".to_string())));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn parses_uri_of_modbus_binding() {
+        let provider = ModbusValueProvider::from_uri("modbus+tcp://192.168.0.1/3/40?quantity=2").unwrap();
+        assert_eq!(provider.get_address(), "192.168.0.1:502");
+        assert_eq!(provider.get_unit_id(), 3);
+        assert_eq!(provider.get_register(), 40);
+        assert_eq!(provider.get_quantity(), Some(2));
+        assert_eq!(ModbusValueProvider::from_uri("modbus://192.168.0.1/a").err(),
+                   Some(BindingError::InvalidAddress("modbus://192.168.0.1/a".to_string())));
+    }
+}
\ No newline at end of file
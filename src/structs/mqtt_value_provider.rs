@@ -0,0 +1,280 @@
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::enumerations::binding_error::BindingError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::structs::value_binding::{communication_error, extract_value};
+use crate::traits::value_provider::TValueProvider;
+use crate::transport::{connect, split_uri, with_default_port};
+
+///Provider reading values from an MQTT topic, e.g. the telemetry a device publishes to a broker.
+///
+/// Each read connects to the broker with MQTT 3.1.1, subscribes to the topic and takes the payload
+/// of the first message, which is the retained message if the broker has one. JSON payloads are
+/// handled like the bodies of HTTP responses. Only plain TCP without authentication is supported.
+#[derive(PartialEq, Clone)]
+pub struct MqttValueProvider {
+    ///Host and port of the broker, e.g. `192.168.0.1:1883`.
+    address: String,
+    ///Topic the value is published to.
+    topic: String,
+    ///JSON pointer selecting the value within a structured JSON payload, e.g. `/value`.
+    json_pointer: Option<String>,
+    ///Timeout of connecting and of waiting for a message.
+    timeout: Duration
+}
+
+impl MqttValueProvider {
+    ///Creates a new provider with a timeout of 5 seconds.
+    ///
+    /// [address]: host and port of the broker, e.g. `192.168.0.1:1883`
+    /// [topic]: topic the value is published to, e.g. `pump/temperature`
+    pub fn new(address: String, topic: String) -> MqttValueProvider {
+        MqttValueProvider {
+            address,
+            topic,
+            json_pointer: None,
+            timeout: Duration::from_secs(5)
+        }
+    }
+
+    ///Creates a provider from a URI of the MQTT binding of the Thing Description, e.g.
+    /// `mqtt://192.168.0.1:1883/pump/temperature` for the topic `pump/temperature`.
+    ///
+    /// [uri]: URI of the value
+    pub fn from_uri(uri: &str) -> Result<MqttValueProvider, BindingError> {
+        let (scheme, authority, path) = split_uri(uri).ok_or_else(|| BindingError::InvalidAddress(uri.to_string()))?;
+        if !scheme.eq_ignore_ascii_case("mqtt") {
+            return Err(BindingError::UnsupportedProtocol(scheme.to_string()));
+        }
+        let topic = path.trim_start_matches('/');
+        if topic.is_empty() {
+            return Err(BindingError::InvalidAddress(uri.to_string()));
+        }
+        Ok(MqttValueProvider::new(with_default_port(authority, 1883), topic.to_string()))
+    }
+
+    ///Returns the host and port of the broker.
+    pub fn get_address(&self) -> &String {
+        &self.address
+    }
+
+    ///Returns the topic the value is published to.
+    pub fn get_topic(&self) -> &String {
+        &self.topic
+    }
+
+    ///Sets the topic the value is published to.
+    ///
+    /// [topic]: topic the value is published to
+    pub fn set_topic(&mut self, topic: String) {
+        self.topic = topic;
+    }
+
+    ///Returns the JSON pointer selecting the value within a structured JSON payload.
+    pub fn get_json_pointer(&self) -> Option<&String> {
+        self.json_pointer.as_ref()
+    }
+
+    ///Sets the JSON pointer selecting the value within a structured JSON payload. Passing None
+    /// takes the whole payload.
+    ///
+    /// [json_pointer]: JSON pointer, e.g. `/value`
+    pub fn set_json_pointer(&mut self, json_pointer: Option<String>) {
+        self.json_pointer = json_pointer;
+    }
+
+    ///Returns the timeout of connecting and of waiting for a message.
+    pub fn get_timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    ///Sets the timeout of connecting and of waiting for a message.
+    ///
+    /// [timeout]: timeout of connecting and of waiting for a message
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    ///Subscribes to the topic and returns the payload of the first message.
+    fn receive(&self) -> Result<Vec<u8>, BindingError> {
+        let io_error = |error| communication_error(&self.address, error);
+        let mut stream = connect(&self.address, self.timeout).map_err(io_error)?;
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.subsec_nanos()).unwrap_or_default();
+        let client_id = format!("aas-model-rs-{}-{}", std::process::id(), nanos);
+        let mut connect_packet = vec![0, 4, b'M', b'Q', b'T', b'T', 4, 0x02, 0, 60];
+        push_string(&mut connect_packet, &client_id);
+        write_packet(&mut stream, 0x10, &connect_packet).map_err(io_error)?;
+        let (packet_type, payload) = read_packet(&mut stream).map_err(io_error)?;
+        if packet_type >> 4 != 2 || payload.len() < 2 {
+            return Err(BindingError::ProtocolError("expected CONNACK".to_string()));
+        }
+        if payload[1] != 0 {
+            return Err(BindingError::ProtocolError(format!("connection refused with code {}", payload[1])));
+        }
+        let mut subscribe_packet = vec![0, 1];
+        push_string(&mut subscribe_packet, &self.topic);
+        subscribe_packet.push(0);
+        write_packet(&mut stream, 0x82, &subscribe_packet).map_err(io_error)?;
+        let deadline = Instant::now() + self.timeout;
+        loop {
+            if Instant::now() >= deadline {
+                return Err(BindingError::Timeout);
+            }
+            let (packet_type, payload) = read_packet(&mut stream).map_err(io_error)?;
+            match packet_type >> 4 {
+                3 => {
+                    let topic_length = payload.get(0..2).map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]) as usize)
+                        .ok_or_else(|| BindingError::ProtocolError("malformed PUBLISH".to_string()))?;
+                    let quality_of_service = (packet_type >> 1) & 0x03;
+                    let start = 2 + topic_length + if quality_of_service > 0 { 2 } else { 0 };
+                    let message = payload.get(start..).ok_or_else(|| BindingError::ProtocolError("malformed PUBLISH".to_string()))?.to_vec();
+                    if quality_of_service == 1 {
+                        write_packet(&mut stream, 0x40, &payload[start - 2..start]).map_err(io_error)?;
+                    }
+                    let _ = write_packet(&mut stream, 0xE0, &[]);
+                    return Ok(message);
+                },
+                9 if payload.get(2) == Some(&0x80) => return Err(BindingError::ProtocolError(format!("subscription to '{}' refused", self.topic))),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl TValueProvider for MqttValueProvider {
+    fn read_value(&mut self, _value_type: &DataTypeDefXsd) -> Result<String, BindingError> {
+        let message = self.receive()?;
+        let text = String::from_utf8(message).map_err(|_| BindingError::ProtocolError("the payload is no UTF-8 text".to_string()))?;
+        extract_value(&text, self.json_pointer.as_deref())
+    }
+}
+
+fn push_string(buffer: &mut Vec<u8>, text: &str) {
+    buffer.extend_from_slice(&(text.len() as u16).to_be_bytes());
+    buffer.extend_from_slice(text.as_bytes());
+}
+
+fn write_packet(stream: &mut TcpStream, header: u8, payload: &[u8]) -> std::io::Result<()> {
+    let mut packet = vec![header];
+    let mut length = payload.len();
+    loop {
+        let mut byte = (length % 128) as u8;
+        length /= 128;
+        if length > 0 {
+            byte |= 0x80;
+        }
+        packet.push(byte);
+        if length == 0 {
+            break;
+        }
+    }
+    packet.extend_from_slice(payload);
+    stream.write_all(&packet)
+}
+
+fn read_packet(stream: &mut TcpStream) -> std::io::Result<(u8, Vec<u8>)> {
+    let mut byte = [0; 1];
+    stream.read_exact(&mut byte)?;
+    let packet_type = byte[0];
+    let mut length = 0;
+    let mut multiplier = 1;
+    loop {
+        stream.read_exact(&mut byte)?;
+        length += (byte[0] & 0x7F) as usize * multiplier;
+        multiplier *= 128;
+        if byte[0] & 0x80 == 0 {
+            break;
+        }
+        if multiplier > 128 * 128 * 128 {
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "malformed remaining length"));
+        }
+    }
+    let mut payload = vec![0; length];
+    stream.read_exact(&mut payload)?;
+    Ok((packet_type, payload))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::net::{TcpListener, TcpStream};
+    use std::thread::JoinHandle;
+
+    use crate::enumerations::binding_error::BindingError;
+    use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+    use crate::structs::mqtt_value_provider::{push_string, read_packet, write_packet, MqttValueProvider};
+    use crate::traits::value_provider::TValueProvider;
+
+    ///Runs a stand-in broker on a loopback port for a single client and returns its address and
+    /// the handle of the broker thread, which yields the subscribed topic. The broker acknowledges
+    /// the subscription with the granted QoS, 0x80 refusing it, and publishes the payload with the
+    /// granted QoS.
+    fn serve(granted: u8, payload: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let broker = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let (packet_type, _) = read_packet(&mut stream).unwrap();
+            assert_eq!(packet_type, 0x10);
+            write_packet(&mut stream, 0x20, &[0, 0]).unwrap();
+            let (packet_type, subscription) = read_packet(&mut stream).unwrap();
+            assert_eq!(packet_type, 0x82);
+            let topic_length = u16::from_be_bytes([subscription[2], subscription[3]]) as usize;
+            let topic = String::from_utf8(subscription[4..4 + topic_length].to_vec()).unwrap();
+            write_packet(&mut stream, 0x90, &[subscription[0], subscription[1], granted]).unwrap();
+            if granted == 0x80 {
+                return topic;
+            }
+            publish(&mut stream, granted, &topic, payload);
+            if granted == 1 {
+                assert_eq!(read_packet(&mut stream).unwrap(), (0x40, vec![0, 5]));
+            }
+            topic
+        });
+        (address, broker)
+    }
+
+    fn publish(stream: &mut TcpStream, quality_of_service: u8, topic: &str, payload: &str) {
+        let mut packet = Vec::new();
+        push_string(&mut packet, topic);
+        if quality_of_service > 0 {
+            packet.extend_from_slice(&[0, 5]);
+        }
+        packet.extend_from_slice(payload.as_bytes());
+        write_packet(stream, 0x30 | quality_of_service << 1, &packet).unwrap();
+    }
+
+    #[test]
+    fn receives_plain_payload() {
+        let (address, broker) = serve(0, "21.5");
+        let mut provider = MqttValueProvider::from_uri(&format!("mqtt://{}/pump/temperature", address)).unwrap();
+        assert_eq!(provider.read_value(&DataTypeDefXsd::Double), Ok("21.5".to_string()));
+        assert_eq!(broker.join().unwrap(), "pump/temperature");
+    }
+
+    #[test]
+    fn acknowledges_payload_published_with_qos_1() {
+        let (address, broker) = serve(1, "{\"temperature\": 21, \"unit\": \"C\"}");
+        let mut provider = MqttValueProvider::new(address, "pump".to_string());
+        provider.set_json_pointer(Some("/temperature".to_string()));
+        assert_eq!(provider.read_value(&DataTypeDefXsd::Int), Ok("21".to_string()));
+        broker.join().unwrap();
+    }
+
+    #[test]
+    fn reports_refused_subscription() {
+        let (address, broker) = serve(0x80, "");
+        let mut provider = MqttValueProvider::new(address, "pump".to_string());
+        assert_eq!(provider.read_value(&DataTypeDefXsd::Int),
+                   Err(BindingError::ProtocolError("subscription to 'pump' refused".to_string())));
+        broker.join().unwrap();
+    }
+
+    #[test]
+    fn rejects_uri_without_topic() {
+        assert_eq!(MqttValueProvider::from_uri("mqtt://broker").err(), Some(BindingError::InvalidAddress("mqtt://broker".to_string())));
+        assert_eq!(MqttValueProvider::from_uri("mqtts://broker/pump").err(), Some(BindingError::UnsupportedProtocol("mqtts".to_string())));
+    }
+}
\ No newline at end of file
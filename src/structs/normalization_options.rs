@@ -0,0 +1,83 @@
+///Options controlling which steps are applied when an environment is normalized.
+#[derive(PartialEq, Clone)]
+pub struct NormalizationOptions {
+    ///Whether concept descriptions not referenced by any shell or submodel are removed.
+    remove_unreferenced_concept_descriptions: bool,
+    ///Whether identical references in supplemental semantic IDs and embedded data specifications
+    /// are removed.
+    deduplicate_references: bool,
+    ///Whether shells, submodels and concept descriptions are sorted by their ID.
+    sort_identifiables: bool,
+    ///Whether the elements of submodel element lists whose order is not relevant are sorted.
+    sort_unordered_lists: bool
+}
+
+impl NormalizationOptions {
+    ///Creates a new instance of the struct. All steps are enabled except for sorting submodel
+    /// element lists, whose order is kept.
+    pub fn new() -> NormalizationOptions {
+        NormalizationOptions {
+            remove_unreferenced_concept_descriptions: true,
+            deduplicate_references: true,
+            sort_identifiables: true,
+            sort_unordered_lists: false
+        }
+    }
+
+    ///Sets whether concept descriptions not referenced by any shell or submodel are removed.
+    ///
+    /// [remove_unreferenced_concept_descriptions]: whether unreferenced concept descriptions are removed
+    pub fn set_remove_unreferenced_concept_descriptions(&mut self, remove_unreferenced_concept_descriptions: bool) {
+        self.remove_unreferenced_concept_descriptions = remove_unreferenced_concept_descriptions;
+    }
+
+    ///Returns whether concept descriptions not referenced by any shell or submodel are removed.
+    pub fn get_remove_unreferenced_concept_descriptions(&self) -> bool {
+        self.remove_unreferenced_concept_descriptions
+    }
+
+    ///Sets whether identical references in supplemental semantic IDs and embedded data
+    /// specifications are removed.
+    ///
+    /// [deduplicate_references]: whether identical references are removed
+    pub fn set_deduplicate_references(&mut self, deduplicate_references: bool) {
+        self.deduplicate_references = deduplicate_references;
+    }
+
+    ///Returns whether identical references in supplemental semantic IDs and embedded data
+    /// specifications are removed.
+    pub fn get_deduplicate_references(&self) -> bool {
+        self.deduplicate_references
+    }
+
+    ///Sets whether shells, submodels and concept descriptions are sorted by their ID.
+    ///
+    /// [sort_identifiables]: whether identifiables are sorted
+    pub fn set_sort_identifiables(&mut self, sort_identifiables: bool) {
+        self.sort_identifiables = sort_identifiables;
+    }
+
+    ///Returns whether shells, submodels and concept descriptions are sorted by their ID.
+    pub fn get_sort_identifiables(&self) -> bool {
+        self.sort_identifiables
+    }
+
+    ///Sets whether the elements of submodel element lists whose order is not relevant are sorted.
+    ///
+    /// [sort_unordered_lists]: whether unordered lists are sorted
+    pub fn set_sort_unordered_lists(&mut self, sort_unordered_lists: bool) {
+        self.sort_unordered_lists = sort_unordered_lists;
+    }
+
+    ///Returns whether the elements of submodel element lists whose order is not relevant are
+    /// sorted.
+    pub fn get_sort_unordered_lists(&self) -> bool {
+        self.sort_unordered_lists
+    }
+}
+
+impl Default for NormalizationOptions {
+    fn default() -> NormalizationOptions {
+        NormalizationOptions::new()
+    }
+}
@@ -0,0 +1,53 @@
+///Page of a list returned by the HTTP API together with the cursor of the next page.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PagedResult<T> {
+    ///Items of the page.
+    result: Vec<T>,
+    ///Cursor of the next page, which is None for the last page.
+    cursor: Option<String>
+}
+
+impl<T> PagedResult<T> {
+    ///Creates a new page.
+    ///
+    /// [result]: items of the page
+    /// [cursor]: cursor of the next page, None for the last page
+    pub fn new(result: Vec<T>, cursor: Option<String>) -> PagedResult<T> {
+        PagedResult {
+            result,
+            cursor
+        }
+    }
+
+    ///Collects the items of all pages by requesting the pages one after another, starting without
+    /// a cursor.
+    ///
+    /// [next_page]: requests the page at the given cursor, e.g. by calling `AasClient::get_shells`
+    pub fn collect_all<E>(mut next_page: impl FnMut(Option<&str>) -> Result<PagedResult<T>, E>) -> Result<Vec<T>, E> {
+        let mut items = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let page = next_page(cursor.as_deref())?;
+            items.extend(page.result);
+            match page.cursor {
+                Some(next) if Some(&next) != cursor.as_ref() => cursor = Some(next),
+                _ => return Ok(items)
+            }
+        }
+    }
+
+    ///Returns the items of the page.
+    pub fn get_result(&self) -> &Vec<T> {
+        &self.result
+    }
+
+    ///Returns the cursor of the next page, which is None for the last page.
+    pub fn get_cursor(&self) -> Option<&String> {
+        self.cursor.as_ref()
+    }
+
+    ///Consumes the page and returns its items.
+    pub fn into_result(self) -> Vec<T> {
+        self.result
+    }
+}
\ No newline at end of file
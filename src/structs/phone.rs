@@ -0,0 +1,119 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::multi_language_text_type::MultiLanguageTextType;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, TemplateElement};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on the telephone of a contact information.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct Phone {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl Phone {
+    ///Semantic ID of a phone.
+    pub const SEMANTIC_ID: &str = "0173-1#02-AAQ833#005";
+
+    ///Creates a new phone.
+    ///
+    /// [telephone_number]: complete telephone number including the country and area code
+    pub fn new(telephone_number: Vec<MultiLanguageTextType>) -> Phone {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_semantic_id(global_reference(Phone::SEMANTIC_ID));
+        let mut phone = Phone {
+            collection
+        };
+        phone.set_telephone_number(telephone_number);
+        phone
+    }
+
+    ///Wraps a submodel element collection after checking the elements of a phone.
+    ///
+    /// [collection]: collection containing a phone
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<Phone, ConversionError> {
+        Phone::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(Phone {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the phone and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the complete telephone number including the country and area code.
+    pub fn get_telephone_number(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(TELEPHONE_NUMBER.id_short, TELEPHONE_NUMBER.semantic_id)
+    }
+
+    ///Sets the complete telephone number including the country and area code.
+    ///
+    /// [telephone_number]: complete telephone number including the country and area code
+    pub fn set_telephone_number(&mut self, telephone_number: Vec<MultiLanguageTextType>) {
+        self.collection.set_multi_language_value(TELEPHONE_NUMBER.id_short, TELEPHONE_NUMBER.semantic_id, Some(telephone_number));
+    }
+
+    ///Returns the type of the telephone, e.g. office or mobile.
+    pub fn get_type_of_telephone(&self) -> Option<String> {
+        self.collection.get_property_value(TYPE_OF_TELEPHONE.id_short, TYPE_OF_TELEPHONE.semantic_id)
+    }
+
+    ///Sets the type of the telephone, e.g. office or mobile. Passing None removes it.
+    ///
+    /// [type_of_telephone]: type of the telephone, e.g. office or mobile
+    pub fn set_type_of_telephone(&mut self, type_of_telephone: Option<String>) {
+        TYPE_OF_TELEPHONE.set_property_value(&mut self.collection, type_of_telephone.as_ref());
+    }
+
+    ///Returns the times the contact is available by telephone.
+    pub fn get_available_time(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(AVAILABLE_TIME.id_short, AVAILABLE_TIME.semantic_id)
+    }
+
+    ///Sets the times the contact is available by telephone. Passing None removes it.
+    ///
+    /// [available_time]: times the contact is available by telephone
+    pub fn set_available_time(&mut self, available_time: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(AVAILABLE_TIME.id_short, AVAILABLE_TIME.semantic_id, available_time);
+    }
+
+    ///Returns the elements of a phone in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        ELEMENTS.iter().map(|element| element.to_element()).collect()
+    }
+
+    ///Checks the elements of a phone.
+    ///
+    /// [collection]: collection containing a phone
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        Ok(())
+    }
+}
+
+const TELEPHONE_NUMBER: TemplateElement =
+    TemplateElement::new("TelephoneNumber", "0173-1#02-AAO136#002", AasSubmodelElements::MultiLanguageProperty, Cardinality::One);
+const TYPE_OF_TELEPHONE: TemplateElement =
+    TemplateElement::property("TypeOfTelephone", "0173-1#02-AAO137#003", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const AVAILABLE_TIME: TemplateElement =
+    TemplateElement::new("AvailableTime", "https://admin-shell.io/zvei/nameplate/1/0/ContactInformations/ContactInformation/AvailableTime/", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+
+const ELEMENTS: [&TemplateElement; 3] = [&TELEPHONE_NUMBER, &TYPE_OF_TELEPHONE, &AVAILABLE_TIME];
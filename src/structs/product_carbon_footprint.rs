@@ -0,0 +1,246 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::file::File;
+use crate::structs::goods_address::GoodsAddress;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, join_path, TemplateElement};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on a product carbon footprint of the Carbon Footprint, i.e. the greenhouse gas
+/// emissions of the product in life cycle phases.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct ProductCarbonFootprint {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl ProductCarbonFootprint {
+    ///Semantic ID of a product carbon footprint.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/idta/CarbonFootprint/ProductCarbonFootprint/0/9";
+
+    ///Creates a new product carbon footprint.
+    ///
+    /// [pcf_calculation_methods]: standards the footprint is calculated according to, e.g. EN 15804
+    /// [pcf_co2eq]: greenhouse gas emissions in kg CO2 equivalents
+    /// [pcf_reference_value_for_calculation]: unit of the quantity the emissions refer to, e.g. piece
+    /// [pcf_quantity_of_measure_for_calculation]: quantity the emissions refer to
+    /// [pcf_life_cycle_phases]: life cycle phases covered, e.g. A1-A3
+    pub fn new(pcf_calculation_methods: Vec<String>, pcf_co2eq: f64, pcf_reference_value_for_calculation: String,
+               pcf_quantity_of_measure_for_calculation: f64, pcf_life_cycle_phases: Vec<String>) -> ProductCarbonFootprint {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_semantic_id(global_reference(ProductCarbonFootprint::SEMANTIC_ID));
+        let mut product_carbon_footprint = ProductCarbonFootprint {
+            collection
+        };
+        product_carbon_footprint.set_pcf_calculation_methods(pcf_calculation_methods);
+        product_carbon_footprint.set_pcf_co2eq(pcf_co2eq);
+        product_carbon_footprint.set_pcf_reference_value_for_calculation(pcf_reference_value_for_calculation);
+        product_carbon_footprint.set_pcf_quantity_of_measure_for_calculation(pcf_quantity_of_measure_for_calculation);
+        product_carbon_footprint.set_pcf_life_cycle_phases(pcf_life_cycle_phases);
+        product_carbon_footprint
+    }
+
+    ///Wraps a submodel element collection after checking the elements of a product carbon footprint.
+    ///
+    /// [collection]: collection containing a product carbon footprint
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<ProductCarbonFootprint, ConversionError> {
+        ProductCarbonFootprint::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(ProductCarbonFootprint {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the product carbon footprint and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the standards the footprint is calculated according to, e.g. EN 15804.
+    pub fn get_pcf_calculation_methods(&self) -> Vec<String> {
+        self.collection.find_elements(PCF_CALCULATION_METHOD.id_short, PCF_CALCULATION_METHOD.semantic_id).into_iter()
+            .filter_map(|element| match element {
+                SubmodelElement::Property(property) => property.get_typed_value(),
+                _ => None
+            })
+            .collect()
+    }
+
+    ///Sets the standards the footprint is calculated according to, e.g. EN 15804.
+    ///
+    /// [pcf_calculation_methods]: standards the footprint is calculated according to, e.g. EN 15804
+    pub fn set_pcf_calculation_methods(&mut self, pcf_calculation_methods: Vec<String>) {
+        let elements = pcf_calculation_methods.iter().map(|value| PCF_CALCULATION_METHOD.new_property(value)).collect();
+        self.collection.set_elements(PCF_CALCULATION_METHOD.id_short, PCF_CALCULATION_METHOD.semantic_id, elements);
+    }
+
+    ///Returns the greenhouse gas emissions in kg CO2 equivalents.
+    pub fn get_pcf_co2eq(&self) -> Option<f64> {
+        self.collection.get_property_value(PCF_CO2EQ.id_short, PCF_CO2EQ.semantic_id)
+    }
+
+    ///Sets the greenhouse gas emissions in kg CO2 equivalents.
+    ///
+    /// [pcf_co2eq]: greenhouse gas emissions in kg CO2 equivalents
+    pub fn set_pcf_co2eq(&mut self, pcf_co2eq: f64) {
+        PCF_CO2EQ.set_property_value(&mut self.collection, Some(&pcf_co2eq));
+    }
+
+    ///Returns the unit of the quantity the emissions refer to, e.g. piece.
+    pub fn get_pcf_reference_value_for_calculation(&self) -> Option<String> {
+        self.collection.get_property_value(PCF_REFERENCE_VALUE_FOR_CALCULATION.id_short, PCF_REFERENCE_VALUE_FOR_CALCULATION.semantic_id)
+    }
+
+    ///Sets the unit of the quantity the emissions refer to, e.g. piece.
+    ///
+    /// [pcf_reference_value_for_calculation]: unit of the quantity the emissions refer to, e.g. piece
+    pub fn set_pcf_reference_value_for_calculation(&mut self, pcf_reference_value_for_calculation: String) {
+        PCF_REFERENCE_VALUE_FOR_CALCULATION.set_property_value(&mut self.collection, Some(&pcf_reference_value_for_calculation));
+    }
+
+    ///Returns the quantity the emissions refer to.
+    pub fn get_pcf_quantity_of_measure_for_calculation(&self) -> Option<f64> {
+        self.collection.get_property_value(PCF_QUANTITY_OF_MEASURE_FOR_CALCULATION.id_short, PCF_QUANTITY_OF_MEASURE_FOR_CALCULATION.semantic_id)
+    }
+
+    ///Sets the quantity the emissions refer to.
+    ///
+    /// [pcf_quantity_of_measure_for_calculation]: quantity the emissions refer to
+    pub fn set_pcf_quantity_of_measure_for_calculation(&mut self, pcf_quantity_of_measure_for_calculation: f64) {
+        PCF_QUANTITY_OF_MEASURE_FOR_CALCULATION.set_property_value(&mut self.collection, Some(&pcf_quantity_of_measure_for_calculation));
+    }
+
+    ///Returns the life cycle phases covered, e.g. A1-A3.
+    pub fn get_pcf_life_cycle_phases(&self) -> Vec<String> {
+        self.collection.find_elements(PCF_LIFE_CYCLE_PHASE.id_short, PCF_LIFE_CYCLE_PHASE.semantic_id).into_iter()
+            .filter_map(|element| match element {
+                SubmodelElement::Property(property) => property.get_typed_value(),
+                _ => None
+            })
+            .collect()
+    }
+
+    ///Sets the life cycle phases covered, e.g. A1-A3.
+    ///
+    /// [pcf_life_cycle_phases]: life cycle phases covered, e.g. A1-A3
+    pub fn set_pcf_life_cycle_phases(&mut self, pcf_life_cycle_phases: Vec<String>) {
+        let elements = pcf_life_cycle_phases.iter().map(|value| PCF_LIFE_CYCLE_PHASE.new_property(value)).collect();
+        self.collection.set_elements(PCF_LIFE_CYCLE_PHASE.id_short, PCF_LIFE_CYCLE_PHASE.semantic_id, elements);
+    }
+
+    ///Returns the file with an explanatory statement on the calculation.
+    pub fn get_explanatory_statement(&self) -> Option<&File> {
+        self.collection.get_file(EXPLANATORY_STATEMENT.id_short, EXPLANATORY_STATEMENT.semantic_id)
+    }
+
+    ///Sets the file with an explanatory statement on the calculation. Passing None removes it.
+    ///
+    /// [explanatory_statement]: file with an explanatory statement on the calculation
+    pub fn set_explanatory_statement(&mut self, explanatory_statement: Option<File>) {
+        self.collection.set_element(EXPLANATORY_STATEMENT.id_short, EXPLANATORY_STATEMENT.semantic_id, explanatory_statement.map(SubmodelElement::File));
+    }
+
+    ///Returns the time the footprint was published, as xs:dateTime.
+    pub fn get_publication_date(&self) -> Option<String> {
+        self.collection.get_property_value(PUBLICATION_DATE.id_short, PUBLICATION_DATE.semantic_id)
+    }
+
+    ///Sets the time the footprint was published, as xs:dateTime. Passing None removes it.
+    ///
+    /// [publication_date]: time the footprint was published, as xs:dateTime
+    pub fn set_publication_date(&mut self, publication_date: Option<String>) {
+        PUBLICATION_DATE.set_property_value(&mut self.collection, publication_date.as_ref());
+    }
+
+    ///Returns the time the footprint expires, as xs:dateTime.
+    pub fn get_expiration_date(&self) -> Option<String> {
+        self.collection.get_property_value(EXPIRATION_DATE.id_short, EXPIRATION_DATE.semantic_id)
+    }
+
+    ///Sets the time the footprint expires, as xs:dateTime. Passing None removes it.
+    ///
+    /// [expiration_date]: time the footprint expires, as xs:dateTime
+    pub fn set_expiration_date(&mut self, expiration_date: Option<String>) {
+        EXPIRATION_DATE.set_property_value(&mut self.collection, expiration_date.as_ref());
+    }
+
+    ///Returns the address at which the goods are handed over to the customer.
+    pub fn get_pcf_goods_address_handover(&self) -> Option<GoodsAddress> {
+        self.collection.get_collection(PCF_GOODS_ADDRESS_HANDOVER.id_short, PCF_GOODS_ADDRESS_HANDOVER.semantic_id)
+            .map(|collection| GoodsAddress {
+                collection: collection.clone()
+            })
+    }
+
+    ///Sets the address at which the goods are handed over to the customer. Passing None removes it.
+    ///
+    /// [pcf_goods_address_handover]: address at which the goods are handed over to the customer
+    pub fn set_pcf_goods_address_handover(&mut self, pcf_goods_address_handover: Option<GoodsAddress>) {
+        self.collection.set_element(PCF_GOODS_ADDRESS_HANDOVER.id_short, PCF_GOODS_ADDRESS_HANDOVER.semantic_id, pcf_goods_address_handover.map(|pcf_goods_address_handover| SubmodelElement::SubmodelElementCollection(pcf_goods_address_handover.into_collection())));
+    }
+
+    ///Returns the elements of a product carbon footprint in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        vec![
+            PCF_CALCULATION_METHOD.to_element(),
+            PCF_CO2EQ.to_element(),
+            PCF_REFERENCE_VALUE_FOR_CALCULATION.to_element(),
+            PCF_QUANTITY_OF_MEASURE_FOR_CALCULATION.to_element(),
+            PCF_LIFE_CYCLE_PHASE.to_element(),
+            EXPLANATORY_STATEMENT.to_element(),
+            PCF_GOODS_ADDRESS_HANDOVER.to_collection(GoodsAddress::template_elements()),
+            PUBLICATION_DATE.to_element(),
+            EXPIRATION_DATE.to_element()
+        ]
+    }
+
+    ///Checks the elements of a product carbon footprint.
+    ///
+    /// [collection]: collection containing a product carbon footprint
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        for element in PCF_GOODS_ADDRESS_HANDOVER.check(collection, path)? {
+            if let SubmodelElement::SubmodelElementCollection(address) = element {
+                GoodsAddress::check(address, &join_path(path, PCF_GOODS_ADDRESS_HANDOVER.id_short))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+const PCF_CALCULATION_METHOD: TemplateElement =
+    TemplateElement::property("PCFCalculationMethod{00}", "0173-1#02-ABG854#002", DataTypeDefXsd::String, Cardinality::OneToMany);
+const PCF_CO2EQ: TemplateElement =
+    TemplateElement::property("PCFCO2eq", "0173-1#02-ABG855#001", DataTypeDefXsd::Double, Cardinality::One);
+const PCF_REFERENCE_VALUE_FOR_CALCULATION: TemplateElement =
+    TemplateElement::property("PCFReferenceValueForCalculation", "0173-1#02-ABG856#001", DataTypeDefXsd::String, Cardinality::One);
+const PCF_QUANTITY_OF_MEASURE_FOR_CALCULATION: TemplateElement =
+    TemplateElement::property("PCFQuantityOfMeasureForCalculation", "0173-1#02-ABG857#001", DataTypeDefXsd::Double, Cardinality::One);
+const PCF_LIFE_CYCLE_PHASE: TemplateElement =
+    TemplateElement::property("PCFLifeCyclePhase{00}", "0173-1#02-ABG858#001", DataTypeDefXsd::String, Cardinality::OneToMany);
+const EXPLANATORY_STATEMENT: TemplateElement =
+    TemplateElement::new("ExplanatoryStatement", "https://admin-shell.io/idta/CarbonFootprint/ExplanatoryStatement/1/0", AasSubmodelElements::File, Cardinality::ZeroToOne);
+const PCF_GOODS_ADDRESS_HANDOVER: TemplateElement =
+    TemplateElement::new("PCFGoodsAddressHandover", "0173-1#02-ABI497#001/0173-1#01-AHI305#001", AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToOne);
+const PUBLICATION_DATE: TemplateElement =
+    TemplateElement::property("PublicationDate", "https://admin-shell.io/idta/CarbonFootprint/PublicationDate/1/0", DataTypeDefXsd::DateTime, Cardinality::ZeroToOne);
+const EXPIRATION_DATE: TemplateElement =
+    TemplateElement::property("ExpirationDate", "https://admin-shell.io/idta/CarbonFootprint/ExpirationDate/1/0", DataTypeDefXsd::DateTime, Cardinality::ZeroToOne);
+
+const ELEMENTS: [&TemplateElement; 9] = [&PCF_CALCULATION_METHOD, &PCF_CO2EQ, &PCF_REFERENCE_VALUE_FOR_CALCULATION,
+    &PCF_QUANTITY_OF_MEASURE_FOR_CALCULATION, &PCF_LIFE_CYCLE_PHASE, &EXPLANATORY_STATEMENT,
+    &PCF_GOODS_ADDRESS_HANDOVER, &PUBLICATION_DATE, &EXPIRATION_DATE];
@@ -0,0 +1,121 @@
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, TemplateElement};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on a product classification item of the Technical Data, which assigns the product to a
+/// class of a classification system.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct ProductClassification {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl ProductClassification {
+    ///Semantic ID of a product classification item.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/ZVEI/TechnicalData/ProductClassificationItem/1/1";
+
+    ///Creates a new product classification.
+    ///
+    /// [product_classification_system]: classification system, e.g. ECLASS or IEC CDD
+    /// [product_class_id]: ID of the class of the product in the classification system
+    pub fn new(product_classification_system: String, product_class_id: String) -> ProductClassification {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_semantic_id(global_reference(ProductClassification::SEMANTIC_ID));
+        let mut product_classification = ProductClassification {
+            collection
+        };
+        product_classification.set_product_classification_system(product_classification_system);
+        product_classification.set_product_class_id(product_class_id);
+        product_classification
+    }
+
+    ///Wraps a submodel element collection after checking the elements of a product classification item.
+    ///
+    /// [collection]: collection containing a product classification item
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<ProductClassification, ConversionError> {
+        ProductClassification::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(ProductClassification {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the product classification and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the classification system, e.g. ECLASS or IEC CDD.
+    pub fn get_product_classification_system(&self) -> Option<String> {
+        self.collection.get_property_value(PRODUCT_CLASSIFICATION_SYSTEM.id_short, PRODUCT_CLASSIFICATION_SYSTEM.semantic_id)
+    }
+
+    ///Sets the classification system, e.g. ECLASS or IEC CDD.
+    ///
+    /// [product_classification_system]: classification system, e.g. ECLASS or IEC CDD
+    pub fn set_product_classification_system(&mut self, product_classification_system: String) {
+        PRODUCT_CLASSIFICATION_SYSTEM.set_property_value(&mut self.collection, Some(&product_classification_system));
+    }
+
+    ///Returns the version of the classification system.
+    pub fn get_classification_system_version(&self) -> Option<String> {
+        self.collection.get_property_value(CLASSIFICATION_SYSTEM_VERSION.id_short, CLASSIFICATION_SYSTEM_VERSION.semantic_id)
+    }
+
+    ///Sets the version of the classification system. Passing None removes it.
+    ///
+    /// [classification_system_version]: version of the classification system
+    pub fn set_classification_system_version(&mut self, classification_system_version: Option<String>) {
+        CLASSIFICATION_SYSTEM_VERSION.set_property_value(&mut self.collection, classification_system_version.as_ref());
+    }
+
+    ///Returns the ID of the class of the product in the classification system.
+    pub fn get_product_class_id(&self) -> Option<String> {
+        self.collection.get_property_value(PRODUCT_CLASS_ID.id_short, PRODUCT_CLASS_ID.semantic_id)
+    }
+
+    ///Sets the ID of the class of the product in the classification system.
+    ///
+    /// [product_class_id]: ID of the class of the product in the classification system
+    pub fn set_product_class_id(&mut self, product_class_id: String) {
+        PRODUCT_CLASS_ID.set_property_value(&mut self.collection, Some(&product_class_id));
+    }
+
+    ///Returns the elements of a product classification item in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        ELEMENTS.iter().map(|element| element.to_element()).collect()
+    }
+
+    ///Checks the elements of a product classification item.
+    ///
+    /// [collection]: collection containing a product classification item
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        Ok(())
+    }
+}
+
+const PRODUCT_CLASSIFICATION_SYSTEM: TemplateElement =
+    TemplateElement::property("ProductClassificationSystem", "https://admin-shell.io/ZVEI/TechnicalData/ProductClassificationSystem/1/1", DataTypeDefXsd::String, Cardinality::One);
+const CLASSIFICATION_SYSTEM_VERSION: TemplateElement =
+    TemplateElement::property("ClassificationSystemVersion", "https://admin-shell.io/ZVEI/TechnicalData/ClassificationSystemVersion/1/1", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const PRODUCT_CLASS_ID: TemplateElement =
+    TemplateElement::property("ProductClassId", "https://admin-shell.io/ZVEI/TechnicalData/ProductClassId/1/1", DataTypeDefXsd::String, Cardinality::One);
+
+const ELEMENTS: [&TemplateElement; 3] = [&PRODUCT_CLASSIFICATION_SYSTEM, &CLASSIFICATION_SYSTEM_VERSION,
+    &PRODUCT_CLASS_ID];
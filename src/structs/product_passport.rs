@@ -0,0 +1,312 @@
+use crate::enumerations::asset_kind::AssetKind;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::key_type::KeyType;
+use crate::enumerations::modelling_kind::ModellingKind;
+use crate::enumerations::passport_error::PassportError;
+use crate::enumerations::reference_type::ReferenceType;
+use crate::structs::asset_administration_shell::AssetAdministrationShell;
+use crate::structs::asset_information::AssetInformation;
+use crate::structs::carbon_footprint::CarbonFootprint;
+use crate::structs::contact_informations::ContactInformations;
+use crate::structs::digital_nameplate::DigitalNameplate;
+use crate::structs::environment::Environment;
+use crate::structs::handover_documentation::HandoverDocumentation;
+use crate::structs::key::Key;
+use crate::structs::reference::Reference;
+use crate::structs::software_nameplate::SoftwareNameplate;
+use crate::structs::submodel::Submodel;
+use crate::structs::technical_data::TechnicalData;
+use crate::traits::has_kind::THasKind;
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::identifiable::TIdentifiable;
+use crate::traits::referable::TReferable;
+
+///Composition of the submodels of a digital product passport in the style of the EU battery and
+/// product passport regulations into one asset administration shell.
+///
+/// A passport requires the Digital Nameplate, the Technical Data, the Carbon Footprint with at
+/// least one product carbon footprint and the Handover Documentation. The Software Nameplate and
+/// the Contact Information are optional, further submodels can be added with `set_submodel`. Each
+/// submodel is referenced by the shell; a submodel replaces a previous one with the same semantic
+/// ID.
+#[derive(PartialEq, Clone)]
+pub struct ProductPassport {
+    ///The asset administration shell of the product.
+    shell: AssetAdministrationShell,
+    ///The submodels referenced by the shell.
+    submodels: Vec<Submodel>
+}
+
+impl ProductPassport {
+    ///Creates a new passport without submodels for an asset instance.
+    ///
+    /// [id]: global identifier of the asset administration shell
+    /// [global_asset_id]: global identifier of the product, e.g. the URI of the product instance
+    pub fn new(id: String, global_asset_id: String) -> ProductPassport {
+        let mut asset_information = AssetInformation::new(AssetKind::Instance);
+        asset_information.set_global_asset_id(global_asset_id);
+        ProductPassport {
+            shell: AssetAdministrationShell::new(id, asset_information),
+            submodels: Vec::new()
+        }
+    }
+
+    ///Returns the asset administration shell of the product.
+    pub fn get_shell(&self) -> &AssetAdministrationShell {
+        &self.shell
+    }
+
+    ///Returns the mutable asset administration shell of the product, e.g. to add specific asset
+    /// IDs.
+    pub fn get_mut_shell(&mut self) -> &mut AssetAdministrationShell {
+        &mut self.shell
+    }
+
+    ///Returns the submodels of the passport.
+    pub fn get_submodels(&self) -> &Vec<Submodel> {
+        &self.submodels
+    }
+
+    ///Returns the submodel with the semantic ID.
+    ///
+    /// [semantic_id]: semantic ID of the submodel, e.g. `DigitalNameplate::SEMANTIC_ID`
+    pub fn get_submodel(&self, semantic_id: &str) -> Option<&Submodel> {
+        self.submodels.iter().find(|submodel| has_semantic_id(submodel, semantic_id))
+    }
+
+    ///Adds a submodel and references it from the shell. A submodel with the same semantic ID or
+    /// the same ID is replaced together with its reference.
+    ///
+    /// [submodel]: submodel to add
+    pub fn set_submodel(&mut self, submodel: Submodel) {
+        let semantic_id = submodel.get_semantic_id().and_then(|reference| reference.get_keys().first())
+            .map(|key| key.get_value().clone());
+        let replaced: Vec<String> = self.submodels.iter()
+            .filter(|existing| existing.get_id() == submodel.get_id()
+                || semantic_id.as_ref().is_some_and(|semantic_id| has_semantic_id(existing, semantic_id)))
+            .map(|existing| existing.get_id().clone())
+            .collect();
+        self.submodels.retain(|existing| !replaced.contains(existing.get_id()));
+        self.shell.get_mut_submodels().retain(|reference| reference.get_keys().last()
+            .is_none_or(|key| !replaced.contains(key.get_value())));
+        self.shell.add_submodel(Reference::new(ReferenceType::ModelReference,
+                                               vec![Key::new(KeyType::Submodel, submodel.get_id().clone())]));
+        self.submodels.push(submodel);
+    }
+
+    ///Returns the Digital Nameplate, or None if it is missing or invalid.
+    pub fn get_digital_nameplate(&self) -> Option<DigitalNameplate> {
+        DigitalNameplate::from_submodel(self.get_submodel(DigitalNameplate::SEMANTIC_ID)?.clone()).ok()
+    }
+
+    ///Sets the Digital Nameplate.
+    ///
+    /// [digital_nameplate]: Digital Nameplate of the product
+    pub fn set_digital_nameplate(&mut self, digital_nameplate: DigitalNameplate) {
+        self.set_submodel(digital_nameplate.into_submodel());
+    }
+
+    ///Returns the Software Nameplate, or None if it is missing or invalid.
+    pub fn get_software_nameplate(&self) -> Option<SoftwareNameplate> {
+        SoftwareNameplate::from_submodel(self.get_submodel(SoftwareNameplate::SEMANTIC_ID)?.clone()).ok()
+    }
+
+    ///Sets the Software Nameplate of the software embedded in the product.
+    ///
+    /// [software_nameplate]: Software Nameplate of the embedded software
+    pub fn set_software_nameplate(&mut self, software_nameplate: SoftwareNameplate) {
+        self.set_submodel(software_nameplate.into_submodel());
+    }
+
+    ///Returns the Technical Data, or None if they are missing or invalid.
+    pub fn get_technical_data(&self) -> Option<TechnicalData> {
+        TechnicalData::from_submodel(self.get_submodel(TechnicalData::SEMANTIC_ID)?.clone()).ok()
+    }
+
+    ///Sets the Technical Data.
+    ///
+    /// [technical_data]: Technical Data of the product
+    pub fn set_technical_data(&mut self, technical_data: TechnicalData) {
+        self.set_submodel(technical_data.into_submodel());
+    }
+
+    ///Returns the Carbon Footprint, or None if it is missing or invalid.
+    pub fn get_carbon_footprint(&self) -> Option<CarbonFootprint> {
+        CarbonFootprint::from_submodel(self.get_submodel(CarbonFootprint::SEMANTIC_ID)?.clone()).ok()
+    }
+
+    ///Sets the Carbon Footprint.
+    ///
+    /// [carbon_footprint]: Carbon Footprint of the product
+    pub fn set_carbon_footprint(&mut self, carbon_footprint: CarbonFootprint) {
+        self.set_submodel(carbon_footprint.into_submodel());
+    }
+
+    ///Returns the Handover Documentation, or None if it is missing or invalid.
+    pub fn get_handover_documentation(&self) -> Option<HandoverDocumentation> {
+        HandoverDocumentation::from_submodel(self.get_submodel(HandoverDocumentation::SEMANTIC_ID)?.clone()).ok()
+    }
+
+    ///Sets the Handover Documentation.
+    ///
+    /// [handover_documentation]: Handover Documentation of the product
+    pub fn set_handover_documentation(&mut self, handover_documentation: HandoverDocumentation) {
+        self.set_submodel(handover_documentation.into_submodel());
+    }
+
+    ///Returns the Contact Information, or None if it is missing or invalid.
+    pub fn get_contact_informations(&self) -> Option<ContactInformations> {
+        ContactInformations::from_submodel(self.get_submodel(ContactInformations::SEMANTIC_ID)?.clone()).ok()
+    }
+
+    ///Sets the Contact Information, e.g. of the economic operator.
+    ///
+    /// [contact_informations]: Contact Information of the product
+    pub fn set_contact_informations(&mut self, contact_informations: ContactInformations) {
+        self.set_submodel(contact_informations.into_submodel());
+    }
+
+    ///Checks that the passport contains all mandatory data: the global asset ID, the mandatory
+    /// submodels with values of their mandatory elements and valid elements in the optional
+    /// submodels. All submodels must be instances. Returns all problems found; an empty vector
+    /// means the passport is complete.
+    pub fn check(&self) -> Vec<PassportError> {
+        let mut errors = Vec::new();
+        if self.shell.get_asset_information().get_global_asset_id().is_none_or(|global_asset_id| global_asset_id.is_empty()) {
+            errors.push(PassportError::MissingGlobalAssetId);
+        }
+        for submodel in self.submodels.iter().filter(|submodel| submodel.get_kind() == Some(&ModellingKind::Template)) {
+            errors.push(PassportError::TemplateSubmodel(submodel.get_id_short().unwrap_or(submodel.get_id()).clone()));
+        }
+        for (semantic_id, id_short, mandatory, check) in SUBMODELS {
+            match self.get_submodel(semantic_id) {
+                Some(submodel) => if let Err(error) = check(submodel) {
+                    errors.push(PassportError::InvalidSubmodel(id_short.to_string(), error));
+                },
+                None if mandatory => errors.push(PassportError::MissingSubmodel(id_short.to_string())),
+                None => {}
+            }
+        }
+        if self.get_carbon_footprint().is_some_and(|carbon_footprint| carbon_footprint.get_product_carbon_footprints().is_empty()) {
+            errors.push(PassportError::InvalidSubmodel(CarbonFootprint::ID_SHORT.to_string(),
+                                                       ConversionError::MissingElement("ProductCarbonFootprint".to_string())));
+        }
+        errors
+    }
+
+    ///Consumes the passport and returns an environment with the shell and its submodels after
+    /// checking that the passport is complete.
+    pub fn into_environment(self) -> Result<Environment, Vec<PassportError>> {
+        let errors = self.check();
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        let mut environment = Environment::new();
+        environment.add_asset_administration_shell(self.shell);
+        environment.set_submodels(self.submodels);
+        Ok(environment)
+    }
+}
+
+fn has_semantic_id(submodel: &Submodel, semantic_id: &str) -> bool {
+    submodel.get_semantic_id().and_then(|reference| reference.get_keys().first())
+        .is_some_and(|key| key.get_value() == semantic_id)
+}
+
+///Submodels of a passport with semantic ID, idShort, whether they are mandatory and their check.
+type SubmodelCheck = (&'static str, &'static str, bool, fn(&Submodel) -> Result<(), ConversionError>);
+
+const SUBMODELS: [SubmodelCheck; 6] = [
+    (DigitalNameplate::SEMANTIC_ID, DigitalNameplate::ID_SHORT, true,
+     |submodel| DigitalNameplate::from_submodel(submodel.clone()).map(|_| ())),
+    (TechnicalData::SEMANTIC_ID, TechnicalData::ID_SHORT, true,
+     |submodel| TechnicalData::from_submodel(submodel.clone()).map(|_| ())),
+    (CarbonFootprint::SEMANTIC_ID, CarbonFootprint::ID_SHORT, true,
+     |submodel| CarbonFootprint::from_submodel(submodel.clone()).map(|_| ())),
+    (HandoverDocumentation::SEMANTIC_ID, HandoverDocumentation::ID_SHORT, true,
+     |submodel| HandoverDocumentation::from_submodel(submodel.clone()).map(|_| ())),
+    (SoftwareNameplate::SEMANTIC_ID, SoftwareNameplate::ID_SHORT, false,
+     |submodel| SoftwareNameplate::from_submodel(submodel.clone()).map(|_| ())),
+    (ContactInformations::SEMANTIC_ID, ContactInformations::ID_SHORT, false,
+     |submodel| ContactInformations::from_submodel(submodel.clone()).map(|_| ()))
+];
+
+
+#[cfg(test)]
+mod tests {
+    use crate::enumerations::conversion_error::ConversionError;
+    use crate::enumerations::passport_error::PassportError;
+    use crate::structs::carbon_footprint::CarbonFootprint;
+    use crate::structs::contact_information::ContactInformation;
+    use crate::structs::digital_nameplate::DigitalNameplate;
+    use crate::structs::general_information::GeneralInformation;
+    use crate::structs::handover_documentation::HandoverDocumentation;
+    use crate::structs::multi_language_text_type::MultiLanguageTextType;
+    use crate::structs::product_carbon_footprint::ProductCarbonFootprint;
+    use crate::structs::product_passport::ProductPassport;
+    use crate::structs::technical_data::TechnicalData;
+
+    fn text(text: &str) -> Vec<MultiLanguageTextType> {
+        vec![MultiLanguageTextType::new("en".to_string(), text.to_string())]
+    }
+
+    fn nameplate(manufacturer_name: Vec<MultiLanguageTextType>) -> DigitalNameplate {
+        DigitalNameplate::new("urn:example:nameplate".to_string(), "https://example.com/pump/1".to_string(),
+                              manufacturer_name, text("Pump"), ContactInformation::new(), "2024".to_string())
+    }
+
+    fn passport() -> ProductPassport {
+        let mut passport = ProductPassport::new("urn:example:aas".to_string(), "https://example.com/pump/1".to_string());
+        passport.set_digital_nameplate(nameplate(text("Example Inc.")));
+        passport.set_technical_data(TechnicalData::new("urn:example:technical_data".to_string(),
+            GeneralInformation::new("Example Inc.".to_string(), text("Pump"), "P-1".to_string(), "P-1-A".to_string())));
+        let mut carbon_footprint = CarbonFootprint::new("urn:example:carbon_footprint".to_string());
+        carbon_footprint.set_product_carbon_footprints(vec![ProductCarbonFootprint::new(vec!["EN 15804".to_string()], 12.5,
+            "piece".to_string(), 1.0, vec!["A1-A3".to_string()])]);
+        passport.set_carbon_footprint(carbon_footprint);
+        passport.set_handover_documentation(HandoverDocumentation::new("urn:example:handover_documentation".to_string()));
+        passport
+    }
+
+    #[test]
+    fn accepts_complete_passport() {
+        assert_eq!(passport().check(), Vec::new());
+        assert!(passport().into_environment().is_ok());
+    }
+
+    #[test]
+    fn reports_missing_submodels_and_global_asset_id() {
+        let passport = ProductPassport::new("urn:example:aas".to_string(), String::new());
+        assert_eq!(passport.check(), vec![
+            PassportError::MissingGlobalAssetId,
+            PassportError::MissingSubmodel(DigitalNameplate::ID_SHORT.to_string()),
+            PassportError::MissingSubmodel(TechnicalData::ID_SHORT.to_string()),
+            PassportError::MissingSubmodel(CarbonFootprint::ID_SHORT.to_string()),
+            PassportError::MissingSubmodel(HandoverDocumentation::ID_SHORT.to_string())
+        ]);
+    }
+
+    #[test]
+    fn reports_mandatory_elements_without_value() {
+        let mut passport = passport();
+        passport.set_digital_nameplate(nameplate(Vec::new()));
+        assert_eq!(passport.check(), vec![PassportError::InvalidSubmodel(DigitalNameplate::ID_SHORT.to_string(),
+                                                                          ConversionError::InvalidValue("ManufacturerName".to_string()))]);
+    }
+
+    #[test]
+    fn reports_passport_of_templates() {
+        let mut passport = ProductPassport::new("urn:example:aas".to_string(), "https://example.com/pump/1".to_string());
+        passport.set_submodel(DigitalNameplate::template());
+        passport.set_submodel(TechnicalData::template());
+        passport.set_submodel(CarbonFootprint::template());
+        passport.set_submodel(HandoverDocumentation::template());
+        let errors = passport.check();
+        for id_short in [DigitalNameplate::ID_SHORT, TechnicalData::ID_SHORT, CarbonFootprint::ID_SHORT, HandoverDocumentation::ID_SHORT] {
+            assert!(errors.contains(&PassportError::TemplateSubmodel(id_short.to_string())), "{} is not reported", id_short);
+        }
+        assert!(errors.contains(&PassportError::InvalidSubmodel(DigitalNameplate::ID_SHORT.to_string(),
+                                                                ConversionError::InvalidValue("URIOfTheProduct".to_string()))));
+        assert!(passport.into_environment().is_err());
+    }
+}
\ No newline at end of file
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::structs::embedded_data_specification::EmbeddedDataSpecification;
 use crate::structs::extension::Extension;
 use crate::structs::multi_language_name_type::MultiLanguageNameType;
 use crate::structs::multi_language_text_type::MultiLanguageTextType;
@@ -10,6 +11,7 @@ use crate::traits::data_element::TDataElement;
 use crate::traits::has_data_specification::THasDataSpecification;
 use crate::traits::has_extensions::THasExtensions;
 use crate::traits::has_semantics::THasSemantics;
+use crate::traits::property_value::TPropertyValue;
 use crate::traits::qualifiable::TQualifiable;
 use crate::traits::referable::TReferable;
 use crate::traits::submodel_element::TSubmodelElement;
@@ -21,24 +23,29 @@ pub struct Property {
     #[serde(rename = "valueType")]
     value_type: DataTypeDefXsd,
     ///The optional value of the property instance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     value: Option<String>,
     ///The optional reference to the global unique ID of a coded value.
-    #[serde(rename = "valueId")]
+    #[serde(rename = "valueId", default, skip_serializing_if = "Option::is_none")]
     value_id: Option<Reference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     category: Option<String>,
-    #[serde(rename = "idShort")]
+    #[serde(rename = "idShort", default, skip_serializing_if = "Option::is_none")]
     id_short: Option<String>,
-    #[serde(rename = "displayName")]
+    #[serde(rename = "displayName", default, skip_serializing_if = "Vec::is_empty")]
     display_name: Vec<MultiLanguageNameType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     description: Vec<MultiLanguageTextType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     extensions: Vec<Extension>,
-    #[serde(rename = "semanticId")]
+    #[serde(rename = "semanticId", default, skip_serializing_if = "Option::is_none")]
     semantic_id: Option<Reference>,
-    #[serde(rename = "supplementalSemanticIds")]
+    #[serde(rename = "supplementalSemanticIds", default, skip_serializing_if = "Vec::is_empty")]
     supplemental_semantic_ids: Vec<Reference>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     qualifiers: Vec<Qualifier>,
-    #[serde(rename = "embeddedDataSpecifications")]
-    data_specifications: Vec<Reference>
+    #[serde(rename = "embeddedDataSpecifications", default, skip_serializing_if = "Vec::is_empty")]
+    data_specifications: Vec<EmbeddedDataSpecification>
 }
 
 impl Property {
@@ -112,6 +119,20 @@ impl Property {
     pub fn get_mut_value_id(&mut self) -> Option<&mut Reference> {
         self.value_id.as_mut()
     }
+
+    ///Returns the value of the property instance converted to a Rust type. Returns None if the
+    /// property has no value or the value cannot be converted.
+    pub fn get_typed_value<T: TPropertyValue>(&self) -> Option<T> {
+        self.value.as_deref().and_then(T::parse_value)
+    }
+
+    ///Sets the value of the property instance from a Rust value. The data type of the value
+    /// attribute is kept.
+    ///
+    /// [value]: value of the property instance
+    pub fn set_typed_value<T: TPropertyValue>(&mut self, value: &T) {
+        self.value = Some(value.format_value());
+    }
 }
 
 impl TSubmodelElement for Property {}
@@ -261,23 +282,23 @@ impl TQualifiable for Property {
 }
 
 impl THasDataSpecification for Property {
-    fn get_data_specifications(&self) -> &Vec<Reference> {
+    fn get_data_specifications(&self) -> &Vec<EmbeddedDataSpecification> {
         &self.data_specifications
     }
 
-    fn get_mut_data_specifications(&mut self) -> &mut Vec<Reference> {
+    fn get_mut_data_specifications(&mut self) -> &mut Vec<EmbeddedDataSpecification> {
         &mut self.data_specifications
     }
 
-    fn set_data_specifications(&mut self, data_specifications: Vec<Reference>) {
+    fn set_data_specifications(&mut self, data_specifications: Vec<EmbeddedDataSpecification>) {
         self.data_specifications = data_specifications;
     }
 
-    fn add_data_specification(&mut self, data_specification: Reference) {
+    fn add_data_specification(&mut self, data_specification: EmbeddedDataSpecification) {
         self.data_specifications.push(data_specification);
     }
 
-    fn remove_data_specification(&mut self, index: usize) -> Reference {
+    fn remove_data_specification(&mut self, index: usize) -> EmbeddedDataSpecification {
         self.data_specifications.remove(index)
     }
 }
@@ -0,0 +1,219 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::asset_interface::{add_terms, get_term, set_term, term_id_short, terms_to_json};
+use crate::structs::form::Form;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, join_path, TemplateElement, ARBITRARY};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+use serde_json::{Map, Value};
+
+///Typed view on the definition of a property affordance of an asset interface, i.e. a data point
+/// of the asset like a temperature, with its data schema and the forms to access it.
+///
+/// The idShort of the collection is the name of the property affordance. Names that are no valid
+/// idShort are kept in the element `key`.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct PropertyDefinition {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl PropertyDefinition {
+    ///Semantic ID of a property definition.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/idta/AssetInterfacesDescription/1/0/PropertyDefinition";
+
+    ///Creates a new property definition.
+    ///
+    /// [name]: name of the property affordance, e.g. `temperature`
+    /// [forms]: form to access the property affordance
+    pub fn new(name: &str, forms: Form) -> PropertyDefinition {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_id_short(term_id_short(name));
+        collection.set_semantic_id(global_reference(PropertyDefinition::SEMANTIC_ID));
+        let mut property_definition = PropertyDefinition {
+            collection
+        };
+        if term_id_short(name) != name {
+            KEY.set_property_value(&mut property_definition.collection, Some(&name.to_string()));
+        }
+        property_definition.set_forms(forms);
+        property_definition
+    }
+
+    ///Wraps a submodel element collection after checking the elements of a property definition.
+    ///
+    /// [collection]: collection containing a property definition
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<PropertyDefinition, ConversionError> {
+        PropertyDefinition::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(PropertyDefinition {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the property definition and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the name of the property affordance, i.e. the key if present, otherwise the idShort.
+    pub fn get_name(&self) -> String {
+        self.collection.get_property_value(KEY.id_short, KEY.semantic_id)
+            .or_else(|| self.collection.get_id_short().cloned())
+            .unwrap_or_default()
+    }
+
+    ///Returns the human-readable title of the property affordance.
+    pub fn get_title(&self) -> Option<String> {
+        self.collection.get_property_value(TITLE.id_short, TITLE.semantic_id)
+    }
+
+    ///Sets the human-readable title of the property affordance. Passing None removes it.
+    ///
+    /// [title]: human-readable title of the property affordance
+    pub fn set_title(&mut self, title: Option<String>) {
+        TITLE.set_property_value(&mut self.collection, title.as_ref());
+    }
+
+    ///Returns the JSON type of the values, e.g. `number`.
+    pub fn get_type(&self) -> Option<String> {
+        self.collection.get_property_value(TYPE.id_short, TYPE.semantic_id)
+    }
+
+    ///Sets the JSON type of the values. Passing None removes it.
+    ///
+    /// [value_type]: JSON type of the values, e.g. `number`
+    pub fn set_type(&mut self, value_type: Option<String>) {
+        TYPE.set_property_value(&mut self.collection, value_type.as_ref());
+    }
+
+    ///Returns whether changes of the values can be observed.
+    pub fn get_observable(&self) -> Option<bool> {
+        self.collection.get_property_value(OBSERVABLE.id_short, OBSERVABLE.semantic_id)
+    }
+
+    ///Sets whether changes of the values can be observed. Passing None removes it.
+    ///
+    /// [observable]: whether changes of the values can be observed
+    pub fn set_observable(&mut self, observable: Option<bool>) {
+        OBSERVABLE.set_property_value(&mut self.collection, observable.as_ref());
+    }
+
+    ///Returns the unit of the values, e.g. `degree Celsius`.
+    pub fn get_unit(&self) -> Option<String> {
+        self.collection.get_property_value(UNIT.id_short, UNIT.semantic_id)
+    }
+
+    ///Sets the unit of the values. Passing None removes it.
+    ///
+    /// [unit]: unit of the values, e.g. `degree Celsius`
+    pub fn set_unit(&mut self, unit: Option<String>) {
+        UNIT.set_property_value(&mut self.collection, unit.as_ref());
+    }
+
+    ///Returns the form to access the property affordance.
+    pub fn get_forms(&self) -> Option<Form> {
+        self.collection.get_collection(FORMS.id_short, FORMS.semantic_id)
+            .map(|collection| Form {
+                collection: collection.clone()
+            })
+    }
+
+    ///Sets the form to access the property affordance.
+    ///
+    /// [forms]: form to access the property affordance
+    pub fn set_forms(&mut self, forms: Form) {
+        self.collection.set_element(FORMS.id_short, FORMS.semantic_id, Some(SubmodelElement::SubmodelElementCollection(forms.into_collection())));
+    }
+
+    ///Returns the value of a term of the property affordance as JSON, e.g. `0` for `minimum`.
+    ///
+    /// [term]: term of the Thing Description
+    pub fn get_term(&self, term: &str) -> Option<Value> {
+        get_term(&self.collection, term)
+    }
+
+    ///Sets the value of a term of the property affordance. Passing None removes it.
+    ///
+    /// [term]: term of the Thing Description
+    /// [value]: JSON value of the term
+    pub fn set_term(&mut self, term: &str, value: Option<&Value>) {
+        set_term(&mut self.collection, term, value);
+    }
+
+    ///Returns the property affordance as JSON object of a Thing Description.
+    pub(crate) fn to_json(&self) -> Map<String, Value> {
+        let mut json = terms_to_json(&self.collection, &[KEY.id_short, FORMS.id_short]);
+        if let Some(forms) = self.get_forms() {
+            json.insert(FORMS.id_short.to_string(), Value::Array(vec![Value::Object(forms.to_json())]));
+        }
+        json
+    }
+
+    ///Creates a property definition from the JSON object of a Thing Description. Only the first
+    /// form is kept, as a property definition has one form.
+    ///
+    /// [name]: name of the property affordance
+    /// [forms]: form to access the property affordance
+    /// [json]: JSON object of the property affordance
+    pub(crate) fn from_json(name: &str, forms: Form, json: &Map<String, Value>) -> PropertyDefinition {
+        let mut property_definition = PropertyDefinition::new(name, forms);
+        add_terms(&mut property_definition.collection, json, &[KEY.id_short, FORMS.id_short]);
+        property_definition
+    }
+
+    ///Returns the elements of a property definition in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        vec![
+            KEY.to_element(),
+            TITLE.to_element(),
+            TYPE.to_element(),
+            OBSERVABLE.to_element(),
+            UNIT.to_element(),
+            FORMS.to_collection(Form::template_elements()),
+            ARBITRARY.to_element()
+        ]
+    }
+
+    ///Checks the elements of a property definition.
+    ///
+    /// [collection]: collection containing a property definition
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        for element in FORMS.check(collection, path)? {
+            if let SubmodelElement::SubmodelElementCollection(forms) = element {
+                Form::check(forms, &join_path(path, FORMS.id_short))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+const KEY: TemplateElement =
+    TemplateElement::property("key", "https://admin-shell.io/idta/AssetInterfacesDescription/1/0/key", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const TITLE: TemplateElement =
+    TemplateElement::property("title", "https://www.w3.org/2019/wot/td#title", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const TYPE: TemplateElement =
+    TemplateElement::property("type", "http://www.w3.org/1999/02/22-rdf-syntax-ns#type", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const OBSERVABLE: TemplateElement =
+    TemplateElement::property("observable", "https://www.w3.org/2019/wot/td#isObservable", DataTypeDefXsd::Boolean, Cardinality::ZeroToOne);
+const UNIT: TemplateElement =
+    TemplateElement::property("unit", "https://schema.org/unitCode", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const FORMS: TemplateElement =
+    TemplateElement::new(Form::ID_SHORT, Form::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::One);
+
+const ELEMENTS: [&TemplateElement; 5] = [&KEY, &TITLE, &TYPE, &OBSERVABLE, &UNIT];
@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::security_attribute_object::SecurityAttributeObject;
+
+///Information on how to reach an endpoint of a shell or submodel, e.g. its URL and protocol.
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
+pub struct ProtocolInformation {
+    ///URL of the endpoint, e.g. `http://localhost:8081/shells/dXJuOmFhczox`.
+    href: String,
+    ///Protocol of the endpoint, e.g. `HTTP`.
+    #[serde(rename = "endpointProtocol", default, skip_serializing_if = "Option::is_none")]
+    endpoint_protocol: Option<String>,
+    ///Versions of the protocol of the endpoint, e.g. `1.1`.
+    #[serde(rename = "endpointProtocolVersion", default, skip_serializing_if = "Vec::is_empty")]
+    endpoint_protocol_versions: Vec<String>,
+    ///Subprotocol of the endpoint, e.g. `OPC UA Basic SOAP`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    subprotocol: Option<String>,
+    ///Body of the subprotocol, e.g. the node ID of an OPC UA server.
+    #[serde(rename = "subprotocolBody", default, skip_serializing_if = "Option::is_none")]
+    subprotocol_body: Option<String>,
+    ///Encoding of the body of the subprotocol, e.g. `plain`.
+    #[serde(rename = "subprotocolBodyEncoding", default, skip_serializing_if = "Option::is_none")]
+    subprotocol_body_encoding: Option<String>,
+    ///Security attributes of the endpoint.
+    #[serde(rename = "securityAttributes", default, skip_serializing_if = "Vec::is_empty")]
+    security_attributes: Vec<SecurityAttributeObject>
+}
+
+impl ProtocolInformation {
+    ///Creates new protocol information with the URL only.
+    ///
+    /// [href]: URL of the endpoint
+    pub fn new(href: String) -> ProtocolInformation {
+        ProtocolInformation {
+            href,
+            endpoint_protocol: None,
+            endpoint_protocol_versions: Vec::new(),
+            subprotocol: None,
+            subprotocol_body: None,
+            subprotocol_body_encoding: None,
+            security_attributes: Vec::new()
+        }
+    }
+
+    ///Creates new protocol information for an HTTP endpoint, whose protocol is taken from the
+    /// scheme of the URL, e.g. `HTTPS` for `https://example.com/submodels/dXJuOnNtOjE`.
+    ///
+    /// [href]: URL of the endpoint
+    pub fn from_href(href: String) -> ProtocolInformation {
+        let mut protocol_information = ProtocolInformation::new(href);
+        if let Some((scheme, _)) = protocol_information.href.split_once("://") {
+            protocol_information.endpoint_protocol = Some(scheme.to_uppercase());
+            protocol_information.endpoint_protocol_versions = vec!["1.1".to_string()];
+        }
+        protocol_information
+    }
+
+    ///Returns the URL of the endpoint.
+    pub fn get_href(&self) -> &String {
+        &self.href
+    }
+
+    ///Sets the URL of the endpoint.
+    ///
+    /// [href]: URL of the endpoint
+    pub fn set_href(&mut self, href: String) {
+        self.href = href;
+    }
+
+    ///Returns the protocol of the endpoint.
+    pub fn get_endpoint_protocol(&self) -> Option<&String> {
+        self.endpoint_protocol.as_ref()
+    }
+
+    ///Sets the protocol of the endpoint. Passing None removes it.
+    ///
+    /// [endpoint_protocol]: protocol of the endpoint, e.g. `HTTP`
+    pub fn set_endpoint_protocol(&mut self, endpoint_protocol: Option<String>) {
+        self.endpoint_protocol = endpoint_protocol;
+    }
+
+    ///Returns the versions of the protocol of the endpoint.
+    pub fn get_endpoint_protocol_versions(&self) -> &Vec<String> {
+        &self.endpoint_protocol_versions
+    }
+
+    ///Sets the versions of the protocol of the endpoint.
+    ///
+    /// [endpoint_protocol_versions]: versions of the protocol, e.g. `1.1`
+    pub fn set_endpoint_protocol_versions(&mut self, endpoint_protocol_versions: Vec<String>) {
+        self.endpoint_protocol_versions = endpoint_protocol_versions;
+    }
+
+    ///Returns the subprotocol of the endpoint.
+    pub fn get_subprotocol(&self) -> Option<&String> {
+        self.subprotocol.as_ref()
+    }
+
+    ///Sets the subprotocol of the endpoint. Passing None removes it.
+    ///
+    /// [subprotocol]: subprotocol of the endpoint
+    pub fn set_subprotocol(&mut self, subprotocol: Option<String>) {
+        self.subprotocol = subprotocol;
+    }
+
+    ///Returns the body of the subprotocol.
+    pub fn get_subprotocol_body(&self) -> Option<&String> {
+        self.subprotocol_body.as_ref()
+    }
+
+    ///Sets the body of the subprotocol. Passing None removes it.
+    ///
+    /// [subprotocol_body]: body of the subprotocol
+    pub fn set_subprotocol_body(&mut self, subprotocol_body: Option<String>) {
+        self.subprotocol_body = subprotocol_body;
+    }
+
+    ///Returns the encoding of the body of the subprotocol.
+    pub fn get_subprotocol_body_encoding(&self) -> Option<&String> {
+        self.subprotocol_body_encoding.as_ref()
+    }
+
+    ///Sets the encoding of the body of the subprotocol. Passing None removes it.
+    ///
+    /// [subprotocol_body_encoding]: encoding of the body of the subprotocol
+    pub fn set_subprotocol_body_encoding(&mut self, subprotocol_body_encoding: Option<String>) {
+        self.subprotocol_body_encoding = subprotocol_body_encoding;
+    }
+
+    ///Returns the security attributes of the endpoint.
+    pub fn get_security_attributes(&self) -> &Vec<SecurityAttributeObject> {
+        &self.security_attributes
+    }
+
+    ///Returns the mutable security attributes of the endpoint.
+    pub fn get_mut_security_attributes(&mut self) -> &mut Vec<SecurityAttributeObject> {
+        &mut self.security_attributes
+    }
+
+    ///Adds a security attribute of the endpoint.
+    ///
+    /// [security_attribute]: security attribute to add
+    pub fn add_security_attribute(&mut self, security_attribute: SecurityAttributeObject) {
+        self.security_attributes.push(security_attribute);
+    }
+}
\ No newline at end of file
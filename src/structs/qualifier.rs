@@ -10,6 +10,7 @@ use crate::traits::has_semantics::THasSemantics;
 #[derive(PartialEq, Clone, Serialize, Deserialize)]
 pub struct Qualifier {
     ///The optional qualifier kind describes the kind of qualifier that is applied to the element.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     kind: Option<QualifierKind>,
     ///The qualifier type describes the type of qualifier that is applied to the element.
     #[serde(rename = "type")]
@@ -18,13 +19,14 @@ pub struct Qualifier {
     #[serde(rename = "valueType")]
     value_type: DataTypeDefXsd,
     ///The optional qualifier value is the value of the qualifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     value: Option<String>,
     ///An optional reference to the global unique ID of a coded value.
-    #[serde(rename = "valueId")]
+    #[serde(rename = "valueId", default, skip_serializing_if = "Option::is_none")]
     value_id: Option<Reference>,
-    #[serde(rename = "semanticId")]
+    #[serde(rename = "semanticId", default, skip_serializing_if = "Option::is_none")]
     semantic_id: Option<Reference>,
-    #[serde(rename = "supplementalSemanticIds")]
+    #[serde(rename = "supplementalSemanticIds", default, skip_serializing_if = "Vec::is_empty")]
     supplemental_semantic_ids: Vec<Reference>
 }
 
@@ -123,6 +125,25 @@ impl Qualifier {
     pub fn get_mut_value_id(&mut self) -> Option<&mut Reference> {
         self.value_id.as_mut()
     }
+
+    ///Returns all references contained in the qualifier.
+    pub fn get_references(&self) -> Vec<&Reference> {
+        self.value_id.iter()
+            .chain(self.semantic_id.iter())
+            .chain(self.supplemental_semantic_ids.iter())
+            .flat_map(|reference| reference.get_references())
+            .collect()
+    }
+
+    ///Calls the visitor for every reference contained in the qualifier.
+    ///
+    /// [visitor]: function called for every reference
+    pub fn visit_references_mut(&mut self, visitor: &mut dyn FnMut(&mut Reference)) {
+        self.value_id.iter_mut()
+            .chain(self.semantic_id.iter_mut())
+            .chain(self.supplemental_semantic_ids.iter_mut())
+            .for_each(|reference| reference.visit_references_mut(visitor));
+    }
 }
 
 impl THasSemantics for Qualifier {
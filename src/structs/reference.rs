@@ -1,14 +1,17 @@
 use serde::{Deserialize, Serialize};
 
+use crate::enumerations::key_type::KeyType;
 use crate::enumerations::reference_type::ReferenceType;
 use crate::structs::key::Key;
+use crate::structs::template_element::join_path;
 
 #[derive(PartialEq, Clone, Serialize, Deserialize)]
 pub struct Reference {
     #[serde(rename = "type")]
     reference_type: ReferenceType,
-    #[serde(rename = "referredSemanticId")]
+    #[serde(rename = "referredSemanticId", default, skip_serializing_if = "Option::is_none")]
     referred_semantic_id: Option<Box<Reference>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     keys: Vec<Key>
 }
 
@@ -56,4 +59,93 @@ impl Reference {
     pub fn remove_key(&mut self, index: usize) -> Key {
         self.keys.remove(index)
     }
+
+    ///Returns the semantic ID of the element the reference refers to, which allows checking the
+    /// type of the element without resolving the reference.
+    pub fn get_referred_semantic_id(&self) -> Option<&Reference> {
+        self.referred_semantic_id.as_deref()
+    }
+
+    ///Returns the mutable semantic ID of the element the reference refers to.
+    pub fn get_mut_referred_semantic_id(&mut self) -> Option<&mut Reference> {
+        self.referred_semantic_id.as_deref_mut()
+    }
+
+    ///Returns the reference itself together with the references nested in its referred semantic ID.
+    pub fn get_references(&self) -> Vec<&Reference> {
+        let mut references = vec![self];
+        if let Some(referred_semantic_id) = &self.referred_semantic_id {
+            references.extend(referred_semantic_id.get_references());
+        }
+        references
+    }
+
+    ///Calls the visitor for the reference itself and for the references nested in its referred
+    /// semantic ID.
+    ///
+    /// [visitor]: function called for every reference
+    pub fn visit_references_mut(&mut self, visitor: &mut dyn FnMut(&mut Reference)) {
+        visitor(self);
+        if let Some(referred_semantic_id) = self.referred_semantic_id.as_deref_mut() {
+            referred_semantic_id.visit_references_mut(visitor);
+        }
+    }
+
+    ///Returns the submodel ID and the idShortPath of the submodel element a model reference
+    /// addresses, e.g. `List[0].Property` for keys of the types Submodel, SubmodelElementList and
+    /// Property. The path is empty if the reference addresses the submodel itself. Returns None if
+    /// the reference is no model reference into a submodel.
+    pub fn get_submodel_element_path(&self) -> Option<(&String, String)> {
+        let (first, rest) = self.keys.split_first()?;
+        if self.reference_type != ReferenceType::ModelReference || *first.get_key_type() != KeyType::Submodel {
+            return None;
+        }
+        let mut path = String::new();
+        let mut previous_key_type = first.get_key_type();
+        for key in rest {
+            if *previous_key_type == KeyType::SubmodelElementList {
+                path = format!("{}[{}]", path, key.get_value());
+            } else {
+                path = join_path(&path, key.get_value());
+            }
+            previous_key_type = key.get_key_type();
+        }
+        Some((first.get_value(), path))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::enumerations::key_type::KeyType;
+    use crate::enumerations::reference_type::ReferenceType;
+    use crate::structs::key::Key;
+    use crate::structs::reference::Reference;
+
+    fn reference(reference_type: ReferenceType, keys: &[(KeyType, &str)]) -> Reference {
+        Reference::new(reference_type, keys.iter().map(|(key_type, value)| Key::new(key_type.clone(), value.to_string())).collect())
+    }
+
+    #[test]
+    fn returns_submodel_element_path_of_model_references() {
+        let element = reference(ReferenceType::ModelReference, &[(KeyType::Submodel, "urn:example:submodel"),
+            (KeyType::SubmodelElementCollection, "Collection"), (KeyType::SubmodelElementList, "List"), (KeyType::Entity, "2"),
+            (KeyType::Property, "Property")]);
+        let submodel = reference(ReferenceType::ModelReference, &[(KeyType::Submodel, "urn:example:submodel")]);
+
+        assert!(element.get_submodel_element_path()
+            == Some((&"urn:example:submodel".to_string(), "Collection.List[2].Property".to_string())));
+        assert!(submodel.get_submodel_element_path() == Some((&"urn:example:submodel".to_string(), String::new())));
+    }
+
+    #[test]
+    fn returns_no_submodel_element_path_of_other_references() {
+        let external = reference(ReferenceType::ExternalReference, &[(KeyType::Submodel, "urn:example:submodel")]);
+        let shell = reference(ReferenceType::ModelReference, &[(KeyType::AssetAdministrationShell, "urn:example:shell")]);
+        let empty = reference(ReferenceType::ModelReference, &[]);
+
+        assert!(external.get_submodel_element_path().is_none());
+        assert!(shell.get_submodel_element_path().is_none());
+        assert!(empty.get_submodel_element_path().is_none());
+    }
 }
\ No newline at end of file
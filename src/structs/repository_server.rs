@@ -0,0 +1,1063 @@
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{header, HeaderMap, Method, StatusCode, Uri};
+use axum::response::{IntoResponse, Response};
+use axum::routing::any;
+use axum::Router;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::enumerations::extent::Extent;
+use crate::enumerations::id_short_path_segment::IdShortPathSegment;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::key_type::KeyType;
+use crate::enumerations::level::Level;
+use crate::enumerations::reference_type::ReferenceType;
+use crate::enumerations::repository_error::RepositoryError;
+use crate::structs::asset_administration_shell::AssetAdministrationShell;
+use crate::structs::asset_information::AssetInformation;
+use crate::structs::blob::{decode_base64, encode_base64};
+use crate::structs::concept_description::ConceptDescription;
+use crate::structs::key::Key;
+use crate::structs::reference::Reference;
+use crate::structs::service_result::ServiceResult;
+use crate::structs::submodel::Submodel;
+use crate::traits::has_data_specification::THasDataSpecification;
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::identifiable::TIdentifiable;
+use crate::traits::referable::TReferable;
+use crate::traits::repository_storage::TRepositoryStorage;
+
+///HTTP server implementing the AAS Repository, Submodel Repository and Concept Description
+/// Repository interfaces of the HTTP API (IDTA 01002 Part 2 V3.1) over a storage backend.
+///
+/// The server provides an axum router, which can be served directly or merged into the router of
+/// an application. Identifiers in paths are base64url encoded, idShortPaths are percent-encoded.
+/// Lists are paged by the query parameters `limit` and `cursor`; responses are modified by `level`,
+/// `extent` and the `$metadata`, `$value`, `$reference` and `$path` modifiers. Errors are answered
+/// with a `ServiceResult`. Operations and the thumbnails of shells are not supported.
+pub struct RepositoryServer<S> {
+    ///The storage backend shared by the handlers of the router.
+    storage: Arc<RwLock<S>>
+}
+
+impl<S: TRepositoryStorage + Send + Sync + 'static> RepositoryServer<S> {
+    ///Creates a new server over a storage backend.
+    ///
+    /// [storage]: storage of the shells, submodels and concept descriptions
+    pub fn new(storage: S) -> RepositoryServer<S> {
+        RepositoryServer {
+            storage: Arc::new(RwLock::new(storage))
+        }
+    }
+
+    ///Returns the storage backend, e.g. to modify the repository besides the HTTP API.
+    pub fn get_storage(&self) -> &Arc<RwLock<S>> {
+        &self.storage
+    }
+
+    ///Returns the router with the endpoints of the repository interfaces below `/shells`,
+    /// `/submodels` and `/concept-descriptions`.
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route("/shells", any(handle::<S>))
+            .route("/shells/{*path}", any(handle::<S>))
+            .route("/submodels", any(handle::<S>))
+            .route("/submodels/{*path}", any(handle::<S>))
+            .route("/concept-descriptions", any(handle::<S>))
+            .route("/concept-descriptions/{*path}", any(handle::<S>))
+            .with_state(self.storage.clone())
+    }
+}
+
+///Request of the HTTP API with the percent-decoded segments of its path and its query.
+struct Request {
+    ///The HTTP method.
+    method: Method,
+    ///The percent-decoded segments of the path.
+    segments: Vec<String>,
+    ///The percent-decoded parameters of the query.
+    query: Vec<(String, String)>,
+    ///The content type of the body.
+    content_type: Option<String>,
+    ///The body.
+    body: Bytes
+}
+
+impl Request {
+    ///Returns the first value of a query parameter.
+    fn query(&self, name: &str) -> Option<&str> {
+        self.query.iter().find(|(parameter, _)| parameter == name).map(|(_, value)| value.as_str())
+    }
+
+    ///Returns all values of a query parameter.
+    fn queries(&self, name: &str) -> Vec<&str> {
+        self.query.iter().filter(|(parameter, _)| parameter == name).map(|(_, value)| value.as_str()).collect()
+    }
+
+    ///Deserializes the JSON body.
+    fn json<T: DeserializeOwned>(&self) -> Result<T, RepositoryError> {
+        serde_json::from_slice(&self.body).map_err(|error| RepositoryError::InvalidRequest(error.to_string()))
+    }
+}
+
+///Kind of content of a response selected by a modifier path segment.
+#[derive(PartialEq, Clone, Copy)]
+enum Content {
+    ///The complete serialization.
+    Normal,
+    ///The serialization without values (`$metadata`).
+    Metadata,
+    ///The value-only serialization (`$value`).
+    Value,
+    ///A model reference (`$reference`).
+    Reference,
+    ///The idShortPaths of the elements (`$path`).
+    Path
+}
+
+impl Content {
+    fn parse(modifier: &str) -> Result<Content, RepositoryError> {
+        match modifier {
+            "$metadata" => Ok(Content::Metadata),
+            "$value" => Ok(Content::Value),
+            "$reference" => Ok(Content::Reference),
+            "$path" => Ok(Content::Path),
+            _ => Err(RepositoryError::NotFound(modifier.to_string()))
+        }
+    }
+}
+
+///Serialization modifiers given by the query parameters `level` and `extent`.
+struct Modifiers {
+    ///Whether nested elements are included at all levels (`level=deep`) or only the direct
+    /// children (`level=core`).
+    deep: bool,
+    ///Whether the values of blobs are included (`extent=withBlobValue`).
+    with_blob_value: bool
+}
+
+impl Modifiers {
+    fn parse(request: &Request) -> Result<Modifiers, RepositoryError> {
+        let level = request.query("level").map(|level| Level::from_name(level)
+            .ok_or_else(|| RepositoryError::InvalidRequest(format!("invalid level '{}'", level)))).transpose()?;
+        let extent = request.query("extent").map(|extent| Extent::from_name(extent)
+            .ok_or_else(|| RepositoryError::InvalidRequest(format!("invalid extent '{}'", extent)))).transpose()?;
+        let deep = level.unwrap_or(Level::Deep) == Level::Deep;
+        let with_blob_value = extent.unwrap_or(Extent::WithoutBlobValue) == Extent::WithBlobValue;
+        Ok(Modifiers {
+            deep,
+            with_blob_value
+        })
+    }
+}
+
+async fn handle<S: TRepositoryStorage + Send + Sync>(State(storage): State<Arc<RwLock<S>>>, method: Method, uri: Uri,
+                                                     headers: HeaderMap, body: Bytes) -> Response {
+    let request = Request {
+        method,
+        segments: uri.path().split('/').filter(|segment| !segment.is_empty()).map(|segment| percent_decode(segment, false)).collect(),
+        query: uri.query().unwrap_or_default().split('&').filter(|parameter| !parameter.is_empty())
+            .map(|parameter| {
+                let (name, value) = parameter.split_once('=').unwrap_or((parameter, ""));
+                (percent_decode(name, true), percent_decode(value, true))
+            })
+            .collect(),
+        content_type: headers.get(header::CONTENT_TYPE).and_then(|value| value.to_str().ok()).map(|value| value.to_string()),
+        body
+    };
+    let segments: Vec<&str> = request.segments.iter().map(|segment| segment.as_str()).collect();
+    let result = match segments.as_slice() {
+        ["shells", path @ ..] => shells(&storage, &request, path),
+        ["submodels", path @ ..] => submodels(&storage, &request, path),
+        ["concept-descriptions", path @ ..] => concept_descriptions(&storage, &request, path),
+        _ => Err(RepositoryError::NotFound(uri.path().to_string()))
+    };
+    result.unwrap_or_else(error_response)
+}
+
+fn shells<S: TRepositoryStorage>(storage: &RwLock<S>, request: &Request, path: &[&str]) -> Result<Response, RepositoryError> {
+    match (&request.method, path) {
+        (&Method::GET, []) | (&Method::GET, ["$reference"]) => {
+            let asset_ids = request.queries("assetIds").into_iter()
+                .map(|asset_id| decode_id(asset_id)
+                    .and_then(|asset_id| serde_json::from_str::<Value>(&asset_id).map_err(|error| RepositoryError::InvalidRequest(error.to_string()))))
+                .collect::<Result<Vec<Value>, RepositoryError>>()?;
+            let shells: Vec<AssetAdministrationShell> = read(storage)?.get_shells()?.into_iter()
+                .filter(|shell| matches_id_short(shell.get_id_short(), request.query("idShort")))
+                .filter(|shell| asset_ids.iter().all(|asset_id| has_asset_id(shell, asset_id)))
+                .collect();
+            let items = match path.is_empty() {
+                true => shells.iter().map(to_json).collect::<Result<Vec<Value>, RepositoryError>>()?,
+                false => shells.iter().map(|shell| to_json(&model_reference(KeyType::AssetAdministrationShell, shell.get_id())))
+                    .collect::<Result<Vec<Value>, RepositoryError>>()?
+            };
+            paged(request, items)
+        },
+        (&Method::POST, []) => {
+            let shell: AssetAdministrationShell = request.json()?;
+            let mut storage = write(storage)?;
+            if storage.get_shell(shell.get_id())?.is_some() {
+                return Err(RepositoryError::Conflict(shell.get_id().clone()));
+            }
+            storage.put_shell(shell.clone())?;
+            json_response(StatusCode::CREATED, to_json(&shell)?)
+        },
+        (_, [id, path @ ..]) => shell(storage, request, &decode_id(id)?, path),
+        _ => Ok(method_not_allowed())
+    }
+}
+
+fn shell<S: TRepositoryStorage>(storage: &RwLock<S>, request: &Request, id: &str, path: &[&str]) -> Result<Response, RepositoryError> {
+    match (&request.method, path) {
+        (&Method::GET, []) => json_response(StatusCode::OK, to_json(&get_shell(&*read(storage)?, id)?)?),
+        (&Method::PUT, []) => {
+            let shell: AssetAdministrationShell = request.json()?;
+            check_id(shell.get_id(), id)?;
+            let mut storage = write(storage)?;
+            let created = storage.get_shell(id)?.is_none();
+            storage.put_shell(shell.clone())?;
+            created_or_no_content(created, &shell)
+        },
+        (&Method::DELETE, []) => match write(storage)?.delete_shell(id)? {
+            true => Ok(StatusCode::NO_CONTENT.into_response()),
+            false => Err(RepositoryError::NotFound(id.to_string()))
+        },
+        (&Method::GET, ["$reference"]) => {
+            get_shell(&*read(storage)?, id)?;
+            json_response(StatusCode::OK, to_json(&model_reference(KeyType::AssetAdministrationShell, id))?)
+        },
+        (&Method::GET, ["asset-information"]) =>
+            json_response(StatusCode::OK, to_json(get_shell(&*read(storage)?, id)?.get_asset_information())?),
+        (&Method::PUT, ["asset-information"]) => {
+            let asset_information: AssetInformation = request.json()?;
+            let mut storage = write(storage)?;
+            let mut shell = get_shell(&*storage, id)?;
+            shell.set_asset_information(asset_information);
+            storage.put_shell(shell)?;
+            Ok(StatusCode::NO_CONTENT.into_response())
+        },
+        (&Method::GET, ["submodel-refs"]) => {
+            let shell = get_shell(&*read(storage)?, id)?;
+            paged(request, shell.get_submodels().iter().map(to_json).collect::<Result<Vec<Value>, RepositoryError>>()?)
+        },
+        (&Method::POST, ["submodel-refs"]) => {
+            let reference: Reference = request.json()?;
+            let mut storage = write(storage)?;
+            let mut shell = get_shell(&*storage, id)?;
+            if shell.get_submodels().contains(&reference) {
+                return Err(RepositoryError::Conflict(reference.get_keys().last().map(|key| key.get_value().clone()).unwrap_or_default()));
+            }
+            shell.add_submodel(reference.clone());
+            storage.put_shell(shell)?;
+            json_response(StatusCode::CREATED, to_json(&reference)?)
+        },
+        (&Method::DELETE, ["submodel-refs", submodel_id]) => {
+            let submodel_id = decode_id(submodel_id)?;
+            let mut storage = write(storage)?;
+            let mut shell = get_shell(&*storage, id)?;
+            if !remove_submodel_reference(&mut shell, &submodel_id) {
+                return Err(RepositoryError::NotFound(submodel_id));
+            }
+            storage.put_shell(shell)?;
+            Ok(StatusCode::NO_CONTENT.into_response())
+        },
+        (_, ["submodels", submodel_id, path @ ..]) => {
+            let submodel_id = decode_id(submodel_id)?;
+            let shell = get_shell(&*read(storage)?, id)?;
+            if !references_submodel(&shell, &submodel_id) {
+                return Err(RepositoryError::NotFound(submodel_id));
+            }
+            let response = submodel(storage, request, &submodel_id, path)?;
+            if request.method == Method::DELETE && path.is_empty() {
+                let mut storage = write(storage)?;
+                let mut shell = get_shell(&*storage, id)?;
+                remove_submodel_reference(&mut shell, &submodel_id);
+                storage.put_shell(shell)?;
+            }
+            Ok(response)
+        },
+        _ => Ok(method_not_allowed())
+    }
+}
+
+fn submodels<S: TRepositoryStorage>(storage: &RwLock<S>, request: &Request, path: &[&str]) -> Result<Response, RepositoryError> {
+    match (&request.method, path) {
+        (&Method::GET, []) => list_submodels(storage, request, Content::Normal),
+        (&Method::GET, [modifier]) if modifier.starts_with('$') => list_submodels(storage, request, Content::parse(modifier)?),
+        (&Method::POST, []) => {
+            let submodel: Submodel = request.json()?;
+            let mut storage = write(storage)?;
+            if storage.get_submodel(submodel.get_id())?.is_some() {
+                return Err(RepositoryError::Conflict(submodel.get_id().clone()));
+            }
+            storage.put_submodel(submodel.clone())?;
+            json_response(StatusCode::CREATED, to_json(&submodel)?)
+        },
+        (_, [id, path @ ..]) => submodel(storage, request, &decode_id(id)?, path),
+        _ => Ok(method_not_allowed())
+    }
+}
+
+fn list_submodels<S: TRepositoryStorage>(storage: &RwLock<S>, request: &Request, content: Content) -> Result<Response, RepositoryError> {
+    let modifiers = Modifiers::parse(request)?;
+    let semantic_id = request.query("semanticId").map(decode_id).transpose()?;
+    let items = read(storage)?.get_submodels()?.iter()
+        .filter(|submodel| matches_id_short(submodel.get_id_short(), request.query("idShort")))
+        .filter(|submodel| semantic_id.as_ref().is_none_or(|semantic_id| submodel.get_semantic_id()
+            .is_some_and(|reference| reference.get_keys().iter().any(|key| key.get_value() == semantic_id))))
+        .map(|submodel| submodel_content(submodel, content, &modifiers))
+        .collect::<Result<Vec<Value>, RepositoryError>>()?;
+    paged(request, items)
+}
+
+fn submodel<S: TRepositoryStorage>(storage: &RwLock<S>, request: &Request, id: &str, path: &[&str]) -> Result<Response, RepositoryError> {
+    let modifiers = Modifiers::parse(request)?;
+    match (&request.method, path) {
+        (&Method::GET, []) =>
+            json_response(StatusCode::OK, submodel_content(&get_submodel(&*read(storage)?, id)?, Content::Normal, &modifiers)?),
+        (&Method::GET, [modifier]) if modifier.starts_with('$') =>
+            json_response(StatusCode::OK, submodel_content(&get_submodel(&*read(storage)?, id)?, Content::parse(modifier)?, &modifiers)?),
+        (&Method::PUT, []) => {
+            let submodel: Submodel = request.json()?;
+            check_id(submodel.get_id(), id)?;
+            let mut storage = write(storage)?;
+            let created = storage.get_submodel(id)?.is_none();
+            storage.put_submodel(submodel.clone())?;
+            created_or_no_content(created, &submodel)
+        },
+        (&Method::PATCH, []) => {
+            let submodel: Submodel = request.json()?;
+            check_id(submodel.get_id(), id)?;
+            let mut storage = write(storage)?;
+            get_submodel(&*storage, id)?;
+            storage.put_submodel(submodel)?;
+            Ok(StatusCode::NO_CONTENT.into_response())
+        },
+        (&Method::PATCH, ["$metadata"]) => {
+            let mut metadata: Submodel = request.json()?;
+            check_id(metadata.get_id(), id)?;
+            let mut storage = write(storage)?;
+            let submodel = get_submodel(&*storage, id)?;
+            metadata.set_submodel_elements(submodel.get_submodel_elements().clone());
+            storage.put_submodel(metadata)?;
+            Ok(StatusCode::NO_CONTENT.into_response())
+        },
+        (&Method::PATCH, ["$value"]) => {
+            let value: Value = request.json()?;
+            let mut storage = write(storage)?;
+            let mut submodel = get_submodel(&*storage, id)?;
+            submodel.set_value_only(&value).map_err(|error| RepositoryError::InvalidRequest(error.to_string()))?;
+            storage.put_submodel(submodel)?;
+            Ok(StatusCode::NO_CONTENT.into_response())
+        },
+        (&Method::DELETE, []) => match write(storage)?.delete_submodel(id)? {
+            true => Ok(StatusCode::NO_CONTENT.into_response()),
+            false => Err(RepositoryError::NotFound(id.to_string()))
+        },
+        (&Method::GET, ["submodel-elements"]) => list_elements(&get_submodel(&*read(storage)?, id)?, request, Content::Normal, &modifiers),
+        (&Method::GET, ["submodel-elements", modifier]) if modifier.starts_with('$') =>
+            list_elements(&get_submodel(&*read(storage)?, id)?, request, Content::parse(modifier)?, &modifiers),
+        (&Method::POST, ["submodel-elements"]) => {
+            let element: SubmodelElement = request.json()?;
+            let mut storage = write(storage)?;
+            let mut submodel = get_submodel(&*storage, id)?;
+            add_child(submodel.get_mut_submodel_elements(), element.clone(), false)?;
+            storage.put_submodel(submodel)?;
+            json_response(StatusCode::CREATED, to_json(&element)?)
+        },
+        (_, ["submodel-elements", id_short_path, path @ ..]) => element(storage, request, id, id_short_path, path, &modifiers),
+        _ => Ok(method_not_allowed())
+    }
+}
+
+fn list_elements(submodel: &Submodel, request: &Request, content: Content, modifiers: &Modifiers) -> Result<Response, RepositoryError> {
+    let items = submodel.get_submodel_elements().iter()
+        .map(|element| {
+            let path = element.get_id_short().cloned().unwrap_or_default();
+            element_content(submodel, &path, element, content, modifiers)
+        })
+        .collect::<Result<Vec<Value>, RepositoryError>>()?;
+    paged(request, items)
+}
+
+fn element<S: TRepositoryStorage>(storage: &RwLock<S>, request: &Request, submodel_id: &str, id_short_path: &str, path: &[&str],
+                                  modifiers: &Modifiers) -> Result<Response, RepositoryError> {
+    let segments = IdShortPathSegment::parse_path(id_short_path).filter(|segments| !segments.is_empty())
+        .ok_or_else(|| RepositoryError::InvalidRequest(format!("invalid idShortPath '{}'", id_short_path)))?;
+    let not_found = || RepositoryError::NotFound(id_short_path.to_string());
+    match (&request.method, path) {
+        (&Method::GET, []) => {
+            let submodel = get_submodel(&*read(storage)?, submodel_id)?;
+            let element = submodel.get_submodel_element(id_short_path).ok_or_else(not_found)?;
+            json_response(StatusCode::OK, element_content(&submodel, id_short_path, element, Content::Normal, modifiers)?)
+        },
+        (&Method::GET, [modifier]) if modifier.starts_with('$') => {
+            let submodel = get_submodel(&*read(storage)?, submodel_id)?;
+            let element = submodel.get_submodel_element(id_short_path).ok_or_else(not_found)?;
+            json_response(StatusCode::OK, element_content(&submodel, id_short_path, element, Content::parse(modifier)?, modifiers)?)
+        },
+        (&Method::PUT, []) | (&Method::PATCH, []) => {
+            let element: SubmodelElement = request.json()?;
+            if let Some(IdShortPathSegment::IdShort(id_short)) = segments.last() && element.get_id_short() != Some(id_short) {
+                return Err(RepositoryError::InvalidRequest(format!("the idShort of the element differs from '{}'", id_short)));
+            }
+            update_element(storage, submodel_id, id_short_path, |existing| {
+                *existing = element;
+                Ok(())
+            })
+        },
+        (&Method::PATCH, ["$metadata"]) => {
+            let mut metadata: SubmodelElement = request.json()?;
+            update_element(storage, submodel_id, id_short_path, |existing| {
+                if metadata.get_key_type() != existing.get_key_type() || metadata.get_id_short() != existing.get_id_short() {
+                    return Err(RepositoryError::InvalidRequest("the metadata differ in type or idShort from the element".to_string()));
+                }
+                if let (Some(children), Some(existing_children)) = (metadata.get_mut_children(), existing.get_children()) {
+                    *children = existing_children.clone();
+                }
+                if let Some(value) = existing.get_value_only(true) {
+                    metadata.set_value_only(&value).map_err(|error| RepositoryError::InvalidRequest(error.to_string()))?;
+                }
+                *existing = metadata;
+                Ok(())
+            })
+        },
+        (&Method::PATCH, ["$value"]) => {
+            let value: Value = request.json()?;
+            update_element(storage, submodel_id, id_short_path, |existing| existing.set_value_only(&value)
+                .map_err(|error| RepositoryError::InvalidRequest(error.to_string())))
+        },
+        (&Method::POST, []) => {
+            let element: SubmodelElement = request.json()?;
+            let mut storage = write(storage)?;
+            let mut submodel = get_submodel(&*storage, submodel_id)?;
+            let parent = submodel.get_mut_submodel_element(id_short_path).ok_or_else(not_found)?;
+            let is_list = matches!(parent, SubmodelElement::SubmodelElementList(_));
+            let children = parent.get_mut_children()
+                .ok_or_else(|| RepositoryError::InvalidRequest(format!("element '{}' cannot contain elements", id_short_path)))?;
+            add_child(children, element.clone(), is_list)?;
+            storage.put_submodel(submodel)?;
+            json_response(StatusCode::CREATED, to_json(&element)?)
+        },
+        (&Method::DELETE, []) => {
+            let mut storage = write(storage)?;
+            let mut submodel = get_submodel(&*storage, submodel_id)?;
+            let (parent, last) = segments.split_at(segments.len() - 1);
+            let children = match parent.is_empty() {
+                true => Some(submodel.get_mut_submodel_elements()),
+                false => submodel.get_mut_submodel_element(&IdShortPathSegment::format_path(parent))
+                    .and_then(|parent| parent.get_mut_children())
+            };
+            let children = children.ok_or_else(not_found)?;
+            let index = match &last[0] {
+                IdShortPathSegment::IdShort(id_short) => children.iter()
+                    .position(|child| child.get_id_short().is_some_and(|child_id_short| child_id_short == id_short)),
+                IdShortPathSegment::Index(index) => Some(*index).filter(|index| *index < children.len())
+            };
+            children.remove(index.ok_or_else(not_found)?);
+            storage.put_submodel(submodel)?;
+            storage.delete_attachment(submodel_id, id_short_path)?;
+            Ok(StatusCode::NO_CONTENT.into_response())
+        },
+        (&Method::GET, ["attachment"]) => {
+            let storage = read(storage)?;
+            let submodel = get_submodel(&*storage, submodel_id)?;
+            let (content_type, content) = match submodel.get_submodel_element(id_short_path).ok_or_else(not_found)? {
+                SubmodelElement::Blob(blob) if !blob.get_value().is_empty() =>
+                    (blob.get_content_type().cloned().unwrap_or_default(), blob.get_value().clone()),
+                SubmodelElement::File(_) => storage.get_attachment(submodel_id, id_short_path)?.ok_or_else(not_found)?,
+                SubmodelElement::Blob(_) => return Err(not_found()),
+                _ => return Err(RepositoryError::InvalidRequest(format!("element '{}' is neither a file nor a blob", id_short_path)))
+            };
+            Ok((StatusCode::OK, [(header::CONTENT_TYPE, content_type)], content).into_response())
+        },
+        (&Method::PUT, ["attachment"]) => {
+            let (file_name, content_type, content) = parse_attachment(request)?;
+            let mut storage = write(storage)?;
+            let mut submodel = get_submodel(&*storage, submodel_id)?;
+            match submodel.get_mut_submodel_element(id_short_path).ok_or_else(not_found)? {
+                SubmodelElement::Blob(blob) => {
+                    blob.set_content_type(content_type);
+                    blob.set_value(content);
+                },
+                SubmodelElement::File(file) => {
+                    file.set_content_type(content_type.clone());
+                    if let Some(file_name) = file_name {
+                        file.set_value(file_name);
+                    }
+                    storage.put_attachment(submodel_id, id_short_path, content_type, content)?;
+                },
+                _ => return Err(RepositoryError::InvalidRequest(format!("element '{}' is neither a file nor a blob", id_short_path)))
+            }
+            storage.put_submodel(submodel)?;
+            Ok(StatusCode::NO_CONTENT.into_response())
+        },
+        (&Method::DELETE, ["attachment"]) => {
+            let mut storage = write(storage)?;
+            let mut submodel = get_submodel(&*storage, submodel_id)?;
+            match submodel.get_mut_submodel_element(id_short_path).ok_or_else(not_found)? {
+                SubmodelElement::Blob(blob) if !blob.get_value().is_empty() => {
+                    blob.set_value(Vec::new());
+                    storage.put_submodel(submodel)?;
+                },
+                SubmodelElement::File(_) if storage.delete_attachment(submodel_id, id_short_path)? => {},
+                SubmodelElement::Blob(_) | SubmodelElement::File(_) => return Err(not_found()),
+                _ => return Err(RepositoryError::InvalidRequest(format!("element '{}' is neither a file nor a blob", id_short_path)))
+            }
+            Ok(StatusCode::NO_CONTENT.into_response())
+        },
+        _ => Ok(method_not_allowed())
+    }
+}
+
+fn concept_descriptions<S: TRepositoryStorage>(storage: &RwLock<S>, request: &Request, path: &[&str]) -> Result<Response, RepositoryError> {
+    match (&request.method, path) {
+        (&Method::GET, []) => {
+            let is_case_of = request.query("isCaseOf").map(decode_reference).transpose()?;
+            let data_specification = request.query("dataSpecificationRef").map(decode_reference).transpose()?;
+            let items = read(storage)?.get_concept_descriptions()?.iter()
+                .filter(|concept_description| matches_id_short(concept_description.get_id_short(), request.query("idShort")))
+                .filter(|concept_description| is_case_of.as_ref()
+                    .is_none_or(|is_case_of| concept_description.get_is_case_ofs().contains(is_case_of)))
+                .filter(|concept_description| data_specification.as_ref()
+                    .is_none_or(|data_specification| concept_description.get_data_specifications().iter()
+                        .any(|embedded| embedded.get_data_specification() == data_specification)))
+                .map(to_json)
+                .collect::<Result<Vec<Value>, RepositoryError>>()?;
+            paged(request, items)
+        },
+        (&Method::POST, []) => {
+            let concept_description: ConceptDescription = request.json()?;
+            let mut storage = write(storage)?;
+            if storage.get_concept_description(concept_description.get_id())?.is_some() {
+                return Err(RepositoryError::Conflict(concept_description.get_id().clone()));
+            }
+            storage.put_concept_description(concept_description.clone())?;
+            json_response(StatusCode::CREATED, to_json(&concept_description)?)
+        },
+        (&Method::GET, [id]) => {
+            let id = decode_id(id)?;
+            let concept_description = read(storage)?.get_concept_description(&id)?.ok_or(RepositoryError::NotFound(id))?;
+            json_response(StatusCode::OK, to_json(&concept_description)?)
+        },
+        (&Method::PUT, [id]) => {
+            let id = decode_id(id)?;
+            let concept_description: ConceptDescription = request.json()?;
+            check_id(concept_description.get_id(), &id)?;
+            let mut storage = write(storage)?;
+            let created = storage.get_concept_description(&id)?.is_none();
+            storage.put_concept_description(concept_description.clone())?;
+            created_or_no_content(created, &concept_description)
+        },
+        (&Method::DELETE, [id]) => {
+            let id = decode_id(id)?;
+            match write(storage)?.delete_concept_description(&id)? {
+                true => Ok(StatusCode::NO_CONTENT.into_response()),
+                false => Err(RepositoryError::NotFound(id))
+            }
+        },
+        _ => Ok(method_not_allowed())
+    }
+}
+
+///Returns the serialization of a submodel selected by the content and the modifiers.
+fn submodel_content(submodel: &Submodel, content: Content, modifiers: &Modifiers) -> Result<Value, RepositoryError> {
+    match content {
+        Content::Normal => {
+            let mut value = to_json(submodel)?;
+            apply_modifiers(&mut value, modifiers);
+            Ok(value)
+        },
+        Content::Metadata => {
+            let mut value = to_json(submodel)?;
+            value.as_object_mut().into_iter().for_each(|submodel| {
+                submodel.remove("submodelElements");
+            });
+            Ok(value)
+        },
+        Content::Value if modifiers.deep => Ok(submodel.get_value_only(modifiers.with_blob_value)),
+        Content::Value => {
+            let mut submodel = submodel.clone();
+            remove_grandchildren(submodel.get_mut_submodel_elements());
+            Ok(submodel.get_value_only(modifiers.with_blob_value))
+        },
+        Content::Reference => to_json(&model_reference(KeyType::Submodel, submodel.get_id())),
+        Content::Path => {
+            let mut paths = Vec::new();
+            for element in submodel.get_submodel_elements() {
+                collect_paths(element, element.get_id_short().cloned().unwrap_or_default(), modifiers.deep, &mut paths);
+            }
+            Ok(Value::Array(paths))
+        }
+    }
+}
+
+///Returns the serialization of a submodel element selected by the content and the modifiers.
+fn element_content(submodel: &Submodel, id_short_path: &str, element: &SubmodelElement, content: Content, modifiers: &Modifiers)
+                   -> Result<Value, RepositoryError> {
+    match content {
+        Content::Normal => {
+            let mut value = to_json(element)?;
+            apply_modifiers(&mut value, modifiers);
+            Ok(value)
+        },
+        Content::Metadata => {
+            let mut value = to_json(element)?;
+            remove_values(&mut value);
+            Ok(value)
+        },
+        Content::Value if modifiers.deep => Ok(element.get_value_only(modifiers.with_blob_value).unwrap_or(Value::Null)),
+        Content::Value => {
+            let mut element = element.clone();
+            element.get_mut_children().into_iter().for_each(|children| remove_grandchildren(children));
+            Ok(element.get_value_only(modifiers.with_blob_value).unwrap_or(Value::Null))
+        },
+        Content::Reference => to_json(&submodel.get_submodel_element_reference(id_short_path)
+            .ok_or_else(|| RepositoryError::NotFound(id_short_path.to_string()))?),
+        Content::Path => {
+            let mut paths = Vec::new();
+            collect_paths(element, id_short_path.to_string(), modifiers.deep, &mut paths);
+            Ok(Value::Array(paths))
+        }
+    }
+}
+
+///Removes the elements below the direct children for `level=core` and the values of blobs for
+/// `extent=withoutBlobValue` from a serialized submodel or submodel element.
+fn apply_modifiers(value: &mut Value, modifiers: &Modifiers) {
+    if !modifiers.deep {
+        let model_type = value.get("modelType").and_then(|model_type| model_type.as_str()).unwrap_or_default().to_string();
+        if let Some(Value::Array(children)) = children_key(&model_type).and_then(|key| value.get_mut(key)) {
+            for child in children.iter_mut() {
+                let child_model_type = child.get("modelType").and_then(|model_type| model_type.as_str()).unwrap_or_default().to_string();
+                if let (Some(key), Some(child)) = (children_key(&child_model_type), child.as_object_mut()) {
+                    child.remove(key);
+                }
+            }
+        }
+    }
+    if !modifiers.with_blob_value {
+        remove_blob_values(value);
+    }
+}
+
+///Removes the elements nested in the elements for `level=core`, leaving collections, lists and
+/// entities empty.
+fn remove_grandchildren(children: &mut [SubmodelElement]) {
+    children.iter_mut().filter_map(|child| child.get_mut_children()).for_each(|grandchildren| grandchildren.clear());
+}
+
+///Returns the key of the nested elements of a serialized submodel or submodel element.
+fn children_key(model_type: &str) -> Option<&'static str> {
+    match model_type {
+        "Submodel" => Some("submodelElements"),
+        "SubmodelElementCollection" | "SubmodelElementList" => Some("value"),
+        "Entity" => Some("statements"),
+        _ => None
+    }
+}
+
+fn remove_blob_values(value: &mut Value) {
+    match value {
+        Value::Object(object) => {
+            if object.get("modelType").is_some_and(|model_type| model_type == "Blob") {
+                object.remove("value");
+            }
+            object.values_mut().for_each(remove_blob_values);
+        },
+        Value::Array(array) => array.iter_mut().for_each(remove_blob_values),
+        _ => {}
+    }
+}
+
+///Removes the attributes of a serialized submodel element that belong to its value for `$metadata`.
+fn remove_values(value: &mut Value) {
+    let Some(object) = value.as_object_mut() else {
+        return;
+    };
+    let keys: &[&str] = match object.get("modelType").and_then(|model_type| model_type.as_str()).unwrap_or_default() {
+        "Property" | "MultiLanguageProperty" => &["value", "valueId"],
+        "Range" => &["min", "max"],
+        "Blob" | "File" | "ReferenceElement" | "SubmodelElementCollection" | "SubmodelElementList" => &["value"],
+        "RelationshipElement" => &["first", "second"],
+        "AnnotatedRelationshipElement" => &["first", "second", "annotations"],
+        "Entity" => &["statements", "globalAssetId", "specificAssetIds"],
+        "BasicEventElement" => &["observed"],
+        _ => &[]
+    };
+    keys.iter().for_each(|key| {
+        object.remove(*key);
+    });
+}
+
+///Adds the idShortPaths of an element and of the elements nested in it.
+fn collect_paths(element: &SubmodelElement, path: String, deep: bool, paths: &mut Vec<Value>) {
+    let children = element.get_children().cloned().unwrap_or_default();
+    let is_list = matches!(element, SubmodelElement::SubmodelElementList(_));
+    paths.push(Value::String(path.clone()));
+    for (index, child) in children.iter().enumerate() {
+        let child_path = match is_list {
+            true => format!("{}[{}]", path, index),
+            false => format!("{}.{}", path, child.get_id_short().cloned().unwrap_or_default())
+        };
+        match deep {
+            true => collect_paths(child, child_path, deep, paths),
+            false => paths.push(Value::String(child_path))
+        }
+    }
+}
+
+///Adds an element to the elements of a submodel, collection, list or entity. Elements outside of
+/// lists need an idShort that is unique among their siblings.
+fn add_child(children: &mut Vec<SubmodelElement>, element: SubmodelElement, is_list: bool) -> Result<(), RepositoryError> {
+    if !is_list {
+        let id_short = element.get_id_short().ok_or_else(|| RepositoryError::InvalidRequest("the element has no idShort".to_string()))?;
+        if children.iter().any(|child| child.get_id_short() == Some(id_short)) {
+            return Err(RepositoryError::Conflict(id_short.clone()));
+        }
+    }
+    children.push(element);
+    Ok(())
+}
+
+///Reads a submodel, modifies the element at the idShortPath and writes the submodel.
+fn update_element<S: TRepositoryStorage>(storage: &RwLock<S>, submodel_id: &str, id_short_path: &str,
+                                         update: impl FnOnce(&mut SubmodelElement) -> Result<(), RepositoryError>) -> Result<Response, RepositoryError> {
+    let mut storage = write(storage)?;
+    let mut submodel = get_submodel(&*storage, submodel_id)?;
+    let element = submodel.get_mut_submodel_element(id_short_path).ok_or_else(|| RepositoryError::NotFound(id_short_path.to_string()))?;
+    update(element)?;
+    storage.put_submodel(submodel)?;
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+///Returns the file name, the content type and the content of an uploaded attachment, which is
+/// either the part named `file` of a `multipart/form-data` body or the whole body.
+fn parse_attachment(request: &Request) -> Result<(Option<String>, String, Vec<u8>), RepositoryError> {
+    let content_type = request.content_type.clone().unwrap_or_else(|| "application/octet-stream".to_string());
+    let Some(boundary) = content_type.strip_prefix("multipart/form-data")
+        .and_then(|parameters| parameters.split(';').find_map(|parameter| parameter.trim().strip_prefix("boundary=")))
+        .map(|boundary| format!("--{}", boundary.trim_matches('"'))) else {
+        return Ok((request.query("fileName").map(|file_name| file_name.to_string()), content_type, request.body.to_vec()));
+    };
+    let invalid = || RepositoryError::InvalidRequest("malformed multipart body".to_string());
+    let mut file = None;
+    let mut file_name = None;
+    for part in split_bytes(&request.body, boundary.as_bytes()).into_iter().skip(1) {
+        if part.starts_with(b"--") {
+            break;
+        }
+        let part = part.strip_prefix(b"\r\n").unwrap_or(part);
+        let part = part.strip_suffix(b"\r\n").unwrap_or(part);
+        let header_end = part.windows(4).position(|window| window == b"\r\n\r\n").ok_or_else(invalid)?;
+        let headers = String::from_utf8_lossy(&part[..header_end]).to_string();
+        let content = part[header_end + 4..].to_vec();
+        let header_value = |name: &str| headers.split("\r\n").filter_map(|line| line.split_once(':'))
+            .find(|(header, _)| header.trim().eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.trim().to_string());
+        let disposition = header_value("content-disposition").unwrap_or_default();
+        let parameter = |name: &str| disposition.split(';')
+            .find_map(|parameter| parameter.trim().strip_prefix(name).and_then(|value| value.strip_prefix('=')))
+            .map(|value| value.trim_matches('"').to_string());
+        match parameter("name").as_deref() {
+            Some("file") => file = Some((parameter("filename"), header_value("content-type"), content)),
+            Some("fileName") => file_name = Some(String::from_utf8_lossy(&content).to_string()),
+            _ => {}
+        }
+    }
+    let (uploaded_file_name, part_content_type, content) = file.ok_or_else(invalid)?;
+    Ok((file_name.or(uploaded_file_name), part_content_type.unwrap_or_else(|| "application/octet-stream".to_string()), content))
+}
+
+fn split_bytes<'a>(bytes: &'a [u8], separator: &[u8]) -> Vec<&'a [u8]> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut position = 0;
+    while position + separator.len() <= bytes.len() {
+        if &bytes[position..position + separator.len()] == separator {
+            parts.push(&bytes[start..position]);
+            position += separator.len();
+            start = position;
+        } else {
+            position += 1;
+        }
+    }
+    parts.push(&bytes[start..]);
+    parts
+}
+
+///Returns a page of the items selected by the query parameters `cursor` and `limit` together with
+/// the cursor of the next page.
+fn paged(request: &Request, items: Vec<Value>) -> Result<Response, RepositoryError> {
+    let start = match request.query("cursor") {
+        Some(cursor) => decode_id(cursor).ok().and_then(|start| start.parse::<usize>().ok())
+            .ok_or_else(|| RepositoryError::InvalidRequest(format!("invalid cursor '{}'", cursor)))?,
+        None => 0
+    };
+    let limit = match request.query("limit") {
+        Some(limit) => limit.parse::<usize>().ok().filter(|limit| *limit > 0)
+            .ok_or_else(|| RepositoryError::InvalidRequest(format!("invalid limit '{}'", limit)))?,
+        None => usize::MAX
+    };
+    let end = start.saturating_add(limit).min(items.len());
+    let mut paging_metadata = Map::new();
+    if end < items.len() {
+        paging_metadata.insert("cursor".to_string(), Value::String(encode_base64(end.to_string().as_bytes(), true)));
+    }
+    let mut page = Map::new();
+    page.insert("paging_metadata".to_string(), Value::Object(paging_metadata));
+    page.insert("result".to_string(), Value::Array(items.into_iter().take(end).skip(start).collect()));
+    json_response(StatusCode::OK, Value::Object(page))
+}
+
+fn json_response(status: StatusCode, value: Value) -> Result<Response, RepositoryError> {
+    Ok((status, [(header::CONTENT_TYPE, "application/json")], value.to_string()).into_response())
+}
+
+fn created_or_no_content<T: Serialize>(created: bool, identifiable: &T) -> Result<Response, RepositoryError> {
+    match created {
+        true => json_response(StatusCode::CREATED, to_json(identifiable)?),
+        false => Ok(StatusCode::NO_CONTENT.into_response())
+    }
+}
+
+fn error_response(error: RepositoryError) -> Response {
+    let status = match error {
+        RepositoryError::NotFound(_) => StatusCode::NOT_FOUND,
+        RepositoryError::Conflict(_) => StatusCode::CONFLICT,
+        RepositoryError::InvalidRequest(_) => StatusCode::BAD_REQUEST,
+        RepositoryError::StorageFailed(_) => StatusCode::INTERNAL_SERVER_ERROR
+    };
+    service_result(status, error.to_string())
+}
+
+fn method_not_allowed() -> Response {
+    service_result(StatusCode::METHOD_NOT_ALLOWED, "the method is not allowed for the resource".to_string())
+}
+
+fn service_result(status: StatusCode, text: String) -> Response {
+    let result = ServiceResult::error(status.as_u16().to_string(), text);
+    let body = serde_json::to_string(&result).unwrap_or_default();
+    (status, [(header::CONTENT_TYPE, "application/json")], body).into_response()
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<Value, RepositoryError> {
+    serde_json::to_value(value).map_err(|error| RepositoryError::StorageFailed(error.to_string()))
+}
+
+fn read<S>(storage: &RwLock<S>) -> Result<RwLockReadGuard<'_, S>, RepositoryError> {
+    storage.read().map_err(|_| RepositoryError::StorageFailed("the storage is poisoned".to_string()))
+}
+
+fn write<S>(storage: &RwLock<S>) -> Result<RwLockWriteGuard<'_, S>, RepositoryError> {
+    storage.write().map_err(|_| RepositoryError::StorageFailed("the storage is poisoned".to_string()))
+}
+
+fn get_shell<S: TRepositoryStorage>(storage: &S, id: &str) -> Result<AssetAdministrationShell, RepositoryError> {
+    storage.get_shell(id)?.ok_or_else(|| RepositoryError::NotFound(id.to_string()))
+}
+
+fn get_submodel<S: TRepositoryStorage>(storage: &S, id: &str) -> Result<Submodel, RepositoryError> {
+    storage.get_submodel(id)?.ok_or_else(|| RepositoryError::NotFound(id.to_string()))
+}
+
+fn check_id(id: &str, path_id: &str) -> Result<(), RepositoryError> {
+    match id == path_id {
+        true => Ok(()),
+        false => Err(RepositoryError::InvalidRequest(format!("the identifier '{}' differs from '{}' of the path", id, path_id)))
+    }
+}
+
+///Decodes a base64url encoded identifier of a path or query.
+fn decode_id(encoded: &str) -> Result<String, RepositoryError> {
+    decode_base64(encoded).and_then(|id| String::from_utf8(id).ok())
+        .ok_or_else(|| RepositoryError::InvalidRequest(format!("'{}' is no base64url encoded identifier", encoded)))
+}
+
+///Decodes a base64url encoded JSON reference of a query.
+fn decode_reference(encoded: &str) -> Result<Reference, RepositoryError> {
+    serde_json::from_str(&decode_id(encoded)?).map_err(|error| RepositoryError::InvalidRequest(error.to_string()))
+}
+
+fn model_reference(key_type: KeyType, id: &str) -> Reference {
+    Reference::new(ReferenceType::ModelReference, vec![Key::new(key_type, id.to_string())])
+}
+
+fn matches_id_short(id_short: Option<&String>, filter: Option<&str>) -> bool {
+    filter.is_none_or(|filter| id_short.is_some_and(|id_short| id_short == filter))
+}
+
+///Returns whether the shell has the asset ID given as JSON object with name and value. The name
+/// `globalAssetId` denotes the global asset ID.
+fn has_asset_id(shell: &AssetAdministrationShell, asset_id: &Value) -> bool {
+    let name = asset_id.get("name").and_then(|name| name.as_str()).unwrap_or_default();
+    let value = asset_id.get("value").and_then(|value| value.as_str()).unwrap_or_default();
+    let asset_information = shell.get_asset_information();
+    match name {
+        "globalAssetId" => asset_information.get_global_asset_id().is_some_and(|global_asset_id| global_asset_id == value),
+        _ => asset_information.get_specific_asset_ids().iter()
+            .any(|specific_asset_id| specific_asset_id.get_name() == name && specific_asset_id.get_value() == value)
+    }
+}
+
+fn references_submodel(shell: &AssetAdministrationShell, submodel_id: &str) -> bool {
+    shell.get_submodels().iter().any(|reference| reference.get_keys().last().is_some_and(|key| key.get_value() == submodel_id))
+}
+
+fn remove_submodel_reference(shell: &mut AssetAdministrationShell, submodel_id: &str) -> bool {
+    let count = shell.get_submodels().len();
+    shell.get_mut_submodels().retain(|reference| reference.get_keys().last().is_none_or(|key| key.get_value() != submodel_id));
+    shell.get_submodels().len() != count
+}
+
+///Decodes the percent-encoded characters of a path segment or query component; in queries `+`
+/// denotes a space.
+fn percent_decode(text: &str, query: bool) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'%' if index + 2 < bytes.len() && bytes[index + 1].is_ascii_hexdigit() && bytes[index + 2].is_ascii_hexdigit() => {
+                decoded.push(u8::from_str_radix(&text[index + 1..index + 3], 16).unwrap_or_default());
+                index += 3;
+            },
+            b'+' if query => {
+                decoded.push(b' ');
+                index += 1;
+            },
+            byte => {
+                decoded.push(byte);
+                index += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::{Method, Request, StatusCode};
+    use axum::Router;
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+    use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+    use crate::structs::blob::encode_base64;
+    use crate::structs::memory_storage::MemoryStorage;
+    use crate::structs::property::Property;
+    use crate::structs::range::Range;
+    use crate::structs::repository_server::RepositoryServer;
+    use crate::structs::submodel::Submodel;
+    use crate::structs::submodel_element_collection::SubmodelElementCollection;
+    use crate::traits::referable::TReferable;
+    use crate::traits::repository_storage::TRepositoryStorage;
+
+    const SUBMODEL_ID: &str = "urn:example:submodel";
+
+    fn property(id_short: &str, value: &str) -> SubmodelElement {
+        let mut property = Property::new(DataTypeDefXsd::Int);
+        property.set_id_short(id_short.to_string());
+        property.set_value(value.to_string());
+        SubmodelElement::Property(property)
+    }
+
+    fn collection(id_short: &str, elements: Vec<SubmodelElement>) -> SubmodelElement {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_id_short(id_short.to_string());
+        collection.set_value(elements);
+        SubmodelElement::SubmodelElementCollection(collection)
+    }
+
+    ///Returns the router of a server whose storage contains a submodel with a nested collection
+    /// and a range.
+    fn router() -> Router {
+        let mut range = Range::new(DataTypeDefXsd::Double);
+        range.set_id_short("Limits".to_string());
+        range.set_min("0.5".to_string());
+        let mut submodel = Submodel::new(SUBMODEL_ID.to_string());
+        submodel.set_submodel_elements(vec![
+            property("Temperature", "21"),
+            collection("Status", vec![property("Speed", "1200"), collection("Details", vec![property("Errors", "0")])]),
+            SubmodelElement::Range(range)
+        ]);
+        let mut storage = MemoryStorage::new();
+        storage.put_submodel(submodel).unwrap();
+        RepositoryServer::new(storage).router()
+    }
+
+    fn submodel_path(path: &str) -> String {
+        format!("/submodels/{}{}", encode_base64(SUBMODEL_ID.as_bytes(), true), path)
+    }
+
+    ///Sends a request to the router and returns the status and the JSON body of the response.
+    async fn send(router: &Router, method: Method, path: &str, body: Option<Value>) -> (StatusCode, Value) {
+        let request = Request::builder().method(method).uri(path)
+            .header("Content-Type", "application/json")
+            .body(body.map(|body| Body::from(body.to_string())).unwrap_or_default())
+            .unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+    }
+
+    #[tokio::test]
+    async fn patches_valid_property_value() {
+        let router = router();
+        let path = submodel_path("/submodel-elements/Temperature/$value");
+        assert_eq!(send(&router, Method::PATCH, &path, Some(json!(22))).await.0, StatusCode::NO_CONTENT);
+        assert_eq!(send(&router, Method::GET, &path, None).await, (StatusCode::OK, json!(22)));
+    }
+
+    #[tokio::test]
+    async fn rejects_value_invalid_for_data_type() {
+        let router = router();
+        let path = submodel_path("/submodel-elements/Temperature/$value");
+        let (status, result) = send(&router, Method::PATCH, &path, Some(json!("abc"))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(result["messages"].is_array());
+        let (status, _) = send(&router, Method::PATCH, &submodel_path("/$value"), Some(json!({"Limits": {"max": "high"}}))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(send(&router, Method::GET, &submodel_path("/$value"), None).await.1,
+                   json!({"Temperature": 21, "Status": {"Speed": 1200, "Details": {"Errors": 0}}, "Limits": {"min": 0.5}}));
+    }
+
+    #[tokio::test]
+    async fn restricts_value_to_core_level() {
+        let router = router();
+        assert_eq!(send(&router, Method::GET, &submodel_path("/$value?level=core"), None).await.1,
+                   json!({"Temperature": 21, "Status": {}, "Limits": {"min": 0.5}}));
+        assert_eq!(send(&router, Method::GET, &submodel_path("/submodel-elements/Status/$value?level=core"), None).await.1,
+                   json!({"Speed": 1200, "Details": {}}));
+        assert_eq!(send(&router, Method::GET, &submodel_path("/submodel-elements/Status/$value"), None).await.1,
+                   json!({"Speed": 1200, "Details": {"Errors": 0}}));
+    }
+
+    #[tokio::test]
+    async fn restricts_elements_to_core_level() {
+        let router = router();
+        let (status, element) = send(&router, Method::GET, &submodel_path("/submodel-elements/Status?level=core"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(element["value"][0]["value"], json!("1200"));
+        assert_eq!(element["value"][1].get("value"), None);
+        assert_eq!(send(&router, Method::GET, &submodel_path("/$path?level=core"), None).await.1,
+                   json!(["Temperature", "Status", "Status.Speed", "Status.Details", "Limits"]));
+    }
+
+    #[tokio::test]
+    async fn answers_unknown_resources_with_service_result() {
+        let router = router();
+        let (status, result) = send(&router, Method::GET, &submodel_path("/submodel-elements/Humidity"), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert!(result["messages"][0]["text"].as_str().is_some_and(|text| text.contains("Humidity")));
+        assert_eq!(send(&router, Method::GET, "/submodels/invalid%20id", None).await.0, StatusCode::BAD_REQUEST);
+        assert_eq!(send(&router, Method::DELETE, &submodel_path("/$value"), None).await.0, StatusCode::METHOD_NOT_ALLOWED);
+    }
+}
\ No newline at end of file
@@ -7,7 +7,7 @@ pub struct Resource {
     ///Path and name of the resource (with file extension).
     path: String,
     ///Content type of the content of the file.
-    #[serde(rename = "contentType")]
+    #[serde(rename = "contentType", default, skip_serializing_if = "Option::is_none")]
     content_type: Option<String>
 }
 
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+use crate::enumerations::security_type::SecurityType;
+
+///Security attribute of the protocol information of an endpoint, e.g. a TLSA record the
+/// certificate of the endpoint is verified with.
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
+pub struct SecurityAttributeObject {
+    ///Type of the security attribute.
+    #[serde(rename = "type")]
+    security_type: SecurityType,
+    ///Key of the security attribute.
+    key: String,
+    ///Value of the security attribute.
+    value: String
+}
+
+impl SecurityAttributeObject {
+    ///Creates a new security attribute.
+    ///
+    /// [security_type]: type of the security attribute
+    /// [key]: key of the security attribute
+    /// [value]: value of the security attribute
+    pub fn new(security_type: SecurityType, key: String, value: String) -> SecurityAttributeObject {
+        SecurityAttributeObject {
+            security_type,
+            key,
+            value
+        }
+    }
+
+    ///Returns the type of the security attribute.
+    pub fn get_security_type(&self) -> &SecurityType {
+        &self.security_type
+    }
+
+    ///Sets the type of the security attribute.
+    ///
+    /// [security_type]: type of the security attribute
+    pub fn set_security_type(&mut self, security_type: SecurityType) {
+        self.security_type = security_type;
+    }
+
+    ///Returns the key of the security attribute.
+    pub fn get_key(&self) -> &String {
+        &self.key
+    }
+
+    ///Sets the key of the security attribute.
+    ///
+    /// [key]: key of the security attribute
+    pub fn set_key(&mut self, key: String) {
+        self.key = key;
+    }
+
+    ///Returns the value of the security attribute.
+    pub fn get_value(&self) -> &String {
+        &self.value
+    }
+
+    ///Sets the value of the security attribute.
+    ///
+    /// [value]: value of the security attribute
+    pub fn set_value(&mut self, value: String) {
+        self.value = value;
+    }
+}
\ No newline at end of file
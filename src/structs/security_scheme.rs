@@ -0,0 +1,132 @@
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::structs::asset_interface::{add_terms, get_term, set_term, terms_to_json};
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, TemplateElement};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+use serde_json::{Map, Value};
+
+///Typed view on a security scheme of the endpoint metadata of an asset interface, e.g. basic
+/// authentication. The idShort of the collection is the name the security configuration refers
+/// to.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct SecurityScheme {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl SecurityScheme {
+    ///Namespace of the semantic IDs of security schemes, which end with the class of the scheme,
+    /// e.g. `BasicSecurityScheme`.
+    pub const SEMANTIC_ID_NAMESPACE: &str = "https://www.w3.org/2019/wot/security#";
+
+    ///Creates a new security scheme.
+    ///
+    /// [name]: name of the security scheme, e.g. `basic_sc`
+    /// [scheme]: identifier of the scheme, e.g. `basic` or `nosec`
+    pub fn new(name: &str, scheme: &str) -> SecurityScheme {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_id_short(name.to_string());
+        let class = SCHEMES.iter()
+            .find(|(identifier, _)| *identifier == scheme)
+            .map(|(_, class)| *class)
+            .unwrap_or("SecurityScheme");
+        collection.set_semantic_id(global_reference(&format!("{}{}", SecurityScheme::SEMANTIC_ID_NAMESPACE, class)));
+        let mut security_scheme = SecurityScheme {
+            collection
+        };
+        SCHEME.set_property_value(&mut security_scheme.collection, Some(&scheme.to_string()));
+        security_scheme
+    }
+
+    ///Wraps a submodel element collection after checking the elements of a security scheme.
+    ///
+    /// [collection]: collection containing a security scheme
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<SecurityScheme, ConversionError> {
+        SecurityScheme::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(SecurityScheme {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the security scheme and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the name of the security scheme, i.e. the idShort.
+    pub fn get_name(&self) -> Option<&String> {
+        self.collection.get_id_short()
+    }
+
+    ///Returns the identifier of the scheme, e.g. `basic`.
+    pub fn get_scheme(&self) -> Option<String> {
+        self.collection.get_property_value(SCHEME.id_short, SCHEME.semantic_id)
+    }
+
+    ///Returns the value of a term of the security scheme as JSON, e.g. `"header"` for `in`.
+    ///
+    /// [term]: term of the Thing Description
+    pub fn get_term(&self, term: &str) -> Option<Value> {
+        get_term(&self.collection, term)
+    }
+
+    ///Sets the value of a term of the security scheme. Passing None removes it.
+    ///
+    /// [term]: term of the Thing Description
+    /// [value]: JSON value of the term
+    pub fn set_term(&mut self, term: &str, value: Option<&Value>) {
+        set_term(&mut self.collection, term, value);
+    }
+
+    ///Returns the security scheme as JSON object of a Thing Description.
+    pub(crate) fn to_json(&self) -> Map<String, Value> {
+        terms_to_json(&self.collection, &[])
+    }
+
+    ///Creates a security scheme from the JSON object of a Thing Description.
+    ///
+    /// [name]: name of the security scheme
+    /// [scheme]: identifier of the scheme
+    /// [json]: JSON object of the security scheme
+    pub(crate) fn from_json(name: &str, scheme: &str, json: &Map<String, Value>) -> SecurityScheme {
+        let mut security_scheme = SecurityScheme::new(name, scheme);
+        add_terms(&mut security_scheme.collection, json, &[SCHEME.id_short]);
+        security_scheme
+    }
+
+    ///Checks the elements of a security scheme.
+    ///
+    /// [collection]: collection containing a security scheme
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        SCHEME.check(collection, path)?;
+        Ok(())
+    }
+}
+
+const SCHEME: TemplateElement =
+    TemplateElement::property("scheme", "https://www.w3.org/2019/wot/security#SecurityScheme", DataTypeDefXsd::String, Cardinality::One);
+
+///Identifiers of the security schemes of the Thing Description with their classes.
+const SCHEMES: [(&str, &str); 9] = [
+    ("nosec", "NoSecurityScheme"),
+    ("auto", "AutoSecurityScheme"),
+    ("combo", "ComboSecurityScheme"),
+    ("basic", "BasicSecurityScheme"),
+    ("digest", "DigestSecurityScheme"),
+    ("apikey", "APIKeySecurityScheme"),
+    ("bearer", "BearerSecurityScheme"),
+    ("psk", "PSKSecurityScheme"),
+    ("oauth2", "OAuth2SecurityScheme")
+];
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+use crate::enumerations::message_type::MessageType;
+use crate::structs::message::Message;
+
+///Result of a call of the HTTP API with the messages of the server, which is the body of error
+/// responses, e.g. if a shell is not found.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ServiceResult {
+    ///Messages of the server.
+    #[serde(default)]
+    messages: Vec<Message>
+}
+
+impl ServiceResult {
+    ///Creates a new result without messages.
+    pub fn new() -> ServiceResult {
+        ServiceResult {
+            messages: Vec::new()
+        }
+    }
+
+    ///Creates a new result with one error message.
+    ///
+    /// [code]: code of the message, e.g. the HTTP status code `404`
+    /// [text]: text of the message
+    pub fn error(code: String, text: String) -> ServiceResult {
+        let mut message = Message::new(MessageType::Error, text);
+        message.set_code(code);
+        ServiceResult {
+            messages: vec![message]
+        }
+    }
+
+    ///Returns the messages of the server.
+    pub fn get_messages(&self) -> &Vec<Message> {
+        &self.messages
+    }
+
+    ///Adds a message.
+    ///
+    /// [message]: message to add
+    pub fn add_message(&mut self, message: Message) {
+        self.messages.push(message);
+    }
+}
+
+impl Default for ServiceResult {
+    fn default() -> Self {
+        ServiceResult::new()
+    }
+}
\ No newline at end of file
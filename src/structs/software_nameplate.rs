@@ -0,0 +1,139 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::modelling_kind::ModellingKind;
+use crate::structs::conformance_report::ConformanceReport;
+use crate::structs::software_nameplate_instance::SoftwareNameplateInstance;
+use crate::structs::software_nameplate_type::SoftwareNameplateType;
+use crate::structs::submodel::Submodel;
+use crate::structs::template_element::{global_reference, TemplateElement};
+use crate::traits::has_kind::THasKind;
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed model of the submodel template Software Nameplate (IDTA 02007-1-0), which identifies a
+/// software product by its type part and, for installed software, by its instance part.
+///
+/// The model wraps the submodel, so the conversion from and to a submodel is lossless and elements
+/// the model does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct SoftwareNameplate {
+    ///The wrapped submodel.
+    submodel: Submodel
+}
+
+impl SoftwareNameplate {
+    ///Semantic ID of the Software Nameplate submodel.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/idta/SoftwareNameplate/1/0";
+    ///idShort of the Software Nameplate submodel.
+    pub const ID_SHORT: &str = "SoftwareNameplate";
+
+    ///Creates a new Software Nameplate submodel with the type part.
+    ///
+    /// [id]: global identifier of the submodel
+    /// [software_nameplate_type]: type part describing the released software
+    pub fn new(id: String, software_nameplate_type: SoftwareNameplateType) -> SoftwareNameplate {
+        let mut submodel = Submodel::new(id);
+        submodel.set_id_short(SoftwareNameplate::ID_SHORT.to_string());
+        submodel.set_semantic_id(global_reference(SoftwareNameplate::SEMANTIC_ID));
+        submodel.set_kind(ModellingKind::Instance);
+        let mut software_nameplate = SoftwareNameplate {
+            submodel
+        };
+        software_nameplate.set_software_nameplate_type(software_nameplate_type);
+        software_nameplate
+    }
+
+    ///Wraps a submodel after checking that it contains the mandatory elements of the Software
+    /// Nameplate with the expected types and valid values.
+    ///
+    /// [submodel]: Software Nameplate submodel
+    pub fn from_submodel(submodel: Submodel) -> Result<SoftwareNameplate, ConversionError> {
+        SoftwareNameplate::check(&submodel)?;
+        Ok(SoftwareNameplate {
+            submodel
+        })
+    }
+
+    ///Returns the wrapped submodel.
+    pub fn get_submodel(&self) -> &Submodel {
+        &self.submodel
+    }
+
+    ///Consumes the Software Nameplate and returns the wrapped submodel.
+    pub fn into_submodel(self) -> Submodel {
+        self.submodel
+    }
+
+    ///Returns the submodel template of the Software Nameplate.
+    pub fn template() -> Submodel {
+        let mut template = Submodel::new("https://admin-shell.io/idta/SubmodelTemplate/SoftwareNameplate/1/0".to_string());
+        template.set_id_short(SoftwareNameplate::ID_SHORT.to_string());
+        template.set_semantic_id(global_reference(SoftwareNameplate::SEMANTIC_ID));
+        template.set_kind(ModellingKind::Template);
+        template.set_submodel_elements(vec![
+            SOFTWARE_NAMEPLATE_TYPE.to_collection(SoftwareNameplateType::template_elements()),
+            SOFTWARE_NAMEPLATE_INSTANCE.to_collection(SoftwareNameplateInstance::template_elements())
+        ]);
+        template
+    }
+
+    ///Checks the wrapped submodel against the submodel template of the Software Nameplate.
+    pub fn check_conformance(&self) -> ConformanceReport {
+        self.submodel.check_conformance(&SoftwareNameplate::template())
+    }
+
+    ///Returns the type part describing the released software.
+    pub fn get_software_nameplate_type(&self) -> Option<SoftwareNameplateType> {
+        self.submodel.get_collection(SOFTWARE_NAMEPLATE_TYPE.id_short, SOFTWARE_NAMEPLATE_TYPE.semantic_id)
+            .map(|collection| SoftwareNameplateType {
+                collection: collection.clone()
+            })
+    }
+
+    ///Sets the type part describing the released software.
+    ///
+    /// [software_nameplate_type]: type part describing the released software
+    pub fn set_software_nameplate_type(&mut self, software_nameplate_type: SoftwareNameplateType) {
+        self.submodel.set_element(SOFTWARE_NAMEPLATE_TYPE.id_short, SOFTWARE_NAMEPLATE_TYPE.semantic_id,
+                                  Some(SubmodelElement::SubmodelElementCollection(software_nameplate_type.into_collection())));
+    }
+
+    ///Returns the instance part describing the installed software.
+    pub fn get_software_nameplate_instance(&self) -> Option<SoftwareNameplateInstance> {
+        self.submodel.get_collection(SOFTWARE_NAMEPLATE_INSTANCE.id_short, SOFTWARE_NAMEPLATE_INSTANCE.semantic_id)
+            .map(|collection| SoftwareNameplateInstance {
+                collection: collection.clone()
+            })
+    }
+
+    ///Sets the instance part describing the installed software. Passing None removes it, e.g. for
+    /// the nameplate of a software product that is not installed.
+    ///
+    /// [software_nameplate_instance]: instance part describing the installed software
+    pub fn set_software_nameplate_instance(&mut self, software_nameplate_instance: Option<SoftwareNameplateInstance>) {
+        self.submodel.set_element(SOFTWARE_NAMEPLATE_INSTANCE.id_short, SOFTWARE_NAMEPLATE_INSTANCE.semantic_id,
+                                  software_nameplate_instance.map(|instance| SubmodelElement::SubmodelElementCollection(instance.into_collection())));
+    }
+
+    fn check(submodel: &Submodel) -> Result<(), ConversionError> {
+        for element in SOFTWARE_NAMEPLATE_TYPE.check(submodel, "")? {
+            if let SubmodelElement::SubmodelElementCollection(collection) = element {
+                SoftwareNameplateType::check(collection, SOFTWARE_NAMEPLATE_TYPE.id_short)?;
+            }
+        }
+        for element in SOFTWARE_NAMEPLATE_INSTANCE.check(submodel, "")? {
+            if let SubmodelElement::SubmodelElementCollection(collection) = element {
+                SoftwareNameplateInstance::check(collection, SOFTWARE_NAMEPLATE_INSTANCE.id_short)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+const SOFTWARE_NAMEPLATE_TYPE: TemplateElement =
+    TemplateElement::new("SoftwareNameplateType", SoftwareNameplateType::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::One);
+const SOFTWARE_NAMEPLATE_INSTANCE: TemplateElement =
+    TemplateElement::new("SoftwareNameplateInstance", SoftwareNameplateInstance::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToOne);
@@ -0,0 +1,306 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::multi_language_text_type::MultiLanguageTextType;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, join_path, TemplateElement};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on the instance part of the Software Nameplate, which describes a software product as
+/// installed on a particular system.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct SoftwareNameplateInstance {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl SoftwareNameplateInstance {
+    ///Semantic ID of the instance part of the Software Nameplate.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateInstance";
+
+    ///Creates a new instance part with the mandatory elements.
+    ///
+    /// [serial_number]: serial number of the software instance
+    /// [instance_name]: name of the software instance
+    /// [installed_version]: version of the software that is installed
+    pub fn new(serial_number: String, instance_name: String, installed_version: String) -> SoftwareNameplateInstance {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_semantic_id(global_reference(SoftwareNameplateInstance::SEMANTIC_ID));
+        let mut software_nameplate_instance = SoftwareNameplateInstance {
+            collection
+        };
+        software_nameplate_instance.set_serial_number(serial_number);
+        software_nameplate_instance.set_instance_name(instance_name);
+        software_nameplate_instance.set_installed_version(installed_version);
+        software_nameplate_instance
+    }
+
+    ///Wraps a submodel element collection after checking the elements of the instance part.
+    ///
+    /// [collection]: collection containing the instance part
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<SoftwareNameplateInstance, ConversionError> {
+        SoftwareNameplateInstance::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(SoftwareNameplateInstance {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the instance part and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the serial number of the software instance, e.g. its license key.
+    pub fn get_serial_number(&self) -> Option<String> {
+        self.collection.get_property_value(SERIAL_NUMBER.id_short, SERIAL_NUMBER.semantic_id)
+    }
+
+    ///Sets the serial number of the software instance, e.g. its license key.
+    ///
+    /// [serial_number]: serial number of the software instance, e.g. its license key
+    pub fn set_serial_number(&mut self, serial_number: String) {
+        SERIAL_NUMBER.set_property_value(&mut self.collection, Some(&serial_number));
+    }
+
+    ///Returns the name of the software instance.
+    pub fn get_instance_name(&self) -> Option<String> {
+        self.collection.get_property_value(INSTANCE_NAME.id_short, INSTANCE_NAME.semantic_id)
+    }
+
+    ///Sets the name of the software instance.
+    ///
+    /// [instance_name]: name of the software instance
+    pub fn set_instance_name(&mut self, instance_name: String) {
+        INSTANCE_NAME.set_property_value(&mut self.collection, Some(&instance_name));
+    }
+
+    ///Returns the version of the software that is installed.
+    pub fn get_installed_version(&self) -> Option<String> {
+        self.collection.get_property_value(INSTALLED_VERSION.id_short, INSTALLED_VERSION.semantic_id)
+    }
+
+    ///Sets the version of the software that is installed.
+    ///
+    /// [installed_version]: version of the software that is installed
+    pub fn set_installed_version(&mut self, installed_version: String) {
+        INSTALLED_VERSION.set_property_value(&mut self.collection, Some(&installed_version));
+    }
+
+    ///Returns the date the software was installed, as xs:date.
+    pub fn get_installation_date(&self) -> Option<String> {
+        self.collection.get_property_value(INSTALLATION_DATE.id_short, INSTALLATION_DATE.semantic_id)
+    }
+
+    ///Sets the date the software was installed, as xs:date. Passing None removes it.
+    ///
+    /// [installation_date]: date the software was installed, as xs:date
+    pub fn set_installation_date(&mut self, installation_date: Option<String>) {
+        INSTALLATION_DATE.set_property_value(&mut self.collection, installation_date.as_ref());
+    }
+
+    ///Returns the path the software is installed in.
+    pub fn get_installation_path(&self) -> Option<String> {
+        self.collection.get_property_value(INSTALLATION_PATH.id_short, INSTALLATION_PATH.semantic_id)
+    }
+
+    ///Sets the path the software is installed in. Passing None removes it.
+    ///
+    /// [installation_path]: path the software is installed in
+    pub fn set_installation_path(&mut self, installation_path: Option<String>) {
+        INSTALLATION_PATH.set_property_value(&mut self.collection, installation_path.as_ref());
+    }
+
+    ///Returns the source the software was installed from, e.g. a repository.
+    pub fn get_installation_source(&self) -> Option<String> {
+        self.collection.get_property_value(INSTALLATION_SOURCE.id_short, INSTALLATION_SOURCE.semantic_id)
+    }
+
+    ///Sets the source the software was installed from, e.g. a repository. Passing None removes it.
+    ///
+    /// [installation_source]: source the software was installed from, e.g. a repository
+    pub fn set_installation_source(&mut self, installation_source: Option<String>) {
+        INSTALLATION_SOURCE.set_property_value(&mut self.collection, installation_source.as_ref());
+    }
+
+    ///Returns the processor architecture the software runs on, e.g. x86-64.
+    pub fn get_installed_on_architecture(&self) -> Option<String> {
+        self.collection.get_property_value(INSTALLED_ON_ARCHITECTURE.id_short, INSTALLED_ON_ARCHITECTURE.semantic_id)
+    }
+
+    ///Sets the processor architecture the software runs on, e.g. x86-64. Passing None removes it.
+    ///
+    /// [installed_on_architecture]: processor architecture the software runs on, e.g. x86-64
+    pub fn set_installed_on_architecture(&mut self, installed_on_architecture: Option<String>) {
+        INSTALLED_ON_ARCHITECTURE.set_property_value(&mut self.collection, installed_on_architecture.as_ref());
+    }
+
+    ///Returns the operating system the software runs on.
+    pub fn get_installed_on_os(&self) -> Option<String> {
+        self.collection.get_property_value(INSTALLED_ON_OS.id_short, INSTALLED_ON_OS.semantic_id)
+    }
+
+    ///Sets the operating system the software runs on. Passing None removes it.
+    ///
+    /// [installed_on_os]: operating system the software runs on
+    pub fn set_installed_on_os(&mut self, installed_on_os: Option<String>) {
+        INSTALLED_ON_OS.set_property_value(&mut self.collection, installed_on_os.as_ref());
+    }
+
+    ///Returns the host the software is installed on.
+    pub fn get_installed_on_host(&self) -> Option<String> {
+        self.collection.get_property_value(INSTALLED_ON_HOST.id_short, INSTALLED_ON_HOST.semantic_id)
+    }
+
+    ///Sets the host the software is installed on. Passing None removes it.
+    ///
+    /// [installed_on_host]: host the software is installed on
+    pub fn set_installed_on_host(&mut self, installed_on_host: Option<String>) {
+        INSTALLED_ON_HOST.set_property_value(&mut self.collection, installed_on_host.as_ref());
+    }
+
+    ///Returns the service level agreement of the software instance.
+    pub fn get_sla_information(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(SLA_INFORMATION.id_short, SLA_INFORMATION.semantic_id)
+    }
+
+    ///Sets the service level agreement of the software instance. Passing None removes it.
+    ///
+    /// [sla_information]: service level agreement of the software instance
+    pub fn set_sla_information(&mut self, sla_information: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(SLA_INFORMATION.id_short, SLA_INFORMATION.semantic_id, sla_information);
+    }
+
+    ///Returns the license the software instance is used under.
+    pub fn get_software_license(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(SOFTWARE_LICENSE.id_short, SOFTWARE_LICENSE.semantic_id)
+    }
+
+    ///Sets the license the software instance is used under. Passing None removes it.
+    ///
+    /// [software_license]: license the software instance is used under
+    pub fn set_software_license(&mut self, software_license: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(SOFTWARE_LICENSE.id_short, SOFTWARE_LICENSE.semantic_id, software_license);
+    }
+
+    ///Returns the names of the installed modules of the software.
+    pub fn get_installed_modules(&self) -> Vec<String> {
+        self.get_values(&INSTALLED_MODULES, &INSTALLED_MODULE)
+    }
+
+    ///Sets the names of the installed modules of the software. An empty vector removes them.
+    ///
+    /// [installed_modules]: names of the installed modules
+    pub fn set_installed_modules(&mut self, installed_modules: Vec<String>) {
+        self.set_values(&INSTALLED_MODULES, &INSTALLED_MODULE, installed_modules);
+    }
+
+    ///Returns the paths of the configuration files of the software instance.
+    pub fn get_configuration_paths(&self) -> Vec<String> {
+        self.get_values(&CONFIGURATION_PATHS, &CONFIGURATION_PATH)
+    }
+
+    ///Sets the paths of the configuration files of the software instance. An empty vector removes
+    /// them.
+    ///
+    /// [configuration_paths]: paths of the configuration files
+    pub fn set_configuration_paths(&mut self, configuration_paths: Vec<String>) {
+        self.set_values(&CONFIGURATION_PATHS, &CONFIGURATION_PATH, configuration_paths);
+    }
+
+    ///Returns the elements of the instance part in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        let mut elements: Vec<SubmodelElement> = ELEMENTS.iter().map(|element| element.to_element()).collect();
+        elements.push(INSTALLED_MODULES.to_collection(vec![INSTALLED_MODULE.to_element()]));
+        elements.push(CONFIGURATION_PATHS.to_collection(vec![CONFIGURATION_PATH.to_element()]));
+        elements
+    }
+
+    ///Checks the elements of the instance part.
+    ///
+    /// [collection]: collection containing the instance part
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        for (values, value) in [(&INSTALLED_MODULES, &INSTALLED_MODULE), (&CONFIGURATION_PATHS, &CONFIGURATION_PATH)] {
+            for element in values.check(collection, path)? {
+                if let SubmodelElement::SubmodelElementCollection(values_collection) = element {
+                    value.check(values_collection, &join_path(path, values.id_short))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    ///Returns the values of the properties in the collection of values.
+    fn get_values(&self, values: &TemplateElement, value: &TemplateElement) -> Vec<String> {
+        self.collection.get_collection(values.id_short, values.semantic_id)
+            .map(|collection| collection.find_elements(value.id_short, value.semantic_id).into_iter()
+                .filter_map(|element| match element {
+                    SubmodelElement::Property(property) => property.get_typed_value(),
+                    _ => None
+                })
+                .collect())
+            .unwrap_or_default()
+    }
+
+    ///Sets the values of the properties in the collection of values, keeping the attributes of an
+    /// existing collection.
+    fn set_values(&mut self, values: &TemplateElement, value: &TemplateElement, new_values: Vec<String>) {
+        if new_values.is_empty() {
+            self.collection.set_element(values.id_short, values.semantic_id, None);
+            return;
+        }
+        let mut collection = self.collection.get_collection(values.id_short, values.semantic_id).cloned()
+            .unwrap_or_else(SubmodelElementCollection::new);
+        collection.set_elements(value.id_short, value.semantic_id, new_values.iter().map(|new_value| value.new_property(new_value)).collect());
+        self.collection.set_element(values.id_short, values.semantic_id, Some(SubmodelElement::SubmodelElementCollection(collection)));
+    }
+}
+
+const SERIAL_NUMBER: TemplateElement =
+    TemplateElement::property("SerialNumber", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateInstance/SerialNumber", DataTypeDefXsd::String, Cardinality::One);
+const INSTANCE_NAME: TemplateElement =
+    TemplateElement::property("InstanceName", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateInstance/InstanceName", DataTypeDefXsd::String, Cardinality::One);
+const INSTALLED_VERSION: TemplateElement =
+    TemplateElement::property("InstalledVersion", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateInstance/InstalledVersion", DataTypeDefXsd::String, Cardinality::One);
+const INSTALLATION_DATE: TemplateElement =
+    TemplateElement::property("InstallationDate", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateInstance/InstallationDate", DataTypeDefXsd::Date, Cardinality::ZeroToOne);
+const INSTALLATION_PATH: TemplateElement =
+    TemplateElement::property("InstallationPath", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateInstance/InstallationPath", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const INSTALLATION_SOURCE: TemplateElement =
+    TemplateElement::property("InstallationSource", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateInstance/InstallationSource", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const INSTALLED_ON_ARCHITECTURE: TemplateElement =
+    TemplateElement::property("InstalledOnArchitecture", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateInstance/InstalledOnArchitecture", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const INSTALLED_ON_OS: TemplateElement =
+    TemplateElement::property("InstalledOnOS", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateInstance/InstalledOnOS", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const INSTALLED_ON_HOST: TemplateElement =
+    TemplateElement::property("InstalledOnHost", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateInstance/InstalledOnHost", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const SLA_INFORMATION: TemplateElement =
+    TemplateElement::new("SLAInformation", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateInstance/SLAInformation", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const SOFTWARE_LICENSE: TemplateElement =
+    TemplateElement::new("SoftwareLicense", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateInstance/SoftwareLicense", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const INSTALLED_MODULES: TemplateElement =
+    TemplateElement::new("InstalledModules", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateInstance/InstalledModules", AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToOne);
+const CONFIGURATION_PATHS: TemplateElement =
+    TemplateElement::new("ConfigurationPaths", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateInstance/ConfigurationPaths", AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToOne);
+const INSTALLED_MODULE: TemplateElement =
+    TemplateElement::property("InstalledModule{00}", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateInstance/InstalledModule", DataTypeDefXsd::String, Cardinality::ZeroToMany);
+const CONFIGURATION_PATH: TemplateElement =
+    TemplateElement::property("ConfigurationPath{00}", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateInstance/ConfigurationPath", DataTypeDefXsd::String, Cardinality::ZeroToMany);
+
+const ELEMENTS: [&TemplateElement; 11] = [&SERIAL_NUMBER, &INSTANCE_NAME, &INSTALLED_VERSION, &INSTALLATION_DATE,
+    &INSTALLATION_PATH, &INSTALLATION_SOURCE, &INSTALLED_ON_ARCHITECTURE, &INSTALLED_ON_OS, &INSTALLED_ON_HOST,
+    &SLA_INFORMATION, &SOFTWARE_LICENSE];
@@ -0,0 +1,327 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::file::File;
+use crate::structs::multi_language_text_type::MultiLanguageTextType;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, TemplateElement};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on the type part of the Software Nameplate, which describes a version of a software
+/// product as released by its manufacturer.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct SoftwareNameplateType {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl SoftwareNameplateType {
+    ///Semantic ID of the type part of the Software Nameplate.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateType";
+
+    ///Creates a new type part with the mandatory elements.
+    ///
+    /// [uri_of_the_product]: unique global identification of the software product
+    /// [manufacturer_name]: legally valid designation of the manufacturer
+    /// [manufacturer_product_designation]: short description of the software product
+    /// [version]: version of the software
+    pub fn new(uri_of_the_product: String, manufacturer_name: Vec<MultiLanguageTextType>,
+               manufacturer_product_designation: Vec<MultiLanguageTextType>, version: String) -> SoftwareNameplateType {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_semantic_id(global_reference(SoftwareNameplateType::SEMANTIC_ID));
+        let mut software_nameplate_type = SoftwareNameplateType {
+            collection
+        };
+        software_nameplate_type.set_uri_of_the_product(uri_of_the_product);
+        software_nameplate_type.set_manufacturer_name(manufacturer_name);
+        software_nameplate_type.set_manufacturer_product_designation(manufacturer_product_designation);
+        software_nameplate_type.set_version(version);
+        software_nameplate_type
+    }
+
+    ///Wraps a submodel element collection after checking the elements of the type part.
+    ///
+    /// [collection]: collection containing the type part
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<SoftwareNameplateType, ConversionError> {
+        SoftwareNameplateType::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(SoftwareNameplateType {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the type part and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the unique global identification of the software product.
+    pub fn get_uri_of_the_product(&self) -> Option<String> {
+        self.collection.get_property_value(URI_OF_THE_PRODUCT.id_short, URI_OF_THE_PRODUCT.semantic_id)
+    }
+
+    ///Sets the unique global identification of the software product.
+    ///
+    /// [uri_of_the_product]: unique global identification of the software product
+    pub fn set_uri_of_the_product(&mut self, uri_of_the_product: String) {
+        URI_OF_THE_PRODUCT.set_property_value(&mut self.collection, Some(&uri_of_the_product));
+    }
+
+    ///Returns the legally valid designation of the manufacturer.
+    pub fn get_manufacturer_name(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(MANUFACTURER_NAME.id_short, MANUFACTURER_NAME.semantic_id)
+    }
+
+    ///Sets the legally valid designation of the manufacturer.
+    ///
+    /// [manufacturer_name]: legally valid designation of the manufacturer
+    pub fn set_manufacturer_name(&mut self, manufacturer_name: Vec<MultiLanguageTextType>) {
+        self.collection.set_multi_language_value(MANUFACTURER_NAME.id_short, MANUFACTURER_NAME.semantic_id, Some(manufacturer_name));
+    }
+
+    ///Returns the short description of the software product.
+    pub fn get_manufacturer_product_designation(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(MANUFACTURER_PRODUCT_DESIGNATION.id_short, MANUFACTURER_PRODUCT_DESIGNATION.semantic_id)
+    }
+
+    ///Sets the short description of the software product.
+    ///
+    /// [manufacturer_product_designation]: short description of the software product
+    pub fn set_manufacturer_product_designation(&mut self, manufacturer_product_designation: Vec<MultiLanguageTextType>) {
+        self.collection.set_multi_language_value(MANUFACTURER_PRODUCT_DESIGNATION.id_short, MANUFACTURER_PRODUCT_DESIGNATION.semantic_id, Some(manufacturer_product_designation));
+    }
+
+    ///Returns the description of the software product and its functions.
+    pub fn get_manufacturer_product_description(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(MANUFACTURER_PRODUCT_DESCRIPTION.id_short, MANUFACTURER_PRODUCT_DESCRIPTION.semantic_id)
+    }
+
+    ///Sets the description of the software product and its functions. Passing None removes it.
+    ///
+    /// [manufacturer_product_description]: description of the software product and its functions
+    pub fn set_manufacturer_product_description(&mut self, manufacturer_product_description: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(MANUFACTURER_PRODUCT_DESCRIPTION.id_short, MANUFACTURER_PRODUCT_DESCRIPTION.semantic_id, manufacturer_product_description);
+    }
+
+    ///Returns the second level of the product hierarchy of the manufacturer.
+    pub fn get_manufacturer_product_family(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(MANUFACTURER_PRODUCT_FAMILY.id_short, MANUFACTURER_PRODUCT_FAMILY.semantic_id)
+    }
+
+    ///Sets the second level of the product hierarchy of the manufacturer. Passing None removes it.
+    ///
+    /// [manufacturer_product_family]: second level of the product hierarchy of the manufacturer
+    pub fn set_manufacturer_product_family(&mut self, manufacturer_product_family: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(MANUFACTURER_PRODUCT_FAMILY.id_short, MANUFACTURER_PRODUCT_FAMILY.semantic_id, manufacturer_product_family);
+    }
+
+    ///Returns the characteristic designation of the product type.
+    pub fn get_manufacturer_product_type(&self) -> Option<String> {
+        self.collection.get_property_value(MANUFACTURER_PRODUCT_TYPE.id_short, MANUFACTURER_PRODUCT_TYPE.semantic_id)
+    }
+
+    ///Sets the characteristic designation of the product type. Passing None removes it.
+    ///
+    /// [manufacturer_product_type]: characteristic designation of the product type
+    pub fn set_manufacturer_product_type(&mut self, manufacturer_product_type: Option<String>) {
+        MANUFACTURER_PRODUCT_TYPE.set_property_value(&mut self.collection, manufacturer_product_type.as_ref());
+    }
+
+    ///Returns the type of the software, e.g. firmware or PLC runtime.
+    pub fn get_software_type(&self) -> Option<String> {
+        self.collection.get_property_value(SOFTWARE_TYPE.id_short, SOFTWARE_TYPE.semantic_id)
+    }
+
+    ///Sets the type of the software, e.g. firmware or PLC runtime. Passing None removes it.
+    ///
+    /// [software_type]: type of the software, e.g. firmware or PLC runtime
+    pub fn set_software_type(&mut self, software_type: Option<String>) {
+        SOFTWARE_TYPE.set_property_value(&mut self.collection, software_type.as_ref());
+    }
+
+    ///Returns the version of the software.
+    pub fn get_version(&self) -> Option<String> {
+        self.collection.get_property_value(VERSION.id_short, VERSION.semantic_id)
+    }
+
+    ///Sets the version of the software.
+    ///
+    /// [version]: version of the software
+    pub fn set_version(&mut self, version: String) {
+        VERSION.set_property_value(&mut self.collection, Some(&version));
+    }
+
+    ///Returns the name of the version, e.g. a code name.
+    pub fn get_version_name(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(VERSION_NAME.id_short, VERSION_NAME.semantic_id)
+    }
+
+    ///Sets the name of the version, e.g. a code name. Passing None removes it.
+    ///
+    /// [version_name]: name of the version, e.g. a code name
+    pub fn set_version_name(&mut self, version_name: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(VERSION_NAME.id_short, VERSION_NAME.semantic_id, version_name);
+    }
+
+    ///Returns the information about the version, e.g. its scope.
+    pub fn get_version_info(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(VERSION_INFO.id_short, VERSION_INFO.semantic_id)
+    }
+
+    ///Sets the information about the version, e.g. its scope. Passing None removes it.
+    ///
+    /// [version_info]: information about the version, e.g. its scope
+    pub fn set_version_info(&mut self, version_info: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(VERSION_INFO.id_short, VERSION_INFO.semantic_id, version_info);
+    }
+
+    ///Returns the date the version was released, as xs:date.
+    pub fn get_release_date(&self) -> Option<String> {
+        self.collection.get_property_value(RELEASE_DATE.id_short, RELEASE_DATE.semantic_id)
+    }
+
+    ///Sets the date the version was released, as xs:date. Passing None removes it.
+    ///
+    /// [release_date]: date the version was released, as xs:date
+    pub fn set_release_date(&mut self, release_date: Option<String>) {
+        RELEASE_DATE.set_property_value(&mut self.collection, release_date.as_ref());
+    }
+
+    ///Returns the notes about the changes of the version.
+    pub fn get_release_notes(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(RELEASE_NOTES.id_short, RELEASE_NOTES.semantic_id)
+    }
+
+    ///Sets the notes about the changes of the version. Passing None removes it.
+    ///
+    /// [release_notes]: notes about the changes of the version
+    pub fn set_release_notes(&mut self, release_notes: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(RELEASE_NOTES.id_short, RELEASE_NOTES.semantic_id, release_notes);
+    }
+
+    ///Returns the date the version was built, as xs:date.
+    pub fn get_build_date(&self) -> Option<String> {
+        self.collection.get_property_value(BUILD_DATE.id_short, BUILD_DATE.semantic_id)
+    }
+
+    ///Sets the date the version was built, as xs:date. Passing None removes it.
+    ///
+    /// [build_date]: date the version was built, as xs:date
+    pub fn set_build_date(&mut self, build_date: Option<String>) {
+        BUILD_DATE.set_property_value(&mut self.collection, build_date.as_ref());
+    }
+
+    ///Returns the URI the installation file can be downloaded from.
+    pub fn get_installation_uri(&self) -> Option<String> {
+        self.collection.get_property_value(INSTALLATION_URI.id_short, INSTALLATION_URI.semantic_id)
+    }
+
+    ///Sets the URI the installation file can be downloaded from. Passing None removes it.
+    ///
+    /// [installation_uri]: URI the installation file can be downloaded from
+    pub fn set_installation_uri(&mut self, installation_uri: Option<String>) {
+        INSTALLATION_URI.set_property_value(&mut self.collection, installation_uri.as_ref());
+    }
+
+    ///Returns the installation file of the software.
+    pub fn get_installation_file(&self) -> Option<&File> {
+        self.collection.get_file(INSTALLATION_FILE.id_short, INSTALLATION_FILE.semantic_id)
+    }
+
+    ///Sets the installation file of the software. Passing None removes it.
+    ///
+    /// [installation_file]: installation file of the software
+    pub fn set_installation_file(&mut self, installation_file: Option<File>) {
+        self.collection.set_element(INSTALLATION_FILE.id_short, INSTALLATION_FILE.semantic_id, installation_file.map(SubmodelElement::File));
+    }
+
+    ///Returns the type of the installer, e.g. MSI or Debian package.
+    pub fn get_installer_type(&self) -> Option<String> {
+        self.collection.get_property_value(INSTALLER_TYPE.id_short, INSTALLER_TYPE.semantic_id)
+    }
+
+    ///Sets the type of the installer, e.g. MSI or Debian package. Passing None removes it.
+    ///
+    /// [installer_type]: type of the installer, e.g. MSI or Debian package
+    pub fn set_installer_type(&mut self, installer_type: Option<String>) {
+        INSTALLER_TYPE.set_property_value(&mut self.collection, installer_type.as_ref());
+    }
+
+    ///Returns the checksum of the installation file.
+    pub fn get_installation_checksum(&self) -> Option<String> {
+        self.collection.get_property_value(INSTALLATION_CHECKSUM.id_short, INSTALLATION_CHECKSUM.semantic_id)
+    }
+
+    ///Sets the checksum of the installation file. Passing None removes it.
+    ///
+    /// [installation_checksum]: checksum of the installation file
+    pub fn set_installation_checksum(&mut self, installation_checksum: Option<String>) {
+        INSTALLATION_CHECKSUM.set_property_value(&mut self.collection, installation_checksum.as_ref());
+    }
+
+    ///Returns the elements of the type part in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        ELEMENTS.iter().map(|element| element.to_element()).collect()
+    }
+
+    ///Checks the elements of the type part.
+    ///
+    /// [collection]: collection containing the type part
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        Ok(())
+    }
+}
+
+const URI_OF_THE_PRODUCT: TemplateElement =
+    TemplateElement::property("URIOfTheProduct", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateType/URIOfTheProduct", DataTypeDefXsd::String, Cardinality::One);
+const MANUFACTURER_NAME: TemplateElement =
+    TemplateElement::new("ManufacturerName", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateType/ManufacturerName", AasSubmodelElements::MultiLanguageProperty, Cardinality::One);
+const MANUFACTURER_PRODUCT_DESIGNATION: TemplateElement =
+    TemplateElement::new("ManufacturerProductDesignation", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateType/ManufacturerProductDesignation", AasSubmodelElements::MultiLanguageProperty, Cardinality::One);
+const MANUFACTURER_PRODUCT_DESCRIPTION: TemplateElement =
+    TemplateElement::new("ManufacturerProductDescription", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateType/ManufacturerProductDescription", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const MANUFACTURER_PRODUCT_FAMILY: TemplateElement =
+    TemplateElement::new("ManufacturerProductFamily", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateType/ManufacturerProductFamily", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const MANUFACTURER_PRODUCT_TYPE: TemplateElement =
+    TemplateElement::property("ManufacturerProductType", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateType/ManufacturerProductType", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const SOFTWARE_TYPE: TemplateElement =
+    TemplateElement::property("SoftwareType", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateType/SoftwareType", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const VERSION: TemplateElement =
+    TemplateElement::property("Version", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateType/Version", DataTypeDefXsd::String, Cardinality::One);
+const VERSION_NAME: TemplateElement =
+    TemplateElement::new("VersionName", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateType/VersionName", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const VERSION_INFO: TemplateElement =
+    TemplateElement::new("VersionInfo", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateType/VersionInfo", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const RELEASE_DATE: TemplateElement =
+    TemplateElement::property("ReleaseDate", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateType/ReleaseDate", DataTypeDefXsd::Date, Cardinality::ZeroToOne);
+const RELEASE_NOTES: TemplateElement =
+    TemplateElement::new("ReleaseNotes", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateType/ReleaseNotes", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const BUILD_DATE: TemplateElement =
+    TemplateElement::property("BuildDate", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateType/BuildDate", DataTypeDefXsd::Date, Cardinality::ZeroToOne);
+const INSTALLATION_URI: TemplateElement =
+    TemplateElement::property("InstallationURI", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateType/InstallationURI", DataTypeDefXsd::AnyUri, Cardinality::ZeroToOne);
+const INSTALLATION_FILE: TemplateElement =
+    TemplateElement::new("InstallationFile", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateType/InstallationFile", AasSubmodelElements::File, Cardinality::ZeroToOne);
+const INSTALLER_TYPE: TemplateElement =
+    TemplateElement::property("InstallerType", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateType/InstallerType", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const INSTALLATION_CHECKSUM: TemplateElement =
+    TemplateElement::property("InstallationChecksum", "https://admin-shell.io/idta/SoftwareNameplate/1/0/SoftwareNameplateType/InstallationChecksum", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+
+const ELEMENTS: [&TemplateElement; 17] = [&URI_OF_THE_PRODUCT, &MANUFACTURER_NAME,
+    &MANUFACTURER_PRODUCT_DESIGNATION, &MANUFACTURER_PRODUCT_DESCRIPTION, &MANUFACTURER_PRODUCT_FAMILY,
+    &MANUFACTURER_PRODUCT_TYPE, &SOFTWARE_TYPE, &VERSION, &VERSION_NAME, &VERSION_INFO, &RELEASE_DATE,
+    &RELEASE_NOTES, &BUILD_DATE, &INSTALLATION_URI, &INSTALLATION_FILE, &INSTALLER_TYPE, &INSTALLATION_CHECKSUM];
@@ -13,11 +13,11 @@ pub struct SpecificAssetId {
     value: String,
     ///The unique ID of the (external) subject the specific asset ID value belongs to or has meaning
     /// to.
-    #[serde(rename = "externalSubjectId")]
+    #[serde(rename = "externalSubjectId", default, skip_serializing_if = "Option::is_none")]
     external_subject_id: Option<Reference>,
-    #[serde(rename = "semanticId")]
+    #[serde(rename = "semanticId", default, skip_serializing_if = "Option::is_none")]
     semantic_id: Option<Reference>,
-    #[serde(rename = "supplementalSemanticIds")]
+    #[serde(rename = "supplementalSemanticIds", default, skip_serializing_if = "Vec::is_empty")]
     supplemental_semantic_ids: Vec<Reference>
 }
 
@@ -87,6 +87,25 @@ impl SpecificAssetId {
     pub fn get_mut_external_subject_id(&mut self) -> Option<&mut Reference> {
         self.external_subject_id.as_mut()
     }
+
+    ///Returns all references contained in the specific asset ID.
+    pub fn get_references(&self) -> Vec<&Reference> {
+        self.external_subject_id.iter()
+            .chain(self.semantic_id.iter())
+            .chain(self.supplemental_semantic_ids.iter())
+            .flat_map(|reference| reference.get_references())
+            .collect()
+    }
+
+    ///Calls the visitor for every reference contained in the specific asset ID.
+    ///
+    /// [visitor]: function called for every reference
+    pub fn visit_references_mut(&mut self, visitor: &mut dyn FnMut(&mut Reference)) {
+        self.external_subject_id.iter_mut()
+            .chain(self.semantic_id.iter_mut())
+            .chain(self.supplemental_semantic_ids.iter_mut())
+            .for_each(|reference| reference.visit_references_mut(visitor));
+    }
 }
 
 impl THasSemantics for SpecificAssetId {
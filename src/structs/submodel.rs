@@ -1,9 +1,20 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::id_short_path_segment::IdShortPathSegment;
+use crate::enumerations::instantiation_error::InstantiationError;
+use crate::enumerations::interface_enumerations::submodel_element::{elements_value_only, set_elements_value_only, SubmodelElement};
+use crate::enumerations::key_type::KeyType;
 use crate::enumerations::modelling_kind::ModellingKind;
+use crate::enumerations::qualifier_kind::QualifierKind;
+use crate::enumerations::reference_type::ReferenceType;
 use crate::structs::administrative_information::AdministrativeInformation;
+use crate::structs::conformance_report::ConformanceReport;
+use crate::structs::embedded_data_specification::EmbeddedDataSpecification;
 use crate::structs::extension::Extension;
+use crate::structs::instantiation_options::InstantiationOptions;
+use crate::structs::key::Key;
 use crate::structs::multi_language_name_type::MultiLanguageNameType;
 use crate::structs::multi_language_text_type::MultiLanguageTextType;
 use crate::structs::qualifier::Qualifier;
@@ -18,27 +29,33 @@ use crate::traits::referable::TReferable;
 
 ///A submodel defines a specific aspect of the asset represented by the Asset Administration Shell.
 #[derive(PartialEq, Clone, Serialize, Deserialize)]
+#[serde(tag = "modelType", rename = "Submodel")]
 pub struct Submodel {
     ///List of submodel elements a submodel consists of.
-    #[serde(rename = "submodelElements")]
+    #[serde(rename = "submodelElements", default, skip_serializing_if = "Vec::is_empty")]
     submodel_elements: Vec<SubmodelElement>,
-    #[serde(rename = "administration")]
+    #[serde(rename = "administration", default, skip_serializing_if = "Option::is_none")]
     administrative_information: Option<AdministrativeInformation>,
     id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     kind: Option<ModellingKind>,
-    #[serde(rename = "semanticId")]
+    #[serde(rename = "semanticId", default, skip_serializing_if = "Option::is_none")]
     semantic_id: Option<Reference>,
-    #[serde(rename = "supplementalSemanticIds")]
+    #[serde(rename = "supplementalSemanticIds", default, skip_serializing_if = "Vec::is_empty")]
     supplemental_semantic_ids: Vec<Reference>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     qualifiers: Vec<Qualifier>,
-    #[serde(rename = "embeddedDataSpecifications")]
-    data_specifications: Vec<Reference>,
+    #[serde(rename = "embeddedDataSpecifications", default, skip_serializing_if = "Vec::is_empty")]
+    data_specifications: Vec<EmbeddedDataSpecification>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     category: Option<String>,
-    #[serde(rename = "idShort")]
+    #[serde(rename = "idShort", default, skip_serializing_if = "Option::is_none")]
     id_short: Option<String>,
-    #[serde(rename = "displayName")]
+    #[serde(rename = "displayName", default, skip_serializing_if = "Vec::is_empty")]
     display_name: Vec<MultiLanguageNameType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     description: Vec<MultiLanguageTextType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     extensions: Vec<Extension>
 }
 
@@ -91,6 +108,147 @@ impl Submodel {
     pub fn remove_submodel_element(&mut self, index: usize) -> SubmodelElement {
         self.submodel_elements.remove(index)
     }
+
+    ///Returns all references contained in the submodel and in its submodel elements.
+    pub fn get_references(&self) -> Vec<&Reference> {
+        self.semantic_id.iter()
+            .chain(self.supplemental_semantic_ids.iter())
+            .flat_map(|reference| reference.get_references())
+            .chain(self.data_specifications.iter().flat_map(|data_specification| data_specification.get_references()))
+            .chain(self.qualifiers.iter().flat_map(|qualifier| qualifier.get_references()))
+            .chain(self.extensions.iter().flat_map(|extension| extension.get_references()))
+            .chain(self.administrative_information.iter().flat_map(|administration| administration.get_references()))
+            .chain(self.submodel_elements.iter().flat_map(|submodel_element| submodel_element.get_references()))
+            .collect()
+    }
+
+    ///Calls the visitor for every reference contained in the submodel and in its submodel
+    /// elements.
+    ///
+    /// [visitor]: function called for every reference
+    pub fn visit_references_mut(&mut self, visitor: &mut dyn FnMut(&mut Reference)) {
+        self.semantic_id.iter_mut()
+            .chain(self.supplemental_semantic_ids.iter_mut())
+            .for_each(|reference| reference.visit_references_mut(visitor));
+        self.data_specifications.iter_mut()
+            .for_each(|data_specification| data_specification.visit_references_mut(visitor));
+        self.qualifiers.iter_mut().for_each(|qualifier| qualifier.visit_references_mut(visitor));
+        self.extensions.iter_mut().for_each(|extension| extension.visit_references_mut(visitor));
+        self.administrative_information.iter_mut()
+            .for_each(|administration| administration.visit_references_mut(visitor));
+        self.submodel_elements.iter_mut()
+            .for_each(|submodel_element| submodel_element.visit_references_mut(visitor));
+    }
+
+    ///Returns the submodel element addressed by the idShortPath, e.g. `Collection.List[2].Property`.
+    ///
+    /// [id_short_path]: idShortPath of the submodel element
+    pub fn get_submodel_element(&self, id_short_path: &str) -> Option<&SubmodelElement> {
+        let segments = IdShortPathSegment::parse_path(id_short_path)?;
+        let (first, rest) = segments.split_first()?;
+        let mut element = find_child(&self.submodel_elements, first)?;
+        for segment in rest {
+            element = find_child(element.get_children()?, segment)?;
+        }
+        Some(element)
+    }
+
+    ///Returns the mutable submodel element addressed by the idShortPath, e.g.
+    /// `Collection.List[2].Property`.
+    ///
+    /// [id_short_path]: idShortPath of the submodel element
+    pub fn get_mut_submodel_element(&mut self, id_short_path: &str) -> Option<&mut SubmodelElement> {
+        let segments = IdShortPathSegment::parse_path(id_short_path)?;
+        let (first, rest) = segments.split_first()?;
+        let mut element = find_child_mut(&mut self.submodel_elements, first)?;
+        for segment in rest {
+            element = find_child_mut(element.get_mut_children()?, segment)?;
+        }
+        Some(element)
+    }
+
+    ///Returns a model reference to the submodel element addressed by the idShortPath with one key
+    /// per element on the path, e.g. `Collection.List[2]`. An empty path results in a reference to
+    /// the submodel. Returns None if no element exists at the path.
+    ///
+    /// [id_short_path]: idShortPath of the submodel element
+    pub fn get_submodel_element_reference(&self, id_short_path: &str) -> Option<Reference> {
+        let segments = IdShortPathSegment::parse_path(id_short_path)?;
+        let mut keys = vec![Key::new(KeyType::Submodel, self.id.clone())];
+        for (index, segment) in segments.iter().enumerate() {
+            let element = self.get_submodel_element(&IdShortPathSegment::format_path(&segments[..=index]))?;
+            keys.push(Key::new(element.get_key_type(), segment.get_key_value()));
+        }
+        Some(Reference::new(ReferenceType::ModelReference, keys))
+    }
+
+    ///Returns the values of the submodel elements in the value-only serialization of the HTTP API
+    /// as object of the values by idShort.
+    ///
+    /// [with_blob_value]: whether the values of blobs are included as base64 text
+    pub fn get_value_only(&self, with_blob_value: bool) -> Value {
+        elements_value_only(&self.submodel_elements, with_blob_value)
+    }
+
+    ///Sets the values of the submodel elements from their value-only serialization of the HTTP API.
+    /// Elements not contained in the value keep their values.
+    ///
+    /// [value]: object of the values by idShort
+    pub fn set_value_only(&mut self, value: &Value) -> Result<(), ConversionError> {
+        set_elements_value_only(&mut self.submodel_elements, value, "")
+    }
+
+    ///Creates an instance of the submodel template. The instance gets the given ID, the modelling
+    /// kind instance and the ID of the template as template ID of its administrative information.
+    /// Template qualifiers are removed from the instance. Elements whose SMT cardinality is
+    /// `ZeroToOne` or `ZeroToMany` are optional and only instantiated if requested by the options.
+    /// All other elements are mandatory and always instantiated. An element whose idShort contains
+    /// the placeholder `{00}` is instantiated once for every number a supplied value addresses, e.g.
+    /// `Document02` for `Document{00}`, or as `Document01` if none does and the element is mandatory
+    /// or optional elements are requested. Elements of submodel element lists are taken over as they
+    /// are. Properties and files without value get the default value of
+    /// their template, supplied values replace both.
+    ///
+    /// [id]: global identifier of the instance
+    /// [options]: supplied values and handling of optional elements
+    pub fn instantiate(&self, id: String, options: &InstantiationOptions) -> Result<Submodel, InstantiationError> {
+        let mut values = Vec::new();
+        for (path, value) in options.get_values() {
+            let segments = IdShortPathSegment::parse_path(path)
+                .filter(|segments| !segments.is_empty())
+                .ok_or_else(|| InstantiationError::InvalidPath(path.clone()))?;
+            values.push((segments, path, value));
+        }
+
+        let mut instance = self.clone();
+        instance.id = id;
+        instance.kind = Some(ModellingKind::Instance);
+        let mut administration = instance.administrative_information.take().unwrap_or_else(AdministrativeInformation::new);
+        administration.set_template_id(self.id.clone());
+        instance.administrative_information = Some(administration);
+        remove_template_qualifiers(&mut instance.qualifiers);
+        let supplied: Vec<&[IdShortPathSegment]> = values.iter().map(|(segments, _, _)| segments.as_slice()).collect();
+        instantiate_elements(&mut instance.submodel_elements, &[], false, &supplied,
+                             options.get_include_optional_elements());
+
+        for (_, path, value) in values {
+            match instance.get_mut_submodel_element(path) {
+                Some(SubmodelElement::Property(property)) => property.set_value(value.clone()),
+                Some(SubmodelElement::File(file)) => file.set_value(value.clone()),
+                Some(_) => return Err(InstantiationError::UnsupportedValue(path.clone())),
+                None => return Err(InstantiationError::ElementNotFound(path.clone()))
+            }
+        }
+        Ok(instance)
+    }
+
+    ///Checks the submodel instance against the submodel template it claims to conform to and
+    /// reports every deviation.
+    ///
+    /// [template]: submodel template
+    pub fn check_conformance(&self, template: &Submodel) -> ConformanceReport {
+        ConformanceReport::check(self, template)
+    }
 }
 
 impl TReferable for Submodel {
@@ -278,23 +436,246 @@ impl TQualifiable for Submodel {
 }
 
 impl THasDataSpecification for Submodel {
-    fn get_data_specifications(&self) -> &Vec<Reference> {
+    fn get_data_specifications(&self) -> &Vec<EmbeddedDataSpecification> {
         &self.data_specifications
     }
 
-    fn get_mut_data_specifications(&mut self) -> &mut Vec<Reference> {
+    fn get_mut_data_specifications(&mut self) -> &mut Vec<EmbeddedDataSpecification> {
         &mut self.data_specifications
     }
 
-    fn set_data_specifications(&mut self, data_specifications: Vec<Reference>) {
+    fn set_data_specifications(&mut self, data_specifications: Vec<EmbeddedDataSpecification>) {
         self.data_specifications = data_specifications;
     }
 
-    fn add_data_specification(&mut self, data_specification: Reference) {
+    fn add_data_specification(&mut self, data_specification: EmbeddedDataSpecification) {
         self.data_specifications.push(data_specification);
     }
 
-    fn remove_data_specification(&mut self, index: usize) -> Reference {
+    fn remove_data_specification(&mut self, index: usize) -> EmbeddedDataSpecification {
         self.data_specifications.remove(index)
     }
+}
+
+fn find_child<'a>(children: &'a [SubmodelElement], segment: &IdShortPathSegment) -> Option<&'a SubmodelElement> {
+    match segment {
+        IdShortPathSegment::IdShort(id_short) => children.iter().find(|child| child.get_id_short() == Some(id_short)),
+        IdShortPathSegment::Index(index) => children.get(*index)
+    }
+}
+
+fn find_child_mut<'a>(children: &'a mut [SubmodelElement], segment: &IdShortPathSegment) -> Option<&'a mut SubmodelElement> {
+    match segment {
+        IdShortPathSegment::IdShort(id_short) => children.iter_mut().find(|child| child.get_id_short() == Some(id_short)),
+        IdShortPathSegment::Index(index) => children.get_mut(*index)
+    }
+}
+
+///Returns the number an idShort gives for the `{00}` placeholder of a template idShort, e.g. 2 for
+/// `Document02` and `Document{00}`, or None if the idShort does not match the template idShort.
+fn placeholder_number(template_id_short: &str, id_short: &str) -> Option<usize> {
+    let (prefix, suffix) = template_id_short.split_once("{00}")?;
+    let digits = id_short.strip_prefix(prefix)?.strip_suffix(suffix)?;
+    if digits.is_empty() || !digits.chars().all(|character| character.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+fn remove_template_qualifiers(qualifiers: &mut Vec<Qualifier>) {
+    qualifiers.retain(|qualifier| qualifier.get_kind() != Some(&QualifierKind::TemplateQualifier));
+}
+
+fn instantiate_elements(elements: &mut Vec<SubmodelElement>, prefix: &[IdShortPathSegment], in_list: bool,
+                        supplied: &[&[IdShortPathSegment]], include_optional_elements: bool) {
+    let path = |segment: IdShortPathSegment| {
+        let mut path = prefix.to_vec();
+        path.push(segment);
+        path
+    };
+    if !in_list {
+        let mut instantiated = Vec::new();
+        for element in elements.drain(..) {
+            let Some(id_short) = element.get_id_short().cloned() else {
+                instantiated.push(element);
+                continue;
+            };
+            let optional = element.get_cardinality().is_some_and(|cardinality| cardinality.is_optional());
+            if id_short.contains("{00}") {
+                let mut id_shorts: Vec<(usize, String)> = supplied.iter()
+                    .filter(|segments| segments.len() > prefix.len() && segments.starts_with(prefix))
+                    .filter_map(|segments| match &segments[prefix.len()] {
+                        IdShortPathSegment::IdShort(supplied_id_short) => placeholder_number(&id_short, supplied_id_short)
+                            .map(|number| (number, supplied_id_short.clone())),
+                        IdShortPathSegment::Index(_) => None
+                    })
+                    .collect();
+                id_shorts.sort();
+                id_shorts.dedup();
+                if id_shorts.is_empty() && (!optional || include_optional_elements) {
+                    id_shorts.push((1, id_short.replace("{00}", "01")));
+                }
+                for (_, numbered_id_short) in id_shorts {
+                    let mut copy = element.clone();
+                    copy.set_id_short(numbered_id_short);
+                    instantiated.push(copy);
+                }
+            } else if !optional || include_optional_elements
+                || supplied.iter().any(|segments| segments.starts_with(&path(IdShortPathSegment::IdShort(id_short.clone())))) {
+                instantiated.push(element);
+            }
+        }
+        *elements = instantiated;
+    }
+    for (index, element) in elements.iter_mut().enumerate() {
+        let segment = match element.get_id_short() {
+            Some(id_short) if !in_list => IdShortPathSegment::IdShort(id_short.clone()),
+            _ => IdShortPathSegment::Index(index)
+        };
+        instantiate_element(element, &path(segment), supplied, include_optional_elements);
+    }
+}
+
+fn instantiate_element(element: &mut SubmodelElement, path: &[IdShortPathSegment], supplied: &[&[IdShortPathSegment]],
+                       include_optional_elements: bool) {
+    if let Some(default_value) = element.get_default_value() {
+        match element {
+            SubmodelElement::Property(property) if property.get_value().is_none_or(|value| value.is_empty()) => {
+                property.set_value(default_value);
+            },
+            SubmodelElement::File(file) if file.get_value().is_none_or(|value| value.is_empty()) => {
+                file.set_value(default_value);
+            },
+            _ => {}
+        }
+    }
+    remove_template_qualifiers(element.get_mut_qualifiers());
+    let in_list = matches!(element, SubmodelElement::SubmodelElementList(_));
+    if let Some(children) = element.get_mut_children() {
+        instantiate_elements(children, path, in_list, supplied, include_optional_elements);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::enumerations::cardinality::Cardinality;
+    use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+    use crate::enumerations::instantiation_error::InstantiationError;
+    use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+    use crate::enumerations::modelling_kind::ModellingKind;
+    use crate::enumerations::qualifier_kind::QualifierKind;
+    use crate::enumerations::smt_qualifier::SmtQualifier;
+    use crate::structs::instantiation_options::InstantiationOptions;
+    use crate::structs::property::Property;
+    use crate::structs::qualifier::Qualifier;
+    use crate::structs::submodel::Submodel;
+    use crate::structs::submodel_element_collection::SubmodelElementCollection;
+    use crate::traits::has_kind::THasKind;
+    use crate::traits::identifiable::TIdentifiable;
+    use crate::traits::qualifiable::TQualifiable;
+    use crate::traits::referable::TReferable;
+
+    fn property(id_short: &str, cardinality: Cardinality) -> Property {
+        let mut property = Property::new(DataTypeDefXsd::String);
+        property.set_id_short(id_short.to_string());
+        property.add_smt_qualifier(SmtQualifier::Cardinality(cardinality));
+        property
+    }
+
+    ///Creates a template with a mandatory property with default value, an optional property and a
+    /// mandatory collection `Marking{00}` with a property.
+    fn template() -> Submodel {
+        let mut manufacturer = property("Manufacturer", Cardinality::One);
+        manufacturer.add_smt_qualifier(SmtQualifier::DefaultValue("ACME".to_string()));
+        let mut concept_qualifier = Qualifier::new("Unit".to_string(), DataTypeDefXsd::String);
+        concept_qualifier.set_kind(QualifierKind::ConceptQualifier);
+        manufacturer.add_qualifier(concept_qualifier);
+        let mut marking = SubmodelElementCollection::new();
+        marking.set_id_short("Marking{00}".to_string());
+        marking.add_smt_qualifier(SmtQualifier::Cardinality(Cardinality::OneToMany));
+        marking.add_value(SubmodelElement::Property(property("Name", Cardinality::One)));
+
+        let mut template = Submodel::new("urn:example:template".to_string());
+        template.set_kind(ModellingKind::Template);
+        template.add_smt_qualifier(SmtQualifier::Cardinality(Cardinality::One));
+        template.set_submodel_elements(vec![SubmodelElement::Property(manufacturer),
+                                            SubmodelElement::Property(property("Comment", Cardinality::ZeroToOne)),
+                                            SubmodelElement::SubmodelElementCollection(marking)]);
+        template
+    }
+
+    fn id_shorts(submodel: &Submodel) -> Vec<String> {
+        submodel.get_submodel_elements().iter().filter_map(|element| element.get_id_short().cloned()).collect()
+    }
+
+    fn value(submodel: &Submodel, id_short_path: &str) -> Option<String> {
+        match submodel.get_submodel_element(id_short_path) {
+            Some(SubmodelElement::Property(property)) => property.get_value().cloned(),
+            _ => None
+        }
+    }
+
+    #[test]
+    fn instantiates_template_without_template_qualifiers() {
+        let instance = template().instantiate("urn:example:instance".to_string(), &InstantiationOptions::new()).unwrap();
+
+        assert!(instance.get_id() == "urn:example:instance");
+        assert!(instance.get_kind() == Some(&ModellingKind::Instance));
+        assert!(instance.get_administration().and_then(|administration| administration.get_template_id())
+            == Some(&"urn:example:template".to_string()));
+        assert!(instance.get_qualifiers().is_empty());
+        let qualifiers = instance.get_submodel_element("Manufacturer").unwrap().get_qualifiers();
+        assert!(qualifiers.len() == 1);
+        assert!(qualifiers[0].get_kind() == Some(&QualifierKind::ConceptQualifier));
+        assert!(instance.get_submodel_element("Marking01.Name").unwrap().get_qualifiers().is_empty());
+    }
+
+    #[test]
+    fn instantiates_mandatory_elements_with_default_values() {
+        let instance = template().instantiate("urn:example:instance".to_string(), &InstantiationOptions::new()).unwrap();
+
+        assert!(id_shorts(&instance) == vec!["Manufacturer", "Marking01"]);
+        assert!(value(&instance, "Manufacturer") == Some("ACME".to_string()));
+    }
+
+    #[test]
+    fn instantiates_optional_elements_if_requested() {
+        let mut options = InstantiationOptions::new();
+        options.set_include_optional_elements(true);
+        let instance = template().instantiate("urn:example:instance".to_string(), &options).unwrap();
+
+        assert!(id_shorts(&instance) == vec!["Manufacturer", "Comment", "Marking01"]);
+    }
+
+    #[test]
+    fn instantiates_elements_with_supplied_values() {
+        let mut options = InstantiationOptions::new();
+        options.add_value("Manufacturer".to_string(), "Example Corp".to_string());
+        options.add_value("Comment".to_string(), "Prototype".to_string());
+        options.add_value("Marking03.Name".to_string(), "CE".to_string());
+        options.add_value("Marking02.Name".to_string(), "UKCA".to_string());
+        let instance = template().instantiate("urn:example:instance".to_string(), &options).unwrap();
+
+        assert!(id_shorts(&instance) == vec!["Manufacturer", "Comment", "Marking02", "Marking03"]);
+        assert!(value(&instance, "Manufacturer") == Some("Example Corp".to_string()));
+        assert!(value(&instance, "Comment") == Some("Prototype".to_string()));
+        assert!(value(&instance, "Marking02.Name") == Some("UKCA".to_string()));
+        assert!(value(&instance, "Marking03.Name") == Some("CE".to_string()));
+    }
+
+    #[test]
+    fn rejects_invalid_supplied_values() {
+        let instantiate = |path: &str| {
+            let mut options = InstantiationOptions::new();
+            options.add_value(path.to_string(), "value".to_string());
+            template().instantiate("urn:example:instance".to_string(), &options).err()
+        };
+
+        assert!(instantiate("") == Some(InstantiationError::InvalidPath("".to_string())));
+        assert!(instantiate("Marking01[") == Some(InstantiationError::InvalidPath("Marking01[".to_string())));
+        assert!(instantiate("Serial") == Some(InstantiationError::ElementNotFound("Serial".to_string())));
+        assert!(instantiate("Marking01.Serial") == Some(InstantiationError::ElementNotFound("Marking01.Serial".to_string())));
+        assert!(instantiate("Marking01") == Some(InstantiationError::UnsupportedValue("Marking01".to_string())));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,227 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::administrative_information::AdministrativeInformation;
+use crate::structs::blob::encode_base64;
+use crate::structs::endpoint::Endpoint;
+use crate::structs::extension::Extension;
+use crate::structs::multi_language_name_type::MultiLanguageNameType;
+use crate::structs::multi_language_text_type::MultiLanguageTextType;
+use crate::structs::protocol_information::ProtocolInformation;
+use crate::structs::reference::Reference;
+use crate::structs::submodel::Submodel;
+use crate::traits::has_extensions::THasExtensions;
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::identifiable::TIdentifiable;
+use crate::traits::referable::TReferable;
+
+///Descriptor of a submodel in a registry, which describes the submodel and the endpoints it is
+/// reachable at.
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
+pub struct SubmodelDescriptor {
+    ///Description of the submodel in different languages.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    description: Vec<MultiLanguageTextType>,
+    ///Display name of the submodel in different languages.
+    #[serde(rename = "displayName", default, skip_serializing_if = "Vec::is_empty")]
+    display_name: Vec<MultiLanguageNameType>,
+    ///Extensions of the descriptor.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    extensions: Vec<Extension>,
+    ///Administrative information of the submodel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    administration: Option<AdministrativeInformation>,
+    ///Endpoints the submodel is reachable at.
+    #[serde(default)]
+    endpoints: Vec<Endpoint>,
+    ///Short name of the submodel.
+    #[serde(rename = "idShort", default, skip_serializing_if = "Option::is_none")]
+    id_short: Option<String>,
+    ///Global identifier of the submodel.
+    id: String,
+    ///Semantic ID of the submodel.
+    #[serde(rename = "semanticId", default, skip_serializing_if = "Option::is_none")]
+    semantic_id: Option<Reference>,
+    ///Supplemental semantic IDs of the submodel.
+    #[serde(rename = "supplementalSemanticId", default, skip_serializing_if = "Vec::is_empty")]
+    supplemental_semantic_ids: Vec<Reference>
+}
+
+impl SubmodelDescriptor {
+    ///Creates a new descriptor without endpoints.
+    ///
+    /// [id]: global identifier of the submodel
+    pub fn new(id: String) -> SubmodelDescriptor {
+        SubmodelDescriptor {
+            description: Vec::new(),
+            display_name: Vec::new(),
+            extensions: Vec::new(),
+            administration: None,
+            endpoints: Vec::new(),
+            id_short: None,
+            id,
+            semantic_id: None,
+            supplemental_semantic_ids: Vec::new()
+        }
+    }
+
+    ///Creates the descriptor of a submodel served by a submodel repository. The endpoint is the
+    /// URL of the submodel below the base URL, e.g. `http://localhost:8081/submodels/dXJuOnNtOjE`.
+    ///
+    /// [submodel]: described submodel
+    /// [base_url]: base URL of the repository serving the submodel, e.g. `http://localhost:8081`
+    pub fn from_submodel(submodel: &Submodel, base_url: &str) -> SubmodelDescriptor {
+        let href = format!("{}/submodels/{}", base_url.trim_end_matches('/'), encode_base64(submodel.get_id().as_bytes(), true));
+        SubmodelDescriptor {
+            description: submodel.get_description().clone(),
+            display_name: submodel.get_display_name().clone(),
+            extensions: submodel.get_extensions().clone(),
+            administration: submodel.get_administration().cloned(),
+            endpoints: vec![Endpoint::new(Endpoint::SUBMODEL_INTERFACE.to_string(), ProtocolInformation::from_href(href))],
+            id_short: submodel.get_id_short().cloned(),
+            id: submodel.get_id().clone(),
+            semantic_id: submodel.get_semantic_id().cloned(),
+            supplemental_semantic_ids: submodel.get_supplemental_semantic_ids().clone()
+        }
+    }
+
+    ///Returns the description of the submodel.
+    pub fn get_description(&self) -> &Vec<MultiLanguageTextType> {
+        &self.description
+    }
+
+    ///Sets the description of the submodel.
+    ///
+    /// [description]: description in different languages
+    pub fn set_description(&mut self, description: Vec<MultiLanguageTextType>) {
+        self.description = description;
+    }
+
+    ///Returns the display name of the submodel.
+    pub fn get_display_name(&self) -> &Vec<MultiLanguageNameType> {
+        &self.display_name
+    }
+
+    ///Sets the display name of the submodel.
+    ///
+    /// [display_name]: display name in different languages
+    pub fn set_display_name(&mut self, display_name: Vec<MultiLanguageNameType>) {
+        self.display_name = display_name;
+    }
+
+    ///Returns the extensions of the descriptor.
+    pub fn get_extensions(&self) -> &Vec<Extension> {
+        &self.extensions
+    }
+
+    ///Sets the extensions of the descriptor.
+    ///
+    /// [extensions]: extensions of the descriptor
+    pub fn set_extensions(&mut self, extensions: Vec<Extension>) {
+        self.extensions = extensions;
+    }
+
+    ///Returns the administrative information of the submodel.
+    pub fn get_administration(&self) -> Option<&AdministrativeInformation> {
+        self.administration.as_ref()
+    }
+
+    ///Sets the administrative information of the submodel. Passing None removes it.
+    ///
+    /// [administration]: administrative information of the submodel
+    pub fn set_administration(&mut self, administration: Option<AdministrativeInformation>) {
+        self.administration = administration;
+    }
+
+    ///Returns the endpoints the submodel is reachable at.
+    pub fn get_endpoints(&self) -> &Vec<Endpoint> {
+        &self.endpoints
+    }
+
+    ///Returns the mutable endpoints the submodel is reachable at.
+    pub fn get_mut_endpoints(&mut self) -> &mut Vec<Endpoint> {
+        &mut self.endpoints
+    }
+
+    ///Adds an endpoint the submodel is reachable at.
+    ///
+    /// [endpoint]: endpoint to add
+    pub fn add_endpoint(&mut self, endpoint: Endpoint) {
+        self.endpoints.push(endpoint);
+    }
+
+    ///Returns the short name of the submodel.
+    pub fn get_id_short(&self) -> Option<&String> {
+        self.id_short.as_ref()
+    }
+
+    ///Sets the short name of the submodel. Passing None removes it.
+    ///
+    /// [id_short]: short name of the submodel
+    pub fn set_id_short(&mut self, id_short: Option<String>) {
+        self.id_short = id_short;
+    }
+
+    ///Returns the global identifier of the submodel.
+    pub fn get_id(&self) -> &String {
+        &self.id
+    }
+
+    ///Sets the global identifier of the submodel.
+    ///
+    /// [id]: global identifier of the submodel
+    pub fn set_id(&mut self, id: String) {
+        self.id = id;
+    }
+
+    ///Returns the semantic ID of the submodel.
+    pub fn get_semantic_id(&self) -> Option<&Reference> {
+        self.semantic_id.as_ref()
+    }
+
+    ///Sets the semantic ID of the submodel. Passing None removes it.
+    ///
+    /// [semantic_id]: semantic ID of the submodel
+    pub fn set_semantic_id(&mut self, semantic_id: Option<Reference>) {
+        self.semantic_id = semantic_id;
+    }
+
+    ///Returns the supplemental semantic IDs of the submodel.
+    pub fn get_supplemental_semantic_ids(&self) -> &Vec<Reference> {
+        &self.supplemental_semantic_ids
+    }
+
+    ///Sets the supplemental semantic IDs of the submodel.
+    ///
+    /// [supplemental_semantic_ids]: supplemental semantic IDs of the submodel
+    pub fn set_supplemental_semantic_ids(&mut self, supplemental_semantic_ids: Vec<Reference>) {
+        self.supplemental_semantic_ids = supplemental_semantic_ids;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::structs::endpoint::Endpoint;
+    use crate::structs::submodel::Submodel;
+    use crate::structs::submodel_descriptor::SubmodelDescriptor;
+    use crate::structs::template_element::global_reference;
+    use crate::traits::has_semantics::THasSemantics;
+    use crate::traits::referable::TReferable;
+
+    #[test]
+    fn creates_descriptor_of_submodel_below_base_url() {
+        let mut submodel = Submodel::new("urn:sm:1".to_string());
+        submodel.set_id_short("Nameplate".to_string());
+        submodel.set_semantic_id(global_reference("https://admin-shell.io/idta/nameplate/3/0/Nameplate"));
+
+        let descriptor = SubmodelDescriptor::from_submodel(&submodel, "https://example.com/api/");
+
+        assert_eq!(descriptor.get_id(), "urn:sm:1");
+        assert_eq!(descriptor.get_id_short(), Some(&"Nameplate".to_string()));
+        assert!(descriptor.get_semantic_id() == submodel.get_semantic_id());
+        assert_eq!(descriptor.get_endpoints().len(), 1);
+        let endpoint = &descriptor.get_endpoints()[0];
+        assert_eq!(endpoint.get_interface(), Endpoint::SUBMODEL_INTERFACE);
+        assert_eq!(endpoint.get_protocol_information().get_href(), "https://example.com/api/submodels/dXJuOnNtOjE");
+        assert_eq!(endpoint.get_protocol_information().get_endpoint_protocol(), Some(&"HTTPS".to_string()));
+    }
+}
\ No newline at end of file
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
 use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
 use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::embedded_data_specification::EmbeddedDataSpecification;
 use crate::structs::extension::Extension;
 use crate::structs::multi_language_name_type::MultiLanguageNameType;
 use crate::structs::multi_language_text_type::MultiLanguageTextType;
@@ -19,32 +20,38 @@ use crate::traits::submodel_element::TSubmodelElement;
 #[derive(PartialEq, Clone, Serialize, Deserialize)]
 pub struct SubmodelElementList {
     ///Defines whether order in list is relevant.
-    #[serde(rename = "orderRelevant")]
+    #[serde(rename = "orderRelevant", default, skip_serializing_if = "Option::is_none")]
     order_relevant: Option<bool>,
     ///Submodel elements contained in the list.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     value: Vec<SubmodelElement>,
     ///Optional semantic ID which the submodel elements contained in the list match.
-    #[serde(rename = "semanticIdListElement")]
+    #[serde(rename = "semanticIdListElement", default, skip_serializing_if = "Option::is_none")]
     semantic_id_list_element: Option<Reference>,
     ///The submodel element type of the submodel elements contained in the list.
+    #[serde(rename = "typeValueListElement", alias = "type_value_list_element")]
     type_value_list_element: AasSubmodelElements,
     ///Optional value type of the submodel element contained in the list.
-    #[serde(rename = "valueTypeListElement")]
+    #[serde(rename = "valueTypeListElement", default, skip_serializing_if = "Option::is_none")]
     value_type_list_element: Option<DataTypeDefXsd>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     category: Option<String>,
-    #[serde(rename = "idShort")]
+    #[serde(rename = "idShort", default, skip_serializing_if = "Option::is_none")]
     id_short: Option<String>,
-    #[serde(rename = "displayName")]
+    #[serde(rename = "displayName", default, skip_serializing_if = "Vec::is_empty")]
     display_name: Vec<MultiLanguageNameType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     description: Vec<MultiLanguageTextType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     extensions: Vec<Extension>,
-    #[serde(rename = "semanticId")]
+    #[serde(rename = "semanticId", default, skip_serializing_if = "Option::is_none")]
     semantic_id: Option<Reference>,
-    #[serde(rename = "supplementalSemanticIds")]
+    #[serde(rename = "supplementalSemanticIds", default, skip_serializing_if = "Vec::is_empty")]
     supplemental_semantic_ids: Vec<Reference>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     qualifiers: Vec<Qualifier>,
-    #[serde(rename = "embeddedDataSpecifications")]
-    data_specifications: Vec<Reference>
+    #[serde(rename = "embeddedDataSpecifications", default, skip_serializing_if = "Vec::is_empty")]
+    data_specifications: Vec<EmbeddedDataSpecification>
 }
 
 impl SubmodelElementList {
@@ -309,23 +316,23 @@ impl TQualifiable for SubmodelElementList {
 }
 
 impl THasDataSpecification for SubmodelElementList {
-    fn get_data_specifications(&self) -> &Vec<Reference> {
+    fn get_data_specifications(&self) -> &Vec<EmbeddedDataSpecification> {
         &self.data_specifications
     }
 
-    fn get_mut_data_specifications(&mut self) -> &mut Vec<Reference> {
+    fn get_mut_data_specifications(&mut self) -> &mut Vec<EmbeddedDataSpecification> {
         &mut self.data_specifications
     }
 
-    fn set_data_specifications(&mut self, data_specifications: Vec<Reference>) {
+    fn set_data_specifications(&mut self, data_specifications: Vec<EmbeddedDataSpecification>) {
         self.data_specifications = data_specifications;
     }
 
-    fn add_data_specification(&mut self, data_specification: Reference) {
+    fn add_data_specification(&mut self, data_specification: EmbeddedDataSpecification) {
         self.data_specifications.push(data_specification);
     }
 
-    fn remove_data_specification(&mut self, index: usize) -> Reference {
+    fn remove_data_specification(&mut self, index: usize) -> EmbeddedDataSpecification {
         self.data_specifications.remove(index)
     }
 }
@@ -0,0 +1,346 @@
+use std::mem::discriminant;
+
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::merge_conflict_kind::MergeConflictKind;
+use crate::structs::merge_conflict::MergeConflict;
+use crate::structs::submodel::Submodel;
+
+///Result of a three-way merge of a base, our and their version of a submodel.
+///
+/// Elements are matched by their idShortPath. Elements of submodel element lists are matched by
+/// their index. Whenever a change cannot be merged automatically, a conflict is recorded and the
+/// merged submodel keeps the state of our version for the conflicting element.
+#[derive(PartialEq, Clone)]
+pub struct SubmodelMerge {
+    ///The merged submodel.
+    merged: Submodel,
+    ///Conflicts that could not be resolved automatically.
+    conflicts: Vec<MergeConflict>
+}
+
+impl SubmodelMerge {
+    ///Merges the changes of our and their version of a submodel relative to the common base
+    /// version.
+    ///
+    /// [base]: common ancestor of both versions
+    /// [ours]: our version of the submodel
+    /// [theirs]: their version of the submodel
+    pub fn merge(base: &Submodel, ours: &Submodel, theirs: &Submodel) -> SubmodelMerge {
+        let mut conflicts = Vec::new();
+        let (stripped_base, stripped_ours, stripped_theirs) = (strip_submodel(base), strip_submodel(ours), strip_submodel(theirs));
+        let mut merged = merge_value(&stripped_base, &stripped_ours, &stripped_theirs)
+            .unwrap_or_else(|| {
+                conflicts.push(MergeConflict::for_submodel(stripped_base, stripped_ours.clone(), stripped_theirs));
+                stripped_ours
+            });
+        merged.set_submodel_elements(merge_elements(base.get_submodel_elements(), ours.get_submodel_elements(),
+                                                    theirs.get_submodel_elements(), "", false, &mut conflicts));
+        SubmodelMerge {
+            merged,
+            conflicts
+        }
+    }
+
+    ///Returns the merged submodel.
+    pub fn get_merged(&self) -> &Submodel {
+        &self.merged
+    }
+
+    ///Returns the conflicts that could not be resolved automatically.
+    pub fn get_conflicts(&self) -> &Vec<MergeConflict> {
+        &self.conflicts
+    }
+
+    ///Returns whether the merge completed without conflicts.
+    pub fn is_clean(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+
+    ///Consumes the result and returns the merged submodel.
+    pub fn into_merged(self) -> Submodel {
+        self.merged
+    }
+}
+
+///Three-way merge of a single value. Returns None if both sides changed the value differently.
+fn merge_value<T: PartialEq + Clone>(base: &T, ours: &T, theirs: &T) -> Option<T> {
+    if ours == theirs || base == theirs {
+        Some(ours.clone())
+    } else if base == ours {
+        Some(theirs.clone())
+    } else {
+        None
+    }
+}
+
+fn strip_submodel(submodel: &Submodel) -> Submodel {
+    let mut stripped = submodel.clone();
+    stripped.set_submodel_elements(Vec::new());
+    stripped
+}
+
+fn strip_element(element: &SubmodelElement) -> SubmodelElement {
+    let mut stripped = element.clone();
+    if let Some(children) = stripped.get_mut_children() {
+        children.clear();
+    }
+    stripped
+}
+
+///Returns the path segment of an element within its parent.
+fn segment(element: &SubmodelElement, index: usize, in_list: bool) -> String {
+    match element.get_id_short() {
+        Some(id_short) if !in_list => id_short.clone(),
+        _ => format!("[{}]", index)
+    }
+}
+
+fn join_path(prefix: &str, segment: &str) -> String {
+    if prefix.is_empty() || segment.starts_with('[') {
+        format!("{}{}", prefix, segment)
+    } else {
+        format!("{}.{}", prefix, segment)
+    }
+}
+
+fn find<'a>(elements: &'a [SubmodelElement], key: &str, in_list: bool) -> Option<&'a SubmodelElement> {
+    elements.iter().enumerate()
+        .find(|(index, element)| segment(element, *index, in_list) == key)
+        .map(|(_, element)| element)
+}
+
+fn merge_elements(base: &[SubmodelElement], ours: &[SubmodelElement], theirs: &[SubmodelElement],
+                  prefix: &str, in_list: bool, conflicts: &mut Vec<MergeConflict>) -> Vec<SubmodelElement> {
+    let mut keys: Vec<String> = ours.iter().enumerate()
+        .map(|(index, element)| segment(element, index, in_list))
+        .collect();
+    for (index, element) in theirs.iter().enumerate() {
+        let key = segment(element, index, in_list);
+        if !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+
+    let mut merged = Vec::new();
+    for key in keys {
+        let path = join_path(prefix, &key);
+        let base_element = find(base, &key, in_list);
+        let our_element = find(ours, &key, in_list);
+        let their_element = find(theirs, &key, in_list);
+        if let Some(element) = merge_element(base_element, our_element, their_element, &path, conflicts) {
+            merged.push(element);
+        }
+    }
+    merged
+}
+
+fn merge_element(base: Option<&SubmodelElement>, ours: Option<&SubmodelElement>, theirs: Option<&SubmodelElement>,
+                 path: &str, conflicts: &mut Vec<MergeConflict>) -> Option<SubmodelElement> {
+    if let Some(merged) = merge_value(&base, &ours, &theirs) {
+        return merged.cloned();
+    }
+
+    let kind = match (base, ours, theirs) {
+        (_, Some(our_element), Some(their_element)) if is_mergeable_container(base, our_element, their_element) => {
+            return Some(merge_container(base, our_element, their_element, path, conflicts));
+        },
+        (None, Some(_), Some(_)) => MergeConflictKind::AddedOnBothSides,
+        (Some(_), None, Some(_)) => MergeConflictKind::DeletedByOursModifiedByTheirs,
+        (Some(_), Some(_), None) => MergeConflictKind::ModifiedByOursDeletedByTheirs,
+        (_, Some(SubmodelElement::Property(our_property)), Some(SubmodelElement::Property(their_property))) => {
+            let mut our_property = our_property.clone();
+            let mut their_property = their_property.clone();
+            our_property.set_value(String::new());
+            their_property.set_value(String::new());
+            if our_property == their_property {
+                MergeConflictKind::ValueChanged
+            } else {
+                MergeConflictKind::ModifiedOnBothSides
+            }
+        },
+        _ => MergeConflictKind::ModifiedOnBothSides
+    };
+    conflicts.push(MergeConflict::new(path.to_string(), kind, base.cloned(), ours.cloned(), theirs.cloned()));
+    ours.cloned()
+}
+
+///Returns whether all present versions of an element contain further submodel elements and are
+/// of the same type, so that their children can be merged individually.
+fn is_mergeable_container(base: Option<&SubmodelElement>, ours: &SubmodelElement, theirs: &SubmodelElement) -> bool {
+    let same_type = |element: &SubmodelElement| discriminant(element) == discriminant(ours);
+    ours.get_children().is_some() && same_type(theirs) && base.is_none_or(same_type)
+}
+
+fn merge_container(base: Option<&SubmodelElement>, ours: &SubmodelElement, theirs: &SubmodelElement, path: &str,
+                   conflicts: &mut Vec<MergeConflict>) -> SubmodelElement {
+    let base_shell = base.map(strip_element);
+    let our_shell = strip_element(ours);
+    let their_shell = strip_element(theirs);
+    let mut merged = match merge_value(&base_shell, &Some(our_shell.clone()), &Some(their_shell)) {
+        Some(Some(shell)) => shell,
+        _ => {
+            let kind = if base.is_none() {
+                MergeConflictKind::AddedOnBothSides
+            } else {
+                MergeConflictKind::ModifiedOnBothSides
+            };
+            conflicts.push(MergeConflict::new(path.to_string(), kind, base.cloned(), Some(ours.clone()),
+                                              Some(theirs.clone())));
+            our_shell
+        }
+    };
+
+    let empty = Vec::new();
+    let base_children = base.and_then(|element| element.get_children()).unwrap_or(&empty);
+    let our_children = ours.get_children().unwrap_or(&empty);
+    let their_children = theirs.get_children().unwrap_or(&empty);
+    let in_list = matches!(ours, SubmodelElement::SubmodelElementList(_));
+    let children = merge_elements(base_children, our_children, their_children, path, in_list, conflicts);
+    if let Some(merged_children) = merged.get_mut_children() {
+        *merged_children = children;
+    }
+    merged
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+    use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+    use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+    use crate::enumerations::merge_conflict_kind::MergeConflictKind;
+    use crate::structs::property::Property;
+    use crate::structs::submodel::Submodel;
+    use crate::structs::submodel_element_collection::SubmodelElementCollection;
+    use crate::structs::submodel_element_list::SubmodelElementList;
+    use crate::structs::submodel_merge::SubmodelMerge;
+    use crate::traits::referable::TReferable;
+
+    fn property(id_short: &str, value: &str) -> SubmodelElement {
+        let mut property = Property::new(DataTypeDefXsd::String);
+        property.set_id_short(id_short.to_string());
+        property.set_value(value.to_string());
+        SubmodelElement::Property(property)
+    }
+
+    fn submodel(elements: Vec<SubmodelElement>) -> Submodel {
+        let mut submodel = Submodel::new("urn:example:submodel".to_string());
+        submodel.set_submodel_elements(elements);
+        submodel
+    }
+
+    fn value(submodel: &Submodel, path: &str) -> Option<String> {
+        match submodel.get_submodel_element(path) {
+            Some(SubmodelElement::Property(property)) => property.get_value().cloned(),
+            _ => None
+        }
+    }
+
+    fn single_conflict(merge: &SubmodelMerge) -> (&str, &MergeConflictKind) {
+        assert_eq!(merge.get_conflicts().len(), 1);
+        let conflict = &merge.get_conflicts()[0];
+        (conflict.get_path().as_str(), conflict.get_kind())
+    }
+
+    #[test]
+    fn merges_changes_of_both_sides() {
+        let base = submodel(vec![property("A", "1"), property("B", "1")]);
+        let ours = submodel(vec![property("A", "2"), property("B", "1")]);
+        let theirs = submodel(vec![property("A", "1"), property("B", "2"), property("C", "3")]);
+        let merge = SubmodelMerge::merge(&base, &ours, &theirs);
+        assert!(merge.is_clean());
+        assert_eq!(value(merge.get_merged(), "A").as_deref(), Some("2"));
+        assert_eq!(value(merge.get_merged(), "B").as_deref(), Some("2"));
+        assert_eq!(value(merge.get_merged(), "C").as_deref(), Some("3"));
+    }
+
+    #[test]
+    fn reports_value_changed() {
+        let base = submodel(vec![property("A", "1")]);
+        let merge = SubmodelMerge::merge(&base, &submodel(vec![property("A", "2")]), &submodel(vec![property("A", "3")]));
+        assert!(single_conflict(&merge) == ("A", &MergeConflictKind::ValueChanged));
+        assert_eq!(value(merge.get_merged(), "A").as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn reports_modified_on_both_sides() {
+        let base = submodel(vec![property("A", "1")]);
+        let categorized = |category: &str| {
+            let mut element = property("A", "1");
+            if let SubmodelElement::Property(property) = &mut element {
+                property.set_category(category.to_string());
+            }
+            element
+        };
+        let merge = SubmodelMerge::merge(&base, &submodel(vec![categorized("PARAMETER")]),
+                                         &submodel(vec![categorized("VARIABLE")]));
+        assert!(single_conflict(&merge) == ("A", &MergeConflictKind::ModifiedOnBothSides));
+    }
+
+    #[test]
+    fn reports_modified_submodel_attributes_on_both_sides() {
+        let base = submodel(vec![property("A", "1")]);
+        let mut ours = submodel(vec![property("A", "1")]);
+        ours.set_category("ours".to_string());
+        let mut theirs = submodel(vec![property("A", "1")]);
+        theirs.set_category("theirs".to_string());
+        let merge = SubmodelMerge::merge(&base, &ours, &theirs);
+        assert!(single_conflict(&merge) == ("", &MergeConflictKind::ModifiedOnBothSides));
+        assert!(merge.get_merged().get_category() == Some(&"ours".to_string()));
+        let conflict = &merge.get_conflicts()[0];
+        assert!(conflict.get_base_submodel().is_some_and(|submodel| submodel.get_submodel_elements().is_empty()));
+        assert!(conflict.get_ours_submodel().and_then(|submodel| submodel.get_category()) == Some(&"ours".to_string()));
+        assert!(conflict.get_theirs_submodel().and_then(|submodel| submodel.get_category()) == Some(&"theirs".to_string()));
+        assert!(conflict.get_ours().is_none());
+    }
+
+    #[test]
+    fn reports_deleted_by_ours_modified_by_theirs() {
+        let base = submodel(vec![property("A", "1")]);
+        let merge = SubmodelMerge::merge(&base, &submodel(Vec::new()), &submodel(vec![property("A", "2")]));
+        assert!(single_conflict(&merge) == ("A", &MergeConflictKind::DeletedByOursModifiedByTheirs));
+        assert!(merge.get_merged().get_submodel_elements().is_empty());
+    }
+
+    #[test]
+    fn reports_modified_by_ours_deleted_by_theirs() {
+        let base = submodel(vec![property("A", "1")]);
+        let merge = SubmodelMerge::merge(&base, &submodel(vec![property("A", "2")]), &submodel(Vec::new()));
+        assert!(single_conflict(&merge) == ("A", &MergeConflictKind::ModifiedByOursDeletedByTheirs));
+        assert_eq!(value(merge.get_merged(), "A").as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn reports_id_short_collision_of_added_elements() {
+        let base = submodel(Vec::new());
+        let merge = SubmodelMerge::merge(&base, &submodel(vec![property("A", "1")]), &submodel(vec![property("A", "2")]));
+        assert!(single_conflict(&merge) == ("A", &MergeConflictKind::AddedOnBothSides));
+        assert_eq!(merge.get_merged().get_submodel_elements().len(), 1);
+        assert_eq!(value(merge.get_merged(), "A").as_deref(), Some("1"));
+    }
+
+    #[test]
+    fn merges_identical_additions_once() {
+        let base = submodel(Vec::new());
+        let merge = SubmodelMerge::merge(&base, &submodel(vec![property("A", "1")]), &submodel(vec![property("A", "1")]));
+        assert!(merge.is_clean());
+        assert_eq!(merge.get_merged().get_submodel_elements().len(), 1);
+    }
+
+    #[test]
+    fn reports_conflicts_of_nested_elements_by_path() {
+        let collection = |value: &str| {
+            let mut list = SubmodelElementList::new(AasSubmodelElements::Property);
+            list.set_id_short("List".to_string());
+            list.set_value(vec![property("Item", "0"), property("Item", value)]);
+            let mut collection = SubmodelElementCollection::new();
+            collection.set_id_short("Collection".to_string());
+            collection.set_value(vec![SubmodelElement::SubmodelElementList(list)]);
+            SubmodelElement::SubmodelElementCollection(collection)
+        };
+        let base = submodel(vec![collection("1")]);
+        let merge = SubmodelMerge::merge(&base, &submodel(vec![collection("2")]), &submodel(vec![collection("3")]));
+        assert!(single_conflict(&merge) == ("Collection.List[1]", &MergeConflictKind::ValueChanged));
+        assert_eq!(value(merge.get_merged(), "Collection.List[1]").as_deref(), Some("2"));
+    }
+}
\ No newline at end of file
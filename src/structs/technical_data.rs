@@ -0,0 +1,395 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::modelling_kind::ModellingKind;
+use crate::structs::concept_description::ConceptDescription;
+use crate::structs::conformance_report::ConformanceReport;
+use crate::structs::further_information::FurtherInformation;
+use crate::structs::general_information::GeneralInformation;
+use crate::structs::product_classification::ProductClassification;
+use crate::structs::submodel::Submodel;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::technical_property::TechnicalProperty;
+use crate::structs::template_element::{global_reference, join_path, ARBITRARY, TemplateElement};
+use crate::traits::has_kind::THasKind;
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed model of the submodel template Generic Frame for Technical Data for Industrial Equipment
+/// (IDTA 02003-1-2). The technical properties are kept as arbitrary submodel elements, which can
+/// be queried without walking the element tree.
+///
+/// The model wraps the submodel, so the conversion from and to a submodel is lossless and elements
+/// the model does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct TechnicalData {
+    ///The wrapped submodel.
+    submodel: Submodel
+}
+
+impl TechnicalData {
+    ///Semantic ID of the Technical Data submodel.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/ZVEI/TechnicalData/Submodel/1/2";
+    ///idShort of the Technical Data submodel.
+    pub const ID_SHORT: &str = "TechnicalData";
+
+    ///Creates a new Technical Data submodel with the mandatory elements and empty product
+    /// classifications and technical properties.
+    ///
+    /// [id]: global identifier of the submodel
+    /// [general_information]: general information on the manufacturer and the product
+    pub fn new(id: String, general_information: GeneralInformation) -> TechnicalData {
+        let mut submodel = Submodel::new(id);
+        submodel.set_id_short(TechnicalData::ID_SHORT.to_string());
+        submodel.set_semantic_id(global_reference(TechnicalData::SEMANTIC_ID));
+        submodel.set_kind(ModellingKind::Instance);
+        let mut technical_data = TechnicalData {
+            submodel
+        };
+        technical_data.set_general_information(general_information);
+        technical_data.set_product_classifications(Vec::new());
+        technical_data.set_technical_properties(Vec::new());
+        technical_data
+    }
+
+    ///Wraps a submodel after checking that it contains the mandatory elements of the Technical Data
+    /// with the expected types and valid values.
+    ///
+    /// [submodel]: Technical Data submodel
+    pub fn from_submodel(submodel: Submodel) -> Result<TechnicalData, ConversionError> {
+        TechnicalData::check(&submodel)?;
+        Ok(TechnicalData {
+            submodel
+        })
+    }
+
+    ///Returns the wrapped submodel.
+    pub fn get_submodel(&self) -> &Submodel {
+        &self.submodel
+    }
+
+    ///Consumes the Technical Data and returns the wrapped submodel.
+    pub fn into_submodel(self) -> Submodel {
+        self.submodel
+    }
+
+    ///Returns the submodel template of the Technical Data.
+    pub fn template() -> Submodel {
+        let mut template = Submodel::new("https://admin-shell.io/idta/SubmodelTemplate/TechnicalData/1/2".to_string());
+        template.set_id_short(TechnicalData::ID_SHORT.to_string());
+        template.set_semantic_id(global_reference(TechnicalData::SEMANTIC_ID));
+        template.set_kind(ModellingKind::Template);
+        template.set_submodel_elements(vec![
+            GENERAL_INFORMATION.to_collection(GeneralInformation::template_elements()),
+            PRODUCT_CLASSIFICATIONS.to_collection(vec![
+                PRODUCT_CLASSIFICATION_ITEM.to_collection(ProductClassification::template_elements())
+            ]),
+            TECHNICAL_PROPERTIES.to_collection(vec![
+                ARBITRARY.to_element(),
+                MAIN_SECTION.to_collection(vec![
+                    ARBITRARY.to_element(),
+                    SUB_SECTION.to_collection(vec![ARBITRARY.to_element()])
+                ])
+            ]),
+            FURTHER_INFORMATION.to_collection(FurtherInformation::template_elements())
+        ]);
+        template
+    }
+
+    ///Checks the wrapped submodel against the submodel template of the Technical Data.
+    pub fn check_conformance(&self) -> ConformanceReport {
+        self.submodel.check_conformance(&TechnicalData::template())
+    }
+
+    ///Returns the general information on the manufacturer and the product.
+    pub fn get_general_information(&self) -> Option<GeneralInformation> {
+        self.submodel.get_collection(GENERAL_INFORMATION.id_short, GENERAL_INFORMATION.semantic_id)
+            .map(|collection| GeneralInformation {
+                collection: collection.clone()
+            })
+    }
+
+    ///Sets the general information on the manufacturer and the product.
+    ///
+    /// [general_information]: general information on the manufacturer and the product
+    pub fn set_general_information(&mut self, general_information: GeneralInformation) {
+        self.submodel.set_element(GENERAL_INFORMATION.id_short, GENERAL_INFORMATION.semantic_id, Some(SubmodelElement::SubmodelElementCollection(general_information.into_collection())));
+    }
+
+    ///Returns the classifications of the product.
+    pub fn get_product_classifications(&self) -> Vec<ProductClassification> {
+        self.submodel.get_collection(PRODUCT_CLASSIFICATIONS.id_short, PRODUCT_CLASSIFICATIONS.semantic_id)
+            .map(|collection| collection.get_collections(PRODUCT_CLASSIFICATION_ITEM.id_short, PRODUCT_CLASSIFICATION_ITEM.semantic_id)
+                .into_iter()
+                .map(|collection| ProductClassification {
+                    collection: collection.clone()
+                })
+                .collect())
+            .unwrap_or_default()
+    }
+
+    ///Sets the classifications of the product.
+    ///
+    /// [product_classifications]: classifications of the product
+    pub fn set_product_classifications(&mut self, product_classifications: Vec<ProductClassification>) {
+        let elements = product_classifications.into_iter()
+            .map(|classification| SubmodelElement::SubmodelElementCollection(classification.into_collection()))
+            .collect();
+        let classifications = self.get_or_add_collection(&PRODUCT_CLASSIFICATIONS);
+        classifications.set_elements(PRODUCT_CLASSIFICATION_ITEM.id_short, PRODUCT_CLASSIFICATION_ITEM.semantic_id, elements);
+    }
+
+    ///Returns the ID of the class of the product in a classification system, e.g. the ECLASS class.
+    /// The name of the classification system is compared case-insensitively.
+    ///
+    /// [classification_system]: name of the classification system, e.g. ECLASS
+    pub fn get_product_class_id(&self, classification_system: &str) -> Option<String> {
+        self.get_product_classifications().into_iter()
+            .find(|classification| classification.get_product_classification_system()
+                .is_some_and(|system| system.eq_ignore_ascii_case(classification_system)))
+            .and_then(|classification| classification.get_product_class_id())
+    }
+
+    ///Returns the collection of the technical properties, whose elements are arbitrary.
+    pub fn get_technical_properties(&self) -> Option<&SubmodelElementCollection> {
+        self.submodel.get_collection(TECHNICAL_PROPERTIES.id_short, TECHNICAL_PROPERTIES.semantic_id)
+    }
+
+    ///Returns the mutable collection of the technical properties.
+    pub fn get_mut_technical_properties(&mut self) -> Option<&mut SubmodelElementCollection> {
+        match self.submodel.find_mut_element(TECHNICAL_PROPERTIES.id_short, TECHNICAL_PROPERTIES.semantic_id)? {
+            SubmodelElement::SubmodelElementCollection(collection) => Some(collection),
+            _ => None
+        }
+    }
+
+    ///Sets the elements of the technical properties, usually properties identified by their
+    /// semantic ID and sections grouping them.
+    ///
+    /// [technical_properties]: elements of the technical properties
+    pub fn set_technical_properties(&mut self, technical_properties: Vec<SubmodelElement>) {
+        self.get_or_add_collection(&TECHNICAL_PROPERTIES).set_value(technical_properties);
+    }
+
+    ///Returns all technical properties, descending into sections and other collections and lists.
+    /// Properties can be selected by their semantic ID, e.g. an ECLASS IRDI.
+    pub fn find_technical_properties(&self) -> Vec<TechnicalProperty<'_>> {
+        let mut properties = Vec::new();
+        if let Some(collection) = self.get_technical_properties() {
+            let path = collection.get_id_short().cloned().unwrap_or(TECHNICAL_PROPERTIES.id_short.to_string());
+            collect_technical_properties(collection.get_value(), &path, false, &mut properties);
+        }
+        properties
+    }
+
+    ///Returns all technical properties with a unit. The unit is taken from the data specification
+    /// IEC 61360 of the concept description identified by the semantic ID of the property.
+    ///
+    /// [concept_descriptions]: concept descriptions, e.g. those of the environment
+    pub fn find_technical_properties_with_unit(&self, concept_descriptions: &[ConceptDescription]) -> Vec<TechnicalProperty<'_>> {
+        self.find_technical_properties().into_iter()
+            .filter(|property| property.get_unit(concept_descriptions).is_some())
+            .collect()
+    }
+
+    ///Returns the first technical property with the semantic ID.
+    ///
+    /// [semantic_id]: semantic ID of the property, e.g. an ECLASS IRDI
+    pub fn find_technical_property(&self, semantic_id: &str) -> Option<TechnicalProperty<'_>> {
+        self.find_technical_properties().into_iter()
+            .find(|property| property.get_semantic_id().is_some_and(|property_semantic_id| property_semantic_id == semantic_id))
+    }
+
+    ///Returns the further information on the technical data.
+    pub fn get_further_information(&self) -> Option<FurtherInformation> {
+        self.submodel.get_collection(FURTHER_INFORMATION.id_short, FURTHER_INFORMATION.semantic_id)
+            .map(|collection| FurtherInformation {
+                collection: collection.clone()
+            })
+    }
+
+    ///Sets the further information on the technical data. Passing None removes it.
+    ///
+    /// [further_information]: further information on the technical data
+    pub fn set_further_information(&mut self, further_information: Option<FurtherInformation>) {
+        self.submodel.set_element(FURTHER_INFORMATION.id_short, FURTHER_INFORMATION.semantic_id, further_information.map(|further_information| SubmodelElement::SubmodelElementCollection(further_information.into_collection())));
+    }
+
+    fn get_or_add_collection(&mut self, element: &TemplateElement) -> &mut SubmodelElementCollection {
+        if self.submodel.get_collection(element.id_short, element.semantic_id).is_none() {
+            self.submodel.set_element(element.id_short, element.semantic_id,
+                                      Some(SubmodelElement::SubmodelElementCollection(SubmodelElementCollection::new())));
+        }
+        match self.submodel.find_mut_element(element.id_short, element.semantic_id) {
+            Some(SubmodelElement::SubmodelElementCollection(collection)) => collection,
+            _ => unreachable!("the collection was added above")
+        }
+    }
+
+    fn check(submodel: &Submodel) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(submodel, "")?;
+        }
+        for element in GENERAL_INFORMATION.check(submodel, "")? {
+            if let SubmodelElement::SubmodelElementCollection(collection) = element {
+                GeneralInformation::check(collection, GENERAL_INFORMATION.id_short)?;
+            }
+        }
+        for element in PRODUCT_CLASSIFICATIONS.check(submodel, "")? {
+            if let SubmodelElement::SubmodelElementCollection(collection) = element {
+                for item in PRODUCT_CLASSIFICATION_ITEM.check(collection, PRODUCT_CLASSIFICATIONS.id_short)? {
+                    if let SubmodelElement::SubmodelElementCollection(item) = item {
+                        let path = join_path(PRODUCT_CLASSIFICATIONS.id_short,
+                                             item.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default());
+                        ProductClassification::check(item, &path)?;
+                    }
+                }
+            }
+        }
+        for element in FURTHER_INFORMATION.check(submodel, "")? {
+            if let SubmodelElement::SubmodelElementCollection(collection) = element {
+                FurtherInformation::check(collection, FURTHER_INFORMATION.id_short)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+const GENERAL_INFORMATION: TemplateElement =
+    TemplateElement::new("GeneralInformation", GeneralInformation::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::One);
+const PRODUCT_CLASSIFICATIONS: TemplateElement =
+    TemplateElement::new("ProductClassifications", "https://admin-shell.io/ZVEI/TechnicalData/ProductClassifications/1/1", AasSubmodelElements::SubmodelElementCollection, Cardinality::One);
+const TECHNICAL_PROPERTIES: TemplateElement =
+    TemplateElement::new("TechnicalProperties", "https://admin-shell.io/ZVEI/TechnicalData/TechnicalProperties/1/1", AasSubmodelElements::SubmodelElementCollection, Cardinality::One);
+const FURTHER_INFORMATION: TemplateElement =
+    TemplateElement::new("FurtherInformation", FurtherInformation::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToOne);
+const PRODUCT_CLASSIFICATION_ITEM: TemplateElement =
+    TemplateElement::new("ProductClassificationItem{00}", ProductClassification::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToMany);
+const MAIN_SECTION: TemplateElement =
+    TemplateElement::new("MainSection{00}", "https://admin-shell.io/ZVEI/TechnicalData/MainSection/1/1", AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToMany);
+const SUB_SECTION: TemplateElement =
+    TemplateElement::new("SubSection{00}", "https://admin-shell.io/ZVEI/TechnicalData/SubSection/1/1", AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToMany);
+const ELEMENTS: [&TemplateElement; 4] = [&GENERAL_INFORMATION, &PRODUCT_CLASSIFICATIONS, &TECHNICAL_PROPERTIES,
+    &FURTHER_INFORMATION];
+
+fn collect_technical_properties<'a>(elements: &'a [SubmodelElement], path: &str, in_list: bool,
+                                    properties: &mut Vec<TechnicalProperty<'a>>) {
+    for (index, element) in elements.iter().enumerate() {
+        let element_path = if in_list {
+            format!("{}[{}]", path, index)
+        } else {
+            join_path(path, element.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())
+        };
+        match element {
+            SubmodelElement::SubmodelElementCollection(collection) =>
+                collect_technical_properties(collection.get_value(), &element_path, false, properties),
+            SubmodelElement::SubmodelElementList(list) =>
+                collect_technical_properties(list.get_value(), &element_path, true, properties),
+            _ => properties.push(TechnicalProperty::new(element_path, element))
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+    use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+    use crate::structs::concept_description::ConceptDescription;
+    use crate::structs::general_information::GeneralInformation;
+    use crate::structs::property::Property;
+    use crate::structs::submodel_element_collection::SubmodelElementCollection;
+    use crate::structs::technical_data::TechnicalData;
+    use crate::structs::template_element::global_reference;
+    use crate::traits::has_semantics::THasSemantics;
+    use crate::traits::referable::TReferable;
+
+    fn property(id_short: &str, semantic_id: &str, value: &str) -> SubmodelElement {
+        let mut property = Property::new(DataTypeDefXsd::Double);
+        property.set_id_short(id_short.to_string());
+        property.set_semantic_id(global_reference(semantic_id));
+        property.set_value(value.to_string());
+        SubmodelElement::Property(property)
+    }
+
+    ///Returns the concept descriptions of the rated voltage with unit and of the color without unit
+    /// in the JSON serialization of the specification.
+    fn concept_descriptions() -> Vec<ConceptDescription> {
+        serde_json::from_str(r#"[
+            {
+                "modelType": "ConceptDescription",
+                "id": "0173-1#02-AAB123#001",
+                "embeddedDataSpecifications": [{
+                    "dataSpecification": {
+                        "type": "ExternalReference",
+                        "keys": [{"type": "GlobalReference", "value": "https://admin-shell.io/DataSpecificationTemplates/DataSpecificationIec61360/3"}]
+                    },
+                    "dataSpecificationContent": {
+                        "modelType": "DataSpecificationIec61360",
+                        "preferredName": [{"language": "en", "text": "Rated voltage"}],
+                        "unit": "V",
+                        "unitId": {"type": "ExternalReference", "keys": [{"type": "GlobalReference", "value": "0173-1#05-AAA480#002"}]},
+                        "dataType": "REAL_MEASURE",
+                        "levelType": {"min": false, "nom": true, "typ": false, "max": false}
+                    }
+                }]
+            },
+            {
+                "modelType": "ConceptDescription",
+                "id": "0173-1#02-AAB456#001",
+                "embeddedDataSpecifications": [{
+                    "dataSpecification": {
+                        "type": "ExternalReference",
+                        "keys": [{"type": "GlobalReference", "value": "https://admin-shell.io/DataSpecificationTemplates/DataSpecificationIec61360/3"}]
+                    },
+                    "dataSpecificationContent": {
+                        "modelType": "DataSpecificationIec61360",
+                        "preferredName": [{"language": "en", "text": "Color"}],
+                        "dataType": "STRING",
+                        "valueList": {"valueReferencePairs": [{"value": "red", "valueId": {"type": "ExternalReference",
+                            "keys": [{"type": "GlobalReference", "value": "0173-1#07-AAA001#001"}]}}]}
+                    }
+                }]
+            }
+        ]"#).unwrap()
+    }
+
+    fn technical_data() -> TechnicalData {
+        let general_information = GeneralInformation::new("ACME".to_string(), Vec::new(), "A-100".to_string(), "O-100".to_string());
+        let mut technical_data = TechnicalData::new("urn:example:technical-data".to_string(), general_information);
+        let mut section = SubmodelElementCollection::new();
+        section.set_id_short("MainSection01".to_string());
+        section.add_value(property("RatedVoltage", "0173-1#02-AAB123#001", "230"));
+        section.add_value(property("Color", "0173-1#02-AAB456#001", "red"));
+        technical_data.set_technical_properties(vec![SubmodelElement::SubmodelElementCollection(section),
+                                                     property("Weight", "0173-1#02-AAB789#001", "1.5")]);
+        technical_data
+    }
+
+    #[test]
+    fn finds_technical_properties_with_unit_of_their_concept_description() {
+        let concept_descriptions = concept_descriptions();
+        let technical_data = technical_data();
+        let properties = technical_data.find_technical_properties_with_unit(&concept_descriptions);
+
+        assert!(properties.len() == 1);
+        assert!(properties[0].get_path() == "TechnicalProperties.MainSection01.RatedVoltage");
+        assert!(properties[0].get_unit(&concept_descriptions) == Some(&"V".to_string()));
+        assert!(technical_data.find_technical_property("0173-1#02-AAB456#001")
+            .is_some_and(|property| property.get_unit(&concept_descriptions).is_none()));
+        assert!(technical_data.find_technical_properties_with_unit(&[]).is_empty());
+    }
+
+    #[test]
+    fn round_trips_embedded_data_specifications() {
+        let concept_descriptions = concept_descriptions();
+        let json = serde_json::to_value(&concept_descriptions).unwrap();
+
+        assert!(json[0]["embeddedDataSpecifications"][0]["dataSpecificationContent"]["modelType"] == "DataSpecificationIec61360");
+        assert!(json[1]["embeddedDataSpecifications"][0]["dataSpecificationContent"]["valueList"]["valueReferencePairs"][0]["value"]
+            == "red");
+        assert!(serde_json::from_value::<Vec<ConceptDescription>>(json).unwrap() == concept_descriptions);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,76 @@
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::concept_description::ConceptDescription;
+use crate::traits::identifiable::TIdentifiable;
+use crate::traits::property_value::TPropertyValue;
+
+///A technical property of the Technical Data, i.e. an element of the technical properties that is
+/// no submodel element collection or list, together with its idShortPath. Sections, e.g.
+/// `MainSection01`, are part of the path.
+#[derive(PartialEq, Clone)]
+pub struct TechnicalProperty<'a> {
+    ///IdShortPath of the element within the submodel.
+    path: String,
+    ///The element.
+    element: &'a SubmodelElement
+}
+
+impl<'a> TechnicalProperty<'a> {
+    ///Creates a new instance of the struct.
+    ///
+    /// [path]: idShortPath of the element within the submodel
+    /// [element]: the element
+    pub fn new(path: String, element: &'a SubmodelElement) -> TechnicalProperty<'a> {
+        TechnicalProperty {
+            path,
+            element
+        }
+    }
+
+    ///Returns the idShortPath of the element within the submodel, e.g.
+    /// `TechnicalProperties.MainSection01.RatedVoltage`.
+    pub fn get_path(&self) -> &String {
+        &self.path
+    }
+
+    ///Returns the element.
+    pub fn get_element(&self) -> &'a SubmodelElement {
+        self.element
+    }
+
+    ///Returns the value of the first key of the semantic ID of the element, e.g. the IRDI of an
+    /// ECLASS property.
+    pub fn get_semantic_id(&self) -> Option<&'a String> {
+        self.element.get_semantic_id()
+            .and_then(|semantic_id| semantic_id.get_keys().first())
+            .map(|key| key.get_value())
+    }
+
+    ///Returns the unit of the element, which is taken from the data specification IEC 61360 of the
+    /// concept description identified by the semantic ID of the element.
+    ///
+    /// [concept_descriptions]: concept descriptions, e.g. those of the environment
+    pub fn get_unit<'b>(&self, concept_descriptions: &'b [ConceptDescription]) -> Option<&'b String> {
+        let semantic_id = self.get_semantic_id()?;
+        concept_descriptions.iter()
+            .find(|concept_description| concept_description.get_id() == semantic_id)
+            .and_then(|concept_description| concept_description.get_data_specification_iec61360())
+            .and_then(|data_specification| data_specification.get_unit())
+    }
+
+    ///Returns the value of the element if it is a property.
+    pub fn get_value(&self) -> Option<&'a String> {
+        match self.element {
+            SubmodelElement::Property(property) => property.get_value(),
+            _ => None
+        }
+    }
+
+    ///Returns the value of the element converted to a Rust type if it is a property. Returns None
+    /// if the element is no property or its value cannot be converted.
+    pub fn get_typed_value<T: TPropertyValue>(&self) -> Option<T> {
+        match self.element {
+            SubmodelElement::Property(property) => property.get_typed_value(),
+            _ => None
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::direction::Direction;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::key_type::KeyType;
+use crate::enumerations::reference_type::ReferenceType;
+use crate::enumerations::smt_qualifier::SmtQualifier;
+use crate::enumerations::state_of_event::StateOfEvent;
+use crate::structs::annotated_relationship_element::AnnotatedRelationshipElement;
+use crate::structs::basic_event_element::BasicEventElement;
+use crate::structs::blob::Blob;
+use crate::structs::capability::Capability;
+use crate::structs::conformance_report::{is_of_type, ARBITRARY_SEMANTIC_ID};
+use crate::structs::entity::Entity;
+use crate::structs::file::File;
+use crate::structs::key::Key;
+use crate::structs::multi_language_property::MultiLanguageProperty;
+use crate::structs::operation::Operation;
+use crate::structs::property::Property;
+use crate::structs::range::Range;
+use crate::structs::reference::Reference;
+use crate::structs::reference_element::ReferenceElement;
+use crate::structs::relationship_element::RelationshipElement;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::submodel_element_list::SubmodelElementList;
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::property_value::TPropertyValue;
+use crate::traits::qualifiable::TQualifiable;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Element of a submodel template as used by the typed submodel models to create their template,
+/// to check instances and to access the elements of instances.
+pub(crate) struct TemplateElement {
+    pub(crate) id_short: &'static str,
+    pub(crate) semantic_id: &'static str,
+    pub(crate) element_type: AasSubmodelElements,
+    pub(crate) value_type: Option<DataTypeDefXsd>,
+    pub(crate) cardinality: Cardinality
+}
+
+impl TemplateElement {
+    pub(crate) const fn new(id_short: &'static str, semantic_id: &'static str, element_type: AasSubmodelElements,
+                            cardinality: Cardinality) -> TemplateElement {
+        TemplateElement {
+            id_short,
+            semantic_id,
+            element_type,
+            value_type: None,
+            cardinality
+        }
+    }
+
+    pub(crate) const fn property(id_short: &'static str, semantic_id: &'static str, value_type: DataTypeDefXsd,
+                                 cardinality: Cardinality) -> TemplateElement {
+        TemplateElement {
+            id_short,
+            semantic_id,
+            element_type: AasSubmodelElements::Property,
+            value_type: Some(value_type),
+            cardinality
+        }
+    }
+
+    ///Creates the element of the submodel template. Collections and lists are created empty.
+    pub(crate) fn to_element(&self) -> SubmodelElement {
+        self.create(true)
+    }
+
+    fn create(&self, with_id_short: bool) -> SubmodelElement {
+        let value_type = self.value_type.clone().unwrap_or(DataTypeDefXsd::String);
+        match self.element_type {
+            AasSubmodelElements::AnnotatedRelationshipElement =>
+                SubmodelElement::AnnotatedRelationshipElement(self.prepare(AnnotatedRelationshipElement::new(), with_id_short)),
+            AasSubmodelElements::RelationshipElement => SubmodelElement::RelationshipElement(self.prepare(RelationshipElement::new(), with_id_short)),
+            AasSubmodelElements::BasicEventElement | AasSubmodelElements::EventElement =>
+                SubmodelElement::BasicEventElement(self.prepare(BasicEventElement::new(
+                    Reference::new(ReferenceType::ModelReference, Vec::new()), Direction::Output, StateOfEvent::Off), with_id_short)),
+            AasSubmodelElements::Blob => SubmodelElement::Blob(self.prepare(Blob::new(), with_id_short)),
+            AasSubmodelElements::Capability => SubmodelElement::Capability(self.prepare(Capability::new(), with_id_short)),
+            AasSubmodelElements::Entity => SubmodelElement::Entity(self.prepare(Entity::new(), with_id_short)),
+            AasSubmodelElements::File => SubmodelElement::File(self.prepare(File::new(), with_id_short)),
+            AasSubmodelElements::MultiLanguageProperty => SubmodelElement::MultiLanguageProperty(self.prepare(MultiLanguageProperty::new(), with_id_short)),
+            AasSubmodelElements::Operation => SubmodelElement::Operation(self.prepare(Operation::new(), with_id_short)),
+            AasSubmodelElements::Range => SubmodelElement::Range(self.prepare(Range::new(value_type), with_id_short)),
+            AasSubmodelElements::ReferenceElement => SubmodelElement::ReferenceElement(self.prepare(ReferenceElement::new(), with_id_short)),
+            AasSubmodelElements::SubmodelElementCollection =>
+                SubmodelElement::SubmodelElementCollection(self.prepare(SubmodelElementCollection::new(), with_id_short)),
+            AasSubmodelElements::SubmodelElementList =>
+                SubmodelElement::SubmodelElementList(self.prepare(SubmodelElementList::new(AasSubmodelElements::SubmodelElement), with_id_short)),
+            AasSubmodelElements::Property | AasSubmodelElements::DataElement | AasSubmodelElements::SubmodelElement =>
+                SubmodelElement::Property(self.prepare(Property::new(value_type), with_id_short))
+        }
+    }
+
+    ///Creates the submodel element collection of the submodel template.
+    ///
+    /// [children]: elements of the collection in the submodel template
+    pub(crate) fn to_collection(&self, children: Vec<SubmodelElement>) -> SubmodelElement {
+        let mut collection = self.prepare(SubmodelElementCollection::new(), true);
+        collection.set_value(children);
+        SubmodelElement::SubmodelElementCollection(collection)
+    }
+
+    ///Creates the entity of the submodel template.
+    ///
+    /// [statements]: statements of the entity in the submodel template
+    pub(crate) fn to_entity(&self, statements: Vec<SubmodelElement>) -> SubmodelElement {
+        let mut entity = self.prepare(Entity::new(), true);
+        entity.set_statements(statements);
+        SubmodelElement::Entity(entity)
+    }
+
+    ///Creates the submodel element list of the submodel template. The semantic ID of the item is
+    /// used as semantic ID of the list elements.
+    ///
+    /// [item]: template of the list elements
+    /// [children]: elements of the list elements in the submodel template, if they are collections
+    pub(crate) fn to_list(&self, item: &TemplateElement, children: Vec<SubmodelElement>) -> SubmodelElement {
+        let mut list = self.prepare(SubmodelElementList::new(item.element_type.clone()), true);
+        if let Some(value_type) = &item.value_type {
+            list.set_value_type_list_element(value_type.clone());
+        }
+        list.set_semantic_id_list_element(global_reference(item.semantic_id));
+        let mut item = item.create(false);
+        if let Some(item_children) = item.get_mut_children() {
+            *item_children = children;
+        }
+        list.set_value(vec![item]);
+        SubmodelElement::SubmodelElementList(list)
+    }
+
+    ///Checks that the container has the number of elements the cardinality requires and that they
+    /// are of the expected type and have valid values. Mandatory properties and multi language
+    /// properties must have a value. Returns the matching elements.
+    ///
+    /// [container]: container of the elements
+    /// [path]: idShortPath of the container used in error messages
+    pub(crate) fn check<'a, C: TSubmodelElementContainer + ?Sized>(&self, container: &'a C, path: &str)
+        -> Result<Vec<&'a SubmodelElement>, ConversionError> {
+        let elements = container.find_elements(self.id_short, self.semantic_id);
+        if elements.len() < self.cardinality.get_min() {
+            return Err(ConversionError::MissingElement(join_path(path, self.id_short)));
+        }
+        for element in &elements {
+            let element_path = join_path(path, element.get_id_short().map(|id_short| id_short.as_str()).unwrap_or(self.id_short));
+            if !is_of_type(element, &self.element_type) {
+                return Err(ConversionError::UnexpectedElementType(element_path));
+            }
+            if let (Some(value_type), SubmodelElement::Property(property)) = (&self.value_type, element)
+                && property.get_value().is_some_and(|value| !value_type.is_valid_value(value)) {
+                return Err(ConversionError::InvalidValue(element_path));
+            }
+            if self.cardinality.get_min() > 0 && !has_value(element) {
+                return Err(ConversionError::InvalidValue(element_path));
+            }
+        }
+        Ok(elements)
+    }
+
+    ///Sets the value of the matching property in the container. A missing property is added with the
+    /// data type of the template. Passing None removes the property.
+    ///
+    /// [container]: container of the property
+    /// [value]: value of the property
+    pub(crate) fn set_property_value<C: TSubmodelElementContainer + ?Sized, T: TPropertyValue>(&self, container: &mut C,
+                                                                                          value: Option<&T>) {
+        if let Some(value) = value
+            && container.find_element(self.id_short, self.semantic_id).is_none() {
+            container.set_element(self.id_short, self.semantic_id, Some(self.new_property(value)));
+        }
+        container.set_property_value(self.id_short, self.semantic_id, value);
+    }
+
+    ///Creates a property for an instance with the data type of the template and the value. The
+    /// idShort and semantic ID are set when the property is added to a container.
+    ///
+    /// [value]: value of the property
+    pub(crate) fn new_property<T: TPropertyValue>(&self, value: &T) -> SubmodelElement {
+        let mut property = Property::new(self.value_type.clone().unwrap_or(T::get_value_type()));
+        property.set_typed_value(value);
+        SubmodelElement::Property(property)
+    }
+
+    fn prepare<T: TReferable + THasSemantics + TQualifiable>(&self, mut element: T, with_id_short: bool) -> T {
+        if with_id_short {
+            element.set_id_short(self.id_short.to_string());
+        }
+        element.set_semantic_id(global_reference(self.semantic_id));
+        element.add_smt_qualifier(SmtQualifier::Cardinality(self.cardinality.clone()));
+        element
+    }
+}
+
+///Template element standing for arbitrary elements of an instance.
+pub(crate) const ARBITRARY: TemplateElement =
+    TemplateElement::new("Arbitrary", ARBITRARY_SEMANTIC_ID, AasSubmodelElements::SubmodelElement, Cardinality::ZeroToMany);
+
+///Returns an external reference to the global ID, as used for the semantic IDs of submodel
+/// templates.
+pub(crate) fn global_reference(value: &str) -> Reference {
+    Reference::new(ReferenceType::ExternalReference, vec![Key::new(KeyType::GlobalReference, value.to_string())])
+}
+
+///Returns the idShortPath of a child element.
+pub(crate) fn join_path(path: &str, id_short: &str) -> String {
+    if path.is_empty() {
+        id_short.to_string()
+    } else {
+        format!("{}.{}", path, id_short)
+    }
+}
+
+
+///Returns whether the element has a value; elements other than properties and multi language
+/// properties always have one.
+fn has_value(element: &SubmodelElement) -> bool {
+    match element {
+        SubmodelElement::Property(property) => property.get_value().is_some(),
+        SubmodelElement::MultiLanguageProperty(multi_language_property) => !multi_language_property.get_value().is_empty(),
+        _ => true
+    }
+}
\ No newline at end of file
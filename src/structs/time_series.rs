@@ -0,0 +1,234 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::modelling_kind::ModellingKind;
+use crate::enumerations::segment_kind::SegmentKind;
+use crate::enumerations::time_series_error::TimeSeriesError;
+use crate::structs::conformance_report::ConformanceReport;
+use crate::structs::submodel::Submodel;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, join_path, TemplateElement};
+use crate::structs::time_series_metadata::TimeSeriesMetadata;
+use crate::structs::time_series_record::TimeSeriesRecord;
+use crate::structs::time_series_segment::TimeSeriesSegment;
+use crate::traits::file_store::TFileStore;
+use crate::traits::has_kind::THasKind;
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed model of the submodel template Time Series Data (IDTA 02008-1-1), which describes the
+/// records of a time series in its metadata and stores them in internal, linked or external
+/// segments.
+///
+/// The model wraps the submodel, so the conversion from and to a submodel is lossless and elements
+/// the model does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct TimeSeries {
+    ///The wrapped submodel.
+    submodel: Submodel
+}
+
+impl TimeSeries {
+    ///Semantic ID of the Time Series submodel.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/idta/TimeSeries/1/1";
+    ///idShort of the Time Series submodel.
+    pub const ID_SHORT: &str = "TimeSeries";
+
+    ///Creates a new Time Series submodel without segments.
+    ///
+    /// [id]: global identifier of the submodel
+    /// [metadata]: metadata with the record definition
+    pub fn new(id: String, metadata: TimeSeriesMetadata) -> TimeSeries {
+        let mut submodel = Submodel::new(id);
+        submodel.set_id_short(TimeSeries::ID_SHORT.to_string());
+        submodel.set_semantic_id(global_reference(TimeSeries::SEMANTIC_ID));
+        submodel.set_kind(ModellingKind::Instance);
+        let mut time_series = TimeSeries {
+            submodel
+        };
+        time_series.set_metadata(metadata);
+        time_series.set_segments(Vec::new());
+        time_series
+    }
+
+    ///Wraps a submodel after checking that it contains the mandatory elements of the Time Series
+    /// with the expected types and valid values.
+    ///
+    /// [submodel]: Time Series submodel
+    pub fn from_submodel(submodel: Submodel) -> Result<TimeSeries, ConversionError> {
+        TimeSeries::check(&submodel)?;
+        Ok(TimeSeries {
+            submodel
+        })
+    }
+
+    ///Returns the wrapped submodel.
+    pub fn get_submodel(&self) -> &Submodel {
+        &self.submodel
+    }
+
+    ///Consumes the Time Series and returns the wrapped submodel.
+    pub fn into_submodel(self) -> Submodel {
+        self.submodel
+    }
+
+    ///Returns the submodel template of the Time Series.
+    pub fn template() -> Submodel {
+        let mut template = Submodel::new("https://admin-shell.io/idta/SubmodelTemplate/TimeSeries/1/1".to_string());
+        template.set_id_short(TimeSeries::ID_SHORT.to_string());
+        template.set_semantic_id(global_reference(TimeSeries::SEMANTIC_ID));
+        template.set_kind(ModellingKind::Template);
+        template.set_submodel_elements(vec![
+            METADATA.to_collection(TimeSeriesMetadata::template_elements()),
+            SEGMENTS.to_collection(SEGMENT_KINDS.iter()
+                .map(|(element, kind)| element.to_collection(TimeSeriesSegment::template_elements(kind)))
+                .collect())
+        ]);
+        template
+    }
+
+    ///Checks the wrapped submodel against the submodel template of the Time Series.
+    pub fn check_conformance(&self) -> ConformanceReport {
+        self.submodel.check_conformance(&TimeSeries::template())
+    }
+
+    ///Returns the metadata with the record definition.
+    pub fn get_metadata(&self) -> Option<TimeSeriesMetadata> {
+        self.submodel.get_collection(METADATA.id_short, METADATA.semantic_id)
+            .map(|collection| TimeSeriesMetadata {
+                collection: collection.clone()
+            })
+    }
+
+    ///Sets the metadata with the record definition.
+    ///
+    /// [metadata]: metadata with the record definition
+    pub fn set_metadata(&mut self, metadata: TimeSeriesMetadata) {
+        self.submodel.set_element(METADATA.id_short, METADATA.semantic_id,
+                                  Some(SubmodelElement::SubmodelElementCollection(metadata.into_collection())));
+    }
+
+    ///Returns the segments of all kinds.
+    pub fn get_segments(&self) -> Vec<TimeSeriesSegment> {
+        self.submodel.get_collection(SEGMENTS.id_short, SEGMENTS.semantic_id)
+            .map(|segments| SEGMENT_KINDS.iter()
+                .flat_map(|(element, _)| segments.get_collections(element.id_short, element.semantic_id))
+                .map(|collection| TimeSeriesSegment {
+                    collection: collection.clone()
+                })
+                .collect())
+            .unwrap_or_default()
+    }
+
+    ///Returns the segment with the idShort.
+    ///
+    /// [id_short]: idShort of the segment, e.g. `InternalSegment01`
+    pub fn get_segment(&self, id_short: &str) -> Option<TimeSeriesSegment> {
+        self.get_segments().into_iter()
+            .find(|segment| segment.get_collection().get_id_short().is_some_and(|segment_id_short| segment_id_short == id_short))
+    }
+
+    ///Sets the segments. The segments get idShorts numbered per kind, e.g. `InternalSegment01`.
+    ///
+    /// [segments]: segments of all kinds
+    pub fn set_segments(&mut self, segments: Vec<TimeSeriesSegment>) {
+        let mut collection = self.submodel.get_collection(SEGMENTS.id_short, SEGMENTS.semantic_id).cloned()
+            .unwrap_or_else(SubmodelElementCollection::new);
+        for (element, kind) in &SEGMENT_KINDS {
+            let elements = segments.iter()
+                .filter(|segment| segment.get_kind().as_ref() == Some(kind))
+                .map(|segment| SubmodelElement::SubmodelElementCollection(segment.get_collection().clone()))
+                .collect();
+            collection.set_elements(element.id_short, element.semantic_id, elements);
+        }
+        self.submodel.set_element(SEGMENTS.id_short, SEGMENTS.semantic_id, Some(SubmodelElement::SubmodelElementCollection(collection)));
+    }
+
+    ///Adds a segment after the segments of its kind. The segment gets the next free idShort of its
+    /// kind, e.g. `InternalSegment02`. Segments of unknown kind are ignored.
+    ///
+    /// [segment]: segment to add
+    pub fn add_segment(&mut self, segment: TimeSeriesSegment) {
+        let Some(kind) = segment.get_kind() else {
+            return;
+        };
+        if self.submodel.get_collection(SEGMENTS.id_short, SEGMENTS.semantic_id).is_none() {
+            self.set_segments(Vec::new());
+        }
+        if let Some(SubmodelElement::SubmodelElementCollection(segments)) = self.submodel.find_mut_element(SEGMENTS.id_short, SEGMENTS.semantic_id) {
+            segments.add_element(kind.get_id_short(), kind.get_semantic_id(),
+                                 SubmodelElement::SubmodelElementCollection(segment.into_collection()));
+        }
+    }
+
+    ///Appends a record to the internal segment with the idShort.
+    ///
+    /// [id_short]: idShort of the internal segment, e.g. `InternalSegment01`
+    /// [record]: record to append
+    pub fn append_record(&mut self, id_short: &str, record: &TimeSeriesRecord) -> Result<(), TimeSeriesError> {
+        let metadata = self.get_metadata().unwrap_or_else(|| TimeSeriesMetadata::new(Vec::new(), Vec::new()));
+        let Some(SubmodelElement::SubmodelElementCollection(segments)) = self.submodel.find_mut_element(SEGMENTS.id_short, SEGMENTS.semantic_id) else {
+            return Err(TimeSeriesError::SegmentNotFound(id_short.to_string()));
+        };
+        let collection = segments.get_mut_value().iter_mut()
+            .find_map(|element| match element {
+                SubmodelElement::SubmodelElementCollection(collection)
+                if collection.get_id_short().is_some_and(|segment_id_short| segment_id_short == id_short) => Some(collection),
+                _ => None
+            })
+            .ok_or_else(|| TimeSeriesError::SegmentNotFound(id_short.to_string()))?;
+        let mut segment = TimeSeriesSegment {
+            collection: std::mem::replace(collection, SubmodelElementCollection::new())
+        };
+        let result = segment.append_record(record, &metadata);
+        *collection = segment.into_collection();
+        result
+    }
+
+    ///Reads the records of the segment with the idShort, see `TimeSeriesSegment::read_records`.
+    ///
+    /// [id_short]: idShort of the segment, e.g. `ExternalSegment01`
+    /// [store]: file store containing the file of an external segment, e.g. an AASX package
+    pub fn read_records(&self, id_short: &str, store: &dyn TFileStore) -> Result<Vec<TimeSeriesRecord>, TimeSeriesError> {
+        let segment = self.get_segment(id_short).ok_or_else(|| TimeSeriesError::SegmentNotFound(id_short.to_string()))?;
+        let metadata = self.get_metadata().unwrap_or_else(|| TimeSeriesMetadata::new(Vec::new(), Vec::new()));
+        segment.read_records(&metadata, store)
+    }
+
+    fn check(submodel: &Submodel) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(submodel, "")?;
+        }
+        for element in METADATA.check(submodel, "")? {
+            if let SubmodelElement::SubmodelElementCollection(collection) = element {
+                TimeSeriesMetadata::check(collection, METADATA.id_short)?;
+            }
+        }
+        if let Some(segments) = submodel.get_collection(SEGMENTS.id_short, SEGMENTS.semantic_id) {
+            for (element, _) in &SEGMENT_KINDS {
+                for collection in segments.get_collections(element.id_short, element.semantic_id) {
+                    let path = join_path(SEGMENTS.id_short, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default());
+                    TimeSeriesSegment::check(collection, &path)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+const METADATA: TemplateElement =
+    TemplateElement::new("Metadata", TimeSeriesMetadata::SEMANTIC_ID, AasSubmodelElements::SubmodelElementCollection, Cardinality::One);
+const SEGMENTS: TemplateElement =
+    TemplateElement::new("Segments", "https://admin-shell.io/idta/TimeSeries/Segments/1/1", AasSubmodelElements::SubmodelElementCollection, Cardinality::One);
+const INTERNAL_SEGMENT: TemplateElement =
+    TemplateElement::new(SegmentKind::Internal.get_id_short(), SegmentKind::Internal.get_semantic_id(), AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToMany);
+const LINKED_SEGMENT: TemplateElement =
+    TemplateElement::new(SegmentKind::Linked.get_id_short(), SegmentKind::Linked.get_semantic_id(), AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToMany);
+const EXTERNAL_SEGMENT: TemplateElement =
+    TemplateElement::new(SegmentKind::External.get_id_short(), SegmentKind::External.get_semantic_id(), AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToMany);
+
+const ELEMENTS: [&TemplateElement; 2] = [&METADATA, &SEGMENTS];
+const SEGMENT_KINDS: [(&TemplateElement, SegmentKind); 3] = [(&INTERNAL_SEGMENT, SegmentKind::Internal),
+    (&LINKED_SEGMENT, SegmentKind::Linked), (&EXTERNAL_SEGMENT, SegmentKind::External)];
@@ -0,0 +1,160 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::multi_language_text_type::MultiLanguageTextType;
+use crate::structs::property::Property;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, TemplateElement, ARBITRARY};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on the metadata of the Time Series with the record definition, i.e. the variables
+/// every record consists of.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct TimeSeriesMetadata {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl TimeSeriesMetadata {
+    ///Semantic ID of the metadata.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/idta/TimeSeries/Metadata/1/1";
+    ///Semantic IDs of the variables of a record definition that state the point in time of a
+    /// record: UTC time, TAI time, relative point in time and relative time duration.
+    pub const TIME_SEMANTIC_IDS: [&str; 4] = [
+        "https://admin-shell.io/idta/TimeSeries/UtcTime/1/1",
+        "https://admin-shell.io/idta/TimeSeries/TaiTime/1/1",
+        "https://admin-shell.io/idta/TimeSeries/RelativePointInTime/1/1",
+        "https://admin-shell.io/idta/TimeSeries/RelativeTimeDuration/1/1"
+    ];
+
+    ///Creates new metadata.
+    ///
+    /// [name]: name of the time series
+    /// [variables]: variables of the record definition, i.e. properties with idShort, semantic ID
+    /// and data type but without value, one of them stating the point in time
+    pub fn new(name: Vec<MultiLanguageTextType>, variables: Vec<Property>) -> TimeSeriesMetadata {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_semantic_id(global_reference(TimeSeriesMetadata::SEMANTIC_ID));
+        let mut metadata = TimeSeriesMetadata {
+            collection
+        };
+        metadata.set_name(name);
+        metadata.set_variables(variables);
+        metadata
+    }
+
+    ///Wraps a submodel element collection after checking the elements of the metadata.
+    ///
+    /// [collection]: collection containing the metadata
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<TimeSeriesMetadata, ConversionError> {
+        TimeSeriesMetadata::check(&collection, "Metadata")?;
+        Ok(TimeSeriesMetadata {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the metadata and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the name of the time series.
+    pub fn get_name(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(NAME.id_short, NAME.semantic_id)
+    }
+
+    ///Sets the name of the time series.
+    ///
+    /// [name]: name of the time series
+    pub fn set_name(&mut self, name: Vec<MultiLanguageTextType>) {
+        self.collection.set_multi_language_value(NAME.id_short, NAME.semantic_id, Some(name));
+    }
+
+    ///Returns the description of the time series.
+    pub fn get_description(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(DESCRIPTION.id_short, DESCRIPTION.semantic_id)
+    }
+
+    ///Sets the description of the time series. Passing None removes it.
+    ///
+    /// [description]: description of the time series
+    pub fn set_description(&mut self, description: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(DESCRIPTION.id_short, DESCRIPTION.semantic_id, description);
+    }
+
+    ///Returns the variables of the record definition.
+    pub fn get_variables(&self) -> Vec<&Property> {
+        self.collection.get_collection(RECORD.id_short, RECORD.semantic_id)
+            .map(|record| record.get_value().iter()
+                .filter_map(|element| match element {
+                    SubmodelElement::Property(property) => Some(property),
+                    _ => None
+                })
+                .collect())
+            .unwrap_or_default()
+    }
+
+    ///Sets the variables of the record definition.
+    ///
+    /// [variables]: properties with idShort, semantic ID and data type but without value, one of
+    /// them stating the point in time
+    pub fn set_variables(&mut self, variables: Vec<Property>) {
+        let mut record = SubmodelElementCollection::new();
+        record.set_value(variables.into_iter().map(SubmodelElement::Property).collect());
+        self.collection.set_element(RECORD.id_short, RECORD.semantic_id, Some(SubmodelElement::SubmodelElementCollection(record)));
+    }
+
+    ///Returns the variable of the record definition stating the point in time of a record, i.e.
+    /// the first variable with one of the time semantic IDs.
+    pub fn get_time_variable(&self) -> Option<&Property> {
+        self.get_variables().into_iter().find(|variable| is_time_variable(variable))
+    }
+
+    ///Returns the elements of the metadata in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        vec![
+            NAME.to_element(),
+            DESCRIPTION.to_element(),
+            RECORD.to_collection(vec![TIME.to_element(), ARBITRARY.to_element()])
+        ]
+    }
+
+    ///Checks the elements of the metadata.
+    ///
+    /// [collection]: collection containing the metadata
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        Ok(())
+    }
+}
+
+///Returns whether the variable states the point in time of a record.
+pub(crate) fn is_time_variable(variable: &Property) -> bool {
+    variable.get_semantic_id()
+        .and_then(|semantic_id| semantic_id.get_keys().first())
+        .is_some_and(|key| TimeSeriesMetadata::TIME_SEMANTIC_IDS.contains(&key.get_value().as_str()))
+}
+
+const NAME: TemplateElement =
+    TemplateElement::new("Name", "https://admin-shell.io/idta/TimeSeries/Metadata/Name/1/1", AasSubmodelElements::MultiLanguageProperty, Cardinality::One);
+const DESCRIPTION: TemplateElement =
+    TemplateElement::new("Description", "https://admin-shell.io/idta/TimeSeries/Metadata/Description/1/1", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const RECORD: TemplateElement =
+    TemplateElement::new("Record", "https://admin-shell.io/idta/TimeSeries/Record/1/1", AasSubmodelElements::SubmodelElementCollection, Cardinality::One);
+const TIME: TemplateElement =
+    TemplateElement::property("Time", TimeSeriesMetadata::TIME_SEMANTIC_IDS[0], DataTypeDefXsd::DateTime, Cardinality::ZeroToOne);
+
+const ELEMENTS: [&TemplateElement; 3] = [&NAME, &DESCRIPTION, &RECORD];
@@ -0,0 +1,52 @@
+use crate::structs::property::Property;
+use crate::traits::property_value::TPropertyValue;
+use crate::traits::referable::TReferable;
+
+///Record of a time series, i.e. the point in time and the values of the variables at that point.
+/// The values are properties with the idShorts and data types of the variables of the record
+/// definition in the metadata.
+#[derive(PartialEq, Clone)]
+pub struct TimeSeriesRecord {
+    ///Point in time of the record, e.g. a UTC timestamp as xs:dateTime.
+    time: Option<String>,
+    ///Values of the variables.
+    values: Vec<Property>
+}
+
+impl TimeSeriesRecord {
+    ///Creates a new instance of the struct.
+    ///
+    /// [time]: point in time of the record, e.g. a UTC timestamp as xs:dateTime
+    /// [values]: values of the variables as properties with the idShorts of the variables
+    pub fn new(time: Option<String>, values: Vec<Property>) -> TimeSeriesRecord {
+        TimeSeriesRecord {
+            time,
+            values
+        }
+    }
+
+    ///Returns the point in time of the record.
+    pub fn get_time(&self) -> Option<&String> {
+        self.time.as_ref()
+    }
+
+    ///Returns the values of the variables.
+    pub fn get_values(&self) -> &Vec<Property> {
+        &self.values
+    }
+
+    ///Returns the value of the variable with the idShort.
+    ///
+    /// [id_short]: idShort of the variable
+    pub fn get_value(&self, id_short: &str) -> Option<&Property> {
+        self.values.iter().find(|value| value.get_id_short().is_some_and(|value_id_short| value_id_short == id_short))
+    }
+
+    ///Returns the value of the variable with the idShort converted to a Rust type. Returns None if
+    /// the variable has no value or the value cannot be converted.
+    ///
+    /// [id_short]: idShort of the variable
+    pub fn get_typed_value<T: TPropertyValue>(&self, id_short: &str) -> Option<T> {
+        self.get_value(id_short).and_then(|value| value.get_typed_value())
+    }
+}
@@ -0,0 +1,736 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::segment_kind::SegmentKind;
+use crate::enumerations::time_series_error::TimeSeriesError;
+use crate::structs::blob::Blob;
+use crate::structs::file::File;
+use crate::structs::multi_language_text_type::MultiLanguageTextType;
+use crate::structs::property::Property;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, TemplateElement, ARBITRARY};
+use crate::structs::time_series_metadata::TimeSeriesMetadata;
+use crate::structs::time_series_record::TimeSeriesRecord;
+use crate::traits::file_store::TFileStore;
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on a segment of the Time Series, i.e. a part of the records of the time series. The
+/// kind of the segment states where the records are stored: internal segments contain them, linked
+/// segments refer to an endpoint providing them and external segments store them in a file or
+/// blob.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct TimeSeriesSegment {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl TimeSeriesSegment {
+    ///Creates a new segment without records.
+    ///
+    /// [kind]: kind of the segment
+    pub fn new(kind: SegmentKind) -> TimeSeriesSegment {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_semantic_id(global_reference(kind.get_semantic_id()));
+        if kind == SegmentKind::Internal {
+            collection.set_element(RECORDS.id_short, RECORDS.semantic_id, Some(RECORDS.to_collection(Vec::new())));
+        }
+        TimeSeriesSegment {
+            collection
+        }
+    }
+
+    ///Creates a new linked segment.
+    ///
+    /// [endpoint]: endpoint providing the records, e.g. the URL of a time series database
+    /// [query]: query selecting the records of the segment at the endpoint
+    pub fn new_linked(endpoint: String, query: String) -> TimeSeriesSegment {
+        let mut segment = TimeSeriesSegment::new(SegmentKind::Linked);
+        segment.set_endpoint(endpoint);
+        segment.set_query(query);
+        segment
+    }
+
+    ///Creates a new external segment whose records are stored in a file, e.g. a CSV file.
+    ///
+    /// [file]: file containing the records
+    pub fn new_external(file: File) -> TimeSeriesSegment {
+        let mut segment = TimeSeriesSegment::new(SegmentKind::External);
+        segment.set_file(Some(file));
+        segment
+    }
+
+    ///Wraps a submodel element collection after checking the elements of a segment of the kind
+    /// given by its semantic ID.
+    ///
+    /// [collection]: collection containing a segment
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<TimeSeriesSegment, ConversionError> {
+        TimeSeriesSegment::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(TimeSeriesSegment {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the segment and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the kind of the segment given by its semantic ID.
+    pub fn get_kind(&self) -> Option<SegmentKind> {
+        self.collection.get_semantic_id()
+            .and_then(|semantic_id| semantic_id.get_keys().first())
+            .and_then(|key| SegmentKind::from_semantic_id(key.get_value()))
+    }
+
+    ///Returns the name of the segment.
+    pub fn get_name(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(NAME.id_short, NAME.semantic_id)
+    }
+
+    ///Sets the name of the segment. Passing None removes it.
+    ///
+    /// [name]: name of the segment
+    pub fn set_name(&mut self, name: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(NAME.id_short, NAME.semantic_id, name);
+    }
+
+    ///Returns the description of the segment.
+    pub fn get_description(&self) -> Option<&Vec<MultiLanguageTextType>> {
+        self.collection.get_multi_language_value(DESCRIPTION.id_short, DESCRIPTION.semantic_id)
+    }
+
+    ///Sets the description of the segment. Passing None removes it.
+    ///
+    /// [description]: description of the segment
+    pub fn set_description(&mut self, description: Option<Vec<MultiLanguageTextType>>) {
+        self.collection.set_multi_language_value(DESCRIPTION.id_short, DESCRIPTION.semantic_id, description);
+    }
+
+    ///Returns the number of records of the segment.
+    pub fn get_record_count(&self) -> Option<i64> {
+        self.collection.get_property_value(RECORD_COUNT.id_short, RECORD_COUNT.semantic_id)
+    }
+
+    ///Sets the number of records of the segment. Passing None removes it.
+    ///
+    /// [record_count]: number of records of the segment
+    pub fn set_record_count(&mut self, record_count: Option<i64>) {
+        RECORD_COUNT.set_property_value(&mut self.collection, record_count.as_ref());
+    }
+
+    ///Returns the point in time of the first record of the segment as xs:dateTime.
+    pub fn get_start_time(&self) -> Option<String> {
+        self.collection.get_property_value(START_TIME.id_short, START_TIME.semantic_id)
+    }
+
+    ///Sets the point in time of the first record of the segment. Passing None removes it.
+    ///
+    /// [start_time]: point in time of the first record as xs:dateTime
+    pub fn set_start_time(&mut self, start_time: Option<String>) {
+        START_TIME.set_property_value(&mut self.collection, start_time.as_ref());
+    }
+
+    ///Returns the point in time of the last record of the segment as xs:dateTime.
+    pub fn get_end_time(&self) -> Option<String> {
+        self.collection.get_property_value(END_TIME.id_short, END_TIME.semantic_id)
+    }
+
+    ///Sets the point in time of the last record of the segment. Passing None removes it.
+    ///
+    /// [end_time]: point in time of the last record as xs:dateTime
+    pub fn set_end_time(&mut self, end_time: Option<String>) {
+        END_TIME.set_property_value(&mut self.collection, end_time.as_ref());
+    }
+
+    ///Returns the period covered by the segment as xs:duration.
+    pub fn get_duration(&self) -> Option<String> {
+        self.collection.get_property_value(DURATION.id_short, DURATION.semantic_id)
+    }
+
+    ///Sets the period covered by the segment. Passing None removes it.
+    ///
+    /// [duration]: period covered by the segment as xs:duration, e.g. PT1H
+    pub fn set_duration(&mut self, duration: Option<String>) {
+        DURATION.set_property_value(&mut self.collection, duration.as_ref());
+    }
+
+    ///Returns the time between two records in milliseconds if the records are equidistant.
+    pub fn get_sampling_interval(&self) -> Option<i64> {
+        self.collection.get_property_value(SAMPLING_INTERVAL.id_short, SAMPLING_INTERVAL.semantic_id)
+    }
+
+    ///Sets the time between two records in milliseconds. Passing None removes it.
+    ///
+    /// [sampling_interval]: time between two records in milliseconds
+    pub fn set_sampling_interval(&mut self, sampling_interval: Option<i64>) {
+        SAMPLING_INTERVAL.set_property_value(&mut self.collection, sampling_interval.as_ref());
+    }
+
+    ///Returns the number of records per second if the records are equidistant.
+    pub fn get_sampling_rate(&self) -> Option<i64> {
+        self.collection.get_property_value(SAMPLING_RATE.id_short, SAMPLING_RATE.semantic_id)
+    }
+
+    ///Sets the number of records per second. Passing None removes it.
+    ///
+    /// [sampling_rate]: number of records per second
+    pub fn set_sampling_rate(&mut self, sampling_rate: Option<i64>) {
+        SAMPLING_RATE.set_property_value(&mut self.collection, sampling_rate.as_ref());
+    }
+
+    ///Returns the state of the segment, e.g. `in progress` or `completed`.
+    pub fn get_state(&self) -> Option<String> {
+        self.collection.get_property_value(STATE.id_short, STATE.semantic_id)
+    }
+
+    ///Sets the state of the segment. Passing None removes it.
+    ///
+    /// [state]: state of the segment, e.g. `in progress` or `completed`
+    pub fn set_state(&mut self, state: Option<String>) {
+        STATE.set_property_value(&mut self.collection, state.as_ref());
+    }
+
+    ///Returns the point in time the segment was last updated as xs:dateTime.
+    pub fn get_last_update(&self) -> Option<String> {
+        self.collection.get_property_value(LAST_UPDATE.id_short, LAST_UPDATE.semantic_id)
+    }
+
+    ///Sets the point in time the segment was last updated. Passing None removes it.
+    ///
+    /// [last_update]: point in time of the last update as xs:dateTime
+    pub fn set_last_update(&mut self, last_update: Option<String>) {
+        LAST_UPDATE.set_property_value(&mut self.collection, last_update.as_ref());
+    }
+
+    ///Returns the endpoint providing the records of a linked segment.
+    pub fn get_endpoint(&self) -> Option<String> {
+        self.collection.get_property_value(ENDPOINT.id_short, ENDPOINT.semantic_id)
+    }
+
+    ///Sets the endpoint providing the records of a linked segment.
+    ///
+    /// [endpoint]: endpoint providing the records, e.g. the URL of a time series database
+    pub fn set_endpoint(&mut self, endpoint: String) {
+        ENDPOINT.set_property_value(&mut self.collection, Some(&endpoint));
+    }
+
+    ///Returns the query selecting the records of a linked segment at the endpoint.
+    pub fn get_query(&self) -> Option<String> {
+        self.collection.get_property_value(QUERY.id_short, QUERY.semantic_id)
+    }
+
+    ///Sets the query selecting the records of a linked segment at the endpoint.
+    ///
+    /// [query]: query selecting the records of the segment at the endpoint
+    pub fn set_query(&mut self, query: String) {
+        QUERY.set_property_value(&mut self.collection, Some(&query));
+    }
+
+    ///Returns the file containing the records of an external segment.
+    pub fn get_file(&self) -> Option<&File> {
+        self.collection.get_file(FILE.id_short, FILE.semantic_id)
+    }
+
+    ///Sets the file containing the records of an external segment. Passing None removes it.
+    ///
+    /// [file]: file containing the records, e.g. a CSV file
+    pub fn set_file(&mut self, file: Option<File>) {
+        self.collection.set_element(FILE.id_short, FILE.semantic_id, file.map(SubmodelElement::File));
+    }
+
+    ///Returns the blob containing the records of an external segment.
+    pub fn get_blob(&self) -> Option<&Blob> {
+        match self.collection.find_element(BLOB.id_short, BLOB.semantic_id)? {
+            SubmodelElement::Blob(blob) => Some(blob),
+            _ => None
+        }
+    }
+
+    ///Sets the blob containing the records of an external segment. Passing None removes it.
+    ///
+    /// [blob]: blob containing the records, e.g. as JSON
+    pub fn set_blob(&mut self, blob: Option<Blob>) {
+        self.collection.set_element(BLOB.id_short, BLOB.semantic_id, blob.map(SubmodelElement::Blob));
+    }
+
+    ///Returns the records contained in an internal segment. The value of the time variable of the
+    /// record definition is the point in time of a record, all other properties are its values.
+    ///
+    /// [metadata]: metadata of the time series with the record definition
+    pub fn get_records(&self, metadata: &TimeSeriesMetadata) -> Vec<TimeSeriesRecord> {
+        let time_id_short = metadata.get_time_variable().and_then(|variable| variable.get_id_short());
+        let Some(records) = self.collection.get_collection(RECORDS.id_short, RECORDS.semantic_id) else {
+            return Vec::new();
+        };
+        records.get_collections(RECORD.id_short, RECORD.semantic_id).into_iter()
+            .map(|record| {
+                let mut time = None;
+                let mut values = Vec::new();
+                for element in record.get_value() {
+                    if let SubmodelElement::Property(property) = element {
+                        if property.get_id_short().is_some() && property.get_id_short() == time_id_short {
+                            time = property.get_value().cloned();
+                        } else {
+                            values.push(property.clone());
+                        }
+                    }
+                }
+                TimeSeriesRecord::new(time, values)
+            })
+            .collect()
+    }
+
+    ///Appends a record to an internal segment. The values of the record get the semantic IDs and
+    /// data types of their variables in the record definition. The record count is updated and, if
+    /// the time variable is of type xs:dateTime, the start and end time as well.
+    ///
+    /// [record]: record to append
+    /// [metadata]: metadata of the time series with the record definition
+    pub fn append_record(&mut self, record: &TimeSeriesRecord, metadata: &TimeSeriesMetadata) -> Result<(), TimeSeriesError> {
+        if self.get_kind() != Some(SegmentKind::Internal) {
+            return Err(TimeSeriesError::UnsupportedSegment(self.collection.get_id_short().cloned().unwrap_or_default()));
+        }
+        let time_variable = metadata.get_time_variable();
+        let mut elements = Vec::new();
+        if let (Some(variable), Some(time)) = (time_variable, record.get_time()) {
+            elements.push(SubmodelElement::Property(with_value(variable, Some(time))?));
+        }
+        for value in record.get_values() {
+            let id_short = value.get_id_short().cloned().unwrap_or_default();
+            let variable = metadata.get_variables().into_iter()
+                .find(|variable| variable.get_id_short() == Some(&id_short))
+                .ok_or(TimeSeriesError::UnknownVariable(id_short))?;
+            elements.push(SubmodelElement::Property(with_value(variable, value.get_value())?));
+        }
+        let mut record_collection = SubmodelElementCollection::new();
+        record_collection.set_value(elements);
+
+        if self.collection.get_collection(RECORDS.id_short, RECORDS.semantic_id).is_none() {
+            self.collection.set_element(RECORDS.id_short, RECORDS.semantic_id, Some(RECORDS.to_collection(Vec::new())));
+        }
+        let Some(SubmodelElement::SubmodelElementCollection(records)) = self.collection.find_mut_element(RECORDS.id_short, RECORDS.semantic_id) else {
+            unreachable!("the records were added above")
+        };
+        records.add_element(RECORD.id_short, RECORD.semantic_id, SubmodelElement::SubmodelElementCollection(record_collection));
+        let record_count = records.get_collections(RECORD.id_short, RECORD.semantic_id).len() as i64;
+        self.set_record_count(Some(record_count));
+        if let (Some(variable), Some(time)) = (time_variable, record.get_time())
+            && *variable.get_value_type() == DataTypeDefXsd::DateTime {
+            if self.get_start_time().is_none() {
+                self.set_start_time(Some(time.clone()));
+            }
+            self.set_end_time(Some(time.clone()));
+        }
+        Ok(())
+    }
+
+    ///Reads the records of the segment. The records of internal segments are returned as they
+    /// are. The file or blob of external segments is parsed as CSV or JSON depending on its content
+    /// type or, if it has none, on the extension of the file path. CSV data starts with a header
+    /// row with the idShorts of the variables, JSON data is an array of objects with the idShorts
+    /// of the variables as keys. The values are checked against the data types of the record
+    /// definition. Linked segments cannot be read.
+    ///
+    /// [metadata]: metadata of the time series with the record definition
+    /// [store]: file store containing the file of an external segment, e.g. an AASX package
+    pub fn read_records(&self, metadata: &TimeSeriesMetadata, store: &dyn TFileStore) -> Result<Vec<TimeSeriesRecord>, TimeSeriesError> {
+        let id_short = self.collection.get_id_short().cloned().unwrap_or_default();
+        match self.get_kind() {
+            Some(SegmentKind::Internal) => Ok(self.get_records(metadata)),
+            Some(SegmentKind::External) => {
+                let (data, content_type, path) = match (self.get_file(), self.get_blob()) {
+                    (Some(file), _) => (store.resolve(file).map_err(TimeSeriesError::FileResolution)?,
+                                        file.get_content_type(), file.get_value()),
+                    (None, Some(blob)) => (blob.get_value().clone(), blob.get_content_type(), None),
+                    (None, None) => return Err(TimeSeriesError::MissingData(id_short))
+                };
+                let text = String::from_utf8(data).map_err(|error| TimeSeriesError::InvalidData(error.to_string()))?;
+                let format = content_type.or(path).map(|format| format.to_lowercase()).unwrap_or_default();
+                let rows = if format.contains("csv") {
+                    parse_csv(&text)?
+                } else if format.contains("json") {
+                    parse_json(&text)?
+                } else {
+                    return Err(TimeSeriesError::UnsupportedFormat(format));
+                };
+                rows.into_iter().map(|row| to_record(row, metadata)).collect()
+            },
+            _ => Err(TimeSeriesError::UnsupportedSegment(id_short))
+        }
+    }
+
+    ///Returns the elements of a segment of the kind in the submodel template.
+    ///
+    /// [kind]: kind of the segment
+    pub(crate) fn template_elements(kind: &SegmentKind) -> Vec<SubmodelElement> {
+        COMMON_ELEMENTS.iter().map(|element| element.to_element())
+            .chain(match kind {
+                SegmentKind::Internal => vec![RECORDS.to_collection(vec![RECORD.to_collection(vec![ARBITRARY.to_element()])])],
+                SegmentKind::Linked => vec![ENDPOINT.to_element(), QUERY.to_element()],
+                SegmentKind::External => vec![FILE.to_element(), BLOB.to_element()]
+            })
+            .collect()
+    }
+
+    ///Checks the elements of a segment of the kind given by its semantic ID.
+    ///
+    /// [collection]: collection containing a segment
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        let kind = collection.get_semantic_id()
+            .and_then(|semantic_id| semantic_id.get_keys().first())
+            .and_then(|key| SegmentKind::from_semantic_id(key.get_value()))
+            .ok_or_else(|| ConversionError::UnexpectedElementType(path.to_string()))?;
+        let elements: &[&TemplateElement] = match kind {
+            SegmentKind::Internal => &[&RECORDS],
+            SegmentKind::Linked => &[&ENDPOINT, &QUERY],
+            SegmentKind::External => &[&FILE, &BLOB]
+        };
+        for element in COMMON_ELEMENTS.iter().chain(elements) {
+            element.check(collection, path)?;
+        }
+        Ok(())
+    }
+}
+
+///Returns a copy of the variable with the value after checking the value against the data type of
+/// the variable.
+fn with_value(variable: &Property, value: Option<&String>) -> Result<Property, TimeSeriesError> {
+    let mut property = variable.clone();
+    if let Some(value) = value {
+        if !variable.get_value_type().is_valid_value(value) {
+            let id_short = variable.get_id_short().cloned().unwrap_or_default();
+            return Err(TimeSeriesError::InvalidValue(id_short, value.clone()));
+        }
+        property.set_value(value.clone());
+    }
+    Ok(property)
+}
+
+///Row of external data, i.e. pairs of variable idShorts and values.
+type Row = Vec<(String, Option<String>)>;
+
+///Converts a row of external data, i.e. pairs of variable idShorts and values, into a record. Like
+/// for internal segments, the value of the time variable of the record definition is the point in
+/// time of the record.
+fn to_record(row: Row, metadata: &TimeSeriesMetadata) -> Result<TimeSeriesRecord, TimeSeriesError> {
+    let variables = metadata.get_variables();
+    let time_id_short = metadata.get_time_variable().and_then(|variable| variable.get_id_short());
+    let mut time = None;
+    let mut values = Vec::new();
+    for (id_short, value) in row {
+        let variable = variables.iter()
+            .find(|variable| variable.get_id_short() == Some(&id_short))
+            .ok_or(TimeSeriesError::UnknownVariable(id_short))?;
+        let property = with_value(variable, value.as_ref())?;
+        if variable.get_id_short() == time_id_short {
+            time = value;
+        } else {
+            values.push(property);
+        }
+    }
+    Ok(TimeSeriesRecord::new(time, values))
+}
+
+///Parses CSV data with a header row into rows of idShorts and values. Fields are separated by
+/// commas and may be quoted with double quotes; empty fields have no value.
+fn parse_csv(text: &str) -> Result<Vec<Row>, TimeSeriesError> {
+    let mut lines = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut characters = text.chars().peekable();
+    while let Some(character) = characters.next() {
+        match character {
+            '"' if quoted && characters.peek() == Some(&'"') => {
+                characters.next();
+                field.push('"');
+            },
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            '\r' if !quoted => {},
+            '\n' if !quoted => {
+                fields.push(std::mem::take(&mut field));
+                lines.push(std::mem::take(&mut fields));
+            },
+            _ => field.push(character)
+        }
+    }
+    if quoted {
+        return Err(TimeSeriesError::InvalidData("unterminated quoted field".to_string()));
+    }
+    if !field.is_empty() || !fields.is_empty() {
+        fields.push(field);
+        lines.push(fields);
+    }
+    lines.retain(|line| !(line.len() == 1 && line[0].trim().is_empty()));
+
+    let mut lines = lines.into_iter();
+    let header: Vec<String> = lines.next().unwrap_or_default().into_iter().map(|name| name.trim().to_string()).collect();
+    lines.enumerate()
+        .map(|(index, line)| {
+            if line.len() != header.len() {
+                return Err(TimeSeriesError::InvalidData(format!("row {} has {} fields, expected {}", index + 1, line.len(), header.len())));
+            }
+            Ok(header.iter().cloned()
+                .zip(line.into_iter().map(|value| Some(value.trim().to_string()).filter(|value| !value.is_empty())))
+                .collect())
+        })
+        .collect()
+}
+
+///Parses JSON data, i.e. an array of objects, into rows of idShorts and values. Null values have
+/// no value, numbers and booleans are converted to their lexical representation.
+fn parse_json(text: &str) -> Result<Vec<Row>, TimeSeriesError> {
+    let json: serde_json::Value = serde_json::from_str(text).map_err(|error| TimeSeriesError::InvalidData(error.to_string()))?;
+    let serde_json::Value::Array(records) = json else {
+        return Err(TimeSeriesError::InvalidData("expected an array of records".to_string()));
+    };
+    records.into_iter()
+        .map(|record| {
+            let serde_json::Value::Object(fields) = record else {
+                return Err(TimeSeriesError::InvalidData("expected a record object".to_string()));
+            };
+            fields.into_iter()
+                .map(|(id_short, value)| match value {
+                    serde_json::Value::Null => Ok((id_short, None)),
+                    serde_json::Value::String(value) => Ok((id_short, Some(value))),
+                    serde_json::Value::Number(_) | serde_json::Value::Bool(_) => Ok((id_short, Some(value.to_string()))),
+                    _ => Err(TimeSeriesError::InvalidData(format!("value of '{}' is no scalar", id_short)))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+const NAME: TemplateElement =
+    TemplateElement::new("Name", "https://admin-shell.io/idta/TimeSeries/Segment/Name/1/1", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const DESCRIPTION: TemplateElement =
+    TemplateElement::new("Description", "https://admin-shell.io/idta/TimeSeries/Segment/Description/1/1", AasSubmodelElements::MultiLanguageProperty, Cardinality::ZeroToOne);
+const RECORD_COUNT: TemplateElement =
+    TemplateElement::property("RecordCount", "https://admin-shell.io/idta/TimeSeries/Segment/RecordCount/1/1", DataTypeDefXsd::Long, Cardinality::ZeroToOne);
+const START_TIME: TemplateElement =
+    TemplateElement::property("StartTime", "https://admin-shell.io/idta/TimeSeries/Segment/StartTime/1/1", DataTypeDefXsd::DateTime, Cardinality::ZeroToOne);
+const END_TIME: TemplateElement =
+    TemplateElement::property("EndTime", "https://admin-shell.io/idta/TimeSeries/Segment/EndTime/1/1", DataTypeDefXsd::DateTime, Cardinality::ZeroToOne);
+const DURATION: TemplateElement =
+    TemplateElement::property("Duration", "https://admin-shell.io/idta/TimeSeries/Segment/Duration/1/1", DataTypeDefXsd::Duration, Cardinality::ZeroToOne);
+const SAMPLING_INTERVAL: TemplateElement =
+    TemplateElement::property("SamplingInterval", "https://admin-shell.io/idta/TimeSeries/Segment/SamplingInterval/1/1", DataTypeDefXsd::Long, Cardinality::ZeroToOne);
+const SAMPLING_RATE: TemplateElement =
+    TemplateElement::property("SamplingRate", "https://admin-shell.io/idta/TimeSeries/Segment/SamplingRate/1/1", DataTypeDefXsd::Long, Cardinality::ZeroToOne);
+const STATE: TemplateElement =
+    TemplateElement::property("State", "https://admin-shell.io/idta/TimeSeries/Segment/State/1/1", DataTypeDefXsd::String, Cardinality::ZeroToOne);
+const LAST_UPDATE: TemplateElement =
+    TemplateElement::property("LastUpdate", "https://admin-shell.io/idta/TimeSeries/Segment/LastUpdate/1/1", DataTypeDefXsd::DateTime, Cardinality::ZeroToOne);
+const RECORDS: TemplateElement =
+    TemplateElement::new("Records", "https://admin-shell.io/idta/TimeSeries/Records/1/1", AasSubmodelElements::SubmodelElementCollection, Cardinality::One);
+const RECORD: TemplateElement =
+    TemplateElement::new("Record{00}", "https://admin-shell.io/idta/TimeSeries/Record/1/1", AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToMany);
+const ENDPOINT: TemplateElement =
+    TemplateElement::property("Endpoint", "https://admin-shell.io/idta/TimeSeries/Endpoint/1/1", DataTypeDefXsd::AnyUri, Cardinality::One);
+const QUERY: TemplateElement =
+    TemplateElement::property("Query", "https://admin-shell.io/idta/TimeSeries/Query/1/1", DataTypeDefXsd::String, Cardinality::One);
+const FILE: TemplateElement =
+    TemplateElement::new("File", "https://admin-shell.io/idta/TimeSeries/File/1/1", AasSubmodelElements::File, Cardinality::ZeroToOne);
+const BLOB: TemplateElement =
+    TemplateElement::new("Blob", "https://admin-shell.io/idta/TimeSeries/Blob/1/1", AasSubmodelElements::Blob, Cardinality::ZeroToOne);
+
+const COMMON_ELEMENTS: [&TemplateElement; 10] = [&NAME, &DESCRIPTION, &RECORD_COUNT, &START_TIME, &END_TIME, &DURATION,
+    &SAMPLING_INTERVAL, &SAMPLING_RATE, &STATE, &LAST_UPDATE];
+
+
+#[cfg(test)]
+mod tests {
+    use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+    use crate::enumerations::file_resolution_error::FileResolutionError;
+    use crate::enumerations::segment_kind::SegmentKind;
+    use crate::enumerations::time_series_error::TimeSeriesError;
+    use crate::structs::blob::Blob;
+    use crate::structs::file::File;
+    use crate::structs::property::Property;
+    use crate::structs::template_element::global_reference;
+    use crate::structs::time_series_metadata::TimeSeriesMetadata;
+    use crate::structs::time_series_record::TimeSeriesRecord;
+    use crate::structs::time_series_segment::{parse_csv, TimeSeriesSegment};
+    use crate::traits::file_store::TFileStore;
+    use crate::traits::has_semantics::THasSemantics;
+    use crate::traits::referable::TReferable;
+
+    ///File store containing a single file.
+    struct SingleFileStore {
+        path: &'static str,
+        data: &'static str
+    }
+
+    impl TFileStore for SingleFileStore {
+        fn read_file(&self, path: &str) -> Result<Vec<u8>, FileResolutionError> {
+            if path == self.path {
+                Ok(self.data.as_bytes().to_vec())
+            } else {
+                Err(FileResolutionError::FileNotFound(path.to_string()))
+            }
+        }
+    }
+
+    fn variable(id_short: &str, semantic_id: &str, value_type: DataTypeDefXsd) -> Property {
+        let mut property = Property::new(value_type);
+        property.set_id_short(id_short.to_string());
+        property.set_semantic_id(global_reference(semantic_id));
+        property
+    }
+
+    ///Returns metadata with the UTC time, a temperature and the TAI time, which is a value since
+    /// only the first time variable states the point in time.
+    fn metadata() -> TimeSeriesMetadata {
+        TimeSeriesMetadata::new(Vec::new(), vec![
+            variable("Time", TimeSeriesMetadata::TIME_SEMANTIC_IDS[0], DataTypeDefXsd::DateTime),
+            variable("Temperature", "https://example.com/Temperature", DataTypeDefXsd::Double),
+            variable("TaiTime", TimeSeriesMetadata::TIME_SEMANTIC_IDS[1], DataTypeDefXsd::DateTime)
+        ])
+    }
+
+    fn external_file(path: &str, content_type: &str) -> TimeSeriesSegment {
+        let mut file = File::new();
+        file.set_value(path.to_string());
+        file.set_content_type(content_type.to_string());
+        TimeSeriesSegment::new_external(file)
+    }
+
+    fn value(record: &TimeSeriesRecord, id_short: &str) -> Option<String> {
+        record.get_value(id_short).and_then(|property| property.get_value()).cloned()
+    }
+
+    #[test]
+    fn parses_quoted_csv_fields() {
+        let rows = parse_csv("Time,Comment\r\n\"2024-01-01T00:00:00Z\",\"cold, \"\"very\"\" cold\"\r\n2024-01-01T01:00:00Z,\n").unwrap();
+
+        assert_eq!(rows, vec![
+            vec![("Time".to_string(), Some("2024-01-01T00:00:00Z".to_string())), ("Comment".to_string(), Some("cold, \"very\" cold".to_string()))],
+            vec![("Time".to_string(), Some("2024-01-01T01:00:00Z".to_string())), ("Comment".to_string(), None)]
+        ]);
+        assert_eq!(parse_csv("Time\n\"2024").unwrap_err(), TimeSeriesError::InvalidData("unterminated quoted field".to_string()));
+        assert!(matches!(parse_csv("Time,Temperature\n2024-01-01T00:00:00Z\n"), Err(TimeSeriesError::InvalidData(_))));
+    }
+
+    #[test]
+    fn reads_records_of_external_csv_file() {
+        let segment = external_file("/aasx/files/temperatures.csv", "text/csv");
+        let store = SingleFileStore {
+            path: "/aasx/files/temperatures.csv",
+            data: "Time,Temperature,TaiTime\n2024-01-01T00:00:00Z,20.5,2024-01-01T00:00:37Z\n2024-01-01T01:00:00Z,,\n"
+        };
+
+        let records = segment.read_records(&metadata(), &store).unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].get_time(), Some(&"2024-01-01T00:00:00Z".to_string()));
+        assert_eq!(value(&records[0], "Temperature"), Some("20.5".to_string()));
+        assert_eq!(value(&records[0], "TaiTime"), Some("2024-01-01T00:00:37Z".to_string()));
+        assert_eq!(records[1].get_time(), Some(&"2024-01-01T01:00:00Z".to_string()));
+        assert!(records[1].get_value("Temperature").is_some());
+        assert_eq!(value(&records[1], "Temperature"), None);
+    }
+
+    #[test]
+    fn reads_records_of_external_json_blob() {
+        let mut blob = Blob::new();
+        blob.set_content_type("application/json".to_string());
+        blob.set_value(br#"[{"Time": "2024-01-01T00:00:00Z", "Temperature": 20.5}, {"Temperature": null}]"#.to_vec());
+        let mut segment = TimeSeriesSegment::new(SegmentKind::External);
+        segment.set_blob(Some(blob));
+        let store = SingleFileStore {
+            path: "",
+            data: ""
+        };
+
+        let records = segment.read_records(&metadata(), &store).unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].get_time(), Some(&"2024-01-01T00:00:00Z".to_string()));
+        assert_eq!(value(&records[0], "Temperature"), Some("20.5".to_string()));
+        assert_eq!(records[1].get_time(), None);
+        assert_eq!(value(&records[1], "Temperature"), None);
+    }
+
+    #[test]
+    fn reads_rows_without_time_column_without_time() {
+        let segment = external_file("/aasx/files/temperatures.csv", "text/csv");
+        let store = SingleFileStore {
+            path: "/aasx/files/temperatures.csv",
+            data: "Temperature\n20.5\n"
+        };
+
+        let records = segment.read_records(&metadata(), &store).unwrap();
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].get_time(), None);
+        assert_eq!(value(&records[0], "Temperature"), Some("20.5".to_string()));
+    }
+
+    #[test]
+    fn rejects_invalid_values_and_unknown_variables() {
+        let segment = external_file("/aasx/files/temperatures.csv", "text/csv");
+        let store = SingleFileStore {
+            path: "/aasx/files/temperatures.csv",
+            data: "Time,Temperature\n2024-01-01T00:00:00Z,warm\n"
+        };
+        assert_eq!(segment.read_records(&metadata(), &store).err(),
+                   Some(TimeSeriesError::InvalidValue("Temperature".to_string(), "warm".to_string())));
+
+        let store = SingleFileStore {
+            path: "/aasx/files/temperatures.csv",
+            data: "Time,Pressure\n2024-01-01T00:00:00Z,1.0\n"
+        };
+        assert_eq!(segment.read_records(&metadata(), &store).err(), Some(TimeSeriesError::UnknownVariable("Pressure".to_string())));
+
+        let mut segment = TimeSeriesSegment::new(SegmentKind::Internal);
+        let mut temperature = Property::new(DataTypeDefXsd::String);
+        temperature.set_id_short("Temperature".to_string());
+        temperature.set_value("warm".to_string());
+        let record = TimeSeriesRecord::new(Some("2024-01-01T00:00:00Z".to_string()), vec![temperature]);
+        assert_eq!(segment.append_record(&record, &metadata()).unwrap_err(),
+                   TimeSeriesError::InvalidValue("Temperature".to_string(), "warm".to_string()));
+        assert_eq!(segment.get_record_count(), None);
+    }
+
+    #[test]
+    fn appends_and_gets_records_of_internal_segment() {
+        let metadata = metadata();
+        let mut segment = TimeSeriesSegment::new(SegmentKind::Internal);
+        let mut temperature = Property::new(DataTypeDefXsd::String);
+        temperature.set_id_short("Temperature".to_string());
+        temperature.set_value("20.5".to_string());
+        let mut tai_time = Property::new(DataTypeDefXsd::String);
+        tai_time.set_id_short("TaiTime".to_string());
+        tai_time.set_value("2024-01-01T00:00:37Z".to_string());
+        segment.append_record(&TimeSeriesRecord::new(Some("2024-01-01T00:00:00Z".to_string()), vec![temperature.clone(), tai_time]), &metadata).unwrap();
+        temperature.set_value("21".to_string());
+        segment.append_record(&TimeSeriesRecord::new(Some("2024-01-01T01:00:00Z".to_string()), vec![temperature]), &metadata).unwrap();
+
+        let records = segment.get_records(&metadata);
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].get_time(), Some(&"2024-01-01T00:00:00Z".to_string()));
+        assert_eq!(value(&records[0], "Temperature"), Some("20.5".to_string()));
+        assert_eq!(value(&records[0], "TaiTime"), Some("2024-01-01T00:00:37Z".to_string()));
+        assert!(*records[0].get_value("Temperature").unwrap().get_value_type() == DataTypeDefXsd::Double);
+        assert_eq!(records[1].get_time(), Some(&"2024-01-01T01:00:00Z".to_string()));
+        assert_eq!(value(&records[1], "Temperature"), Some("21".to_string()));
+        assert_eq!(segment.get_record_count(), Some(2));
+        assert_eq!(segment.get_start_time(), Some("2024-01-01T00:00:00Z".to_string()));
+        assert_eq!(segment.get_end_time(), Some("2024-01-01T01:00:00Z".to_string()));
+        assert!(segment.read_records(&metadata, &SingleFileStore { path: "", data: "" }).unwrap() == records);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,269 @@
+use crate::enumerations::aas_submodel_elements::AasSubmodelElements;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::file::File;
+use crate::structs::goods_address::GoodsAddress;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::template_element::{global_reference, join_path, TemplateElement};
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+use crate::traits::submodel_element_container::TSubmodelElementContainer;
+
+///Typed view on a transport carbon footprint of the Carbon Footprint, i.e. the greenhouse gas
+/// emissions of transporting the product.
+///
+/// The view wraps the submodel element collection, so elements it does not know about are kept.
+#[derive(PartialEq, Clone)]
+pub struct TransportCarbonFootprint {
+    ///The wrapped collection.
+    pub(crate) collection: SubmodelElementCollection
+}
+
+impl TransportCarbonFootprint {
+    ///Semantic ID of a transport carbon footprint.
+    pub const SEMANTIC_ID: &str = "https://admin-shell.io/idta/CarbonFootprint/TransportCarbonFootprint/0/9";
+
+    ///Creates a new transport carbon footprint.
+    ///
+    /// [tcf_calculation_methods]: standards the footprint is calculated according to, e.g. EN 16258
+    /// [tcf_co2eq]: greenhouse gas emissions of the transport in kg CO2 equivalents
+    /// [tcf_reference_value_for_calculation]: unit of the quantity the emissions refer to, e.g. piece
+    /// [tcf_quantity_of_measure_for_calculation]: quantity the emissions refer to
+    /// [tcf_processes]: processes of the transport service causing emissions
+    pub fn new(tcf_calculation_methods: Vec<String>, tcf_co2eq: f64, tcf_reference_value_for_calculation: String,
+               tcf_quantity_of_measure_for_calculation: f64, tcf_processes: Vec<String>) -> TransportCarbonFootprint {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_semantic_id(global_reference(TransportCarbonFootprint::SEMANTIC_ID));
+        let mut transport_carbon_footprint = TransportCarbonFootprint {
+            collection
+        };
+        transport_carbon_footprint.set_tcf_calculation_methods(tcf_calculation_methods);
+        transport_carbon_footprint.set_tcf_co2eq(tcf_co2eq);
+        transport_carbon_footprint.set_tcf_reference_value_for_calculation(tcf_reference_value_for_calculation);
+        transport_carbon_footprint.set_tcf_quantity_of_measure_for_calculation(tcf_quantity_of_measure_for_calculation);
+        transport_carbon_footprint.set_tcf_processes(tcf_processes);
+        transport_carbon_footprint
+    }
+
+    ///Wraps a submodel element collection after checking the elements of a transport carbon footprint.
+    ///
+    /// [collection]: collection containing a transport carbon footprint
+    pub fn from_collection(collection: SubmodelElementCollection) -> Result<TransportCarbonFootprint, ConversionError> {
+        TransportCarbonFootprint::check(&collection, collection.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default())?;
+        Ok(TransportCarbonFootprint {
+            collection
+        })
+    }
+
+    ///Returns the wrapped submodel element collection.
+    pub fn get_collection(&self) -> &SubmodelElementCollection {
+        &self.collection
+    }
+
+    ///Consumes the transport carbon footprint and returns the wrapped submodel element collection.
+    pub fn into_collection(self) -> SubmodelElementCollection {
+        self.collection
+    }
+
+    ///Returns the standards the footprint is calculated according to, e.g. EN 16258.
+    pub fn get_tcf_calculation_methods(&self) -> Vec<String> {
+        self.collection.find_elements(TCF_CALCULATION_METHOD.id_short, TCF_CALCULATION_METHOD.semantic_id).into_iter()
+            .filter_map(|element| match element {
+                SubmodelElement::Property(property) => property.get_typed_value(),
+                _ => None
+            })
+            .collect()
+    }
+
+    ///Sets the standards the footprint is calculated according to, e.g. EN 16258.
+    ///
+    /// [tcf_calculation_methods]: standards the footprint is calculated according to, e.g. EN 16258
+    pub fn set_tcf_calculation_methods(&mut self, tcf_calculation_methods: Vec<String>) {
+        let elements = tcf_calculation_methods.iter().map(|value| TCF_CALCULATION_METHOD.new_property(value)).collect();
+        self.collection.set_elements(TCF_CALCULATION_METHOD.id_short, TCF_CALCULATION_METHOD.semantic_id, elements);
+    }
+
+    ///Returns the greenhouse gas emissions of the transport in kg CO2 equivalents.
+    pub fn get_tcf_co2eq(&self) -> Option<f64> {
+        self.collection.get_property_value(TCF_CO2EQ.id_short, TCF_CO2EQ.semantic_id)
+    }
+
+    ///Sets the greenhouse gas emissions of the transport in kg CO2 equivalents.
+    ///
+    /// [tcf_co2eq]: greenhouse gas emissions of the transport in kg CO2 equivalents
+    pub fn set_tcf_co2eq(&mut self, tcf_co2eq: f64) {
+        TCF_CO2EQ.set_property_value(&mut self.collection, Some(&tcf_co2eq));
+    }
+
+    ///Returns the unit of the quantity the emissions refer to, e.g. piece.
+    pub fn get_tcf_reference_value_for_calculation(&self) -> Option<String> {
+        self.collection.get_property_value(TCF_REFERENCE_VALUE_FOR_CALCULATION.id_short, TCF_REFERENCE_VALUE_FOR_CALCULATION.semantic_id)
+    }
+
+    ///Sets the unit of the quantity the emissions refer to, e.g. piece.
+    ///
+    /// [tcf_reference_value_for_calculation]: unit of the quantity the emissions refer to, e.g. piece
+    pub fn set_tcf_reference_value_for_calculation(&mut self, tcf_reference_value_for_calculation: String) {
+        TCF_REFERENCE_VALUE_FOR_CALCULATION.set_property_value(&mut self.collection, Some(&tcf_reference_value_for_calculation));
+    }
+
+    ///Returns the quantity the emissions refer to.
+    pub fn get_tcf_quantity_of_measure_for_calculation(&self) -> Option<f64> {
+        self.collection.get_property_value(TCF_QUANTITY_OF_MEASURE_FOR_CALCULATION.id_short, TCF_QUANTITY_OF_MEASURE_FOR_CALCULATION.semantic_id)
+    }
+
+    ///Sets the quantity the emissions refer to.
+    ///
+    /// [tcf_quantity_of_measure_for_calculation]: quantity the emissions refer to
+    pub fn set_tcf_quantity_of_measure_for_calculation(&mut self, tcf_quantity_of_measure_for_calculation: f64) {
+        TCF_QUANTITY_OF_MEASURE_FOR_CALCULATION.set_property_value(&mut self.collection, Some(&tcf_quantity_of_measure_for_calculation));
+    }
+
+    ///Returns the processes of the transport service causing emissions.
+    pub fn get_tcf_processes(&self) -> Vec<String> {
+        self.collection.find_elements(TCF_PROCESSES.id_short, TCF_PROCESSES.semantic_id).into_iter()
+            .filter_map(|element| match element {
+                SubmodelElement::Property(property) => property.get_typed_value(),
+                _ => None
+            })
+            .collect()
+    }
+
+    ///Sets the processes of the transport service causing emissions.
+    ///
+    /// [tcf_processes]: processes of the transport service causing emissions
+    pub fn set_tcf_processes(&mut self, tcf_processes: Vec<String>) {
+        let elements = tcf_processes.iter().map(|value| TCF_PROCESSES.new_property(value)).collect();
+        self.collection.set_elements(TCF_PROCESSES.id_short, TCF_PROCESSES.semantic_id, elements);
+    }
+
+    ///Returns the file with an explanatory statement on the calculation.
+    pub fn get_explanatory_statement(&self) -> Option<&File> {
+        self.collection.get_file(EXPLANATORY_STATEMENT.id_short, EXPLANATORY_STATEMENT.semantic_id)
+    }
+
+    ///Sets the file with an explanatory statement on the calculation. Passing None removes it.
+    ///
+    /// [explanatory_statement]: file with an explanatory statement on the calculation
+    pub fn set_explanatory_statement(&mut self, explanatory_statement: Option<File>) {
+        self.collection.set_element(EXPLANATORY_STATEMENT.id_short, EXPLANATORY_STATEMENT.semantic_id, explanatory_statement.map(SubmodelElement::File));
+    }
+
+    ///Returns the time the footprint was published, as xs:dateTime.
+    pub fn get_publication_date(&self) -> Option<String> {
+        self.collection.get_property_value(PUBLICATION_DATE.id_short, PUBLICATION_DATE.semantic_id)
+    }
+
+    ///Sets the time the footprint was published, as xs:dateTime. Passing None removes it.
+    ///
+    /// [publication_date]: time the footprint was published, as xs:dateTime
+    pub fn set_publication_date(&mut self, publication_date: Option<String>) {
+        PUBLICATION_DATE.set_property_value(&mut self.collection, publication_date.as_ref());
+    }
+
+    ///Returns the time the footprint expires, as xs:dateTime.
+    pub fn get_expiration_date(&self) -> Option<String> {
+        self.collection.get_property_value(EXPIRATION_DATE.id_short, EXPIRATION_DATE.semantic_id)
+    }
+
+    ///Sets the time the footprint expires, as xs:dateTime. Passing None removes it.
+    ///
+    /// [expiration_date]: time the footprint expires, as xs:dateTime
+    pub fn set_expiration_date(&mut self, expiration_date: Option<String>) {
+        EXPIRATION_DATE.set_property_value(&mut self.collection, expiration_date.as_ref());
+    }
+
+    ///Returns the address at which the goods are taken over for transport.
+    pub fn get_tcf_goods_transport_address_takeover(&self) -> Option<GoodsAddress> {
+        self.collection.get_collection(TCF_GOODS_TRANSPORT_ADDRESS_TAKEOVER.id_short, TCF_GOODS_TRANSPORT_ADDRESS_TAKEOVER.semantic_id)
+            .map(|collection| GoodsAddress {
+                collection: collection.clone()
+            })
+    }
+
+    ///Sets the address at which the goods are taken over for transport. Passing None removes it.
+    ///
+    /// [tcf_goods_transport_address_takeover]: address at which the goods are taken over for transport
+    pub fn set_tcf_goods_transport_address_takeover(&mut self, tcf_goods_transport_address_takeover: Option<GoodsAddress>) {
+        self.collection.set_element(TCF_GOODS_TRANSPORT_ADDRESS_TAKEOVER.id_short, TCF_GOODS_TRANSPORT_ADDRESS_TAKEOVER.semantic_id, tcf_goods_transport_address_takeover.map(|tcf_goods_transport_address_takeover| SubmodelElement::SubmodelElementCollection(tcf_goods_transport_address_takeover.into_collection())));
+    }
+
+    ///Returns the address at which the goods are handed over after transport.
+    pub fn get_tcf_goods_transport_address_handover(&self) -> Option<GoodsAddress> {
+        self.collection.get_collection(TCF_GOODS_TRANSPORT_ADDRESS_HANDOVER.id_short, TCF_GOODS_TRANSPORT_ADDRESS_HANDOVER.semantic_id)
+            .map(|collection| GoodsAddress {
+                collection: collection.clone()
+            })
+    }
+
+    ///Sets the address at which the goods are handed over after transport. Passing None removes it.
+    ///
+    /// [tcf_goods_transport_address_handover]: address at which the goods are handed over after transport
+    pub fn set_tcf_goods_transport_address_handover(&mut self, tcf_goods_transport_address_handover: Option<GoodsAddress>) {
+        self.collection.set_element(TCF_GOODS_TRANSPORT_ADDRESS_HANDOVER.id_short, TCF_GOODS_TRANSPORT_ADDRESS_HANDOVER.semantic_id, tcf_goods_transport_address_handover.map(|tcf_goods_transport_address_handover| SubmodelElement::SubmodelElementCollection(tcf_goods_transport_address_handover.into_collection())));
+    }
+
+    ///Returns the elements of a transport carbon footprint in the submodel template.
+    pub(crate) fn template_elements() -> Vec<SubmodelElement> {
+        vec![
+            TCF_CALCULATION_METHOD.to_element(),
+            TCF_CO2EQ.to_element(),
+            TCF_REFERENCE_VALUE_FOR_CALCULATION.to_element(),
+            TCF_QUANTITY_OF_MEASURE_FOR_CALCULATION.to_element(),
+            TCF_PROCESSES.to_element(),
+            EXPLANATORY_STATEMENT.to_element(),
+            TCF_GOODS_TRANSPORT_ADDRESS_TAKEOVER.to_collection(GoodsAddress::template_elements()),
+            TCF_GOODS_TRANSPORT_ADDRESS_HANDOVER.to_collection(GoodsAddress::template_elements()),
+            PUBLICATION_DATE.to_element(),
+            EXPIRATION_DATE.to_element()
+        ]
+    }
+
+    ///Checks the elements of a transport carbon footprint.
+    ///
+    /// [collection]: collection containing a transport carbon footprint
+    /// [path]: idShortPath of the collection used in error messages
+    pub(crate) fn check(collection: &SubmodelElementCollection, path: &str) -> Result<(), ConversionError> {
+        for element in &ELEMENTS {
+            element.check(collection, path)?;
+        }
+        for element in TCF_GOODS_TRANSPORT_ADDRESS_TAKEOVER.check(collection, path)? {
+            if let SubmodelElement::SubmodelElementCollection(address) = element {
+                GoodsAddress::check(address, &join_path(path, TCF_GOODS_TRANSPORT_ADDRESS_TAKEOVER.id_short))?;
+            }
+        }
+        for element in TCF_GOODS_TRANSPORT_ADDRESS_HANDOVER.check(collection, path)? {
+            if let SubmodelElement::SubmodelElementCollection(address) = element {
+                GoodsAddress::check(address, &join_path(path, TCF_GOODS_TRANSPORT_ADDRESS_HANDOVER.id_short))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+const TCF_CALCULATION_METHOD: TemplateElement =
+    TemplateElement::property("TCFCalculationMethod{00}", "0173-1#02-ABG859#001", DataTypeDefXsd::String, Cardinality::OneToMany);
+const TCF_CO2EQ: TemplateElement =
+    TemplateElement::property("TCFCO2eq", "0173-1#02-ABG860#001", DataTypeDefXsd::Double, Cardinality::One);
+const TCF_REFERENCE_VALUE_FOR_CALCULATION: TemplateElement =
+    TemplateElement::property("TCFReferenceValueForCalculation", "0173-1#02-ABG861#001", DataTypeDefXsd::String, Cardinality::One);
+const TCF_QUANTITY_OF_MEASURE_FOR_CALCULATION: TemplateElement =
+    TemplateElement::property("TCFQuantityOfMeasureForCalculation", "0173-1#02-ABG862#001", DataTypeDefXsd::Double, Cardinality::One);
+const TCF_PROCESSES: TemplateElement =
+    TemplateElement::property("TCFProcessesForGreenhouseGasEmissionInATransportService{00}", "0173-1#02-ABG863#001", DataTypeDefXsd::String, Cardinality::OneToMany);
+const EXPLANATORY_STATEMENT: TemplateElement =
+    TemplateElement::new("ExplanatoryStatement", "https://admin-shell.io/idta/CarbonFootprint/ExplanatoryStatement/1/0", AasSubmodelElements::File, Cardinality::ZeroToOne);
+const TCF_GOODS_TRANSPORT_ADDRESS_TAKEOVER: TemplateElement =
+    TemplateElement::new("TCFGoodsTransportAddressTakeover", "0173-1#02-ABI498#001/0173-1#01-AHI306#001", AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToOne);
+const TCF_GOODS_TRANSPORT_ADDRESS_HANDOVER: TemplateElement =
+    TemplateElement::new("TCFGoodsTransportAddressHandover", "0173-1#02-ABI499#001/0173-1#01-AHI307#001", AasSubmodelElements::SubmodelElementCollection, Cardinality::ZeroToOne);
+const PUBLICATION_DATE: TemplateElement =
+    TemplateElement::property("PublicationDate", "https://admin-shell.io/idta/CarbonFootprint/PublicationDate/1/0", DataTypeDefXsd::DateTime, Cardinality::ZeroToOne);
+const EXPIRATION_DATE: TemplateElement =
+    TemplateElement::property("ExpirationDate", "https://admin-shell.io/idta/CarbonFootprint/ExpirationDate/1/0", DataTypeDefXsd::DateTime, Cardinality::ZeroToOne);
+
+const ELEMENTS: [&TemplateElement; 10] = [&TCF_CALCULATION_METHOD, &TCF_CO2EQ, &TCF_REFERENCE_VALUE_FOR_CALCULATION,
+    &TCF_QUANTITY_OF_MEASURE_FOR_CALCULATION, &TCF_PROCESSES, &EXPLANATORY_STATEMENT,
+    &TCF_GOODS_TRANSPORT_ADDRESS_TAKEOVER, &TCF_GOODS_TRANSPORT_ADDRESS_HANDOVER, &PUBLICATION_DATE, &EXPIRATION_DATE];
@@ -0,0 +1,294 @@
+use std::time::{Duration, Instant};
+
+use crate::enumerations::binding_error::BindingError;
+use crate::enumerations::binding_target::BindingTarget;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::submodel::Submodel;
+use crate::traits::value_provider::TValueProvider;
+use serde_json::Value;
+
+///Binding of a property or a range of a submodel, addressed by its idShortPath, to the source of
+/// its live value.
+///
+/// On refresh the value is read from the provider, converted to the lexical form of the data type
+/// of the element, e.g. `21.0` to `21` for xs:int or `on` to `true` for xs:boolean, and written to
+/// the element. Bindings with an interval are refreshed periodically by `ValueBindings`.
+pub struct ValueBinding {
+    ///idShortPath of the bound element.
+    id_short_path: String,
+    ///Value of the element the binding writes to.
+    target: BindingTarget,
+    ///Source of the value.
+    provider: Box<dyn TValueProvider>,
+    ///Interval of periodic refreshes; None refreshes on demand only.
+    interval: Option<Duration>,
+    ///Point in time of the last refresh.
+    last_refresh: Option<Instant>
+}
+
+impl ValueBinding {
+    ///Creates a new binding of the value of a property, refreshed on demand only.
+    ///
+    /// [id_short_path]: idShortPath of the property, e.g. `Status.Temperature`
+    /// [provider]: source of the value
+    pub fn new(id_short_path: String, provider: Box<dyn TValueProvider>) -> ValueBinding {
+        ValueBinding {
+            id_short_path,
+            target: BindingTarget::Value,
+            provider,
+            interval: None,
+            last_refresh: None
+        }
+    }
+
+    ///Returns the idShortPath of the bound element.
+    pub fn get_id_short_path(&self) -> &String {
+        &self.id_short_path
+    }
+
+    ///Returns the value of the element the binding writes to.
+    pub fn get_target(&self) -> &BindingTarget {
+        &self.target
+    }
+
+    ///Sets the value of the element the binding writes to, e.g. the lower bound of a range.
+    ///
+    /// [target]: value of the element
+    pub fn set_target(&mut self, target: BindingTarget) {
+        self.target = target;
+    }
+
+    ///Returns the source of the value.
+    pub fn get_mut_provider(&mut self) -> &mut dyn TValueProvider {
+        self.provider.as_mut()
+    }
+
+    ///Returns the interval of periodic refreshes.
+    pub fn get_interval(&self) -> Option<Duration> {
+        self.interval
+    }
+
+    ///Sets the interval of periodic refreshes. Passing None refreshes on demand only.
+    ///
+    /// [interval]: interval of periodic refreshes
+    pub fn set_interval(&mut self, interval: Option<Duration>) {
+        self.interval = interval;
+    }
+
+    ///Returns the point in time of the last refresh, successful or not.
+    pub fn get_last_refresh(&self) -> Option<Instant> {
+        self.last_refresh
+    }
+
+    ///Returns whether a periodic refresh is due, i.e. the binding has an interval that has elapsed
+    /// since the last refresh.
+    ///
+    /// [now]: current point in time
+    pub fn is_due(&self, now: Instant) -> bool {
+        self.interval.is_some_and(|interval| self.last_refresh
+            .is_none_or(|last_refresh| now.saturating_duration_since(last_refresh) >= interval))
+    }
+
+    ///Reads the value from the provider and writes it to the bound element of the submodel.
+    ///
+    /// [submodel]: submodel containing the bound element
+    pub fn refresh(&mut self, submodel: &mut Submodel) -> Result<(), BindingError> {
+        self.last_refresh = Some(Instant::now());
+        let path = &self.id_short_path;
+        let value_type = match (submodel.get_submodel_element(path), &self.target) {
+            (Some(SubmodelElement::Property(property)), BindingTarget::Value) => property.get_value_type().clone(),
+            (Some(SubmodelElement::Range(range)), BindingTarget::Min | BindingTarget::Max) => range.get_value_type().clone(),
+            (Some(_), _) => return Err(BindingError::UnsupportedElement(path.clone())),
+            (None, _) => return Err(BindingError::ElementNotFound(path.clone()))
+        };
+        let value = self.provider.read_value(&value_type)?;
+        let value = convert_value(&value, &value_type).ok_or_else(|| BindingError::InvalidValue(path.clone(), value))?;
+        match submodel.get_mut_submodel_element(path) {
+            Some(SubmodelElement::Property(property)) => property.set_value(value),
+            Some(SubmodelElement::Range(range)) if self.target == BindingTarget::Min => range.set_min(value),
+            Some(SubmodelElement::Range(range)) => range.set_max(value),
+            _ => unreachable!("the element was checked before reading the value")
+        }
+        Ok(())
+    }
+}
+
+///Converts a value read from a source to the lexical form of the data type. Returns None if the
+/// value cannot be converted.
+fn convert_value(value: &str, value_type: &DataTypeDefXsd) -> Option<String> {
+    let value = value.trim();
+    let converted = match value_type {
+        DataTypeDefXsd::Boolean => match value.to_lowercase().as_str() {
+            "true" | "1" | "on" => "true".to_string(),
+            "false" | "0" | "off" => "false".to_string(),
+            _ => return None
+        },
+        _ if value_type.is_valid_value(value) => value.to_string(),
+        DataTypeDefXsd::Byte | DataTypeDefXsd::Short | DataTypeDefXsd::Int | DataTypeDefXsd::Long | DataTypeDefXsd::Integer
+        | DataTypeDefXsd::NegativeInteger | DataTypeDefXsd::NonNegativeInteger | DataTypeDefXsd::NonPositiveInteger
+        | DataTypeDefXsd::PositiveInteger | DataTypeDefXsd::UnsignedByte | DataTypeDefXsd::UnsignedShort
+        | DataTypeDefXsd::UnsignedInt | DataTypeDefXsd::UnsignedLong => convert_integer(value)?,
+        DataTypeDefXsd::Decimal => value.parse::<f64>().ok().filter(|number| number.is_finite())?.to_string(),
+        _ => return None
+    };
+    value_type.is_valid_value(&converted).then_some(converted)
+}
+
+///Converts a number without fractional part to the lexical form of an integer. Decimal notation,
+/// e.g. `21.0`, is parsed as integer so that 64-bit values keep their precision, while exponential
+/// notation is only accepted within the range of integers an f64 represents exactly.
+fn convert_integer(value: &str) -> Option<String> {
+    let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if let Ok(number) = integer.parse::<i128>() && fraction.bytes().all(|digit| digit == b'0') {
+        return Some(number.to_string());
+    }
+    const MAX_EXACT: f64 = 9_007_199_254_740_992.0;
+    let number = value.parse::<f64>().ok()
+        .filter(|number| number.is_finite() && number.fract() == 0.0 && number.abs() <= MAX_EXACT)?;
+    Some((number as i128).to_string())
+}
+
+
+///Converts an I/O error of the communication with a source, mapping timeouts to
+/// `BindingError::Timeout`.
+///
+/// [address]: address of the source
+/// [error]: I/O error
+pub(crate) fn communication_error(address: &str, error: std::io::Error) -> BindingError {
+    match error.kind() {
+        std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut => BindingError::Timeout,
+        _ => BindingError::ConnectionFailed(address.to_string(), error.to_string())
+    }
+}
+
+///Returns the value contained in a text received from a source. A JSON scalar is taken as is, the
+/// value within structured JSON is selected by the JSON pointer and other text is trimmed.
+///
+/// [text]: body or payload received from the source
+/// [json_pointer]: JSON pointer selecting the value within structured JSON, e.g. `/value`
+pub(crate) fn extract_value(text: &str, json_pointer: Option<&str>) -> Result<String, BindingError> {
+    let Ok(json) = serde_json::from_str::<Value>(text) else {
+        return match json_pointer {
+            Some(_) => Err(BindingError::ProtocolError("the received value is no JSON".to_string())),
+            None => Ok(text.trim().to_string())
+        };
+    };
+    let value = match json_pointer {
+        Some(pointer) => json.pointer(pointer)
+            .ok_or_else(|| BindingError::ProtocolError(format!("no value at JSON pointer '{}'", pointer)))?,
+        None => &json
+    };
+    match value {
+        Value::String(text) => Ok(text.clone()),
+        Value::Null => Err(BindingError::ProtocolError("the received value is null".to_string())),
+        value => Ok(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::enumerations::binding_error::BindingError;
+    use crate::enumerations::binding_target::BindingTarget;
+    use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+    use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+    use crate::structs::property::Property;
+    use crate::structs::range::Range;
+    use crate::structs::submodel::Submodel;
+    use crate::structs::value_binding::{convert_value, extract_value, ValueBinding};
+    use crate::traits::referable::TReferable;
+    use crate::traits::value_provider::TValueProvider;
+
+    struct FixedValue(&'static str);
+
+    impl TValueProvider for FixedValue {
+        fn read_value(&mut self, _value_type: &DataTypeDefXsd) -> Result<String, BindingError> {
+            Ok(self.0.to_string())
+        }
+    }
+
+    fn submodel() -> Submodel {
+        let mut property = Property::new(DataTypeDefXsd::Int);
+        property.set_id_short("Temperature".to_string());
+        let mut range = Range::new(DataTypeDefXsd::Double);
+        range.set_id_short("Limits".to_string());
+        let mut submodel = Submodel::new("urn:example:submodel".to_string());
+        submodel.add_submodel_element(SubmodelElement::Property(property));
+        submodel.add_submodel_element(SubmodelElement::Range(range));
+        submodel
+    }
+
+    #[test]
+    fn converts_booleans() {
+        assert_eq!(convert_value("ON", &DataTypeDefXsd::Boolean).as_deref(), Some("true"));
+        assert_eq!(convert_value("0", &DataTypeDefXsd::Boolean).as_deref(), Some("false"));
+        assert_eq!(convert_value("maybe", &DataTypeDefXsd::Boolean), None);
+    }
+
+    #[test]
+    fn converts_integers_in_decimal_notation() {
+        assert_eq!(convert_value(" 21.0 ", &DataTypeDefXsd::Int).as_deref(), Some("21"));
+        assert_eq!(convert_value("-7.", &DataTypeDefXsd::Short).as_deref(), Some("-7"));
+        assert_eq!(convert_value("2.5e1", &DataTypeDefXsd::Int).as_deref(), Some("25"));
+        assert_eq!(convert_value("21.5", &DataTypeDefXsd::Int), None);
+        assert_eq!(convert_value("300", &DataTypeDefXsd::Byte), None);
+    }
+
+    #[test]
+    fn keeps_precision_of_64_bit_integers() {
+        assert_eq!(convert_value("9007199254740993.0", &DataTypeDefXsd::Long).as_deref(), Some("9007199254740993"));
+        assert_eq!(convert_value("18446744073709551615.00", &DataTypeDefXsd::UnsignedLong).as_deref(),
+                   Some("18446744073709551615"));
+        assert_eq!(convert_value("1e19", &DataTypeDefXsd::UnsignedLong), None);
+    }
+
+    #[test]
+    fn keeps_valid_values_and_rejects_others() {
+        assert_eq!(convert_value("21.50", &DataTypeDefXsd::Decimal).as_deref(), Some("21.50"));
+        assert_eq!(convert_value("1e-3", &DataTypeDefXsd::Decimal).as_deref(), Some("0.001"));
+        assert_eq!(convert_value("INF", &DataTypeDefXsd::Double).as_deref(), Some("INF"));
+        assert_eq!(convert_value("abc", &DataTypeDefXsd::Float), None);
+    }
+
+    #[test]
+    fn extracts_values_from_json() {
+        assert_eq!(extract_value(" 21.5\n", None), Ok("21.5".to_string()));
+        assert_eq!(extract_value("\"on\"", None), Ok("on".to_string()));
+        assert_eq!(extract_value("{\"value\": {\"temperature\": 21}}", Some("/value/temperature")), Ok("21".to_string()));
+        assert!(matches!(extract_value("{\"value\": 21}", Some("/other")), Err(BindingError::ProtocolError(_))));
+        assert!(matches!(extract_value("null", None), Err(BindingError::ProtocolError(_))));
+    }
+
+    #[test]
+    fn refreshes_bound_elements() {
+        let mut submodel = submodel();
+        let mut binding = ValueBinding::new("Temperature".to_string(), Box::new(FixedValue("21.0")));
+        binding.refresh(&mut submodel).unwrap();
+        let mut max = ValueBinding::new("Limits".to_string(), Box::new(FixedValue("80.5")));
+        max.set_target(BindingTarget::Max);
+        max.refresh(&mut submodel).unwrap();
+        match submodel.get_submodel_element("Temperature") {
+            Some(SubmodelElement::Property(property)) => assert_eq!(property.get_value().map(String::as_str), Some("21")),
+            _ => panic!("expected the property")
+        }
+        match submodel.get_submodel_element("Limits") {
+            Some(SubmodelElement::Range(range)) => {
+                assert_eq!(range.get_max().map(String::as_str), Some("80.5"));
+                assert_eq!(range.get_min(), None);
+            },
+            _ => panic!("expected the range")
+        }
+        assert!(binding.get_last_refresh().is_some());
+    }
+
+    #[test]
+    fn reports_invalid_bindings() {
+        let mut submodel = submodel();
+        let mut invalid = ValueBinding::new("Temperature".to_string(), Box::new(FixedValue("warm")));
+        assert_eq!(invalid.refresh(&mut submodel), Err(BindingError::InvalidValue("Temperature".to_string(), "warm".to_string())));
+        let mut missing = ValueBinding::new("Humidity".to_string(), Box::new(FixedValue("50")));
+        assert_eq!(missing.refresh(&mut submodel), Err(BindingError::ElementNotFound("Humidity".to_string())));
+        let mut range = ValueBinding::new("Limits".to_string(), Box::new(FixedValue("50")));
+        assert_eq!(range.refresh(&mut submodel), Err(BindingError::UnsupportedElement("Limits".to_string())));
+    }
+}
\ No newline at end of file
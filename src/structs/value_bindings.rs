@@ -0,0 +1,119 @@
+use std::time::{Duration, Instant};
+
+use crate::enumerations::binding_error::BindingError;
+use crate::structs::asset_interfaces_description::AssetInterfacesDescription;
+use crate::structs::asset_interfaces_mapping_configuration::AssetInterfacesMappingConfiguration;
+use crate::structs::submodel::Submodel;
+use crate::structs::value_binding::ValueBinding;
+use crate::traits::identifiable::TIdentifiable;
+use crate::traits::referable::TReferable;
+
+///Set of value bindings of the elements of one submodel.
+///
+/// The bindings are either refreshed all at once on demand or periodically by calling
+/// `refresh_due` regularly, e.g. from a timer loop of the application. Bindings are created
+/// manually or from an Asset Interfaces Mapping Configuration.
+pub struct ValueBindings {
+    ///The bindings.
+    bindings: Vec<ValueBinding>
+}
+
+impl ValueBindings {
+    ///Creates a new set without bindings.
+    pub fn new() -> ValueBindings {
+        ValueBindings {
+            bindings: Vec::new()
+        }
+    }
+
+    ///Creates the bindings of the elements of a submodel that are the sinks of the relations of an
+    /// Asset Interfaces Mapping Configuration. The sources are resolved to the property affordances
+    /// of the interfaces of the Asset Interfaces Description, which provide the protocol and the
+    /// address of the values. Relations with sinks in other submodels are skipped.
+    ///
+    /// [mapping_configuration]: Asset Interfaces Mapping Configuration
+    /// [interfaces_description]: Asset Interfaces Description the mapping configuration refers to
+    /// [submodel_id]: identifier of the submodel containing the bound elements
+    /// [interval]: interval of periodic refreshes of the bindings, None refreshes on demand only
+    pub fn from_mapping_configuration(mapping_configuration: &AssetInterfacesMappingConfiguration,
+                                      interfaces_description: &AssetInterfacesDescription, submodel_id: &str,
+                                      interval: Option<Duration>) -> Result<ValueBindings, BindingError> {
+        let mut value_bindings = ValueBindings::new();
+        for configuration in mapping_configuration.get_mapping_configurations() {
+            let Some(interface_reference) = configuration.get_interface_reference() else {
+                continue;
+            };
+            let interface_path = interface_reference.get_submodel_element_path();
+            let Some((_, interface_id_short)) = interface_path
+                .filter(|(id, _)| *id == interfaces_description.get_submodel().get_id()) else {
+                continue;
+            };
+            let asset_interface = interfaces_description.get_interface(&interface_id_short)
+                .ok_or_else(|| BindingError::ElementNotFound(interface_id_short.clone()))?;
+            for (source, sink) in configuration.get_relations() {
+                let Some((sink_submodel_id, path)) = sink.get_submodel_element_path() else {
+                    continue;
+                };
+                if sink_submodel_id != submodel_id {
+                    continue;
+                }
+                let property_id_short = source.get_keys().last().map(|key| key.get_value().clone()).unwrap_or_default();
+                let property_definition = asset_interface.get_properties().into_iter()
+                    .find(|property_definition| property_definition.get_collection().get_id_short()
+                        .is_some_and(|id_short| *id_short == property_id_short))
+                    .ok_or_else(|| BindingError::ElementNotFound(property_id_short.clone()))?;
+                let mut value_binding = ValueBinding::new(path, asset_interface.create_value_provider(&property_definition)?);
+                value_binding.set_interval(interval);
+                value_bindings.add_binding(value_binding);
+            }
+        }
+        Ok(value_bindings)
+    }
+
+    ///Returns the bindings.
+    pub fn get_bindings(&self) -> &Vec<ValueBinding> {
+        &self.bindings
+    }
+
+    ///Returns the bindings for modification, e.g. of their intervals.
+    pub fn get_mut_bindings(&mut self) -> &mut Vec<ValueBinding> {
+        &mut self.bindings
+    }
+
+    ///Adds a binding.
+    ///
+    /// [value_binding]: binding to add
+    pub fn add_binding(&mut self, value_binding: ValueBinding) {
+        self.bindings.push(value_binding);
+    }
+
+    ///Refreshes all bindings. A failing binding does not stop the others; the errors are returned
+    /// with the idShortPaths of their elements.
+    ///
+    /// [submodel]: submodel containing the bound elements
+    pub fn refresh(&mut self, submodel: &mut Submodel) -> Vec<(String, BindingError)> {
+        self.bindings.iter_mut()
+            .filter_map(|value_binding| value_binding.refresh(submodel).err()
+                .map(|error| (value_binding.get_id_short_path().clone(), error)))
+            .collect()
+    }
+
+    ///Refreshes the bindings whose interval has elapsed since their last refresh. A failing binding
+    /// does not stop the others; the errors are returned with the idShortPaths of their elements.
+    ///
+    /// [submodel]: submodel containing the bound elements
+    /// [now]: current point in time
+    pub fn refresh_due(&mut self, submodel: &mut Submodel, now: Instant) -> Vec<(String, BindingError)> {
+        self.bindings.iter_mut()
+            .filter(|value_binding| value_binding.is_due(now))
+            .filter_map(|value_binding| value_binding.refresh(submodel).err()
+                .map(|error| (value_binding.get_id_short_path().clone(), error)))
+            .collect()
+    }
+}
+
+impl Default for ValueBindings {
+    fn default() -> Self {
+        ValueBindings::new()
+    }
+}
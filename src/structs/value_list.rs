@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::value_reference_pair::ValueReferencePair;
+
+///List of the allowed values of a concept described with the data specification IEC 61360.
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
+pub struct ValueList {
+    ///The allowed values with the references to their concepts.
+    #[serde(rename = "valueReferencePairs")]
+    value_reference_pairs: Vec<ValueReferencePair>
+}
+
+impl ValueList {
+    ///Creates a new instance of the struct.
+    ///
+    /// [value_reference_pairs]: allowed values with the references to their concepts
+    pub fn new(value_reference_pairs: Vec<ValueReferencePair>) -> ValueList {
+        ValueList {
+            value_reference_pairs
+        }
+    }
+
+    ///Sets the allowed values with the references to their concepts.
+    ///
+    /// [value_reference_pairs]: allowed values with the references to their concepts
+    pub fn set_value_reference_pairs(&mut self, value_reference_pairs: Vec<ValueReferencePair>) {
+        self.value_reference_pairs = value_reference_pairs;
+    }
+
+    ///Returns the allowed values with the references to their concepts.
+    pub fn get_value_reference_pairs(&self) -> &Vec<ValueReferencePair> {
+        &self.value_reference_pairs
+    }
+
+    ///Returns the mutable allowed values with the references to their concepts.
+    pub fn get_mut_value_reference_pairs(&mut self) -> &mut Vec<ValueReferencePair> {
+        &mut self.value_reference_pairs
+    }
+
+    ///Adds an allowed value.
+    ///
+    /// [value_reference_pair]: allowed value with the reference to its concept
+    pub fn add_value_reference_pair(&mut self, value_reference_pair: ValueReferencePair) {
+        self.value_reference_pairs.push(value_reference_pair);
+    }
+}
\ No newline at end of file
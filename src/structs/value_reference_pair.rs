@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::reference::Reference;
+
+///A value of a value list of the data specification IEC 61360 together with the global reference
+/// to the concept of the value.
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
+pub struct ValueReferencePair {
+    ///The value.
+    value: String,
+    ///Global reference to the concept of the value, e.g. an ECLASS value IRDI.
+    #[serde(rename = "valueId", default, skip_serializing_if = "Option::is_none")]
+    value_id: Option<Reference>
+}
+
+impl ValueReferencePair {
+    ///Creates a new instance of the struct.
+    ///
+    /// [value]: the value
+    pub fn new(value: String) -> ValueReferencePair {
+        ValueReferencePair {
+            value,
+            value_id: None
+        }
+    }
+
+    ///Sets the value.
+    ///
+    /// [value]: the value
+    pub fn set_value(&mut self, value: String) {
+        self.value = value;
+    }
+
+    ///Returns the value.
+    pub fn get_value(&self) -> &String {
+        &self.value
+    }
+
+    ///Returns the mutable value.
+    pub fn get_mut_value(&mut self) -> &mut String {
+        &mut self.value
+    }
+
+    ///Sets the global reference to the concept of the value.
+    ///
+    /// [value_id]: global reference to the concept of the value
+    pub fn set_value_id(&mut self, value_id: Reference) {
+        self.value_id = Some(value_id);
+    }
+
+    ///Returns the optional global reference to the concept of the value.
+    pub fn get_value_id(&self) -> Option<&Reference> {
+        self.value_id.as_ref()
+    }
+
+    ///Returns the optional mutable global reference to the concept of the value.
+    pub fn get_mut_value_id(&mut self) -> Option<&mut Reference> {
+        self.value_id.as_mut()
+    }
+}
\ No newline at end of file
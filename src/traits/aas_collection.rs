@@ -0,0 +1,163 @@
+use crate::enumerations::conversion_error::ConversionError;
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::enumerations::key_type::KeyType;
+use crate::enumerations::modelling_kind::ModellingKind;
+use crate::enumerations::reference_type::ReferenceType;
+use crate::structs::key::Key;
+use crate::structs::reference::Reference;
+use crate::structs::submodel::Submodel;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::traits::has_kind::THasKind;
+use crate::traits::has_semantics::THasSemantics;
+use crate::traits::referable::TReferable;
+
+///Trait for Rust structs that are stored as the elements of a submodel element collection or a
+/// submodel. It is usually implemented with `#[derive(AasCollection)]`, which requires the
+/// `derive` feature.
+pub trait TAasCollection: Sized {
+    ///idShort of the collection or submodel.
+    const ID_SHORT: &'static str;
+    ///Global reference used as semantic ID of the collection or submodel, if any.
+    const SEMANTIC_ID: Option<&'static str>;
+
+    ///Writes the struct as submodel elements.
+    fn to_elements(&self) -> Vec<SubmodelElement>;
+
+    ///Reads the struct from submodel elements.
+    ///
+    /// [elements]: submodel elements to read
+    /// [path]: idShortPath of the parent of the elements used in error messages
+    fn from_elements(elements: &[SubmodelElement], path: &str) -> Result<Self, ConversionError>;
+
+    ///Writes the struct as a submodel element collection.
+    fn to_collection(&self) -> SubmodelElementCollection {
+        let mut collection = SubmodelElementCollection::new();
+        collection.set_id_short(Self::ID_SHORT.to_string());
+        if let Some(semantic_id) = Self::SEMANTIC_ID {
+            collection.set_semantic_id(global_reference(semantic_id));
+        }
+        collection.set_value(self.to_elements());
+        collection
+    }
+
+    ///Reads the struct from a submodel element collection.
+    ///
+    /// [collection]: collection to read
+    /// [path]: idShortPath of the collection used in error messages
+    fn from_collection(collection: &SubmodelElementCollection, path: &str) -> Result<Self, ConversionError> {
+        Self::from_elements(collection.get_value(), path)
+    }
+
+    ///Writes the struct as a submodel instance.
+    ///
+    /// [id]: global identifier of the submodel
+    fn to_submodel(&self, id: String) -> Submodel {
+        let mut submodel = Submodel::new(id);
+        submodel.set_id_short(Self::ID_SHORT.to_string());
+        if let Some(semantic_id) = Self::SEMANTIC_ID {
+            submodel.set_semantic_id(global_reference(semantic_id));
+        }
+        submodel.set_kind(ModellingKind::Instance);
+        submodel.set_submodel_elements(self.to_elements());
+        submodel
+    }
+
+    ///Reads the struct from a submodel.
+    ///
+    /// [submodel]: submodel to read
+    fn from_submodel(submodel: &Submodel) -> Result<Self, ConversionError> {
+        Self::from_elements(submodel.get_submodel_elements(), "")
+    }
+}
+
+fn global_reference(value: &str) -> Reference {
+    Reference::new(ReferenceType::ExternalReference, vec![Key::new(KeyType::GlobalReference, value.to_string())])
+}
+
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use crate::AasCollection;
+    use crate::enumerations::conversion_error::ConversionError;
+    use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+    use crate::structs::multi_language_text_type::MultiLanguageTextType;
+    use crate::structs::submodel::Submodel;
+    use crate::structs::submodel_element_collection::SubmodelElementCollection;
+    use crate::traits::aas_collection::TAasCollection;
+    use crate::traits::has_semantics::THasSemantics;
+    use crate::traits::identifiable::TIdentifiable;
+    use crate::traits::referable::TReferable;
+
+    ///Nested collection named like a struct of this crate, which must not be confused with it.
+    #[derive(AasCollection, PartialEq, Clone)]
+    #[aas(crate = "crate", semantic_id = "urn:example:reference")]
+    struct Reference {
+        code: String
+    }
+
+    #[derive(AasCollection, PartialEq, Clone)]
+    #[aas(crate = "crate", id_short = "Nameplate", semantic_id = "urn:example:nameplate")]
+    struct Nameplate {
+        #[aas(id)]
+        id: String,
+        #[aas(id_short = "ManufacturerName")]
+        manufacturer: String,
+        year_of_construction: Option<u16>,
+        #[aas(multi_language)]
+        description: Vec<MultiLanguageTextType>,
+        #[aas(collection)]
+        reference: Option<Reference>
+    }
+
+    fn nameplate() -> Nameplate {
+        Nameplate {
+            id: "urn:example:nameplate:1".to_string(),
+            manufacturer: "ACME".to_string(),
+            year_of_construction: Some(2024),
+            description: vec![MultiLanguageTextType::new("en".to_string(), "Pump".to_string())],
+            reference: Some(Reference { code: "P-100".to_string() })
+        }
+    }
+
+    #[test]
+    fn round_trips_submodel() {
+        let submodel: Submodel = nameplate().into();
+
+        assert!(submodel.get_id() == "urn:example:nameplate:1");
+        assert!(submodel.get_id_short() == Some(&"Nameplate".to_string()));
+        let id_shorts: Vec<&str> = submodel.get_submodel_elements().iter()
+            .filter_map(|element| element.get_id_short().map(|id_short| id_short.as_str()))
+            .collect();
+        assert!(id_shorts == vec!["ManufacturerName", "YearOfConstruction", "Description", "Reference"]);
+        assert!(Nameplate::try_from(&submodel).ok() == Some(nameplate()));
+    }
+
+    #[test]
+    fn round_trips_collection_without_optional_elements() {
+        let value = Nameplate {
+            year_of_construction: None,
+            description: Vec::new(),
+            reference: None,
+            ..nameplate()
+        };
+        let collection: SubmodelElementCollection = value.clone().into();
+
+        assert!(collection.get_semantic_id().and_then(|reference| reference.get_keys().first())
+            .is_some_and(|key| key.get_value() == "urn:example:nameplate"));
+        assert!(collection.get_value().len() == 2);
+        assert!(Nameplate::try_from(collection).ok() == Some(Nameplate { id: String::new(), ..value }));
+    }
+
+    #[test]
+    fn reports_missing_and_invalid_elements() {
+        let mut elements = nameplate().to_elements();
+        if let Some(SubmodelElement::Property(property)) = elements.get_mut(1) {
+            property.set_value("next year".to_string());
+        }
+        assert!(Nameplate::from_elements(&elements, "Nameplate").err()
+            == Some(ConversionError::InvalidValue("Nameplate.YearOfConstruction".to_string())));
+
+        assert!(Nameplate::from_elements(&elements[1..], "Nameplate").err()
+            == Some(ConversionError::MissingElement("Nameplate.ManufacturerName".to_string())));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,42 @@
+use crate::enumerations::file_resolution_error::FileResolutionError;
+use crate::structs::file::File;
+
+///Trait for stores of the files referenced by file elements, e.g. an AASX package or a directory
+/// with supplementary files. Paths are the values of file elements, e.g. `/aasx/files/manual.pdf`,
+/// which are resolved relative to the root of the store.
+pub trait TFileStore {
+    ///Reads the file at the path.
+    ///
+    /// [path]: path of the file as given in a file element
+    fn read_file(&self, path: &str) -> Result<Vec<u8>, FileResolutionError>;
+
+    ///Returns whether a file exists at the path.
+    ///
+    /// [path]: path of the file as given in a file element
+    fn contains_file(&self, path: &str) -> bool {
+        self.read_file(path).is_ok()
+    }
+
+    ///Reads the file the file element refers to.
+    ///
+    /// [file]: file element
+    fn resolve(&self, file: &File) -> Result<Vec<u8>, FileResolutionError> {
+        match file.get_value() {
+            Some(path) => self.read_file(path),
+            None => Err(FileResolutionError::MissingPath)
+        }
+    }
+}
+
+///Splits the path of a file element into the segments of a path relative to the root of a file
+/// store. Returns None if the path is a URI with a scheme or leaves the root.
+pub(crate) fn relative_segments(path: &str) -> Option<Vec<&str>> {
+    if path.contains("://") || path.starts_with("file:") {
+        return None;
+    }
+    let segments: Vec<&str> = path.split('/').filter(|segment| !segment.is_empty() && *segment != ".").collect();
+    if segments.is_empty() || segments.contains(&"..") {
+        return None;
+    }
+    Some(segments)
+}
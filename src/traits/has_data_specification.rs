@@ -1,20 +1,21 @@
-use crate::structs::reference::Reference;
+use crate::structs::embedded_data_specification::EmbeddedDataSpecification;
 
 ///Trait with functions for an element that can be extended by using data specification templates.
 /// A data specification template defines a named set of additional attributes an element may or
-/// shall have. The data specifications used are explicitly specified with their global ID.
+/// shall have. The data specifications used are embedded with their global ID and the content the
+/// element provides for them.
 pub trait THasDataSpecification {
-    ///Returns a list of external references to the data specification templates used by the element.
-    fn get_data_specifications(&self) -> &Vec<Reference>;
-    ///Returns a mutable list of external references to the data specification templates used by the element.
-    fn get_mut_data_specifications(&mut self) -> &mut Vec<Reference>;
-    ///Sets the list of external references to the data specification templates used by the element.
-    /// [data_specifications]: list of external references
-    fn set_data_specifications(&mut self, data_specifications: Vec<Reference>);
-    ///Adds an external reference to a data specification template used by the element.
-    /// [data_specification]: external reference
-    fn add_data_specification(&mut self, data_specification: Reference);
-    ///Removes an external reference to a data specification template used by the element.
-    /// [index]: index of the reference to be removed
-    fn remove_data_specification(&mut self, index: usize) -> Reference;
+    ///Returns the list of embedded data specifications of the element.
+    fn get_data_specifications(&self) -> &Vec<EmbeddedDataSpecification>;
+    ///Returns the mutable list of embedded data specifications of the element.
+    fn get_mut_data_specifications(&mut self) -> &mut Vec<EmbeddedDataSpecification>;
+    ///Sets the list of embedded data specifications of the element.
+    /// [data_specifications]: list of embedded data specifications
+    fn set_data_specifications(&mut self, data_specifications: Vec<EmbeddedDataSpecification>);
+    ///Adds an embedded data specification to the element.
+    /// [data_specification]: data specification template with its content
+    fn add_data_specification(&mut self, data_specification: EmbeddedDataSpecification);
+    ///Removes an embedded data specification of the element.
+    /// [index]: index of the embedded data specification to be removed
+    fn remove_data_specification(&mut self, index: usize) -> EmbeddedDataSpecification;
 }
\ No newline at end of file
@@ -8,4 +8,12 @@ pub mod qualifiable;
 pub mod submodel_element;
 pub mod relationship_element;
 pub mod data_element;
-pub mod event_element;
\ No newline at end of file
+pub mod event_element;
+pub mod property_value;
+pub mod aas_collection;
+pub mod submodel_element_container;
+
+pub mod file_store;
+
+pub mod value_provider;
+pub mod repository_storage;
\ No newline at end of file
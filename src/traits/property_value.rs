@@ -0,0 +1,100 @@
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+
+///Trait for Rust types that can be stored as the value of a property, converting between the
+/// Rust value and its lexical representation in the XML schema data type.
+pub trait TPropertyValue: Sized {
+    ///Returns the data type used for values of the Rust type.
+    fn get_value_type() -> DataTypeDefXsd;
+    ///Parses a value from its lexical representation. Returns None if the representation is
+    /// invalid.
+    /// [value]: lexical representation of the value
+    fn parse_value(value: &str) -> Option<Self>;
+    ///Returns the lexical representation of the value.
+    fn format_value(&self) -> String;
+}
+
+impl TPropertyValue for String {
+    fn get_value_type() -> DataTypeDefXsd {
+        DataTypeDefXsd::String
+    }
+
+    fn parse_value(value: &str) -> Option<String> {
+        Some(value.to_string())
+    }
+
+    fn format_value(&self) -> String {
+        self.clone()
+    }
+}
+
+impl TPropertyValue for bool {
+    fn get_value_type() -> DataTypeDefXsd {
+        DataTypeDefXsd::Boolean
+    }
+
+    fn parse_value(value: &str) -> Option<bool> {
+        match value.trim() {
+            "true" | "1" => Some(true),
+            "false" | "0" => Some(false),
+            _ => None
+        }
+    }
+
+    fn format_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+macro_rules! impl_integer_property_value {
+    ($($integer:ty => $value_type:ident),*) => {
+        $(impl TPropertyValue for $integer {
+            fn get_value_type() -> DataTypeDefXsd {
+                DataTypeDefXsd::$value_type
+            }
+
+            fn parse_value(value: &str) -> Option<$integer> {
+                let value = value.trim();
+                value.strip_prefix('+').unwrap_or(value).parse().ok()
+            }
+
+            fn format_value(&self) -> String {
+                self.to_string()
+            }
+        })*
+    };
+}
+
+impl_integer_property_value!(i8 => Byte, i16 => Short, i32 => Int, i64 => Long, u8 => UnsignedByte,
+    u16 => UnsignedShort, u32 => UnsignedInt, u64 => UnsignedLong);
+
+macro_rules! impl_floating_property_value {
+    ($($floating:ty => $value_type:ident),*) => {
+        $(impl TPropertyValue for $floating {
+            fn get_value_type() -> DataTypeDefXsd {
+                DataTypeDefXsd::$value_type
+            }
+
+            fn parse_value(value: &str) -> Option<$floating> {
+                match value.trim() {
+                    "INF" | "+INF" => Some(<$floating>::INFINITY),
+                    "-INF" => Some(<$floating>::NEG_INFINITY),
+                    "NaN" => Some(<$floating>::NAN),
+                    value if value.contains(['i', 'I', 'n', 'N']) => None,
+                    value => value.parse().ok()
+                }
+            }
+
+            fn format_value(&self) -> String {
+                if self.is_nan() {
+                    "NaN".to_string()
+                } else if self.is_infinite() {
+                    if self.is_sign_positive() { "INF" } else { "-INF" }.to_string()
+                } else {
+                    self.to_string()
+                }
+            }
+        })*
+    };
+}
+
+impl_floating_property_value!(f32 => Float, f64 => Double);
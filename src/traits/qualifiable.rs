@@ -1,3 +1,7 @@
+use crate::enumerations::access_mode::AccessMode;
+use crate::enumerations::cardinality::Cardinality;
+use crate::enumerations::smt_qualifier::SmtQualifier;
+use crate::structs::allowed_range::AllowedRange;
 use crate::structs::qualifier::Qualifier;
 
 ///Trait with functions for a qualifiable element which may be further qualified by one or more
@@ -16,4 +20,99 @@ pub trait TQualifiable {
     ///Removes a qualification from the qualifiable element.
     /// [index]: index of the qualification to be removed
     fn remove_qualifier(&mut self, index: usize) -> Qualifier;
-}
\ No newline at end of file
+    ///Returns the qualifiers of the IDTA submodel template specification of the qualifiable
+    /// element. Other and malformed qualifiers are skipped.
+    fn get_smt_qualifiers(&self) -> Vec<SmtQualifier> {
+        self.get_qualifiers().iter().filter_map(SmtQualifier::parse).collect()
+    }
+    ///Replaces all qualifiers of the qualifiable element with the same SMT name by the qualifier.
+    /// [smt_qualifier]: qualifier of the IDTA submodel template specification
+    fn set_smt_qualifier(&mut self, smt_qualifier: SmtQualifier) {
+        self.get_mut_qualifiers().retain(|qualifier| SmtQualifier::parse(qualifier)
+            .is_none_or(|existing| existing.get_name() != smt_qualifier.get_name()));
+        self.add_qualifier(smt_qualifier.to_qualifier());
+    }
+    ///Adds a qualifier of the IDTA submodel template specification to the qualifiable element.
+    /// [smt_qualifier]: qualifier of the IDTA submodel template specification
+    fn add_smt_qualifier(&mut self, smt_qualifier: SmtQualifier) {
+        self.add_qualifier(smt_qualifier.to_qualifier());
+    }
+    ///Returns the SMT cardinality of the qualifiable element. Elements without cardinality are
+    /// mandatory and occur exactly once.
+    fn get_cardinality(&self) -> Option<Cardinality> {
+        self.get_smt_qualifiers().into_iter().find_map(|qualifier| match qualifier {
+            SmtQualifier::Cardinality(cardinality) => Some(cardinality),
+            _ => None
+        })
+    }
+    ///Returns the ID of the SMT either-or group of the qualifiable element.
+    fn get_either_or(&self) -> Option<String> {
+        self.get_smt_qualifiers().into_iter().find_map(|qualifier| match qualifier {
+            SmtQualifier::EitherOr(group) => Some(group),
+            _ => None
+        })
+    }
+    ///Returns the SMT default value of the qualifiable element.
+    fn get_default_value(&self) -> Option<String> {
+        self.get_smt_qualifiers().into_iter().find_map(|qualifier| match qualifier {
+            SmtQualifier::DefaultValue(value) => Some(value),
+            _ => None
+        })
+    }
+    ///Returns the SMT initial value of the qualifiable element.
+    fn get_initial_value(&self) -> Option<String> {
+        self.get_smt_qualifiers().into_iter().find_map(|qualifier| match qualifier {
+            SmtQualifier::InitialValue(value) => Some(value),
+            _ => None
+        })
+    }
+    ///Returns the SMT example value of the qualifiable element.
+    fn get_example_value(&self) -> Option<String> {
+        self.get_smt_qualifiers().into_iter().find_map(|qualifier| match qualifier {
+            SmtQualifier::ExampleValue(value) => Some(value),
+            _ => None
+        })
+    }
+    ///Returns the SMT allowed range of the qualifiable element.
+    fn get_allowed_range(&self) -> Option<AllowedRange> {
+        self.get_smt_qualifiers().into_iter().find_map(|qualifier| match qualifier {
+            SmtQualifier::AllowedRange(range) => Some(range),
+            _ => None
+        })
+    }
+    ///Returns all SMT allowed values of the qualifiable element.
+    fn get_allowed_values(&self) -> Vec<String> {
+        self.get_smt_qualifiers().into_iter().filter_map(|qualifier| match qualifier {
+            SmtQualifier::AllowedValue(value) => Some(value),
+            _ => None
+        }).collect()
+    }
+    ///Returns all SMT required languages of the qualifiable element.
+    fn get_required_langs(&self) -> Vec<String> {
+        self.get_smt_qualifiers().into_iter().filter_map(|qualifier| match qualifier {
+            SmtQualifier::RequiredLang(language) => Some(language),
+            _ => None
+        }).collect()
+    }
+    ///Returns the SMT access mode of the qualifiable element.
+    fn get_access_mode(&self) -> Option<AccessMode> {
+        self.get_smt_qualifiers().into_iter().find_map(|qualifier| match qualifier {
+            SmtQualifier::AccessMode(access_mode) => Some(access_mode),
+            _ => None
+        })
+    }
+    ///Returns the SMT form title of the qualifiable element.
+    fn get_form_title(&self) -> Option<String> {
+        self.get_smt_qualifiers().into_iter().find_map(|qualifier| match qualifier {
+            SmtQualifier::FormTitle(title) => Some(title),
+            _ => None
+        })
+    }
+    ///Returns the SMT form information of the qualifiable element.
+    fn get_form_info(&self) -> Option<String> {
+        self.get_smt_qualifiers().into_iter().find_map(|qualifier| match qualifier {
+            SmtQualifier::FormInfo(info) => Some(info),
+            _ => None
+        })
+    }
+}
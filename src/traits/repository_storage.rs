@@ -0,0 +1,86 @@
+use crate::enumerations::repository_error::RepositoryError;
+use crate::structs::asset_administration_shell::AssetAdministrationShell;
+use crate::structs::concept_description::ConceptDescription;
+use crate::structs::submodel::Submodel;
+
+///Trait for the storage backends of a repository of shells, submodels and concept descriptions,
+/// e.g. an environment in memory or a database. The repository server reads and writes the
+/// identifiables as a whole; attachments are the contents of file elements addressed by the
+/// identifier of their submodel and their idShortPath.
+pub trait TRepositoryStorage {
+    ///Returns all asset administration shells.
+    fn get_shells(&self) -> Result<Vec<AssetAdministrationShell>, RepositoryError>;
+
+    ///Returns the asset administration shell with the identifier, or None if it does not exist.
+    ///
+    /// [id]: identifier of the shell
+    fn get_shell(&self, id: &str) -> Result<Option<AssetAdministrationShell>, RepositoryError>;
+
+    ///Stores an asset administration shell, replacing the shell with the same identifier.
+    ///
+    /// [shell]: shell to store
+    fn put_shell(&mut self, shell: AssetAdministrationShell) -> Result<(), RepositoryError>;
+
+    ///Deletes the asset administration shell with the identifier. Returns whether it existed.
+    ///
+    /// [id]: identifier of the shell
+    fn delete_shell(&mut self, id: &str) -> Result<bool, RepositoryError>;
+
+    ///Returns all submodels.
+    fn get_submodels(&self) -> Result<Vec<Submodel>, RepositoryError>;
+
+    ///Returns the submodel with the identifier, or None if it does not exist.
+    ///
+    /// [id]: identifier of the submodel
+    fn get_submodel(&self, id: &str) -> Result<Option<Submodel>, RepositoryError>;
+
+    ///Stores a submodel, replacing the submodel with the same identifier.
+    ///
+    /// [submodel]: submodel to store
+    fn put_submodel(&mut self, submodel: Submodel) -> Result<(), RepositoryError>;
+
+    ///Deletes the submodel with the identifier and its attachments. Returns whether it existed.
+    ///
+    /// [id]: identifier of the submodel
+    fn delete_submodel(&mut self, id: &str) -> Result<bool, RepositoryError>;
+
+    ///Returns all concept descriptions.
+    fn get_concept_descriptions(&self) -> Result<Vec<ConceptDescription>, RepositoryError>;
+
+    ///Returns the concept description with the identifier, or None if it does not exist.
+    ///
+    /// [id]: identifier of the concept description
+    fn get_concept_description(&self, id: &str) -> Result<Option<ConceptDescription>, RepositoryError>;
+
+    ///Stores a concept description, replacing the concept description with the same identifier.
+    ///
+    /// [concept_description]: concept description to store
+    fn put_concept_description(&mut self, concept_description: ConceptDescription) -> Result<(), RepositoryError>;
+
+    ///Deletes the concept description with the identifier. Returns whether it existed.
+    ///
+    /// [id]: identifier of the concept description
+    fn delete_concept_description(&mut self, id: &str) -> Result<bool, RepositoryError>;
+
+    ///Returns the content type and the content of the attachment of a file element, or None if
+    /// the element has no attachment.
+    ///
+    /// [submodel_id]: identifier of the submodel containing the file element
+    /// [id_short_path]: idShortPath of the file element
+    fn get_attachment(&self, submodel_id: &str, id_short_path: &str) -> Result<Option<(String, Vec<u8>)>, RepositoryError>;
+
+    ///Stores the attachment of a file element, replacing a previous attachment.
+    ///
+    /// [submodel_id]: identifier of the submodel containing the file element
+    /// [id_short_path]: idShortPath of the file element
+    /// [content_type]: content type of the attachment, e.g. `application/pdf`
+    /// [content]: content of the attachment
+    fn put_attachment(&mut self, submodel_id: &str, id_short_path: &str, content_type: String, content: Vec<u8>)
+        -> Result<(), RepositoryError>;
+
+    ///Deletes the attachment of a file element. Returns whether it existed.
+    ///
+    /// [submodel_id]: identifier of the submodel containing the file element
+    /// [id_short_path]: idShortPath of the file element
+    fn delete_attachment(&mut self, submodel_id: &str, id_short_path: &str) -> Result<bool, RepositoryError>;
+}
\ No newline at end of file
@@ -0,0 +1,285 @@
+use crate::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use crate::structs::entity::Entity;
+use crate::structs::file::File;
+use crate::structs::multi_language_property::MultiLanguageProperty;
+use crate::structs::multi_language_text_type::MultiLanguageTextType;
+use crate::structs::property::Property;
+use crate::structs::submodel::Submodel;
+use crate::structs::submodel_element_collection::SubmodelElementCollection;
+use crate::structs::submodel_element_list::SubmodelElementList;
+use crate::structs::template_element::global_reference;
+use crate::traits::property_value::TPropertyValue;
+
+///Trait for elements that contain submodel elements, i.e. submodels, submodel element collections
+/// and entities. It provides access to the contained elements the way submodel templates identify
+/// them: by their semantic ID or, for elements without semantic ID, by their idShort. An idShort
+/// containing `{00}` matches every idShort with a number at this position.
+pub trait TSubmodelElementContainer {
+    ///Returns the contained submodel elements.
+    fn get_elements(&self) -> &Vec<SubmodelElement>;
+
+    ///Returns the mutable reference to the contained submodel elements.
+    fn get_mut_elements(&mut self) -> &mut Vec<SubmodelElement>;
+
+    ///Returns all contained elements matching the semantic ID or idShort.
+    ///
+    /// [id_short]: idShort of the elements in the submodel template
+    /// [semantic_id]: semantic ID of the elements in the submodel template
+    fn find_elements(&self, id_short: &str, semantic_id: &str) -> Vec<&SubmodelElement> {
+        self.get_elements().iter().filter(|element| is_match(element, id_short, semantic_id)).collect()
+    }
+
+    ///Returns the first contained element matching the semantic ID or idShort.
+    ///
+    /// [id_short]: idShort of the element in the submodel template
+    /// [semantic_id]: semantic ID of the element in the submodel template
+    fn find_element(&self, id_short: &str, semantic_id: &str) -> Option<&SubmodelElement> {
+        self.get_elements().iter().find(|element| is_match(element, id_short, semantic_id))
+    }
+
+    ///Returns the mutable reference to the first contained element matching the semantic ID or
+    /// idShort.
+    ///
+    /// [id_short]: idShort of the element in the submodel template
+    /// [semantic_id]: semantic ID of the element in the submodel template
+    fn find_mut_element(&mut self, id_short: &str, semantic_id: &str) -> Option<&mut SubmodelElement> {
+        self.get_mut_elements().iter_mut().find(|element| is_match(element, id_short, semantic_id))
+    }
+
+    ///Returns the value of the first matching property converted to a Rust type. Returns None if
+    /// there is no such property or its value cannot be converted.
+    ///
+    /// [id_short]: idShort of the property in the submodel template
+    /// [semantic_id]: semantic ID of the property in the submodel template
+    fn get_property_value<T: TPropertyValue>(&self, id_short: &str, semantic_id: &str) -> Option<T> {
+        match self.find_element(id_short, semantic_id)? {
+            SubmodelElement::Property(property) => property.get_typed_value(),
+            _ => None
+        }
+    }
+
+    ///Sets the value of the first matching property. A missing property is added with the data
+    /// type of the Rust type, an existing one keeps its data type. Passing None removes the
+    /// property.
+    ///
+    /// [id_short]: idShort of the property in the submodel template
+    /// [semantic_id]: semantic ID of the property in the submodel template
+    /// [value]: value of the property
+    fn set_property_value<T: TPropertyValue>(&mut self, id_short: &str, semantic_id: &str, value: Option<&T>) {
+        let Some(value) = value else {
+            self.set_element(id_short, semantic_id, None);
+            return;
+        };
+        if let Some(SubmodelElement::Property(property)) = self.find_mut_element(id_short, semantic_id) {
+            property.set_typed_value(value);
+            return;
+        }
+        let mut property = Property::new(T::get_value_type());
+        property.set_typed_value(value);
+        self.set_element(id_short, semantic_id, Some(SubmodelElement::Property(property)));
+    }
+
+    ///Returns the value of the first matching multi-language property.
+    ///
+    /// [id_short]: idShort of the property in the submodel template
+    /// [semantic_id]: semantic ID of the property in the submodel template
+    fn get_multi_language_value(&self, id_short: &str, semantic_id: &str) -> Option<&Vec<MultiLanguageTextType>> {
+        match self.find_element(id_short, semantic_id)? {
+            SubmodelElement::MultiLanguageProperty(property) => Some(property.get_value()),
+            _ => None
+        }
+    }
+
+    ///Sets the value of the first matching multi-language property, which is added if it is
+    /// missing. Passing None removes the property.
+    ///
+    /// [id_short]: idShort of the property in the submodel template
+    /// [semantic_id]: semantic ID of the property in the submodel template
+    /// [value]: value of the property
+    fn set_multi_language_value(&mut self, id_short: &str, semantic_id: &str, value: Option<Vec<MultiLanguageTextType>>) {
+        let Some(value) = value else {
+            self.set_element(id_short, semantic_id, None);
+            return;
+        };
+        if let Some(SubmodelElement::MultiLanguageProperty(property)) = self.find_mut_element(id_short, semantic_id) {
+            property.set_value(value);
+            return;
+        }
+        let mut property = MultiLanguageProperty::new();
+        property.set_value(value);
+        self.set_element(id_short, semantic_id, Some(SubmodelElement::MultiLanguageProperty(property)));
+    }
+
+    ///Returns the first matching file element.
+    ///
+    /// [id_short]: idShort of the file in the submodel template
+    /// [semantic_id]: semantic ID of the file in the submodel template
+    fn get_file(&self, id_short: &str, semantic_id: &str) -> Option<&File> {
+        match self.find_element(id_short, semantic_id)? {
+            SubmodelElement::File(file) => Some(file),
+            _ => None
+        }
+    }
+
+    ///Returns the first matching submodel element collection.
+    ///
+    /// [id_short]: idShort of the collection in the submodel template
+    /// [semantic_id]: semantic ID of the collection in the submodel template
+    fn get_collection(&self, id_short: &str, semantic_id: &str) -> Option<&SubmodelElementCollection> {
+        match self.find_element(id_short, semantic_id)? {
+            SubmodelElement::SubmodelElementCollection(collection) => Some(collection),
+            _ => None
+        }
+    }
+
+    ///Returns all matching submodel element collections.
+    ///
+    /// [id_short]: idShort of the collections in the submodel template
+    /// [semantic_id]: semantic ID of the collections in the submodel template
+    fn get_collections(&self, id_short: &str, semantic_id: &str) -> Vec<&SubmodelElementCollection> {
+        self.find_elements(id_short, semantic_id).into_iter()
+            .filter_map(|element| match element {
+                SubmodelElement::SubmodelElementCollection(collection) => Some(collection),
+                _ => None
+            })
+            .collect()
+    }
+
+    ///Returns the first matching submodel element list.
+    ///
+    /// [id_short]: idShort of the list in the submodel template
+    /// [semantic_id]: semantic ID of the list in the submodel template
+    fn get_list(&self, id_short: &str, semantic_id: &str) -> Option<&SubmodelElementList> {
+        match self.find_element(id_short, semantic_id)? {
+            SubmodelElement::SubmodelElementList(list) => Some(list),
+            _ => None
+        }
+    }
+
+    ///Replaces the first matching element, adds the element if there is none or removes the
+    /// matching element if None is passed. The element gets the idShort and, if it has none, the
+    /// semantic ID of the template.
+    ///
+    /// [id_short]: idShort of the element in the submodel template
+    /// [semantic_id]: semantic ID of the element in the submodel template
+    /// [element]: new element
+    fn set_element(&mut self, id_short: &str, semantic_id: &str, element: Option<SubmodelElement>) {
+        let position = self.get_elements().iter().position(|existing| is_match(existing, id_short, semantic_id));
+        match (element, position) {
+            (Some(element), Some(position)) => self.get_mut_elements()[position] = identify(element, id_short, semantic_id),
+            (Some(element), None) => self.get_mut_elements().push(identify(element, id_short, semantic_id)),
+            (None, Some(position)) => {
+                self.get_mut_elements().remove(position);
+            },
+            (None, None) => {}
+        }
+    }
+
+    ///Adds the element after the last matching element. The element gets the semantic ID of the
+    /// template if it has none and the idShort with `{00}` replaced by the first number, starting
+    /// at 01, that no contained element uses.
+    ///
+    /// [id_short]: idShort of the elements in the submodel template
+    /// [semantic_id]: semantic ID of the elements in the submodel template
+    /// [element]: new element
+    fn add_element(&mut self, id_short: &str, semantic_id: &str, element: SubmodelElement) {
+        let existing = self.get_mut_elements();
+        let mut number = 1;
+        let numbered = |number: usize| id_short.replace("{00}", &format!("{:02}", number));
+        while existing.iter().any(|element| element.get_id_short().is_some_and(|used| *used == numbered(number))) {
+            number += 1;
+        }
+        let position = existing.iter().rposition(|element| is_match(element, id_short, semantic_id))
+            .map(|position| position + 1)
+            .unwrap_or(existing.len());
+        existing.insert(position, identify(element, &numbered(number), semantic_id));
+    }
+
+    ///Replaces all matching elements by the given elements, which are inserted at the position of
+    /// the first matching element. The elements get the semantic ID of the template if they have
+    /// none and idShorts with `{00}` replaced by a running number, starting at 01.
+    ///
+    /// [id_short]: idShort of the elements in the submodel template
+    /// [semantic_id]: semantic ID of the elements in the submodel template
+    /// [elements]: new elements
+    fn set_elements(&mut self, id_short: &str, semantic_id: &str, elements: Vec<SubmodelElement>) {
+        let existing = self.get_mut_elements();
+        let position = existing.iter().position(|element| is_match(element, id_short, semantic_id)).unwrap_or(existing.len());
+        existing.retain(|element| !is_match(element, id_short, semantic_id));
+        let elements = elements.into_iter().enumerate().map(|(index, element)| {
+            identify(element, &id_short.replace("{00}", &format!("{:02}", index + 1)), semantic_id)
+        });
+        existing.splice(position..position, elements);
+    }
+
+    ///Replaces all matching elements by the given elements, which are inserted at the position of
+    /// the first matching element. Unlike `set_elements`, the elements keep their idShorts, e.g.
+    /// names of the interaction affordances of an asset interface, and only get the semantic ID of
+    /// the template if they have none.
+    ///
+    /// [id_short]: idShort of the elements in the submodel template
+    /// [semantic_id]: semantic ID of the elements in the submodel template
+    /// [elements]: new elements
+    fn set_named_elements(&mut self, id_short: &str, semantic_id: &str, elements: Vec<SubmodelElement>) {
+        let existing = self.get_mut_elements();
+        let position = existing.iter().position(|element| is_match(element, id_short, semantic_id)).unwrap_or(existing.len());
+        existing.retain(|element| !is_match(element, id_short, semantic_id));
+        let elements = elements.into_iter().map(|mut element| {
+            if element.get_semantic_id().is_none() {
+                element.set_semantic_id(global_reference(semantic_id));
+            }
+            element
+        });
+        existing.splice(position..position, elements);
+    }
+}
+
+impl TSubmodelElementContainer for Submodel {
+    fn get_elements(&self) -> &Vec<SubmodelElement> {
+        self.get_submodel_elements()
+    }
+
+    fn get_mut_elements(&mut self) -> &mut Vec<SubmodelElement> {
+        self.get_mut_submodel_elements()
+    }
+}
+
+impl TSubmodelElementContainer for SubmodelElementCollection {
+    fn get_elements(&self) -> &Vec<SubmodelElement> {
+        self.get_value()
+    }
+
+    fn get_mut_elements(&mut self) -> &mut Vec<SubmodelElement> {
+        self.get_mut_value()
+    }
+}
+
+impl TSubmodelElementContainer for Entity {
+    fn get_elements(&self) -> &Vec<SubmodelElement> {
+        self.get_statements()
+    }
+
+    fn get_mut_elements(&mut self) -> &mut Vec<SubmodelElement> {
+        self.get_mut_statements()
+    }
+}
+
+///Returns whether the element has the semantic ID or, if it has no semantic ID, the idShort.
+fn is_match(element: &SubmodelElement, id_short: &str, semantic_id: &str) -> bool {
+    match element.get_semantic_id().and_then(|reference| reference.get_keys().first()) {
+        Some(key) => key.get_value() == semantic_id,
+        None => element.get_id_short().is_some_and(|element_id_short| match id_short.split_once("{00}") {
+            Some((prefix, suffix)) => element_id_short.len() > prefix.len() + suffix.len()
+                && element_id_short.starts_with(prefix) && element_id_short.ends_with(suffix),
+            None => element_id_short == id_short
+        })
+    }
+}
+
+fn identify(mut element: SubmodelElement, id_short: &str, semantic_id: &str) -> SubmodelElement {
+    element.set_id_short(id_short.to_string());
+    if element.get_semantic_id().is_none() {
+        element.set_semantic_id(global_reference(semantic_id));
+    }
+    element
+}
\ No newline at end of file
@@ -0,0 +1,14 @@
+use crate::enumerations::binding_error::BindingError;
+use crate::enumerations::data_type_def_xsd::DataTypeDefXsd;
+
+///Trait for sources of the live values of data elements, e.g. an HTTP endpoint, a Modbus register
+/// or an MQTT topic of the asset. A provider reads the current value on each call; the values are
+/// written to the elements by value bindings.
+pub trait TValueProvider {
+    ///Reads the current value of the source. The value is returned as text and is converted to
+    /// the lexical form of the data type by the binding.
+    ///
+    /// [value_type]: data type of the bound element, which determines how binary sources like
+    /// Modbus registers are decoded
+    fn read_value(&mut self, value_type: &DataTypeDefXsd) -> Result<String, BindingError>;
+}
\ No newline at end of file
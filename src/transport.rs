@@ -0,0 +1,86 @@
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+///Splits an absolute URI into scheme, authority and path including the query, e.g.
+/// `http://localhost:8080/temperature` into `http`, `localhost:8080` and `/temperature`. Returns
+/// None if the URI has no scheme or authority.
+pub(crate) fn split_uri(uri: &str) -> Option<(&str, &str, &str)> {
+    let (scheme, rest) = uri.split_once("://")?;
+    let (authority, path) = match rest.find('/') {
+        Some(position) => rest.split_at(position),
+        None => (rest, "")
+    };
+    if scheme.is_empty() || authority.is_empty() {
+        return None;
+    }
+    Some((scheme, authority, path))
+}
+
+///Returns the authority with the default port of the scheme if it has no port.
+///
+/// [authority]: host with optional port, e.g. `localhost` or `[::1]:8080`
+/// [default_port]: default port of the scheme
+pub(crate) fn with_default_port(authority: &str, default_port: u16) -> String {
+    let host_end = authority.rfind(']').map(|position| position + 1).unwrap_or(0);
+    match authority[host_end..].contains(':') {
+        true => authority.to_string(),
+        false => format!("{}:{}", authority, default_port)
+    }
+}
+
+///Connects to the address with the timeout for connecting, reading and writing.
+///
+/// [address]: host and port, e.g. `localhost:8080`
+/// [timeout]: timeout of connecting, reading and writing
+pub(crate) fn connect(address: &str, timeout: Duration) -> std::io::Result<TcpStream> {
+    let mut last_error = std::io::Error::new(std::io::ErrorKind::NotFound, "the host cannot be resolved");
+    for socket_address in address.to_socket_addrs()? {
+        match TcpStream::connect_timeout(&socket_address, timeout) {
+            Ok(stream) => {
+                stream.set_read_timeout(Some(timeout))?;
+                stream.set_write_timeout(Some(timeout))?;
+                return Ok(stream);
+            },
+            Err(error) => last_error = error
+        }
+    }
+    Err(last_error)
+}
+
+///Status code, headers with lowercase names and body of an HTTP response.
+pub(crate) type HttpResponse = (u16, Vec<(String, String)>, Vec<u8>);
+
+///Splits a complete HTTP/1.1 response into status code, headers and body; a chunked body is
+/// decoded. Returns None if the response is malformed or incomplete.
+///
+/// [response]: bytes received until the server closed the connection
+pub(crate) fn parse_http_response(response: &[u8]) -> Option<HttpResponse> {
+    let header_end = response.windows(4).position(|window| window == b"\r\n\r\n")?;
+    let head = String::from_utf8_lossy(&response[..header_end]).to_string();
+    let mut lines = head.split("\r\n");
+    let status = lines.next()?.split(' ').nth(1)?.parse::<u16>().ok()?;
+    let headers: Vec<(String, String)> = lines.filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_lowercase(), value.trim().to_string()))
+        .collect();
+    let chunked = headers.iter().any(|(name, value)| name == "transfer-encoding" && value.eq_ignore_ascii_case("chunked"));
+    let body = &response[header_end + 4..];
+    let body = match chunked {
+        true => decode_chunked(body)?,
+        false => body.to_vec()
+    };
+    Some((status, headers, body))
+}
+
+fn decode_chunked(mut body: &[u8]) -> Option<Vec<u8>> {
+    let mut decoded = Vec::new();
+    loop {
+        let line_end = body.windows(2).position(|window| window == b"\r\n")?;
+        let size = String::from_utf8_lossy(&body[..line_end]);
+        let size = usize::from_str_radix(size.split(';').next().unwrap_or_default().trim(), 16).ok()?;
+        if size == 0 {
+            return Some(decoded);
+        }
+        decoded.extend_from_slice(body.get(line_end + 2..line_end + 2 + size)?);
+        body = body.get(line_end + 4 + size..)?;
+    }
+}
\ No newline at end of file
@@ -0,0 +1,101 @@
+use aas_model_rs::enumerations::cardinality::Cardinality;
+use aas_model_rs::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use aas_model_rs::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use aas_model_rs::enumerations::key_type::KeyType;
+use aas_model_rs::enumerations::modelling_kind::ModellingKind;
+use aas_model_rs::enumerations::reference_type::ReferenceType;
+use aas_model_rs::enumerations::smt_qualifier::SmtQualifier;
+use aas_model_rs::structs::capability::Capability;
+use aas_model_rs::structs::code_generator::CodeGenerator;
+use aas_model_rs::structs::key::Key;
+use aas_model_rs::structs::property::Property;
+use aas_model_rs::structs::reference::Reference;
+use aas_model_rs::structs::submodel::Submodel;
+use aas_model_rs::structs::submodel_element_collection::SubmodelElementCollection;
+use aas_model_rs::traits::has_kind::THasKind;
+use aas_model_rs::traits::has_semantics::THasSemantics;
+use aas_model_rs::traits::qualifiable::TQualifiable;
+use aas_model_rs::traits::referable::TReferable;
+
+#[allow(dead_code)]
+mod markings {
+    include!("generated/markings.rs");
+}
+
+use markings::{Document, Markings};
+
+fn global_reference(value: &str) -> Reference {
+    Reference::new(ReferenceType::ExternalReference, vec![Key::new(KeyType::GlobalReference, value.to_string())])
+}
+
+fn property(id_short: &str, cardinality: Cardinality) -> SubmodelElement {
+    let mut property = Property::new(DataTypeDefXsd::String);
+    property.set_id_short(id_short.to_string());
+    property.add_smt_qualifier(SmtQualifier::Cardinality(cardinality));
+    SubmodelElement::Property(property)
+}
+
+///Creates the template of `generated/markings.rs` with single, optional and multi-valued elements
+/// with and without placeholder and semantic ID.
+fn template() -> Submodel {
+    let mut document = SubmodelElementCollection::new();
+    document.set_id_short("Document{00}".to_string());
+    document.set_semantic_id(global_reference("urn:example:document"));
+    document.add_smt_qualifier(SmtQualifier::Cardinality(Cardinality::ZeroToMany));
+    document.add_value(property("Title", Cardinality::One));
+    let mut skill = Capability::new();
+    skill.set_id_short("Skill".to_string());
+    skill.add_smt_qualifier(SmtQualifier::Cardinality(Cardinality::ZeroToMany));
+
+    let mut template = Submodel::new("urn:example:markings:template".to_string());
+    template.set_id_short("Markings".to_string());
+    template.set_semantic_id(global_reference("urn:example:markings"));
+    template.set_kind(ModellingKind::Template);
+    template.set_submodel_elements(vec![property("Manufacturer", Cardinality::One),
+                                        property("Marking", Cardinality::OneToMany),
+                                        property("Note", Cardinality::ZeroToOne),
+                                        SubmodelElement::SubmodelElementCollection(document),
+                                        SubmodelElement::Capability(skill)]);
+    template
+}
+
+#[test]
+fn generates_expected_code() {
+    assert!(CodeGenerator::new().generate(&template()) == include_str!("generated/markings.rs"));
+}
+
+#[test]
+fn round_trips_generated_struct() {
+    let mut skill = Capability::new();
+    skill.set_id_short("Skill01".to_string());
+    let markings = Markings {
+        manufacturer: "ACME".to_string(),
+        marking: vec!["CE".to_string(), "UKCA".to_string()],
+        note: None,
+        document: vec![Document { title: "Manual".to_string() }, Document { title: "Declaration".to_string() }],
+        skill: vec![SubmodelElement::Capability(skill)]
+    };
+    let submodel = markings.to_submodel("urn:example:markings:instance".to_string());
+
+    let id_shorts: Vec<&str> = submodel.get_submodel_elements().iter()
+        .filter_map(|element| element.get_id_short().map(|id_short| id_short.as_str()))
+        .collect();
+    assert!(id_shorts == vec!["Manufacturer", "Marking01", "Marking02", "Document01", "Document02", "Skill01"]);
+    assert!(Markings::from_submodel(&submodel).ok() == Some(markings));
+}
+
+#[test]
+fn reads_only_numbered_elements_of_multi_valued_fields() {
+    let instance_property = |id_short: &str| {
+        let mut property = Property::new(DataTypeDefXsd::String);
+        property.set_id_short(id_short.to_string());
+        property.set_value("CE".to_string());
+        SubmodelElement::Property(property)
+    };
+    let mut submodel = Submodel::new("urn:example:markings:instance".to_string());
+    submodel.set_submodel_elements(["Manufacturer", "Marking", "MarkingNote", "Marking07"].into_iter()
+        .map(instance_property)
+        .collect());
+
+    assert!(Markings::from_submodel(&submodel).is_ok_and(|markings| markings.marking.len() == 1));
+}
\ No newline at end of file
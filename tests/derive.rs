@@ -0,0 +1,6 @@
+#![cfg(feature = "derive")]
+
+#[test]
+fn rejects_unsupported_field_types() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
\ No newline at end of file
@@ -0,0 +1,219 @@
+// Generated by aas-model-rs from the submodel template 'urn:example:markings:template'. Do not edit.
+
+use aas_model_rs::enumerations::conversion_error::ConversionError;
+use aas_model_rs::enumerations::data_type_def_xsd::DataTypeDefXsd;
+use aas_model_rs::enumerations::interface_enumerations::submodel_element::SubmodelElement;
+use aas_model_rs::enumerations::key_type::KeyType;
+use aas_model_rs::enumerations::modelling_kind::ModellingKind;
+use aas_model_rs::enumerations::reference_type::ReferenceType;
+use aas_model_rs::structs::key::Key;
+use aas_model_rs::structs::property::Property;
+use aas_model_rs::structs::reference::Reference;
+use aas_model_rs::structs::submodel::Submodel;
+use aas_model_rs::structs::submodel_element_collection::SubmodelElementCollection;
+use aas_model_rs::traits::has_kind::THasKind;
+use aas_model_rs::traits::has_semantics::THasSemantics;
+use aas_model_rs::traits::property_value::TPropertyValue;
+use aas_model_rs::traits::referable::TReferable;
+
+///Markings
+#[derive(PartialEq, Clone)]
+pub struct Markings {
+    ///Manufacturer
+    pub manufacturer: String,
+    ///Marking
+    pub marking: Vec<String>,
+    ///Note
+    pub note: Option<String>,
+    ///Document{00}
+    pub document: Vec<Document>,
+    ///Skill
+    pub skill: Vec<SubmodelElement>,
+}
+
+impl Markings {
+    ///Semantic ID of the submodel template.
+    pub const SEMANTIC_ID: &str = "urn:example:markings";
+
+    ///Reads the struct from submodel elements.
+    ///
+    /// [elements]: submodel elements to read
+    /// [path]: idShortPath of the parent of the elements used in error messages
+    pub fn from_elements(elements: &[SubmodelElement], path: &str) -> Result<Markings, ConversionError> {
+        Ok(Markings {
+            manufacturer: {
+                let path = join_path(path, "Manufacturer");
+                let element = required(find_elements(elements, "Manufacturer", None), &path)?;
+                property_value::<String>(element, &path)?
+            },
+            marking: {
+                let mut values = Vec::new();
+                for element in find_elements(elements, "Marking{00}", None) {
+                    let path = join_path(path, element.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default());
+                    values.push(property_value::<String>(element, &path)?);
+                }
+                values
+            },
+            note: {
+                let path = join_path(path, "Note");
+                match find_elements(elements, "Note", None).first() {
+                    Some(element) => Some(property_value::<String>(element, &path)?),
+                    None => None
+                }
+            },
+            document: {
+                let mut values = Vec::new();
+                for element in find_elements(elements, "Document{00}", Some("urn:example:document")) {
+                    let path = join_path(path, element.get_id_short().map(|id_short| id_short.as_str()).unwrap_or_default());
+                    values.push(Document::from_elements(children(element, &path)?, &path)?);
+                }
+                values
+            },
+            skill: find_elements(elements, "Skill{00}", None).into_iter().cloned().collect(),
+        })
+    }
+
+    ///Writes the struct as submodel elements.
+    pub fn to_elements(&self) -> Vec<SubmodelElement> {
+        let mut elements = vec![
+            property(Some("Manufacturer".to_string()), None, DataTypeDefXsd::String, self.manufacturer.format_value()),
+        ];
+        for (index, value) in self.marking.iter().enumerate() {
+            elements.push(property(Some(format!("Marking{:02}", index + 1)), None, DataTypeDefXsd::String, value.format_value()));
+        }
+        if let Some(value) = &self.note {
+            elements.push(property(Some("Note".to_string()), None, DataTypeDefXsd::String, value.format_value()));
+        }
+        for (index, value) in self.document.iter().enumerate() {
+            elements.push(collection(Some(format!("Document{:02}", index + 1)), Some("urn:example:document"), value.to_elements()));
+        }
+        for (index, value) in self.skill.iter().enumerate() {
+            elements.push(raw(Some(format!("Skill{:02}", index + 1)), None, value.clone()));
+        }
+        elements
+    }
+
+    ///Reads the struct from a submodel instance of the template.
+    ///
+    /// [submodel]: submodel instance
+    pub fn from_submodel(submodel: &Submodel) -> Result<Markings, ConversionError> {
+        Markings::from_elements(submodel.get_submodel_elements(), "")
+    }
+
+    ///Writes the struct as a submodel instance of the template.
+    ///
+    /// [id]: global identifier of the submodel instance
+    pub fn to_submodel(&self, id: String) -> Submodel {
+        let mut submodel = Submodel::new(id);
+        submodel.set_id_short("Markings".to_string());
+        submodel.set_semantic_id(global_reference(Markings::SEMANTIC_ID));
+        submodel.set_kind(ModellingKind::Instance);
+        submodel.set_submodel_elements(self.to_elements());
+        submodel
+    }
+}
+
+///Document
+#[derive(PartialEq, Clone)]
+pub struct Document {
+    ///Title
+    pub title: String,
+}
+
+impl Document {
+    ///Reads the struct from submodel elements.
+    ///
+    /// [elements]: submodel elements to read
+    /// [path]: idShortPath of the parent of the elements used in error messages
+    pub fn from_elements(elements: &[SubmodelElement], path: &str) -> Result<Document, ConversionError> {
+        Ok(Document {
+            title: {
+                let path = join_path(path, "Title");
+                let element = required(find_elements(elements, "Title", None), &path)?;
+                property_value::<String>(element, &path)?
+            },
+        })
+    }
+
+    ///Writes the struct as submodel elements.
+    pub fn to_elements(&self) -> Vec<SubmodelElement> {
+        vec![
+            property(Some("Title".to_string()), None, DataTypeDefXsd::String, self.title.format_value()),
+        ]
+    }
+}
+
+fn children<'a>(element: &'a SubmodelElement, path: &str) -> Result<&'a Vec<SubmodelElement>, ConversionError> {
+    element.get_children().ok_or_else(|| ConversionError::UnexpectedElementType(path.to_string()))
+}
+
+fn collection(id_short: Option<String>, semantic_id: Option<&str>, value: Vec<SubmodelElement>) -> SubmodelElement {
+    let mut collection = SubmodelElementCollection::new();
+    collection.set_value(value);
+    SubmodelElement::SubmodelElementCollection(identify(collection, id_short, semantic_id))
+}
+
+fn find_elements<'a>(elements: &'a [SubmodelElement], id_short: &str, semantic_id: Option<&str>)
+    -> Vec<&'a SubmodelElement> {
+    elements.iter().filter(|element| match semantic_id {
+        Some(semantic_id) => element.get_semantic_id().and_then(|reference| reference.get_keys().first())
+            .is_some_and(|key| key.get_value() == semantic_id),
+        None => element.get_id_short().is_some_and(|element_id_short| match id_short.split_once("{00}") {
+            Some((prefix, suffix)) => element_id_short.strip_prefix(prefix)
+                .and_then(|number| number.strip_suffix(suffix))
+                .is_some_and(|number| !number.is_empty() && number.chars().all(|char| char.is_ascii_digit())),
+            None => element_id_short == id_short
+        })
+    }).collect()
+}
+
+fn global_reference(value: &str) -> Reference {
+    Reference::new(ReferenceType::ExternalReference, vec![Key::new(KeyType::GlobalReference, value.to_string())])
+}
+
+fn identify<T: TReferable + THasSemantics>(mut element: T, id_short: Option<String>, semantic_id: Option<&str>) -> T {
+    if let Some(id_short) = id_short {
+        element.set_id_short(id_short);
+    }
+    if let Some(semantic_id) = semantic_id {
+        element.set_semantic_id(global_reference(semantic_id));
+    }
+    element
+}
+
+fn join_path(path: &str, id_short: &str) -> String {
+    if path.is_empty() {
+        id_short.to_string()
+    } else {
+        format!("{}.{}", path, id_short)
+    }
+}
+
+fn property(id_short: Option<String>, semantic_id: Option<&str>, value_type: DataTypeDefXsd, value: String)
+    -> SubmodelElement {
+    let mut property = Property::new(value_type);
+    property.set_value(value);
+    SubmodelElement::Property(identify(property, id_short, semantic_id))
+}
+
+fn property_value<T: TPropertyValue>(element: &SubmodelElement, path: &str) -> Result<T, ConversionError> {
+    match element {
+        SubmodelElement::Property(property) => property.get_typed_value()
+            .ok_or_else(|| ConversionError::InvalidValue(path.to_string())),
+        _ => Err(ConversionError::UnexpectedElementType(path.to_string()))
+    }
+}
+
+fn raw(id_short: Option<String>, semantic_id: Option<&str>, mut element: SubmodelElement) -> SubmodelElement {
+    if let Some(id_short) = id_short {
+        element.set_id_short(id_short);
+    }
+    if let Some(semantic_id) = semantic_id {
+        element.set_semantic_id(global_reference(semantic_id));
+    }
+    element
+}
+
+fn required<'a>(elements: Vec<&'a SubmodelElement>, path: &str) -> Result<&'a SubmodelElement, ConversionError> {
+    elements.into_iter().next().ok_or_else(|| ConversionError::MissingElement(path.to_string()))
+}
@@ -0,0 +1,14 @@
+use aas_model_rs::AasCollection;
+
+struct Dimensions {
+    width: f64,
+    height: f64
+}
+
+#[derive(AasCollection)]
+struct Nameplate {
+    manufacturer: String,
+    dimensions: Dimensions
+}
+
+fn main() {}